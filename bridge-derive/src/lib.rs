@@ -1,12 +1,13 @@
-use darling::FromDeriveInput;
+use darling::{FromDeriveInput, FromField};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{DeriveInput, Type};
 
 #[derive(FromDeriveInput, Default)]
 #[darling(default, attributes(bridge_pack), forward_attrs(allow, doc, cfg))]
 struct Opts {
     length: usize,
+    legacy_length: Option<usize>,
 }
 
 #[proc_macro_derive(BridgePack, attributes(bridge_pack))]
@@ -21,21 +22,144 @@ fn impl_derive_bridge_pack(input: syn::DeriveInput) -> TokenStream {
 
     let len = opts.length;
 
+    // Accounts created before the layout grew keep their length
+    let legacy = opts.legacy_length.map(|legacy_len| {
+        quote! {
+            fn unpack_unchecked(input: &[u8]) -> Result<Self, ProgramError> {
+                if input.len() == #legacy_len {
+                    // Fields appended to the legacy layout are read as zeroed
+                    let mut padded = input.to_vec();
+                    padded.resize(Self::LEN, 0);
+                    return Self::unpack_from_slice(&padded);
+                }
+                if input.len() != Self::LEN {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                Self::unpack_from_slice(input)
+            }
+
+            fn pack(src: Self, dst: &mut [u8]) -> Result<(), ProgramError> {
+                if dst.len() != Self::LEN && dst.len() != #legacy_len {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                // Legacy account has to be migrated once the data outgrows it
+                if src.try_to_vec()?.len() > dst.len() {
+                    return Err(ProgramError::AccountDataTooSmall);
+                }
+                src.pack_into_slice(dst);
+                Ok(())
+            }
+        }
+    });
+
     let output = quote! {
         impl Pack for #ident {
             const LEN: usize = #len;
 
             fn pack_into_slice(&self, dst: &mut [u8]) {
-                let mut data = self.try_to_vec().unwrap();
-                let (left, _) = dst.split_at_mut(data.len());
-                left.copy_from_slice(&data);
+                let data = self.try_to_vec().unwrap();
+                ::bridge_utils::state::pack_with_discriminator(&self.account_kind, &data, dst);
             }
 
-            fn unpack_from_slice(mut src: &[u8]) -> Result<Self, ProgramError> {
+            fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+                let mut src = ::bridge_utils::state::strip_discriminator(src);
                 let unpacked = Self::deserialize(&mut src)?;
                 Ok(unpacked)
             }
+
+            #legacy
+        }
+    };
+    output
+}
+
+#[derive(FromField)]
+#[darling(attributes(account))]
+struct AccountOpts {
+    ident: Option<syn::Ident>,
+    ty: Type,
+    #[darling(default)]
+    signer: bool,
+    #[darling(default)]
+    writable: bool,
+}
+
+#[derive(FromDeriveInput)]
+#[darling(supports(struct_named))]
+struct AccountMetasOpts {
+    ident: syn::Ident,
+    data: darling::ast::Data<(), AccountOpts>,
+}
+
+#[proc_macro_derive(AccountMetas, attributes(account))]
+pub fn derive_account_metas(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    impl_derive_account_metas(input).into()
+}
+
+fn impl_derive_account_metas(input: syn::DeriveInput) -> TokenStream {
+    let opts = AccountMetasOpts::from_derive_input(&input).expect("Wrong options");
+    let ident = opts.ident;
+
+    let fields = opts.data.take_struct().expect("Named struct").fields;
+
+    let pushes = fields.into_iter().map(|field| {
+        let name = field.ident.expect("Named field");
+        let signer = field.signer;
+
+        let meta = |pubkey: TokenStream| {
+            if field.writable {
+                quote! { ::solana_program::instruction::AccountMeta::new(#pubkey, #signer) }
+            } else {
+                quote! { ::solana_program::instruction::AccountMeta::new_readonly(#pubkey, #signer) }
+            }
+        };
+
+        // Optional accounts are skipped, lists are appended in order
+        match wrapper_type(&field.ty) {
+            Some(wrapper) if wrapper == "Option" => {
+                let meta = meta(quote! { pubkey });
+                quote! {
+                    if let Some(pubkey) = self.#name {
+                        accounts.push(#meta);
+                    }
+                }
+            }
+            Some(wrapper) if wrapper == "Vec" => {
+                let meta = meta(quote! { *pubkey });
+                quote! {
+                    accounts.extend(self.#name.iter().map(|pubkey| #meta));
+                }
+            }
+            _ => {
+                let meta = meta(quote! { self.#name });
+                quote! {
+                    accounts.push(#meta);
+                }
+            }
+        }
+    });
+
+    let output = quote! {
+        impl #ident {
+            pub fn to_account_metas(&self) -> Vec<::solana_program::instruction::AccountMeta> {
+                let mut accounts = Vec::new();
+                #(#pushes)*
+                accounts
+            }
         }
     };
     output
 }
+
+fn wrapper_type(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .filter(|segment| !segment.arguments.is_empty())
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
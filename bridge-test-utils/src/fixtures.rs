@@ -0,0 +1,135 @@
+use borsh::BorshSerialize;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::EverAddress;
+
+use solana_program::hash::hash;
+use solana_program::pubkey::Pubkey;
+
+use token_proxy::{Settings, TokenKind, TokenSettings, CURRENT_ACCOUNT_VERSION};
+
+/// Token Proxy settings with all optional features disabled
+pub fn settings(guardian: Pubkey, manager: Pubkey, withdrawal_manager: Pubkey) -> Settings {
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    }
+}
+
+/// Round Loader settings with default quorum and no handover period
+pub fn round_loader_settings(current_round_number: u32) -> round_loader::Settings {
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    }
+}
+
+/// Settings of EVER token without limits
+pub fn ever_token_settings(token: EverAddress, decimals: u8) -> TokenSettings {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], &token_proxy::id());
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+
+    let kind = TokenKind::Ever {
+        mint: token_proxy::get_mint_address(&token),
+        token,
+        decimals,
+    };
+
+    token_settings(
+        AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind,
+    )
+}
+
+/// Settings of Solana token without limits
+pub fn sol_token_settings(mint: Pubkey) -> TokenSettings {
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint.to_bytes()], &token_proxy::id());
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+
+    let kind = TokenKind::Solana {
+        mint,
+        vault: token_proxy::get_vault_address(&mint),
+    };
+
+    token_settings(
+        AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind,
+    )
+}
+
+fn token_settings(account_kind: AccountKind, kind: TokenKind) -> TokenSettings {
+    TokenSettings {
+        is_initialized: true,
+        account_kind,
+        kind,
+        name: "USDT Solana Octusbridge".to_string(),
+        symbol: "USDT".to_string(),
+        deposit_limit: u64::MAX,
+        withdrawal_limit: u64::MAX,
+        withdrawal_daily_limit: u64::MAX,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    }
+}
@@ -0,0 +1,5 @@
+mod fixtures;
+mod test_bridge;
+
+pub use self::fixtures::*;
+pub use self::test_bridge::*;
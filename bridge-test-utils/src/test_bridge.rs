@@ -0,0 +1,225 @@
+use bridge_utils::state::AccountKind;
+
+use solana_program::hash::Hash;
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_program;
+use solana_program_test::{processor, BanksClient, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::signature::Keypair;
+use spl_token::state::AccountState;
+
+use token_proxy::{MultiVault, Settings, TokenKind, TokenSettings, CURRENT_ACCOUNT_VERSION};
+
+/// Token Proxy program test seeded with bridge accounts
+pub struct TestBridge {
+    program_test: ProgramTest,
+}
+
+impl Default for TestBridge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestBridge {
+    pub fn new() -> Self {
+        let program_test = ProgramTest::new(
+            "token_proxy",
+            token_proxy::id(),
+            processor!(token_proxy::Processor::process),
+        );
+
+        Self { program_test }
+    }
+
+    pub fn program_test_mut(&mut self) -> &mut ProgramTest {
+        &mut self.program_test
+    }
+
+    pub fn with_account(mut self, address: Pubkey, account: Account) -> Self {
+        self.program_test.add_account(address, account);
+        self
+    }
+
+    /// System account holding `lamports`
+    pub fn with_funded_account(self, address: Pubkey, lamports: u64) -> Self {
+        self.with_account(
+            address,
+            Account {
+                lamports,
+                data: vec![],
+                owner: system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+    }
+
+    /// Rent exempt account of `owner` holding packed `data`
+    pub fn with_packed_account<T: Pack>(self, address: Pubkey, owner: Pubkey, data: T) -> Self {
+        let mut packed = vec![0; T::LEN];
+        T::pack(data, &mut packed).expect("pack");
+
+        self.with_account(
+            address,
+            Account {
+                lamports: Rent::default().minimum_balance(T::LEN),
+                data: packed,
+                owner,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+    }
+
+    pub fn with_settings(self, settings: Settings) -> Self {
+        self.with_packed_account(
+            token_proxy::get_settings_address(),
+            token_proxy::id(),
+            settings,
+        )
+    }
+
+    pub fn with_multivault(self) -> Self {
+        let (_, multivault_nonce) =
+            Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+
+        self.with_packed_account(
+            token_proxy::get_multivault_address(),
+            token_proxy::id(),
+            MultiVault {
+                is_initialized: true,
+                account_kind: AccountKind::MultiVault(multivault_nonce),
+                version: CURRENT_ACCOUNT_VERSION,
+            },
+        )
+    }
+
+    pub fn with_round_loader_settings(self, settings: round_loader::Settings) -> Self {
+        self.with_packed_account(
+            round_loader::get_settings_address(),
+            round_loader::id(),
+            settings,
+        )
+    }
+
+    /// Relay Round with equal weights of Relays
+    pub fn with_relay_round(self, round_number: u32, relays: Vec<Pubkey>, round_end: u32) -> Self {
+        self.with_weighted_relay_round(round_number, relays, vec![], round_end)
+    }
+
+    pub fn with_weighted_relay_round(
+        self,
+        round_number: u32,
+        relays: Vec<Pubkey>,
+        weights: Vec<u32>,
+        round_end: u32,
+    ) -> Self {
+        let (_, relay_round_nonce) = Pubkey::find_program_address(
+            &[br"relay_round", &round_number.to_le_bytes()],
+            &round_loader::id(),
+        );
+
+        self.with_packed_account(
+            round_loader::get_relay_round_address(round_number),
+            round_loader::id(),
+            round_loader::RelayRound {
+                is_initialized: true,
+                account_kind: AccountKind::RelayRound(relay_round_nonce),
+                round_number,
+                round_end,
+                relays,
+                weights,
+                quorum_numerator: 0,
+                quorum_denominator: 0,
+            },
+        )
+    }
+
+    /// EVER token settings together with its mint
+    pub fn with_ever_token(self, token_settings: TokenSettings, supply: u64) -> Self {
+        let (token, mint, decimals) = match token_settings.kind {
+            TokenKind::Ever {
+                token,
+                mint,
+                decimals,
+            } => (token, mint, decimals),
+            TokenKind::Solana { .. } => panic!("expected EVER token settings"),
+        };
+
+        self.with_mint(mint, mint, decimals, supply)
+            .with_packed_account(
+                token_proxy::get_token_settings_ever_address(&token),
+                token_proxy::id(),
+                token_settings,
+            )
+    }
+
+    /// Solana token settings together with its mint and vault holding `vault_amount`
+    pub fn with_sol_token(
+        self,
+        token_settings: TokenSettings,
+        decimals: u8,
+        vault_amount: u64,
+    ) -> Self {
+        let (mint, vault) = match token_settings.kind {
+            TokenKind::Solana { mint, vault } => (mint, vault),
+            TokenKind::Ever { .. } => panic!("expected Solana token settings"),
+        };
+
+        self.with_mint(mint, mint, decimals, vault_amount)
+            .with_token_account(vault, mint, vault, vault_amount)
+            .with_packed_account(
+                token_proxy::get_token_settings_sol_address(&mint),
+                token_proxy::id(),
+                token_settings,
+            )
+    }
+
+    pub fn with_mint(self, mint: Pubkey, authority: Pubkey, decimals: u8, supply: u64) -> Self {
+        self.with_packed_account(
+            mint,
+            spl_token::id(),
+            spl_token::state::Mint {
+                is_initialized: true,
+                mint_authority: COption::Some(authority),
+                supply,
+                decimals,
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn with_token_account(
+        self,
+        address: Pubkey,
+        mint: Pubkey,
+        owner: Pubkey,
+        amount: u64,
+    ) -> Self {
+        self.with_packed_account(
+            address,
+            spl_token::id(),
+            spl_token::state::Account {
+                mint,
+                owner,
+                amount,
+                state: AccountState::Initialized,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Associated token account of `owner` holding `amount`
+    pub fn with_associated_token_account(self, owner: Pubkey, mint: Pubkey, amount: u64) -> Self {
+        let address = spl_associated_token_account::get_associated_token_address(&owner, &mint);
+        self.with_token_account(address, mint, owner, amount)
+    }
+
+    pub async fn start(self) -> (BanksClient, Keypair, Hash) {
+        self.program_test.start().await
+    }
+}
@@ -0,0 +1,58 @@
+use super::errors::SolanaBridgeError;
+
+/// Scale amount of Solana token to Ever decimals. Rejects amounts that would lose precision
+pub fn to_ever_amount(
+    amount: u64,
+    ever_decimals: u8,
+    solana_decimals: u8,
+) -> Result<u128, SolanaBridgeError> {
+    let amount = if ever_decimals > solana_decimals {
+        let trunc_multiplier = decimals_factor(ever_decimals - solana_decimals)?;
+        (amount as u128)
+            .checked_mul(trunc_multiplier)
+            .ok_or(SolanaBridgeError::Overflow)?
+    } else {
+        let trunc_divisor = decimals_factor(solana_decimals - ever_decimals)?;
+        let trunc_amount = (amount as u128)
+            .checked_div(trunc_divisor)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Reject amounts that would lose precision
+        if trunc_amount.checked_mul(trunc_divisor) != Some(amount as u128) {
+            return Err(SolanaBridgeError::AmountTruncated);
+        }
+
+        trunc_amount
+    };
+
+    Ok(amount)
+}
+
+/// Scale amount of Ever token to Solana decimals. Extra Ever decimals are truncated
+pub fn to_solana_amount(
+    amount: u128,
+    ever_decimals: u8,
+    solana_decimals: u8,
+) -> Result<u64, SolanaBridgeError> {
+    let amount = if ever_decimals > solana_decimals {
+        let trunc_divisor = decimals_factor(ever_decimals - solana_decimals)?;
+        amount
+            .checked_div(trunc_divisor)
+            .ok_or(SolanaBridgeError::Overflow)?
+    } else {
+        let trunc_multiplier = decimals_factor(solana_decimals - ever_decimals)?;
+        amount
+            .checked_mul(trunc_multiplier)
+            .ok_or(SolanaBridgeError::Overflow)?
+    };
+
+    let amount = u64::try_from(amount).map_err(|_| SolanaBridgeError::Overflow)?;
+
+    Ok(amount)
+}
+
+fn decimals_factor(decimals: u8) -> Result<u128, SolanaBridgeError> {
+    10u128
+        .checked_pow(decimals as u32)
+        .ok_or(SolanaBridgeError::Overflow)
+}
@@ -38,6 +38,106 @@ pub enum SolanaBridgeError {
     InvalidTokenSettingsName,
     #[error("Failed to deserialize payload")]
     DeserializePayload,
+    #[error("Payload program is not allowed")]
+    PayloadProgramNotAllowed,
+    #[error("Payload allowlist is too long")]
+    PayloadAllowlistLenLimit,
+    #[error("Deposit caller is not allowed")]
+    DepositCallerNotAllowed,
+    #[error("Deposit caller allowlist is too long")]
+    DepositCallerAllowlistLenLimit,
+    #[error("Amount cannot be converted without truncation")]
+    AmountTruncated,
+    #[error("Invalid token decimals")]
+    InvalidTokenDecimals,
+    #[error("Deposit daily limit exceeded")]
+    DepositDailyLimit,
+    #[error("Token is delisted")]
+    TokenDelisted,
+    #[error("Token is not delisted")]
+    TokenNotDelisted,
+    #[error("Authority migration is not proposed")]
+    AuthorityMigrationNotProposed,
+    #[error("Authority migration is timelocked")]
+    AuthorityMigrationTimelocked,
+    #[error("Proposal with different event data already exists")]
+    DivergentProposal,
+    #[error("Event hash does not match event data")]
+    InvalidEventHash,
+    #[error("Chain is not enabled")]
+    ChainDisabled,
+    #[error("Enabled chains list is too long")]
+    EnabledChainsLenLimit,
+    #[error("Token deposits are paused")]
+    TokenDepositsPaused,
+    #[error("Token withdrawals are paused")]
+    TokenWithdrawalsPaused,
+    #[error("Relay eviction already executed")]
+    RelayEvictionExecuted,
+    #[error("Deposit value doesn't cover expected evers")]
+    DepositValueTooLow,
+    #[error("Instruction version is no longer supported")]
+    InstructionVersionNotSupported,
+    #[error("Withdrawal already has votes")]
+    WithdrawalHasVotes,
+    #[error("Message payload is too long")]
+    MessagePayloadLenLimit,
+    #[error("Message is not confirmed by relays")]
+    MessageNotConfirmed,
+    #[error("Withdrawal limit exemptions list is too long")]
+    WithdrawalLimitExemptionsLenLimit,
+    #[error("Recipient hook is too long")]
+    RecipientHookLenLimit,
+    #[error("Recipient hook program is not allowed")]
+    RecipientHookProgramNotAllowed,
+    #[error("Role transfer is not proposed")]
+    RoleTransferNotProposed,
+    #[error("Owner changes have to be scheduled through timelock")]
+    TimelockEnabled,
+    #[error("Timelocked change is not ready yet")]
+    TimelockNotExpired,
+    #[error("Votes don't prove Relay misbehavior")]
+    RelayMisbehaviorNotProven,
+    #[error("Account already has the current layout")]
+    AccountVersionUpToDate,
+    #[error("Fee tiers list is too long")]
+    FeeTiersLenLimit,
+    #[error("Withdrawal request is not expired yet")]
+    WithdrawalNotExpired,
+    #[error("Deposit is already refunded")]
+    DepositAlreadyRefunded,
+    #[error("Denylist is too long")]
+    DenylistLenLimit,
+    #[error("Withdrawal has unclaimed vote rewards")]
+    UnclaimedVoteRewards,
+    #[error("Deposit is not refunded")]
+    DepositNotRefunded,
+    #[error("Too many withdrawal recipients")]
+    WithdrawalRecipientsLenLimit,
+    #[error("Deposit amount is below minimum")]
+    DepositBelowMinimum,
+    #[error("Liquidity provider registry is too long")]
+    LpRegistryLenLimit,
+    #[error("Liquidity provider is not registered")]
+    LiquidityProviderNotRegistered,
+    #[error("Deposit callback program is not allowed")]
+    DepositCallbackProgramNotAllowed,
+    #[error("Approval queue is full")]
+    ApprovalQueueLenLimit,
+    #[error("Approval note is too long")]
+    ApprovalNoteTooLong,
+    #[error("Emergency expiry is in the past")]
+    EmergencyExpiryInPast,
+    #[error("Token has withdrawals to be paid out")]
+    WithdrawalsPending,
+    #[error("Token is closed")]
+    TokenClosed,
+    #[error("Bridge deposits are paused")]
+    DepositsPaused,
+    #[error("Bridge withdrawals are paused")]
+    WithdrawalsPaused,
+    #[error("Guardians list is too long")]
+    GuardiansLenLimit,
 }
 
 impl From<SolanaBridgeError> for ProgramError {
@@ -58,14 +158,78 @@ impl TryFrom<u32> for SolanaBridgeError {
             4 => Ok(SolanaBridgeError::InvalidRelay),
             5 => Ok(SolanaBridgeError::EmergencyEnabled),
             6 => Ok(SolanaBridgeError::InvalidWithdrawalStatus),
-            7 => Ok(SolanaBridgeError::InsufficientVaultBalance),
-            8 => Ok(SolanaBridgeError::RelayAlreadyVoted),
-            9 => Ok(SolanaBridgeError::Overflow),
-            10 => Ok(SolanaBridgeError::TokenNameLenLimit),
-            11 => Ok(SolanaBridgeError::TokenSymbolLenLimit),
-            12 => Ok(SolanaBridgeError::InvalidVote),
-            13 => Ok(SolanaBridgeError::VotesOverflow),
+            7 => Ok(SolanaBridgeError::InsufficientBalance),
+            8 => Ok(SolanaBridgeError::InsufficientVaultBalance),
+            9 => Ok(SolanaBridgeError::RelayAlreadyVoted),
+            10 => Ok(SolanaBridgeError::Overflow),
+            11 => Ok(SolanaBridgeError::TokenNameLenLimit),
+            12 => Ok(SolanaBridgeError::TokenSymbolLenLimit),
+            13 => Ok(SolanaBridgeError::InvalidVote),
+            14 => Ok(SolanaBridgeError::VotesOverflow),
+            15 => Ok(SolanaBridgeError::InvalidTokenSettingsName),
+            16 => Ok(SolanaBridgeError::DeserializePayload),
+            17 => Ok(SolanaBridgeError::PayloadProgramNotAllowed),
+            18 => Ok(SolanaBridgeError::PayloadAllowlistLenLimit),
+            19 => Ok(SolanaBridgeError::DepositCallerNotAllowed),
+            20 => Ok(SolanaBridgeError::DepositCallerAllowlistLenLimit),
+            21 => Ok(SolanaBridgeError::AmountTruncated),
+            22 => Ok(SolanaBridgeError::InvalidTokenDecimals),
+            23 => Ok(SolanaBridgeError::DepositDailyLimit),
+            24 => Ok(SolanaBridgeError::TokenDelisted),
+            25 => Ok(SolanaBridgeError::TokenNotDelisted),
+            26 => Ok(SolanaBridgeError::AuthorityMigrationNotProposed),
+            27 => Ok(SolanaBridgeError::AuthorityMigrationTimelocked),
+            28 => Ok(SolanaBridgeError::DivergentProposal),
+            29 => Ok(SolanaBridgeError::InvalidEventHash),
+            30 => Ok(SolanaBridgeError::ChainDisabled),
+            31 => Ok(SolanaBridgeError::EnabledChainsLenLimit),
+            32 => Ok(SolanaBridgeError::TokenDepositsPaused),
+            33 => Ok(SolanaBridgeError::TokenWithdrawalsPaused),
+            34 => Ok(SolanaBridgeError::RelayEvictionExecuted),
+            35 => Ok(SolanaBridgeError::DepositValueTooLow),
+            36 => Ok(SolanaBridgeError::InstructionVersionNotSupported),
+            37 => Ok(SolanaBridgeError::WithdrawalHasVotes),
+            38 => Ok(SolanaBridgeError::MessagePayloadLenLimit),
+            39 => Ok(SolanaBridgeError::MessageNotConfirmed),
+            40 => Ok(SolanaBridgeError::WithdrawalLimitExemptionsLenLimit),
+            41 => Ok(SolanaBridgeError::RecipientHookLenLimit),
+            42 => Ok(SolanaBridgeError::RecipientHookProgramNotAllowed),
+            43 => Ok(SolanaBridgeError::RoleTransferNotProposed),
+            44 => Ok(SolanaBridgeError::TimelockEnabled),
+            45 => Ok(SolanaBridgeError::TimelockNotExpired),
+            46 => Ok(SolanaBridgeError::RelayMisbehaviorNotProven),
+            47 => Ok(SolanaBridgeError::AccountVersionUpToDate),
+            48 => Ok(SolanaBridgeError::FeeTiersLenLimit),
+            49 => Ok(SolanaBridgeError::WithdrawalNotExpired),
+            50 => Ok(SolanaBridgeError::DepositAlreadyRefunded),
+            51 => Ok(SolanaBridgeError::DenylistLenLimit),
+            52 => Ok(SolanaBridgeError::UnclaimedVoteRewards),
+            53 => Ok(SolanaBridgeError::DepositNotRefunded),
+            54 => Ok(SolanaBridgeError::WithdrawalRecipientsLenLimit),
+            55 => Ok(SolanaBridgeError::DepositBelowMinimum),
+            56 => Ok(SolanaBridgeError::LpRegistryLenLimit),
+            57 => Ok(SolanaBridgeError::LiquidityProviderNotRegistered),
+            58 => Ok(SolanaBridgeError::DepositCallbackProgramNotAllowed),
+            59 => Ok(SolanaBridgeError::ApprovalQueueLenLimit),
+            60 => Ok(SolanaBridgeError::ApprovalNoteTooLong),
+            61 => Ok(SolanaBridgeError::EmergencyExpiryInPast),
+            62 => Ok(SolanaBridgeError::WithdrawalsPending),
+            63 => Ok(SolanaBridgeError::TokenClosed),
+            64 => Ok(SolanaBridgeError::DepositsPaused),
+            65 => Ok(SolanaBridgeError::WithdrawalsPaused),
+            66 => Ok(SolanaBridgeError::GuardiansLenLimit),
             _ => Err(()),
         }
     }
 }
+
+impl SolanaBridgeError {
+    /// Finds the bridge error in logs of a failed transaction
+    pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Option<Self> {
+        logs.iter().find_map(|log| {
+            let code = log.as_ref().split("custom program error: 0x").nth(1)?;
+            let code = u32::from_str_radix(code.trim(), 16).ok()?;
+            SolanaBridgeError::try_from(code).ok()
+        })
+    }
+}
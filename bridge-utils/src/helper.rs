@@ -1,9 +1,22 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::bpf_loader_upgradeable;
 use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
-use solana_program::hash::Hash;
+use solana_program::ed25519_program;
+use solana_program::hash::{hashv, Hash};
+use solana_program::instruction::Instruction;
 use solana_program::program_error::ProgramError;
-use solana_program::pubkey::Pubkey;
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use super::types::Vote;
+
+const ED25519_SIGNATURE_LEN: usize = 64;
+const ED25519_SIGNATURE_OFFSETS_START: usize = 2;
+const ED25519_SIGNATURE_OFFSETS_LEN: usize = 14;
+// Offsets point to data of the Ed25519 instruction itself
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
 
 pub fn get_programdata_address(program_id: &Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0
@@ -17,26 +30,216 @@ pub fn get_associated_relay_round_address(program_id: &Pubkey, round_number: u32
     Pubkey::find_program_address(&[br"relay_round", &round_number.to_le_bytes()], program_id).0
 }
 
+pub fn get_governance_address(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    governed_account: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"account-governance",
+            realm.as_ref(),
+            governed_account.as_ref(),
+        ],
+        governance_program_id,
+    )
+    .0
+}
+
+pub fn get_native_treasury_address(governance_program_id: &Pubkey, governance: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"native-treasury", governance.as_ref()],
+        governance_program_id,
+    )
+    .0
+}
+
+pub fn get_proposal_seeds(
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    chain_id: Option<u64>,
+    event_data: &[u8],
+) -> Vec<Vec<u8>> {
+    let mut seeds = vec![
+        br"proposal".to_vec(),
+        round_number.to_le_bytes().to_vec(),
+        event_timestamp.to_le_bytes().to_vec(),
+        event_transaction_lt.to_le_bytes().to_vec(),
+        event_configuration.to_bytes().to_vec(),
+    ];
+
+    // Proposals from EVER chain keep the original seeds
+    if let Some(chain_id) = chain_id {
+        seeds.push(chain_id.to_le_bytes().to_vec());
+    }
+
+    seeds.push(event_data.to_vec());
+    seeds
+}
+
 pub fn get_associated_proposal_address(
     program_id: &Pubkey,
     round_number: u32,
     event_timestamp: u32,
     event_transaction_lt: u64,
     event_configuration: &Pubkey,
+    chain_id: Option<u64>,
     event_data: &[u8],
 ) -> Pubkey {
-    Pubkey::find_program_address(
-        &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            event_data,
-        ],
-        program_id,
-    )
-    .0
+    let seeds = get_proposal_seeds(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        chain_id,
+        event_data,
+    );
+    let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    Pubkey::find_program_address(&seeds, program_id).0
+}
+
+/// Confirmed vote weight recorded by proposal votes, Relays evicted afterwards are still counted.
+/// Proposals voted before Relay weights only count votes
+pub fn get_recorded_weight(signers: &[Vote], confirmed_weight: u32) -> u32 {
+    let confirms = signers
+        .iter()
+        .filter(|vote| **vote == Vote::Confirm)
+        .count() as u32;
+    confirms.max(confirmed_weight)
+}
+
+/// Confirmed vote weight of proposal. Votes of Relays evicted from the round are not counted
+pub fn get_confirmed_weight(
+    signers: &[Vote],
+    relays: &[Pubkey],
+    weight: impl Fn(usize) -> u32,
+) -> u32 {
+    signers
+        .iter()
+        .zip(relays)
+        .enumerate()
+        .filter(|(_, (vote, relay))| **vote == Vote::Confirm && **relay != Pubkey::default())
+        .fold(0, |confirms: u32, (index, _)| {
+            confirms.saturating_add(weight(index))
+        })
+}
+
+/// Message signed off-chain by relay to confirm proposal
+pub fn get_vote_message(proposal: &Pubkey, event_data: &Hash) -> Hash {
+    hashv(&[br"vote", proposal.as_ref(), event_data.as_ref()])
+}
+
+/// Ed25519 program instruction verifying signatures of the same message
+pub fn ed25519_signatures_ix(
+    message: &[u8],
+    signatures: &[(Pubkey, [u8; ED25519_SIGNATURE_LEN])],
+) -> Instruction {
+    let data_start =
+        ED25519_SIGNATURE_OFFSETS_START + signatures.len() * ED25519_SIGNATURE_OFFSETS_LEN;
+    let message_offset = data_start + signatures.len() * (PUBKEY_BYTES + ED25519_SIGNATURE_LEN);
+
+    let mut offsets = vec![signatures.len() as u8, 0];
+    let mut data = Vec::with_capacity(message_offset - data_start + message.len());
+
+    for (pubkey, signature) in signatures {
+        let public_key_offset = data_start + data.len();
+        data.extend_from_slice(pubkey.as_ref());
+
+        let signature_offset = data_start + data.len();
+        data.extend_from_slice(signature);
+
+        for value in [
+            signature_offset as u16,
+            ED25519_CURRENT_INSTRUCTION,
+            public_key_offset as u16,
+            ED25519_CURRENT_INSTRUCTION,
+            message_offset as u16,
+            message.len() as u16,
+            ED25519_CURRENT_INSTRUCTION,
+        ] {
+            offsets.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    data.extend_from_slice(message);
+    offsets.extend_from_slice(&data);
+
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data: offsets,
+    }
+}
+
+/// Public keys whose signatures of the message were verified
+/// by Ed25519 program instructions preceding the current one
+pub fn get_ed25519_signers(
+    instructions_sysvar_info: &AccountInfo,
+    message: &[u8],
+) -> Result<Vec<Pubkey>, ProgramError> {
+    let current_index = load_current_index_checked(instructions_sysvar_info)?;
+
+    let mut signers = Vec::new();
+
+    for index in 0..current_index {
+        let ix = load_instruction_at_checked(index as usize, instructions_sysvar_info)?;
+        if ix.program_id != ed25519_program::id() {
+            continue;
+        }
+
+        let num_signatures = *ix
+            .data
+            .first()
+            .ok_or(ProgramError::InvalidInstructionData)? as usize;
+
+        for i in 0..num_signatures {
+            let start = ED25519_SIGNATURE_OFFSETS_START + i * ED25519_SIGNATURE_OFFSETS_LEN;
+            let offsets = ix
+                .data
+                .get(start..start + ED25519_SIGNATURE_OFFSETS_LEN)
+                .ok_or(ProgramError::InvalidInstructionData)?
+                .chunks_exact(2)
+                .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+                .collect::<Vec<_>>();
+
+            let (public_key_offset, message_offset, message_size) = (
+                offsets[2] as usize,
+                offsets[4] as usize,
+                offsets[5] as usize,
+            );
+
+            // Data referenced from other instructions is ignored
+            if offsets[1] != ED25519_CURRENT_INSTRUCTION
+                || offsets[3] != ED25519_CURRENT_INSTRUCTION
+                || offsets[6] != ED25519_CURRENT_INSTRUCTION
+            {
+                continue;
+            }
+
+            let signed_message = ix
+                .data
+                .get(message_offset..message_offset + message_size)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+
+            if signed_message != message {
+                continue;
+            }
+
+            let public_key = ix
+                .data
+                .get(public_key_offset..public_key_offset + PUBKEY_BYTES)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+
+            signers.push(
+                Pubkey::try_from(public_key).map_err(|_| ProgramError::InvalidInstructionData)?,
+            );
+        }
+    }
+
+    Ok(signers)
 }
 
 pub fn validate_programdata_account(
@@ -106,21 +309,22 @@ pub fn validate_proposal_account(
     event_timestamp: u32,
     event_transaction_lt: u64,
     event_configuration: &Pubkey,
+    chain_id: Option<u64>,
     event_data: &Hash,
     nonce: u8,
     proposal_account_info: &AccountInfo,
 ) -> Result<Pubkey, ProgramError> {
-    let (account, expected_nonce) = Pubkey::find_program_address(
-        &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data.to_bytes(),
-        ],
-        program_id,
+    let seeds = get_proposal_seeds(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        chain_id,
+        event_data.as_ref(),
     );
+    let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    let (account, expected_nonce) = Pubkey::find_program_address(&seeds, program_id);
 
     if account != *proposal_account_info.key {
         return Err(ProgramError::InvalidArgument);
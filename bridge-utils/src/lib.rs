@@ -1,3 +1,4 @@
+pub mod amount;
 pub mod errors;
 pub mod helper;
 pub mod state;
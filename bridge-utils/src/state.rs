@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
-use super::types::Vote;
+use super::types::{Vote, VoteReward};
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub struct Proposal {
@@ -18,22 +18,29 @@ pub struct Proposal {
     pub event: Vec<u8>,
     pub meta: Vec<u8>,
     pub signers: Vec<Vote>,
+    pub chain_id: Option<u64>,
+    pub vote_rewards: Vec<VoteReward>,
+    pub version: u8,
+    // Sum of vote weights of confirmed Relays
+    pub confirmed_weight: u32,
 }
 
 impl Proposal {
     pub fn pack_into_slice(&self, dst: &mut [u8]) {
         let data = self.try_to_vec().unwrap();
-        let (left, _) = dst.split_at_mut(data.len());
-        left.copy_from_slice(&data);
+        pack_with_discriminator(&self.account_kind, &data, dst);
     }
 
-    pub fn unpack_from_slice(mut src: &[u8]) -> Result<Self, ProgramError> {
+    pub fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let mut src = strip_discriminator(src);
         let unpacked = Self::deserialize(&mut src)?;
         Ok(unpacked)
     }
 }
 
-#[derive(Debug, Default, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[derive(
+    Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
+)]
 pub struct PDA {
     pub event_timestamp: u32,
     pub event_transaction_lt: u64,
@@ -59,10 +66,24 @@ pub enum AccountKind {
     RelayRound(u8),
     MultiVault(u8),
     TokenSettings(u8, u8), // Token settings nonce and mint/vault nonce
+    TokenDecimals(u8),
+    ProposalIndex(u8),
+    RelayEviction(u8),
+    FeeLedger(u8),
+    RecipientHook(u8),
+    PendingChange(u8),
+    Denylist(u8),
+    // Reserved for merkle relay rounds, keeps tags of the following kinds
+    MerkleRelayRound(u8),
+    RelayReward(u8),
+    BridgeStats(u8),
+    LpRegistry(u8),
+    Referral(u8),
+    ApprovalQueue(u8),
 }
 
 impl AccountKind {
-    pub fn to_value(&self) -> u8 {
+    pub const fn to_value(&self) -> u8 {
         match self {
             AccountKind::Settings(_, _) => 0,
             AccountKind::Deposit(_) => 1,
@@ -70,6 +91,68 @@ impl AccountKind {
             AccountKind::RelayRound(_) => 3,
             AccountKind::MultiVault(_) => 4,
             AccountKind::TokenSettings(_, _) => 5,
+            AccountKind::TokenDecimals(_) => 6,
+            AccountKind::ProposalIndex(_) => 7,
+            AccountKind::RelayEviction(_) => 8,
+            AccountKind::FeeLedger(_) => 9,
+            AccountKind::RecipientHook(_) => 10,
+            AccountKind::PendingChange(_) => 11,
+            AccountKind::Denylist(_) => 12,
+            AccountKind::MerkleRelayRound(_) => 13,
+            AccountKind::RelayReward(_) => 14,
+            AccountKind::BridgeStats(_) => 15,
+            AccountKind::LpRegistry(_) => 16,
+            AccountKind::Referral(_) => 17,
+            AccountKind::ApprovalQueue(_) => 18,
         }
     }
+
+    /// Type tag written at zero offset of packed accounts
+    pub const fn discriminator(&self) -> [u8; DISCRIMINATOR_LEN] {
+        let prefix = DISCRIMINATOR_PREFIX;
+        [
+            prefix[0],
+            prefix[1],
+            prefix[2],
+            prefix[3],
+            prefix[4],
+            prefix[5],
+            prefix[6],
+            self.to_value(),
+        ]
+    }
+}
+
+/// Length of type tag at zero offset of packed accounts
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+// Legacy untagged accounts start with `is_initialized` flag, so the prefix never matches them
+const DISCRIMINATOR_PREFIX: [u8; DISCRIMINATOR_LEN - 1] = *b"octusbr";
+
+/// Offset of account data following the type tag. Zero for legacy untagged accounts
+pub fn discriminator_offset(src: &[u8]) -> usize {
+    match src.len() >= DISCRIMINATOR_LEN && src.starts_with(&DISCRIMINATOR_PREFIX) {
+        true => DISCRIMINATOR_LEN,
+        false => 0,
+    }
+}
+
+/// Account data following the type tag
+pub fn strip_discriminator(src: &[u8]) -> &[u8] {
+    &src[discriminator_offset(src)..]
+}
+
+/// Write account data prefixed with its type tag.
+/// Legacy accounts too small for the tag keep untagged layout
+pub fn pack_with_discriminator(account_kind: &AccountKind, data: &[u8], dst: &mut [u8]) {
+    let dst = if dst.len() >= DISCRIMINATOR_LEN + data.len() {
+        let (tag, dst) = dst.split_at_mut(DISCRIMINATOR_LEN);
+        tag.copy_from_slice(&account_kind.discriminator());
+        dst
+    } else {
+        dst
+    };
+
+    let (left, _) = dst.split_at_mut(data.len());
+    left.copy_from_slice(data);
 }
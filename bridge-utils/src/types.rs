@@ -1,12 +1,20 @@
+use std::io::{self, Read, Write};
 use std::str::FromStr;
 use std::{cmp, fmt, mem};
 
+use base64::engine::general_purpose;
+use base64::Engine;
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 pub const RELAY_REPARATION: u64 = 20000;
 
+const USER_FRIENDLY_ADDRESS_LEN: usize = 36;
+const BOUNCEABLE_TAG: u8 = 0x11;
+const NON_BOUNCEABLE_TAG: u8 = 0x51;
+const TESTNET_FLAG: u8 = 0x80;
+
 #[derive(
     Debug, Copy, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
 )]
@@ -16,6 +24,16 @@ pub enum Vote {
     Reject,
 }
 
+/// Reward credited to a relay for a confirming vote
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
+)]
+pub enum VoteReward {
+    None,
+    Unclaimed,
+    Claimed,
+}
+
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
 )]
@@ -27,11 +45,105 @@ impl EverAddress {
     pub fn with_standart(workchain_id: i8, address: [u8; 32]) -> Self {
         EverAddress::AddrStd(MsgAddrStd::with_address(workchain_id, address))
     }
+
+    /// Parse packed base64 (either standard or url safe) user-friendly address
+    pub fn from_base64(string: &str) -> Result<(Self, UserFriendlyFlags), ParseEverAddressError> {
+        let string = string.replace('-', "+").replace('_', "/");
+        let data = general_purpose::STANDARD
+            .decode(string)
+            .map_err(|_| ParseEverAddressError::InvalidBase64)?;
+
+        if data.len() != USER_FRIENDLY_ADDRESS_LEN {
+            return Err(ParseEverAddressError::WrongSize);
+        }
+
+        let (data, crc) = data.split_at(USER_FRIENDLY_ADDRESS_LEN - 2);
+        if crc16(data).to_be_bytes() != crc {
+            return Err(ParseEverAddressError::InvalidChecksum);
+        }
+
+        let testnet = data[0] & TESTNET_FLAG != 0;
+        let bounceable = match data[0] & !TESTNET_FLAG {
+            BOUNCEABLE_TAG => true,
+            NON_BOUNCEABLE_TAG => false,
+            _ => return Err(ParseEverAddressError::InvalidFlags),
+        };
+
+        let workchain_id = data[1] as i8;
+        let address = <[u8; 32]>::try_from(&data[2..]).expect("slice with incorrect length");
+
+        Ok((
+            EverAddress::with_standart(workchain_id, address),
+            UserFriendlyFlags {
+                bounceable,
+                testnet,
+            },
+        ))
+    }
+
+    /// Pack address into base64 user-friendly format
+    pub fn to_base64(&self, flags: UserFriendlyFlags, url_safe: bool) -> String {
+        let EverAddress::AddrStd(addr) = self;
+
+        let mut tag = match flags.bounceable {
+            true => BOUNCEABLE_TAG,
+            false => NON_BOUNCEABLE_TAG,
+        };
+        if flags.testnet {
+            tag |= TESTNET_FLAG;
+        }
+
+        let mut data = Vec::with_capacity(USER_FRIENDLY_ADDRESS_LEN);
+        data.push(tag);
+        data.push(addr.workchain_id as u8);
+        data.extend_from_slice(&addr.address);
+        data.extend_from_slice(&crc16(&data).to_be_bytes());
+
+        match url_safe {
+            true => general_purpose::URL_SAFE.encode(data),
+            false => general_purpose::STANDARD.encode(data),
+        }
+    }
+}
+
+/// User-friendly address flags
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserFriendlyFlags {
+    pub bounceable: bool,
+    pub testnet: bool,
+}
+
+#[derive(Error, Debug, Serialize, Clone, PartialEq)]
+pub enum ParseEverAddressError {
+    #[error("Invalid base64 string")]
+    InvalidBase64,
+    #[error("Address is the wrong size")]
+    WrongSize,
+    #[error("Invalid address checksum")]
+    InvalidChecksum,
+    #[error("Invalid address flags")]
+    InvalidFlags,
+}
+
+/// CRC16/XMODEM used in user-friendly addresses
+fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, byte| {
+        (0..8).fold(crc ^ ((*byte as u16) << 8), |crc, _| match crc & 0x8000 {
+            0 => crc << 1,
+            _ => (crc << 1) ^ 0x1021,
+        })
+    })
 }
 
 impl FromStr for EverAddress {
     type Err = Box<dyn std::error::Error>;
     fn from_str(string: &str) -> Result<Self, Self::Err> {
+        // Addresses copied from wallets are in user-friendly format
+        if !string.contains(':') {
+            let (address, _) = EverAddress::from_base64(string)?;
+            return Ok(address);
+        }
+
         let parts: Vec<&str> = string.split(':').take(4).collect();
         let len = parts.len();
         if len != 2 {
@@ -179,3 +291,42 @@ pub enum ParseUInt256Error {
     #[error("Invalid HEX string")]
     Invalid,
 }
+
+/// String encoded with a single byte length prefix
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShortString(pub String);
+
+impl From<String> for ShortString {
+    fn from(value: String) -> Self {
+        ShortString(value)
+    }
+}
+
+impl From<ShortString> for String {
+    fn from(value: ShortString) -> Self {
+        value.0
+    }
+}
+
+impl BorshSerialize for ShortString {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let len = u8::try_from(self.0.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "String is too long"))?;
+
+        writer.write_all(&[len])?;
+        writer.write_all(self.0.as_bytes())
+    }
+}
+
+impl BorshDeserialize for ShortString {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let len = u8::deserialize_reader(reader)?;
+
+        let mut data = vec![0; len as usize];
+        reader.read_exact(&mut data)?;
+
+        String::from_utf8(data)
+            .map(ShortString)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Invalid UTF-8 string"))
+    }
+}
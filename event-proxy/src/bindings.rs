@@ -0,0 +1,382 @@
+use borsh::BorshSerialize;
+use bridge_derive::AccountMetas;
+use bridge_utils::types::{EverAddress, Vote};
+
+use solana_program::hash::hash;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
+
+use crate::*;
+
+pub fn get_programdata_address() -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_programdata_address(program_id)
+}
+
+pub fn get_settings_address() -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_associated_settings_address(program_id)
+}
+
+pub fn get_deposit_address(seed: u128) -> Pubkey {
+    let program_id = &id();
+    get_associated_deposit_address(program_id, seed)
+}
+
+pub fn get_authority_address(event_configuration: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_authority_address(program_id, event_configuration)
+}
+
+pub fn get_message_proposal_address(
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    target: Pubkey,
+    payload: Vec<u8>,
+) -> Pubkey {
+    let program_id = &id();
+
+    let event_data = hash(
+        &MessageProposalEvent { target, payload }
+            .try_to_vec()
+            .expect("pack"),
+    )
+    .to_bytes();
+
+    bridge_utils::helper::get_associated_proposal_address(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        None,
+        &event_data,
+    )
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct InitializeAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub initializer: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn initialize_ix(
+    funder_pubkey: Pubkey,
+    initializer_pubkey: Pubkey,
+    guardian: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = EventProxyInstruction::Initialize { guardian }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: InitializeAccounts {
+            funder: funder_pubkey,
+            initializer: initializer_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct SendMessageAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub deposit: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn send_message_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    message_seed: u128,
+    recipient: EverAddress,
+    payload: Vec<u8>,
+) -> Instruction {
+    let deposit_pubkey = get_deposit_address(message_seed);
+    let settings_pubkey = get_settings_address();
+
+    let data = EventProxyInstruction::SendMessage {
+        message_seed,
+        recipient,
+        payload,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: SendMessageAccounts {
+            funder: funder_pubkey,
+            author: author_pubkey,
+            deposit: deposit_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct MessageRequestAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub proposal: Pubkey,
+    pub rl_settings: Pubkey,
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn message_request_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    target: Pubkey,
+    payload: Vec<u8>,
+) -> Instruction {
+    let proposal_pubkey = get_message_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        target,
+        payload.clone(),
+    );
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let data = EventProxyInstruction::MessageRequest {
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        target,
+        payload,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: MessageRequestAccounts {
+            funder: funder_pubkey,
+            author: author_pubkey,
+            proposal: proposal_pubkey,
+            rl_settings: rl_settings_pubkey,
+            relay_round: relay_round_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+            clock_sysvar: sysvar::clock::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct VoteForMessageRequestAccounts {
+    #[account(signer, writable)]
+    pub voter: Pubkey,
+    #[account(writable)]
+    pub proposal: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+}
+
+pub fn vote_for_message_request_ix(
+    voter_pubkey: Pubkey,
+    proposal_pubkey: Pubkey,
+    round_number: u32,
+    vote: Vote,
+) -> Instruction {
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+
+    let data = EventProxyInstruction::VoteForMessageRequest { vote }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: VoteForMessageRequestAccounts {
+            voter: voter_pubkey,
+            proposal: proposal_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ExecuteMessageAccounts {
+    #[account(writable)]
+    pub proposal: Pubkey,
+    pub settings: Pubkey,
+    pub authority: Pubkey,
+    pub target_program: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+}
+
+pub fn execute_message_ix(
+    proposal_pubkey: Pubkey,
+    round_number: u32,
+    event_configuration: Pubkey,
+    target: Pubkey,
+    target_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let authority_pubkey = get_authority_address(&event_configuration);
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+
+    let data = EventProxyInstruction::ExecuteMessage
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = ExecuteMessageAccounts {
+        proposal: proposal_pubkey,
+        settings: settings_pubkey,
+        authority: authority_pubkey,
+        target_program: target,
+        relay_round: relay_round_pubkey,
+        rl_settings: rl_settings_pubkey,
+    }
+    .to_account_metas();
+
+    // Accounts required by the target program
+    accounts.extend(target_accounts);
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct EnableEmergencyAccounts {
+    #[account(signer, writable)]
+    pub guardian: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn enable_emergency_ix(guardian_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = EventProxyInstruction::EnableEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: EnableEmergencyAccounts {
+            guardian: guardian_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct EnableEmergencyByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn enable_emergency_by_owner_ix(owner_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = EventProxyInstruction::EnableEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: EnableEmergencyByOwnerAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct DisableEmergencyAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn disable_emergency_ix(owner_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = EventProxyInstruction::DisableEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: DisableEmergencyAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
@@ -0,0 +1,73 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::types::{EverAddress, Vote};
+
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum EventProxyInstruction {
+    /// Initialize Event Proxy
+    ///
+    /// # Account references
+    /// ...
+    Initialize {
+        // Guardian role
+        guardian: Pubkey,
+    },
+
+    /// Send message to EVER
+    ///
+    /// # Account references
+    /// ...
+    SendMessage {
+        // Message seed
+        message_seed: u128,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Message payload
+        payload: Vec<u8>,
+    },
+
+    /// Create request to deliver message from EVER
+    ///
+    /// # Account references
+    /// ...
+    MessageRequest {
+        // EVER->SOL event timestamp
+        event_timestamp: u32,
+        // EVER->SOL event transaction lt
+        event_transaction_lt: u64,
+        // EVER->SOL event configuration
+        event_configuration: Pubkey,
+        // Solana program to deliver message to
+        target: Pubkey,
+        // Message payload
+        payload: Vec<u8>,
+    },
+
+    /// Vote for message request
+    ///
+    /// # Account references
+    /// ...
+    VoteForMessageRequest {
+        // Vote type
+        vote: Vote,
+    },
+
+    /// Deliver confirmed message to the target program
+    ///
+    /// # Account references
+    /// ...
+    ExecuteMessage,
+
+    /// Enable emergency mode
+    ///
+    /// # Account references
+    /// ...
+    EnableEmergencyMode,
+
+    /// Disable emergency mode
+    ///
+    /// # Account references
+    /// ...
+    DisableEmergencyMode,
+}
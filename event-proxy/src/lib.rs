@@ -0,0 +1,20 @@
+mod instruction;
+mod processor;
+mod state;
+mod utils;
+
+pub use self::instruction::*;
+pub use self::processor::*;
+pub use self::state::*;
+pub use self::utils::*;
+
+#[cfg(feature = "bindings")]
+mod bindings;
+
+#[cfg(feature = "bindings")]
+pub use self::bindings::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+mod entrypoint;
+
+solana_program::declare_id!("F6ZxSyViXoJowYB5vb4xxSBMc2JYVgbstmdqF3zRaggq");
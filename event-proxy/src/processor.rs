@@ -0,0 +1,771 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::types::{EverAddress, Vote, RELAY_REPARATION};
+use round_loader::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+use solana_program::{bpf_loader_upgradeable, msg, system_instruction};
+
+use crate::*;
+
+pub struct Processor;
+impl Processor {
+    pub fn process(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        let instruction = EventProxyInstruction::try_from_slice(instruction_data)?;
+
+        match instruction {
+            EventProxyInstruction::Initialize { guardian } => {
+                msg!("Instruction: Initialize Event Proxy");
+                Self::process_initialize(program_id, accounts, guardian)?;
+            }
+            EventProxyInstruction::SendMessage {
+                message_seed,
+                recipient,
+                payload,
+            } => {
+                msg!("Instruction: Send Message");
+                Self::process_send_message(program_id, accounts, message_seed, recipient, payload)?;
+            }
+            EventProxyInstruction::MessageRequest {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                target,
+                payload,
+            } => {
+                msg!("Instruction: Message Request");
+                Self::process_message_request(
+                    program_id,
+                    accounts,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    target,
+                    payload,
+                )?;
+            }
+            EventProxyInstruction::VoteForMessageRequest { vote } => {
+                msg!("Instruction: Vote for Message Request");
+                Self::process_vote_for_message_request(program_id, accounts, vote)?;
+            }
+            EventProxyInstruction::ExecuteMessage => {
+                msg!("Instruction: Execute Message");
+                Self::process_execute_message(program_id, accounts)?;
+            }
+            EventProxyInstruction::EnableEmergencyMode => {
+                msg!("Instruction: Enable emergency mode");
+                Self::process_enable_emergency_mode(program_id, accounts)?;
+            }
+            EventProxyInstruction::DisableEmergencyMode => {
+                msg!("Instruction: Disable emergency mode");
+                Self::process_disable_emergency_mode(program_id, accounts)?;
+            }
+        };
+
+        Ok(())
+    }
+
+    fn process_initialize(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        guardian: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let initializer_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !initializer_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Initializer Account
+        let (programdata_pubkey, programdata_nonce) =
+            Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+
+        if programdata_pubkey != *programdata_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        bridge_utils::helper::validate_initializer_account(
+            initializer_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Create Settings Account
+        let (settings_pubkey, settings_nonce) =
+            Pubkey::find_program_address(&[br"settings"], program_id);
+        let settings_account_signer_seeds: &[&[_]] = &[br"settings", &[settings_nonce]];
+
+        if settings_pubkey != *settings_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                settings_account_info.key,
+                1.max(rent.minimum_balance(Settings::LEN)),
+                Settings::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[settings_account_signer_seeds],
+        )?;
+
+        // Init Settings Account
+        let settings_account_data = Settings {
+            is_initialized: true,
+            account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+            emergency: false,
+            guardian,
+        };
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_send_message(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        message_seed: u128,
+        recipient: EverAddress,
+        payload: Vec<u8>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let author_account_info = next_account_info(account_info_iter)?;
+        let deposit_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if payload.len() > MAX_PAYLOAD_LEN {
+            return Err(SolanaBridgeError::MessagePayloadLenLimit.into());
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.emergency {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Create Deposit Account
+        let (deposit_pubkey, deposit_nonce) =
+            Pubkey::find_program_address(&[br"deposit", &message_seed.to_le_bytes()], program_id);
+        let deposit_account_signer_seeds: &[&[_]] =
+            &[br"deposit", &message_seed.to_le_bytes(), &[deposit_nonce]];
+
+        if deposit_pubkey != *deposit_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                deposit_account_info.key,
+                1.max(rent.minimum_balance(MessageDeposit::LEN)),
+                MessageDeposit::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[deposit_account_signer_seeds],
+        )?;
+
+        // Init Deposit Account
+        let deposit_account_data = MessageDeposit {
+            is_initialized: true,
+            account_kind: AccountKind::Deposit(deposit_nonce),
+            author: *author_account_info.key,
+            event: MessageDepositEventWithLen::new(*author_account_info.key, recipient, payload),
+            meta: MessageDepositMetaWithLen::new(
+                message_seed,
+                *funder_account_info.key,
+                Clock::get()?.slot,
+            ),
+        };
+
+        let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
+            .to_bytes()
+            .to_vec();
+
+        solana_program::log::sol_log_data(&[&SendMessageEvent {
+            account: deposit_pubkey,
+            recipient,
+            seed: message_seed,
+            event_data,
+        }
+        .try_to_vec()?]);
+
+        MessageDeposit::pack(
+            deposit_account_data,
+            &mut deposit_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_message_request(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        event_timestamp: u32,
+        event_transaction_lt: u64,
+        event_configuration: Pubkey,
+        target: Pubkey,
+        payload: Vec<u8>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let author_account_info = next_account_info(account_info_iter)?;
+        let proposal_account_info = next_account_info(account_info_iter)?;
+        let rl_settings_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if payload.len() > MAX_PAYLOAD_LEN {
+            return Err(SolanaBridgeError::MessagePayloadLenLimit.into());
+        }
+
+        // Validate Round Loader Settings Account
+        let rl_settings_account_data =
+            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+        let (rl_settings_nonce, _) = rl_settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            &round_loader::id(),
+            rl_settings_nonce,
+            rl_settings_account_info,
+        )?;
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let round_number = relay_round_account_data.round_number;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
+            return Err(SolanaBridgeError::RelayRoundExpired.into());
+        }
+
+        let required_votes = round_loader::get_round_quorum(
+            relay_round_account_data.total_weight() as usize,
+            &relay_round_account_data,
+            &rl_settings_account_data,
+        );
+
+        // Create Proposal Account
+        let event = MessageProposalEventWithLen::new(target, payload);
+
+        let event_data = hash(&event.data.try_to_vec()?);
+
+        let mut proposal_seeds = bridge_utils::helper::get_proposal_seeds(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            None,
+            event_data.as_ref(),
+        );
+
+        let (proposal_pubkey, proposal_nonce) = Pubkey::find_program_address(
+            &proposal_seeds.iter().map(Vec::as_slice).collect::<Vec<_>>(),
+            program_id,
+        );
+
+        proposal_seeds.push(vec![proposal_nonce]);
+        let proposal_account_signer_seeds =
+            proposal_seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+        if proposal_pubkey != *proposal_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if proposal_account_info.lamports() == 0 {
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    proposal_account_info.key,
+                    1.max(rent.minimum_balance(MessageProposal::LEN)),
+                    MessageProposal::LEN as u64,
+                    program_id,
+                ),
+                accounts,
+                &[&proposal_account_signer_seeds],
+            )?;
+
+            let proposal_account_data = MessageProposal {
+                is_initialized: true,
+                account_kind: AccountKind::Proposal(proposal_nonce, None),
+                author: *author_account_info.key,
+                round_number,
+                required_votes,
+                pda: PDA {
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                },
+                event,
+                meta: MessageProposalMetaWithLen::new(*funder_account_info.key),
+                signers: vec![Vote::None; relay_round_account_data.relays.len()],
+                chain_id: None,
+            };
+
+            MessageProposal::pack(
+                proposal_account_data,
+                &mut proposal_account_info.data.borrow_mut(),
+            )?;
+
+            let event_data = event_data.to_bytes().to_vec();
+
+            solana_program::log::sol_log_data(&[&MessageRequestEvent {
+                account: proposal_pubkey,
+                target,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                event_data,
+            }
+            .try_to_vec()?]);
+
+            // Send voting reparation for Relay to proposal account
+            invoke(
+                &system_instruction::transfer(
+                    funder_account_info.key,
+                    proposal_account_info.key,
+                    RELAY_REPARATION * relay_round_account_data.relays.len() as u64,
+                ),
+                accounts,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_vote_for_message_request(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        vote: Vote,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let relay_account_info = next_account_info(account_info_iter)?;
+        let proposal_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let rl_settings_account_info = next_account_info(account_info_iter)?;
+
+        if !relay_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate vote
+        if vote == Vote::None {
+            return Err(SolanaBridgeError::InvalidVote.into());
+        }
+
+        // Validate Proposal Account
+        let mut proposal_account_data =
+            MessageProposal::unpack(&proposal_account_info.data.borrow())?;
+
+        let round_number = proposal_account_data.round_number;
+        let event_timestamp = proposal_account_data.pda.event_timestamp;
+        let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+        let event_configuration = proposal_account_data.pda.event_configuration;
+        let event_data = hash(&proposal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = proposal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            proposal_account_data.chain_id,
+            &event_data,
+            nonce,
+            proposal_account_info,
+        )?;
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        // Validate Round Loader Settings Account
+        let rl_settings_account_data =
+            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+        let (rl_settings_nonce, _) = rl_settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            &round_loader::id(),
+            rl_settings_nonce,
+            rl_settings_account_info,
+        )?;
+
+        // Previous round can vote only within handover period
+        round_loader::validate_relay_round_for_voting(
+            &rl_settings_account_data,
+            &relay_round_account_data,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        // Vote for message request
+        let index = relay_round_account_data
+            .relays
+            .iter()
+            .position(|pubkey| pubkey == relay_account_info.key)
+            .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+        if proposal_account_data.signers[index] == Vote::None {
+            proposal_account_data.signers[index] = vote;
+
+            MessageProposal::pack(
+                proposal_account_data,
+                &mut proposal_account_info.data.borrow_mut(),
+            )?;
+
+            // Get back voting reparation to Relay
+            let proposal_starting_lamports = proposal_account_info.lamports();
+            **proposal_account_info.lamports.borrow_mut() = proposal_starting_lamports
+                .checked_sub(RELAY_REPARATION)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            let relay_starting_lamports = relay_account_info.lamports();
+            **relay_account_info.lamports.borrow_mut() = relay_starting_lamports
+                .checked_add(RELAY_REPARATION)
+                .ok_or(SolanaBridgeError::Overflow)?;
+        }
+
+        Ok(())
+    }
+
+    fn process_execute_message(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let proposal_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let target_program_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let rl_settings_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.emergency {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Proposal Account
+        let mut proposal_account_data =
+            MessageProposal::unpack(&proposal_account_info.data.borrow())?;
+
+        let round_number = proposal_account_data.round_number;
+        let event_timestamp = proposal_account_data.pda.event_timestamp;
+        let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+        let event_configuration = proposal_account_data.pda.event_configuration;
+        let event_data = hash(&proposal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = proposal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            proposal_account_data.chain_id,
+            &event_data,
+            nonce,
+            proposal_account_info,
+        )?;
+
+        if proposal_account_data.meta.data.status != MessageStatus::New {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        // Validate Round Loader Settings Account
+        let rl_settings_account_data =
+            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+        let (rl_settings_nonce, _) = rl_settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            &round_loader::id(),
+            rl_settings_nonce,
+            rl_settings_account_info,
+        )?;
+
+        // Do we have enough signers. Votes of evicted Relays are not counted
+        let sig_count = relay_round_account_data.confirmed_weight(&proposal_account_data.signers);
+        let required_votes = round_loader::get_round_required_votes(
+            proposal_account_data.required_votes,
+            &relay_round_account_data,
+            &rl_settings_account_data,
+        );
+
+        if sig_count < required_votes {
+            return Err(SolanaBridgeError::MessageNotConfirmed.into());
+        }
+
+        // Validate Target Program
+        if *target_program_info.key != proposal_account_data.event.data.target {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Authority Account
+        let authority_nonce =
+            validate_authority_account(program_id, &event_configuration, authority_account_info)?;
+
+        // Message is delivered only once
+        proposal_account_data.meta.data.status = MessageStatus::Processed;
+
+        let payload = proposal_account_data.event.data.payload.clone();
+
+        MessageProposal::pack(
+            proposal_account_data,
+            &mut proposal_account_info.data.borrow_mut(),
+        )?;
+
+        // Authority goes first, the rest accounts are passed to the target program as is
+        let mut target_accounts =
+            vec![AccountMeta::new_readonly(*authority_account_info.key, true)];
+
+        target_accounts.extend(account_info_iter.map(|account_info| AccountMeta {
+            pubkey: *account_info.key,
+            is_signer: account_info.is_signer,
+            is_writable: account_info.is_writable,
+        }));
+
+        invoke_signed(
+            &Instruction {
+                program_id: *target_program_info.key,
+                accounts: target_accounts,
+                data: payload,
+            },
+            accounts,
+            &[&[
+                br"authority",
+                &event_configuration.to_bytes(),
+                &[authority_nonce],
+            ]],
+        )?;
+
+        Ok(())
+    }
+
+    fn process_enable_emergency_mode(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Guardian Account
+        if *authority_account_info.key != settings_account_data.guardian {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+        }
+
+        settings_account_data.emergency = true;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_disable_emergency_mode(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        settings_account_data.emergency = false;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_derive::BridgePack;
+use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::types::{EverAddress, Vote};
+use serde::{Deserialize, Serialize};
+
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::{IsInitialized, Pack, Sealed};
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+
+pub const MAX_PAYLOAD_LEN: usize = 500;
+
+const MESSAGE_DEPOSIT_EVENT_LEN: usize = PUBKEY_BYTES   // solana sender address
+    + 1 + 1 + PUBKEY_BYTES                              // ever recipient address
+;
+
+const MESSAGE_DEPOSIT_META_LEN: usize = 16  // seed
+    + PUBKEY_BYTES                          // funder
+    + 8                                     // slot
+;
+
+const MESSAGE_PROPOSAL_EVENT_LEN: usize = PUBKEY_BYTES; // solana target program
+
+const MESSAGE_PROPOSAL_META_LEN: usize = 1  // status
+    + PUBKEY_BYTES                          // funder
+;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 500)]
+pub struct Settings {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub emergency: bool,
+    pub guardian: Pubkey,
+}
+
+impl Sealed for Settings {}
+
+impl IsInitialized for Settings {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 1000)]
+pub struct MessageDeposit {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub event: MessageDepositEventWithLen,
+    pub meta: MessageDepositMetaWithLen,
+}
+
+impl Sealed for MessageDeposit {}
+
+impl IsInitialized for MessageDeposit {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MessageDepositEvent {
+    pub sender: Pubkey,
+    pub recipient: EverAddress,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MessageDepositEventWithLen {
+    pub len: u32,
+    pub data: MessageDepositEvent,
+}
+
+impl MessageDepositEventWithLen {
+    pub fn new(sender: Pubkey, recipient: EverAddress, payload: Vec<u8>) -> Self {
+        Self {
+            len: MESSAGE_DEPOSIT_EVENT_LEN as u32 + 4 + (payload.len() as u32),
+            data: MessageDepositEvent {
+                sender,
+                recipient,
+                payload,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MessageDepositMeta {
+    pub seed: u128,
+    pub funder: Pubkey,
+    // Slot of the transaction that created the message
+    pub slot: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MessageDepositMetaWithLen {
+    pub len: u32,
+    pub data: MessageDepositMeta,
+}
+
+impl MessageDepositMetaWithLen {
+    pub fn new(seed: u128, funder: Pubkey, slot: u64) -> Self {
+        Self {
+            len: MESSAGE_DEPOSIT_META_LEN as u32,
+            data: MessageDepositMeta { seed, funder, slot },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 1000)]
+pub struct MessageProposal {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: MessageProposalEventWithLen,
+    pub meta: MessageProposalMetaWithLen,
+    pub signers: Vec<Vote>,
+    pub chain_id: Option<u64>,
+}
+
+impl Sealed for MessageProposal {}
+
+impl IsInitialized for MessageProposal {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MessageProposalEvent {
+    pub target: Pubkey,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MessageProposalEventWithLen {
+    pub len: u32,
+    pub data: MessageProposalEvent,
+}
+
+impl MessageProposalEventWithLen {
+    pub fn new(target: Pubkey, payload: Vec<u8>) -> Self {
+        Self {
+            len: MESSAGE_PROPOSAL_EVENT_LEN as u32 + 4 + (payload.len() as u32),
+            data: MessageProposalEvent { target, payload },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MessageProposalMeta {
+    pub status: MessageStatus,
+    pub funder: Pubkey,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct MessageProposalMetaWithLen {
+    pub len: u32,
+    pub data: MessageProposalMeta,
+}
+
+impl MessageProposalMetaWithLen {
+    pub fn new(funder: Pubkey) -> Self {
+        Self {
+            len: MESSAGE_PROPOSAL_META_LEN as u32,
+            data: MessageProposalMeta {
+                status: MessageStatus::New,
+                funder,
+            },
+        }
+    }
+}
+
+#[derive(
+    Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Eq, PartialEq,
+)]
+pub enum MessageStatus {
+    New,
+    Processed,
+}
+
+// Events
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct SendMessageEvent {
+    pub account: Pubkey,
+    pub recipient: EverAddress,
+    pub seed: u128,
+    pub event_data: Vec<u8>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct MessageRequestEvent {
+    pub account: Pubkey,
+    pub target: Pubkey,
+    pub event_timestamp: u32,
+    pub event_transaction_lt: u64,
+    pub event_configuration: Pubkey,
+    pub event_data: Vec<u8>,
+}
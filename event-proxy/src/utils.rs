@@ -0,0 +1,36 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub fn get_associated_deposit_address(program_id: &Pubkey, seed: u128) -> Pubkey {
+    Pubkey::find_program_address(&[br"deposit", &seed.to_le_bytes()], program_id).0
+}
+
+/// Authority signing delivered messages. Target programs check it to trust the EVER event configuration
+pub fn get_associated_authority_address(
+    program_id: &Pubkey,
+    event_configuration: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"authority", &event_configuration.to_bytes()],
+        program_id,
+    )
+    .0
+}
+
+pub fn validate_authority_account(
+    program_id: &Pubkey,
+    event_configuration: &Pubkey,
+    account_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (account, nonce) = Pubkey::find_program_address(
+        &[br"authority", &event_configuration.to_bytes()],
+        program_id,
+    );
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(nonce)
+}
@@ -0,0 +1,434 @@
+#![cfg(feature = "test-bpf")]
+
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::{EverAddress, Vote};
+
+use solana_program::account_info::AccountInfo;
+use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::AccountMeta;
+use solana_program::program_error::ProgramError;
+use solana_program::rent::Rent;
+use solana_program::{bpf_loader_upgradeable, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::{processor, tokio, ProgramTest};
+use solana_sdk::account::{Account, ReadableAccount};
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+use event_proxy::*;
+
+// Target program storing delivered payload into the given account
+fn process_target(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let authority_account_info = &accounts[0];
+    let storage_account_info = &accounts[1];
+
+    if !authority_account_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    storage_account_info.data.borrow_mut()[..data.len()].copy_from_slice(data);
+
+    Ok(())
+}
+
+fn add_settings_account(program_test: &mut ProgramTest, guardian: Pubkey, emergency: bool) {
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &event_proxy::id());
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency,
+        guardian,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        get_settings_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: event_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+#[tokio::test]
+async fn test_init_settings() {
+    let mut program_test = ProgramTest::new(
+        "event_proxy",
+        event_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let initializer = Keypair::new();
+
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[event_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(initializer.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: event_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let guardian = Pubkey::new_unique();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[initialize_ix(
+            funder.pubkey(),
+            initializer.pubkey(),
+            guardian,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Settings Account
+    let settings_info = banks_client
+        .get_account(get_settings_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.is_initialized, true);
+    assert_eq!(settings_data.emergency, false);
+    assert_eq!(settings_data.guardian, guardian);
+
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &event_proxy::id());
+    assert_eq!(
+        settings_data.account_kind,
+        AccountKind::Settings(settings_nonce, programdata_nonce)
+    );
+}
+
+#[tokio::test]
+async fn test_send_message() {
+    let mut program_test = ProgramTest::new(
+        "event_proxy",
+        event_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    add_settings_account(&mut program_test, Pubkey::new_unique(), false);
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let message_seed = uuid::Uuid::new_v4().as_u128();
+    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let payload = vec![1, 2, 3, 4];
+
+    let mut transaction = Transaction::new_with_payer(
+        &[send_message_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            message_seed,
+            recipient,
+            payload.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Deposit Account
+    let deposit_address = get_deposit_address(message_seed);
+    let deposit_info = banks_client
+        .get_account(deposit_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let deposit_data = MessageDeposit::unpack(deposit_info.data()).expect("deposit unpack");
+
+    assert_eq!(deposit_data.is_initialized, true);
+    assert_eq!(deposit_data.author, author.pubkey());
+    assert_eq!(deposit_data.event.data.sender, author.pubkey());
+    assert_eq!(deposit_data.event.data.recipient, recipient);
+    assert_eq!(deposit_data.event.data.payload, payload);
+    assert_eq!(deposit_data.meta.data.seed, message_seed);
+    assert_eq!(deposit_data.meta.data.funder, funder.pubkey());
+    assert_ne!(deposit_data.meta.data.slot, 0);
+
+    // Too long payload is rejected
+    let mut transaction = Transaction::new_with_payer(
+        &[send_message_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            uuid::Uuid::new_v4().as_u128(),
+            recipient,
+            vec![0; MAX_PAYLOAD_LEN + 1],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_deliver_message() {
+    let mut program_test = ProgramTest::new(
+        "event_proxy",
+        event_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    let target = Pubkey::new_unique();
+    program_test.add_program("target", target, processor!(process_target));
+
+    // Setup environment
+    add_settings_account(&mut program_test, Pubkey::new_unique(), false);
+
+    // Add Round Loader Settings Account
+    let round_number = 5;
+
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let rl_settings_account_data = round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    };
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    program_test.add_account(
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id()),
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Accounts
+    let relays = vec![Keypair::new(), Keypair::new(), Keypair::new()];
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        round_number,
+        round_end: 1209600 + chrono::Utc::now().timestamp() as u32,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number),
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Target Storage Account
+    let payload = vec![7, 7, 7];
+
+    let storage = Pubkey::new_unique();
+    program_test.add_account(
+        storage,
+        Account {
+            lamports: Rent::default().minimum_balance(payload.len()),
+            data: vec![0; payload.len()],
+            owner: target,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[message_request_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            target,
+            payload.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let proposal_address = get_message_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        target,
+        payload.clone(),
+    );
+
+    let execute_ix = execute_message_ix(
+        proposal_address,
+        round_number,
+        event_configuration,
+        target,
+        vec![AccountMeta::new(storage, false)],
+    );
+
+    // Message can't be delivered without relay votes
+    let mut transaction =
+        Transaction::new_with_payer(&[execute_ix.clone()], Some(&author.pubkey()));
+    transaction.sign(&[&author], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Vote for message request
+    for relay in &relays {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_message_request_ix(
+                relay.pubkey(),
+                proposal_address,
+                round_number,
+                Vote::Confirm,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, relay], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Deliver message
+    let mut transaction = Transaction::new_with_payer(&[execute_ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Proposal Account
+    let proposal_info = banks_client
+        .get_account(proposal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = MessageProposal::unpack(proposal_info.data()).expect("proposal unpack");
+
+    assert_eq!(proposal_data.event.data.target, target);
+    assert_eq!(proposal_data.event.data.payload, payload);
+    assert_eq!(proposal_data.meta.data.status, MessageStatus::Processed);
+
+    // Check Target Storage Account
+    let storage_info = banks_client
+        .get_account(storage)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(storage_info.data(), payload.as_slice());
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use borsh::BorshDeserialize;
+use libfuzzer_sys::fuzz_target;
+
+use round_loader::RoundLoaderInstruction;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = RoundLoaderInstruction::try_from_slice(data);
+});
@@ -0,0 +1,25 @@
+#![no_main]
+
+use borsh::BorshDeserialize;
+use libfuzzer_sys::fuzz_target;
+use solana_program::program_pack::{IsInitialized, Pack};
+
+use round_loader::*;
+
+fn unpack<T: Pack + IsInitialized>(data: &[u8]) {
+    // Accounts are unpacked only with the exact layout length
+    let mut account_data = data.to_vec();
+    account_data.resize(T::LEN, 0);
+
+    let _ = T::unpack(&account_data);
+    let _ = T::unpack_from_slice(data);
+}
+
+fuzz_target!(|data: &[u8]| {
+    unpack::<Settings>(data);
+    unpack::<RelayRound>(data);
+    unpack::<RelayRoundProposal>(data);
+    unpack::<RelayEviction>(data);
+
+    let _ = RelayRoundProposalMetaWithLen::deserialize(&mut &data[..]);
+});
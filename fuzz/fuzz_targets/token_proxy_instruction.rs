@@ -0,0 +1,10 @@
+#![no_main]
+
+use borsh::BorshDeserialize;
+use libfuzzer_sys::fuzz_target;
+
+use token_proxy::TokenProxyInstruction;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = TokenProxyInstruction::try_from_slice(data);
+});
@@ -0,0 +1,42 @@
+#![no_main]
+
+use borsh::BorshDeserialize;
+use bridge_utils::state::Proposal;
+use libfuzzer_sys::fuzz_target;
+use solana_program::program_pack::{IsInitialized, Pack};
+
+use token_proxy::*;
+
+fn unpack<T: Pack + IsInitialized>(data: &[u8]) {
+    // Accounts are unpacked only with the exact layout length
+    let mut account_data = data.to_vec();
+    account_data.resize(T::LEN, 0);
+
+    let _ = T::unpack(&account_data);
+    let _ = T::unpack_from_slice(data);
+}
+
+fuzz_target!(|data: &[u8]| {
+    unpack::<Settings>(data);
+    unpack::<MultiVault>(data);
+    unpack::<TokenSettings>(data);
+    unpack::<TokenDecimals>(data);
+    unpack::<ProposalIndex>(data);
+    unpack::<FeeLedger>(data);
+    unpack::<RecipientHook>(data);
+    unpack::<PendingChange>(data);
+    unpack::<Denylist>(data);
+    unpack::<DepositMultiTokenSol>(data);
+    unpack::<DepositMultiTokenEver>(data);
+    unpack::<WithdrawalMultiTokenEver>(data);
+    unpack::<WithdrawalMultiTokenSol>(data);
+    unpack::<WithdrawalMultiTokenEverToMany>(data);
+
+    // Common layouts of deposits and withdrawals
+    let _ = Deposit::deserialize(&mut &data[..]);
+    let _ = Proposal::deserialize(&mut &data[..]);
+    let _ = DepositTokenMeta::deserialize(&mut &data[..]);
+    let _ = WithdrawalTokenMeta::deserialize(&mut &data[..]);
+    let _ = DepositTokenMetaWithLen::deserialize(&mut &data[..]);
+    let _ = WithdrawalTokenMetaWithLen::deserialize(&mut &data[..]);
+});
@@ -6,6 +6,15 @@ use solana_program::{system_program, sysvar};
 
 use crate::*;
 
+pub fn get_wrap_address(deposit_seed: u128) -> Pubkey {
+    Pubkey::find_program_address(&[br"wrap", &deposit_seed.to_le_bytes()], &id()).0
+}
+
+pub fn get_unwrap_address(withdrawal_pubkey: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"unwrap", &withdrawal_pubkey.to_bytes()], &id()).0
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn deposit_ix(
     funder_pubkey: Pubkey,
     author_pubkey: Pubkey,
@@ -25,6 +34,8 @@ pub fn deposit_ix(
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
 
     let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
 
@@ -56,7 +67,164 @@ pub fn deposit_ix(
             AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(token_proxy::id(), false),
             AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn wrap_and_deposit_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    deposit_seed: u128,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    reference: Option<[u8; 32]>,
+) -> Instruction {
+    let mint_pubkey = spl_token::native_mint::id();
+
+    let wrap_pubkey = get_wrap_address(deposit_seed);
+
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multivault_pubkey = token_proxy::get_multivault_address();
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+
+    let data = NativeProxyInstruction::WrapAndDeposit {
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(wrap_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(token_proxy::id(), false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
         ],
         data,
     }
 }
+
+pub fn withdraw_sol_ix(
+    funder_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
+) -> Instruction {
+    let mint_pubkey = spl_token::native_mint::id();
+
+    let unwrap_pubkey = get_unwrap_address(&withdrawal_pubkey);
+
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let settings_pubkey = token_proxy::get_settings_address();
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = token_proxy::get_denylist_address();
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let relay_round_pubkey = token_proxy::get_relay_round_address(round_number);
+    let rl_settings_pubkey = token_proxy::get_rl_settings_address();
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+
+    let data = NativeProxyInstruction::WithdrawSol
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(recipient_pubkey, true),
+            AccountMeta::new(unwrap_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(token_proxy::id(), false),
+            AccountMeta::new(proposal_index_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new_readonly(recipient_hook_pubkey, false),
+            AccountMeta::new_readonly(denylist_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_v2_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    deposit_seed: u128,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    reference: Option<[u8; 32]>,
+) -> Instruction {
+    let mut ix = deposit_ix(
+        funder_pubkey,
+        author_pubkey,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        vec![],
+    );
+
+    ix.data = NativeProxyInstruction::DepositV2 {
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    ix
+}
@@ -17,4 +17,84 @@ pub enum NativeProxyInstruction {
         // Random payload to transfer to ever
         payload: Vec<u8>,
     },
+    /// Wrap lamports into a temporary wSOL account, deposit them and close the account
+    ///
+    /// # Account references
+    ///   0. [WRITE, SIGNER] Funder account
+    ///   1. [WRITE, SIGNER] Author account
+    ///   2. [WRITE] Temporary wSOL account
+    ///   3. [WRITE] Vault account
+    ///   4. [WRITE] Deposit account
+    ///   5. [WRITE] Mint account
+    ///   6. [WRITE] Multivault account
+    ///   7. [WRITE] Token settings account
+    ///   8. [] Settings account
+    ///   9. [] System program
+    ///   10. [] Token program
+    ///   11. [] Rent sysvar
+    ///   12. [] Token proxy program
+    ///   13. [] Instructions sysvar
+    ///   14. [WRITE] Fee ledger account
+    ///   15. [WRITE] Bridge stats account
+    WrapAndDeposit {
+        // Deposit seed
+        deposit_seed: u128,
+        // Deposit amount
+        amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Off-chain reference to match the deposit with ledger entries
+        reference: Option<[u8; 32]>,
+    },
+    /// Withdraw wSOL into a temporary account and pay it out to the recipient as lamports
+    ///
+    /// # Account references
+    ///   0. [WRITE, SIGNER] Funder account
+    ///   1. [WRITE, SIGNER] Recipient account
+    ///   2. [WRITE] Temporary wSOL account
+    ///   3. [WRITE] Withdrawal account
+    ///   4. [WRITE] Vault account
+    ///   5. [WRITE] Token settings account
+    ///   6. [] Mint account
+    ///   7. [] Settings account
+    ///   8. [] System program
+    ///   9. [] Token program
+    ///   10. [] Rent sysvar
+    ///   11. [] Clock sysvar
+    ///   12. [] Token proxy program
+    ///   13. [WRITE] Proposal index account
+    ///   14. [] Relay round account
+    ///   15. [] Round loader settings account
+    ///   16. [WRITE] Fee ledger account
+    ///   17. [] Recipient hook account
+    ///   18. [] Denylist account
+    ///   19. [WRITE] Bridge stats account
+    ///   20. [WRITE] Approval queue account
+    WithdrawSol,
+    /// Deposit carrying off-chain reference to match it with ledger entries
+    ///
+    /// # Account references
+    /// ...
+    DepositV2 {
+        // Deposit seed
+        deposit_seed: u128,
+        // Deposit amount
+        amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Off-chain reference to match the deposit with ledger entries
+        reference: Option<[u8; 32]>,
+    },
 }
@@ -3,9 +3,12 @@ use borsh::BorshDeserialize;
 use bridge_utils::types::{EverAddress, UInt256};
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
-use solana_program::program::invoke;
+use solana_program::program::{invoke, invoke_signed};
 use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
 use solana_program::{msg, system_instruction};
 
 use crate::*;
@@ -41,6 +44,55 @@ impl Processor {
                     value,
                     expected_evers,
                     payload,
+                    None,
+                )?;
+            }
+            NativeProxyInstruction::WrapAndDeposit {
+                deposit_seed,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                reference,
+            } => {
+                msg!("Instruction: Wrapping SOL into temporary account");
+                Self::process_wrap_and_deposit(
+                    program_id,
+                    accounts,
+                    deposit_seed,
+                    amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    reference,
+                )?;
+            }
+            NativeProxyInstruction::WithdrawSol => {
+                msg!("Instruction: Withdraw SOL");
+                Self::process_withdraw_sol(program_id, accounts)?;
+            }
+            NativeProxyInstruction::DepositV2 {
+                deposit_seed,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                reference,
+            } => {
+                msg!("Instruction: Wrapping SOL V2");
+                Self::process_deposit(
+                    program_id,
+                    accounts,
+                    deposit_seed,
+                    amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    reference,
                 )?;
             }
         };
@@ -48,6 +100,7 @@ impl Processor {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn process_deposit(
         _program_id: &Pubkey,
         accounts: &[AccountInfo],
@@ -57,6 +110,7 @@ impl Processor {
         value: u64,
         expected_evers: UInt256,
         payload: Vec<u8>,
+        reference: Option<[u8; 32]>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
@@ -107,7 +161,7 @@ impl Processor {
         let symbol = WSOL_SYMBOL.to_string();
 
         invoke(
-            &token_proxy::deposit_multi_token_sol_ix(
+            &token_proxy::deposit_multi_token_sol_v2_ix(
                 *funder_account_info.key,
                 *creator_account_info.key,
                 *creator_token_account_info.key,
@@ -119,11 +173,241 @@ impl Processor {
                 recipient,
                 value,
                 expected_evers,
-                payload,
+                Some(payload),
+                reference,
             ),
             accounts,
         )?;
 
         Ok(())
     }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_wrap_and_deposit(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        deposit_seed: u128,
+        amount: u64,
+        recipient: EverAddress,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+        reference: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let creator_account_info = next_account_info(account_info_iter)?;
+        let wrap_account_info = next_account_info(account_info_iter)?;
+        let _vault_account_info = next_account_info(account_info_iter)?;
+        let _deposit_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let _multi_vault_account_info = next_account_info(account_info_iter)?;
+        let _token_settings_account_info = next_account_info(account_info_iter)?;
+        let _settings_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+        let _token_proxy_program_info = next_account_info(account_info_iter)?;
+        let _instructions_sysvar_info = next_account_info(account_info_iter)?;
+        let _fee_ledger_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+
+        if *mint_account_info.key != spl_token::native_mint::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (wrap_pubkey, wrap_nonce) =
+            Pubkey::find_program_address(&[br"wrap", &deposit_seed.to_le_bytes()], program_id);
+        let wrap_account_signer_seeds: &[&[_]] =
+            &[br"wrap", &deposit_seed.to_le_bytes(), &[wrap_nonce]];
+
+        if wrap_pubkey != *wrap_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Create temporary Token Account
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                wrap_account_info.key,
+                rent.minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::id(),
+            ),
+            accounts,
+            &[wrap_account_signer_seeds],
+        )?;
+
+        invoke(
+            &spl_token::instruction::initialize_account3(
+                &spl_token::id(),
+                wrap_account_info.key,
+                &spl_token::native_mint::id(),
+                creator_account_info.key,
+            )?,
+            accounts,
+        )?;
+
+        invoke(
+            &system_instruction::transfer(creator_account_info.key, &wrap_pubkey, amount),
+            accounts,
+        )?;
+
+        invoke(
+            &spl_token::instruction::sync_native(&spl_token::id(), &wrap_pubkey)?,
+            accounts,
+        )?;
+
+        let name = WSOL_NAME.to_string();
+        let symbol = WSOL_SYMBOL.to_string();
+
+        invoke(
+            &token_proxy::deposit_multi_token_sol_v2_ix(
+                *funder_account_info.key,
+                *creator_account_info.key,
+                wrap_pubkey,
+                spl_token::native_mint::id(),
+                deposit_seed,
+                name,
+                symbol,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                Some(payload),
+                reference,
+            ),
+            accounts,
+        )?;
+
+        // Return rent to funder
+        invoke(
+            &spl_token::instruction::close_account(
+                &spl_token::id(),
+                &wrap_pubkey,
+                funder_account_info.key,
+                creator_account_info.key,
+                &[],
+            )?,
+            accounts,
+        )?;
+
+        Ok(())
+    }
+    fn process_withdraw_sol(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let recipient_account_info = next_account_info(account_info_iter)?;
+        let unwrap_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let _vault_account_info = next_account_info(account_info_iter)?;
+        let _token_settings_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let _settings_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+        let _clock_info = next_account_info(account_info_iter)?;
+        let _token_proxy_program_info = next_account_info(account_info_iter)?;
+        let proposal_index_account_info = next_account_info(account_info_iter)?;
+        let _relay_round_account_info = next_account_info(account_info_iter)?;
+        let _rl_settings_account_info = next_account_info(account_info_iter)?;
+        let _fee_ledger_account_info = next_account_info(account_info_iter)?;
+        let _recipient_hook_account_info = next_account_info(account_info_iter)?;
+        let _denylist_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        let _approval_queue_account_info = next_account_info(account_info_iter)?;
+
+        if !recipient_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if *mint_account_info.key != spl_token::native_mint::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (unwrap_pubkey, unwrap_nonce) = Pubkey::find_program_address(
+            &[br"unwrap", &withdrawal_account_info.key.to_bytes()],
+            program_id,
+        );
+        let unwrap_account_signer_seeds: &[&[_]] = &[
+            br"unwrap",
+            &withdrawal_account_info.key.to_bytes(),
+            &[unwrap_nonce],
+        ];
+
+        if unwrap_pubkey != *unwrap_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let withdrawal_account_data =
+            token_proxy::WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+
+        let unwrap_rent = rent.minimum_balance(spl_token::state::Account::LEN);
+
+        // Create temporary Token Account owned by recipient
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                unwrap_account_info.key,
+                unwrap_rent,
+                spl_token::state::Account::LEN as u64,
+                &spl_token::id(),
+            ),
+            accounts,
+            &[unwrap_account_signer_seeds],
+        )?;
+
+        invoke(
+            &spl_token::instruction::initialize_account3(
+                &spl_token::id(),
+                unwrap_account_info.key,
+                &spl_token::native_mint::id(),
+                recipient_account_info.key,
+            )?,
+            accounts,
+        )?;
+
+        invoke(
+            &token_proxy::withdrawal_sol_ix(
+                *withdrawal_account_info.key,
+                unwrap_pubkey,
+                *recipient_account_info.key,
+                spl_token::native_mint::id(),
+                withdrawal_account_data.round_number,
+                *proposal_index_account_info.key,
+            ),
+            accounts,
+        )?;
+
+        // Unwrap withdrawn lamports to recipient
+        invoke(
+            &spl_token::instruction::close_account(
+                &spl_token::id(),
+                &unwrap_pubkey,
+                recipient_account_info.key,
+                recipient_account_info.key,
+                &[],
+            )?,
+            accounts,
+        )?;
+
+        // Return rent to funder
+        if funder_account_info.key != recipient_account_info.key {
+            invoke(
+                &system_instruction::transfer(
+                    recipient_account_info.key,
+                    funder_account_info.key,
+                    unwrap_rent,
+                ),
+                accounts,
+            )?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,9 +1,11 @@
 #![cfg(feature = "test-bpf")]
 
 use borsh::BorshSerialize;
-use bridge_utils::state::AccountKind;
-use bridge_utils::types::{EverAddress, UInt256};
+use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::types::{EverAddress, UInt256, Vote};
 use native_proxy::deposit_ix;
+use solana_program::hash::hash;
+use solana_program::program_option::COption;
 use solana_program::rent::Rent;
 use solana_program::{program_pack::Pack, pubkey::Pubkey};
 use solana_program_test::{processor, tokio, ProgramTest};
@@ -12,6 +14,7 @@ use solana_sdk::signature::{Keypair, Signer};
 use solana_sdk::transaction::Transaction;
 use spl_token::state::AccountState;
 
+use bridge_test_utils::TestBridge;
 use native_proxy::*;
 
 #[tokio::test]
@@ -55,6 +58,23 @@ async fn test_deposit() {
         guardian,
         manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: token_proxy::CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; token_proxy::Settings::LEN];
@@ -78,6 +98,7 @@ async fn test_deposit() {
     let multivault_account_data = token_proxy::MultiVault {
         is_initialized: true,
         account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: token_proxy::CURRENT_ACCOUNT_VERSION,
     };
 
     let mut multivault_packed = vec![0; token_proxy::MultiVault::LEN];
@@ -153,6 +174,26 @@ async fn test_deposit() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: token_proxy::CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
     let mut token_settings_packed = vec![0; token_proxy::TokenSettings::LEN];
@@ -295,3 +336,558 @@ async fn test_deposit() {
         deposit_data.meta.data.try_to_vec().unwrap()
     );
 }
+
+#[tokio::test]
+async fn test_wrap_and_deposit() {
+    let mut program_test = ProgramTest::new("native_proxy", id(), processor!(Processor::process));
+
+    program_test.add_program(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(token_proxy::Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Sender Account
+    let sender = Keypair::new();
+
+    program_test.add_account(
+        sender.pubkey(),
+        Account {
+            lamports: 1_000_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = token_proxy::get_settings_address();
+
+    let settings_account_data = token_proxy::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: token_proxy::CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; token_proxy::Settings::LEN];
+    token_proxy::Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(token_proxy::Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add MultiVault Account
+    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+
+    let multivault_address = token_proxy::get_multivault_address();
+
+    let multivault_account_data = token_proxy::MultiVault {
+        is_initialized: true,
+        account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: token_proxy::CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut multivault_packed = vec![0; token_proxy::MultiVault::LEN];
+    token_proxy::MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
+    program_test.add_account(
+        multivault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(token_proxy::MultiVault::LEN),
+            data: multivault_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Vault Account
+    let mint_address = spl_token::native_mint::id();
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = token_proxy::get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "wSOL".to_string();
+    let name = "Wrapped SOL".to_string();
+    let deposit_limit = 10_000_000_000;
+    let withdrawal_limit = 10_000_000_000;
+    let withdrawal_daily_limit = 10_000_000_000;
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = token_proxy::get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = token_proxy::TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: token_proxy::TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name: name.clone(),
+        symbol: symbol.clone(),
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: token_proxy::CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; token_proxy::TokenSettings::LEN];
+    token_proxy::TokenSettings::pack(token_settings_account_data, &mut token_settings_packed)
+        .unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(token_proxy::TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let amount = 1_000_000_000;
+    let value = 0;
+    let payload: Vec<u8> = vec![];
+    let expected_evers = UInt256::default();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[wrap_and_deposit_ix(
+            funder.pubkey(),
+            sender.pubkey(),
+            deposit_seed,
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload.clone(),
+            None,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Vault Balance
+    let vault_address = token_proxy::get_vault_address(&mint_address);
+
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, amount);
+
+    // Check temporary wSOL Account is closed
+    let wrap_info = banks_client
+        .get_account(get_wrap_address(deposit_seed))
+        .await
+        .expect("get_account");
+    assert!(wrap_info.is_none());
+
+    // Check Sender has no wSOL Account
+    let sender_token_info = banks_client
+        .get_account(spl_associated_token_account::get_associated_token_address(
+            &sender.pubkey(),
+            &mint_address,
+        ))
+        .await
+        .expect("get_account");
+    assert!(sender_token_info.is_none());
+
+    // Check Deposit Account
+    let deposit_address = token_proxy::get_deposit_address(deposit_seed);
+    let deposit_info = banks_client
+        .get_account(deposit_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let deposit_data = token_proxy::DepositMultiTokenSol::unpack(deposit_info.data())
+        .expect("deposit token unpack");
+
+    assert_eq!(deposit_data.is_initialized, true);
+
+    let (_, deposit_nonce) = Pubkey::find_program_address(
+        &[br"deposit", &deposit_seed.to_le_bytes()],
+        &token_proxy::id(),
+    );
+    assert_eq!(
+        deposit_data.account_kind,
+        AccountKind::Deposit(deposit_nonce)
+    );
+
+    assert_eq!(deposit_data.event.data.base_token, mint_address);
+    assert_eq!(deposit_data.event.data.name, name);
+    assert_eq!(deposit_data.event.data.symbol, symbol);
+    assert_eq!(
+        deposit_data.event.data.decimals,
+        spl_token::native_mint::DECIMALS
+    );
+    assert_eq!(deposit_data.event.data.value, value);
+    assert_eq!(deposit_data.event.data.recipient, recipient);
+    assert_eq!(deposit_data.event.data.payload, payload);
+
+    assert_eq!(deposit_data.meta.data.seed, deposit_seed);
+
+    let token_settings_address = token_proxy::get_token_settings_sol_address(&mint_address);
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data = token_proxy::TokenSettings::unpack(token_settings_info.data())
+        .expect("deposit token unpack");
+
+    let fee_info = &token_settings_data.fee_deposit_info;
+    let fee = 1.max(
+        (amount)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount - fee;
+
+    assert_eq!(deposit_data.event.data.amount, transfer_amount as u128);
+
+    // Check Deposit Account to unpack
+    let raw_deposit_data =
+        token_proxy::Deposit::unpack_from_slice(deposit_info.data()).expect("deposit token unpack");
+
+    assert_eq!(
+        raw_deposit_data.event,
+        deposit_data.event.data.try_to_vec().unwrap()
+    );
+    assert_eq!(
+        raw_deposit_data.meta,
+        deposit_data.meta.data.try_to_vec().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_withdraw_sol() {
+    // Setup environment
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+
+    let mint_address = spl_token::native_mint::id();
+    let vault_address = token_proxy::get_vault_address(&mint_address);
+    let vault_amount = 1_000_000_000;
+
+    let recipient = Keypair::new();
+    let recipient_balance = 1_000_000_000;
+
+    let token_settings_account_data = bridge_test_utils::sol_token_settings(mint_address);
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut test_bridge = TestBridge::new()
+        .with_settings(bridge_test_utils::settings(
+            guardian,
+            manager,
+            withdrawal_manager,
+        ))
+        .with_packed_account(
+            token_proxy::get_token_settings_sol_address(&mint_address),
+            token_proxy::id(),
+            token_settings_account_data,
+        )
+        .with_funded_account(recipient.pubkey(), recipient_balance);
+
+    test_bridge.program_test_mut().add_program(
+        "native_proxy",
+        id(),
+        processor!(Processor::process),
+    );
+
+    // Add wSOL Vault Account
+    let vault_rent = Rent::default().minimum_balance(spl_token::state::Account::LEN);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        amount: vault_amount,
+        state: AccountState::Initialized,
+        is_native: COption::Some(vault_rent),
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    let test_bridge = test_bridge.with_account(
+        vault_address,
+        Account {
+            lamports: vault_rent + vault_amount,
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let amount = 100_000_000;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = token_proxy::get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient.pubkey(),
+        amount,
+        payload.clone(),
+    );
+
+    let event = token_proxy::WithdrawalMultiTokenSolEventWithLen::new(
+        mint_address,
+        amount,
+        recipient.pubkey(),
+        payload,
+    );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let signers = vec![Vote::Confirm; 3];
+
+    let withdrawal_account_data = token_proxy::WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: token_proxy::WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers,
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: token_proxy::CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+
+    let test_bridge = test_bridge.with_packed_account(
+        withdrawal_address,
+        token_proxy::id(),
+        withdrawal_account_data,
+    );
+
+    // Add Relay Round Accounts
+    let test_bridge = test_bridge
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number))
+        .with_relay_round(
+            round_number,
+            vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ],
+            u32::MAX,
+        );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdraw_sol_ix(
+            funder.pubkey(),
+            recipient.pubkey(),
+            withdrawal_address,
+            round_number,
+            token_proxy::get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &recipient], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee;
+
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, vault_amount - transfer_amount);
+
+    // Check Recipient received lamports
+    let recipient_info = banks_client
+        .get_account(recipient.pubkey())
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(recipient_info.lamports, recipient_balance + transfer_amount);
+
+    // Check temporary wSOL Account is closed
+    let unwrap_info = banks_client
+        .get_account(get_unwrap_address(&withdrawal_address))
+        .await
+        .expect("get_account");
+    assert!(unwrap_info.is_none());
+
+    // Check Recipient has no wSOL Account
+    let recipient_token_info = banks_client
+        .get_account(spl_associated_token_account::get_associated_token_address(
+            &recipient.pubkey(),
+            &mint_address,
+        ))
+        .await
+        .expect("get_account");
+    assert!(recipient_token_info.is_none());
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data = token_proxy::WithdrawalMultiTokenSol::unpack(withdrawal_info.data())
+        .expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        token_proxy::WithdrawalTokenStatus::Processed
+    );
+}
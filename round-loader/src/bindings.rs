@@ -1,13 +1,26 @@
 use borsh::BorshSerialize;
+use bridge_derive::AccountMetas;
+use bridge_utils::state::{AccountKind, DISCRIMINATOR_LEN};
 use bridge_utils::types::Vote;
 
 use solana_program::hash::hash;
-use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::instruction::Instruction;
 use solana_program::pubkey::Pubkey;
 use solana_program::{system_program, sysvar};
 
 use crate::*;
 
+// Packed accounts start with type tag used in `memcmp` filters of `getProgramAccounts`
+// at zero offset. Legacy accounts are tagged once they are rewritten
+pub const SETTINGS_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::Settings(0, 0).discriminator();
+pub const RELAY_ROUND_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::RelayRound(0).discriminator();
+pub const PROPOSAL_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::Proposal(0, None).discriminator();
+pub const RELAY_EVICTION_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::RelayEviction(0).discriminator();
+
 pub fn get_programdata_address() -> Pubkey {
     let program_id = &id();
     bridge_utils::helper::get_programdata_address(program_id)
@@ -23,6 +36,11 @@ pub fn get_relay_round_address(round_number: u32) -> Pubkey {
     bridge_utils::helper::get_associated_relay_round_address(program_id, round_number)
 }
 
+pub fn get_relay_eviction_address(round_number: u32, relay: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_relay_eviction_address(program_id, round_number, relay)
+}
+
 pub fn get_proposal_address(
     round_number: u32,
     event_timestamp: u32,
@@ -40,10 +58,24 @@ pub fn get_proposal_address(
         event_timestamp,
         event_transaction_lt,
         event_configuration,
+        None,
         &event_data.to_bytes(),
     )
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct InitializeAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub initializer: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
 pub fn initialize_ix(
     funder_pubkey: &Pubkey,
     initializer_pubkey: &Pubkey,
@@ -66,24 +98,35 @@ pub fn initialize_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*funder_pubkey, true),
-            AccountMeta::new(*initializer_pubkey, true),
-            AccountMeta::new(setting_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
+        accounts: InitializeAccounts {
+            funder: *funder_pubkey,
+            initializer: *initializer_pubkey,
+            settings: setting_pubkey,
+            program_data: program_data_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct UpdateSettingsAccounts {
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
 pub fn update_settings_ix(
     author_pubkey: &Pubkey,
     current_round_number: Option<u32>,
     round_submitter: Option<Pubkey>,
     min_required_votes: Option<u32>,
     round_ttl: Option<u32>,
+    guardian: Option<Pubkey>,
 ) -> Instruction {
     let setting_pubkey = get_settings_address();
     let program_data_pubkey = get_programdata_address();
@@ -93,21 +136,107 @@ pub fn update_settings_ix(
         round_submitter,
         min_required_votes,
         round_ttl,
+        guardian,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: UpdateSettingsAccounts {
+            author: *author_pubkey,
+            settings: setting_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct UpdateRoundHandoverPeriodAccounts {
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn update_round_handover_period_ix(
+    author_pubkey: &Pubkey,
+    round_handover_period: u32,
+) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = RoundLoaderInstruction::UpdateRoundHandoverPeriod {
+        round_handover_period,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: UpdateRoundHandoverPeriodAccounts {
+            author: *author_pubkey,
+            settings: setting_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct UpdateQuorumAccounts {
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn update_quorum_ix(
+    author_pubkey: &Pubkey,
+    quorum_numerator: u32,
+    quorum_denominator: u32,
+) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = RoundLoaderInstruction::UpdateQuorum {
+        quorum_numerator,
+        quorum_denominator,
     }
     .try_to_vec()
     .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*author_pubkey, true),
-            AccountMeta::new(setting_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: UpdateQuorumAccounts {
+            author: *author_pubkey,
+            settings: setting_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateRelayRoundAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub creator: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    #[account(writable)]
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
 pub fn create_relay_round_ix(
     funder_pubkey: &Pubkey,
     creator_pubkey: &Pubkey,
@@ -128,18 +257,66 @@ pub fn create_relay_round_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*funder_pubkey, true),
-            AccountMeta::new(*creator_pubkey, true),
-            AccountMeta::new(setting_pubkey, false),
-            AccountMeta::new(relay_round_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
+        accounts: CreateRelayRoundAccounts {
+            funder: *funder_pubkey,
+            creator: *creator_pubkey,
+            settings: setting_pubkey,
+            relay_round: relay_round_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+pub fn create_weighted_relay_round_ix(
+    funder_pubkey: &Pubkey,
+    creator_pubkey: &Pubkey,
+    round_number: u32,
+    round_end: u32,
+    relays: Vec<Pubkey>,
+    weights: Vec<u32>,
+) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::CreateWeightedRelayRound {
+        round_number,
+        relays,
+        weights,
+        round_end,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CreateRelayRoundAccounts {
+            funder: *funder_pubkey,
+            creator: *creator_pubkey,
+            settings: setting_pubkey,
+            relay_round: relay_round_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateProposalAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub creator: Pubkey,
+    #[account(writable)]
+    pub proposal: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
 pub fn create_proposal_ix(
     funder_pubkey: &Pubkey,
     creator_pubkey: &Pubkey,
@@ -171,17 +348,24 @@ pub fn create_proposal_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*funder_pubkey, true),
-            AccountMeta::new(*creator_pubkey, true),
-            AccountMeta::new(proposal_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
+        accounts: CreateProposalAccounts {
+            funder: *funder_pubkey,
+            creator: *creator_pubkey,
+            proposal: proposal_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WriteProposalAccounts {
+    #[account(writable)]
+    pub proposal: Pubkey,
+}
+
 pub fn write_proposal_ix(proposal_pubkey: &Pubkey, offset: u32, bytes: Vec<u8>) -> Instruction {
     let data = RoundLoaderInstruction::WriteProposal { offset, bytes }
         .try_to_vec()
@@ -189,11 +373,25 @@ pub fn write_proposal_ix(proposal_pubkey: &Pubkey, offset: u32, bytes: Vec<u8>)
 
     Instruction {
         program_id: id(),
-        accounts: vec![AccountMeta::new(*proposal_pubkey, false)],
+        accounts: WriteProposalAccounts {
+            proposal: *proposal_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct FinalizeProposalAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub proposal: Pubkey,
+    pub settings: Pubkey,
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+}
+
 pub fn finalize_proposal_ix(
     funder_pubkey: &Pubkey,
     proposal_pubkey: &Pubkey,
@@ -208,17 +406,28 @@ pub fn finalize_proposal_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*funder_pubkey, true),
-            AccountMeta::new(*proposal_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(relay_round_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
+        accounts: FinalizeProposalAccounts {
+            funder: *funder_pubkey,
+            proposal: *proposal_pubkey,
+            settings: settings_pubkey,
+            relay_round: relay_round_pubkey,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct VoteForProposalAccounts {
+    #[account(signer, writable)]
+    pub voter: Pubkey,
+    #[account(writable)]
+    pub proposal: Pubkey,
+    pub relay_round: Pubkey,
+    pub settings: Pubkey,
+}
+
 pub fn vote_for_proposal_ix(
     voter_pubkey: &Pubkey,
     proposal_pubkey: &Pubkey,
@@ -226,6 +435,7 @@ pub fn vote_for_proposal_ix(
     vote: Vote,
 ) -> Instruction {
     let relay_round_pubkey = get_relay_round_address(round_number);
+    let setting_pubkey = get_settings_address();
 
     let data = RoundLoaderInstruction::VoteForProposal { vote }
         .try_to_vec()
@@ -233,22 +443,41 @@ pub fn vote_for_proposal_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*voter_pubkey, true),
-            AccountMeta::new(*proposal_pubkey, false),
-            AccountMeta::new_readonly(relay_round_pubkey, false),
-        ],
+        accounts: VoteForProposalAccounts {
+            voter: *voter_pubkey,
+            proposal: *proposal_pubkey,
+            relay_round: relay_round_pubkey,
+            settings: setting_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ExecuteProposalAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    #[account(writable)]
+    pub proposal: Pubkey,
+    #[account(writable)]
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub voting_relay_round: Pubkey,
+}
+
 pub fn execute_proposal_ix(
     funder_pubkey: &Pubkey,
     proposal_pubkey: &Pubkey,
     round_number: u32,
+    voting_round_number: u32,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
     let relay_round_pubkey = get_relay_round_address(round_number);
+    let voting_relay_round_pubkey = get_relay_round_address(voting_round_number);
 
     let data = RoundLoaderInstruction::ExecuteProposal
         .try_to_vec()
@@ -256,18 +485,36 @@ pub fn execute_proposal_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*funder_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new(*proposal_pubkey, false),
-            AccountMeta::new(relay_round_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
+        accounts: ExecuteProposalAccounts {
+            funder: *funder_pubkey,
+            settings: settings_pubkey,
+            proposal: *proposal_pubkey,
+            relay_round: relay_round_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+            voting_relay_round: voting_relay_round_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ExecuteProposalByAdminAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub creator: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    #[account(writable)]
+    pub proposal: Pubkey,
+    #[account(writable)]
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
 pub fn execute_proposal_by_admin_ix(
     funder_pubkey: &Pubkey,
     creator_pubkey: &Pubkey,
@@ -283,15 +530,166 @@ pub fn execute_proposal_by_admin_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*funder_pubkey, true),
-            AccountMeta::new(*creator_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new(*proposal_pubkey, false),
-            AccountMeta::new(relay_round_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
+        accounts: ExecuteProposalByAdminAccounts {
+            funder: *funder_pubkey,
+            creator: *creator_pubkey,
+            settings: settings_pubkey,
+            proposal: *proposal_pubkey,
+            relay_round: relay_round_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateRelayEvictionAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer)]
+    pub guardian: Pubkey,
+    pub settings: Pubkey,
+    pub relay_round: Pubkey,
+    #[account(writable)]
+    pub eviction: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn create_relay_eviction_ix(
+    funder_pubkey: &Pubkey,
+    guardian_pubkey: &Pubkey,
+    round_number: u32,
+    relay: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let eviction_pubkey = get_relay_eviction_address(round_number, &relay);
+
+    let data = RoundLoaderInstruction::CreateRelayEviction {
+        round_number,
+        relay,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CreateRelayEvictionAccounts {
+            funder: *funder_pubkey,
+            guardian: *guardian_pubkey,
+            settings: settings_pubkey,
+            relay_round: relay_round_pubkey,
+            eviction: eviction_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct VoteForRelayEvictionAccounts {
+    #[account(signer, writable)]
+    pub voter: Pubkey,
+    #[account(writable)]
+    pub eviction: Pubkey,
+    #[account(writable)]
+    pub relay_round: Pubkey,
+}
+
+pub fn vote_for_relay_eviction_ix(
+    voter_pubkey: &Pubkey,
+    round_number: u32,
+    relay: &Pubkey,
+) -> Instruction {
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let eviction_pubkey = get_relay_eviction_address(round_number, relay);
+
+    let data = RoundLoaderInstruction::VoteForRelayEviction
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: VoteForRelayEvictionAccounts {
+            voter: *voter_pubkey,
+            eviction: eviction_pubkey,
+            relay_round: relay_round_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ReportRelayMisbehaviorAccounts {
+    #[account(signer, writable)]
+    pub reporter: Pubkey,
+    #[account(writable)]
+    pub relay_round: Pubkey,
+    pub first_proposal: Pubkey,
+    pub second_proposal: Pubkey,
+}
+
+pub fn report_relay_misbehavior_ix(
+    reporter_pubkey: &Pubkey,
+    round_number: u32,
+    relay: Pubkey,
+    first_proposal_pubkey: Pubkey,
+    second_proposal_pubkey: Pubkey,
+) -> Instruction {
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::ReportRelayMisbehavior {
+        round_number,
+        relay,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ReportRelayMisbehaviorAccounts {
+            reporter: *reporter_pubkey,
+            relay_round: relay_round_pubkey,
+            first_proposal: first_proposal_pubkey,
+            second_proposal: second_proposal_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct MigrateRelayRoundAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn migrate_relay_round_ix(funder_pubkey: &Pubkey, round_number: u32) -> Instruction {
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::MigrateRelayRound
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: MigrateRelayRoundAccounts {
+            funder: *funder_pubkey,
+            relay_round: relay_round_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
         data,
     }
 }
@@ -42,6 +42,8 @@ pub enum RoundLoaderInstruction {
         min_required_votes: Option<u32>,
         // Round TTL
         round_ttl: Option<u32>,
+        // Guardian role
+        guardian: Option<Pubkey>,
     },
 
     /// Create Relay Round
@@ -102,4 +104,73 @@ pub enum RoundLoaderInstruction {
     /// # Account references
     /// ...
     ExecuteProposalByAdmin,
+
+    /// Create eviction of a compromised Relay from the active round
+    ///
+    /// # Account references
+    /// ...
+    CreateRelayEviction {
+        // Relay Round number
+        round_number: u32,
+        // Relay to evict
+        relay: Pubkey,
+    },
+
+    /// Vote for Relay eviction. Relay is evicted once quorum is reached
+    ///
+    /// # Account references
+    /// ...
+    VoteForRelayEviction,
+
+    /// Update share of Relays required to reach quorum
+    ///
+    /// # Account references
+    /// ...
+    UpdateQuorum {
+        // Quorum numerator
+        quorum_numerator: u32,
+        // Quorum denominator
+        quorum_denominator: u32,
+    },
+
+    /// Slash a Relay that confirmed two conflicting proposals for the same event
+    ///
+    /// # Account references
+    /// ...
+    ReportRelayMisbehavior {
+        // Relay Round number
+        round_number: u32,
+        // Misbehaving Relay
+        relay: Pubkey,
+    },
+
+    /// Update overlap window during which previous Relay Round still can vote
+    ///
+    /// # Account references
+    /// ...
+    UpdateRoundHandoverPeriod {
+        // Seconds after round end
+        round_handover_period: u32,
+    },
+
+    /// Create Relay Round with vote weight of each Relay
+    ///
+    /// # Account references
+    /// ...
+    CreateWeightedRelayRound {
+        // Relay Round number
+        round_number: u32,
+        // Relays keys in a new round
+        relays: Vec<Pubkey>,
+        // Vote weight of each Relay
+        weights: Vec<u32>,
+        // Round end
+        round_end: u32,
+    },
+
+    /// Expand Relay Round account created before Relay weights to the current layout
+    ///
+    /// # Account references
+    /// ...
+    MigrateRelayRound,
 }
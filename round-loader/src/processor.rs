@@ -1,9 +1,10 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use bridge_utils::errors::SolanaBridgeError;
-use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::state::{AccountKind, Proposal, PDA};
 use bridge_utils::types::{Vote, RELAY_REPARATION};
 
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::hash::{hash, Hash};
 use solana_program::program::{invoke, invoke_signed};
@@ -47,6 +48,7 @@ impl Processor {
                 round_submitter,
                 min_required_votes,
                 round_ttl,
+                guardian,
             } => {
                 msg!("Instruction: Update Settings");
                 Self::process_update_settings(
@@ -56,6 +58,7 @@ impl Processor {
                     round_submitter,
                     min_required_votes,
                     round_ttl,
+                    guardian,
                 )?;
             }
             RoundLoaderInstruction::CreateRelayRound {
@@ -69,6 +72,7 @@ impl Processor {
                     accounts,
                     round_number,
                     relays,
+                    vec![],
                     round_end,
                 )?;
             }
@@ -110,6 +114,66 @@ impl Processor {
                 msg!("Instruction: Execute by admin");
                 Self::process_execute_proposal_by_admin(program_id, accounts)?;
             }
+            RoundLoaderInstruction::CreateRelayEviction {
+                round_number,
+                relay,
+            } => {
+                msg!("Instruction: Create Relay eviction");
+                Self::process_create_relay_eviction(program_id, accounts, round_number, relay)?;
+            }
+            RoundLoaderInstruction::VoteForRelayEviction => {
+                msg!("Instruction: Vote for Relay eviction");
+                Self::process_vote_for_relay_eviction(program_id, accounts)?;
+            }
+            RoundLoaderInstruction::ReportRelayMisbehavior {
+                round_number,
+                relay,
+            } => {
+                msg!("Instruction: Report Relay misbehavior");
+                Self::process_report_relay_misbehavior(program_id, accounts, round_number, relay)?;
+            }
+            RoundLoaderInstruction::UpdateQuorum {
+                quorum_numerator,
+                quorum_denominator,
+            } => {
+                msg!("Instruction: Update quorum");
+                Self::process_update_quorum(
+                    program_id,
+                    accounts,
+                    quorum_numerator,
+                    quorum_denominator,
+                )?;
+            }
+            RoundLoaderInstruction::UpdateRoundHandoverPeriod {
+                round_handover_period,
+            } => {
+                msg!("Instruction: Update round handover period");
+                Self::process_update_round_handover_period(
+                    program_id,
+                    accounts,
+                    round_handover_period,
+                )?;
+            }
+            RoundLoaderInstruction::CreateWeightedRelayRound {
+                round_number,
+                relays,
+                weights,
+                round_end,
+            } => {
+                msg!("Instruction: Create weighted Relay Round");
+                Self::process_create_relay_round(
+                    program_id,
+                    accounts,
+                    round_number,
+                    relays,
+                    weights,
+                    round_end,
+                )?;
+            }
+            RoundLoaderInstruction::MigrateRelayRound => {
+                msg!("Instruction: Migrate Relay Round");
+                Self::process_migrate_relay_round(program_id, accounts)?;
+            }
         };
 
         Ok(())
@@ -179,6 +243,10 @@ impl Processor {
             round_submitter,
             min_required_votes,
             round_ttl,
+            guardian: Pubkey::default(),
+            quorum_numerator: DEFAULT_QUORUM_NUMERATOR,
+            quorum_denominator: DEFAULT_QUORUM_DENOMINATOR,
+            round_handover_period: 0,
         };
 
         Settings::pack(
@@ -196,6 +264,7 @@ impl Processor {
         round_submitter: Option<Pubkey>,
         min_required_votes: Option<u32>,
         round_ttl: Option<u32>,
+        guardian: Option<Pubkey>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
@@ -252,6 +321,129 @@ impl Processor {
             settings_account_data.round_ttl = round_ttl;
         }
 
+        if let Some(guardian) = guardian {
+            settings_account_data.guardian = guardian;
+        }
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_update_round_handover_period(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        round_handover_period: u32,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let author_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_info.owner != program_id {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            author_account_info.key,
+            programdata_account_info,
+        )?;
+
+        settings_account_data.round_handover_period = round_handover_period;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_update_quorum(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        quorum_numerator: u32,
+        quorum_denominator: u32,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let author_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_info.owner != program_id {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            author_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Quorum can't exceed all Relays in the round
+        // Quorum of less than half of Relays lets conflicting proposals both pass
+        if quorum_denominator == 0
+            || quorum_numerator >= quorum_denominator
+            || (quorum_numerator as u64) * 2 < quorum_denominator as u64
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        settings_account_data.quorum_numerator = quorum_numerator;
+        settings_account_data.quorum_denominator = quorum_denominator;
+
         Settings::pack(
             settings_account_data,
             &mut settings_account_info.data.borrow_mut(),
@@ -265,6 +457,7 @@ impl Processor {
         accounts: &[AccountInfo],
         round_number: u32,
         relays: Vec<Pubkey>,
+        weights: Vec<u32>,
         round_end: u32,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
@@ -281,6 +474,18 @@ impl Processor {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        // Every Relay has a non-zero weight and the total fits required votes
+        if !weights.is_empty() {
+            if weights.len() != relays.len() || weights.contains(&0) {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let total_weight: u64 = weights.iter().map(|weight| *weight as u64).sum();
+            if total_weight > u32::MAX as u64 {
+                return Err(SolanaBridgeError::Overflow.into());
+            }
+        }
+
         // Validate Settings Account
         let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
@@ -341,8 +546,19 @@ impl Processor {
             round_number,
             round_end,
             relays,
+            weights,
+            quorum_numerator: settings_account_data.quorum_numerator,
+            quorum_denominator: settings_account_data.quorum_denominator,
         };
 
+        solana_program::log::sol_log_data(&[&RelayRoundEvent {
+            account: *relay_round_account_info.key,
+            round_number,
+            round_end,
+            relays_count: relay_round_account_data.relays.len() as u32,
+        }
+        .try_to_vec()?]);
+
         RelayRound::pack(
             relay_round_account_data,
             &mut relay_round_account_info.data.borrow_mut(),
@@ -517,6 +733,7 @@ impl Processor {
             event_timestamp,
             event_transaction_lt,
             &event_configuration,
+            None,
             &event_data,
             nonce,
             proposal_account_info,
@@ -542,15 +759,15 @@ impl Processor {
             relay_round_account_info,
         )?;
 
-        let mut required_votes = (relay_round_account_data.relays.len() * 2 / 3 + 1) as u32;
-        if settings_account_data.min_required_votes > required_votes {
-            required_votes = settings_account_data.min_required_votes;
-        }
+        let relays_count = relay_round_account_data.relays.len();
+        let total_weight = relay_round_account_data.total_weight() as usize;
+
+        let required_votes = get_required_votes(total_weight, &settings_account_data);
 
         proposal_account_data.is_initialized = true;
         proposal_account_data.round_number = round_number;
         proposal_account_data.required_votes = required_votes;
-        proposal_account_data.signers = vec![Vote::None; relay_round_account_data.relays.len()];
+        proposal_account_data.signers = vec![Vote::None; relays_count];
 
         proposal_account_data.meta = RelayRoundProposalMetaWithLen::default();
 
@@ -564,7 +781,7 @@ impl Processor {
             &system_instruction::transfer(
                 funder_account_info.key,
                 &proposal_pubkey,
-                RELAY_REPARATION * relay_round_account_data.relays.len() as u64,
+                RELAY_REPARATION * relays_count as u64,
             ),
             accounts,
         )?;
@@ -582,6 +799,7 @@ impl Processor {
         let voter_account_info = next_account_info(account_info_iter)?;
         let proposal_account_info = next_account_info(account_info_iter)?;
         let relay_round_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
 
         if !voter_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
@@ -611,6 +829,7 @@ impl Processor {
             event_timestamp,
             event_transaction_lt,
             &event_configuration,
+            None,
             &event_data,
             nonce,
             proposal_account_info,
@@ -632,6 +851,27 @@ impl Processor {
             relay_round_account_info,
         )?;
 
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Previous round can vote only within handover period
+        validate_relay_round_for_voting(
+            &settings_account_data,
+            &relay_round_account_data,
+            Clock::get()?.unix_timestamp,
+        )?;
+
         // Vote for proposal request
         let index = relay_round_account_data
             .relays
@@ -644,6 +884,13 @@ impl Processor {
             proposal_account_data.signers[index] = vote;
             proposal_account_data.pack_into_slice(&mut proposal_account_info.data.borrow_mut());
 
+            solana_program::log::sol_log_data(&[&VoteForProposalEvent {
+                account: *proposal_account_info.key,
+                relay: *voter_account_info.key,
+                vote,
+            }
+            .try_to_vec()?]);
+
             // Get back voting reparation to Relay
             let proposal_starting_lamports = proposal_account_info.lamports();
             **proposal_account_info.lamports.borrow_mut() = proposal_starting_lamports
@@ -669,6 +916,7 @@ impl Processor {
         let _system_program_info = next_account_info(account_info_iter)?;
         let rent_sysvar_info = next_account_info(account_info_iter)?;
         let rent = &Rent::from_account_info(rent_sysvar_info)?;
+        let voting_relay_round_account_info = next_account_info(account_info_iter)?;
 
         // Validate Settings Account
         let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
@@ -704,17 +952,19 @@ impl Processor {
             event_timestamp,
             event_transaction_lt,
             &event_configuration,
+            None,
             &event_data,
             nonce,
             proposal_account_info,
         )?;
 
-        // Do we have enough signers.
-        let sig_count = proposal_account_data
-            .signers
-            .iter()
-            .filter(|vote| **vote == Vote::Confirm)
-            .count() as u32;
+        // Do we have enough signers. Votes are weighted by the round that voted
+        let sig_count = get_confirmed_votes(
+            program_id,
+            round_number,
+            voting_relay_round_account_info,
+            &proposal_account_data.signers,
+        )?;
 
         if proposal_account_data.meta.data.status == ProposalStatus::New
             && sig_count >= proposal_account_data.required_votes
@@ -758,8 +1008,19 @@ impl Processor {
                 round_number,
                 round_end,
                 relays: proposal_account_data.event.data.relays.clone(),
+                weights: vec![],
+                quorum_numerator: settings_account_data.quorum_numerator,
+                quorum_denominator: settings_account_data.quorum_denominator,
             };
 
+            solana_program::log::sol_log_data(&[&RelayRoundEvent {
+                account: *relay_round_account_info.key,
+                round_number,
+                round_end,
+                relays_count: relay_round_account_data.relays.len() as u32,
+            }
+            .try_to_vec()?]);
+
             RelayRound::pack(
                 relay_round_account_data,
                 &mut relay_round_account_info.data.borrow_mut(),
@@ -862,8 +1123,19 @@ impl Processor {
             round_number,
             round_end,
             relays: proposal.event.data.relays.clone(),
+            weights: vec![],
+            quorum_numerator: settings_account_data.quorum_numerator,
+            quorum_denominator: settings_account_data.quorum_denominator,
         };
 
+        solana_program::log::sol_log_data(&[&RelayRoundEvent {
+            account: *relay_round_account_info.key,
+            round_number,
+            round_end,
+            relays_count: relay_round_account_data.relays.len() as u32,
+        }
+        .try_to_vec()?]);
+
         RelayRound::pack(
             relay_round_account_data,
             &mut relay_round_account_info.data.borrow_mut(),
@@ -884,18 +1156,481 @@ impl Processor {
 
         Ok(())
     }
+
+    fn process_create_relay_eviction(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        round_number: u32,
+        relay: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let guardian_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let eviction_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !guardian_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.guardian == Pubkey::default()
+            || settings_account_data.guardian != *guardian_account_info.key
+        {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_relay_round_account(
+            program_id,
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        if relay_round_account_data.round_end <= Clock::get()?.unix_timestamp as u32 {
+            return Err(SolanaBridgeError::RelayRoundExpired.into());
+        }
+
+        if relay == Pubkey::default() || !relay_round_account_data.relays.contains(&relay) {
+            return Err(SolanaBridgeError::InvalidRelay.into());
+        }
+
+        // Quorum is taken from vote weight of the Relays remaining in the round
+        let relay_index = relay_round_account_data
+            .relays
+            .iter()
+            .position(|pubkey| *pubkey == relay)
+            .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+        let required_votes = get_required_votes(
+            (relay_round_account_data.total_weight()
+                - relay_round_account_data.weight(relay_index) as u64) as usize,
+            &settings_account_data,
+        );
+
+        // Create Relay Eviction Account
+        let (eviction_pubkey, eviction_nonce) = Pubkey::find_program_address(
+            &[br"eviction", &round_number.to_le_bytes(), &relay.to_bytes()],
+            program_id,
+        );
+        let eviction_account_signer_seeds: &[&[_]] = &[
+            br"eviction",
+            &round_number.to_le_bytes(),
+            &relay.to_bytes(),
+            &[eviction_nonce],
+        ];
+
+        if eviction_pubkey != *eviction_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                eviction_account_info.key,
+                1.max(rent.minimum_balance(RelayEviction::LEN)),
+                RelayEviction::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[eviction_account_signer_seeds],
+        )?;
+
+        let eviction_account_data = RelayEviction {
+            is_initialized: true,
+            account_kind: AccountKind::RelayEviction(eviction_nonce),
+            round_number,
+            relay,
+            required_votes,
+            executed: false,
+            signers: vec![Vote::None; relay_round_account_data.relays.len()],
+        };
+
+        RelayEviction::pack(
+            eviction_account_data,
+            &mut eviction_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_vote_for_relay_eviction(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let voter_account_info = next_account_info(account_info_iter)?;
+        let eviction_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+
+        if !voter_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Relay Eviction Account
+        let mut eviction_account_data =
+            RelayEviction::unpack(&eviction_account_info.data.borrow())?;
+
+        let eviction_nonce = eviction_account_data
+            .account_kind
+            .into_relay_eviction()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let round_number = eviction_account_data.round_number;
+        let relay = eviction_account_data.relay;
+
+        let eviction_pubkey = Pubkey::create_program_address(
+            &[
+                br"eviction",
+                &round_number.to_le_bytes(),
+                &relay.to_bytes(),
+                &[eviction_nonce],
+            ],
+            program_id,
+        )?;
+
+        if eviction_pubkey != *eviction_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if eviction_account_data.executed {
+            return Err(SolanaBridgeError::RelayEvictionExecuted.into());
+        }
+
+        // Validate Relay Round Account
+        let mut relay_round_account_data =
+            RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_relay_round_account(
+            program_id,
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        // Evicted Relay cannot vote for itself
+        if *voter_account_info.key == relay {
+            return Err(SolanaBridgeError::InvalidRelay.into());
+        }
+
+        let index = relay_round_account_data
+            .relays
+            .iter()
+            .position(|pubkey| pubkey == voter_account_info.key)
+            .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+        if eviction_account_data.signers[index] != Vote::None {
+            return Err(SolanaBridgeError::RelayAlreadyVoted.into());
+        }
+
+        eviction_account_data.signers[index] = Vote::Confirm;
+
+        let votes = relay_round_account_data.confirmed_weight(&eviction_account_data.signers);
+
+        if votes >= eviction_account_data.required_votes {
+            // Keep Relay position so that votes of pending proposals stay in place
+            let relay_index = relay_round_account_data
+                .relays
+                .iter()
+                .position(|pubkey| *pubkey == relay)
+                .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+            relay_round_account_data.relays[relay_index] = Pubkey::default();
+
+            solana_program::log::sol_log_data(&[&RelayEvictedEvent {
+                relay_round: *relay_round_account_info.key,
+                relay,
+            }
+            .try_to_vec()?]);
+
+            RelayRound::pack(
+                relay_round_account_data,
+                &mut relay_round_account_info.data.borrow_mut(),
+            )?;
+
+            eviction_account_data.executed = true;
+        }
+
+        RelayEviction::pack(
+            eviction_account_data,
+            &mut eviction_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_report_relay_misbehavior(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        round_number: u32,
+        relay: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let reporter_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let first_proposal_account_info = next_account_info(account_info_iter)?;
+        let second_proposal_account_info = next_account_info(account_info_iter)?;
+
+        if !reporter_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Relay Round Account
+        let mut relay_round_account_data =
+            RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_relay_round_account(
+            program_id,
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        let relay_index = relay_round_account_data
+            .relays
+            .iter()
+            .position(|pubkey| *pubkey == relay)
+            .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+        // Validate Proposal Accounts
+        if first_proposal_account_info.key == second_proposal_account_info.key
+            || first_proposal_account_info.owner != second_proposal_account_info.owner
+        {
+            return Err(SolanaBridgeError::RelayMisbehaviorNotProven.into());
+        }
+
+        let (first_pda, first_signers) =
+            validate_voted_proposal(program_id, round_number, first_proposal_account_info)?;
+        let (second_pda, second_signers) =
+            validate_voted_proposal(program_id, round_number, second_proposal_account_info)?;
+
+        // Both proposals have to be about the same event
+        if first_pda != second_pda {
+            return Err(SolanaBridgeError::RelayMisbehaviorNotProven.into());
+        }
+
+        let has_confirmed =
+            |signers: &[Vote]| signers.get(relay_index).copied() == Some(Vote::Confirm);
+
+        if !has_confirmed(&first_signers) || !has_confirmed(&second_signers) {
+            return Err(SolanaBridgeError::RelayMisbehaviorNotProven.into());
+        }
+
+        // Keep Relay position so that votes of pending proposals stay in place
+        relay_round_account_data.relays[relay_index] = Pubkey::default();
+
+        RelayRound::pack(
+            relay_round_account_data,
+            &mut relay_round_account_info.data.borrow_mut(),
+        )?;
+
+        solana_program::log::sol_log_data(&[&RelaySlashedEvent {
+            relay_round: *relay_round_account_info.key,
+            relay,
+            first_proposal: *first_proposal_account_info.key,
+            second_proposal: *second_proposal_account_info.key,
+        }
+        .try_to_vec()?]);
+
+        Ok(())
+    }
+
+    fn process_migrate_relay_round(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if relay_round_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        // Validate Relay Round Account. Legacy layout may not fit the current one
+        let (account_kind, round_number) = {
+            // Skip type tag and `is_initialized` flag
+            let data = relay_round_account_info.data.borrow();
+            let data = bridge_utils::state::strip_discriminator(&data);
+            let mut data = data.get(1..).unwrap_or_default();
+            (
+                AccountKind::deserialize(&mut data)?,
+                u32::deserialize(&mut data)?,
+            )
+        };
+
+        let relay_round_nonce = account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_relay_round_account(
+            program_id,
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        if relay_round_account_info.data_len() >= RelayRound::LEN {
+            return Ok(());
+        }
+
+        // Top up rent for the new layout. Missing weights are read as empty
+        let required_lamports = rent
+            .minimum_balance(RelayRound::LEN)
+            .saturating_sub(relay_round_account_info.lamports());
+
+        if required_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    funder_account_info.key,
+                    relay_round_account_info.key,
+                    required_lamports,
+                ),
+                accounts,
+            )?;
+        }
+
+        relay_round_account_info.realloc(RelayRound::LEN, true)
+    }
+}
+
+/// Confirmed vote weight of proposal voted by the round
+fn get_confirmed_votes(
+    program_id: &Pubkey,
+    round_number: u32,
+    relay_round_account_info: &AccountInfo,
+    signers: &[Vote],
+) -> Result<u32, ProgramError> {
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_relay_round_account(
+        program_id,
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    Ok(relay_round_account_data.confirmed_weight(signers))
+}
+
+fn validate_voted_proposal(
+    program_id: &Pubkey,
+    round_number: u32,
+    proposal_account_info: &AccountInfo,
+) -> Result<(PDA, Vec<Vote>), ProgramError> {
+    if !is_proposal_program(program_id, proposal_account_info.owner) {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Relay Round proposals of a full round don't fit the common proposal layout
+    let (proposal_round_number, pda, chain_id, event_data, account_kind, signers) =
+        if proposal_account_info.owner == program_id {
+            let proposal_account_data =
+                RelayRoundProposal::unpack(&proposal_account_info.data.borrow())?;
+            let event_data = hash(&proposal_account_data.event.data.try_to_vec()?);
+            (
+                proposal_account_data.round_number,
+                proposal_account_data.pda,
+                None,
+                event_data,
+                proposal_account_data.account_kind,
+                proposal_account_data.signers,
+            )
+        } else {
+            let proposal_account_data =
+                Proposal::unpack_from_slice(&proposal_account_info.data.borrow())?;
+
+            if !proposal_account_data.is_initialized {
+                return Err(ProgramError::UninitializedAccount);
+            }
+
+            let event_data = hash(&proposal_account_data.event);
+            (
+                proposal_account_data.round_number,
+                proposal_account_data.pda,
+                proposal_account_data.chain_id,
+                event_data,
+                proposal_account_data.account_kind,
+                proposal_account_data.signers,
+            )
+        };
+
+    let (nonce, _) = account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    if proposal_round_number != round_number {
+        return Err(SolanaBridgeError::InvalidRelayRound.into());
+    }
+
+    bridge_utils::helper::validate_proposal_account(
+        proposal_account_info.owner,
+        round_number,
+        pda.event_timestamp,
+        pda.event_transaction_lt,
+        &pda.event_configuration,
+        chain_id,
+        &event_data,
+        nonce,
+        proposal_account_info,
+    )?;
+
+    Ok((pda, signers))
 }
 
 fn write_proposal_data(data: &mut [u8], offset: usize, bytes: &[u8]) -> Result<(), ProgramError> {
-    let offset = LOAD_DATA_BEGIN_OFFSET + offset;
+    // Proposals created before type tags keep untagged layout
+    let discriminator_offset = bridge_utils::state::discriminator_offset(data);
+    let offset = discriminator_offset + LOAD_DATA_BEGIN_OFFSET + offset;
+    let end_offset = discriminator_offset + LOAD_DATA_END_OFFSET;
 
     let len = bytes.len();
-    if LOAD_DATA_END_OFFSET < offset + len {
-        msg!(
-            "Write overflow: {} < {}",
-            LOAD_DATA_END_OFFSET,
-            offset + len
-        );
+    if end_offset < offset + len {
+        msg!("Write overflow: {} < {}", end_offset, offset + len);
         return Err(ProgramError::AccountDataTooSmall);
     }
 
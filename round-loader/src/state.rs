@@ -14,6 +14,10 @@ pub const MIN_RELAYS: usize = 3;
 /// Maximum Relays in round
 pub const MAX_RELAYS: usize = 100;
 
+/// Default share of Relays required to reach quorum
+pub const DEFAULT_QUORUM_NUMERATOR: u32 = 2;
+pub const DEFAULT_QUORUM_DENOMINATOR: u32 = 3;
+
 pub const LOAD_DATA_BEGIN_OFFSET: usize = 1 // is_initialized
     + 3                                     // account_kind
     + PUBKEY_BYTES                          // author
@@ -43,6 +47,22 @@ pub struct Settings {
     pub round_submitter: Pubkey,
     pub min_required_votes: u32,
     pub round_ttl: u32,
+    pub guardian: Pubkey,
+    // Share of Relays required to reach quorum. Zero denominator for default one
+    pub quorum_numerator: u32,
+    pub quorum_denominator: u32,
+    // Seconds after round end its Relays still can vote once the next round is published
+    pub round_handover_period: u32,
+}
+
+impl Settings {
+    pub fn quorum_fraction(&self) -> (u32, u32) {
+        if self.quorum_denominator == 0 {
+            (DEFAULT_QUORUM_NUMERATOR, DEFAULT_QUORUM_DENOMINATOR)
+        } else {
+            (self.quorum_numerator, self.quorum_denominator)
+        }
+    }
 }
 
 impl Sealed for Settings {}
@@ -54,13 +74,62 @@ impl IsInitialized for Settings {
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
-#[bridge_pack(length = 3215)]
+#[bridge_pack(length = 3635, legacy_length = 3215)]
 pub struct RelayRound {
     pub is_initialized: bool,
     pub account_kind: AccountKind,
     pub round_number: u32,
     pub round_end: u32,
     pub relays: Vec<Pubkey>,
+    // Vote weight of each Relay. Empty for rounds where all Relays are equal
+    pub weights: Vec<u32>,
+    // Share of Relays required to reach quorum when the round was created.
+    // Zero denominator for default one
+    pub quorum_numerator: u32,
+    pub quorum_denominator: u32,
+}
+
+impl RelayRound {
+    pub fn quorum_fraction(&self) -> (u32, u32) {
+        if self.quorum_denominator == 0 {
+            (DEFAULT_QUORUM_NUMERATOR, DEFAULT_QUORUM_DENOMINATOR)
+        } else {
+            (self.quorum_numerator, self.quorum_denominator)
+        }
+    }
+
+    /// Relays that were not evicted from the round
+    pub fn active_relays(&self) -> usize {
+        self.relays
+            .iter()
+            .filter(|relay| **relay != Pubkey::default())
+            .count()
+    }
+
+    /// Vote weight of the Relay at `index`
+    pub fn weight(&self, index: usize) -> u32 {
+        match self.weights.is_empty() {
+            true => 1,
+            false => self.weights.get(index).copied().unwrap_or_default(),
+        }
+    }
+
+    /// Confirmed vote weight of proposal voted by the round
+    pub fn confirmed_weight(&self, signers: &[Vote]) -> u32 {
+        bridge_utils::helper::get_confirmed_weight(signers, &self.relays, |index| {
+            self.weight(index)
+        })
+    }
+
+    /// Total vote weight of Relays that were not evicted from the round
+    pub fn total_weight(&self) -> u64 {
+        self.relays
+            .iter()
+            .enumerate()
+            .filter(|(_, relay)| **relay != Pubkey::default())
+            .map(|(index, _)| self.weight(index) as u64)
+            .sum()
+    }
 }
 
 impl Sealed for RelayRound {}
@@ -72,7 +141,7 @@ impl IsInitialized for RelayRound {
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
-#[bridge_pack(length = 3415)]
+#[bridge_pack(length = 3423, legacy_length = 3415)]
 pub struct RelayRoundProposal {
     pub is_initialized: bool,
     pub account_kind: AccountKind,
@@ -93,6 +162,26 @@ impl IsInitialized for RelayRoundProposal {
     }
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 150)]
+pub struct RelayEviction {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    pub relay: Pubkey,
+    pub required_votes: u32,
+    pub executed: bool,
+    pub signers: Vec<Vote>,
+}
+
+impl Sealed for RelayEviction {}
+
+impl IsInitialized for RelayEviction {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
 #[derive(Debug, Default, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 pub struct RelayRoundProposalEvent {
     pub round_num: u32,
@@ -154,3 +243,33 @@ pub enum ProposalStatus {
     New,
     Executed,
 }
+
+// Events
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct VoteForProposalEvent {
+    pub account: Pubkey,
+    pub relay: Pubkey,
+    pub vote: Vote,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct RelayRoundEvent {
+    pub account: Pubkey,
+    pub round_number: u32,
+    pub round_end: u32,
+    pub relays_count: u32,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct RelayEvictedEvent {
+    pub relay_round: Pubkey,
+    pub relay: Pubkey,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct RelaySlashedEvent {
+    pub relay_round: Pubkey,
+    pub relay: Pubkey,
+    pub first_proposal: Pubkey,
+    pub second_proposal: Pubkey,
+}
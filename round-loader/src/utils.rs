@@ -1,7 +1,23 @@
+use bridge_utils::errors::SolanaBridgeError;
 use solana_program::account_info::AccountInfo;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
+use crate::{RelayRound, Settings};
+
+pub mod token_proxy {
+    solana_program::declare_id!("octuswa5MD5hrTwcNBKvdxDvDQoz7C7M9sk2cRRvZfg");
+}
+
+pub mod event_proxy {
+    solana_program::declare_id!("F6ZxSyViXoJowYB5vb4xxSBMc2JYVgbstmdqF3zRaggq");
+}
+
+/// Programs whose proposals are voted by Relays of the round
+pub fn is_proposal_program(program_id: &Pubkey, owner: &Pubkey) -> bool {
+    owner == program_id || *owner == token_proxy::id() || *owner == event_proxy::id()
+}
+
 pub fn validate_relay_round_account(
     program_id: &Pubkey,
     round_number: u32,
@@ -21,3 +37,71 @@ pub fn validate_relay_round_account(
 
     Ok(())
 }
+
+/// Relays of the current round can always vote, the previous round keeps voting
+/// until handover period after its end has passed
+pub fn validate_relay_round_for_voting(
+    settings: &Settings,
+    relay_round: &RelayRound,
+    timestamp: i64,
+) -> Result<(), ProgramError> {
+    if relay_round.round_number >= settings.current_round_number {
+        return Ok(());
+    }
+
+    let handover_deadline = relay_round
+        .round_end
+        .saturating_add(settings.round_handover_period);
+
+    if relay_round.round_number.saturating_add(1) != settings.current_round_number
+        || handover_deadline as i64 <= timestamp
+    {
+        return Err(SolanaBridgeError::RelayRoundExpired.into());
+    }
+
+    Ok(())
+}
+
+pub fn get_associated_relay_eviction_address(
+    program_id: &Pubkey,
+    round_number: u32,
+    relay: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"eviction", &round_number.to_le_bytes(), &relay.to_bytes()],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_required_votes(relays_count: usize, settings: &Settings) -> u32 {
+    get_quorum_votes(relays_count, settings.quorum_fraction(), settings)
+}
+
+/// Required vote weight of proposal voted by the round. Quorum share is the one
+/// snapshotted when the round was created, so later settings changes don't affect it
+pub fn get_round_quorum(relays_count: usize, relay_round: &RelayRound, settings: &Settings) -> u32 {
+    get_quorum_votes(relays_count, relay_round.quorum_fraction(), settings)
+}
+
+/// Required vote weight of proposal, reduced once Relays are evicted from the round
+pub fn get_round_required_votes(
+    required_votes: u32,
+    relay_round: &RelayRound,
+    settings: &Settings,
+) -> u32 {
+    required_votes.min(get_round_quorum(
+        relay_round.total_weight() as usize,
+        relay_round,
+        settings,
+    ))
+}
+
+fn get_quorum_votes(
+    relays_count: usize,
+    (numerator, denominator): (u32, u32),
+    settings: &Settings,
+) -> u32 {
+    let required_votes = (relays_count as u64 * numerator as u64 / denominator as u64 + 1) as u32;
+    required_votes.max(settings.min_required_votes)
+}
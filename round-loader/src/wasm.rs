@@ -5,11 +5,13 @@ use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::{JsCast, JsValue};
 
+use solana_program::hash::hash;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 use solana_program::{system_program, sysvar};
 
+use bridge_utils::errors::SolanaBridgeError;
 use bridge_utils::state::*;
 use bridge_utils::types::*;
 
@@ -23,6 +25,34 @@ pub fn get_relay_round_address_request(round_number: u32) -> Result<JsValue, JsV
     return serde_wasm_bindgen::to_value(&relay_round_pubkey).handle_error();
 }
 
+#[wasm_bindgen(js_name = "getProposalAddress")]
+pub fn get_proposal_address_request(
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: String,
+    new_round_number: u32,
+    new_relays: JsValue,
+    new_round_end: u32,
+) -> Result<JsValue, JsValue> {
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+
+    let event_data = proposal_event(new_round_number, new_relays, new_round_end)?
+        .data
+        .try_to_vec()
+        .handle_error()?;
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+    );
+
+    return serde_wasm_bindgen::to_value(&proposal_pubkey).handle_error();
+}
+
 #[wasm_bindgen(js_name = "initialize")]
 pub fn initialize_ix(
     funder_pubkey: String,
@@ -73,6 +103,7 @@ pub fn update_settings_ix(
     round_submitter: Option<String>,
     min_required_votes: Option<u32>,
     round_ttl: Option<u32>,
+    guardian: Option<String>,
 ) -> Result<JsValue, JsValue> {
     let program_id = &id();
 
@@ -86,11 +117,81 @@ pub fn update_settings_ix(
         .transpose()
         .handle_error()?;
 
+    let guardian = guardian
+        .map(|value| Pubkey::from_str(value.as_str()))
+        .transpose()
+        .handle_error()?;
+
     let data = RoundLoaderInstruction::UpdateSettings {
         current_round_number,
         round_submitter,
         min_required_votes,
         round_ttl,
+        guardian,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(setting_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "updateRoundHandoverPeriod")]
+pub fn update_round_handover_period_ix(
+    author_pubkey: String,
+    round_handover_period: u32,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+
+    let setting_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let program_data_pubkey = bridge_utils::helper::get_programdata_address(program_id);
+
+    let data = RoundLoaderInstruction::UpdateRoundHandoverPeriod {
+        round_handover_period,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(setting_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "updateQuorum")]
+pub fn update_quorum_ix(
+    author_pubkey: String,
+    quorum_numerator: u32,
+    quorum_denominator: u32,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+
+    let setting_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let program_data_pubkey = bridge_utils::helper::get_programdata_address(program_id);
+
+    let data = RoundLoaderInstruction::UpdateQuorum {
+        quorum_numerator,
+        quorum_denominator,
     }
     .try_to_vec()
     .handle_error()?;
@@ -154,11 +255,252 @@ pub fn create_relay_round_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
+#[wasm_bindgen(js_name = "createWeightedRelayRound")]
+pub fn create_weighted_relay_round_ix(
+    funder_pubkey: String,
+    creator_pubkey: String,
+    round_number: u32,
+    round_end: u32,
+    relays: JsValue,
+    weights: JsValue,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let creator_pubkey = Pubkey::from_str(creator_pubkey.as_str()).handle_error()?;
+
+    let setting_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let relays: Vec<String> = serde_wasm_bindgen::from_value(relays).handle_error()?;
+    let relays = relays
+        .into_iter()
+        .map(|x| Pubkey::from_str(x.as_str()))
+        .collect::<Result<Vec<_>, _>>()
+        .handle_error()?;
+
+    let weights: Vec<u32> = serde_wasm_bindgen::from_value(weights).handle_error()?;
+
+    let data = RoundLoaderInstruction::CreateWeightedRelayRound {
+        round_number,
+        relays,
+        weights,
+        round_end,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(creator_pubkey, true),
+            AccountMeta::new(setting_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "migrateRelayRound")]
+pub fn migrate_relay_round_ix(
+    funder_pubkey: String,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::MigrateRelayRound
+        .try_to_vec()
+        .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "createProposal")]
+pub fn create_proposal_ix(
+    funder_pubkey: String,
+    creator_pubkey: String,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: String,
+    new_round_number: u32,
+    new_relays: JsValue,
+    new_round_end: u32,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let creator_pubkey = Pubkey::from_str(creator_pubkey.as_str()).handle_error()?;
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+
+    let event_data = proposal_event(new_round_number, new_relays, new_round_end)?
+        .data
+        .try_to_vec()
+        .handle_error()?;
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+    );
+
+    let data = RoundLoaderInstruction::CreateProposal {
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        event_data: hash(&event_data),
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(creator_pubkey, true),
+            AccountMeta::new(proposal_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+/// Instructions writing a new Relay Round into the proposal in chunks of `chunk_size` bytes
+#[wasm_bindgen(js_name = "writeProposal")]
+pub fn write_proposal_ix(
+    proposal_pubkey: String,
+    new_round_number: u32,
+    new_relays: JsValue,
+    new_round_end: u32,
+    chunk_size: u32,
+) -> Result<JsValue, JsValue> {
+    let proposal_pubkey = Pubkey::from_str(proposal_pubkey.as_str()).handle_error()?;
+
+    if chunk_size == 0 {
+        return Err("Chunk size must be positive").handle_error();
+    }
+
+    let write_data = proposal_event(new_round_number, new_relays, new_round_end)?
+        .try_to_vec()
+        .handle_error()?;
+
+    let ixs = write_data
+        .chunks(chunk_size as usize)
+        .zip(0..)
+        .map(|(chunk, i)| {
+            let data = RoundLoaderInstruction::WriteProposal {
+                offset: i * chunk_size,
+                bytes: chunk.to_vec(),
+            }
+            .try_to_vec()
+            .handle_error()?;
+
+            Ok(Instruction {
+                program_id: id(),
+                accounts: vec![AccountMeta::new(proposal_pubkey, false)],
+                data,
+            })
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+
+    return serde_wasm_bindgen::to_value(&ixs).handle_error();
+}
+
+#[wasm_bindgen(js_name = "finalizeProposal")]
+pub fn finalize_proposal_ix(
+    funder_pubkey: String,
+    proposal_pubkey: String,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let proposal_pubkey = Pubkey::from_str(proposal_pubkey.as_str()).handle_error()?;
+
+    let settings_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::FinalizeProposal
+        .try_to_vec()
+        .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(proposal_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "voteForProposal")]
+pub fn vote_for_proposal_ix(
+    voter_pubkey: String,
+    proposal_pubkey: String,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let voter_pubkey = Pubkey::from_str(voter_pubkey.as_str()).handle_error()?;
+    let proposal_pubkey = Pubkey::from_str(proposal_pubkey.as_str()).handle_error()?;
+
+    let setting_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::VoteForProposal {
+        vote: Vote::Confirm,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(voter_pubkey, true),
+            AccountMeta::new(proposal_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(setting_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
 #[wasm_bindgen(js_name = "execute")]
 pub fn execute_ix(
     funder_pubkey: String,
     proposal_pubkey: String,
     round_number: u32,
+    voting_round_number: u32,
 ) -> Result<JsValue, JsValue> {
     let program_id = &id();
 
@@ -167,6 +509,7 @@ pub fn execute_ix(
 
     let settings_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
     let relay_round_pubkey = get_relay_round_address(round_number);
+    let voting_relay_round_pubkey = get_relay_round_address(voting_round_number);
 
     let data = RoundLoaderInstruction::ExecuteProposal
         .try_to_vec()
@@ -181,6 +524,7 @@ pub fn execute_ix(
             AccountMeta::new(relay_round_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(voting_relay_round_pubkey, false),
         ],
         data,
     };
@@ -225,6 +569,132 @@ pub fn execute_by_admin_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
+#[wasm_bindgen(js_name = "createRelayEviction")]
+pub fn create_relay_eviction_ix(
+    funder_pubkey: String,
+    guardian_pubkey: String,
+    round_number: u32,
+    relay: String,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let guardian_pubkey = Pubkey::from_str(guardian_pubkey.as_str()).handle_error()?;
+    let relay = Pubkey::from_str(relay.as_str()).handle_error()?;
+
+    let settings_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let eviction_pubkey = get_relay_eviction_address(round_number, &relay);
+
+    let data = RoundLoaderInstruction::CreateRelayEviction {
+        round_number,
+        relay,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(guardian_pubkey, true),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new(eviction_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "voteForRelayEviction")]
+pub fn vote_for_relay_eviction_ix(
+    voter_pubkey: String,
+    round_number: u32,
+    relay: String,
+) -> Result<JsValue, JsValue> {
+    let voter_pubkey = Pubkey::from_str(voter_pubkey.as_str()).handle_error()?;
+    let relay = Pubkey::from_str(relay.as_str()).handle_error()?;
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let eviction_pubkey = get_relay_eviction_address(round_number, &relay);
+
+    let data = RoundLoaderInstruction::VoteForRelayEviction
+        .try_to_vec()
+        .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(voter_pubkey, true),
+            AccountMeta::new(eviction_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "reportRelayMisbehavior")]
+pub fn report_relay_misbehavior_ix(
+    reporter_pubkey: String,
+    round_number: u32,
+    relay: String,
+    first_proposal_pubkey: String,
+    second_proposal_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let reporter_pubkey = Pubkey::from_str(reporter_pubkey.as_str()).handle_error()?;
+    let relay = Pubkey::from_str(relay.as_str()).handle_error()?;
+    let first_proposal_pubkey = Pubkey::from_str(first_proposal_pubkey.as_str()).handle_error()?;
+    let second_proposal_pubkey =
+        Pubkey::from_str(second_proposal_pubkey.as_str()).handle_error()?;
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::ReportRelayMisbehavior {
+        round_number,
+        relay,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(reporter_pubkey, true),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(first_proposal_pubkey, false),
+            AccountMeta::new_readonly(second_proposal_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getBridgeErrors")]
+pub fn get_bridge_errors() -> Result<JsValue, JsValue> {
+    let errors = (0..)
+        .map_while(|code| SolanaBridgeError::try_from(code).ok())
+        .map(WasmBridgeError::from)
+        .collect::<Vec<_>>();
+
+    return serde_wasm_bindgen::to_value(&errors).handle_error();
+}
+
+#[wasm_bindgen(js_name = "mapTransactionError")]
+pub fn map_transaction_error(logs: JsValue) -> Result<JsValue, JsValue> {
+    let logs: Vec<String> = serde_wasm_bindgen::from_value(logs).handle_error()?;
+
+    let error = SolanaBridgeError::from_logs(&logs).map(WasmBridgeError::from);
+
+    return serde_wasm_bindgen::to_value(&error).handle_error();
+}
+
 #[wasm_bindgen(js_name = "unpackSettings")]
 pub fn unpack_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
     let settings = Settings::unpack(&data).handle_error()?;
@@ -236,6 +706,10 @@ pub fn unpack_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
         round_submitter: settings.round_submitter,
         min_required_votes: settings.min_required_votes,
         round_ttl: settings.round_ttl,
+        guardian: settings.guardian,
+        quorum_numerator: settings.quorum_numerator,
+        quorum_denominator: settings.quorum_denominator,
+        round_handover_period: settings.round_handover_period,
     };
 
     return serde_wasm_bindgen::to_value(&s).handle_error();
@@ -251,6 +725,9 @@ pub fn unpack_relay_round(data: Vec<u8>) -> Result<JsValue, JsValue> {
         round_number: relay_round.round_number,
         round_end: relay_round.round_end,
         relays: relay_round.relays,
+        weights: relay_round.weights,
+        quorum_numerator: relay_round.quorum_numerator,
+        quorum_denominator: relay_round.quorum_denominator,
     };
 
     return serde_wasm_bindgen::to_value(&rr).handle_error();
@@ -275,6 +752,42 @@ pub fn unpack_relay_round_proposal(data: Vec<u8>) -> Result<JsValue, JsValue> {
     return serde_wasm_bindgen::to_value(&rrp).handle_error();
 }
 
+#[wasm_bindgen(js_name = "unpackRelayEviction")]
+pub fn unpack_relay_eviction(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let relay_eviction = RelayEviction::unpack(&data).handle_error()?;
+
+    let re = WasmRelayEviction {
+        is_initialized: relay_eviction.is_initialized,
+        account_kind: relay_eviction.account_kind,
+        round_number: relay_eviction.round_number,
+        relay: relay_eviction.relay,
+        required_votes: relay_eviction.required_votes,
+        executed: relay_eviction.executed,
+        signers: relay_eviction.signers,
+    };
+
+    return serde_wasm_bindgen::to_value(&re).handle_error();
+}
+
+fn proposal_event(
+    round_number: u32,
+    relays: JsValue,
+    round_end: u32,
+) -> Result<RelayRoundProposalEventWithLen, JsValue> {
+    let relays: Vec<String> = serde_wasm_bindgen::from_value(relays).handle_error()?;
+    let relays = relays
+        .into_iter()
+        .map(|x| Pubkey::from_str(x.as_str()))
+        .collect::<Result<Vec<_>, _>>()
+        .handle_error()?;
+
+    Ok(RelayRoundProposalEventWithLen::new(
+        round_number,
+        relays,
+        round_end,
+    ))
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct WasmSettings {
     pub is_initialized: bool,
@@ -283,6 +796,10 @@ pub struct WasmSettings {
     pub round_submitter: Pubkey,
     pub min_required_votes: u32,
     pub round_ttl: u32,
+    pub guardian: Pubkey,
+    pub quorum_numerator: u32,
+    pub quorum_denominator: u32,
+    pub round_handover_period: u32,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -292,6 +809,9 @@ pub struct WasmRelayRound {
     pub round_number: u32,
     pub round_end: u32,
     pub relays: Vec<Pubkey>,
+    pub weights: Vec<u32>,
+    pub quorum_numerator: u32,
+    pub quorum_denominator: u32,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -307,6 +827,34 @@ pub struct WasmRelayRoundProposal {
     pub signers: Vec<Vote>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct WasmRelayEviction {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    pub relay: Pubkey,
+    pub required_votes: u32,
+    pub executed: bool,
+    pub signers: Vec<Vote>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmBridgeError {
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+}
+
+impl From<SolanaBridgeError> for WasmBridgeError {
+    fn from(error: SolanaBridgeError) -> Self {
+        Self {
+            code: error as u32,
+            name: format!("{:?}", error),
+            message: error.to_string(),
+        }
+    }
+}
+
 impl<T, E> HandleError for Result<T, E>
 where
     E: ToString,
@@ -4,7 +4,7 @@ use borsh::BorshSerialize;
 use bridge_utils::types::Vote;
 use std::str::FromStr;
 
-use bridge_utils::state::AccountKind;
+use bridge_utils::state::{AccountKind, PDA};
 use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
 use solana_program::hash::hash;
 use solana_program::rent::Rent;
@@ -162,6 +162,7 @@ async fn test_init_relay_loader() {
             Some(new_round_submitter),
             Some(new_min_required_votes),
             None,
+            None,
         )],
         Some(&initializer.pubkey()),
     );
@@ -183,6 +184,76 @@ async fn test_init_relay_loader() {
     assert_eq!(settings_data.current_round_number, new_current_round_number);
     assert_eq!(settings_data.round_submitter, new_round_submitter);
     assert_eq!(settings_data.min_required_votes, new_min_required_votes);
+
+    // Update Quorum
+    let new_quorum_numerator = 3;
+    let new_quorum_denominator = 4;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_quorum_ix(
+            &initializer.pubkey(),
+            new_quorum_numerator,
+            new_quorum_denominator,
+        )],
+        Some(&initializer.pubkey()),
+    );
+    transaction.sign(&[&initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.quorum_numerator, new_quorum_numerator);
+    assert_eq!(settings_data.quorum_denominator, new_quorum_denominator);
+    assert_eq!(get_required_votes(20, &settings_data), 16);
+
+    // Quorum below half of Relays is rejected
+    let mut transaction = Transaction::new_with_payer(
+        &[update_quorum_ix(&initializer.pubkey(), 1, 3)],
+        Some(&initializer.pubkey()),
+    );
+    transaction.sign(&[&initializer], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Update Round Handover Period
+    let new_round_handover_period = 1800;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_round_handover_period_ix(
+            &initializer.pubkey(),
+            new_round_handover_period,
+        )],
+        Some(&initializer.pubkey()),
+    );
+    transaction.sign(&[&initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(
+        settings_data.round_handover_period,
+        new_round_handover_period
+    );
 }
 
 #[tokio::test]
@@ -240,6 +311,10 @@ async fn test_create_proposal() {
         round_submitter: Pubkey::new_unique(),
         min_required_votes: 1,
         round_ttl: 1209600,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -269,6 +344,9 @@ async fn test_create_proposal() {
         round_number,
         round_end: chrono::Utc::now().timestamp() as u32,
         relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
     let mut relay_round_packed = vec![0; RelayRound::LEN];
@@ -443,6 +521,7 @@ async fn test_create_proposal() {
             &funder.pubkey(),
             &proposal_pubkey,
             new_round_number,
+            round_number,
         )],
         Some(&funder.pubkey()),
     );
@@ -462,6 +541,7 @@ async fn test_create_proposal() {
 
     let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
     assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+    assert!(proposal_info.data().starts_with(&PROPOSAL_DISCRIMINATOR));
 
     // Check created Relay Round
     let relay_round_address = get_relay_round_address(new_round_number);
@@ -471,6 +551,9 @@ async fn test_create_proposal() {
         .await
         .expect("get_account")
         .expect("account");
+    assert!(relay_round_account
+        .data()
+        .starts_with(&RELAY_ROUND_DISCRIMINATOR));
     let relay_round_data =
         RelayRound::unpack(relay_round_account.data()).expect("relay round unpack");
 
@@ -555,6 +638,10 @@ async fn test_create_proposal_and_execute_by_admin() {
         round_submitter: round_submitter.pubkey(),
         min_required_votes: 1,
         round_ttl: 1209600,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -584,6 +671,9 @@ async fn test_create_proposal_and_execute_by_admin() {
         round_number,
         round_end: chrono::Utc::now().timestamp() as u32,
         relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
     let mut relay_round_packed = vec![0; RelayRound::LEN];
@@ -778,3 +868,389 @@ async fn test_create_proposal_and_execute_by_admin() {
 
     assert_eq!(settings_data.current_round_number, new_round_number);
 }
+
+#[tokio::test]
+async fn test_relay_eviction() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let guardian = Keypair::new();
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..4 {
+        relays.push(Keypair::new());
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        guardian: guardian.pubkey(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32 + 1209600,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Relay Eviction
+    let evicted_relay = relays[3].pubkey();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_relay_eviction_ix(
+            &funder.pubkey(),
+            &guardian.pubkey(),
+            round_number,
+            evicted_relay,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let eviction_address = get_relay_eviction_address(round_number, &evicted_relay);
+
+    let eviction_info = banks_client
+        .get_account(eviction_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let eviction_data = RelayEviction::unpack(eviction_info.data()).expect("eviction unpack");
+
+    assert_eq!(eviction_data.relay, evicted_relay);
+    assert_eq!(eviction_data.required_votes, 3);
+    assert_eq!(eviction_data.executed, false);
+
+    // Evicted Relay cannot vote
+    let mut transaction = Transaction::new_with_payer(
+        &[vote_for_relay_eviction_ix(
+            &relays[3].pubkey(),
+            round_number,
+            &evicted_relay,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &relays[3]], recent_blockhash);
+
+    let _ = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("evicted relay vote");
+
+    // Vote by remaining Relays
+    for relay in &relays[..3] {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_relay_eviction_ix(
+                &relay.pubkey(),
+                round_number,
+                &evicted_relay,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, relay], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    let eviction_info = banks_client
+        .get_account(eviction_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let eviction_data = RelayEviction::unpack(eviction_info.data()).expect("eviction unpack");
+
+    assert_eq!(eviction_data.executed, true);
+
+    // Check Relay Round
+    let relay_round_info = banks_client
+        .get_account(relay_round_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+
+    assert_eq!(relay_round_data.relays.len(), 4);
+    assert_eq!(relay_round_data.relays[3], Pubkey::default());
+    assert_eq!(relay_round_data.active_relays(), 3);
+}
+
+#[tokio::test]
+async fn test_report_relay_misbehavior() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    let round_number: u32 = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..4 {
+        relays.push(Keypair::new());
+    }
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32 + 1209600,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add conflicting Proposal Accounts for the same event
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let misbehaving_relay = relays[2].pubkey();
+
+    let mut proposals = vec![];
+    for _ in 0..2 {
+        let write_data =
+            RelayRoundProposalEventWithLen::new(round_number + 1, vec![Pubkey::new_unique()], 0);
+
+        let serialized_write_data = write_data
+            .data
+            .try_to_vec()
+            .expect("serialize proposal event data");
+
+        let proposal_address = get_proposal_address(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            &serialized_write_data,
+        );
+
+        let proposal_nonce = Pubkey::find_program_address(
+            &bridge_utils::helper::get_proposal_seeds(
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+                &hash(&serialized_write_data).to_bytes(),
+            )
+            .iter()
+            .map(Vec::as_slice)
+            .collect::<Vec<_>>(),
+            &round_loader::id(),
+        )
+        .1;
+
+        let mut signers = vec![Vote::None; relays.len()];
+        signers[2] = Vote::Confirm;
+
+        let proposal_data = RelayRoundProposal {
+            is_initialized: true,
+            account_kind: AccountKind::Proposal(proposal_nonce, None),
+            author: Pubkey::new_unique(),
+            round_number,
+            required_votes: 3,
+            pda: PDA {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+            },
+            event: write_data,
+            meta: RelayRoundProposalMetaWithLen::new(),
+            signers,
+        };
+
+        let mut proposal_packed = vec![0; RelayRoundProposal::LEN];
+        RelayRoundProposal::pack(proposal_data, &mut proposal_packed).unwrap();
+
+        program_test.add_account(
+            proposal_address,
+            Account {
+                lamports: Rent::default().minimum_balance(RelayRoundProposal::LEN),
+                data: proposal_packed,
+                owner: round_loader::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        proposals.push(proposal_address);
+    }
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Relay that didn't vote cannot be slashed
+    let mut transaction = Transaction::new_with_payer(
+        &[report_relay_misbehavior_ix(
+            &funder.pubkey(),
+            round_number,
+            relays[1].pubkey(),
+            proposals[0],
+            proposals[1],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let _ = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("relay without conflicting votes");
+
+    // Report misbehaving Relay
+    let mut transaction = Transaction::new_with_payer(
+        &[report_relay_misbehavior_ix(
+            &funder.pubkey(),
+            round_number,
+            misbehaving_relay,
+            proposals[0],
+            proposals[1],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Relay Round
+    let relay_round_info = banks_client
+        .get_account(relay_round_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+
+    assert_eq!(relay_round_data.relays.len(), 4);
+    assert_eq!(relay_round_data.relays[2], Pubkey::default());
+    assert_eq!(relay_round_data.active_relays(), 3);
+}
+
+#[test]
+fn test_unpack_legacy_relay_round() {
+    let relays = vec![Pubkey::new_unique(); 100];
+
+    // Relay Round created before vote weights
+    let mut legacy_packed = vec![0; 3215];
+    let legacy_data = (
+        true,
+        AccountKind::RelayRound(255),
+        1u32,
+        1759950990u32,
+        relays.clone(),
+    )
+        .try_to_vec()
+        .unwrap();
+    legacy_packed.copy_from_slice(&legacy_data);
+
+    let relay_round_data = RelayRound::unpack(&legacy_packed).expect("relay round unpack");
+
+    assert_eq!(relay_round_data.relays, relays);
+    assert_eq!(relay_round_data.weight(0), 1);
+    assert_eq!(relay_round_data.total_weight(), relays.len() as u64);
+    assert_eq!(
+        relay_round_data.quorum_fraction(),
+        (DEFAULT_QUORUM_NUMERATOR, DEFAULT_QUORUM_DENOMINATOR)
+    );
+
+    // Weights don't fit until the round is migrated
+    assert!(RelayRound::pack(relay_round_data, &mut legacy_packed).is_err());
+}
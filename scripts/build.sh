@@ -24,6 +24,7 @@ while [[ $# -gt 0 ]]; do
         cargo-build-sbf --manifest-path=./token-proxy/Cargo.toml --sbf-out-dir=dist/program
         cargo-build-sbf --manifest-path=./round-loader/Cargo.toml --sbf-out-dir=dist/program
         cargo-build-sbf --manifest-path=./native-proxy/Cargo.toml --sbf-out-dir=dist/program
+        cargo-build-sbf --manifest-path=./event-proxy/Cargo.toml --sbf-out-dir=dist/program
       ;;
       -w|--wasm)
         shift # past argument
@@ -37,6 +38,7 @@ while [[ $# -gt 0 ]]; do
         cargo build --release --manifest-path=./token-proxy/Cargo.toml  --features=bindings
         cargo build --release --manifest-path=./round-loader/Cargo.toml --features=bindings
         cargo build --release --manifest-path=./native-proxy/Cargo.toml --features=bindings
+        cargo build --release --manifest-path=./event-proxy/Cargo.toml --features=bindings
       ;;
       -t|--tests)
         shift # past argument
@@ -44,6 +46,7 @@ while [[ $# -gt 0 ]]; do
         cargo-test-sbf --manifest-path=./token-proxy/Cargo.toml
         cargo-test-sbf --manifest-path=./round-loader/Cargo.toml
         cargo-test-sbf --manifest-path=./native-proxy/Cargo.toml
+        cargo-test-sbf --manifest-path=./event-proxy/Cargo.toml
       ;;
       *) # unknown option
         echo 'ERROR: Unexpected'
@@ -0,0 +1,651 @@
+use borsh::BorshDeserialize;
+use futures::channel::{mpsc, oneshot};
+use futures::{Stream, StreamExt};
+use thiserror::Error;
+
+use bridge_utils::amount::to_solana_amount;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::Proposal;
+use bridge_utils::types::{EverAddress, UInt256, Vote};
+
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::nonblocking::pubsub_client::{PubsubClient, PubsubClientError};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_client::rpc_request::MAX_MULTIPLE_ACCOUNTS;
+use solana_program::clock::Clock;
+use solana_program::instruction::Instruction;
+use solana_program::program_pack::{IsInitialized, Pack};
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar;
+use solana_sdk::account::{from_account, Account};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+use spl_associated_token_account::get_associated_token_address;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use token_proxy::{
+    Denylist, Settings, TokenKind, TokenSettings, WithdrawalMultiTokenEver,
+    WithdrawalMultiTokenSol, WithdrawalPreview, WithdrawalTokenMeta, WithdrawalTokenStatus,
+};
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("RPC request failed: {0}")]
+    Rpc(#[from] solana_client::client_error::ClientError),
+    #[error("Account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("Failed to decode account {0}")]
+    InvalidAccountData(Pubkey),
+    #[error("Subscription failed: {0}")]
+    Pubsub(#[from] PubsubClientError),
+    #[error("Bridge error: {0}")]
+    Bridge(#[from] SolanaBridgeError),
+}
+
+/// Voting state of a withdrawal request
+#[derive(Debug, Clone)]
+pub struct WithdrawalState {
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub confirmations: u32,
+    pub rejections: u32,
+    pub status: WithdrawalTokenStatus,
+    pub bounty: u64,
+}
+
+impl WithdrawalState {
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmations >= self.required_votes
+    }
+}
+
+/// Token of a withdrawal
+#[derive(Debug, Clone)]
+pub enum WithdrawalToken {
+    Ever(EverAddress),
+    Sol(Pubkey),
+}
+
+/// Withdrawal to be previewed by `BridgeClient::simulate_withdrawal`
+#[derive(Debug, Clone)]
+pub struct WithdrawalParams {
+    pub token: WithdrawalToken,
+    pub author: Pubkey,
+    pub recipient: Pubkey,
+    // Amount as in the withdrawal event, with Ever decimals for Ever tokens
+    pub amount: u128,
+    pub chain_id: Option<u64>,
+}
+
+/// Async client of Token Proxy. Builds instructions with PDAs derived
+/// from their seeds, creates missing token accounts and waits for
+/// transactions to be confirmed with the client commitment.
+pub struct BridgeClient {
+    rpc: RpcClient,
+}
+
+impl BridgeClient {
+    pub fn new(url: String) -> Self {
+        Self::with_commitment(url, CommitmentConfig::confirmed())
+    }
+
+    pub fn with_commitment(url: String, commitment: CommitmentConfig) -> Self {
+        Self {
+            rpc: RpcClient::new_with_commitment(url, commitment),
+        }
+    }
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    /// Sign transaction with payer and additional signers, send and confirm it
+    pub async fn send_instructions(
+        &self,
+        payer: &Keypair,
+        signers: &[&Keypair],
+        instructions: &[Instruction],
+    ) -> Result<Signature, ClientError> {
+        let recent_blockhash = self.rpc.get_latest_blockhash().await?;
+
+        let mut transaction_signers = vec![payer];
+        transaction_signers.extend_from_slice(signers);
+
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &transaction_signers,
+            recent_blockhash,
+        );
+
+        let signature = self.rpc.send_and_confirm_transaction(&transaction).await?;
+        Ok(signature)
+    }
+
+    /// Deposit Solana token to Ever. Author pays for the deposit account.
+    /// Returns transaction signature and deposit address
+    #[allow(clippy::too_many_arguments)]
+    pub async fn deposit_sol(
+        &self,
+        author: &Keypair,
+        mint: &Pubkey,
+        deposit_seed: u128,
+        name: String,
+        symbol: String,
+        amount: u64,
+        recipient: EverAddress,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+    ) -> Result<(Signature, Pubkey), ClientError> {
+        let author_pubkey = author.pubkey();
+        let author_token_pubkey = get_associated_token_address(&author_pubkey, mint);
+
+        let ix = token_proxy::deposit_multi_token_sol_ix(
+            author_pubkey,
+            author_pubkey,
+            author_token_pubkey,
+            *mint,
+            deposit_seed,
+            name,
+            symbol,
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        );
+
+        let signature = self.send_instructions(author, &[], &[ix]).await?;
+
+        Ok((signature, token_proxy::get_deposit_address(deposit_seed)))
+    }
+
+    /// Request withdrawal of Ever token. Returns transaction signature and withdrawal address
+    #[allow(clippy::too_many_arguments)]
+    pub async fn request_withdrawal_ever(
+        &self,
+        author: &Keypair,
+        round_number: u32,
+        event_timestamp: u32,
+        event_transaction_lt: u64,
+        event_configuration: Pubkey,
+        token: EverAddress,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        recipient: Pubkey,
+        amount: u128,
+        payload: Vec<u8>,
+        attached_amount: u64,
+    ) -> Result<(Signature, Pubkey), ClientError> {
+        let withdrawal_pubkey = token_proxy::get_withdrawal_ever_address(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            token,
+            name.clone(),
+            symbol.clone(),
+            decimals,
+            recipient,
+            amount,
+            payload.clone(),
+        );
+
+        let ix = token_proxy::withdrawal_multi_token_ever_request_ix(
+            author.pubkey(),
+            author.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            token,
+            name,
+            symbol,
+            decimals,
+            recipient,
+            amount,
+            payload,
+            attached_amount,
+        );
+
+        let signature = self.send_instructions(author, &[], &[ix]).await?;
+
+        Ok((signature, withdrawal_pubkey))
+    }
+
+    /// Request withdrawal of Solana token. Returns transaction signature and withdrawal address
+    #[allow(clippy::too_many_arguments)]
+    pub async fn request_withdrawal_sol(
+        &self,
+        author: &Keypair,
+        round_number: u32,
+        event_timestamp: u32,
+        event_transaction_lt: u64,
+        event_configuration: Pubkey,
+        mint: Pubkey,
+        recipient: Pubkey,
+        amount: u128,
+        payload: Vec<u8>,
+        attached_amount: u64,
+    ) -> Result<(Signature, Pubkey), ClientError> {
+        let withdrawal_pubkey = token_proxy::get_withdrawal_sol_address(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            mint,
+            recipient,
+            amount,
+            payload.clone(),
+        );
+
+        let ix = token_proxy::withdrawal_multi_token_sol_request_ix(
+            author.pubkey(),
+            author.pubkey(),
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            mint,
+            round_number,
+            recipient,
+            amount,
+            payload,
+            attached_amount,
+        );
+
+        let signature = self.send_instructions(author, &[], &[ix]).await?;
+
+        Ok((signature, withdrawal_pubkey))
+    }
+
+    /// Vote for withdrawal request by Relay of the withdrawal round
+    pub async fn vote(
+        &self,
+        relay: &Keypair,
+        withdrawal_pubkey: &Pubkey,
+        vote: Vote,
+    ) -> Result<Signature, ClientError> {
+        let state = self.fetch_withdrawal_state(withdrawal_pubkey).await?;
+
+        let ix = token_proxy::vote_for_withdrawal_request_ix(
+            relay.pubkey(),
+            *withdrawal_pubkey,
+            state.round_number,
+            vote,
+        );
+
+        self.send_instructions(relay, &[], &[ix]).await
+    }
+
+    /// Execute confirmed withdrawal of Ever token, creating recipient token account if needed
+    pub async fn withdraw_ever(
+        &self,
+        payer: &Keypair,
+        withdrawal_pubkey: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let withdrawal = self
+            .fetch_account::<WithdrawalMultiTokenEver>(withdrawal_pubkey)
+            .await?;
+
+        let token = withdrawal.event.data.token;
+        let recipient = withdrawal.event.data.recipient;
+        let mint = token_proxy::get_mint_address(&token);
+        let proposal_index = token_proxy::get_proposal_index_address(
+            withdrawal.pda.event_timestamp,
+            withdrawal.pda.event_transaction_lt,
+            &withdrawal.pda.event_configuration,
+            withdrawal.chain_id,
+        );
+
+        let ixs = [
+            create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                &recipient,
+                &mint,
+                &spl_token::id(),
+            ),
+            token_proxy::withdrawal_ever_ix(
+                *withdrawal_pubkey,
+                get_associated_token_address(&recipient, &mint),
+                recipient,
+                token,
+                withdrawal.round_number,
+                proposal_index,
+            ),
+        ];
+
+        self.send_instructions(payer, &[], &ixs).await
+    }
+
+    /// Execute confirmed withdrawal of Solana token, creating recipient token account if needed
+    pub async fn withdraw_sol(
+        &self,
+        payer: &Keypair,
+        withdrawal_pubkey: &Pubkey,
+    ) -> Result<Signature, ClientError> {
+        let withdrawal = self
+            .fetch_account::<WithdrawalMultiTokenSol>(withdrawal_pubkey)
+            .await?;
+
+        let mint = withdrawal.event.data.mint;
+        let recipient = withdrawal.event.data.recipient;
+        let proposal_index = token_proxy::get_proposal_index_address(
+            withdrawal.pda.event_timestamp,
+            withdrawal.pda.event_transaction_lt,
+            &withdrawal.pda.event_configuration,
+            withdrawal.chain_id,
+        );
+
+        let ixs = [
+            create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                &recipient,
+                &mint,
+                &spl_token::id(),
+            ),
+            token_proxy::withdrawal_sol_ix(
+                *withdrawal_pubkey,
+                get_associated_token_address(&recipient, &mint),
+                recipient,
+                mint,
+                withdrawal.round_number,
+                proposal_index,
+            ),
+        ];
+
+        self.send_instructions(payer, &[], &ixs).await
+    }
+
+    /// Voting state and status of Ever or Solana token withdrawal
+    pub async fn fetch_withdrawal_state(
+        &self,
+        withdrawal_pubkey: &Pubkey,
+    ) -> Result<WithdrawalState, ClientError> {
+        let data = self.fetch_account_data(withdrawal_pubkey).await?;
+        decode_withdrawal_state(&data).ok_or(ClientError::InvalidAccountData(*withdrawal_pubkey))
+    }
+
+    /// Addresses of all withdrawals in ascending order, so that their states can be fetched by pages
+    pub async fn fetch_withdrawal_addresses(&self) -> Result<Vec<Pubkey>, ClientError> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(withdrawal_filters()),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: 0,
+                    length: 0,
+                }),
+                commitment: Some(self.rpc.commitment()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut addresses = self
+            .rpc
+            .get_program_accounts_with_config(&token_proxy::id(), config)
+            .await?
+            .into_iter()
+            .map(|(pubkey, _)| pubkey)
+            .collect::<Vec<_>>();
+        addresses.sort();
+
+        Ok(addresses)
+    }
+
+    /// Voting states of withdrawals. Closed withdrawals are skipped
+    pub async fn fetch_withdrawal_states(
+        &self,
+        withdrawal_pubkeys: &[Pubkey],
+    ) -> Result<Vec<(Pubkey, WithdrawalState)>, ClientError> {
+        let mut states = Vec::with_capacity(withdrawal_pubkeys.len());
+
+        for pubkeys in withdrawal_pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self.rpc.get_multiple_accounts(pubkeys).await?;
+
+            for (pubkey, account) in pubkeys.iter().zip(accounts) {
+                if let Some(account) = account {
+                    let state = decode_withdrawal_state(&account.data)
+                        .ok_or(ClientError::InvalidAccountData(*pubkey))?;
+                    states.push((*pubkey, state));
+                }
+            }
+        }
+
+        Ok(states)
+    }
+
+    /// Withdrawals with the given status, e.g. waiting for approve or for execution
+    pub async fn list_pending_withdrawals(
+        &self,
+        status: WithdrawalTokenStatus,
+    ) -> Result<Vec<(Pubkey, WithdrawalState)>, ClientError> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(withdrawal_filters()),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.rpc.commitment()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let accounts = self
+            .rpc
+            .get_program_accounts_with_config(&token_proxy::id(), config)
+            .await?;
+
+        let mut withdrawals = vec![];
+        for (pubkey, account) in accounts {
+            let state = decode_withdrawal_state(&account.data)
+                .ok_or(ClientError::InvalidAccountData(pubkey))?;
+            if state.status == status {
+                withdrawals.push((pubkey, state));
+            }
+        }
+
+        Ok(withdrawals)
+    }
+
+    pub async fn fetch_token_settings(
+        &self,
+        token_settings_pubkey: &Pubkey,
+    ) -> Result<TokenSettings, ClientError> {
+        self.fetch_account::<TokenSettings>(token_settings_pubkey)
+            .await
+    }
+
+    /// Preview fee, transferred amount and limits of a withdrawal as if it was
+    /// confirmed now. Nothing is sent to the network
+    pub async fn simulate_withdrawal(
+        &self,
+        params: &WithdrawalParams,
+    ) -> Result<WithdrawalPreview, ClientError> {
+        let token_settings_pubkey = match &params.token {
+            WithdrawalToken::Ever(token) => token_proxy::get_token_settings_ever_address(token),
+            WithdrawalToken::Sol(mint) => token_proxy::get_token_settings_sol_address(mint),
+        };
+
+        let settings_pubkey = token_proxy::get_settings_address();
+        let settings = self.fetch_account::<Settings>(&settings_pubkey).await?;
+        let token_settings = self.fetch_token_settings(&token_settings_pubkey).await?;
+
+        // Amount in Solana decimals and balance of Solana token vault
+        let (amount, vault_balance) = match token_settings.kind {
+            TokenKind::Ever { mint, decimals, .. } => {
+                let mint = self.fetch_account::<spl_token::state::Mint>(&mint).await?;
+                let amount = to_solana_amount(params.amount, decimals, mint.decimals)?;
+
+                (amount, None)
+            }
+            TokenKind::Solana { vault, .. } => {
+                let amount =
+                    u64::try_from(params.amount).map_err(|_| SolanaBridgeError::Overflow)?;
+                let vault = self
+                    .fetch_account::<spl_token::state::Account>(&vault)
+                    .await?;
+
+                (amount, Some(vault.amount))
+            }
+        };
+
+        // Denylist account is created by the first added address
+        let denylist_pubkey = token_proxy::get_denylist_address();
+        let denylist = match self.fetch_account::<Denylist>(&denylist_pubkey).await {
+            Ok(denylist) => Some(denylist),
+            Err(ClientError::AccountNotFound(_)) => None,
+            Err(err) => return Err(err),
+        };
+
+        let clock_account = self
+            .rpc
+            .get_account_with_commitment(&sysvar::clock::id(), self.rpc.commitment())
+            .await?
+            .value
+            .ok_or(ClientError::AccountNotFound(sysvar::clock::id()))?;
+        let clock = from_account::<Clock, _>(&clock_account)
+            .ok_or(ClientError::InvalidAccountData(sysvar::clock::id()))?;
+
+        let preview = token_settings.preview_withdrawal(
+            &settings,
+            denylist.as_ref(),
+            &params.author,
+            &params.recipient,
+            amount,
+            params.chain_id,
+            vault_balance,
+            clock.unix_timestamp,
+        )?;
+
+        Ok(preview)
+    }
+
+    async fn fetch_account<T: Pack + IsInitialized>(
+        &self,
+        pubkey: &Pubkey,
+    ) -> Result<T, ClientError> {
+        let data = self.fetch_account_data(pubkey).await?;
+        T::unpack(&data).map_err(|_| ClientError::InvalidAccountData(*pubkey))
+    }
+
+    async fn fetch_account_data(&self, pubkey: &Pubkey) -> Result<Vec<u8>, ClientError> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(pubkey, self.rpc.commitment())
+            .await?
+            .value
+            .ok_or(ClientError::AccountNotFound(*pubkey))?;
+
+        Ok(account.data)
+    }
+}
+
+/// Subscribe to changes of a withdrawal account and yield its state on every
+/// status transition. The stream ends once the withdrawal is processed,
+/// cancelled or expired, or when the websocket connection is closed.
+/// The current state is not yielded, fetch it with `fetch_withdrawal_state`
+pub async fn watch_withdrawal(
+    rpc_ws_url: &str,
+    withdrawal_pubkey: Pubkey,
+) -> Result<impl Stream<Item = WithdrawalState>, ClientError> {
+    let pubsub = PubsubClient::new(rpc_ws_url).await?;
+
+    let (subscribed_sender, subscribed_receiver) = oneshot::channel();
+    let (state_sender, state_receiver) = mpsc::unbounded();
+
+    tokio::spawn(async move {
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        };
+
+        let (mut updates, unsubscribe) = match pubsub
+            .account_subscribe(&withdrawal_pubkey, Some(config))
+            .await
+        {
+            Ok(subscription) => {
+                let _ = subscribed_sender.send(Ok(()));
+                subscription
+            }
+            Err(err) => {
+                let _ = subscribed_sender.send(Err(err));
+                return;
+            }
+        };
+
+        let mut last_status = None;
+
+        while let Some(update) = updates.next().await {
+            let state = match update
+                .value
+                .decode::<Account>()
+                .and_then(|account| decode_withdrawal_state(&account.data))
+            {
+                Some(state) => state,
+                None => continue,
+            };
+
+            if last_status == Some(state.status) {
+                continue;
+            }
+            last_status = Some(state.status);
+
+            let is_final = matches!(
+                state.status,
+                WithdrawalTokenStatus::Processed
+                    | WithdrawalTokenStatus::Cancelled
+                    | WithdrawalTokenStatus::Expired
+            );
+
+            if state_sender.unbounded_send(state).is_err() || is_final {
+                break;
+            }
+        }
+
+        drop(updates);
+        unsubscribe().await;
+        let _ = pubsub.shutdown().await;
+    });
+
+    if let Ok(result) = subscribed_receiver.await {
+        result?;
+    }
+
+    Ok(state_receiver)
+}
+
+// Withdrawals of Ever and Solana tokens have the same size
+fn withdrawal_filters() -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(WithdrawalMultiTokenSol::LEN as u64),
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            token_proxy::WITHDRAWAL_DISCRIMINATOR.to_vec(),
+        )),
+    ]
+}
+
+fn decode_withdrawal_state(data: &[u8]) -> Option<WithdrawalState> {
+    let withdrawal = Proposal::unpack_from_slice(data).ok()?;
+    let meta = WithdrawalTokenMeta::deserialize(&mut withdrawal.meta.as_slice()).ok()?;
+
+    let count_votes = |vote: Vote| withdrawal.signers.iter().filter(|v| **v == vote).count() as u32;
+
+    Some(WithdrawalState {
+        round_number: withdrawal.round_number,
+        required_votes: withdrawal.required_votes,
+        confirmations: bridge_utils::helper::get_recorded_weight(
+            &withdrawal.signers,
+            withdrawal.confirmed_weight,
+        ),
+        rejections: count_votes(Vote::Reject),
+        status: meta.status,
+        bounty: meta.bounty,
+    })
+}
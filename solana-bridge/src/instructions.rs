@@ -23,6 +23,10 @@ pub struct ExecuteProposal {
 pub struct ExecutePayload {
     // Instruction number
     pub instruction: u8,
+    // Number of payload instructions to execute
+    pub count: Option<u32>,
+    // Number of additional proxy accounts to sign for
+    pub proxy_accounts: u8,
 }
 
 pub fn vote_for_proposal_ix(
@@ -34,6 +38,7 @@ pub fn vote_for_proposal_ix(
     vote: Vote,
 ) -> Instruction {
     let relay_round_pubkey = round_loader::get_relay_round_address(round_number);
+    let rl_settings_pubkey = round_loader::get_settings_address();
 
     let data = VoteForProposal { instruction, vote }
         .try_to_vec()
@@ -45,6 +50,7 @@ pub fn vote_for_proposal_ix(
             AccountMeta::new(*voter_pubkey, true),
             AccountMeta::new(*proposal_pubkey, false),
             AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
         ],
         data,
     }
@@ -84,9 +90,17 @@ pub fn execute_payload_ix(
     program_id: Pubkey,
     instruction: u8,
     proposal_pubkey: Pubkey,
+    count: Option<u32>,
+    proxy_accounts: u8,
     accounts: Vec<(Pubkey, bool, bool)>,
 ) -> Instruction {
-    let data = ExecutePayload { instruction }.try_to_vec().expect("pack");
+    let data = ExecutePayload {
+        instruction,
+        count,
+        proxy_accounts,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let mut accounts_with_meta = Vec::with_capacity(accounts.len() + 1);
     accounts_with_meta.push((proposal_pubkey, false, false));
@@ -122,10 +136,19 @@ pub fn withdrawal_ever_request_ix(
     event: token_proxy::WithdrawalMultiTokenEverEvent,
     attached_amount: u64,
 ) -> Instruction {
+    let proposal_index_pubkey = token_proxy::get_associated_proposal_index_address(
+        &program_id,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
+
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
     let relay_round_pubkey =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let bridge_stats_pubkey = token_proxy::get_associated_bridge_stats_address(&program_id);
 
     let mut accounts = vec![
         AccountMeta::new(creator_pubkey, true),
@@ -136,6 +159,8 @@ pub fn withdrawal_ever_request_ix(
         AccountMeta::new_readonly(system_program::id(), false),
         AccountMeta::new_readonly(sysvar::rent::id(), false),
         AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new(proposal_index_pubkey, false),
+        AccountMeta::new(bridge_stats_pubkey, false),
     ];
 
     if !event.payload.is_empty() {
@@ -185,10 +210,19 @@ pub fn withdrawal_sol_request_ix(
     let token_settings_pubkey =
         token_proxy::get_associated_token_settings_sol_address(&program_id, &event.mint);
 
+    let proposal_index_pubkey = token_proxy::get_associated_proposal_index_address(
+        &program_id,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
+
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
     let relay_round_pubkey =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let bridge_stats_pubkey = token_proxy::get_associated_bridge_stats_address(&program_id);
 
     let mut accounts = vec![
         AccountMeta::new(creator_pubkey, true),
@@ -200,6 +234,8 @@ pub fn withdrawal_sol_request_ix(
         AccountMeta::new_readonly(system_program::id(), false),
         AccountMeta::new_readonly(sysvar::rent::id(), false),
         AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new(proposal_index_pubkey, false),
+        AccountMeta::new(bridge_stats_pubkey, false),
     ];
 
     if !event.payload.is_empty() {
@@ -6,4 +6,8 @@ pub use bridge_utils::helper as bridge_helper;
 pub use bridge_utils::state as bridge_state;
 pub use bridge_utils::types as bridge_types;
 
+#[cfg(feature = "client")]
+pub mod client;
 pub mod instructions;
+pub mod reconciliation;
+pub mod snapshot;
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use borsh::BorshSerialize;
+use serde::{Deserialize, Serialize};
+
+use bridge_utils::state::AccountKind;
+
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use token_proxy::{
+    FeeLedger, TokenKind, TokenSettings, WithdrawalMultiTokenEver, WithdrawalMultiTokenSol,
+    WithdrawalTokenStatus,
+};
+
+use crate::snapshot::{BridgeSnapshot, SnapshotAccount};
+
+/// Balance mismatch found during reconciliation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Discrepancy {
+    // Account referenced by token settings is not in the snapshot
+    MissingAccount(String),
+    // Vault doesn't hold enough tokens to cover fees and open withdrawals
+    VaultShortfall { balance: u64, required: u64 },
+    // Mint of Ever token can be minted by someone except the bridge
+    InvalidMintAuthority,
+    // Open withdrawal amount can't be converted into Solana units
+    InvalidWithdrawalAmount(String),
+}
+
+/// Reconciliation result of a single token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenReconciliation {
+    pub token_settings: String,
+    pub mint: String,
+    // Vault balance for Solana tokens and mint supply for Ever ones
+    pub balance: Option<u64>,
+    pub fee_supply: u64,
+    pub open_withdrawals: u64,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+impl TokenReconciliation {
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Compare actual vault/mint balances with amounts implied by token settings,
+/// fee supplies and open withdrawals. Snapshot must include vaults and mints.
+pub fn reconcile(snapshot: &BridgeSnapshot) -> Vec<TokenReconciliation> {
+    let accounts = decode_accounts(
+        snapshot
+            .vaults
+            .iter()
+            .chain(&snapshot.other)
+            .chain(&snapshot.proposals),
+    );
+
+    let fee_ledgers = snapshot
+        .other
+        .iter()
+        .filter_map(|account| FeeLedger::unpack(accounts.get(&account.address)?).ok())
+        .filter(|fee_ledger| matches!(fee_ledger.account_kind, AccountKind::FeeLedger(_)))
+        .map(|fee_ledger| (fee_ledger.token_settings, fee_ledger))
+        .collect::<HashMap<_, _>>();
+
+    let open_withdrawals = collect_open_withdrawals(snapshot, &accounts);
+
+    decode_accounts(&snapshot.token_settings)
+        .into_iter()
+        .filter_map(|(address, data)| {
+            let token_settings = TokenSettings::unpack(&data).ok()?;
+            let address = Pubkey::from_str(&address).ok()?;
+            Some(reconcile_token(
+                &address,
+                &token_settings,
+                fee_ledgers.get(&address),
+                &accounts,
+                open_withdrawals.get(&token_settings_mint(&token_settings)),
+            ))
+        })
+        .collect()
+}
+
+fn reconcile_token(
+    address: &Pubkey,
+    token_settings: &TokenSettings,
+    fee_ledger: Option<&FeeLedger>,
+    accounts: &HashMap<String, Vec<u8>>,
+    withdrawals: Option<&Vec<OpenWithdrawal>>,
+) -> TokenReconciliation {
+    let mut discrepancies = Vec::new();
+
+    let mint = token_settings_mint(token_settings);
+    let mint_account = accounts
+        .get(&mint.to_string())
+        .and_then(|data| spl_token::state::Mint::unpack(data).ok());
+
+    // Clients that don't pass the fee ledger still accrue fee in token settings
+    let fee_supply = token_settings.fee_supply.saturating_add(
+        fee_ledger
+            .map(|fee_ledger| fee_ledger.supply)
+            .unwrap_or_default(),
+    );
+
+    let mut open_withdrawals: u64 = 0;
+    for withdrawal in withdrawals.into_iter().flatten() {
+        let amount = match (&token_settings.kind, &mint_account) {
+            (TokenKind::Ever { .. }, Some(mint_account)) => bridge_utils::amount::to_solana_amount(
+                withdrawal.amount,
+                withdrawal.ever_decimals,
+                mint_account.decimals,
+            )
+            .ok(),
+            // Missing mint is reported below
+            (TokenKind::Ever { .. }, None) => continue,
+            (TokenKind::Solana { .. }, _) => u64::try_from(withdrawal.amount).ok(),
+        };
+
+        match amount {
+            Some(amount) => open_withdrawals = open_withdrawals.saturating_add(amount),
+            None => discrepancies.push(Discrepancy::InvalidWithdrawalAmount(
+                withdrawal.address.clone(),
+            )),
+        }
+    }
+
+    let balance = match token_settings.kind {
+        TokenKind::Ever { mint, .. } => match mint_account {
+            Some(mint_account) => {
+                if mint_account.mint_authority != COption::Some(mint) {
+                    discrepancies.push(Discrepancy::InvalidMintAuthority);
+                }
+                Some(mint_account.supply)
+            }
+            None => {
+                discrepancies.push(Discrepancy::MissingAccount(mint.to_string()));
+                None
+            }
+        },
+        TokenKind::Solana { vault, .. } => {
+            let vault_account = accounts
+                .get(&vault.to_string())
+                .and_then(|data| spl_token::state::Account::unpack(data).ok());
+
+            match vault_account {
+                Some(vault_account) => {
+                    let required = fee_supply.saturating_add(open_withdrawals);
+                    if vault_account.amount < required {
+                        discrepancies.push(Discrepancy::VaultShortfall {
+                            balance: vault_account.amount,
+                            required,
+                        });
+                    }
+                    Some(vault_account.amount)
+                }
+                None => {
+                    discrepancies.push(Discrepancy::MissingAccount(vault.to_string()));
+                    None
+                }
+            }
+        }
+    };
+
+    TokenReconciliation {
+        token_settings: address.to_string(),
+        mint: mint.to_string(),
+        balance,
+        fee_supply,
+        open_withdrawals,
+        discrepancies,
+    }
+}
+
+struct OpenWithdrawal {
+    address: String,
+    amount: u128,
+    ever_decimals: u8,
+}
+
+fn collect_open_withdrawals(
+    snapshot: &BridgeSnapshot,
+    accounts: &HashMap<String, Vec<u8>>,
+) -> HashMap<Pubkey, Vec<OpenWithdrawal>> {
+    let mut withdrawals: HashMap<Pubkey, Vec<OpenWithdrawal>> = HashMap::new();
+
+    for account in &snapshot.proposals {
+        if account.owner != token_proxy::id().to_string() {
+            continue;
+        }
+
+        let data = match accounts.get(&account.address) {
+            Some(data) => data,
+            None => continue,
+        };
+
+        // Ever and Solana withdrawals are told apart by the stored event length
+        let withdrawal = match WithdrawalMultiTokenEver::unpack(data) {
+            Ok(withdrawal) if is_event_len_valid(withdrawal.event.len, &withdrawal.event.data) => {
+                let mint = token_proxy::get_associated_mint_address(
+                    &token_proxy::id(),
+                    &withdrawal.event.data.token,
+                );
+                Some((
+                    mint,
+                    withdrawal.meta.data.status,
+                    withdrawal.event.data.amount,
+                    withdrawal.event.data.decimals,
+                ))
+            }
+            _ => WithdrawalMultiTokenSol::unpack(data)
+                .ok()
+                .map(|withdrawal| {
+                    (
+                        withdrawal.event.data.mint,
+                        withdrawal.meta.data.status,
+                        withdrawal.event.data.amount,
+                        0,
+                    )
+                }),
+        };
+
+        if let Some((mint, status, amount, ever_decimals)) = withdrawal {
+            if status != WithdrawalTokenStatus::Processed
+                && status != WithdrawalTokenStatus::Cancelled
+                && status != WithdrawalTokenStatus::Expired
+            {
+                withdrawals.entry(mint).or_default().push(OpenWithdrawal {
+                    address: account.address.clone(),
+                    amount,
+                    ever_decimals,
+                });
+            }
+        }
+    }
+
+    withdrawals
+}
+
+fn token_settings_mint(token_settings: &TokenSettings) -> Pubkey {
+    match token_settings.kind {
+        TokenKind::Ever { mint, .. } => mint,
+        TokenKind::Solana { mint, .. } => mint,
+    }
+}
+
+fn decode_accounts<'a, I>(accounts: I) -> HashMap<String, Vec<u8>>
+where
+    I: IntoIterator<Item = &'a SnapshotAccount>,
+{
+    accounts
+        .into_iter()
+        .filter_map(|account| {
+            let data = general_purpose::STANDARD.decode(&account.data).ok()?;
+            Some((account.address.clone(), data))
+        })
+        .collect()
+}
+
+fn is_event_len_valid<T: BorshSerialize>(len: u32, event: &T) -> bool {
+    event
+        .try_to_vec()
+        .map(|event| event.len() == len as usize)
+        .unwrap_or_default()
+}
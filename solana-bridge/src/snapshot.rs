@@ -0,0 +1,121 @@
+use base64::engine::general_purpose;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use bridge_utils::state::{AccountKind, Proposal};
+
+use solana_program::pubkey::Pubkey;
+
+use round_loader::ProposalStatus;
+use token_proxy::WithdrawalTokenStatus;
+
+/// Raw bridge account stored in a snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotAccount {
+    pub address: String,
+    pub owner: String,
+    pub lamports: u64,
+    // Base64 encoded account data
+    pub data: String,
+}
+
+impl SnapshotAccount {
+    pub fn new(address: &Pubkey, owner: &Pubkey, lamports: u64, data: &[u8]) -> Self {
+        Self {
+            address: address.to_string(),
+            owner: owner.to_string(),
+            lamports,
+            data: general_purpose::STANDARD.encode(data),
+        }
+    }
+}
+
+/// Full state of the bridge at the given time
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct BridgeSnapshot {
+    pub timestamp: i64,
+    pub token_proxy_settings: Option<SnapshotAccount>,
+    pub round_loader_settings: Option<SnapshotAccount>,
+    pub token_settings: Vec<SnapshotAccount>,
+    pub vaults: Vec<SnapshotAccount>,
+    pub proposals: Vec<SnapshotAccount>,
+    pub relay_rounds: Vec<SnapshotAccount>,
+    pub other: Vec<SnapshotAccount>,
+}
+
+impl BridgeSnapshot {
+    pub fn new(timestamp: i64) -> Self {
+        Self {
+            timestamp,
+            ..Default::default()
+        }
+    }
+
+    /// Classify account by its owner and kind. Closed proposals and
+    /// accounts that don't belong to the bridge are skipped.
+    pub fn add_account(&mut self, address: &Pubkey, owner: &Pubkey, lamports: u64, data: &[u8]) {
+        let account = SnapshotAccount::new(address, owner, lamports, data);
+
+        if *owner == spl_token::id() {
+            self.vaults.push(account);
+            return;
+        }
+
+        let is_token_proxy = *owner == token_proxy::id();
+        if !is_token_proxy && *owner != round_loader::id() {
+            return;
+        }
+
+        let kind = match data {
+            [1, kind, ..] => *kind,
+            _ => return,
+        };
+
+        match kind {
+            0 if is_token_proxy => self.token_proxy_settings = Some(account),
+            0 => self.round_loader_settings = Some(account),
+            2 if is_open_proposal(data, is_token_proxy) => self.proposals.push(account),
+            2 => (),
+            3 => self.relay_rounds.push(account),
+            4 => self.vaults.push(account),
+            5 => self.token_settings.push(account),
+            _ => self.other.push(account),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Export bridge accounts fetched from token proxy, round loader and vaults into a snapshot
+pub fn export_snapshot<'a, I>(timestamp: i64, accounts: I) -> BridgeSnapshot
+where
+    I: IntoIterator<Item = (&'a Pubkey, &'a Pubkey, u64, &'a [u8])>,
+{
+    let mut snapshot = BridgeSnapshot::new(timestamp);
+    for (address, owner, lamports, data) in accounts {
+        snapshot.add_account(address, owner, lamports, data);
+    }
+    snapshot
+}
+
+fn is_open_proposal(data: &[u8], is_token_proxy: bool) -> bool {
+    let proposal = match Proposal::unpack_from_slice(data) {
+        Ok(proposal) if matches!(proposal.account_kind, AccountKind::Proposal(_, _)) => proposal,
+        _ => return false,
+    };
+
+    let status = match proposal.meta.first() {
+        Some(status) => *status,
+        None => return false,
+    };
+
+    if is_token_proxy {
+        status != WithdrawalTokenStatus::Processed as u8
+            && status != WithdrawalTokenStatus::Cancelled as u8
+            && status != WithdrawalTokenStatus::Expired as u8
+    } else {
+        status == ProposalStatus::New as u8
+    }
+}
@@ -0,0 +1,157 @@
+use bridge_utils::types::{EverAddress, UInt256};
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+
+use crate::*;
+
+/// Accounts of EVER token deposit
+pub struct DepositMultiTokenEver<'a, 'info> {
+    pub funder: &'a AccountInfo<'info>,
+    pub author: &'a AccountInfo<'info>,
+    pub author_token: &'a AccountInfo<'info>,
+    pub deposit: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub multivault: &'a AccountInfo<'info>,
+    pub token_settings: &'a AccountInfo<'info>,
+    pub settings: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub rent_sysvar: &'a AccountInfo<'info>,
+    pub bridge_stats: &'a AccountInfo<'info>,
+    pub instructions_sysvar: &'a AccountInfo<'info>,
+    pub fee_ledger: &'a AccountInfo<'info>,
+    pub token_proxy_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> DepositMultiTokenEver<'a, 'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.funder.clone(),
+            self.author.clone(),
+            self.author_token.clone(),
+            self.deposit.clone(),
+            self.mint.clone(),
+            self.multivault.clone(),
+            self.token_settings.clone(),
+            self.settings.clone(),
+            self.system_program.clone(),
+            self.token_program.clone(),
+            self.rent_sysvar.clone(),
+            self.bridge_stats.clone(),
+            self.instructions_sysvar.clone(),
+            self.fee_ledger.clone(),
+            self.token_proxy_program.clone(),
+        ]
+    }
+}
+
+/// Accounts of Solana token deposit
+pub struct DepositMultiTokenSol<'a, 'info> {
+    pub funder: &'a AccountInfo<'info>,
+    pub author: &'a AccountInfo<'info>,
+    pub author_token: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+    pub deposit: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub multivault: &'a AccountInfo<'info>,
+    pub token_settings: &'a AccountInfo<'info>,
+    pub settings: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub rent_sysvar: &'a AccountInfo<'info>,
+    pub bridge_stats: &'a AccountInfo<'info>,
+    pub instructions_sysvar: &'a AccountInfo<'info>,
+    pub fee_ledger: &'a AccountInfo<'info>,
+    pub token_proxy_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> DepositMultiTokenSol<'a, 'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.funder.clone(),
+            self.author.clone(),
+            self.author_token.clone(),
+            self.vault.clone(),
+            self.deposit.clone(),
+            self.mint.clone(),
+            self.multivault.clone(),
+            self.token_settings.clone(),
+            self.settings.clone(),
+            self.system_program.clone(),
+            self.token_program.clone(),
+            self.rent_sysvar.clone(),
+            self.bridge_stats.clone(),
+            self.instructions_sysvar.clone(),
+            self.fee_ledger.clone(),
+            self.token_proxy_program.clone(),
+        ]
+    }
+}
+
+/// Deposit EVER token on behalf of the calling program's user. Pass seeds of the author
+/// in `signers_seeds` if it is PDA of the calling program, or empty slice otherwise
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_ever(
+    accounts: &DepositMultiTokenEver,
+    token: &EverAddress,
+    deposit_seed: u128,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Option<Vec<u8>>,
+    reference: Option<[u8; 32]>,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = deposit_multi_token_ever_ix(
+        *accounts.funder.key,
+        *accounts.author.key,
+        *accounts.author_token.key,
+        token,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+    );
+
+    invoke_signed(&ix, &accounts.to_account_infos(), signers_seeds)
+}
+
+/// Deposit Solana token on behalf of the calling program's user. Pass seeds of the author
+/// in `signers_seeds` if it is PDA of the calling program, or empty slice otherwise
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_sol(
+    accounts: &DepositMultiTokenSol,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Option<Vec<u8>>,
+    reference: Option<[u8; 32]>,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = deposit_multi_token_sol_ix(
+        *accounts.funder.key,
+        *accounts.author.key,
+        *accounts.author_token.key,
+        *accounts.mint.key,
+        deposit_seed,
+        name,
+        symbol,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+    );
+
+    invoke_signed(&ix, &accounts.to_account_infos(), signers_seeds)
+}
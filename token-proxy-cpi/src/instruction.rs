@@ -0,0 +1,134 @@
+use borsh::BorshSerialize;
+use bridge_utils::types::{EverAddress, UInt256};
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
+
+use crate::*;
+
+/// Deposit of EVER token. Author token account is burnt, so author has to sign
+/// the instruction, e.g. as PDA of the calling program
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_ever_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    token: &EverAddress,
+    deposit_seed: u128,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Option<Vec<u8>>,
+    reference: Option<[u8; 32]>,
+) -> Instruction {
+    let mint_pubkey = get_mint_address(token);
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let deposit_pubkey = get_deposit_address(deposit_seed);
+
+    let data = TokenProxyInstruction::DepositMultiTokenEverV2 {
+        version: CURRENT_IX_VERSION,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+        callback_program: None,
+        referrer: None,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+        ],
+        data,
+    }
+}
+
+/// Deposit of Solana token. Tokens are moved from author token account to the vault,
+/// so author has to sign the instruction, e.g. as PDA of the calling program
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_sol_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Option<Vec<u8>>,
+    reference: Option<[u8; 32]>,
+) -> Instruction {
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let deposit_pubkey = get_deposit_address(deposit_seed);
+
+    let data = TokenProxyInstruction::DepositMultiTokenSolV2 {
+        version: CURRENT_IX_VERSION,
+        deposit_seed,
+        name: name.into(),
+        symbol: symbol.into(),
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+        callback_program: None,
+        referrer: None,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+        ],
+        data,
+    }
+}
@@ -0,0 +1,9 @@
+mod cpi;
+mod instruction;
+mod pda;
+
+pub use self::cpi::*;
+pub use self::instruction::*;
+pub use self::pda::*;
+
+pub use token_proxy::{check_id, id, TokenProxyInstruction, CURRENT_IX_VERSION, ID};
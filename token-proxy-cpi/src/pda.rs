@@ -0,0 +1,48 @@
+use bridge_utils::types::EverAddress;
+use solana_program::pubkey::Pubkey;
+
+use token_proxy::{
+    get_associated_bridge_stats_address, get_associated_deposit_address,
+    get_associated_fee_ledger_address, get_associated_mint_address,
+    get_associated_multivault_address, get_associated_settings_address,
+    get_associated_token_settings_ever_address, get_associated_token_settings_sol_address,
+    get_associated_vault_address,
+};
+
+use crate::id;
+
+pub fn get_settings_address() -> Pubkey {
+    get_associated_settings_address(&id())
+}
+
+pub fn get_multivault_address() -> Pubkey {
+    get_associated_multivault_address(&id())
+}
+
+pub fn get_token_settings_ever_address(token: &EverAddress) -> Pubkey {
+    get_associated_token_settings_ever_address(&id(), token)
+}
+
+pub fn get_token_settings_sol_address(mint: &Pubkey) -> Pubkey {
+    get_associated_token_settings_sol_address(&id(), mint)
+}
+
+pub fn get_mint_address(token: &EverAddress) -> Pubkey {
+    get_associated_mint_address(&id(), token)
+}
+
+pub fn get_vault_address(mint: &Pubkey) -> Pubkey {
+    get_associated_vault_address(&id(), mint)
+}
+
+pub fn get_deposit_address(seed: u128) -> Pubkey {
+    get_associated_deposit_address(&id(), seed)
+}
+
+pub fn get_fee_ledger_address(token_settings: &Pubkey) -> Pubkey {
+    get_associated_fee_ledger_address(&id(), token_settings)
+}
+
+pub fn get_bridge_stats_address() -> Pubkey {
+    get_associated_bridge_stats_address(&id())
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "test-bpf")]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::types::{EverAddress, UInt256};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::{processor, tokio};
+use solana_sdk::account::ReadableAccount;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+
+use bridge_test_utils::TestBridge;
+use token_proxy_cpi::*;
+
+// Deposits tokens held by the aggregator's PDA
+fn process_aggregator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (deposit_seed, amount, recipient) =
+        <(u128, u64, EverAddress)>::try_from_slice(instruction_data)?;
+
+    let account_info_iter = &mut accounts.iter();
+
+    let deposit_accounts = DepositMultiTokenSol {
+        funder: next_account_info(account_info_iter)?,
+        author: next_account_info(account_info_iter)?,
+        author_token: next_account_info(account_info_iter)?,
+        vault: next_account_info(account_info_iter)?,
+        deposit: next_account_info(account_info_iter)?,
+        mint: next_account_info(account_info_iter)?,
+        multivault: next_account_info(account_info_iter)?,
+        token_settings: next_account_info(account_info_iter)?,
+        settings: next_account_info(account_info_iter)?,
+        system_program: next_account_info(account_info_iter)?,
+        token_program: next_account_info(account_info_iter)?,
+        rent_sysvar: next_account_info(account_info_iter)?,
+        bridge_stats: next_account_info(account_info_iter)?,
+        instructions_sysvar: next_account_info(account_info_iter)?,
+        fee_ledger: next_account_info(account_info_iter)?,
+        token_proxy_program: next_account_info(account_info_iter)?,
+    };
+
+    let (_, author_nonce) = Pubkey::find_program_address(&[br"author"], program_id);
+
+    deposit_multi_token_sol(
+        &deposit_accounts,
+        deposit_seed,
+        "USDT".to_string(),
+        "USDT".to_string(),
+        amount,
+        recipient,
+        0,
+        UInt256::default(),
+        None,
+        None,
+        &[&[br"author", &[author_nonce]]],
+    )
+}
+
+#[tokio::test]
+async fn test_deposit_multi_token_sol_by_program() {
+    // Setup environment
+    let aggregator_id = Pubkey::new_unique();
+    let (author, _) = Pubkey::find_program_address(&[br"author"], &aggregator_id);
+
+    let settings_account_data = bridge_test_utils::settings(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    let mint = Pubkey::new_unique();
+    let author_token_address =
+        spl_associated_token_account::get_associated_token_address(&author, &mint);
+
+    let mut test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_sol_token(bridge_test_utils::sol_token_settings(mint), 9, 0)
+        .with_associated_token_account(author, mint, 100);
+
+    test_bridge.program_test_mut().add_program(
+        "aggregator",
+        aggregator_id,
+        processor!(process_aggregator),
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let deposit_seed = 1;
+    let amount = 100;
+    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    // Author is PDA of the aggregator, so it signs only inside the program
+    let deposit_ix = deposit_multi_token_sol_ix(
+        funder.pubkey(),
+        author,
+        author_token_address,
+        mint,
+        deposit_seed,
+        "USDT".to_string(),
+        "USDT".to_string(),
+        amount,
+        recipient,
+        0,
+        UInt256::default(),
+        None,
+        None,
+    );
+
+    let mut accounts = deposit_ix.accounts;
+    accounts[1].is_signer = false;
+    accounts.push(AccountMeta::new_readonly(id(), false));
+
+    let mut transaction = Transaction::new_with_payer(
+        &[Instruction {
+            program_id: aggregator_id,
+            accounts,
+            data: (deposit_seed, amount, recipient)
+                .try_to_vec()
+                .expect("pack"),
+        }],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let deposit_info = banks_client
+        .get_account(get_deposit_address(deposit_seed))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let deposit_data =
+        token_proxy::DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit unpack");
+    assert_eq!(deposit_data.meta.data.seed, deposit_seed);
+    assert_eq!(deposit_data.event.data.recipient, recipient);
+
+    let vault_info = banks_client
+        .get_account(get_vault_address(&mint))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, amount);
+}
@@ -1,18 +1,63 @@
 use borsh::BorshSerialize;
+use bridge_derive::AccountMetas;
+use bridge_utils::state::{AccountKind, DISCRIMINATOR_LEN};
 use bridge_utils::types::{EverAddress, UInt256, Vote};
 
-use solana_program::hash::hash;
+use solana_program::address_lookup_table_account::AddressLookupTableAccount;
+use solana_program::hash::{hash, Hash};
 use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::message::{v0, CompileError};
 use solana_program::pubkey::Pubkey;
 use solana_program::{system_program, sysvar};
 
 use crate::*;
 
+// Packed accounts start with type tag used in `memcmp` filters of `getProgramAccounts`
+// at zero offset. Legacy accounts are tagged once they are rewritten or migrated
+pub const SETTINGS_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::Settings(0, 0).discriminator();
+pub const DEPOSIT_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] = AccountKind::Deposit(0).discriminator();
+pub const WITHDRAWAL_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::Proposal(0, None).discriminator();
+pub const MULTIVAULT_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::MultiVault(0).discriminator();
+pub const TOKEN_SETTINGS_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::TokenSettings(0, 0).discriminator();
+pub const TOKEN_DECIMALS_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::TokenDecimals(0).discriminator();
+pub const PROPOSAL_INDEX_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::ProposalIndex(0).discriminator();
+pub const FEE_LEDGER_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::FeeLedger(0).discriminator();
+pub const RECIPIENT_HOOK_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::RecipientHook(0).discriminator();
+pub const PENDING_CHANGE_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::PendingChange(0).discriminator();
+pub const DENYLIST_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::Denylist(0).discriminator();
+pub const RELAY_REWARD_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::RelayReward(0).discriminator();
+pub const BRIDGE_STATS_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::BridgeStats(0).discriminator();
+pub const LP_REGISTRY_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::LpRegistry(0).discriminator();
+pub const REFERRAL_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::Referral(0).discriminator();
+pub const APPROVAL_QUEUE_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    AccountKind::ApprovalQueue(0).discriminator();
+
 pub fn get_programdata_address() -> Pubkey {
     let program_id = &id();
     bridge_utils::helper::get_programdata_address(program_id)
 }
 
+// Governance of the program in SPL Governance realm. Set it as the program upgrade authority
+// to execute owner instructions from governance proposals
+pub fn get_governance_address(governance_program_id: &Pubkey, realm: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_governance_address(governance_program_id, realm, program_id)
+}
+
 pub fn get_settings_address() -> Pubkey {
     let program_id = &id();
     bridge_utils::helper::get_associated_settings_address(program_id)
@@ -33,11 +78,25 @@ pub fn get_token_settings_sol_address(mint: &Pubkey) -> Pubkey {
     get_associated_token_settings_sol_address(program_id, mint)
 }
 
+pub fn get_token_decimals_address(token: &EverAddress) -> Pubkey {
+    let program_id = &id();
+    get_associated_token_decimals_address(program_id, token)
+}
+
 pub fn get_mint_address(token: &EverAddress) -> Pubkey {
     let program_id = &id();
     get_associated_mint_address(program_id, token)
 }
 
+pub fn get_pending_change_address(seed: u128) -> Pubkey {
+    let program_id = &id();
+    get_associated_pending_change_address(program_id, seed)
+}
+
+pub fn get_token_metadata_address(mint: &Pubkey) -> Pubkey {
+    get_associated_token_metadata_address(mint)
+}
+
 pub fn get_vault_address(mint: &Pubkey) -> Pubkey {
     let program_id = &id();
     get_associated_vault_address(program_id, mint)
@@ -48,11 +107,85 @@ pub fn get_deposit_address(seed: u128) -> Pubkey {
     get_associated_deposit_address(program_id, seed)
 }
 
+pub fn get_proposal_index_address(
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    chain_id: Option<u64>,
+) -> Pubkey {
+    let program_id = &id();
+    get_associated_proposal_index_address(
+        program_id,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        chain_id,
+    )
+}
+
+pub fn get_relay_round_address(round_number: u32) -> Pubkey {
+    bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number)
+}
+
+pub fn get_rl_settings_address() -> Pubkey {
+    bridge_utils::helper::get_associated_settings_address(&round_loader::id())
+}
+
+pub fn get_fee_ledger_address(token_settings: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_fee_ledger_address(program_id, token_settings)
+}
+
+pub fn get_recipient_hook_address(recipient: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_recipient_hook_address(program_id, recipient)
+}
+
+pub fn get_denylist_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_denylist_address(program_id)
+}
+
+pub fn get_lp_registry_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_lp_registry_address(program_id)
+}
+
+pub fn get_referral_address(token_settings: &Pubkey, referrer: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_referral_address(program_id, token_settings, referrer)
+}
+
+pub fn get_approval_queue_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_approval_queue_address(program_id)
+}
+
+pub fn get_bridge_stats_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_bridge_stats_address(program_id)
+}
+
+pub fn get_bounty_address(withdrawal: &Pubkey, author: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_bounty_address(program_id, withdrawal, author)
+}
+
+pub fn get_relay_reward_address(relay: &Pubkey, round_number: u32) -> Pubkey {
+    let program_id = &id();
+    get_associated_relay_reward_address(program_id, relay, round_number)
+}
+
 pub fn get_proxy_address(mint: &Pubkey, recipient: &Pubkey) -> Pubkey {
     let program_id = &id();
     get_associated_proxy_address(program_id, mint, recipient)
 }
 
+pub fn get_proxy_account_address(mint: &Pubkey, recipient: &Pubkey, index: u8) -> Pubkey {
+    let program_id = &id();
+    get_associated_proxy_account_address(program_id, mint, recipient, index)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn get_withdrawal_ever_address(
     round_number: u32,
@@ -90,6 +223,7 @@ pub fn get_withdrawal_ever_address(
         event_timestamp,
         event_transaction_lt,
         event_configuration,
+        None,
         &event_data,
     )
 }
@@ -125,10 +259,127 @@ pub fn get_withdrawal_sol_address(
         event_timestamp,
         event_transaction_lt,
         event_configuration,
+        None,
+        &event_data,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn get_withdrawal_ever_to_many_address(
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    recipients: Vec<WithdrawalRecipient>,
+) -> Pubkey {
+    let program_id = &id();
+
+    let event_data = hash(
+        &WithdrawalMultiTokenEverToManyEvent {
+            token,
+            name,
+            symbol,
+            decimals,
+            recipients,
+        }
+        .try_to_vec()
+        .expect("pack"),
+    )
+    .to_bytes();
+
+    bridge_utils::helper::get_associated_proposal_address(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        None,
         &event_data,
     )
 }
 
+/// Bridge accounts to register in an Address Lookup Table, in canonical order
+pub fn get_lookup_table_addresses(mints: &[Pubkey], tokens: &[EverAddress]) -> Vec<Pubkey> {
+    let mut addresses = vec![
+        id(),
+        round_loader::id(),
+        system_program::id(),
+        spl_token::id(),
+        spl_associated_token_account::id(),
+        sysvar::rent::id(),
+        sysvar::clock::id(),
+        sysvar::instructions::id(),
+        get_settings_address(),
+        get_multivault_address(),
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id()),
+    ];
+
+    for mint in mints {
+        let token_settings = get_token_settings_sol_address(mint);
+        addresses.extend([
+            *mint,
+            get_vault_address(mint),
+            token_settings,
+            get_fee_ledger_address(&token_settings),
+        ]);
+    }
+
+    for token in tokens {
+        let token_settings = get_token_settings_ever_address(token);
+        addresses.extend([
+            get_mint_address(token),
+            token_settings,
+            get_fee_ledger_address(&token_settings),
+        ]);
+    }
+
+    addresses
+}
+
+/// Compile v0 message resolving bridge accounts through the lookup table
+/// registered with `get_lookup_table_addresses` for the same tokens
+pub fn compile_message_v0(
+    payer_pubkey: &Pubkey,
+    instructions: &[Instruction],
+    lookup_table_pubkey: Pubkey,
+    mints: &[Pubkey],
+    tokens: &[EverAddress],
+    recent_blockhash: Hash,
+) -> Result<v0::Message, CompileError> {
+    let lookup_table = AddressLookupTableAccount {
+        key: lookup_table_pubkey,
+        addresses: get_lookup_table_addresses(mints, tokens),
+    };
+
+    v0::Message::try_compile(
+        payer_pubkey,
+        instructions,
+        &[lookup_table],
+        recent_blockhash,
+    )
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct InitializeSettingsAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub initializer: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    #[account(writable)]
+    pub multivault: Pubkey,
+    pub program_data: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    #[account(writable)]
+    pub denylist: Pubkey,
+}
+
 pub fn initialize_settings_ix(
     funder_pubkey: Pubkey,
     initializer_pubkey: Pubkey,
@@ -150,19 +401,48 @@ pub fn initialize_settings_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(funder_pubkey, true),
-            AccountMeta::new(initializer_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new(multivault_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
+        accounts: InitializeSettingsAccounts {
+            funder: funder_pubkey,
+            initializer: initializer_pubkey,
+            settings: settings_pubkey,
+            multivault: multivault_pubkey,
+            program_data: program_data_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+            denylist: get_denylist_address(),
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct DepositMultiTokenEverAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub author_token: Pubkey,
+    #[account(writable)]
+    pub deposit: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub multivault: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    pub instructions_sysvar: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn deposit_multi_token_ever_ix(
     funder_pubkey: Pubkey,
@@ -180,6 +460,8 @@ pub fn deposit_multi_token_ever_ix(
     let settings_pubkey = get_settings_address();
     let multivault_pubkey = get_multivault_address();
     let token_settings_pubkey = get_token_settings_ever_address(token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = get_bridge_stats_address();
     let deposit_pubkey = get_deposit_address(deposit_seed);
 
     let data = TokenProxyInstruction::DepositMultiTokenEver {
@@ -195,23 +477,56 @@ pub fn deposit_multi_token_ever_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(funder_pubkey, true),
-            AccountMeta::new(author_pubkey, true),
-            AccountMeta::new(author_token_pubkey, false),
-            AccountMeta::new(deposit_pubkey, false),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(multivault_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
+        accounts: DepositMultiTokenEverAccounts {
+            funder: funder_pubkey,
+            author: author_pubkey,
+            author_token: author_token_pubkey,
+            deposit: deposit_pubkey,
+            mint: mint_pubkey,
+            multivault: multivault_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            instructions_sysvar: sysvar::instructions::id(),
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct DepositMultiTokenSolAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub author_token: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    #[account(writable)]
+    pub deposit: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub multivault: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    pub instructions_sysvar: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn deposit_multi_token_sol_ix(
     funder_pubkey: Pubkey,
@@ -231,6 +546,8 @@ pub fn deposit_multi_token_sol_ix(
     let settings_pubkey = get_settings_address();
     let multivault_pubkey = get_multivault_address();
     let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = get_bridge_stats_address();
 
     let deposit_pubkey = get_deposit_address(deposit_seed);
 
@@ -249,24 +566,161 @@ pub fn deposit_multi_token_sol_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(funder_pubkey, true),
-            AccountMeta::new(author_pubkey, true),
-            AccountMeta::new(author_token_pubkey, false),
-            AccountMeta::new(vault_pubkey, false),
-            AccountMeta::new(deposit_pubkey, false),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(multivault_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-        ],
+        accounts: DepositMultiTokenSolAccounts {
+            funder: funder_pubkey,
+            author: author_pubkey,
+            author_token: author_token_pubkey,
+            vault: vault_pubkey,
+            deposit: deposit_pubkey,
+            mint: mint_pubkey,
+            multivault: multivault_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            instructions_sysvar: sysvar::instructions::id(),
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+/// Credit `referrer` with a share of the deposit fee. Call it before `set_deposit_callback`
+pub fn set_deposit_referrer(ix: &mut Instruction, referrer: Pubkey) {
+    let mut data = into_deposit_v2(TokenProxyInstruction::decode(&ix.data).expect("unpack"));
+
+    let token_settings_pubkey = match &mut data {
+        TokenProxyInstruction::DepositMultiTokenEverV2 {
+            referrer: ix_referrer,
+            ..
+        } => {
+            *ix_referrer = Some(referrer);
+            ix.accounts[6].pubkey
+        }
+        TokenProxyInstruction::DepositMultiTokenSolV2 {
+            referrer: ix_referrer,
+            ..
+        } => {
+            *ix_referrer = Some(referrer);
+            ix.accounts[7].pubkey
+        }
+        _ => panic!("not a deposit instruction"),
+    };
+
+    ix.data = data.try_to_vec().expect("pack");
+
+    ix.accounts.push(AccountMeta::new(
+        get_referral_address(&token_settings_pubkey, &referrer),
+        false,
+    ));
+}
+
+/// Notify `callback_program` via CPI once the deposit is accepted. Callback accounts
+/// are passed to it after the deposit account, without signatures
+pub fn set_deposit_callback(
+    ix: &mut Instruction,
+    callback_program: Pubkey,
+    callback_accounts: Vec<AccountMeta>,
+) {
+    let mut data = into_deposit_v2(TokenProxyInstruction::decode(&ix.data).expect("unpack"));
+
+    match &mut data {
+        TokenProxyInstruction::DepositMultiTokenEverV2 {
+            callback_program: ix_callback_program,
+            ..
+        }
+        | TokenProxyInstruction::DepositMultiTokenSolV2 {
+            callback_program: ix_callback_program,
+            ..
+        } => *ix_callback_program = Some(callback_program),
+        _ => panic!("not a deposit instruction"),
+    }
+
+    ix.data = data.try_to_vec().expect("pack");
+
+    ix.accounts
+        .push(AccountMeta::new_readonly(callback_program, false));
+    ix.accounts.extend(callback_accounts);
+}
+
+// Legacy deposit encodings don't carry optional fields, so they are re-encoded as V2 ones
+fn into_deposit_v2(data: TokenProxyInstruction) -> TokenProxyInstruction {
+    match data {
+        TokenProxyInstruction::DepositMultiTokenEver {
+            deposit_seed,
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        } => TokenProxyInstruction::DepositMultiTokenEverV2 {
+            version: CURRENT_IX_VERSION,
+            deposit_seed,
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload: (!payload.is_empty()).then_some(payload),
+            reference: None,
+            callback_program: None,
+            referrer: None,
+        },
+        TokenProxyInstruction::DepositMultiTokenSol {
+            deposit_seed,
+            name,
+            symbol,
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        } => TokenProxyInstruction::DepositMultiTokenSolV2 {
+            version: CURRENT_IX_VERSION,
+            deposit_seed,
+            name: name.into(),
+            symbol: symbol.into(),
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload: (!payload.is_empty()).then_some(payload),
+            reference: None,
+            callback_program: None,
+            referrer: None,
+        },
+        data => data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalMultiTokenEverRequestAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    pub rl_settings: Pubkey,
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    // Required only for withdrawals with payload
+    #[account(writable)]
+    pub proxy: Option<Pubkey>,
+    #[account(writable)]
+    pub mint: Option<Pubkey>,
+    #[account(writable)]
+    pub token_program: Option<Pubkey>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn withdrawal_multi_token_ever_request_ix(
     funder_pubkey: Pubkey,
@@ -297,30 +751,44 @@ pub fn withdrawal_multi_token_ever_request_ix(
         amount,
         payload.clone(),
     );
+    let proposal_index_pubkey = get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
     let relay_round_pubkey =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
-    let mut accounts = vec![
-        AccountMeta::new(funder_pubkey, true),
-        AccountMeta::new(author_pubkey, true),
-        AccountMeta::new(withdrawal_pubkey, false),
-        AccountMeta::new_readonly(rl_settings_pubkey, false),
-        AccountMeta::new_readonly(relay_round_pubkey, false),
-        AccountMeta::new_readonly(system_program::id(), false),
-        AccountMeta::new_readonly(sysvar::rent::id(), false),
-        AccountMeta::new_readonly(sysvar::clock::id(), false),
-    ];
-
-    if !payload.is_empty() {
+    let (proxy_pubkey, mint_pubkey) = if !payload.is_empty() {
         let mint_pubkey = get_mint_address(&token);
         let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient);
 
-        accounts.push(AccountMeta::new(proxy_pubkey, false));
-        accounts.push(AccountMeta::new(mint_pubkey, false));
-        accounts.push(AccountMeta::new(spl_token::id(), false));
+        (Some(proxy_pubkey), Some(mint_pubkey))
+    } else {
+        (None, None)
+    };
+
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let accounts = WithdrawalMultiTokenEverRequestAccounts {
+        funder: funder_pubkey,
+        author: author_pubkey,
+        withdrawal: withdrawal_pubkey,
+        proposal_index: proposal_index_pubkey,
+        rl_settings: rl_settings_pubkey,
+        relay_round: relay_round_pubkey,
+        system_program: system_program::id(),
+        rent_sysvar: sysvar::rent::id(),
+        clock_sysvar: sysvar::clock::id(),
+        proxy: proxy_pubkey,
+        mint: mint_pubkey,
+        token_program: mint_pubkey.map(|_| spl_token::id()),
+        bridge_stats: bridge_stats_pubkey,
     }
+    .to_account_metas();
 
     let data = TokenProxyInstruction::WithdrawMultiTokenEverRequest {
         event_timestamp,
@@ -345,6 +813,33 @@ pub fn withdrawal_multi_token_ever_request_ix(
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalMultiTokenSolRequestAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    pub token_settings: Pubkey,
+    pub rl_settings: Pubkey,
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    // Required only for withdrawals with payload
+    #[account(writable)]
+    pub proxy: Option<Pubkey>,
+    #[account(writable)]
+    pub mint: Option<Pubkey>,
+    #[account(writable)]
+    pub token_program: Option<Pubkey>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn withdrawal_multi_token_sol_request_ix(
     funder_pubkey: Pubkey,
@@ -370,32 +865,39 @@ pub fn withdrawal_multi_token_sol_request_ix(
         payload.clone(),
     );
 
+    let proposal_index_pubkey = get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
     let token_settings_pubkey = get_token_settings_sol_address(&mint);
+    let bridge_stats_pubkey = get_bridge_stats_address();
 
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
     let relay_round_pubkey =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
-    let mut accounts = vec![
-        AccountMeta::new(funder_pubkey, true),
-        AccountMeta::new(author_pubkey, true),
-        AccountMeta::new(withdrawal_pubkey, false),
-        AccountMeta::new_readonly(token_settings_pubkey, false),
-        AccountMeta::new_readonly(rl_settings_pubkey, false),
-        AccountMeta::new_readonly(relay_round_pubkey, false),
-        AccountMeta::new_readonly(system_program::id(), false),
-        AccountMeta::new_readonly(sysvar::rent::id(), false),
-        AccountMeta::new_readonly(sysvar::clock::id(), false),
-    ];
-
-    if !payload.is_empty() {
-        let proxy_pubkey = get_proxy_address(&mint, &recipient);
-
-        accounts.push(AccountMeta::new(proxy_pubkey, false));
-        accounts.push(AccountMeta::new(mint, false));
-        accounts.push(AccountMeta::new(spl_token::id(), false));
+    let proxy_pubkey = (!payload.is_empty()).then(|| get_proxy_address(&mint, &recipient));
+
+    let accounts = WithdrawalMultiTokenSolRequestAccounts {
+        funder: funder_pubkey,
+        author: author_pubkey,
+        withdrawal: withdrawal_pubkey,
+        proposal_index: proposal_index_pubkey,
+        token_settings: token_settings_pubkey,
+        rl_settings: rl_settings_pubkey,
+        relay_round: relay_round_pubkey,
+        system_program: system_program::id(),
+        rent_sysvar: sysvar::rent::id(),
+        clock_sysvar: sysvar::clock::id(),
+        proxy: proxy_pubkey,
+        mint: proxy_pubkey.map(|_| mint),
+        token_program: proxy_pubkey.map(|_| spl_token::id()),
+        bridge_stats: bridge_stats_pubkey,
     }
+    .to_account_metas();
 
     let data = TokenProxyInstruction::WithdrawMultiTokenSolRequest {
         event_timestamp,
@@ -416,6 +918,16 @@ pub fn withdrawal_multi_token_sol_request_ix(
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct VoteForWithdrawalRequestAccounts {
+    #[account(signer, writable)]
+    pub voter: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+}
+
 pub fn vote_for_withdrawal_request_ix(
     voter_pubkey: Pubkey,
     withdrawal_pubkey: Pubkey,
@@ -425,29 +937,115 @@ pub fn vote_for_withdrawal_request_ix(
     let relay_round_pubkey =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+
     let data = TokenProxyInstruction::VoteForWithdrawRequest { vote }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(voter_pubkey, true),
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new_readonly(relay_round_pubkey, false),
-        ],
+        accounts: VoteForWithdrawalRequestAccounts {
+            voter: voter_pubkey,
+            withdrawal: withdrawal_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct VoteForWithdrawalRequestBySignaturesAccounts {
+    #[account(signer, writable)]
+    pub submitter: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    pub relay_round: Pubkey,
+    pub instructions_sysvar: Pubkey,
+    pub rl_settings: Pubkey,
+}
+
+pub fn vote_for_withdrawal_request_by_signatures_ix(
+    submitter_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+
+    let data = TokenProxyInstruction::VoteForWithdrawRequestBySignatures
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: VoteForWithdrawalRequestBySignaturesAccounts {
+            submitter: submitter_pubkey,
+            withdrawal: withdrawal_pubkey,
+            relay_round: relay_round_pubkey,
+            instructions_sysvar: sysvar::instructions::id(),
+            rl_settings: rl_settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalEverAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub recipient_hook: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
 pub fn withdrawal_ever_ix(
     withdrawal_pubkey: Pubkey,
     recipient_token_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
     token: EverAddress,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
     let mint_pubkey = get_mint_address(&token);
     let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
 
     let data = TokenProxyInstruction::WithdrawMultiTokenEver
         .try_to_vec()
@@ -455,140 +1053,457 @@ pub fn withdrawal_ever_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
-        ],
+        accounts: WithdrawalEverAccounts {
+            withdrawal: withdrawal_pubkey,
+            mint: mint_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalEverWithPayloadAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub proxy: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub recipient_hook: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
 pub fn withdrawal_ever_with_payload_ix(
     withdrawal_pubkey: Pubkey,
     recipient_pubkey: Pubkey,
     token: EverAddress,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
     let mint_pubkey = get_mint_address(&token);
     let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
 
     let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
 
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
     let data = TokenProxyInstruction::WithdrawMultiTokenEver
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(proxy_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
-        ],
+        accounts: WithdrawalEverWithPayloadAccounts {
+            withdrawal: withdrawal_pubkey,
+            mint: mint_pubkey,
+            proxy: proxy_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateEverTokenAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub recipient_hook: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    // If token settings account is not created
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    pub recipient: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub token_decimals: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
 pub fn create_ever_token_ix(
     funder_pubkey: Pubkey,
     withdrawal_pubkey: Pubkey,
     recipient_pubkey: Pubkey,
     token: EverAddress,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
     let mint_pubkey = get_mint_address(&token);
     let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
+    let token_decimals_pubkey = get_token_decimals_address(&token);
 
     let recipient_token_pubkey =
         spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
 
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
     let data = TokenProxyInstruction::WithdrawMultiTokenEver
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
-            // If token settings account is not created
-            AccountMeta::new(funder_pubkey, true),
-            AccountMeta::new_readonly(recipient_pubkey, false),
-            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
-        ],
+        accounts: CreateEverTokenAccounts {
+            withdrawal: withdrawal_pubkey,
+            mint: mint_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            funder: funder_pubkey,
+            token_decimals: token_decimals_pubkey,
+            recipient: recipient_pubkey,
+            associated_token_program: spl_associated_token_account::id(),
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateEverTokenWithPayloadAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub proxy: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub recipient_hook: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    // If token settings account is not created
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    pub recipient: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub token_decimals: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
 pub fn create_ever_token_with_payload_ix(
     funder_pubkey: Pubkey,
     withdrawal_pubkey: Pubkey,
     recipient_pubkey: Pubkey,
     token: EverAddress,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
     let mint_pubkey = get_mint_address(&token);
     let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
+    let token_decimals_pubkey = get_token_decimals_address(&token);
 
     let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
 
     let recipient_token_pubkey =
         spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
 
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
     let data = TokenProxyInstruction::WithdrawMultiTokenEver
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(proxy_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
-            // If token settings account is not created
-            AccountMeta::new(funder_pubkey, true),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new_readonly(recipient_pubkey, false),
-            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
-        ],
+        accounts: CreateEverTokenWithPayloadAccounts {
+            withdrawal: withdrawal_pubkey,
+            mint: mint_pubkey,
+            proxy: proxy_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            funder: funder_pubkey,
+            token_decimals: token_decimals_pubkey,
+            recipient_token: recipient_token_pubkey,
+            recipient: recipient_pubkey,
+            associated_token_program: spl_associated_token_account::id(),
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalEverV2Accounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub recipient_account: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub recipient_hook: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    pub recipient: Pubkey,
+    pub associated_token_program: Pubkey,
+    pub token_decimals: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
+pub fn withdrawal_ever_v2_ix(
+    funder_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    token: EverAddress,
+    with_payload: bool,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
+    let token_decimals_pubkey = get_token_decimals_address(&token);
+
+    let recipient_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
+
+    let recipient_account_pubkey = match with_payload {
+        true => get_proxy_address(&mint_pubkey, &recipient_pubkey),
+        false => recipient_token_pubkey,
+    };
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenEverV2 {
+        version: CURRENT_IX_VERSION,
+        create_if_needed: true,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: WithdrawalEverV2Accounts {
+            withdrawal: withdrawal_pubkey,
+            mint: mint_pubkey,
+            recipient_account: recipient_account_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            funder: funder_pubkey,
+            token_decimals: token_decimals_pubkey,
+            recipient_token: recipient_token_pubkey,
+            recipient: recipient_pubkey,
+            associated_token_program: spl_associated_token_account::id(),
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalSolAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub mint: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub recipient_hook: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
 pub fn withdrawal_sol_ix(
     withdrawal_pubkey: Pubkey,
     recipient_token_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
     mint_pubkey: Pubkey,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
     let vault_pubkey = get_vault_address(&mint_pubkey);
     let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
 
     let data = TokenProxyInstruction::WithdrawMultiTokenSol
         .try_to_vec()
@@ -596,30 +1511,85 @@ pub fn withdrawal_sol_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(vault_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(mint_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
-        ],
+        accounts: WithdrawalSolAccounts {
+            withdrawal: withdrawal_pubkey,
+            vault: vault_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            mint: mint_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn withdrawal_sol_with_payload_ix(
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalSolWithAtaAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub mint: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub recipient_hook: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    pub recipient: Pubkey,
+    pub system_program: Pubkey,
+    pub associated_token_program: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
+pub fn withdrawal_sol_with_ata_ix(
+    funder_pubkey: Pubkey,
     withdrawal_pubkey: Pubkey,
     recipient_pubkey: Pubkey,
     mint_pubkey: Pubkey,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
     let vault_pubkey = get_vault_address(&mint_pubkey);
     let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
 
-    let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
+    let recipient_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
 
     let data = TokenProxyInstruction::WithdrawMultiTokenSol
         .try_to_vec()
@@ -627,22 +1597,123 @@ pub fn withdrawal_sol_with_payload_ix(
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(vault_pubkey, false),
-            AccountMeta::new(proxy_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(mint_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
-        ],
+        accounts: WithdrawalSolWithAtaAccounts {
+            withdrawal: withdrawal_pubkey,
+            vault: vault_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            mint: mint_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            funder: funder_pubkey,
+            recipient: recipient_pubkey,
+            system_program: system_program::id(),
+            associated_token_program: spl_associated_token_account::id(),
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn change_guardian_ix(owner: Pubkey, new_guardian: Pubkey) -> Instruction {
-    let settings_pubkey = get_settings_address();
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalSolWithPayloadAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    #[account(writable)]
+    pub proxy: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub mint: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub recipient_hook: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
+pub fn withdrawal_sol_with_payload_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
+
+    let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenSol
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: WithdrawalSolWithPayloadAccounts {
+            withdrawal: withdrawal_pubkey,
+            vault: vault_pubkey,
+            proxy: proxy_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            mint: mint_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeGuardianAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_guardian_ix(owner: Pubkey, new_guardian: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
     let program_data_pubkey = get_programdata_address();
 
     let data = TokenProxyInstruction::ChangeGuardian { new_guardian }
@@ -651,15 +1722,35 @@ pub fn change_guardian_ix(owner: Pubkey, new_guardian: Pubkey) -> Instruction {
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: ChangeGuardianAccounts {
+            owner,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+// Instruction to insert into governance proposal transaction
+pub fn change_guardian_by_governance_ix(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    new_guardian: Pubkey,
+) -> Instruction {
+    let governance_pubkey = get_governance_address(governance_program_id, realm);
+    change_guardian_ix(governance_pubkey, new_guardian)
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeManagerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
 pub fn change_manager_ix(owner: Pubkey, new_manager: Pubkey) -> Instruction {
     let settings_pubkey = get_settings_address();
     let program_data_pubkey = get_programdata_address();
@@ -670,15 +1761,35 @@ pub fn change_manager_ix(owner: Pubkey, new_manager: Pubkey) -> Instruction {
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: ChangeManagerAccounts {
+            owner,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
+// Instruction to insert into governance proposal transaction
+pub fn change_manager_by_governance_ix(
+    governance_program_id: &Pubkey,
+    realm: &Pubkey,
+    new_manager: Pubkey,
+) -> Instruction {
+    let governance_pubkey = get_governance_address(governance_program_id, realm);
+    change_manager_ix(governance_pubkey, new_manager)
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeWithdrawalManagerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
 pub fn change_withdrawal_manager_ix(owner: Pubkey, new_withdrawal_manager: Pubkey) -> Instruction {
     let settings_pubkey = get_settings_address();
     let program_data_pubkey = get_programdata_address();
@@ -691,611 +1802,4180 @@ pub fn change_withdrawal_manager_ix(owner: Pubkey, new_withdrawal_manager: Pubke
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: ChangeWithdrawalManagerAccounts {
+            owner,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn change_deposit_limit_ix(
-    manager_pubkey: Pubkey,
-    token_settings_pubkey: Pubkey,
-    new_deposit_limit: u64,
-) -> Instruction {
-    let settings_pubkey = get_settings_address();
-
-    let data = TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
-        .try_to_vec()
-        .expect("pack");
-
-    Instruction {
-        program_id: id(),
-        accounts: vec![
-            AccountMeta::new(manager_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-        ],
-        data,
-    }
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ProposeRoleAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
 }
 
-pub fn change_withdrawal_limits_ix(
-    manager_pubkey: Pubkey,
-    token_settings_pubkey: Pubkey,
-    new_withdrawal_limit: Option<u64>,
-    new_withdrawal_daily_limit: Option<u64>,
-) -> Instruction {
+pub fn propose_role_ix(owner: Pubkey, role: Role, new_authority: Option<Pubkey>) -> Instruction {
     let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::ChangeWithdrawalLimits {
-        new_withdrawal_limit,
-        new_withdrawal_daily_limit,
+    let data = TokenProxyInstruction::ProposeRole {
+        role,
+        new_authority,
     }
     .try_to_vec()
     .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(manager_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-        ],
+        accounts: ProposeRoleAccounts {
+            owner,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn change_deposit_limit_by_owner_ix(
-    owner_pubkey: Pubkey,
-    token_settings_pubkey: Pubkey,
-    new_deposit_limit: u64,
-) -> Instruction {
+#[derive(Debug, Clone, AccountMetas)]
+pub struct AcceptRoleAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn accept_role_ix(authority: Pubkey) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
+    let data = TokenProxyInstruction::AcceptRole
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: AcceptRoleAccounts {
+            authority,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn change_withdrawal_limits_by_owner_ix(
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ScheduleChangeAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub pending_change: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn schedule_change_ix(
+    funder_pubkey: Pubkey,
     owner_pubkey: Pubkey,
-    token_settings_pubkey: Pubkey,
-    new_withdrawal_limit: Option<u64>,
-    new_withdrawal_daily_limit: Option<u64>,
+    change_seed: u128,
+    change: TimelockedChange,
 ) -> Instruction {
+    let pending_change_pubkey = get_pending_change_address(change_seed);
     let settings_pubkey = get_settings_address();
     let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::ChangeWithdrawalLimits {
-        new_withdrawal_limit,
-        new_withdrawal_daily_limit,
+    let data = TokenProxyInstruction::ScheduleChange {
+        change_seed,
+        change,
     }
     .try_to_vec()
     .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: ScheduleChangeAccounts {
+            funder: funder_pubkey,
+            owner: owner_pubkey,
+            pending_change: pending_change_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn enable_emergency_ix(guardian_pubkey: Pubkey) -> Instruction {
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ExecuteChangeAccounts {
+    #[account(writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub pending_change: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn execute_change_ix(
+    author_pubkey: Pubkey,
+    change_seed: u128,
+    change: &TimelockedChange,
+) -> Instruction {
+    let pending_change_pubkey = get_pending_change_address(change_seed);
     let settings_pubkey = get_settings_address();
 
-    let data = TokenProxyInstruction::EnableEmergencyMode
+    let data = TokenProxyInstruction::ExecuteChange
         .try_to_vec()
         .expect("pack");
 
+    let mut accounts = ExecuteChangeAccounts {
+        author: author_pubkey,
+        pending_change: pending_change_pubkey,
+        settings: settings_pubkey,
+    }
+    .to_account_metas();
+
+    // Token settings changed by the change
+    if let Some(token_settings_pubkey) = change.token_settings() {
+        accounts.push(AccountMeta::new(token_settings_pubkey, false));
+    }
+
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(guardian_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-        ],
+        accounts,
         data,
     }
 }
 
-pub fn enable_emergency_by_owner_ix(owner_pubkey: Pubkey) -> Instruction {
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CancelChangeAccounts {
+    #[account(signer, writable)]
+    pub guardian: Pubkey,
+    #[account(writable)]
+    pub pending_change: Pubkey,
+    #[account(writable)]
+    pub author: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn cancel_change_ix(
+    guardian_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    change_seed: u128,
+) -> Instruction {
+    let pending_change_pubkey = get_pending_change_address(change_seed);
     let settings_pubkey = get_settings_address();
-    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::EnableEmergencyMode
+    let data = TokenProxyInstruction::CancelChange
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: CancelChangeAccounts {
+            guardian: guardian_pubkey,
+            pending_change: pending_change_pubkey,
+            author: author_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn disable_emergency_ix(owner_pubkey: Pubkey) -> Instruction {
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CancelChangeByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub pending_change: Pubkey,
+    #[account(writable)]
+    pub author: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn cancel_change_by_owner_ix(
+    owner_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    change_seed: u128,
+) -> Instruction {
+    let pending_change_pubkey = get_pending_change_address(change_seed);
     let settings_pubkey = get_settings_address();
     let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::DisableEmergencyMode
+    let data = TokenProxyInstruction::CancelChange
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: CancelChangeByOwnerAccounts {
+            owner: owner_pubkey,
+            pending_change: pending_change_pubkey,
+            author: author_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn enable_emergency_token_ix(
-    guardian_pubkey: Pubkey,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeDepositLimitAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_deposit_limit_ix(
+    manager_pubkey: Pubkey,
     token_settings_pubkey: Pubkey,
+    new_deposit_limit: u64,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
 
-    let data = TokenProxyInstruction::EnableTokenEmergencyMode
+    let data = TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(guardian_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-        ],
+        accounts: ChangeDepositLimitAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn enable_emergency_token_by_owner_ix(
-    owner_pubkey: Pubkey,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeMinDepositAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_min_deposit_ix(
+    manager_pubkey: Pubkey,
     token_settings_pubkey: Pubkey,
+    new_min_deposit_amount: u64,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::EnableTokenEmergencyMode
-        .try_to_vec()
-        .expect("pack");
+    let data = TokenProxyInstruction::ChangeMinDeposit {
+        new_min_deposit_amount,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: ChangeMinDepositAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn disable_emergency_token_ix(
-    owner_pubkey: Pubkey,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeFillsRestrictedAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_fills_restricted_ix(
+    manager_pubkey: Pubkey,
     token_settings_pubkey: Pubkey,
+    fills_restricted: bool,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::DisableTokenEmergencyMode
+    let data = TokenProxyInstruction::ChangeFillsRestricted { fills_restricted }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(owner_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
+        accounts: ChangeFillsRestrictedAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn approve_withdrawal_ever_ix(
-    authority_pubkey: Pubkey,
-    withdrawal_pubkey: Pubkey,
-    recipient_token_pubkey: Pubkey,
-    mint_pubkey: Pubkey,
-    token: &EverAddress,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeReferralFeeAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_referral_fee_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    referral_fee_bps: u16,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let token_settings_pubkey = get_token_settings_ever_address(token);
 
-    let data = TokenProxyInstruction::ApproveWithdrawEver
+    let data = TokenProxyInstruction::ChangeReferralFee { referral_fee_bps }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
-        ],
+        accounts: ChangeReferralFeeAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn approve_withdrawal_sol_ix(
-    authority_pubkey: Pubkey,
-    withdrawal_pubkey: Pubkey,
-    recipient_token_pubkey: Pubkey,
-    mint_pubkey: Pubkey,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeDepositDailyLimitAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_deposit_daily_limit_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_deposit_daily_limit: Option<u64>,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let vault_pubkey = get_vault_address(&mint_pubkey);
-    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
 
-    let data = TokenProxyInstruction::ApproveWithdrawSol
-        .try_to_vec()
-        .expect("pack");
+    let data = TokenProxyInstruction::ChangeDepositDailyLimit {
+        new_deposit_daily_limit,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(vault_pubkey, false),
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
-        ],
+        accounts: ChangeDepositDailyLimitAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn update_fee_ix(
-    authority_pubkey: Pubkey,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeWithdrawalEpochLengthAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_withdrawal_epoch_length_ix(
+    manager_pubkey: Pubkey,
     token_settings_pubkey: Pubkey,
-    fee_type: FeeType,
-    multiplier: u64,
-    divisor: u64,
+    new_withdrawal_epoch_length: Option<i64>,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
 
-    let data = TokenProxyInstruction::UpdateFee {
-        fee_type,
-        multiplier,
-        divisor,
+    let data = TokenProxyInstruction::ChangeWithdrawalEpochLength {
+        new_withdrawal_epoch_length,
     }
     .try_to_vec()
     .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-        ],
+        accounts: ChangeWithdrawalEpochLengthAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn update_token_name_ix(
-    authority_pubkey: Pubkey,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeWithdrawalLimitsAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_withdrawal_limits_ix(
+    manager_pubkey: Pubkey,
     token_settings_pubkey: Pubkey,
-    symbol: String,
-    name: String,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
 
-    let data = TokenProxyInstruction::UpdateTokenName { symbol, name }
-        .try_to_vec()
-        .expect("pack");
+    let data = TokenProxyInstruction::ChangeWithdrawalLimits {
+        new_withdrawal_limit,
+        new_withdrawal_daily_limit,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-        ],
+        accounts: ChangeWithdrawalLimitsAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn withdrawal_ever_fee_ix(
-    authority_pubkey: Pubkey,
-    mint_pubkey: Pubkey,
-    recipient_token_pubkey: Pubkey,
-    token: &EverAddress,
-    amount: u64,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeDepositLimitByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_deposit_limit_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_deposit_limit: u64,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let token_settings_pubkey = get_token_settings_ever_address(token);
+    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::WithdrawEverFee { amount }
+    let data = TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
+        accounts: ChangeDepositLimitByOwnerAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn withdrawal_sol_fee_ix(
-    authority_pubkey: Pubkey,
-    recipient_token_pubkey: Pubkey,
-    mint_pubkey: Pubkey,
-    amount: u64,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeDepositDailyLimitByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_deposit_daily_limit_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_deposit_daily_limit: Option<u64>,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let vault_pubkey = get_vault_address(&mint_pubkey);
-    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::WithdrawSolFee { amount }
-        .try_to_vec()
-        .expect("pack");
+    let data = TokenProxyInstruction::ChangeDepositDailyLimit {
+        new_deposit_daily_limit,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(vault_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
+        accounts: ChangeDepositDailyLimitByOwnerAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn change_bounty_for_withdrawal_sol_ix(
-    author_pubkey: &Pubkey,
-    withdrawal_pubkey: &Pubkey,
-    bounty: u64,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeWithdrawalEpochLengthByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_withdrawal_epoch_length_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_withdrawal_epoch_length: Option<i64>,
 ) -> Instruction {
-    let data = TokenProxyInstruction::ChangeBountyForWithdrawSol { bounty }
-        .try_to_vec()
-        .expect("pack");
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeWithdrawalEpochLength {
+        new_withdrawal_epoch_length,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(*author_pubkey, true),
-            AccountMeta::new(*withdrawal_pubkey, false),
-        ],
+        accounts: ChangeWithdrawalEpochLengthByOwnerAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn cancel_withdrawal_sol_ix(
-    funder_pubkey: Pubkey,
-    author_pubkey: Pubkey,
-    withdrawal_pubkey: Pubkey,
-    mint_pubkey: Pubkey,
-    deposit_seed: u128,
-    recipient: EverAddress,
-    value: u64,
-    expected_evers: UInt256,
-    payload: Vec<u8>,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeWithdrawalLimitsByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_withdrawal_limits_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let multivault_pubkey = get_multivault_address();
-    let deposit_pubkey = get_deposit_address(deposit_seed);
-    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::CancelWithdrawSol {
-        deposit_seed,
-        recipient,
-        value,
-        expected_evers,
-        payload,
+    let data = TokenProxyInstruction::ChangeWithdrawalLimits {
+        new_withdrawal_limit,
+        new_withdrawal_daily_limit,
     }
     .try_to_vec()
     .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(funder_pubkey, true),
-            AccountMeta::new(author_pubkey, true),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(deposit_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(token_settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-            AccountMeta::new(multivault_pubkey, false),
-        ],
+        accounts: ChangeWithdrawalLimitsByOwnerAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn fill_withdrawal_sol_ix(
-    funder_pubkey: Pubkey,
-    author_pubkey: Pubkey,
-    mint_pubkey: Pubkey,
-    deposit_seed: u128,
-    recipient: EverAddress,
-    amount: u64,
-    withdrawals: Vec<(Pubkey, Pubkey)>,
-    vault_pubkey: Option<Pubkey>,
-    value: u64,
-    expected_evers: UInt256,
-    payload: Vec<u8>,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeWithdrawalLimitExemptionsAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_withdrawal_limit_exemptions_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_withdrawal_limit_exemptions: Vec<Pubkey>,
 ) -> Instruction {
-    let author_token_pubkey =
-        spl_associated_token_account::get_associated_token_address(&author_pubkey, &mint_pubkey);
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::ChangeWithdrawalLimitExemptions {
+        new_withdrawal_limit_exemptions,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeWithdrawalLimitExemptionsAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeWithdrawalLimitExemptionsByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
 
+pub fn change_withdrawal_limit_exemptions_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_withdrawal_limit_exemptions: Vec<Pubkey>,
+) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let multivault_pubkey = get_multivault_address();
-    let deposit_pubkey = get_deposit_address(deposit_seed);
-    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
 
-    let data = TokenProxyInstruction::FillWithdrawSol {
-        deposit_seed,
-        recipient,
-        amount,
-        value,
-        expected_evers,
-        payload,
+    let data = TokenProxyInstruction::ChangeWithdrawalLimitExemptions {
+        new_withdrawal_limit_exemptions,
     }
     .try_to_vec()
     .expect("pack");
 
-    let mut accounts = vec![
-        AccountMeta::new(funder_pubkey, true),
-        AccountMeta::new(author_pubkey, true),
-        AccountMeta::new(author_token_pubkey, false),
-        AccountMeta::new(mint_pubkey, false),
-        AccountMeta::new(deposit_pubkey, false),
-        AccountMeta::new_readonly(settings_pubkey, false),
-        AccountMeta::new_readonly(system_program::id(), false),
-        AccountMeta::new_readonly(token_settings_pubkey, false),
-        AccountMeta::new_readonly(spl_token::id(), false),
-        AccountMeta::new_readonly(sysvar::rent::id(), false),
-        AccountMeta::new(multivault_pubkey, false),
-    ];
+    Instruction {
+        program_id: id(),
+        accounts: ChangeWithdrawalLimitExemptionsByOwnerAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct EnableEmergencyAccounts {
+    #[account(signer, writable)]
+    pub guardian: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
 
-    for (withdrawal_pubkey, to_pubkey) in withdrawals {
-        let recipient_token_pubkey =
-            spl_associated_token_account::get_associated_token_address(&to_pubkey, &mint_pubkey);
+pub fn enable_emergency_ix(
+    guardian_pubkey: Pubkey,
+    expiry: Option<i64>,
+    reason: Option<u32>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::EnableEmergencyModeV2 { expiry, reason }
+        .try_to_vec()
+        .expect("pack");
 
-        accounts.push(AccountMeta::new(withdrawal_pubkey, false));
-        accounts.push(AccountMeta::new(recipient_token_pubkey, false));
+    Instruction {
+        program_id: id(),
+        accounts: EnableEmergencyAccounts {
+            guardian: guardian_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
     }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct EnableEmergencyByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn enable_emergency_by_owner_ix(
+    owner_pubkey: Pubkey,
+    expiry: Option<i64>,
+    reason: Option<u32>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::EnableEmergencyModeV2 { expiry, reason }
+        .try_to_vec()
+        .expect("pack");
 
-    if let Some(vault_pubkey) = vault_pubkey {
-        accounts.push(AccountMeta::new(vault_pubkey, false));
+    Instruction {
+        program_id: id(),
+        accounts: EnableEmergencyByOwnerAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
     }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct DisableEmergencyAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn disable_emergency_ix(owner_pubkey: Pubkey, reason: Option<u32>) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::DisableEmergencyModeV2 { reason }
+        .try_to_vec()
+        .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts,
+        accounts: DisableEmergencyAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn withdrawal_proxy_ix(
-    recipient_pubkey: Pubkey,
-    recipient_token_pubkey: Pubkey,
-    mint_pubkey: Pubkey,
-    amount: u64,
+#[derive(Debug, Clone, AccountMetas)]
+pub struct EnableEmergencyTokenAccounts {
+    #[account(signer, writable)]
+    pub guardian: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn enable_emergency_token_ix(
+    guardian_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    expiry: Option<i64>,
+    reason: Option<u32>,
 ) -> Instruction {
-    let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
+    let settings_pubkey = get_settings_address();
 
-    let data = TokenProxyInstruction::WithdrawProxy { amount }
+    let data = TokenProxyInstruction::EnableTokenEmergencyModeV2 { expiry, reason }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(recipient_pubkey, true),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(proxy_pubkey, false),
-            AccountMeta::new_readonly(mint_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
+        accounts: EnableEmergencyTokenAccounts {
+            guardian: guardian_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn close_deposit_ix(author_pubkey: Pubkey, deposit_pubkey: Pubkey) -> Instruction {
-    let data = TokenProxyInstruction::CloseDeposit
+#[derive(Debug, Clone, AccountMetas)]
+pub struct EnableEmergencyTokenByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn enable_emergency_token_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    expiry: Option<i64>,
+    reason: Option<u32>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::EnableTokenEmergencyModeV2 { expiry, reason }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: EnableEmergencyTokenByOwnerAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct DisableEmergencyTokenAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn disable_emergency_token_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    reason: Option<u32>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::DisableTokenEmergencyModeV2 { reason }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(author_pubkey, true),
-            AccountMeta::new(deposit_pubkey, false),
-        ],
+        accounts: DisableEmergencyTokenAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn close_withdrawal_ix(
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ApproveWithdrawalEverAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub recipient_hook: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+}
+
+pub fn approve_withdrawal_ever_ix(
+    authority_pubkey: Pubkey,
     withdrawal_pubkey: Pubkey,
-    withdrawal_author_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    token: &EverAddress,
+    note: Option<Vec<u8>>,
 ) -> Instruction {
-    let data = TokenProxyInstruction::CloseWithdrawal
+    let settings_pubkey = get_settings_address();
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
+
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
+    let data = TokenProxyInstruction::ApproveWithdrawEverV2 { note }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(withdrawal_author_pubkey, false),
-        ],
+        accounts: ApproveWithdrawalEverAccounts {
+            authority: authority_pubkey,
+            mint: mint_pubkey,
+            withdrawal: withdrawal_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            clock_sysvar: sysvar::clock::id(),
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
 
-pub fn withdrawal_multi_vault_ix(
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ApproveWithdrawalSolAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub recipient_hook: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+}
+
+pub fn approve_withdrawal_sol_ix(
     authority_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
     recipient_pubkey: Pubkey,
-    amount: u64,
+    mint_pubkey: Pubkey,
+    note: Option<Vec<u8>>,
 ) -> Instruction {
     let settings_pubkey = get_settings_address();
-    let multi_vault_pubkey = get_multivault_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
 
-    let data = TokenProxyInstruction::WithdrawMultiVault { amount }
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
+    let data = TokenProxyInstruction::ApproveWithdrawSolV2 { note }
         .try_to_vec()
         .expect("pack");
 
     Instruction {
         program_id: id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(recipient_pubkey, false),
-            AccountMeta::new(multi_vault_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
+        accounts: ApproveWithdrawalSolAccounts {
+            authority: authority_pubkey,
+            vault: vault_pubkey,
+            withdrawal: withdrawal_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            clock_sysvar: sysvar::clock::id(),
+            recipient_hook: recipient_hook_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+pub fn reject_withdrawal_ever_ix(
+    authority_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    token: &EverAddress,
+    note: Option<Vec<u8>>,
+) -> Instruction {
+    let mint_pubkey = get_mint_address(token);
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+
+    reject_withdrawal_ix(
+        authority_pubkey,
+        withdrawal_pubkey,
+        mint_pubkey,
+        token_settings_pubkey,
+        TokenProxyInstruction::RejectWithdrawEver { note },
+    )
+}
+
+pub fn reject_withdrawal_sol_ix(
+    authority_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    note: Option<Vec<u8>>,
+) -> Instruction {
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    reject_withdrawal_ix(
+        authority_pubkey,
+        withdrawal_pubkey,
+        mint_pubkey,
+        token_settings_pubkey,
+        TokenProxyInstruction::RejectWithdrawSol { note },
+    )
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RejectWithdrawalAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+}
+
+fn reject_withdrawal_ix(
+    authority_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    instruction: TokenProxyInstruction,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+    let approval_queue_pubkey = get_approval_queue_address();
+
+    let data = instruction.try_to_vec().expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RejectWithdrawalAccounts {
+            authority: authority_pubkey,
+            withdrawal: withdrawal_pubkey,
+            mint: mint_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            approval_queue: approval_queue_pubkey,
+        }
+        .to_account_metas(),
         data,
     }
 }
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct UpdateFeeAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn update_fee_ix(
+    authority_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    fee_type: FeeType,
+    multiplier: u64,
+    divisor: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::UpdateFee {
+        fee_type,
+        multiplier,
+        divisor,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: UpdateFeeAccounts {
+            authority: authority_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+pub fn update_fee_tiers_ix(
+    authority_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    fee_type: FeeType,
+    fee_tiers: FeeTiers,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::UpdateFeeTiers {
+        fee_type,
+        fee_tiers,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: UpdateFeeAccounts {
+            authority: authority_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct UpdateTokenNameAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn update_token_name_ix(
+    authority_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    symbol: String,
+    name: String,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::UpdateTokenName { symbol, name }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: UpdateTokenNameAccounts {
+            authority: authority_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalEverFeeAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn withdrawal_ever_fee_ix(
+    authority_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    token: &EverAddress,
+    amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawEverFee { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: WithdrawalEverFeeAccounts {
+            authority: authority_pubkey,
+            mint: mint_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalSolFeeAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn withdrawal_sol_fee_ix(
+    authority_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawSolFee { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: WithdrawalSolFeeAccounts {
+            authority: authority_pubkey,
+            vault: vault_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            recipient_token: recipient_token_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeBountyForWithdrawalSolAccounts {
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+}
+
+pub fn change_bounty_for_withdrawal_sol_ix(
+    author_pubkey: &Pubkey,
+    withdrawal_pubkey: &Pubkey,
+    bounty: u64,
+) -> Instruction {
+    let data = TokenProxyInstruction::ChangeBountyForWithdrawSol { bounty }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeBountyForWithdrawalSolAccounts {
+            author: *author_pubkey,
+            withdrawal: *withdrawal_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct DepositBountyForWithdrawalSolAccounts {
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub author_token: Pubkey,
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub bounty: Pubkey,
+    pub mint: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn deposit_bounty_for_withdrawal_sol_ix(
+    author_pubkey: &Pubkey,
+    withdrawal_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let author_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(author_pubkey, mint_pubkey);
+    let bounty_pubkey = get_bounty_address(withdrawal_pubkey, author_pubkey);
+
+    let data = TokenProxyInstruction::DepositBountyForWithdrawSol { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: DepositBountyForWithdrawalSolAccounts {
+            author: *author_pubkey,
+            author_token: author_token_pubkey,
+            withdrawal: *withdrawal_pubkey,
+            bounty: bounty_pubkey,
+            mint: *mint_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RefundBountyForWithdrawalSolAccounts {
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub author_token: Pubkey,
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub bounty: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn refund_bounty_for_withdrawal_sol_ix(
+    author_pubkey: &Pubkey,
+    withdrawal_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+) -> Instruction {
+    let author_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(author_pubkey, mint_pubkey);
+    let bounty_pubkey = get_bounty_address(withdrawal_pubkey, author_pubkey);
+
+    let data = TokenProxyInstruction::RefundBountyForWithdrawSol
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RefundBountyForWithdrawalSolAccounts {
+            author: *author_pubkey,
+            author_token: author_token_pubkey,
+            withdrawal: *withdrawal_pubkey,
+            bounty: bounty_pubkey,
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+/// Pay escrowed bounty of the withdrawal to the filler. Append it to fill withdrawal
+/// instruction once per withdrawal with escrow, after all other accounts
+pub fn append_bounty_account(ix: &mut Instruction, withdrawal: &Pubkey, author: &Pubkey) {
+    ix.accounts.push(AccountMeta::new(
+        get_bounty_address(withdrawal, author),
+        false,
+    ));
+}
+
+/// Refund escrowed bounty to the author's associated token account. Append it to
+/// cancel withdrawal instruction, after all other accounts
+pub fn append_bounty_refund_accounts(
+    ix: &mut Instruction,
+    withdrawal: &Pubkey,
+    author: &Pubkey,
+    mint: &Pubkey,
+) {
+    ix.accounts.extend([
+        AccountMeta::new(get_bounty_address(withdrawal, author), false),
+        AccountMeta::new(
+            spl_associated_token_account::get_associated_token_address(author, mint),
+            false,
+        ),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ]);
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CancelWithdrawalSolAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub deposit: Pubkey,
+    pub settings: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    #[account(writable)]
+    pub multivault: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
+pub fn cancel_withdrawal_sol_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let deposit_pubkey = get_deposit_address(deposit_seed);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let data = TokenProxyInstruction::CancelWithdrawSol {
+        deposit_seed,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CancelWithdrawalSolAccounts {
+            funder: funder_pubkey,
+            author: author_pubkey,
+            mint: mint_pubkey,
+            withdrawal: withdrawal_pubkey,
+            deposit: deposit_pubkey,
+            settings: settings_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+            multivault: multivault_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct FillWithdrawalSolAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub author_token: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub deposit: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    #[account(writable)]
+    pub multivault: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    // Withdrawal and its recipient token account, one after another
+    #[account(writable)]
+    pub withdrawals: Vec<Pubkey>,
+    #[account(writable)]
+    pub vault: Option<Pubkey>,
+}
+
+pub fn fill_withdrawal_sol_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    recipient: EverAddress,
+    amount: u64,
+    withdrawals: Vec<(Pubkey, Pubkey)>,
+    vault_pubkey: Option<Pubkey>,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+) -> Instruction {
+    let author_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&author_pubkey, &mint_pubkey);
+
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let deposit_pubkey = get_deposit_address(deposit_seed);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let data = TokenProxyInstruction::FillWithdrawSol {
+        deposit_seed,
+        recipient,
+        amount,
+        value,
+        expected_evers,
+        payload,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let withdrawals = withdrawals
+        .into_iter()
+        .flat_map(|(withdrawal_pubkey, to_pubkey)| {
+            let recipient_token_pubkey = spl_associated_token_account::get_associated_token_address(
+                &to_pubkey,
+                &mint_pubkey,
+            );
+
+            [withdrawal_pubkey, recipient_token_pubkey]
+        })
+        .collect();
+
+    Instruction {
+        program_id: id(),
+        accounts: FillWithdrawalSolAccounts {
+            funder: funder_pubkey,
+            author: author_pubkey,
+            author_token: author_token_pubkey,
+            mint: mint_pubkey,
+            deposit: deposit_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_settings: token_settings_pubkey,
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            multivault: multivault_pubkey,
+            withdrawals,
+            vault: vault_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+/// Fill withdrawals partially. Each withdrawal goes with its recipient and the amount
+/// to cover, `u64::MAX` covers the unfilled rest
+#[allow(clippy::too_many_arguments)]
+pub fn partial_fill_withdrawal_sol_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    recipient: EverAddress,
+    amount: u64,
+    withdrawals: Vec<(Pubkey, Pubkey, u64)>,
+    vault_pubkey: Option<Pubkey>,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+) -> Instruction {
+    let (withdrawals, fill_amounts) = withdrawals
+        .into_iter()
+        .map(|(withdrawal_pubkey, to_pubkey, fill_amount)| {
+            ((withdrawal_pubkey, to_pubkey), fill_amount)
+        })
+        .unzip();
+
+    let data = TokenProxyInstruction::PartialFillWithdrawSol {
+        deposit_seed,
+        recipient,
+        amount,
+        value,
+        expected_evers,
+        payload: payload.clone(),
+        fill_amounts,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        data,
+        ..fill_withdrawal_sol_ix(
+            funder_pubkey,
+            author_pubkey,
+            mint_pubkey,
+            deposit_seed,
+            recipient,
+            amount,
+            withdrawals,
+            vault_pubkey,
+            value,
+            expected_evers,
+            payload,
+        )
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalProxyAccounts {
+    #[account(signer, writable)]
+    pub recipient: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    #[account(writable)]
+    pub proxy: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub fn withdrawal_proxy_ix(
+    recipient_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawProxy { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: WithdrawalProxyAccounts {
+            recipient: recipient_pubkey,
+            recipient_token: recipient_token_pubkey,
+            proxy: proxy_pubkey,
+            mint: mint_pubkey,
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CloseDepositAccounts {
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub deposit: Pubkey,
+    #[account(writable)]
+    pub funder: Pubkey,
+}
+
+pub fn close_deposit_ix(
+    author_pubkey: Pubkey,
+    deposit_pubkey: Pubkey,
+    funder_pubkey: Pubkey,
+) -> Instruction {
+    let data = TokenProxyInstruction::CloseDeposit
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CloseDepositAccounts {
+            author: author_pubkey,
+            deposit: deposit_pubkey,
+            funder: funder_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CloseWithdrawalAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub funder: Pubkey,
+}
+
+pub fn close_withdrawal_ix(withdrawal_pubkey: Pubkey, funder_pubkey: Pubkey) -> Instruction {
+    let data = TokenProxyInstruction::CloseWithdrawal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CloseWithdrawalAccounts {
+            withdrawal: withdrawal_pubkey,
+            funder: funder_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct SweepClosedAccountsAccounts {
+    #[account(writable)]
+    pub accounts: Vec<Pubkey>,
+}
+
+/// Deposit or withdrawal account, the funder who paid its rent and
+/// the proposal index of the withdrawal
+pub fn sweep_closed_accounts_ix(accounts: Vec<(Pubkey, Pubkey, Option<Pubkey>)>) -> Instruction {
+    let data = TokenProxyInstruction::SweepClosedAccounts
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: SweepClosedAccountsAccounts {
+            accounts: accounts
+                .into_iter()
+                .flat_map(|(account, funder, proposal_index)| {
+                    [account, funder].into_iter().chain(proposal_index)
+                })
+                .collect(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ExpireWithdrawalAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub funder: Pubkey,
+    pub clock_sysvar: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+}
+
+pub fn expire_withdrawal_ix(withdrawal_pubkey: Pubkey, funder_pubkey: Pubkey) -> Instruction {
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let data = TokenProxyInstruction::ExpireWithdrawal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ExpireWithdrawalAccounts {
+            withdrawal: withdrawal_pubkey,
+            funder: funder_pubkey,
+            clock_sysvar: sysvar::clock::id(),
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CancelDepositAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub deposit: Pubkey,
+    #[account(writable)]
+    pub author_token: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    #[account(writable)]
+    pub vault: Option<Pubkey>,
+}
+
+pub fn cancel_deposit_ever_ix(
+    authority_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    token: &EverAddress,
+    deposit_seed: u128,
+) -> Instruction {
+    let data = TokenProxyInstruction::CancelDeposit
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CancelDepositAccounts {
+            authority: authority_pubkey,
+            deposit: get_deposit_address(deposit_seed),
+            author_token: author_token_pubkey,
+            mint: get_mint_address(token),
+            token_settings: get_token_settings_ever_address(token),
+            settings: get_settings_address(),
+            token_program: spl_token::id(),
+            vault: None,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+pub fn cancel_deposit_sol_ix(
+    authority_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+) -> Instruction {
+    let data = TokenProxyInstruction::CancelDeposit
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CancelDepositAccounts {
+            authority: authority_pubkey,
+            deposit: get_deposit_address(deposit_seed),
+            author_token: author_token_pubkey,
+            mint: mint_pubkey,
+            token_settings: get_token_settings_sol_address(&mint_pubkey),
+            settings: get_settings_address(),
+            token_program: spl_token::id(),
+            vault: Some(get_vault_address(&mint_pubkey)),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalMultiVaultAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub recipient: Pubkey,
+    #[account(writable)]
+    pub multi_vault: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+}
+
+pub fn withdrawal_multi_vault_ix(
+    authority_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let multi_vault_pubkey = get_multivault_address();
+
+    let data = TokenProxyInstruction::WithdrawMultiVault { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: WithdrawalMultiVaultAccounts {
+            authority: authority_pubkey,
+            recipient: recipient_pubkey,
+            multi_vault: multi_vault_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangePayloadAllowlistAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn change_payload_allowlist_ix(
+    manager_pubkey: Pubkey,
+    new_payload_allowlist: Vec<Pubkey>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::ChangePayloadAllowlist {
+        new_payload_allowlist,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangePayloadAllowlistAccounts {
+            manager: manager_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangePayloadAllowlistByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_payload_allowlist_by_owner_ix(
+    owner_pubkey: Pubkey,
+    new_payload_allowlist: Vec<Pubkey>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangePayloadAllowlist {
+        new_payload_allowlist,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangePayloadAllowlistByOwnerAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeDepositCallerAllowlistAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn change_deposit_caller_allowlist_ix(
+    manager_pubkey: Pubkey,
+    new_deposit_caller_allowlist: Option<Vec<Pubkey>>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::ChangeDepositCallerAllowlist {
+        new_deposit_caller_allowlist,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeDepositCallerAllowlistAccounts {
+            manager: manager_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeDepositCallerAllowlistByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_deposit_caller_allowlist_by_owner_ix(
+    owner_pubkey: Pubkey,
+    new_deposit_caller_allowlist: Option<Vec<Pubkey>>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeDepositCallerAllowlist {
+        new_deposit_caller_allowlist,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeDepositCallerAllowlistByOwnerAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeTokenDecimalsAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_decimals: Pubkey,
+    pub mint: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn change_token_decimals_ix(
+    manager_pubkey: Pubkey,
+    token: EverAddress,
+    solana_decimals: u8,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_decimals_pubkey = get_token_decimals_address(&token);
+
+    let data = TokenProxyInstruction::ChangeTokenDecimals {
+        token,
+        solana_decimals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeTokenDecimalsAccounts {
+            manager: manager_pubkey,
+            token_decimals: token_decimals_pubkey,
+            mint: mint_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeTokenDecimalsByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_decimals: Pubkey,
+    pub mint: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_token_decimals_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token: EverAddress,
+    solana_decimals: u8,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_decimals_pubkey = get_token_decimals_address(&token);
+
+    let data = TokenProxyInstruction::ChangeTokenDecimals {
+        token,
+        solana_decimals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeTokenDecimalsByOwnerAccounts {
+            owner: owner_pubkey,
+            token_decimals: token_decimals_pubkey,
+            mint: mint_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeTokenDefaultsAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn change_token_defaults_ix(
+    manager_pubkey: Pubkey,
+    new_token_defaults: TokenDefaults,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::ChangeTokenDefaults { new_token_defaults }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeTokenDefaultsAccounts {
+            manager: manager_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeTokenDefaultsByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_token_defaults_by_owner_ix(
+    owner_pubkey: Pubkey,
+    new_token_defaults: TokenDefaults,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeTokenDefaults { new_token_defaults }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeTokenDefaultsByOwnerAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RegisterTokenEverAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub token_decimals: Pubkey,
+}
+
+pub fn register_token_ever_ix(
+    manager_pubkey: Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    ever_decimals: u8,
+    solana_decimals: u8,
+    token_defaults: Option<TokenDefaults>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let token_decimals_pubkey = get_token_decimals_address(&token);
+
+    let data = TokenProxyInstruction::RegisterTokenEver {
+        token,
+        name,
+        symbol,
+        ever_decimals,
+        solana_decimals,
+        token_defaults,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RegisterTokenEverAccounts {
+            manager: manager_pubkey,
+            mint: mint_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            token_decimals: token_decimals_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RegisterTokenEverByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub program_data: Pubkey,
+    pub token_decimals: Pubkey,
+}
+
+pub fn register_token_ever_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    ever_decimals: u8,
+    solana_decimals: u8,
+    token_defaults: Option<TokenDefaults>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let token_decimals_pubkey = get_token_decimals_address(&token);
+
+    let data = TokenProxyInstruction::RegisterTokenEver {
+        token,
+        name,
+        symbol,
+        ever_decimals,
+        solana_decimals,
+        token_defaults,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RegisterTokenEverByOwnerAccounts {
+            owner: owner_pubkey,
+            mint: mint_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            program_data: program_data_pubkey,
+            token_decimals: token_decimals_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RegisterTokenSolAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn register_token_sol_ix(
+    manager_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    name: String,
+    symbol: String,
+    token_defaults: Option<TokenDefaults>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::RegisterTokenSol {
+        name,
+        symbol,
+        token_defaults,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RegisterTokenSolAccounts {
+            manager: manager_pubkey,
+            vault: vault_pubkey,
+            mint: mint_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RegisterTokenSolByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub token_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn register_token_sol_by_owner_ix(
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    name: String,
+    symbol: String,
+    token_defaults: Option<TokenDefaults>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::RegisterTokenSol {
+        name,
+        symbol,
+        token_defaults,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RegisterTokenSolByOwnerAccounts {
+            owner: owner_pubkey,
+            vault: vault_pubkey,
+            mint: mint_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            token_program: spl_token::id(),
+            rent_sysvar: sysvar::rent::id(),
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct DelistTokenAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn delist_token_ix(owner_pubkey: Pubkey, token_settings_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::DelistToken
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: DelistTokenAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CloseTokenEverAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+    pub mint: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn close_token_ever_ix(owner_pubkey: Pubkey, token: EverAddress) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+
+    let data = TokenProxyInstruction::CloseToken
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CloseTokenEverAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            mint: mint_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CloseTokenSolAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn close_token_sol_ix(owner_pubkey: Pubkey, mint_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+
+    let data = TokenProxyInstruction::CloseToken
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CloseTokenSolAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            vault: vault_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ProposeMintAuthorityAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn propose_mint_authority_ix(
+    owner_pubkey: Pubkey,
+    token: EverAddress,
+    new_authority: Option<Pubkey>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+
+    let data = TokenProxyInstruction::ProposeMintAuthority { new_authority }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ProposeMintAuthorityAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct MigrateMintAuthorityAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn migrate_mint_authority_ix(owner_pubkey: Pubkey, token: EverAddress) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+
+    let data = TokenProxyInstruction::MigrateMintAuthority
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: MigrateMintAuthorityAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            mint: mint_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ProposeVaultAuthorityAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn propose_vault_authority_ix(
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    new_authority: Option<Pubkey>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::ProposeVaultAuthority { new_authority }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ProposeVaultAuthorityAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct MigrateVaultAuthorityAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub vault: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn migrate_vault_authority_ix(owner_pubkey: Pubkey, mint_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::MigrateVaultAuthority
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: MigrateVaultAuthorityAccounts {
+            owner: owner_pubkey,
+            token_settings: token_settings_pubkey,
+            vault: vault_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct MigrateSettingsV2Accounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    #[account(writable)]
+    pub denylist: Pubkey,
+}
+
+pub fn migrate_settings_v2_ix(funder_pubkey: Pubkey, owner_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::MigrateSettingsV2
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: MigrateSettingsV2Accounts {
+            funder: funder_pubkey,
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+            denylist: get_denylist_address(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct MigrateAccountAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub account: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn migrate_account_ix(funder_pubkey: Pubkey, account_pubkey: Pubkey) -> Instruction {
+    let data = TokenProxyInstruction::MigrateAccount
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: MigrateAccountAccounts {
+            funder: funder_pubkey,
+            account: account_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_ever_v2_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    token: &EverAddress,
+    deposit_seed: u128,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Option<Vec<u8>>,
+    reference: Option<[u8; 32]>,
+) -> Instruction {
+    let mut ix = deposit_multi_token_ever_ix(
+        funder_pubkey,
+        author_pubkey,
+        author_token_pubkey,
+        token,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        vec![],
+    );
+
+    ix.data = TokenProxyInstruction::DepositMultiTokenEverV2 {
+        version: CURRENT_IX_VERSION,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+        callback_program: None,
+        referrer: None,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    ix
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_sol_v2_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Option<Vec<u8>>,
+    reference: Option<[u8; 32]>,
+) -> Instruction {
+    let mut ix = deposit_multi_token_sol_ix(
+        funder_pubkey,
+        author_pubkey,
+        author_token_pubkey,
+        mint_pubkey,
+        deposit_seed,
+        name.clone(),
+        symbol.clone(),
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        vec![],
+    );
+
+    ix.data = TokenProxyInstruction::DepositMultiTokenSolV2 {
+        version: CURRENT_IX_VERSION,
+        deposit_seed,
+        name: name.into(),
+        symbol: symbol.into(),
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+        callback_program: None,
+        referrer: None,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    ix
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdrawal_multi_token_ever_request_v2_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    recipient: Pubkey,
+    amount: u64,
+    payload: Option<Vec<u8>>,
+    attached_amount: u64,
+) -> Instruction {
+    let mut ix = withdrawal_multi_token_ever_request_ix(
+        funder_pubkey,
+        author_pubkey,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        token,
+        name.clone(),
+        symbol.clone(),
+        decimals,
+        recipient,
+        amount as u128,
+        payload.clone().unwrap_or_default(),
+        attached_amount,
+    );
+
+    let event_hash = WithdrawalMultiTokenEverEvent {
+        token,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        decimals,
+        amount: amount as u128,
+        recipient,
+        payload: payload.clone().unwrap_or_default(),
+    }
+    .hash()
+    .expect("pack");
+
+    ix.data = TokenProxyInstruction::WithdrawMultiTokenEverRequestV2 {
+        version: CURRENT_IX_VERSION,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        token,
+        name: name.into(),
+        symbol: symbol.into(),
+        decimals,
+        recipient,
+        amount,
+        payload,
+        attached_amount,
+        event_hash: Some(event_hash),
+        chain_id: None,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    ix
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdrawal_multi_token_sol_request_v2_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    mint: Pubkey,
+    round_number: u32,
+    recipient: Pubkey,
+    amount: u64,
+    payload: Option<Vec<u8>>,
+    attached_amount: u64,
+) -> Instruction {
+    let mut ix = withdrawal_multi_token_sol_request_ix(
+        funder_pubkey,
+        author_pubkey,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        mint,
+        round_number,
+        recipient,
+        amount as u128,
+        payload.clone().unwrap_or_default(),
+        attached_amount,
+    );
+
+    let event_hash = WithdrawalMultiTokenSolEvent {
+        mint,
+        amount: amount as u128,
+        recipient,
+        payload: payload.clone().unwrap_or_default(),
+    }
+    .hash();
+
+    ix.data = TokenProxyInstruction::WithdrawMultiTokenSolRequestV2 {
+        version: CURRENT_IX_VERSION,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        recipient,
+        amount,
+        payload,
+        attached_amount,
+        event_hash: Some(event_hash),
+        chain_id: None,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    ix
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeWeverAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_wever_ix(
+    owner_pubkey: Pubkey,
+    new_wever_mint: Option<Pubkey>,
+    new_wever_defaults: Option<TokenDefaults>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeWever {
+        new_wever_mint,
+        new_wever_defaults,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeWeverAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct SetTreasuryAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn set_treasury_ix(owner_pubkey: Pubkey, treasury: Option<Pubkey>) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::SetTreasury { treasury }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: SetTreasuryAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeEnabledChainsAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_enabled_chains_ix(owner_pubkey: Pubkey, enabled_chains: Vec<u64>) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeEnabledChains { enabled_chains }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeEnabledChainsAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct PauseTokenAccounts {
+    #[account(signer, writable)]
+    pub guardian: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn pause_token_ix(
+    guardian_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    deposits: bool,
+    withdrawals: bool,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::PauseToken {
+        deposits,
+        withdrawals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: PauseTokenAccounts {
+            guardian: guardian_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct UnpauseTokenAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn unpause_token_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    deposits: bool,
+    withdrawals: bool,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::UnpauseToken {
+        deposits,
+        withdrawals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: UnpauseTokenAccounts {
+            manager: manager_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeGuardiansAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_guardians_ix(owner_pubkey: Pubkey, guardians: Vec<Pubkey>) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeGuardians { guardians }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeGuardiansAccounts {
+            owner: owner_pubkey,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct PauseBridgeAccounts {
+    #[account(signer, writable)]
+    pub guardian: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn pause_bridge_ix(guardian_pubkey: Pubkey, deposits: bool, withdrawals: bool) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::PauseBridge {
+        deposits,
+        withdrawals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: PauseBridgeAccounts {
+            guardian: guardian_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct UnpauseBridgeAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn unpause_bridge_ix(manager_pubkey: Pubkey, deposits: bool, withdrawals: bool) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::UnpauseBridge {
+        deposits,
+        withdrawals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: UnpauseBridgeAccounts {
+            manager: manager_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateFeeLedgerAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn create_fee_ledger_ix(funder_pubkey: Pubkey, token_settings_pubkey: Pubkey) -> Instruction {
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+
+    let data = TokenProxyInstruction::CreateFeeLedger
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CreateFeeLedgerAccounts {
+            funder: funder_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateBridgeStatsAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn create_bridge_stats_ix(funder_pubkey: Pubkey) -> Instruction {
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let data = TokenProxyInstruction::CreateBridgeStats
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CreateBridgeStatsAccounts {
+            funder: funder_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateApprovalQueueAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub approval_queue: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn create_approval_queue_ix(funder_pubkey: Pubkey) -> Instruction {
+    let approval_queue_pubkey = get_approval_queue_address();
+
+    let data = TokenProxyInstruction::CreateApprovalQueue
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CreateApprovalQueueAccounts {
+            funder: funder_pubkey,
+            approval_queue: approval_queue_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeDepositValueFloorAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+}
+
+pub fn change_deposit_value_floor_ix(
+    manager_pubkey: Pubkey,
+    new_deposit_value_floor: Option<DepositValueFloor>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::ChangeDepositValueFloor {
+        new_deposit_value_floor,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeDepositValueFloorAccounts {
+            manager: manager_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeMinSupportedIxVersionAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(writable)]
+    pub settings: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn change_min_supported_ix_version_ix(
+    owner: Pubkey,
+    min_supported_ix_version: u8,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeMinSupportedIxVersion {
+        min_supported_ix_version,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeMinSupportedIxVersionAccounts {
+            owner,
+            settings: settings_pubkey,
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CancelWithdrawalRequestAccounts {
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub funder: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+}
+
+pub fn cancel_withdrawal_request_ix(
+    author_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    funder_pubkey: Pubkey,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    chain_id: Option<u64>,
+) -> Instruction {
+    let proposal_index_pubkey = get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        chain_id,
+    );
+
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let data = TokenProxyInstruction::CancelWithdrawalRequest
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CancelWithdrawalRequestAccounts {
+            author: author_pubkey,
+            withdrawal: withdrawal_pubkey,
+            proposal_index: proposal_index_pubkey,
+            funder: funder_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ChangeVoteRewardAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn change_vote_reward_ix(
+    authority_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    vote_reward: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::ChangeVoteReward { vote_reward }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ChangeVoteRewardAccounts {
+            authority: authority_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+pub fn claim_ever_vote_rewards_ix(
+    relay_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    token: &EverAddress,
+    round_number: u32,
+    withdrawals: Vec<Pubkey>,
+) -> Instruction {
+    let mint_pubkey = get_mint_address(token);
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+
+    claim_vote_rewards_ix(
+        relay_pubkey,
+        recipient_token_pubkey,
+        mint_pubkey,
+        token_settings_pubkey,
+        round_number,
+        withdrawals,
+    )
+}
+
+pub fn claim_sol_vote_rewards_ix(
+    relay_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    round_number: u32,
+    withdrawals: Vec<Pubkey>,
+) -> Instruction {
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    claim_vote_rewards_ix(
+        relay_pubkey,
+        recipient_token_pubkey,
+        vault_pubkey,
+        token_settings_pubkey,
+        round_number,
+        withdrawals,
+    )
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ClaimVoteRewardsAccounts {
+    #[account(signer, writable)]
+    pub relay: Pubkey,
+    pub relay_round: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+    #[account(writable)]
+    pub mint_or_vault: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    pub token_program: Pubkey,
+    #[account(writable)]
+    pub withdrawals: Vec<Pubkey>,
+}
+
+fn claim_vote_rewards_ix(
+    relay_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_or_vault_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    round_number: u32,
+    withdrawals: Vec<Pubkey>,
+) -> Instruction {
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let data = TokenProxyInstruction::ClaimVoteRewards
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ClaimVoteRewardsAccounts {
+            relay: relay_pubkey,
+            relay_round: relay_round_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            mint_or_vault: mint_or_vault_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_program: spl_token::id(),
+            withdrawals,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+pub fn claim_ever_referral_fee_ix(
+    referrer_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    token: &EverAddress,
+) -> Instruction {
+    let mint_pubkey = get_mint_address(token);
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+
+    claim_referral_fee_ix(
+        referrer_pubkey,
+        recipient_token_pubkey,
+        mint_pubkey,
+        token_settings_pubkey,
+    )
+}
+
+pub fn claim_sol_referral_fee_ix(
+    referrer_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+) -> Instruction {
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    claim_referral_fee_ix(
+        referrer_pubkey,
+        recipient_token_pubkey,
+        vault_pubkey,
+        token_settings_pubkey,
+    )
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ClaimReferralFeeAccounts {
+    #[account(signer)]
+    pub referrer: Pubkey,
+    #[account(writable)]
+    pub referral: Pubkey,
+    pub token_settings: Pubkey,
+    #[account(writable)]
+    pub mint_or_vault: Pubkey,
+    #[account(writable)]
+    pub recipient_token: Pubkey,
+    pub token_program: Pubkey,
+}
+
+fn claim_referral_fee_ix(
+    referrer_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_or_vault_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+) -> Instruction {
+    let referral_pubkey = get_referral_address(&token_settings_pubkey, &referrer_pubkey);
+
+    let data = TokenProxyInstruction::ClaimReferralFee
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ClaimReferralFeeAccounts {
+            referrer: referrer_pubkey,
+            referral: referral_pubkey,
+            token_settings: token_settings_pubkey,
+            mint_or_vault: mint_or_vault_pubkey,
+            recipient_token: recipient_token_pubkey,
+            token_program: spl_token::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct SetRecipientHookAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer)]
+    pub recipient: Pubkey,
+    #[account(writable)]
+    pub recipient_hook: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn set_recipient_hook_ix(
+    funder_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    hook_program_id: Pubkey,
+    data: Vec<u8>,
+    accounts: Vec<Pubkey>,
+) -> Instruction {
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
+    let data = TokenProxyInstruction::SetRecipientHook {
+        program_id: hook_program_id,
+        data,
+        accounts,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: SetRecipientHookAccounts {
+            funder: funder_pubkey,
+            recipient: recipient_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RemoveRecipientHookAccounts {
+    #[account(signer)]
+    pub recipient: Pubkey,
+    #[account(writable)]
+    pub recipient_hook: Pubkey,
+    #[account(writable)]
+    pub funder: Pubkey,
+}
+
+pub fn remove_recipient_hook_ix(recipient_pubkey: Pubkey, funder_pubkey: Pubkey) -> Instruction {
+    let recipient_hook_pubkey = get_recipient_hook_address(&recipient_pubkey);
+
+    let data = TokenProxyInstruction::RemoveRecipientHook
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RemoveRecipientHookAccounts {
+            recipient: recipient_pubkey,
+            recipient_hook: recipient_hook_pubkey,
+            funder: funder_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+/// Append accounts needed to notify program-owned recipient to withdrawal instruction.
+/// Recipient hook account itself is always passed by withdrawal instructions
+pub fn append_recipient_hook_accounts(
+    ix: &mut Instruction,
+    hook_program_id: Pubkey,
+    hook_accounts: Vec<AccountMeta>,
+) {
+    ix.accounts
+        .push(AccountMeta::new_readonly(hook_program_id, false));
+    ix.accounts.extend(hook_accounts);
+}
+
+/// Record the vote in the relay reward ledger of the round
+pub fn append_relay_reward_accounts(
+    ix: &mut Instruction,
+    relay_pubkey: &Pubkey,
+    round_number: u32,
+) {
+    let relay_reward_pubkey = get_relay_reward_address(relay_pubkey, round_number);
+
+    ix.accounts
+        .push(AccountMeta::new(relay_reward_pubkey, false));
+    ix.accounts
+        .push(AccountMeta::new_readonly(system_program::id(), false));
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateTokenMetadataAccounts {
+    #[account(signer, writable)]
+    pub manager: Pubkey,
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    pub token_settings: Pubkey,
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub metadata: Pubkey,
+    pub settings: Pubkey,
+    pub token_metadata_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn create_token_metadata_ix(
+    manager_pubkey: Pubkey,
+    funder_pubkey: Pubkey,
+    token: EverAddress,
+    uri: String,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let mint_pubkey = get_mint_address(&token);
+    let metadata_pubkey = get_token_metadata_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::CreateTokenMetadata { uri }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CreateTokenMetadataAccounts {
+            manager: manager_pubkey,
+            funder: funder_pubkey,
+            token_settings: token_settings_pubkey,
+            mint: mint_pubkey,
+            metadata: metadata_pubkey,
+            settings: settings_pubkey,
+            token_metadata_program: token_metadata::id(),
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct CreateTokenMetadataByOwnerAccounts {
+    #[account(signer, writable)]
+    pub owner: Pubkey,
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    pub token_settings: Pubkey,
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub metadata: Pubkey,
+    pub settings: Pubkey,
+    pub token_metadata_program: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub program_data: Pubkey,
+}
+
+pub fn create_token_metadata_by_owner_ix(
+    owner_pubkey: Pubkey,
+    funder_pubkey: Pubkey,
+    token: EverAddress,
+    uri: String,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let mint_pubkey = get_mint_address(&token);
+    let metadata_pubkey = get_token_metadata_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::CreateTokenMetadata { uri }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: CreateTokenMetadataByOwnerAccounts {
+            owner: owner_pubkey,
+            funder: funder_pubkey,
+            token_settings: token_settings_pubkey,
+            mint: mint_pubkey,
+            metadata: metadata_pubkey,
+            settings: settings_pubkey,
+            token_metadata_program: token_metadata::id(),
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+            program_data: program_data_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct AddToDenylistAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer)]
+    pub guardian: Pubkey,
+    #[account(writable)]
+    pub denylist: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn add_to_denylist_ix(
+    funder_pubkey: Pubkey,
+    guardian_pubkey: Pubkey,
+    address: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let denylist_pubkey = get_denylist_address();
+
+    let data = TokenProxyInstruction::AddToDenylist { address }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: AddToDenylistAccounts {
+            funder: funder_pubkey,
+            guardian: guardian_pubkey,
+            denylist: denylist_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RemoveFromDenylistAccounts {
+    #[account(signer)]
+    pub guardian: Pubkey,
+    #[account(writable)]
+    pub denylist: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn remove_from_denylist_ix(guardian_pubkey: Pubkey, address: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let denylist_pubkey = get_denylist_address();
+
+    let data = TokenProxyInstruction::RemoveFromDenylist { address }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RemoveFromDenylistAccounts {
+            guardian: guardian_pubkey,
+            denylist: denylist_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct RegisterLiquidityProviderAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub lp_registry: Pubkey,
+    pub settings: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+}
+
+pub fn register_liquidity_provider_ix(
+    funder_pubkey: Pubkey,
+    manager_pubkey: Pubkey,
+    provider: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let lp_registry_pubkey = get_lp_registry_address();
+
+    let data = TokenProxyInstruction::RegisterLiquidityProvider { provider }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: RegisterLiquidityProviderAccounts {
+            funder: funder_pubkey,
+            manager: manager_pubkey,
+            lp_registry: lp_registry_pubkey,
+            settings: settings_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct UnregisterLiquidityProviderAccounts {
+    #[account(signer)]
+    pub manager: Pubkey,
+    #[account(writable)]
+    pub lp_registry: Pubkey,
+    pub settings: Pubkey,
+}
+
+pub fn unregister_liquidity_provider_ix(manager_pubkey: Pubkey, provider: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let lp_registry_pubkey = get_lp_registry_address();
+
+    let data = TokenProxyInstruction::UnregisterLiquidityProvider { provider }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: UnregisterLiquidityProviderAccounts {
+            manager: manager_pubkey,
+            lp_registry: lp_registry_pubkey,
+            settings: settings_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+/// Prove the filler is a registered liquidity provider when fills of the token are
+/// restricted. Append it to fill withdrawal instruction, after all other accounts
+pub fn append_lp_registry_account(ix: &mut Instruction) {
+    ix.accounts
+        .push(AccountMeta::new_readonly(get_lp_registry_address(), false));
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalMultiTokenEverToManyRequestAccounts {
+    #[account(signer, writable)]
+    pub funder: Pubkey,
+    #[account(signer, writable)]
+    pub author: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    pub rl_settings: Pubkey,
+    pub relay_round: Pubkey,
+    pub system_program: Pubkey,
+    pub rent_sysvar: Pubkey,
+    pub clock_sysvar: Pubkey,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdrawal_multi_token_ever_to_many_request_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    recipients: Vec<WithdrawalRecipient>,
+) -> Instruction {
+    let event = WithdrawalMultiTokenEverToManyEvent {
+        token,
+        name: name.clone(),
+        symbol: symbol.clone(),
+        decimals,
+        recipients: recipients.clone(),
+    };
+    let event_hash = hash(&event.try_to_vec().expect("pack"));
+
+    let withdrawal_pubkey = bridge_utils::helper::get_associated_proposal_address(
+        &id(),
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+        &event_hash.to_bytes(),
+    );
+    let proposal_index_pubkey = get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenEverToManyRequest {
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        token,
+        name,
+        symbol,
+        decimals,
+        recipients,
+        event_hash: Some(event_hash),
+        chain_id: None,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: WithdrawalMultiTokenEverToManyRequestAccounts {
+            funder: funder_pubkey,
+            author: author_pubkey,
+            withdrawal: withdrawal_pubkey,
+            proposal_index: proposal_index_pubkey,
+            rl_settings: rl_settings_pubkey,
+            relay_round: relay_round_pubkey,
+            system_program: system_program::id(),
+            rent_sysvar: sysvar::rent::id(),
+            clock_sysvar: sysvar::clock::id(),
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct WithdrawalEverToManyAccounts {
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub clock_sysvar: Pubkey,
+    pub relay_round: Pubkey,
+    pub rl_settings: Pubkey,
+    pub denylist: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub recipient_tokens: Vec<Pubkey>,
+    #[account(writable)]
+    pub proposal_index: Pubkey,
+    #[account(writable)]
+    pub fee_ledger: Pubkey,
+}
+
+/// Recipients are wallets in the order of event recipients, their associated token accounts must exist
+pub fn withdrawal_ever_to_many_ix(
+    withdrawal_pubkey: Pubkey,
+    token: EverAddress,
+    recipients: &[Pubkey],
+    round_number: u32,
+    proposal_index_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = get_denylist_address();
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let rl_settings_pubkey = get_rl_settings_address();
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenEverToMany
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: WithdrawalEverToManyAccounts {
+            withdrawal: withdrawal_pubkey,
+            mint: mint_pubkey,
+            token_settings: token_settings_pubkey,
+            fee_ledger: fee_ledger_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            clock_sysvar: sysvar::clock::id(),
+            denylist: denylist_pubkey,
+            recipient_tokens: recipient_token_addresses(&mint_pubkey, recipients),
+            proposal_index: proposal_index_pubkey,
+            relay_round: relay_round_pubkey,
+            rl_settings: rl_settings_pubkey,
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+#[derive(Debug, Clone, AccountMetas)]
+pub struct ApproveWithdrawalEverToManyAccounts {
+    #[account(signer, writable)]
+    pub authority: Pubkey,
+    #[account(writable)]
+    pub mint: Pubkey,
+    #[account(writable)]
+    pub withdrawal: Pubkey,
+    #[account(writable)]
+    pub token_settings: Pubkey,
+    pub settings: Pubkey,
+    pub token_program: Pubkey,
+    pub clock_sysvar: Pubkey,
+    #[account(writable)]
+    pub bridge_stats: Pubkey,
+    #[account(writable)]
+    pub recipient_tokens: Vec<Pubkey>,
+}
+
+pub fn approve_withdrawal_ever_to_many_ix(
+    authority_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    token: EverAddress,
+    recipients: &[Pubkey],
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    let bridge_stats_pubkey = get_bridge_stats_address();
+
+    let data = TokenProxyInstruction::ApproveWithdrawEverToMany
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: ApproveWithdrawalEverToManyAccounts {
+            authority: authority_pubkey,
+            mint: mint_pubkey,
+            withdrawal: withdrawal_pubkey,
+            token_settings: token_settings_pubkey,
+            settings: settings_pubkey,
+            token_program: spl_token::id(),
+            clock_sysvar: sysvar::clock::id(),
+            recipient_tokens: recipient_token_addresses(&mint_pubkey, recipients),
+            bridge_stats: bridge_stats_pubkey,
+        }
+        .to_account_metas(),
+        data,
+    }
+}
+
+fn recipient_token_addresses(mint: &Pubkey, recipients: &[Pubkey]) -> Vec<Pubkey> {
+    recipients
+        .iter()
+        .map(|recipient| {
+            spl_associated_token_account::get_associated_token_address(recipient, mint)
+        })
+        .collect()
+}
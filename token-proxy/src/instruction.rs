@@ -1,11 +1,23 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use bridge_utils::types::{EverAddress, UInt256, Vote};
+use bridge_utils::types::{EverAddress, ShortString, UInt256, Vote};
 
+use serde::Serialize;
+use solana_program::hash::Hash;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
-use crate::FeeType;
+use crate::{
+    DepositValueFloor, FeeTiers, FeeType, Role, TimelockedChange, TokenDefaults,
+    WithdrawalRecipient,
+};
 
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
+/// Version of instruction encodings superseded by compact ones
+pub const LEGACY_IX_VERSION: u8 = 0;
+
+/// Version of current instruction encodings
+pub const CURRENT_IX_VERSION: u8 = 1;
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
 pub enum TokenProxyInstruction {
     /// Vote for withdraw EVER/SOL request
     ///
@@ -16,13 +28,17 @@ pub enum TokenProxyInstruction {
         vote: Vote,
     },
 
-    /// Withdraw Multi Token EVER
+    /// Withdraw Multi Token EVER. Relay round and round loader settings of
+    /// the proposal round are required to check the proposal quorum
     ///
     /// # Account references
     /// ...
     WithdrawMultiTokenEver,
 
-    /// Withdraw Multi Token SOL
+    /// Withdraw Multi Token SOL. Recipient token account is created
+    /// if funder, recipient, system and associated token programs are appended.
+    /// Relay round and round loader settings of the proposal round are required
+    /// to check the proposal quorum
     ///
     /// # Account references
     /// ...
@@ -42,6 +58,8 @@ pub enum TokenProxyInstruction {
 
     /// Initialize Token Proxy
     ///
+    /// Creates an empty denylist, so payouts always pass it
+    ///
     /// # Account references
     /// ...
     Initialize {
@@ -324,13 +342,13 @@ pub enum TokenProxyInstruction {
         amount: u64,
     },
 
-    /// Close Deposit Account to return SOL
+    /// Close Deposit Account to return SOL to the funder who paid its rent
     ///
     /// # Account references
     /// ...
     CloseDeposit,
 
-    /// Close Withdrawal Account to return SOL
+    /// Close Withdrawal Account to return SOL to the funder who paid its rent
     ///
     /// # Account references
     /// ...
@@ -344,4 +362,1603 @@ pub enum TokenProxyInstruction {
         // Amount SOL to withdraw
         amount: u64,
     },
+
+    /// Change programs allowed to be called from payload
+    ///
+    /// # Account references
+    /// ...
+    ChangePayloadAllowlist {
+        // Allowed program ids
+        new_payload_allowlist: Vec<Pubkey>,
+    },
+
+    /// Change programs allowed to call deposits via CPI
+    ///
+    /// # Account references
+    /// ...
+    ChangeDepositCallerAllowlist {
+        // Allowed caller program ids, each must call directly from top-level instruction.
+        // None to allow any caller
+        new_deposit_caller_allowlist: Option<Vec<Pubkey>>,
+    },
+
+    /// Change Solana decimals of EVER token before its mint is created
+    ///
+    /// # Account references
+    /// ...
+    ChangeTokenDecimals {
+        // EVER token root address
+        token: EverAddress,
+        // Solana mint decimals
+        solana_decimals: u8,
+    },
+
+    /// Change deposit daily limit
+    ///
+    /// # Account references
+    /// ...
+    ChangeDepositDailyLimit {
+        // Deposit daily limit. None to disable the limit
+        new_deposit_daily_limit: Option<u64>,
+    },
+
+    /// Change length of withdrawal limits epoch
+    ///
+    /// # Account references
+    /// ...
+    ChangeWithdrawalEpochLength {
+        // Epoch length in seconds. None to use a day
+        new_withdrawal_epoch_length: Option<i64>,
+    },
+
+    /// Change limits and fees applied to newly created tokens
+    ///
+    /// # Account references
+    /// ...
+    ChangeTokenDefaults {
+        // Token settings defaults
+        new_token_defaults: TokenDefaults,
+    },
+
+    /// Create EVER token mint and settings before the first withdrawal
+    ///
+    /// # Account references
+    /// ...
+    RegisterTokenEver {
+        // Ever token root address
+        token: EverAddress,
+        // Token name
+        name: String,
+        // Token symbol
+        symbol: String,
+        // Ever decimals
+        ever_decimals: u8,
+        // Solana mint decimals. Decimals set with ChangeTokenDecimals take precedence
+        solana_decimals: u8,
+        // Token limits and fees. None to use bridge-wide defaults
+        token_defaults: Option<TokenDefaults>,
+    },
+
+    /// Create SOL token vault and settings before the first deposit
+    ///
+    /// # Account references
+    /// ...
+    RegisterTokenSol {
+        // Token name
+        name: String,
+        // Token symbol
+        symbol: String,
+        // Token limits and fees. None to use bridge-wide defaults
+        token_defaults: Option<TokenDefaults>,
+    },
+
+    /// Disable deposits of token for good, withdrawals are still processed
+    ///
+    /// # Account references
+    /// ...
+    DelistToken,
+
+    /// Close vault of delisted token to return SOL to the owner. Token settings
+    /// are kept paused so that the token can't be listed again
+    ///
+    /// # Account references
+    /// ...
+    CloseToken,
+
+    /// Propose new mint authority of EVER token, effective after a timelock
+    ///
+    /// # Account references
+    /// ...
+    ProposeMintAuthority {
+        // New mint authority. None to cancel the proposal
+        new_authority: Option<Pubkey>,
+    },
+
+    /// Hand mint authority of EVER token over to the proposed authority
+    ///
+    /// # Account references
+    /// ...
+    MigrateMintAuthority,
+
+    /// Propose new owner of SOL token vault, effective after a timelock
+    ///
+    /// # Account references
+    /// ...
+    ProposeVaultAuthority {
+        // New vault owner. None to cancel the proposal
+        new_authority: Option<Pubkey>,
+    },
+
+    /// Hand SOL token vault over to the proposed owner
+    ///
+    /// # Account references
+    /// ...
+    MigrateVaultAuthority,
+
+    /// Expand Settings account to the V2 layout preserving existing values.
+    /// Creates the denylist unless an address was already added
+    ///
+    /// # Account references
+    /// ...
+    MigrateSettingsV2,
+
+    /// Deposit Multi token EVER with compact encoding
+    ///
+    /// # Account references
+    /// ...
+    DepositMultiTokenEverV2 {
+        // Instruction encoding version
+        version: u8,
+        // Deposit seed
+        deposit_seed: u128,
+        // Deposit amount
+        amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Option<Vec<u8>>,
+        // Off-chain reference to match the deposit with ledger entries
+        reference: Option<[u8; 32]>,
+        // Program notified via CPI once the deposit is accepted
+        callback_program: Option<Pubkey>,
+        // Wallet partner credited with a share of deposit fee
+        referrer: Option<Pubkey>,
+    },
+
+    /// Deposit Multi token SOL with compact encoding
+    ///
+    /// # Account references
+    /// ...
+    DepositMultiTokenSolV2 {
+        // Instruction encoding version
+        version: u8,
+        // Deposit seed
+        deposit_seed: u128,
+        // Mint name
+        name: ShortString,
+        // Mint symbol
+        symbol: ShortString,
+        // Deposit amount
+        amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Option<Vec<u8>>,
+        // Off-chain reference to match the deposit with ledger entries
+        reference: Option<[u8; 32]>,
+        // Program notified via CPI once the deposit is accepted
+        callback_program: Option<Pubkey>,
+        // Wallet partner credited with a share of deposit fee
+        referrer: Option<Pubkey>,
+    },
+
+    /// Withdraw Multi token EVER request with compact encoding
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenEverRequestV2 {
+        // Instruction encoding version
+        version: u8,
+        // Ever event timestamp
+        event_timestamp: u32,
+        // Ever event transaction lt
+        event_transaction_lt: u64,
+        // Ever event configuration
+        event_configuration: Pubkey,
+        // Ever token root address
+        token: EverAddress,
+        // token name
+        name: ShortString,
+        // token symbol
+        symbol: ShortString,
+        // decimals
+        decimals: u8,
+        // Solana recipient address
+        recipient: Pubkey,
+        // Withdrawal amount
+        amount: u64,
+        // Random payload to transfer to sol
+        payload: Option<Vec<u8>>,
+        // Attached SOL amount to proxy account
+        attached_amount: u64,
+        // Expected hash of event data
+        event_hash: Option<Hash>,
+        // Source chain id. None for EVER chain
+        chain_id: Option<u64>,
+    },
+
+    /// Withdraw multi token SOL request with compact encoding
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenSolRequestV2 {
+        // Instruction encoding version
+        version: u8,
+        // Ever event timestamp
+        event_timestamp: u32,
+        // Ever event transaction lt
+        event_transaction_lt: u64,
+        // Ever event configuration
+        event_configuration: Pubkey,
+        // Solana recipient address
+        recipient: Pubkey,
+        // Withdrawal amount
+        amount: u64,
+        // Random payload to transfer to sol
+        payload: Option<Vec<u8>>,
+        // Attached SOL amount to proxy account
+        attached_amount: u64,
+        // Expected hash of event data
+        event_hash: Option<Hash>,
+        // Source chain id. None for EVER chain
+        chain_id: Option<u64>,
+    },
+
+    /// Change wrapped EVER mint and its limits and fees
+    ///
+    /// # Account references
+    /// ...
+    ChangeWever {
+        // WEVER mint. None to treat it as a generic EVER token
+        new_wever_mint: Option<Pubkey>,
+        // WEVER limits and fees. None to use bridge-wide defaults
+        new_wever_defaults: Option<TokenDefaults>,
+    },
+
+    /// Change chains allowed to withdraw through this program
+    ///
+    /// # Account references
+    /// ...
+    ChangeEnabledChains {
+        // Enabled chain ids except EVER chain
+        enabled_chains: Vec<u64>,
+    },
+
+    /// Pause token deposits and/or withdrawals
+    ///
+    /// # Account references
+    /// ...
+    PauseToken {
+        // Pause deposits
+        deposits: bool,
+        // Pause withdrawals
+        withdrawals: bool,
+    },
+
+    /// Resume token deposits and/or withdrawals
+    ///
+    /// # Account references
+    /// ...
+    UnpauseToken {
+        // Resume deposits
+        deposits: bool,
+        // Resume withdrawals
+        withdrawals: bool,
+    },
+
+    /// Create token fee ledger and move accrued fee into it
+    ///
+    /// # Account references
+    /// ...
+    CreateFeeLedger,
+
+    /// Withdraw Multi Token EVER with explicit recipient token account creation.
+    /// Relay round and round loader settings of the proposal round are required
+    /// to check the proposal quorum
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenEverV2 {
+        // Instruction encoding version
+        version: u8,
+        // Create recipient associated token account if it doesn't exist
+        create_if_needed: bool,
+    },
+
+    /// Change minimal deposit value per unit of expected evers
+    ///
+    /// # Account references
+    /// ...
+    ChangeDepositValueFloor {
+        // Deposit value floor. None to disable the check
+        new_deposit_value_floor: Option<DepositValueFloor>,
+    },
+
+    /// Change minimal supported instruction version
+    ///
+    /// # Account references
+    /// ...
+    ChangeMinSupportedIxVersion {
+        // Instructions encoded with lower version are rejected
+        min_supported_ix_version: u8,
+    },
+
+    /// Cancel unvoted withdrawal request and close its accounts
+    ///
+    /// # Account references
+    /// ...
+    CancelWithdrawalRequest,
+
+    /// Change reward credited to relays for confirming token withdrawals
+    ///
+    /// # Account references
+    /// ...
+    ChangeVoteReward {
+        // Reward per confirming vote in token units. Zero to disable rewards
+        vote_reward: u64,
+    },
+
+    /// Claim rewards credited to relay in processed token withdrawals
+    ///
+    /// # Account references
+    /// ...
+    ClaimVoteRewards,
+
+    /// Change recipients exempt from token withdrawal daily limit
+    ///
+    /// # Account references
+    /// ...
+    ChangeWithdrawalLimitExemptions {
+        // Exempt recipients
+        new_withdrawal_limit_exemptions: Vec<Pubkey>,
+    },
+
+    /// Set program notified when withdrawal is paid to recipient
+    ///
+    /// # Account references
+    /// ...
+    SetRecipientHook {
+        // Program to invoke
+        program_id: Pubkey,
+        // Instruction data prefix
+        data: Vec<u8>,
+        // Additional accounts passed to the program
+        accounts: Vec<Pubkey>,
+    },
+
+    /// Remove recipient hook and close its account
+    ///
+    /// # Account references
+    /// ...
+    RemoveRecipientHook,
+
+    /// Create Metaplex metadata for bridged Ever token mint
+    ///
+    /// # Account references
+    /// ...
+    CreateTokenMetadata {
+        // Token metadata URI
+        uri: String,
+    },
+
+    /// Propose new authority for Guardian, Manager or Withdrawal Manager role
+    ///
+    /// # Account references
+    /// ...
+    ProposeRole {
+        // Role to transfer
+        role: Role,
+        // Authority that has to accept the role. None to cancel pending transfer
+        new_authority: Option<Pubkey>,
+    },
+
+    /// Accept proposed role
+    ///
+    /// # Account references
+    /// ...
+    AcceptRole,
+
+    /// Schedule owner change to be executed after timelock delay
+    ///
+    /// # Account references
+    /// ...
+    ScheduleChange {
+        // Pending change seed
+        change_seed: u128,
+        // Change to execute
+        change: TimelockedChange,
+    },
+
+    /// Execute scheduled change once timelock delay has passed
+    ///
+    /// # Account references
+    /// ...
+    ExecuteChange,
+
+    /// Cancel scheduled change
+    ///
+    /// # Account references
+    /// ...
+    CancelChange,
+
+    /// Upgrade account to the current layout version, expanding it if needed
+    ///
+    /// # Account references
+    /// ...
+    MigrateAccount,
+
+    /// Update amount-based fee tiers and flat minimum fee
+    ///
+    /// # Account references
+    /// ...
+    UpdateFeeTiers {
+        // Fee type
+        fee_type: FeeType,
+        // Tiers ascending by amount threshold and flat minimum fee
+        fee_tiers: FeeTiers,
+    },
+
+    /// Expire unconfirmed withdrawal request after its TTL and release relay reparation to the funder
+    ///
+    /// # Account references
+    /// ...
+    ExpireWithdrawal,
+
+    /// Confirm withdraw EVER/SOL request by relay signatures verified in preceding Ed25519 instructions
+    ///
+    /// # Account references
+    /// ...
+    VoteForWithdrawRequestBySignatures,
+
+    /// Refund deposit that was never picked up on the EVER side and mark it as refunded
+    ///
+    /// # Account references
+    /// ...
+    CancelDeposit,
+
+    /// Add address to denylist. Withdrawals to or requested by it wait for approve
+    ///
+    /// # Account references
+    /// ...
+    AddToDenylist {
+        // Denied address
+        address: Pubkey,
+    },
+
+    /// Remove address from denylist
+    ///
+    /// # Account references
+    /// ...
+    RemoveFromDenylist {
+        // Denied address
+        address: Pubkey,
+    },
+
+    /// Close processed withdrawals and refunded deposits in bulk to return SOL to their funders.
+    /// Each account is followed by its funder, withdrawal also by its proposal index
+    ///
+    /// # Account references
+    /// ...
+    SweepClosedAccounts,
+
+    /// Set treasury wallet. Fee can be withdrawn to its token accounts without manager
+    ///
+    /// # Account references
+    /// ...
+    SetTreasury {
+        // Treasury wallet. None to disable
+        treasury: Option<Pubkey>,
+    },
+
+    /// Withdraw Multi token EVER request paying out to several recipients
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenEverToManyRequest {
+        // Ever event timestamp
+        event_timestamp: u32,
+        // Ever event transaction lt
+        event_transaction_lt: u64,
+        // Ever event configuration
+        event_configuration: Pubkey,
+        // Ever token root address
+        token: EverAddress,
+        // token name
+        name: String,
+        // token symbol
+        symbol: String,
+        // decimals
+        decimals: u8,
+        // Solana recipient addresses and withdrawal amounts
+        recipients: Vec<WithdrawalRecipient>,
+        // Expected hash of event data
+        event_hash: Option<Hash>,
+        // Source chain id. None for EVER chain
+        chain_id: Option<u64>,
+    },
+
+    /// Withdraw Multi Token EVER to several recipients. Recipient token accounts
+    /// follow in the order of event recipients. Relay round and round loader
+    /// settings of the proposal round are required to check the proposal quorum
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenEverToMany,
+
+    /// Approve withdraw Multi Token EVER to several recipients
+    ///
+    /// # Account references
+    /// ...
+    ApproveWithdrawEverToMany,
+
+    /// Change minimum deposit amount
+    ///
+    /// # Account references
+    /// ...
+    ChangeMinDeposit {
+        // Minimum deposit amount in Solana decimals. Zero to disable
+        new_min_deposit_amount: u64,
+    },
+
+    /// Create bridge-wide statistics account
+    ///
+    /// # Account references
+    /// ...
+    CreateBridgeStats,
+
+    /// Deposit bounty tokens for Withdraw SOL into escrow. The whole escrow
+    /// balance is paid to the author of Fill Withdraw SOL
+    ///
+    /// # Account references
+    /// ...
+    DepositBountyForWithdrawSol {
+        // Amount of tokens to add to escrow
+        amount: u64,
+    },
+
+    /// Refund escrowed bounty of finished Withdraw SOL and close escrow account
+    ///
+    /// # Account references
+    /// ...
+    RefundBountyForWithdrawSol,
+
+    /// Fill a portion of Withdraw SOL. Bounty is split between fillers pro-rata
+    ///
+    /// # Account references
+    /// ...
+    PartialFillWithdrawSol {
+        // Deposit seed
+        deposit_seed: u128,
+        // Recipient address
+        recipient: EverAddress,
+        // Deposit amount
+        amount: u64,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Withdrawal amount to cover, in order of withdrawals. Capped by the unfilled rest
+        fill_amounts: Vec<u64>,
+    },
+
+    /// Register liquidity provider allowed to fill withdrawals of restricted tokens
+    ///
+    /// # Account references
+    /// ...
+    RegisterLiquidityProvider {
+        // Liquidity provider address
+        provider: Pubkey,
+    },
+
+    /// Unregister liquidity provider
+    ///
+    /// # Account references
+    /// ...
+    UnregisterLiquidityProvider {
+        // Liquidity provider address
+        provider: Pubkey,
+    },
+
+    /// Restrict filling withdrawals of the token to registered liquidity providers
+    ///
+    /// # Account references
+    /// ...
+    ChangeFillsRestricted {
+        // Allow fills by registered liquidity providers only
+        fills_restricted: bool,
+    },
+
+    /// Change share of deposit fee credited to referrers
+    ///
+    /// # Account references
+    /// ...
+    ChangeReferralFee {
+        // Referrer's share of deposit fee, in basis points
+        referral_fee_bps: u16,
+    },
+
+    /// Claim deposit fee share accrued to referrer
+    ///
+    /// # Account references
+    /// ...
+    ClaimReferralFee,
+
+    /// Create queue of withdrawals waiting for approve
+    ///
+    /// # Account references
+    /// ...
+    CreateApprovalQueue,
+
+    /// Reject Withdraw EVER waiting for approve
+    ///
+    /// # Account references
+    /// ...
+    RejectWithdrawEver {
+        // Reviewer note stored in approval queue
+        note: Option<Vec<u8>>,
+    },
+
+    /// Reject Withdraw SOL waiting for approve
+    ///
+    /// # Account references
+    /// ...
+    RejectWithdrawSol {
+        // Reviewer note stored in approval queue
+        note: Option<Vec<u8>>,
+    },
+
+    /// Change guardians allowed to pause the bridge and tokens
+    ///
+    /// # Account references
+    /// ...
+    ChangeGuardians {
+        // Guardians in addition to the main guardian
+        guardians: Vec<Pubkey>,
+    },
+
+    /// Pause deposits and/or withdrawals of all tokens
+    ///
+    /// # Account references
+    /// ...
+    PauseBridge {
+        // Pause deposits
+        deposits: bool,
+        // Pause withdrawals
+        withdrawals: bool,
+    },
+
+    /// Resume deposits and/or withdrawals of all tokens
+    ///
+    /// # Account references
+    /// ...
+    UnpauseBridge {
+        // Resume deposits
+        deposits: bool,
+        // Resume withdrawals
+        withdrawals: bool,
+    },
+
+    /// Execute Payload EVER in steps with additional proxy accounts
+    ///
+    /// # Account references
+    /// ...
+    ExecutePayloadEverV2 {
+        // Number of payload instructions to execute, all remaining if empty
+        count: Option<u32>,
+        // Number of additional proxy accounts to sign for
+        proxy_accounts: u8,
+    },
+
+    /// Execute Payload SOL in steps with additional proxy accounts
+    ///
+    /// # Account references
+    /// ...
+    ExecutePayloadSolV2 {
+        // Number of payload instructions to execute, all remaining if empty
+        count: Option<u32>,
+        // Number of additional proxy accounts to sign for
+        proxy_accounts: u8,
+    },
+
+    /// Enable emergency mode with expiry and reason
+    ///
+    /// # Account references
+    /// ...
+    EnableEmergencyModeV2 {
+        // Unix timestamp after which emergency mode is off. None to keep it until disabled
+        expiry: Option<i64>,
+        // Reason code
+        reason: Option<u32>,
+    },
+
+    /// Enable token emergency mode with expiry and reason
+    ///
+    /// # Account references
+    /// ...
+    EnableTokenEmergencyModeV2 {
+        // Unix timestamp after which emergency mode is off. None to keep it until disabled
+        expiry: Option<i64>,
+        // Reason code
+        reason: Option<u32>,
+    },
+
+    /// Disable emergency mode with reason
+    ///
+    /// # Account references
+    /// ...
+    DisableEmergencyModeV2 {
+        // Reason code
+        reason: Option<u32>,
+    },
+
+    /// Disable token emergency mode with reason
+    ///
+    /// # Account references
+    /// ...
+    DisableTokenEmergencyModeV2 {
+        // Reason code
+        reason: Option<u32>,
+    },
+
+    /// Approve Withdraw Ever with reviewer note
+    ///
+    /// # Account references
+    /// ...
+    ApproveWithdrawEverV2 {
+        // Reviewer note stored in approval queue
+        note: Option<Vec<u8>>,
+    },
+
+    /// Approve Withdraw SOL with reviewer note
+    ///
+    /// # Account references
+    /// ...
+    ApproveWithdrawSolV2 {
+        // Reviewer note stored in approval queue
+        note: Option<Vec<u8>>,
+    },
+}
+
+impl TokenProxyInstruction {
+    /// Encoding version checked against `Settings::min_supported_ix_version`
+    /// by instructions that take Settings account. Versioned encodings carry it
+    /// in the leading byte, legacy ones have none
+    pub fn version(&self) -> u8 {
+        match self {
+            TokenProxyInstruction::DepositMultiTokenEverV2 { version, .. }
+            | TokenProxyInstruction::DepositMultiTokenSolV2 { version, .. }
+            | TokenProxyInstruction::WithdrawMultiTokenEverRequestV2 { version, .. }
+            | TokenProxyInstruction::WithdrawMultiTokenSolRequestV2 { version, .. }
+            | TokenProxyInstruction::WithdrawMultiTokenEverV2 { version, .. } => *version,
+            TokenProxyInstruction::DepositMultiTokenEver { .. }
+            | TokenProxyInstruction::DepositMultiTokenSol { .. }
+            | TokenProxyInstruction::WithdrawMultiTokenEverRequest { .. }
+            | TokenProxyInstruction::WithdrawMultiTokenSolRequest { .. }
+            | TokenProxyInstruction::WithdrawMultiTokenEver => LEGACY_IX_VERSION,
+            _ => CURRENT_IX_VERSION,
+        }
+    }
+
+    /// Decode instruction data, e.g. to show a transaction summary before signing
+    pub fn decode(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::try_from_slice(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TokenProxyInstruction::Initialize { .. } => "Initialize",
+            TokenProxyInstruction::DepositMultiTokenEver { .. } => "DepositMultiTokenEver",
+            TokenProxyInstruction::DepositMultiTokenSol { .. } => "DepositMultiTokenSol",
+            TokenProxyInstruction::WithdrawMultiTokenEverRequest { .. } => {
+                "WithdrawMultiTokenEverRequest"
+            }
+            TokenProxyInstruction::WithdrawMultiTokenSolRequest { .. } => {
+                "WithdrawMultiTokenSolRequest"
+            }
+            TokenProxyInstruction::VoteForWithdrawRequest { .. } => "VoteForWithdrawRequest",
+            TokenProxyInstruction::WithdrawMultiTokenEver => "WithdrawMultiTokenEver",
+            TokenProxyInstruction::WithdrawMultiTokenSol => "WithdrawMultiTokenSol",
+            TokenProxyInstruction::ChangeGuardian { .. } => "ChangeGuardian",
+            TokenProxyInstruction::ChangeManager { .. } => "ChangeManager",
+            TokenProxyInstruction::ChangeWithdrawalManager { .. } => "ChangeWithdrawalManager",
+            TokenProxyInstruction::ChangeDepositLimit { .. } => "ChangeDepositLimit",
+            TokenProxyInstruction::ChangeWithdrawalLimits { .. } => "ChangeWithdrawalLimits",
+            TokenProxyInstruction::EnableEmergencyMode => "EnableEmergencyMode",
+            TokenProxyInstruction::DisableEmergencyMode => "DisableEmergencyMode",
+            TokenProxyInstruction::EnableTokenEmergencyMode => "EnableTokenEmergencyMode",
+            TokenProxyInstruction::DisableTokenEmergencyMode => "DisableTokenEmergencyMode",
+            TokenProxyInstruction::ApproveWithdrawEver => "ApproveWithdrawEver",
+            TokenProxyInstruction::ApproveWithdrawSol => "ApproveWithdrawSol",
+            TokenProxyInstruction::UpdateFee { .. } => "UpdateFee",
+            TokenProxyInstruction::UpdateTokenName { .. } => "UpdateTokenName",
+            TokenProxyInstruction::WithdrawEverFee { .. } => "WithdrawEverFee",
+            TokenProxyInstruction::WithdrawSolFee { .. } => "WithdrawSolFee",
+            TokenProxyInstruction::ChangeBountyForWithdrawSol { .. } => {
+                "ChangeBountyForWithdrawSol"
+            }
+            TokenProxyInstruction::CancelWithdrawSol { .. } => "CancelWithdrawSol",
+            TokenProxyInstruction::FillWithdrawSol { .. } => "FillWithdrawSol",
+            TokenProxyInstruction::ExecutePayloadEver => "ExecutePayloadEver",
+            TokenProxyInstruction::ExecutePayloadSol => "ExecutePayloadSol",
+            TokenProxyInstruction::WithdrawProxy { .. } => "WithdrawProxy",
+            TokenProxyInstruction::CloseDeposit => "CloseDeposit",
+            TokenProxyInstruction::CloseWithdrawal => "CloseWithdrawal",
+            TokenProxyInstruction::WithdrawMultiVault { .. } => "WithdrawMultiVault",
+            TokenProxyInstruction::ChangePayloadAllowlist { .. } => "ChangePayloadAllowlist",
+            TokenProxyInstruction::ChangeDepositCallerAllowlist { .. } => {
+                "ChangeDepositCallerAllowlist"
+            }
+            TokenProxyInstruction::ChangeTokenDecimals { .. } => "ChangeTokenDecimals",
+            TokenProxyInstruction::ChangeDepositDailyLimit { .. } => "ChangeDepositDailyLimit",
+            TokenProxyInstruction::ChangeWithdrawalEpochLength { .. } => {
+                "ChangeWithdrawalEpochLength"
+            }
+            TokenProxyInstruction::ChangeTokenDefaults { .. } => "ChangeTokenDefaults",
+            TokenProxyInstruction::RegisterTokenEver { .. } => "RegisterTokenEver",
+            TokenProxyInstruction::RegisterTokenSol { .. } => "RegisterTokenSol",
+            TokenProxyInstruction::DelistToken => "DelistToken",
+            TokenProxyInstruction::CloseToken => "CloseToken",
+            TokenProxyInstruction::ProposeMintAuthority { .. } => "ProposeMintAuthority",
+            TokenProxyInstruction::MigrateMintAuthority => "MigrateMintAuthority",
+            TokenProxyInstruction::ProposeVaultAuthority { .. } => "ProposeVaultAuthority",
+            TokenProxyInstruction::MigrateVaultAuthority => "MigrateVaultAuthority",
+            TokenProxyInstruction::MigrateSettingsV2 => "MigrateSettingsV2",
+            TokenProxyInstruction::DepositMultiTokenEverV2 { .. } => "DepositMultiTokenEverV2",
+            TokenProxyInstruction::DepositMultiTokenSolV2 { .. } => "DepositMultiTokenSolV2",
+            TokenProxyInstruction::WithdrawMultiTokenEverRequestV2 { .. } => {
+                "WithdrawMultiTokenEverRequestV2"
+            }
+            TokenProxyInstruction::WithdrawMultiTokenSolRequestV2 { .. } => {
+                "WithdrawMultiTokenSolRequestV2"
+            }
+            TokenProxyInstruction::ChangeWever { .. } => "ChangeWever",
+            TokenProxyInstruction::ChangeEnabledChains { .. } => "ChangeEnabledChains",
+            TokenProxyInstruction::PauseToken { .. } => "PauseToken",
+            TokenProxyInstruction::UnpauseToken { .. } => "UnpauseToken",
+            TokenProxyInstruction::CreateFeeLedger => "CreateFeeLedger",
+            TokenProxyInstruction::WithdrawMultiTokenEverV2 { .. } => "WithdrawMultiTokenEverV2",
+            TokenProxyInstruction::ChangeDepositValueFloor { .. } => "ChangeDepositValueFloor",
+            TokenProxyInstruction::ChangeMinSupportedIxVersion { .. } => {
+                "ChangeMinSupportedIxVersion"
+            }
+            TokenProxyInstruction::CancelWithdrawalRequest => "CancelWithdrawalRequest",
+            TokenProxyInstruction::ChangeVoteReward { .. } => "ChangeVoteReward",
+            TokenProxyInstruction::ClaimVoteRewards => "ClaimVoteRewards",
+            TokenProxyInstruction::ChangeWithdrawalLimitExemptions { .. } => {
+                "ChangeWithdrawalLimitExemptions"
+            }
+            TokenProxyInstruction::SetRecipientHook { .. } => "SetRecipientHook",
+            TokenProxyInstruction::RemoveRecipientHook => "RemoveRecipientHook",
+            TokenProxyInstruction::CreateTokenMetadata { .. } => "CreateTokenMetadata",
+            TokenProxyInstruction::ProposeRole { .. } => "ProposeRole",
+            TokenProxyInstruction::AcceptRole => "AcceptRole",
+            TokenProxyInstruction::ScheduleChange { .. } => "ScheduleChange",
+            TokenProxyInstruction::ExecuteChange => "ExecuteChange",
+            TokenProxyInstruction::CancelChange => "CancelChange",
+            TokenProxyInstruction::MigrateAccount => "MigrateAccount",
+            TokenProxyInstruction::UpdateFeeTiers { .. } => "UpdateFeeTiers",
+            TokenProxyInstruction::ExpireWithdrawal => "ExpireWithdrawal",
+            TokenProxyInstruction::VoteForWithdrawRequestBySignatures => {
+                "VoteForWithdrawRequestBySignatures"
+            }
+            TokenProxyInstruction::CancelDeposit => "CancelDeposit",
+            TokenProxyInstruction::AddToDenylist { .. } => "AddToDenylist",
+            TokenProxyInstruction::RemoveFromDenylist { .. } => "RemoveFromDenylist",
+            TokenProxyInstruction::SweepClosedAccounts => "SweepClosedAccounts",
+            TokenProxyInstruction::SetTreasury { .. } => "SetTreasury",
+            TokenProxyInstruction::WithdrawMultiTokenEverToManyRequest { .. } => {
+                "WithdrawMultiTokenEverToManyRequest"
+            }
+            TokenProxyInstruction::WithdrawMultiTokenEverToMany => "WithdrawMultiTokenEverToMany",
+            TokenProxyInstruction::ApproveWithdrawEverToMany => "ApproveWithdrawEverToMany",
+            TokenProxyInstruction::ChangeMinDeposit { .. } => "ChangeMinDeposit",
+            TokenProxyInstruction::CreateBridgeStats => "CreateBridgeStats",
+            TokenProxyInstruction::DepositBountyForWithdrawSol { .. } => {
+                "DepositBountyForWithdrawSol"
+            }
+            TokenProxyInstruction::RefundBountyForWithdrawSol => "RefundBountyForWithdrawSol",
+            TokenProxyInstruction::PartialFillWithdrawSol { .. } => "PartialFillWithdrawSol",
+            TokenProxyInstruction::RegisterLiquidityProvider { .. } => "RegisterLiquidityProvider",
+            TokenProxyInstruction::UnregisterLiquidityProvider { .. } => {
+                "UnregisterLiquidityProvider"
+            }
+            TokenProxyInstruction::ChangeFillsRestricted { .. } => "ChangeFillsRestricted",
+            TokenProxyInstruction::ChangeReferralFee { .. } => "ChangeReferralFee",
+            TokenProxyInstruction::ClaimReferralFee => "ClaimReferralFee",
+            TokenProxyInstruction::CreateApprovalQueue => "CreateApprovalQueue",
+            TokenProxyInstruction::RejectWithdrawEver { .. } => "RejectWithdrawEver",
+            TokenProxyInstruction::RejectWithdrawSol { .. } => "RejectWithdrawSol",
+            TokenProxyInstruction::ChangeGuardians { .. } => "ChangeGuardians",
+            TokenProxyInstruction::PauseBridge { .. } => "PauseBridge",
+            TokenProxyInstruction::UnpauseBridge { .. } => "UnpauseBridge",
+            TokenProxyInstruction::ExecutePayloadEverV2 { .. } => "ExecutePayloadEverV2",
+            TokenProxyInstruction::ExecutePayloadSolV2 { .. } => "ExecutePayloadSolV2",
+            TokenProxyInstruction::EnableEmergencyModeV2 { .. } => "EnableEmergencyModeV2",
+            TokenProxyInstruction::EnableTokenEmergencyModeV2 { .. } => {
+                "EnableTokenEmergencyModeV2"
+            }
+            TokenProxyInstruction::DisableEmergencyModeV2 { .. } => "DisableEmergencyModeV2",
+            TokenProxyInstruction::DisableTokenEmergencyModeV2 { .. } => {
+                "DisableTokenEmergencyModeV2"
+            }
+            TokenProxyInstruction::ApproveWithdrawEverV2 { .. } => "ApproveWithdrawEverV2",
+            TokenProxyInstruction::ApproveWithdrawSolV2 { .. } => "ApproveWithdrawSolV2",
+        }
+    }
+
+    /// Accounts in the order the processor reads them. Required accounts go
+    /// first, optional ones follow them, a list takes all accounts left
+    pub fn expected_accounts(&self) -> Vec<AccountRole> {
+        match self {
+            TokenProxyInstruction::Initialize { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("initializer"),
+                writable("settings"),
+                writable("multivault"),
+                readonly("program_data"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                writable("denylist"),
+            ],
+            TokenProxyInstruction::DepositMultiTokenEver { .. }
+            | TokenProxyInstruction::DepositMultiTokenEverV2 { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("author"),
+                writable("author_token"),
+                writable("deposit"),
+                writable("mint"),
+                writable("multivault"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("system_program"),
+                readonly("token_program"),
+                readonly("rent_sysvar"),
+                writable("bridge_stats"),
+                readonly("instructions_sysvar").optional(),
+                writable("fee_ledger").optional(),
+                writable("referral").optional(),
+                readonly("callback_program").optional(),
+                readonly("callback_accounts").list(),
+            ],
+            TokenProxyInstruction::DepositMultiTokenSol { .. }
+            | TokenProxyInstruction::DepositMultiTokenSolV2 { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("author"),
+                writable("author_token"),
+                writable("vault"),
+                writable("deposit"),
+                writable("mint"),
+                writable("multivault"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("system_program"),
+                readonly("token_program"),
+                readonly("rent_sysvar"),
+                writable("bridge_stats"),
+                readonly("instructions_sysvar").optional(),
+                writable("fee_ledger").optional(),
+                writable("referral").optional(),
+                readonly("callback_program").optional(),
+                readonly("callback_accounts").list(),
+            ],
+            TokenProxyInstruction::WithdrawMultiTokenEverRequest { .. }
+            | TokenProxyInstruction::WithdrawMultiTokenEverRequestV2 { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("author"),
+                writable("withdrawal"),
+                readonly("rl_settings"),
+                readonly("relay_round"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                readonly("clock_sysvar"),
+                writable("proposal_index"),
+                writable("bridge_stats"),
+                writable("proxy").optional(),
+                writable("mint").optional(),
+                writable("token_program").optional(),
+            ],
+            TokenProxyInstruction::WithdrawMultiTokenSolRequest { .. }
+            | TokenProxyInstruction::WithdrawMultiTokenSolRequestV2 { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("author"),
+                writable("withdrawal"),
+                readonly("token_settings"),
+                readonly("rl_settings"),
+                readonly("relay_round"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                readonly("clock_sysvar"),
+                writable("proposal_index"),
+                writable("bridge_stats"),
+                writable("proxy").optional(),
+                writable("mint").optional(),
+                writable("token_program").optional(),
+            ],
+            TokenProxyInstruction::VoteForWithdrawRequest { .. } => vec![
+                writable_signer("relay"),
+                writable("withdrawal"),
+                readonly("relay_round"),
+                readonly("rl_settings"),
+                writable("relay_reward").optional(),
+                readonly("system_program").optional(),
+            ],
+            TokenProxyInstruction::WithdrawMultiTokenEver
+            | TokenProxyInstruction::WithdrawMultiTokenEverV2 { .. } => vec![
+                writable("withdrawal"),
+                writable("mint"),
+                writable("recipient"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("system_program"),
+                readonly("token_program"),
+                readonly("rent_sysvar"),
+                readonly("clock_sysvar"),
+                readonly("relay_round"),
+                readonly("rl_settings"),
+                readonly("recipient_hook"),
+                readonly("denylist"),
+                writable("bridge_stats"),
+                writable("approval_queue"),
+                writable_signer("funder").optional(),
+                writable("recipient_token").optional(),
+                readonly("recipient_wallet").optional(),
+                readonly("associated_token_program").optional(),
+                readonly("token_decimals").optional(),
+                writable("proposal_index").optional(),
+                writable("fee_ledger").optional(),
+            ],
+            TokenProxyInstruction::WithdrawMultiTokenSol => vec![
+                writable("withdrawal"),
+                writable("vault"),
+                writable("recipient"),
+                writable("token_settings"),
+                readonly("mint"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("clock_sysvar"),
+                readonly("relay_round"),
+                readonly("rl_settings"),
+                readonly("recipient_hook"),
+                readonly("denylist"),
+                writable("bridge_stats"),
+                writable("approval_queue"),
+                writable_signer("funder").optional(),
+                readonly("recipient_wallet").optional(),
+                readonly("system_program").optional(),
+                readonly("associated_token_program").optional(),
+                writable("proposal_index").optional(),
+                writable("fee_ledger").optional(),
+            ],
+            TokenProxyInstruction::ChangeGuardian { .. }
+            | TokenProxyInstruction::ChangeManager { .. }
+            | TokenProxyInstruction::ChangeWithdrawalManager { .. }
+            | TokenProxyInstruction::DisableEmergencyMode
+            | TokenProxyInstruction::DisableEmergencyModeV2 { .. }
+            | TokenProxyInstruction::ChangeWever { .. }
+            | TokenProxyInstruction::ChangeEnabledChains { .. }
+            | TokenProxyInstruction::ChangeGuardians { .. }
+            | TokenProxyInstruction::ChangeMinSupportedIxVersion { .. }
+            | TokenProxyInstruction::ProposeRole { .. }
+            | TokenProxyInstruction::SetTreasury { .. } => vec![
+                writable_signer("authority"),
+                writable("settings"),
+                readonly("program_data"),
+            ],
+            TokenProxyInstruction::ChangeDepositLimit { .. }
+            | TokenProxyInstruction::ChangeWithdrawalLimits { .. }
+            | TokenProxyInstruction::EnableTokenEmergencyMode
+            | TokenProxyInstruction::EnableTokenEmergencyModeV2 { .. }
+            | TokenProxyInstruction::UpdateFee { .. }
+            | TokenProxyInstruction::UpdateTokenName { .. }
+            | TokenProxyInstruction::ChangeDepositDailyLimit { .. }
+            | TokenProxyInstruction::ChangeWithdrawalEpochLength { .. }
+            | TokenProxyInstruction::PauseToken { .. }
+            | TokenProxyInstruction::UnpauseToken { .. }
+            | TokenProxyInstruction::ChangeVoteReward { .. }
+            | TokenProxyInstruction::ChangeWithdrawalLimitExemptions { .. }
+            | TokenProxyInstruction::UpdateFeeTiers { .. }
+            | TokenProxyInstruction::ChangeMinDeposit { .. }
+            | TokenProxyInstruction::ChangeFillsRestricted { .. }
+            | TokenProxyInstruction::ChangeReferralFee { .. } => vec![
+                writable_signer("authority"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::EnableEmergencyMode
+            | TokenProxyInstruction::EnableEmergencyModeV2 { .. }
+            | TokenProxyInstruction::ChangePayloadAllowlist { .. }
+            | TokenProxyInstruction::ChangeDepositCallerAllowlist { .. }
+            | TokenProxyInstruction::ChangeTokenDefaults { .. }
+            | TokenProxyInstruction::ChangeDepositValueFloor { .. }
+            | TokenProxyInstruction::PauseBridge { .. }
+            | TokenProxyInstruction::UnpauseBridge { .. } => vec![
+                writable_signer("authority"),
+                writable("settings"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::DisableTokenEmergencyMode
+            | TokenProxyInstruction::DisableTokenEmergencyModeV2 { .. }
+            | TokenProxyInstruction::DelistToken
+            | TokenProxyInstruction::ProposeMintAuthority { .. }
+            | TokenProxyInstruction::ProposeVaultAuthority { .. } => vec![
+                writable_signer("authority"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("program_data"),
+            ],
+            TokenProxyInstruction::ApproveWithdrawEver
+            | TokenProxyInstruction::ApproveWithdrawEverV2 { .. } => vec![
+                writable_signer("authority"),
+                writable("mint"),
+                writable("withdrawal"),
+                writable("recipient"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("clock_sysvar"),
+                readonly("recipient_hook"),
+                writable("bridge_stats"),
+                writable("approval_queue"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::ApproveWithdrawSol
+            | TokenProxyInstruction::ApproveWithdrawSolV2 { .. } => vec![
+                writable_signer("authority"),
+                writable("vault"),
+                writable("withdrawal"),
+                writable("recipient"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("clock_sysvar"),
+                readonly("recipient_hook"),
+                writable("bridge_stats"),
+                writable("approval_queue"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::WithdrawEverFee { .. } => vec![
+                writable_signer("authority"),
+                writable("mint"),
+                writable("recipient_token"),
+                writable("token_settings"),
+                writable("fee_ledger"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::WithdrawSolFee { .. } => vec![
+                writable_signer("authority"),
+                writable("vault"),
+                writable("token_settings"),
+                writable("fee_ledger"),
+                writable("recipient_token"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::ChangeBountyForWithdrawSol { .. } => {
+                vec![writable_signer("author"), writable("withdrawal")]
+            }
+            TokenProxyInstruction::CancelWithdrawSol { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("author"),
+                writable("mint"),
+                writable("withdrawal"),
+                writable("deposit"),
+                readonly("settings"),
+                writable("token_settings"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                writable("multivault"),
+                writable("bridge_stats"),
+                writable("fee_ledger").optional(),
+            ],
+            TokenProxyInstruction::FillWithdrawSol { .. }
+            | TokenProxyInstruction::PartialFillWithdrawSol { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("author"),
+                writable("author_token"),
+                writable("mint"),
+                writable("deposit"),
+                readonly("settings"),
+                readonly("system_program"),
+                writable("token_settings"),
+                readonly("token_program"),
+                readonly("rent_sysvar"),
+                writable("multivault"),
+                writable("bridge_stats"),
+                writable("withdrawals").list(),
+                writable("vault"),
+            ],
+            TokenProxyInstruction::ExecutePayloadEver
+            | TokenProxyInstruction::ExecutePayloadEverV2 { .. }
+            | TokenProxyInstruction::ExecutePayloadSol
+            | TokenProxyInstruction::ExecutePayloadSolV2 { .. } => vec![
+                writable("withdrawal"),
+                readonly("settings"),
+                readonly("payload_accounts").list(),
+            ],
+            TokenProxyInstruction::WithdrawProxy { .. } => vec![
+                writable_signer("author"),
+                writable("recipient_token"),
+                writable("proxy"),
+                readonly("mint"),
+                readonly("token_program"),
+            ],
+            TokenProxyInstruction::CloseDeposit => vec![
+                writable_signer("author"),
+                writable("deposit"),
+                writable("funder"),
+            ],
+            TokenProxyInstruction::CloseWithdrawal => {
+                vec![writable("withdrawal"), writable("funder")]
+            }
+            TokenProxyInstruction::WithdrawMultiVault { .. } => vec![
+                writable_signer("authority"),
+                writable("recipient"),
+                writable("multivault"),
+                readonly("settings"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::ChangeTokenDecimals { .. } => vec![
+                writable_signer("authority"),
+                writable("token_decimals"),
+                readonly("mint"),
+                readonly("settings"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::RegisterTokenEver { .. } => vec![
+                writable_signer("authority"),
+                writable("mint"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("system_program"),
+                readonly("token_program"),
+                readonly("rent_sysvar"),
+                readonly("program_data").optional(),
+                readonly("token_decimals").optional(),
+            ],
+            TokenProxyInstruction::RegisterTokenSol { .. } => vec![
+                writable_signer("authority"),
+                writable("vault"),
+                readonly("mint"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("system_program"),
+                readonly("token_program"),
+                readonly("rent_sysvar"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::CloseToken => vec![
+                writable_signer("authority"),
+                writable("token_settings"),
+                writable("fee_ledger"),
+                readonly("mint_or_vault"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("program_data"),
+            ],
+            TokenProxyInstruction::MigrateMintAuthority => vec![
+                writable_signer("authority"),
+                writable("token_settings"),
+                writable("mint"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("program_data"),
+            ],
+            TokenProxyInstruction::MigrateVaultAuthority => vec![
+                writable_signer("authority"),
+                writable("token_settings"),
+                writable("vault"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("program_data"),
+            ],
+            TokenProxyInstruction::MigrateSettingsV2 => vec![
+                writable_signer("funder"),
+                writable_signer("authority"),
+                writable("settings"),
+                readonly("program_data"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                writable("denylist"),
+            ],
+            TokenProxyInstruction::CreateFeeLedger => vec![
+                writable_signer("funder"),
+                writable("token_settings"),
+                writable("fee_ledger"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+            ],
+            TokenProxyInstruction::CancelWithdrawalRequest => vec![
+                writable_signer("author"),
+                writable("withdrawal"),
+                writable("proposal_index"),
+                writable("funder"),
+                writable("bridge_stats"),
+            ],
+            TokenProxyInstruction::ClaimReferralFee => vec![
+                readonly_signer("referrer"),
+                writable("referral"),
+                readonly("token_settings"),
+                writable("mint_or_vault"),
+                writable("recipient_token"),
+                readonly("token_program"),
+            ],
+            TokenProxyInstruction::ClaimVoteRewards => vec![
+                writable_signer("relay"),
+                readonly("relay_round"),
+                writable("token_settings"),
+                writable("fee_ledger"),
+                writable("mint_or_vault"),
+                writable("recipient_token"),
+                readonly("token_program"),
+                writable("withdrawals").list(),
+            ],
+            TokenProxyInstruction::SetRecipientHook { .. } => vec![
+                writable_signer("funder"),
+                readonly_signer("recipient"),
+                writable("recipient_hook"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+            ],
+            TokenProxyInstruction::RemoveRecipientHook => vec![
+                readonly_signer("recipient"),
+                writable("recipient_hook"),
+                writable("funder"),
+            ],
+            TokenProxyInstruction::CreateTokenMetadata { .. } => vec![
+                writable_signer("authority"),
+                writable_signer("funder"),
+                readonly("token_settings"),
+                readonly("mint"),
+                writable("metadata"),
+                readonly("settings"),
+                readonly("token_metadata_program"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::AcceptRole => {
+                vec![writable_signer("authority"), writable("settings")]
+            }
+            TokenProxyInstruction::ScheduleChange { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("authority"),
+                writable("pending_change"),
+                readonly("settings"),
+                readonly("program_data"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+            ],
+            TokenProxyInstruction::ExecuteChange => vec![
+                writable("author"),
+                writable("pending_change"),
+                writable("settings"),
+                readonly("token_settings").optional(),
+            ],
+            TokenProxyInstruction::CancelChange => vec![
+                writable_signer("authority"),
+                writable("pending_change"),
+                writable("author"),
+                readonly("settings"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::MigrateAccount => vec![
+                writable_signer("funder"),
+                writable("account"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+            ],
+            TokenProxyInstruction::ExpireWithdrawal => vec![
+                writable("withdrawal"),
+                writable("funder"),
+                readonly("clock_sysvar"),
+                writable("bridge_stats"),
+            ],
+            TokenProxyInstruction::VoteForWithdrawRequestBySignatures => vec![
+                writable_signer("submitter"),
+                writable("withdrawal"),
+                readonly("relay_round"),
+                readonly("instructions_sysvar"),
+                readonly("rl_settings"),
+            ],
+            TokenProxyInstruction::CancelDeposit => vec![
+                writable_signer("authority"),
+                writable("deposit"),
+                writable("author_token"),
+                writable("mint"),
+                readonly("token_settings"),
+                readonly("settings"),
+                readonly("token_program"),
+                writable("vault").optional(),
+            ],
+            TokenProxyInstruction::AddToDenylist { .. } => vec![
+                writable_signer("funder"),
+                readonly_signer("authority"),
+                writable("denylist"),
+                readonly("settings"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::RemoveFromDenylist { .. } => vec![
+                readonly_signer("authority"),
+                writable("denylist"),
+                readonly("settings"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::RegisterLiquidityProvider { .. } => vec![
+                writable_signer("funder"),
+                readonly_signer("authority"),
+                writable("lp_registry"),
+                readonly("settings"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::UnregisterLiquidityProvider { .. } => vec![
+                readonly_signer("authority"),
+                writable("lp_registry"),
+                readonly("settings"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::SweepClosedAccounts => vec![writable("accounts").list()],
+            TokenProxyInstruction::WithdrawMultiTokenEverToManyRequest { .. } => vec![
+                writable_signer("funder"),
+                writable_signer("author"),
+                writable("withdrawal"),
+                readonly("rl_settings"),
+                readonly("relay_round"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+                readonly("clock_sysvar"),
+                writable("proposal_index"),
+                writable("bridge_stats"),
+            ],
+            TokenProxyInstruction::WithdrawMultiTokenEverToMany => vec![
+                writable("withdrawal"),
+                writable("mint"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("clock_sysvar"),
+                readonly("relay_round"),
+                readonly("rl_settings"),
+                readonly("denylist"),
+                writable("bridge_stats"),
+                writable("recipient_tokens").list(),
+                writable("proposal_index").optional(),
+                writable("fee_ledger").optional(),
+            ],
+            TokenProxyInstruction::ApproveWithdrawEverToMany => vec![
+                writable_signer("authority"),
+                writable("mint"),
+                writable("withdrawal"),
+                writable("token_settings"),
+                readonly("settings"),
+                readonly("token_program"),
+                readonly("clock_sysvar"),
+                writable("bridge_stats"),
+                readonly("program_data").optional(),
+                writable("recipient_tokens").list(),
+            ],
+            TokenProxyInstruction::CreateBridgeStats => vec![
+                writable_signer("funder"),
+                writable("bridge_stats"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+            ],
+            TokenProxyInstruction::CreateApprovalQueue => vec![
+                writable_signer("funder"),
+                writable("approval_queue"),
+                readonly("system_program"),
+                readonly("rent_sysvar"),
+            ],
+            TokenProxyInstruction::RejectWithdrawEver { .. }
+            | TokenProxyInstruction::RejectWithdrawSol { .. } => vec![
+                writable_signer("authority"),
+                writable("withdrawal"),
+                readonly("mint"),
+                writable("token_settings"),
+                readonly("settings"),
+                writable("bridge_stats"),
+                writable("approval_queue"),
+                readonly("program_data").optional(),
+            ],
+            TokenProxyInstruction::DepositBountyForWithdrawSol { .. } => vec![
+                writable_signer("author"),
+                writable("author_token"),
+                readonly("withdrawal"),
+                writable("bounty"),
+                readonly("mint"),
+                readonly("system_program"),
+                readonly("token_program"),
+                readonly("rent_sysvar"),
+            ],
+            TokenProxyInstruction::RefundBountyForWithdrawSol => vec![
+                writable_signer("author"),
+                writable("author_token"),
+                readonly("withdrawal"),
+                writable("bounty"),
+                readonly("token_program"),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AccountRole {
+    pub name: &'static str,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    // Omitted unless the instruction needs it, e.g. program data when signed by the owner
+    pub is_optional: bool,
+    // Any number of accounts
+    pub is_list: bool,
+}
+
+impl AccountRole {
+    const fn optional(self) -> Self {
+        Self {
+            is_optional: true,
+            ..self
+        }
+    }
+
+    const fn list(self) -> Self {
+        Self {
+            is_list: true,
+            ..self
+        }
+    }
+}
+
+const fn account_role(name: &'static str, is_signer: bool, is_writable: bool) -> AccountRole {
+    AccountRole {
+        name,
+        is_signer,
+        is_writable,
+        is_optional: false,
+        is_list: false,
+    }
+}
+
+const fn writable_signer(name: &'static str) -> AccountRole {
+    account_role(name, true, true)
+}
+
+const fn readonly_signer(name: &'static str) -> AccountRole {
+    account_role(name, true, false)
+}
+
+const fn writable(name: &'static str) -> AccountRole {
+    account_role(name, false, true)
+}
+
+const fn readonly(name: &'static str) -> AccountRole {
+    account_role(name, false, false)
 }
@@ -1,20 +1,28 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::amount::{to_ever_amount, to_solana_amount};
 use bridge_utils::errors::SolanaBridgeError;
 use bridge_utils::state::{AccountKind, Proposal, PDA};
-use bridge_utils::types::{EverAddress, UInt256, Vote, RELAY_REPARATION};
+use bridge_utils::types::{EverAddress, UInt256, Vote, VoteReward, RELAY_REPARATION};
 use round_loader::RelayRound;
 
 use solana_program::account_info::{next_account_info, next_account_infos, AccountInfo};
 use solana_program::clock::{Clock, SECONDS_PER_DAY};
 use solana_program::entrypoint::ProgramResult;
-use solana_program::hash::hash;
+use solana_program::hash::{hash, Hash};
+use solana_program::instruction::{
+    get_stack_height, AccountMeta, Instruction, TRANSACTION_LEVEL_STACK_HEIGHT,
+};
 use solana_program::program::{invoke, invoke_signed};
 use solana_program::program_error::ProgramError;
-use solana_program::program_pack::Pack;
+use solana_program::program_pack::{IsInitialized, Pack};
 use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
 use solana_program::sysvar::Sysvar;
-use solana_program::{bpf_loader_upgradeable, msg, system_instruction, system_program};
+use solana_program::{bpf_loader_upgradeable, msg, system_instruction, system_program, sysvar};
+use spl_associated_token_account::tools::account::create_pda_account;
 
 use crate::*;
 
@@ -26,6 +34,7 @@ impl Processor {
         instruction_data: &[u8],
     ) -> ProgramResult {
         let instruction = TokenProxyInstruction::try_from_slice(instruction_data)?;
+        let ix_version = instruction.version();
 
         match instruction {
             TokenProxyInstruction::Initialize {
@@ -60,6 +69,10 @@ impl Processor {
                     value,
                     expected_evers,
                     payload,
+                    None,
+                    None,
+                    None,
+                    ix_version,
                 )?;
             }
             TokenProxyInstruction::DepositMultiTokenSol {
@@ -84,6 +97,10 @@ impl Processor {
                     value,
                     expected_evers,
                     payload,
+                    None,
+                    None,
+                    None,
+                    ix_version,
                 )?;
             }
             TokenProxyInstruction::WithdrawMultiTokenEverRequest {
@@ -114,6 +131,8 @@ impl Processor {
                     amount,
                     payload,
                     attached_amount,
+                    None,
+                    None,
                 )?;
             }
             TokenProxyInstruction::WithdrawMultiTokenSolRequest {
@@ -136,6 +155,8 @@ impl Processor {
                     amount,
                     payload,
                     attached_amount,
+                    None,
+                    None,
                 )?;
             }
             TokenProxyInstruction::VoteForWithdrawRequest { vote } => {
@@ -144,7 +165,7 @@ impl Processor {
             }
             TokenProxyInstruction::WithdrawMultiTokenEver => {
                 msg!("Instruction: Withdraw Multi Token EVER");
-                Self::process_withdraw_multi_token_ever(program_id, accounts)?;
+                Self::process_withdraw_multi_token_ever(program_id, accounts, false, ix_version)?;
             }
             TokenProxyInstruction::WithdrawMultiTokenSol => {
                 msg!("Instruction: Withdraw Multi Token SOL");
@@ -186,27 +207,51 @@ impl Processor {
             }
             TokenProxyInstruction::EnableEmergencyMode => {
                 msg!("Instruction: Enable emergency mode");
-                Self::process_enable_emergency_mode(program_id, accounts)?;
+                Self::process_enable_emergency_mode(program_id, accounts, None, None)?;
+            }
+            TokenProxyInstruction::EnableEmergencyModeV2 { expiry, reason } => {
+                msg!("Instruction: Enable emergency mode V2");
+                Self::process_enable_emergency_mode(program_id, accounts, expiry, reason)?;
             }
             TokenProxyInstruction::DisableEmergencyMode => {
                 msg!("Instruction: Disable emergency mode");
-                Self::process_disable_emergency_mode(program_id, accounts)?;
+                Self::process_disable_emergency_mode(program_id, accounts, None)?;
+            }
+            TokenProxyInstruction::DisableEmergencyModeV2 { reason } => {
+                msg!("Instruction: Disable emergency mode V2");
+                Self::process_disable_emergency_mode(program_id, accounts, reason)?;
             }
             TokenProxyInstruction::EnableTokenEmergencyMode => {
                 msg!("Instruction: Enable token emergency mode");
-                Self::process_enable_token_emergency_mode(program_id, accounts)?;
+                Self::process_enable_token_emergency_mode(program_id, accounts, None, None)?;
+            }
+            TokenProxyInstruction::EnableTokenEmergencyModeV2 { expiry, reason } => {
+                msg!("Instruction: Enable token emergency mode V2");
+                Self::process_enable_token_emergency_mode(program_id, accounts, expiry, reason)?;
             }
             TokenProxyInstruction::DisableTokenEmergencyMode => {
                 msg!("Instruction: Disable token emergency mode");
-                Self::process_disable_token_emergency_mode(program_id, accounts)?;
+                Self::process_disable_token_emergency_mode(program_id, accounts, None)?;
+            }
+            TokenProxyInstruction::DisableTokenEmergencyModeV2 { reason } => {
+                msg!("Instruction: Disable token emergency mode V2");
+                Self::process_disable_token_emergency_mode(program_id, accounts, reason)?;
             }
             TokenProxyInstruction::ApproveWithdrawEver => {
                 msg!("Instruction: Approve Withdraw Multi Token EVER");
-                Self::process_approve_withdraw_ever(program_id, accounts)?;
+                Self::process_approve_withdraw_ever(program_id, accounts, None)?;
+            }
+            TokenProxyInstruction::ApproveWithdrawEverV2 { note } => {
+                msg!("Instruction: Approve Withdraw Multi Token EVER V2");
+                Self::process_approve_withdraw_ever(program_id, accounts, note)?;
             }
             TokenProxyInstruction::ApproveWithdrawSol => {
                 msg!("Instruction: Approve Withdraw Multi Token SOL");
-                Self::process_approve_withdraw_sol(program_id, accounts)?;
+                Self::process_approve_withdraw_sol(program_id, accounts, None)?;
+            }
+            TokenProxyInstruction::ApproveWithdrawSolV2 { note } => {
+                msg!("Instruction: Approve Withdraw Multi Token SOL V2");
+                Self::process_approve_withdraw_sol(program_id, accounts, note)?;
             }
             TokenProxyInstruction::UpdateFee {
                 fee_type,
@@ -268,15 +313,30 @@ impl Processor {
                     value,
                     expected_evers,
                     payload,
+                    None,
                 )?;
             }
             TokenProxyInstruction::ExecutePayloadEver => {
                 msg!("Instruction: Execute Payload EVER");
-                Self::process_execute_payload_ever(program_id, accounts)?;
+                Self::process_execute_payload_ever(program_id, accounts, None, 0)?;
+            }
+            TokenProxyInstruction::ExecutePayloadEverV2 {
+                count,
+                proxy_accounts,
+            } => {
+                msg!("Instruction: Execute Payload EVER V2");
+                Self::process_execute_payload_ever(program_id, accounts, count, proxy_accounts)?;
             }
             TokenProxyInstruction::ExecutePayloadSol => {
                 msg!("Instruction: Execute Payload SOL");
-                Self::process_execute_payload_sol(program_id, accounts)?;
+                Self::process_execute_payload_sol(program_id, accounts, None, 0)?;
+            }
+            TokenProxyInstruction::ExecutePayloadSolV2 {
+                count,
+                proxy_accounts,
+            } => {
+                msg!("Instruction: Execute Payload SOL V2");
+                Self::process_execute_payload_sol(program_id, accounts, count, proxy_accounts)?;
             }
             TokenProxyInstruction::WithdrawProxy { amount } => {
                 msg!("Instruction: Withdraw Proxy");
@@ -294,345 +354,9457 @@ impl Processor {
                 msg!("Instruction: Withdraw Multi Vault");
                 Self::process_withdraw_multi_vault(program_id, accounts, amount)?;
             }
-        };
-
-        Ok(())
-    }
-
-    fn process_initialize(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        guardian: Pubkey,
-        manager: Pubkey,
-        withdrawal_manager: Pubkey,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
-
-        let funder_account_info = next_account_info(account_info_iter)?;
-        let initializer_account_info = next_account_info(account_info_iter)?;
-        let settings_account_info = next_account_info(account_info_iter)?;
-        let multi_vault_account_info = next_account_info(account_info_iter)?;
-        let programdata_account_info = next_account_info(account_info_iter)?;
-        let _system_program_info = next_account_info(account_info_iter)?;
-        let rent_sysvar_info = next_account_info(account_info_iter)?;
-        let rent = &Rent::from_account_info(rent_sysvar_info)?;
-
-        if !initializer_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        // Validate Initializer Account
-        let (programdata_pubkey, programdata_nonce) =
-            Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
-
-        if programdata_pubkey != *programdata_account_info.key {
-            return Err(ProgramError::InvalidArgument);
-        }
-
-        bridge_utils::helper::validate_initializer_account(
-            initializer_account_info.key,
-            programdata_account_info,
-        )?;
-
-        // Create Settings Account
-        let (settings_pubkey, settings_nonce) =
-            Pubkey::find_program_address(&[br"settings"], program_id);
-        let settings_account_signer_seeds: &[&[_]] = &[br"settings", &[settings_nonce]];
-
-        if settings_pubkey != *settings_account_info.key {
-            return Err(ProgramError::InvalidArgument);
-        }
-
-        invoke_signed(
-            &system_instruction::create_account(
-                funder_account_info.key,
-                settings_account_info.key,
-                1.max(rent.minimum_balance(Settings::LEN)),
-                Settings::LEN as u64,
-                program_id,
-            ),
-            accounts,
-            &[settings_account_signer_seeds],
-        )?;
-
-        // Init Settings Account
-        let settings_account_data = Settings {
-            is_initialized: true,
-            account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
-            emergency: false,
-            guardian,
-            manager,
-            withdrawal_manager,
-        };
-
-        Settings::pack(
-            settings_account_data,
-            &mut settings_account_info.data.borrow_mut(),
-        )?;
-
-        // Create Multi Vault Account
-        let (multi_vault_pubkey, multi_vault_nonce) =
-            Pubkey::find_program_address(&[br"multivault"], program_id);
-        let multi_vault_account_signer_seeds: &[&[_]] = &[br"multivault", &[multi_vault_nonce]];
-
-        if multi_vault_pubkey != *multi_vault_account_info.key {
-            return Err(ProgramError::InvalidArgument);
-        }
-
-        invoke_signed(
-            &system_instruction::create_account(
-                funder_account_info.key,
-                multi_vault_account_info.key,
-                1.max(rent.minimum_balance(MultiVault::LEN)),
-                MultiVault::LEN as u64,
-                program_id,
-            ),
-            accounts,
-            &[multi_vault_account_signer_seeds],
-        )?;
-
-        // Init Multi Vault Account
-        let multi_vault_account_data = MultiVault {
-            is_initialized: true,
-            account_kind: AccountKind::MultiVault(multi_vault_nonce),
-        };
-
-        MultiVault::pack(
-            multi_vault_account_data,
-            &mut multi_vault_account_info.data.borrow_mut(),
-        )?;
-
-        Ok(())
-    }
-
-    #[allow(clippy::too_many_arguments)]
-    fn process_deposit_multi_token_ever(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        deposit_seed: u128,
-        amount: u64,
-        recipient: EverAddress,
-        value: u64,
-        expected_evers: UInt256,
-        payload: Vec<u8>,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
-
-        let funder_account_info = next_account_info(account_info_iter)?;
-        let creator_account_info = next_account_info(account_info_iter)?;
-        let creator_token_account_info = next_account_info(account_info_iter)?;
-        let deposit_account_info = next_account_info(account_info_iter)?;
-        let mint_account_info = next_account_info(account_info_iter)?;
-        let multi_vault_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
-        let settings_account_info = next_account_info(account_info_iter)?;
-        let _system_program_info = next_account_info(account_info_iter)?;
-        let _token_program_info = next_account_info(account_info_iter)?;
-        let rent_sysvar_info = next_account_info(account_info_iter)?;
-        let rent = &Rent::from_account_info(rent_sysvar_info)?;
-
-        if !creator_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
-
-        let (settings_nonce, _) = settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        bridge_utils::helper::validate_settings_account(
-            program_id,
-            settings_nonce,
-            settings_account_info,
-        )?;
-
-        if settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
-
-        // Validate Token Settings Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
-
-        let (_, token, ever_decimals) = token_settings_account_data
-            .kind
-            .into_ever()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-        let (token_settings_nonce, mint_nonce) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        validate_token_settings_ever_account(
-            program_id,
-            &token,
-            token_settings_nonce,
-            token_settings_account_info,
-        )?;
-
-        if token_settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
-
-        // Validate Mint Account
-        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
-
-        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
-        let solana_decimals = mint_account_data.decimals;
-
-        // Validate Multi Vault Account
-        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
-        let multi_vault_nonce = multi_vault_account_data
-            .account_kind
-            .into_multi_vault()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
-
-        // Burn EVER tokens
-        invoke(
-            &spl_token::instruction::burn(
-                &spl_token::id(),
-                creator_token_account_info.key,
-                mint_account_info.key,
-                creator_account_info.key,
-                &[creator_account_info.key],
-                amount,
-            )?,
-            accounts,
-        )?;
-
-        // Create Deposit Account
-        let (deposit_pubkey, deposit_nonce) =
-            Pubkey::find_program_address(&[br"deposit", &deposit_seed.to_le_bytes()], program_id);
-        let deposit_account_signer_seeds: &[&[_]] =
-            &[br"deposit", &deposit_seed.to_le_bytes(), &[deposit_nonce]];
-
-        if deposit_pubkey != *deposit_account_info.key {
-            return Err(ProgramError::InvalidArgument);
-        }
-
-        invoke_signed(
-            &system_instruction::create_account(
-                funder_account_info.key,
-                deposit_account_info.key,
-                1.max(rent.minimum_balance(DepositMultiTokenEver::LEN)),
-                DepositMultiTokenEver::LEN as u64,
-                program_id,
-            ),
-            accounts,
-            &[deposit_account_signer_seeds],
-        )?;
-
-        // Init Deposit Account
-        let fee_info = &token_settings_account_data.fee_deposit_info;
-
-        let fee = 1.max(
-            amount
-                .checked_div(fee_info.divisor)
-                .ok_or(SolanaBridgeError::Overflow)?
-                .checked_mul(fee_info.multiplier)
-                .ok_or(SolanaBridgeError::Overflow)?,
-        );
-
-        // Increase fee supply
-        token_settings_account_data.fee_supply = token_settings_account_data
-            .fee_supply
-            .checked_add(fee)
-            .ok_or(SolanaBridgeError::Overflow)?;
-
-        // Amount without fee
-        let pure_amount = amount.checked_sub(fee).ok_or(SolanaBridgeError::Overflow)?;
-
-        // Amount in Ever decimals
-        let transfer_amount = get_deposit_amount(pure_amount, ever_decimals, solana_decimals)?;
-
-        let deposit_account_data = DepositMultiTokenEver {
-            is_initialized: true,
-            account_kind: AccountKind::Deposit(deposit_nonce),
-            author: *creator_account_info.key,
-            event: DepositMultiTokenEverEventWithLen::new(
+            TokenProxyInstruction::ChangePayloadAllowlist {
+                new_payload_allowlist,
+            } => {
+                msg!("Instruction: Change Payload Allowlist");
+                Self::process_change_payload_allowlist(
+                    program_id,
+                    accounts,
+                    new_payload_allowlist,
+                )?;
+            }
+            TokenProxyInstruction::ChangeDepositCallerAllowlist {
+                new_deposit_caller_allowlist,
+            } => {
+                msg!("Instruction: Change Deposit Caller Allowlist");
+                Self::process_change_deposit_caller_allowlist(
+                    program_id,
+                    accounts,
+                    new_deposit_caller_allowlist,
+                )?;
+            }
+            TokenProxyInstruction::ChangeTokenDecimals {
                 token,
-                transfer_amount,
+                solana_decimals,
+            } => {
+                msg!("Instruction: Change Token Decimals");
+                Self::process_change_token_decimals(program_id, accounts, token, solana_decimals)?;
+            }
+            TokenProxyInstruction::ChangeDepositDailyLimit {
+                new_deposit_daily_limit,
+            } => {
+                msg!("Instruction: Change Deposit Daily Limit");
+                Self::process_change_deposit_daily_limit(
+                    program_id,
+                    accounts,
+                    new_deposit_daily_limit,
+                )?;
+            }
+            TokenProxyInstruction::ChangeWithdrawalEpochLength {
+                new_withdrawal_epoch_length,
+            } => {
+                msg!("Instruction: Change Withdrawal Epoch Length");
+                Self::process_change_withdrawal_epoch_length(
+                    program_id,
+                    accounts,
+                    new_withdrawal_epoch_length,
+                )?;
+            }
+            TokenProxyInstruction::ChangeTokenDefaults { new_token_defaults } => {
+                msg!("Instruction: Change Token Defaults");
+                Self::process_change_token_defaults(program_id, accounts, new_token_defaults)?;
+            }
+            TokenProxyInstruction::RegisterTokenEver {
+                token,
+                name,
+                symbol,
+                ever_decimals,
+                solana_decimals,
+                token_defaults,
+            } => {
+                msg!("Instruction: Register Token EVER");
+                Self::process_register_token_ever(
+                    program_id,
+                    accounts,
+                    token,
+                    name,
+                    symbol,
+                    ever_decimals,
+                    solana_decimals,
+                    token_defaults,
+                )?;
+            }
+            TokenProxyInstruction::RegisterTokenSol {
+                name,
+                symbol,
+                token_defaults,
+            } => {
+                msg!("Instruction: Register Token SOL");
+                Self::process_register_token_sol(
+                    program_id,
+                    accounts,
+                    name,
+                    symbol,
+                    token_defaults,
+                )?;
+            }
+            TokenProxyInstruction::DelistToken => {
+                msg!("Instruction: Delist Token");
+                Self::process_delist_token(program_id, accounts)?;
+            }
+            TokenProxyInstruction::CloseToken => {
+                msg!("Instruction: Close Token");
+                Self::process_close_token(program_id, accounts)?;
+            }
+            TokenProxyInstruction::ProposeMintAuthority { new_authority } => {
+                msg!("Instruction: Propose Mint Authority");
+                Self::process_propose_mint_authority(program_id, accounts, new_authority)?;
+            }
+            TokenProxyInstruction::MigrateMintAuthority => {
+                msg!("Instruction: Migrate Mint Authority");
+                Self::process_migrate_mint_authority(program_id, accounts)?;
+            }
+            TokenProxyInstruction::ProposeVaultAuthority { new_authority } => {
+                msg!("Instruction: Propose Vault Authority");
+                Self::process_propose_vault_authority(program_id, accounts, new_authority)?;
+            }
+            TokenProxyInstruction::MigrateVaultAuthority => {
+                msg!("Instruction: Migrate Vault Authority");
+                Self::process_migrate_vault_authority(program_id, accounts)?;
+            }
+            TokenProxyInstruction::MigrateSettingsV2 => {
+                msg!("Instruction: Migrate Settings V2");
+                Self::process_migrate_settings_v2(program_id, accounts)?;
+            }
+            TokenProxyInstruction::DepositMultiTokenEverV2 {
+                deposit_seed,
+                amount,
                 recipient,
                 value,
                 expected_evers,
                 payload,
-            ),
-            meta: DepositTokenMetaWithLen::new(deposit_seed),
-        };
+                reference,
+                callback_program,
+                referrer,
+                ..
+            } => {
+                msg!("Instruction: Deposit MULTI TOKEN EVER V2");
+                Self::process_deposit_multi_token_ever(
+                    program_id,
+                    accounts,
+                    deposit_seed,
+                    amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload.unwrap_or_default(),
+                    reference,
+                    callback_program,
+                    referrer,
+                    ix_version,
+                )?;
+            }
+            TokenProxyInstruction::DepositMultiTokenSolV2 {
+                deposit_seed,
+                name,
+                symbol,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                reference,
+                callback_program,
+                referrer,
+                ..
+            } => {
+                msg!("Instruction: Deposit MULTI TOKEN SOL V2");
+                Self::process_deposit_multi_token_sol(
+                    program_id,
+                    accounts,
+                    deposit_seed,
+                    name.into(),
+                    symbol.into(),
+                    amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload.unwrap_or_default(),
+                    reference,
+                    callback_program,
+                    referrer,
+                    ix_version,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenEverRequestV2 {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                token,
+                name,
+                symbol,
+                decimals,
+                recipient,
+                amount,
+                payload,
+                attached_amount,
+                event_hash,
+                chain_id,
+                ..
+            } => {
+                msg!("Instruction: Withdraw Multi token EVER request V2");
+                Self::process_withdraw_multi_token_ever_request(
+                    program_id,
+                    accounts,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    token,
+                    name.into(),
+                    symbol.into(),
+                    decimals,
+                    recipient,
+                    amount as u128,
+                    payload.unwrap_or_default(),
+                    attached_amount,
+                    event_hash,
+                    chain_id,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenSolRequestV2 {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                recipient,
+                amount,
+                payload,
+                attached_amount,
+                event_hash,
+                chain_id,
+                ..
+            } => {
+                msg!("Instruction: Withdraw multi token SOL request V2");
+                Self::process_withdraw_multi_token_sol_request(
+                    program_id,
+                    accounts,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    recipient,
+                    amount as u128,
+                    payload.unwrap_or_default(),
+                    attached_amount,
+                    event_hash,
+                    chain_id,
+                )?;
+            }
+            TokenProxyInstruction::ChangeWever {
+                new_wever_mint,
+                new_wever_defaults,
+            } => {
+                msg!("Instruction: Change WEVER");
+                Self::process_change_wever(
+                    program_id,
+                    accounts,
+                    new_wever_mint,
+                    new_wever_defaults,
+                )?;
+            }
+            TokenProxyInstruction::ChangeEnabledChains { enabled_chains } => {
+                msg!("Instruction: Change enabled chains");
+                Self::process_change_enabled_chains(program_id, accounts, enabled_chains)?;
+            }
+            TokenProxyInstruction::PauseToken {
+                deposits,
+                withdrawals,
+            } => {
+                msg!("Instruction: Pause token");
+                Self::process_pause_token(program_id, accounts, deposits, withdrawals)?;
+            }
+            TokenProxyInstruction::UnpauseToken {
+                deposits,
+                withdrawals,
+            } => {
+                msg!("Instruction: Unpause token");
+                Self::process_unpause_token(program_id, accounts, deposits, withdrawals)?;
+            }
+            TokenProxyInstruction::ChangeGuardians { guardians } => {
+                msg!("Instruction: Change guardians");
+                Self::process_change_guardians(program_id, accounts, guardians)?;
+            }
+            TokenProxyInstruction::PauseBridge {
+                deposits,
+                withdrawals,
+            } => {
+                msg!("Instruction: Pause bridge");
+                Self::process_pause_bridge(program_id, accounts, deposits, withdrawals)?;
+            }
+            TokenProxyInstruction::UnpauseBridge {
+                deposits,
+                withdrawals,
+            } => {
+                msg!("Instruction: Unpause bridge");
+                Self::process_unpause_bridge(program_id, accounts, deposits, withdrawals)?;
+            }
+            TokenProxyInstruction::CreateFeeLedger => {
+                msg!("Instruction: Create fee ledger");
+                Self::process_create_fee_ledger(program_id, accounts)?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenEverV2 {
+                create_if_needed, ..
+            } => {
+                msg!("Instruction: Withdraw Multi Token EVER V2");
+                Self::process_withdraw_multi_token_ever(
+                    program_id,
+                    accounts,
+                    create_if_needed,
+                    ix_version,
+                )?;
+            }
+            TokenProxyInstruction::ChangeDepositValueFloor {
+                new_deposit_value_floor,
+            } => {
+                msg!("Instruction: Change deposit value floor");
+                Self::process_change_deposit_value_floor(
+                    program_id,
+                    accounts,
+                    new_deposit_value_floor,
+                )?;
+            }
+            TokenProxyInstruction::ChangeMinSupportedIxVersion {
+                min_supported_ix_version,
+            } => {
+                msg!("Instruction: Change min supported instruction version");
+                Self::process_change_min_supported_ix_version(
+                    program_id,
+                    accounts,
+                    min_supported_ix_version,
+                )?;
+            }
+            TokenProxyInstruction::CancelWithdrawalRequest => {
+                msg!("Instruction: Cancel withdrawal request");
+                Self::process_cancel_withdrawal_request(program_id, accounts)?;
+            }
+            TokenProxyInstruction::ChangeVoteReward { vote_reward } => {
+                msg!("Instruction: Change vote reward");
+                Self::process_change_vote_reward(program_id, accounts, vote_reward)?;
+            }
+            TokenProxyInstruction::ClaimVoteRewards => {
+                msg!("Instruction: Claim vote rewards");
+                Self::process_claim_vote_rewards(program_id, accounts)?;
+            }
+            TokenProxyInstruction::ChangeWithdrawalLimitExemptions {
+                new_withdrawal_limit_exemptions,
+            } => {
+                msg!("Instruction: Change withdrawal limit exemptions");
+                Self::process_change_withdrawal_limit_exemptions(
+                    program_id,
+                    accounts,
+                    new_withdrawal_limit_exemptions,
+                )?;
+            }
+            TokenProxyInstruction::SetRecipientHook {
+                program_id: hook_program_id,
+                data,
+                accounts: hook_accounts,
+            } => {
+                msg!("Instruction: Set recipient hook");
+                Self::process_set_recipient_hook(
+                    program_id,
+                    accounts,
+                    hook_program_id,
+                    data,
+                    hook_accounts,
+                )?;
+            }
+            TokenProxyInstruction::RemoveRecipientHook => {
+                msg!("Instruction: Remove recipient hook");
+                Self::process_remove_recipient_hook(program_id, accounts)?;
+            }
+            TokenProxyInstruction::CreateTokenMetadata { uri } => {
+                msg!("Instruction: Create token metadata");
+                Self::process_create_token_metadata(program_id, accounts, uri)?;
+            }
+            TokenProxyInstruction::ProposeRole {
+                role,
+                new_authority,
+            } => {
+                msg!("Instruction: Propose role");
+                Self::process_propose_role(program_id, accounts, role, new_authority)?;
+            }
+            TokenProxyInstruction::AcceptRole => {
+                msg!("Instruction: Accept role");
+                Self::process_accept_role(program_id, accounts)?;
+            }
+            TokenProxyInstruction::ScheduleChange {
+                change_seed,
+                change,
+            } => {
+                msg!("Instruction: Schedule change");
+                Self::process_schedule_change(program_id, accounts, change_seed, change)?;
+            }
+            TokenProxyInstruction::ExecuteChange => {
+                msg!("Instruction: Execute change");
+                Self::process_execute_change(program_id, accounts)?;
+            }
+            TokenProxyInstruction::CancelChange => {
+                msg!("Instruction: Cancel change");
+                Self::process_cancel_change(program_id, accounts)?;
+            }
+            TokenProxyInstruction::MigrateAccount => {
+                msg!("Instruction: Migrate account");
+                Self::process_migrate_account(program_id, accounts)?;
+            }
+            TokenProxyInstruction::UpdateFeeTiers {
+                fee_type,
+                fee_tiers,
+            } => {
+                msg!("Instruction: Update Fee Tiers");
+                Self::process_update_fee_tiers(program_id, accounts, fee_type, fee_tiers)?;
+            }
+            TokenProxyInstruction::ExpireWithdrawal => {
+                msg!("Instruction: Expire withdrawal");
+                Self::process_expire_withdrawal(program_id, accounts)?;
+            }
+            TokenProxyInstruction::VoteForWithdrawRequestBySignatures => {
+                msg!("Instruction: Vote for Withdraw EVER/SOL request by signatures");
+                Self::process_vote_for_withdraw_request_by_signatures(program_id, accounts)?;
+            }
+            TokenProxyInstruction::CancelDeposit => {
+                msg!("Instruction: Cancel deposit");
+                Self::process_cancel_deposit(program_id, accounts)?;
+            }
+            TokenProxyInstruction::AddToDenylist { address } => {
+                msg!("Instruction: Add to denylist");
+                Self::process_add_to_denylist(program_id, accounts, address)?;
+            }
+            TokenProxyInstruction::RemoveFromDenylist { address } => {
+                msg!("Instruction: Remove from denylist");
+                Self::process_remove_from_denylist(program_id, accounts, address)?;
+            }
+            TokenProxyInstruction::SweepClosedAccounts => {
+                msg!("Instruction: Sweep closed accounts");
+                Self::process_sweep_closed_accounts(program_id, accounts)?;
+            }
+            TokenProxyInstruction::SetTreasury { treasury } => {
+                msg!("Instruction: Set treasury");
+                Self::process_set_treasury(program_id, accounts, treasury)?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenEverToManyRequest {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                token,
+                name,
+                symbol,
+                decimals,
+                recipients,
+                event_hash,
+                chain_id,
+            } => {
+                msg!("Instruction: Withdraw Multi token EVER to many request");
+                Self::process_withdraw_multi_token_ever_to_many_request(
+                    program_id,
+                    accounts,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    token,
+                    name,
+                    symbol,
+                    decimals,
+                    recipients,
+                    event_hash,
+                    chain_id,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenEverToMany => {
+                msg!("Instruction: Withdraw Multi Token EVER to many");
+                Self::process_withdraw_multi_token_ever_to_many(program_id, accounts)?;
+            }
+            TokenProxyInstruction::ApproveWithdrawEverToMany => {
+                msg!("Instruction: Approve Withdraw Multi Token EVER to many");
+                Self::process_approve_withdraw_ever_to_many(program_id, accounts)?;
+            }
+            TokenProxyInstruction::ChangeMinDeposit {
+                new_min_deposit_amount,
+            } => {
+                msg!("Instruction: Update minimum deposit amount");
+                Self::process_change_min_deposit(program_id, accounts, new_min_deposit_amount)?;
+            }
+            TokenProxyInstruction::CreateBridgeStats => {
+                msg!("Instruction: Create bridge stats");
+                Self::process_create_bridge_stats(program_id, accounts)?;
+            }
+            TokenProxyInstruction::DepositBountyForWithdrawSol { amount } => {
+                msg!("Instruction: Deposit Bounty For Withdraw Sol");
+                Self::process_deposit_bounty_for_withdraw_sol(program_id, accounts, amount)?;
+            }
+            TokenProxyInstruction::RefundBountyForWithdrawSol => {
+                msg!("Instruction: Refund Bounty For Withdraw Sol");
+                Self::process_refund_bounty_for_withdraw_sol(program_id, accounts)?;
+            }
+            TokenProxyInstruction::RegisterLiquidityProvider { provider } => {
+                msg!("Instruction: Register liquidity provider");
+                Self::process_register_liquidity_provider(program_id, accounts, provider)?;
+            }
+            TokenProxyInstruction::UnregisterLiquidityProvider { provider } => {
+                msg!("Instruction: Unregister liquidity provider");
+                Self::process_unregister_liquidity_provider(program_id, accounts, provider)?;
+            }
+            TokenProxyInstruction::ChangeFillsRestricted { fills_restricted } => {
+                msg!("Instruction: Change fills restricted");
+                Self::process_change_fills_restricted(program_id, accounts, fills_restricted)?;
+            }
+            TokenProxyInstruction::ChangeReferralFee { referral_fee_bps } => {
+                msg!("Instruction: Change referral fee");
+                Self::process_change_referral_fee(program_id, accounts, referral_fee_bps)?;
+            }
+            TokenProxyInstruction::ClaimReferralFee => {
+                msg!("Instruction: Claim referral fee");
+                Self::process_claim_referral_fee(program_id, accounts)?;
+            }
+            TokenProxyInstruction::CreateApprovalQueue => {
+                msg!("Instruction: Create approval queue");
+                Self::process_create_approval_queue(program_id, accounts)?;
+            }
+            TokenProxyInstruction::RejectWithdrawEver { note } => {
+                msg!("Instruction: Reject Withdraw Multi Token EVER");
+                Self::process_reject_withdraw_ever(program_id, accounts, note)?;
+            }
+            TokenProxyInstruction::RejectWithdrawSol { note } => {
+                msg!("Instruction: Reject Withdraw Multi Token SOL");
+                Self::process_reject_withdraw_sol(program_id, accounts, note)?;
+            }
+            TokenProxyInstruction::PartialFillWithdrawSol {
+                deposit_seed,
+                recipient,
+                amount,
+                value,
+                expected_evers,
+                payload,
+                fill_amounts,
+            } => {
+                msg!("Instruction: Partial Fill Withdraw SOL");
+                Self::process_fill_withdraw_sol(
+                    program_id,
+                    accounts,
+                    deposit_seed,
+                    recipient,
+                    amount,
+                    value,
+                    expected_evers,
+                    payload,
+                    Some(fill_amounts),
+                )?;
+            }
+        };
+
+        Ok(())
+    }
+
+    fn process_initialize(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        guardian: Pubkey,
+        manager: Pubkey,
+        withdrawal_manager: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let initializer_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let multi_vault_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+        let denylist_account_info = next_account_info(account_info_iter)?;
+
+        if !initializer_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Initializer Account
+        let (programdata_pubkey, programdata_nonce) =
+            Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+
+        if programdata_pubkey != *programdata_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        bridge_utils::helper::validate_initializer_account(
+            initializer_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Create Settings Account
+        let (settings_pubkey, settings_nonce) =
+            Pubkey::find_program_address(&[br"settings"], program_id);
+        let settings_account_signer_seeds: &[&[_]] = &[br"settings", &[settings_nonce]];
+
+        if settings_pubkey != *settings_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                settings_account_info.key,
+                1.max(rent.minimum_balance(Settings::LEN)),
+                Settings::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[settings_account_signer_seeds],
+        )?;
+
+        // Init Settings Account
+        let settings_account_data = Settings {
+            is_initialized: true,
+            account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+            emergency: false,
+            guardian,
+            manager,
+            withdrawal_manager,
+            payload_allowlist: vec![spl_token::id()],
+            deposit_caller_allowlist: None,
+            emergency_expiry: None,
+            emergency_info: None,
+            token_defaults: None,
+            deposits_paused: false,
+            withdrawals_paused: false,
+            guardians: vec![],
+            treasury: None,
+            wever_mint: None,
+            wever_defaults: None,
+            enabled_chains: vec![],
+            deposit_value_floor: None,
+            min_supported_ix_version: 0,
+            pending_role: None,
+            timelock_delay: 0,
+            version: CURRENT_ACCOUNT_VERSION,
+        };
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        // Create Multi Vault Account
+        let (multi_vault_pubkey, multi_vault_nonce) =
+            Pubkey::find_program_address(&[br"multivault"], program_id);
+        let multi_vault_account_signer_seeds: &[&[_]] = &[br"multivault", &[multi_vault_nonce]];
+
+        if multi_vault_pubkey != *multi_vault_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                multi_vault_account_info.key,
+                1.max(rent.minimum_balance(MultiVault::LEN)),
+                MultiVault::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[multi_vault_account_signer_seeds],
+        )?;
+
+        // Init Multi Vault Account
+        let multi_vault_account_data = MultiVault {
+            is_initialized: true,
+            account_kind: AccountKind::MultiVault(multi_vault_nonce),
+            version: CURRENT_ACCOUNT_VERSION,
+        };
+
+        MultiVault::pack(
+            multi_vault_account_data,
+            &mut multi_vault_account_info.data.borrow_mut(),
+        )?;
+
+        // Create Denylist Account
+        create_denylist_account(
+            program_id,
+            funder_account_info,
+            denylist_account_info,
+            system_program_info,
+            rent,
+        )?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_deposit_multi_token_ever(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        deposit_seed: u128,
+        amount: u64,
+        recipient: EverAddress,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+        reference: Option<[u8; 32]>,
+        callback_program: Option<Pubkey>,
+        referrer: Option<Pubkey>,
+        ix_version: u8,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let creator_account_info = next_account_info(account_info_iter)?;
+        let creator_token_account_info = next_account_info(account_info_iter)?;
+        let deposit_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let multi_vault_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        // Legacy instructions don't pass instructions sysvar, so the account
+        // is consumed only if it is there
+        let instructions_sysvar_info = match account_info_iter.as_slice().first() {
+            Some(account_info) if sysvar::instructions::check_id(account_info.key) => {
+                account_info_iter.next()
+            }
+            _ => None,
+        };
+
+        if !creator_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if ix_version < settings_account_data.min_supported_ix_version
+            || ix_version > CURRENT_IX_VERSION
+        {
+            return Err(SolanaBridgeError::InstructionVersionNotSupported.into());
+        }
+
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Deposit Value
+        if let Some(deposit_value_floor) = &settings_account_data.deposit_value_floor {
+            validate_deposit_value(deposit_value_floor, value, &expected_evers)?;
+        }
+
+        // Validate Caller Program
+        if let Some(deposit_caller_allowlist) = &settings_account_data.deposit_caller_allowlist {
+            validate_deposit_caller(deposit_caller_allowlist, instructions_sysvar_info)?;
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (_, token, ever_decimals) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.deposits_paused {
+            return Err(SolanaBridgeError::DepositsPaused.into());
+        }
+
+        if token_settings_account_data.deposits_paused {
+            return Err(SolanaBridgeError::TokenDepositsPaused.into());
+        }
+
+        if token_settings_account_data.delisted {
+            return Err(SolanaBridgeError::TokenDelisted.into());
+        }
+
+        if amount < token_settings_account_data.min_deposit_amount {
+            return Err(SolanaBridgeError::DepositBelowMinimum.into());
+        }
+
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+        let solana_decimals = mint_account_data.decimals;
+
+        // Validate Multi Vault Account
+        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+        let multi_vault_nonce = multi_vault_account_data
+            .account_kind
+            .into_multi_vault()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+        // Check deposit daily limit
+        update_deposit_daily_amount(&mut token_settings_account_data, amount)?;
+
+        update_bridge_stats(program_id, accounts, |stats| stats.record_deposit(amount))?;
+
+        // Burn EVER tokens
+        invoke(
+            &spl_token::instruction::burn(
+                &spl_token::id(),
+                creator_token_account_info.key,
+                mint_account_info.key,
+                creator_account_info.key,
+                &[creator_account_info.key],
+                amount,
+            )?,
+            accounts,
+        )?;
+
+        // Create Deposit Account
+        let (deposit_pubkey, deposit_nonce) =
+            Pubkey::find_program_address(&[br"deposit", &deposit_seed.to_le_bytes()], program_id);
+        let deposit_account_signer_seeds: &[&[_]] =
+            &[br"deposit", &deposit_seed.to_le_bytes(), &[deposit_nonce]];
+
+        if deposit_pubkey != *deposit_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                deposit_account_info.key,
+                1.max(rent.minimum_balance(DepositMultiTokenEver::LEN)),
+                DepositMultiTokenEver::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[deposit_account_signer_seeds],
+        )?;
+
+        // Init Deposit Account
+        let fee = token_settings_account_data
+            .deposit_fee(amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Referrer's share is accrued apart from fee supply
+        let referral_fee = match referrer {
+            Some(referrer) => accrue_referral_fee(
+                program_id,
+                accounts,
+                funder_account_info,
+                creator_account_info,
+                system_program_info,
+                token_settings_account_info,
+                &token_settings_account_data,
+                &referrer,
+                fee,
+            )?,
+            None => 0,
+        };
+
+        // Increase fee supply
+        increase_fee_supply(
+            program_id,
+            accounts,
+            token_settings_account_info,
+            &mut token_settings_account_data,
+            fee - referral_fee,
+        )?;
+
+        // Amount without fee
+        let pure_amount = amount.checked_sub(fee).ok_or(SolanaBridgeError::Overflow)?;
+
+        // Amount in Ever decimals
+        let transfer_amount = to_ever_amount(pure_amount, ever_decimals, solana_decimals)?;
+
+        // Burnt WEVER is paid out as EVER on the other side
+        let expected_evers = if settings_account_data.wever_mint == Some(*mint_account_info.key) {
+            add_expected_evers(expected_evers, transfer_amount)?
+        } else {
+            expected_evers
+        };
+
+        let deposit_account_data = DepositMultiTokenEver {
+            is_initialized: true,
+            account_kind: AccountKind::Deposit(deposit_nonce),
+            author: *creator_account_info.key,
+            event: DepositMultiTokenEverEventWithLen::new(
+                token,
+                transfer_amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                fee as u128,
+                amount as u128,
+                reference,
+            ),
+            meta: DepositTokenMetaWithLen::new(
+                deposit_seed,
+                *funder_account_info.key,
+                Clock::get()?.slot,
+            ),
+            version: CURRENT_ACCOUNT_VERSION,
+        };
+
+        let event_hash = hash(&deposit_account_data.event.data.try_to_vec()?).to_bytes();
+
+        solana_program::log::sol_log_data(&[&*DepositMultiTokenEvent {
+            account: deposit_pubkey,
+            recipient,
+            transfer_amount,
+            seed: deposit_seed,
+            value,
+            expected_evers,
+            event_data: event_hash.to_vec(),
+        }
+        .try_to_vec()?]);
+
+        DepositMultiTokenEver::pack(
+            deposit_account_data,
+            &mut deposit_account_info.data.borrow_mut(),
+        )?;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        // Send SOL amount to multi vault
+        invoke(
+            &system_instruction::transfer(
+                funder_account_info.key,
+                multi_vault_account_info.key,
+                value,
+            ),
+            accounts,
+        )?;
+
+        // Notify integrator program
+        if let Some(callback_program) = callback_program {
+            invoke_deposit_callback(
+                program_id,
+                accounts,
+                account_info_iter.as_slice(),
+                &callback_program,
+                deposit_account_info.key,
+                deposit_seed,
+                event_hash,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_deposit_multi_token_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        deposit_seed: u128,
+        name: String,
+        symbol: String,
+        amount: u64,
+        recipient: EverAddress,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+        reference: Option<[u8; 32]>,
+        callback_program: Option<Pubkey>,
+        referrer: Option<Pubkey>,
+        ix_version: u8,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let creator_account_info = next_account_info(account_info_iter)?;
+        let creator_token_account_info = next_account_info(account_info_iter)?;
+        let vault_account_info = next_account_info(account_info_iter)?;
+        let deposit_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let multi_vault_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        // Legacy instructions don't pass instructions sysvar, so the account
+        // is consumed only if it is there
+        let instructions_sysvar_info = match account_info_iter.as_slice().first() {
+            Some(account_info) if sysvar::instructions::check_id(account_info.key) => {
+                account_info_iter.next()
+            }
+            _ => None,
+        };
+
+        if !creator_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if ix_version < settings_account_data.min_supported_ix_version
+            || ix_version > CURRENT_IX_VERSION
+        {
+            return Err(SolanaBridgeError::InstructionVersionNotSupported.into());
+        }
+
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Deposit Value
+        if let Some(deposit_value_floor) = &settings_account_data.deposit_value_floor {
+            validate_deposit_value(deposit_value_floor, value, &expected_evers)?;
+        }
+
+        // Validate Caller Program
+        if let Some(deposit_caller_allowlist) = &settings_account_data.deposit_caller_allowlist {
+            validate_deposit_caller(deposit_caller_allowlist, instructions_sysvar_info)?;
+        }
+
+        // Check asset name length
+        if name.len() > MAX_NAME_LEN {
+            return Err(SolanaBridgeError::TokenNameLenLimit.into());
+        }
+
+        // Check asset symbol length
+        if symbol.len() > MAX_SYMBOL_LEN {
+            return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+        }
+
+        // If token settings account is not created
+        if token_settings_account_info.lamports() == 0 {
+            // Create Vault Account
+            let (vault_pubkey, vault_nonce) = Pubkey::find_program_address(
+                &[br"vault", &mint_account_info.key.to_bytes()],
+                program_id,
+            );
+            let vault_account_signer_seeds: &[&[_]] =
+                &[br"vault", &mint_account_info.key.to_bytes(), &[vault_nonce]];
+
+            if vault_pubkey != *vault_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    vault_account_info.key,
+                    1.max(rent.minimum_balance(spl_token::state::Account::LEN)),
+                    spl_token::state::Account::LEN as u64,
+                    &spl_token::id(),
+                ),
+                accounts,
+                &[vault_account_signer_seeds],
+            )?;
+
+            // Init Vault Account
+            invoke_signed(
+                &spl_token::instruction::initialize_account3(
+                    &spl_token::id(),
+                    vault_account_info.key,
+                    mint_account_info.key,
+                    vault_account_info.key,
+                )?,
+                accounts,
+                &[vault_account_signer_seeds],
+            )?;
+
+            // Create Token Settings Account
+            let (token_settings_pubkey, token_settings_nonce) = Pubkey::find_program_address(
+                &[br"settings", &mint_account_info.key.to_bytes()],
+                program_id,
+            );
+            let token_settings_account_signer_seeds: &[&[_]] = &[
+                br"settings",
+                &mint_account_info.key.to_bytes(),
+                &[token_settings_nonce],
+            ];
+
+            if token_settings_pubkey != *token_settings_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    token_settings_account_info.key,
+                    1.max(rent.minimum_balance(TokenSettings::LEN)),
+                    TokenSettings::LEN as u64,
+                    program_id,
+                ),
+                accounts,
+                &[token_settings_account_signer_seeds],
+            )?;
+
+            // Init Settings Account
+            let token_defaults = settings_account_data.token_defaults.unwrap_or_default();
+
+            let token_settings_account_data = TokenSettings {
+                is_initialized: true,
+                account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+                kind: TokenKind::Solana {
+                    mint: *mint_account_info.key,
+                    vault: *vault_account_info.key,
+                },
+                name: name.clone(),
+                symbol: symbol.clone(),
+                withdrawal_epoch: 0,
+                deposit_limit: token_defaults.deposit_limit,
+                withdrawal_limit: token_defaults.withdrawal_limit,
+                withdrawal_daily_limit: token_defaults.withdrawal_daily_limit,
+                withdrawal_daily_amount: 0,
+                emergency: false,
+                fee_supply: Default::default(),
+                fee_deposit_info: token_defaults.fee_deposit_info,
+                fee_withdrawal_info: token_defaults.fee_withdrawal_info,
+                deposit_daily_limit: token_defaults.deposit_daily_limit,
+                deposit_daily_amount: 0,
+                deposit_epoch: 0,
+                withdrawal_epoch_length: None,
+                emergency_expiry: None,
+                emergency_info: None,
+                delisted: false,
+                authority_migration: None,
+                deposits_paused: false,
+                withdrawals_paused: false,
+                vote_reward: 0,
+                withdrawal_limit_exemptions: vec![],
+                version: CURRENT_ACCOUNT_VERSION,
+                fee_deposit_tiers: Default::default(),
+                fee_withdrawal_tiers: Default::default(),
+                min_deposit_amount: 0,
+                fills_restricted: false,
+                referral_fee_bps: 0,
+                pending_withdrawals: 0,
+                closed: false,
+            };
+
+            solana_program::log::sol_log_data(&[&TokenSettingsEvent {
+                account: token_settings_pubkey,
+                symbol: token_settings_account_data.symbol.clone(),
+                name: token_settings_account_data.name.clone(),
+                mint: mint_account_info.key.clone(),
+                vault: Some(vault_account_info.key.clone()),
+                ever_decimals: None,
+                solana_decimals: None,
+                root: None,
+                fee: token_defaults.fee_deposit_info,
+            }
+            .try_to_vec()?]);
+
+            TokenSettings::pack(
+                token_settings_account_data,
+                &mut token_settings_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (mint, vault) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        let (token_settings_nonce, _vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.deposits_paused {
+            return Err(SolanaBridgeError::DepositsPaused.into());
+        }
+
+        if token_settings_account_data.deposits_paused {
+            return Err(SolanaBridgeError::TokenDepositsPaused.into());
+        }
+
+        if token_settings_account_data.delisted {
+            return Err(SolanaBridgeError::TokenDelisted.into());
+        }
+
+        if amount < token_settings_account_data.min_deposit_amount {
+            return Err(SolanaBridgeError::DepositBelowMinimum.into());
+        }
+
+        // Validate Mint Account
+        if *mint_account_info.key != mint && mint_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+        let decimals = mint_account_data.decimals;
+
+        // Validate Vault Account
+        if *vault_account_info.key != vault && vault_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Multi Vault Account
+        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+        let multi_vault_nonce = multi_vault_account_data
+            .account_kind
+            .into_multi_vault()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+        // Make transfer
+        let vault_account_data =
+            spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+
+        if vault_account_data
+            .amount
+            .checked_add(amount)
+            .ok_or(SolanaBridgeError::Overflow)?
+            > token_settings_account_data.deposit_limit
+        {
+            return Err(SolanaBridgeError::DepositLimit.into());
+        }
+
+        // Check deposit daily limit
+        update_deposit_daily_amount(&mut token_settings_account_data, amount)?;
+
+        update_bridge_stats(program_id, accounts, |stats| stats.record_deposit(amount))?;
+
+        // Transfer SOL tokens to Vault Account
+        invoke(
+            &spl_token::instruction::transfer(
+                &spl_token::id(),
+                creator_token_account_info.key,
+                vault_account_info.key,
+                creator_account_info.key,
+                &[creator_account_info.key],
+                amount,
+            )?,
+            accounts,
+        )?;
+
+        // Send sol amount to multi vault
+        invoke(
+            &system_instruction::transfer(
+                funder_account_info.key,
+                multi_vault_account_info.key,
+                value,
+            ),
+            accounts,
+        )?;
+
+        // Create Deposit Account
+        let (deposit_pubkey, deposit_nonce) =
+            Pubkey::find_program_address(&[br"deposit", &deposit_seed.to_le_bytes()], program_id);
+        let deposit_account_signer_seeds: &[&[_]] =
+            &[br"deposit", &deposit_seed.to_le_bytes(), &[deposit_nonce]];
+
+        if deposit_pubkey != *deposit_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                deposit_account_info.key,
+                1.max(rent.minimum_balance(DepositMultiTokenSol::LEN)),
+                DepositMultiTokenSol::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[deposit_account_signer_seeds],
+        )?;
+
+        // Init Deposit Account
+        let fee = token_settings_account_data
+            .deposit_fee(amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Referrer's share is accrued apart from fee supply
+        let referral_fee = match referrer {
+            Some(referrer) => accrue_referral_fee(
+                program_id,
+                accounts,
+                funder_account_info,
+                creator_account_info,
+                system_program_info,
+                token_settings_account_info,
+                &token_settings_account_data,
+                &referrer,
+                fee,
+            )?,
+            None => 0,
+        };
+
+        // Increase fee supply
+        increase_fee_supply(
+            program_id,
+            accounts,
+            token_settings_account_info,
+            &mut token_settings_account_data,
+            fee - referral_fee,
+        )?;
+
+        // Amount without fee
+        let transfer_amount = amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?
+            .try_into()
+            .map_err(|_| SolanaBridgeError::Overflow)?;
+
+        let name = token_settings_account_data.name.clone();
+        let symbol = token_settings_account_data.symbol.clone();
+
+        let deposit_account_data = DepositMultiTokenSol {
+            is_initialized: true,
+            account_kind: AccountKind::Deposit(deposit_nonce),
+            author: *creator_account_info.key,
+            event: DepositMultiTokenSolEventWithLen::new(
+                *mint_account_info.key,
+                name,
+                symbol,
+                decimals,
+                transfer_amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                fee as u128,
+                amount as u128,
+                reference,
+            ),
+            meta: DepositTokenMetaWithLen::new(
+                deposit_seed,
+                *funder_account_info.key,
+                Clock::get()?.slot,
+            ),
+            version: CURRENT_ACCOUNT_VERSION,
+        };
+
+        let event_hash = hash(&deposit_account_data.event.data.try_to_vec()?).to_bytes();
+
+        solana_program::log::sol_log_data(&[&DepositMultiTokenEvent {
+            account: deposit_pubkey,
+            recipient,
+            transfer_amount,
+            seed: deposit_seed,
+            value,
+            expected_evers,
+            event_data: event_hash.to_vec(),
+        }
+        .try_to_vec()?]);
+
+        DepositMultiTokenSol::pack(
+            deposit_account_data,
+            &mut deposit_account_info.data.borrow_mut(),
+        )?;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        // Notify integrator program
+        if let Some(callback_program) = callback_program {
+            invoke_deposit_callback(
+                program_id,
+                accounts,
+                account_info_iter.as_slice(),
+                &callback_program,
+                deposit_account_info.key,
+                deposit_seed,
+                event_hash,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_withdraw_multi_token_ever_request(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        event_timestamp: u32,
+        event_transaction_lt: u64,
+        event_configuration: Pubkey,
+        token: EverAddress,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        recipient: Pubkey,
+        amount: u128,
+        payload: Vec<u8>,
+        attached_amount: u64,
+        event_hash: Option<Hash>,
+        chain_id: Option<u64>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let author_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let rl_settings_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _proposal_index_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check asset name length
+        if name.len() > MAX_NAME_LEN {
+            return Err(SolanaBridgeError::TokenNameLenLimit.into());
+        }
+
+        // Check asset symbol length
+        if symbol.len() > MAX_SYMBOL_LEN {
+            return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+        }
+
+        // Validate Round Loader Settings Account
+        let rl_settings_account_data =
+            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+        let (rl_settings_nonce, _) = rl_settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            &round_loader::id(),
+            rl_settings_nonce,
+            rl_settings_account_info,
+        )?;
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let round_number = relay_round_account_data.round_number;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
+            return Err(SolanaBridgeError::RelayRoundExpired.into());
+        }
+
+        let required_votes = round_loader::get_round_quorum(
+            relay_round_account_data.total_weight() as usize,
+            &relay_round_account_data,
+            &rl_settings_account_data,
+        );
+
+        let epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
+
+        // Create Proxy Account
+        let proxy_nonce = match payload.is_empty() {
+            true => None,
+            false => {
+                let proxy_account_info = next_account_info(account_info_iter)?;
+                let mint_account_info = next_account_info(account_info_iter)?;
+                let spl_token_program_info = next_account_info(account_info_iter)?;
+
+                let mint = get_associated_mint(program_id, &token);
+                if mint != *mint_account_info.key {
+                    return Err(ProgramError::InvalidArgument);
+                }
+
+                let nonce = create_proxy_account(
+                    program_id,
+                    &recipient,
+                    funder_account_info,
+                    proxy_account_info,
+                    mint_account_info,
+                    system_program_info,
+                    spl_token_program_info,
+                )?;
+
+                // Attach SOL to proxy account
+                invoke(
+                    &system_instruction::transfer(
+                        funder_account_info.key,
+                        proxy_account_info.key,
+                        attached_amount,
+                    ),
+                    accounts,
+                )?;
+
+                Some(nonce)
+            }
+        };
+
+        // Create Withdraw Account
+        let event = WithdrawalMultiTokenEverEventWithLen::new(
+            token, name, symbol, decimals, amount, recipient, payload,
+        );
+
+        let event_data = event.data.hash()?;
+
+        if let Some(event_hash) = event_hash {
+            if event_hash != event_data {
+                return Err(SolanaBridgeError::InvalidEventHash.into());
+            }
+        }
+
+        let mut withdrawal_seeds = bridge_utils::helper::get_proposal_seeds(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            chain_id,
+            event_data.as_ref(),
+        );
+
+        let (withdrawal_pubkey, withdrawal_nonce) = Pubkey::find_program_address(
+            &withdrawal_seeds
+                .iter()
+                .map(Vec::as_slice)
+                .collect::<Vec<_>>(),
+            program_id,
+        );
+
+        withdrawal_seeds.push(vec![withdrawal_nonce]);
+        let withdrawal_account_signer_seeds = withdrawal_seeds
+            .iter()
+            .map(Vec::as_slice)
+            .collect::<Vec<_>>();
+
+        if withdrawal_pubkey != *withdrawal_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if withdrawal_account_info.lamports() == 0 {
+            // Proposals diverging from one requested for the same event are rejected
+            record_proposal_index(
+                program_id,
+                accounts,
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                chain_id,
+                &event_data,
+                funder_account_info,
+                system_program_info,
+                rent,
+            )?;
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    withdrawal_account_info.key,
+                    1.max(rent.minimum_balance(WithdrawalMultiTokenEver::LEN)),
+                    WithdrawalMultiTokenEver::LEN as u64,
+                    program_id,
+                ),
+                accounts,
+                &[&withdrawal_account_signer_seeds],
+            )?;
+
+            let withdrawal_account_data = WithdrawalMultiTokenEver {
+                is_initialized: true,
+                account_kind: AccountKind::Proposal(withdrawal_nonce, proxy_nonce),
+                author: *author_account_info.key,
+                round_number,
+                required_votes,
+                event,
+                pda: PDA {
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                },
+                meta: WithdrawalTokenMetaWithLen::new(
+                    0,
+                    epoch,
+                    *funder_account_info.key,
+                    clock.unix_timestamp + WITHDRAWAL_REQUEST_TTL,
+                ),
+                signers: vec![Vote::None; relay_round_account_data.relays.len()],
+                chain_id,
+                vote_rewards: vec![VoteReward::None; relay_round_account_data.relays.len()],
+                version: CURRENT_ACCOUNT_VERSION,
+                confirmed_weight: 0,
+            };
+
+            WithdrawalMultiTokenEver::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+
+            let event_data = event_data.to_bytes().to_vec();
+
+            solana_program::log::sol_log_data(&[&WithdrawMultiTokenRequestEvent {
+                account: withdrawal_pubkey,
+                token: token.to_string(),
+                recipient,
+                amount,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                event_data,
+                bounty: 0,
+            }
+            .try_to_vec()?]);
+
+            update_bridge_stats(program_id, accounts, BridgeStats::record_proposal)?;
+
+            // Send voting reparation for Relay to withdrawal account
+            let relays_lamports = RELAY_REPARATION * relay_round_account_data.relays.len() as u64;
+
+            invoke(
+                &system_instruction::transfer(
+                    funder_account_info.key,
+                    withdrawal_account_info.key,
+                    relays_lamports,
+                ),
+                accounts,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_withdraw_multi_token_sol_request(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        event_timestamp: u32,
+        event_transaction_lt: u64,
+        event_configuration: Pubkey,
+        recipient: Pubkey,
+        amount: u128,
+        payload: Vec<u8>,
+        attached_amount: u64,
+        event_hash: Option<Hash>,
+        chain_id: Option<u64>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let author_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let rl_settings_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _proposal_index_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Token Setting Account
+        let token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        // Validate Round Loader Settings Account
+        let rl_settings_account_data =
+            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+        let (rl_settings_nonce, _) = rl_settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            &round_loader::id(),
+            rl_settings_nonce,
+            rl_settings_account_info,
+        )?;
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let round_number = relay_round_account_data.round_number;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
+            return Err(SolanaBridgeError::RelayRoundExpired.into());
+        }
+
+        let required_votes = round_loader::get_round_quorum(
+            relay_round_account_data.total_weight() as usize,
+            &relay_round_account_data,
+            &rl_settings_account_data,
+        );
+
+        let epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
+
+        // Create Proxy Account
+        let proxy_nonce = match payload.is_empty() {
+            true => None,
+            false => {
+                let proxy_account_info = next_account_info(account_info_iter)?;
+                let mint_account_info = next_account_info(account_info_iter)?;
+                let spl_token_program_info = next_account_info(account_info_iter)?;
+
+                if mint != *mint_account_info.key {
+                    return Err(ProgramError::InvalidArgument);
+                }
+
+                let nonce = create_proxy_account(
+                    program_id,
+                    &recipient,
+                    funder_account_info,
+                    proxy_account_info,
+                    mint_account_info,
+                    system_program_info,
+                    spl_token_program_info,
+                )?;
+
+                // Attach SOL to proxy account
+                invoke(
+                    &system_instruction::transfer(
+                        funder_account_info.key,
+                        proxy_account_info.key,
+                        attached_amount,
+                    ),
+                    accounts,
+                )?;
+
+                Some(nonce)
+            }
+        };
+
+        // Create Withdraw Account
+        let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+
+        let event_data = event.data.hash();
+
+        if let Some(event_hash) = event_hash {
+            if event_hash != event_data {
+                return Err(SolanaBridgeError::InvalidEventHash.into());
+            }
+        }
+
+        let mut withdrawal_seeds = bridge_utils::helper::get_proposal_seeds(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            chain_id,
+            event_data.as_ref(),
+        );
+
+        let (withdrawal_pubkey, withdrawal_nonce) = Pubkey::find_program_address(
+            &withdrawal_seeds
+                .iter()
+                .map(Vec::as_slice)
+                .collect::<Vec<_>>(),
+            program_id,
+        );
+
+        withdrawal_seeds.push(vec![withdrawal_nonce]);
+        let withdrawal_account_signer_seeds = withdrawal_seeds
+            .iter()
+            .map(Vec::as_slice)
+            .collect::<Vec<_>>();
+
+        if withdrawal_pubkey != *withdrawal_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if withdrawal_account_info.lamports() == 0 {
+            // Proposals diverging from one requested for the same event are rejected
+            record_proposal_index(
+                program_id,
+                accounts,
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                chain_id,
+                &event_data,
+                funder_account_info,
+                system_program_info,
+                rent,
+            )?;
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    withdrawal_account_info.key,
+                    1.max(rent.minimum_balance(WithdrawalMultiTokenSol::LEN)),
+                    WithdrawalMultiTokenSol::LEN as u64,
+                    program_id,
+                ),
+                accounts,
+                &[&withdrawal_account_signer_seeds],
+            )?;
+
+            let withdrawal_account_data = WithdrawalMultiTokenSol {
+                is_initialized: true,
+                account_kind: AccountKind::Proposal(withdrawal_nonce, proxy_nonce),
+                author: *author_account_info.key,
+                round_number,
+                required_votes,
+                pda: PDA {
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                },
+                event,
+                meta: WithdrawalTokenMetaWithLen::new(
+                    0,
+                    epoch,
+                    *funder_account_info.key,
+                    clock.unix_timestamp + WITHDRAWAL_REQUEST_TTL,
+                ),
+                signers: vec![Vote::None; relay_round_account_data.relays.len()],
+                chain_id,
+                vote_rewards: vec![VoteReward::None; relay_round_account_data.relays.len()],
+                version: CURRENT_ACCOUNT_VERSION,
+                confirmed_weight: 0,
+            };
+
+            WithdrawalMultiTokenSol::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+
+            let event_data = event_data.to_bytes().to_vec();
+
+            solana_program::log::sol_log_data(&[&WithdrawMultiTokenRequestEvent {
+                account: withdrawal_pubkey,
+                token: mint.to_string(),
+                recipient,
+                amount,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                event_data,
+                bounty: 0,
+            }
+            .try_to_vec()?]);
+
+            update_bridge_stats(program_id, accounts, BridgeStats::record_proposal)?;
+
+            // Send voting reparation for Relay to withdrawal account
+            invoke(
+                &system_instruction::transfer(
+                    funder_account_info.key,
+                    withdrawal_account_info.key,
+                    RELAY_REPARATION * relay_round_account_data.relays.len() as u64,
+                ),
+                accounts,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn process_withdraw_multi_token_ever_to_many_request(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        event_timestamp: u32,
+        event_transaction_lt: u64,
+        event_configuration: Pubkey,
+        token: EverAddress,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        recipients: Vec<WithdrawalRecipient>,
+        event_hash: Option<Hash>,
+        chain_id: Option<u64>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let author_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let rl_settings_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check asset name length
+        if name.len() > MAX_NAME_LEN {
+            return Err(SolanaBridgeError::TokenNameLenLimit.into());
+        }
+
+        // Check asset symbol length
+        if symbol.len() > MAX_SYMBOL_LEN {
+            return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+        }
+
+        // Check recipients count
+        if recipients.is_empty() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if recipients.len() > MAX_WITHDRAWAL_RECIPIENTS {
+            return Err(SolanaBridgeError::WithdrawalRecipientsLenLimit.into());
+        }
+
+        // Validate Round Loader Settings Account
+        let rl_settings_account_data =
+            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+        let (rl_settings_nonce, _) = rl_settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            &round_loader::id(),
+            rl_settings_nonce,
+            rl_settings_account_info,
+        )?;
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let round_number = relay_round_account_data.round_number;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
+            return Err(SolanaBridgeError::RelayRoundExpired.into());
+        }
+
+        let required_votes = round_loader::get_round_quorum(
+            relay_round_account_data.total_weight() as usize,
+            &relay_round_account_data,
+            &rl_settings_account_data,
+        );
+
+        let epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
+
+        // Create Withdraw Account
+        let event = WithdrawalMultiTokenEverToManyEventWithLen::new(
+            token,
+            name,
+            symbol,
+            decimals,
+            recipients.clone(),
+        );
+
+        let event_data = hash(&event.data.try_to_vec()?);
+
+        if let Some(event_hash) = event_hash {
+            if event_hash != event_data {
+                return Err(SolanaBridgeError::InvalidEventHash.into());
+            }
+        }
+
+        let mut withdrawal_seeds = bridge_utils::helper::get_proposal_seeds(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            chain_id,
+            event_data.as_ref(),
+        );
+
+        let (withdrawal_pubkey, withdrawal_nonce) = Pubkey::find_program_address(
+            &withdrawal_seeds
+                .iter()
+                .map(Vec::as_slice)
+                .collect::<Vec<_>>(),
+            program_id,
+        );
+
+        withdrawal_seeds.push(vec![withdrawal_nonce]);
+        let withdrawal_account_signer_seeds = withdrawal_seeds
+            .iter()
+            .map(Vec::as_slice)
+            .collect::<Vec<_>>();
+
+        if withdrawal_pubkey != *withdrawal_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if withdrawal_account_info.lamports() == 0 {
+            // Proposals diverging from one requested for the same event are rejected
+            record_proposal_index(
+                program_id,
+                accounts,
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                chain_id,
+                &event_data,
+                funder_account_info,
+                system_program_info,
+                rent,
+            )?;
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    withdrawal_account_info.key,
+                    1.max(rent.minimum_balance(WithdrawalMultiTokenEverToMany::LEN)),
+                    WithdrawalMultiTokenEverToMany::LEN as u64,
+                    program_id,
+                ),
+                accounts,
+                &[&withdrawal_account_signer_seeds],
+            )?;
+
+            let withdrawal_account_data = WithdrawalMultiTokenEverToMany {
+                is_initialized: true,
+                account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+                author: *author_account_info.key,
+                round_number,
+                required_votes,
+                event,
+                pda: PDA {
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                },
+                meta: WithdrawalTokenMetaWithLen::new(
+                    0,
+                    epoch,
+                    *funder_account_info.key,
+                    clock.unix_timestamp + WITHDRAWAL_REQUEST_TTL,
+                ),
+                signers: vec![Vote::None; relay_round_account_data.relays.len()],
+                chain_id,
+                vote_rewards: vec![VoteReward::None; relay_round_account_data.relays.len()],
+                version: CURRENT_ACCOUNT_VERSION,
+                confirmed_weight: 0,
+            };
+
+            WithdrawalMultiTokenEverToMany::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+
+            let event_data = event_data.to_bytes().to_vec();
+
+            solana_program::log::sol_log_data(&[&WithdrawMultiTokenToManyRequestEvent {
+                account: withdrawal_pubkey,
+                token: token.to_string(),
+                recipients,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                event_data,
+            }
+            .try_to_vec()?]);
+
+            update_bridge_stats(program_id, accounts, BridgeStats::record_proposal)?;
+
+            // Send voting reparation for Relay to withdrawal account
+            let relays_lamports = RELAY_REPARATION * relay_round_account_data.relays.len() as u64;
+
+            invoke(
+                &system_instruction::transfer(
+                    funder_account_info.key,
+                    withdrawal_account_info.key,
+                    relays_lamports,
+                ),
+                accounts,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_vote_for_withdraw_request(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        vote: Vote,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let relay_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let rl_settings_account_info = next_account_info(account_info_iter)?;
+        let relay_reward_account_info = next_account_info(account_info_iter).ok();
+
+        if !relay_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate vote
+        if vote == Vote::None {
+            return Err(SolanaBridgeError::InvalidVote.into());
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        let meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
+        if meta.status == WithdrawalTokenStatus::Expired {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            withdrawal_account_data.round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        // Validate Round Loader Settings Account
+        let rl_settings_account_data =
+            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+        let (rl_settings_nonce, _) = rl_settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            &round_loader::id(),
+            rl_settings_nonce,
+            rl_settings_account_info,
+        )?;
+
+        // Previous round can vote only within handover period
+        round_loader::validate_relay_round_for_voting(
+            &rl_settings_account_data,
+            &relay_round_account_data,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        // Vote for withdraw request
+        let index = relay_round_account_data
+            .relays
+            .iter()
+            .position(|pubkey| pubkey == relay_account_info.key)
+            .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+        if withdrawal_account_data.signers[index] == Vote::None {
+            let confirms =
+                relay_round_account_data.confirmed_weight(&withdrawal_account_data.signers);
+            let required_votes = round_loader::get_round_required_votes(
+                withdrawal_account_data.required_votes,
+                &relay_round_account_data,
+                &rl_settings_account_data,
+            );
+
+            // Vote for proposal
+            withdrawal_account_data.signers[index] = vote;
+
+            if vote == Vote::Confirm {
+                withdrawal_account_data.confirmed_weight = confirms
+                    .checked_add(relay_round_account_data.weight(index))
+                    .ok_or(SolanaBridgeError::Overflow)?;
+            }
+
+            // Credit reward to confirming Relay until the quorum is reached
+            let mut rewarded = false;
+            if vote == Vote::Confirm && confirms < required_votes {
+                if let Some(vote_reward) = withdrawal_account_data.vote_rewards.get_mut(index) {
+                    *vote_reward = VoteReward::Unclaimed;
+                    rewarded = true;
+                }
+            }
+
+            withdrawal_account_data.pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+
+            solana_program::log::sol_log_data(&[&VoteForWithdrawalEvent {
+                account: *withdrawal_account_info.key,
+                relay: *relay_account_info.key,
+                vote,
+            }
+            .try_to_vec()?]);
+
+            // Record voting earnings of Relay for the round
+            if let Some(relay_reward_account_info) = relay_reward_account_info {
+                accrue_relay_reward(
+                    program_id,
+                    accounts,
+                    relay_account_info,
+                    relay_reward_account_info,
+                    round_number,
+                    rewarded,
+                )?;
+            }
+
+            // Get back voting reparation to Relay
+            let withdrawal_starting_lamports = withdrawal_account_info.lamports();
+            **withdrawal_account_info.lamports.borrow_mut() = withdrawal_starting_lamports
+                .checked_sub(RELAY_REPARATION)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            let relay_starting_lamports = relay_account_info.lamports();
+            **relay_account_info.lamports.borrow_mut() = relay_starting_lamports
+                .checked_add(RELAY_REPARATION)
+                .ok_or(SolanaBridgeError::Overflow)?;
+        }
+
+        Ok(())
+    }
+
+    fn process_vote_for_withdraw_request_by_signatures(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let submitter_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let instructions_sysvar_info = next_account_info(account_info_iter)?;
+        let rl_settings_account_info = next_account_info(account_info_iter)?;
+
+        if !submitter_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        let meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
+        if meta.status == WithdrawalTokenStatus::Expired {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            withdrawal_account_data.round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        // Validate Round Loader Settings Account
+        let rl_settings_account_data =
+            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+        let (rl_settings_nonce, _) = rl_settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            &round_loader::id(),
+            rl_settings_nonce,
+            rl_settings_account_info,
+        )?;
+
+        // Previous round can vote only within handover period
+        round_loader::validate_relay_round_for_voting(
+            &rl_settings_account_data,
+            &relay_round_account_data,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        // Relays signed withdrawal confirmation off-chain
+        let message =
+            bridge_utils::helper::get_vote_message(withdrawal_account_info.key, &event_data);
+        let relays =
+            bridge_utils::helper::get_ed25519_signers(instructions_sysvar_info, message.as_ref())?;
+
+        let required_votes = round_loader::get_round_required_votes(
+            withdrawal_account_data.required_votes,
+            &relay_round_account_data,
+            &rl_settings_account_data,
+        );
+
+        let mut votes = 0;
+
+        for relay in relays {
+            // Evicted relay slots are zeroed out
+            if relay == Pubkey::default() {
+                return Err(SolanaBridgeError::InvalidRelay.into());
+            }
+
+            let index = relay_round_account_data
+                .relays
+                .iter()
+                .position(|pubkey| *pubkey == relay)
+                .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+            if withdrawal_account_data.signers[index] != Vote::None {
+                continue;
+            }
+
+            let confirms =
+                relay_round_account_data.confirmed_weight(&withdrawal_account_data.signers);
+
+            // Vote for proposal
+            withdrawal_account_data.signers[index] = Vote::Confirm;
+            withdrawal_account_data.confirmed_weight = confirms
+                .checked_add(relay_round_account_data.weight(index))
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Credit reward to confirming Relay until the quorum is reached
+            if confirms < required_votes {
+                if let Some(vote_reward) = withdrawal_account_data.vote_rewards.get_mut(index) {
+                    *vote_reward = VoteReward::Unclaimed;
+                }
+            }
+
+            solana_program::log::sol_log_data(&[&VoteForWithdrawalEvent {
+                account: *withdrawal_account_info.key,
+                relay,
+                vote: Vote::Confirm,
+            }
+            .try_to_vec()?]);
+
+            votes += 1;
+        }
+
+        withdrawal_account_data.pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+
+        // Get back voting reparation to Submitter
+        let reparation = RELAY_REPARATION
+            .checked_mul(votes)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let withdrawal_starting_lamports = withdrawal_account_info.lamports();
+        **withdrawal_account_info.lamports.borrow_mut() = withdrawal_starting_lamports
+            .checked_sub(reparation)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let submitter_starting_lamports = submitter_account_info.lamports();
+        **submitter_account_info.lamports.borrow_mut() = submitter_starting_lamports
+            .checked_add(reparation)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        Ok(())
+    }
+
+    fn process_withdraw_multi_token_ever(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        create_if_needed: bool,
+        ix_version: u8,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let recipient_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _relay_round_account_info = next_account_info(account_info_iter)?;
+        let _rl_settings_account_info = next_account_info(account_info_iter)?;
+        let _recipient_hook_account_info = next_account_info(account_info_iter)?;
+        let _denylist_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        let _approval_queue_account_info = next_account_info(account_info_iter)?;
+
+        // Accounts to create token and recipient token account
+        let funder_account_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|account_info| account_info.is_signer);
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if ix_version < settings_account_data.min_supported_ix_version
+            || ix_version > CURRENT_IX_VERSION
+        {
+            return Err(SolanaBridgeError::InstructionVersionNotSupported.into());
+        }
+
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if !settings_account_data.is_chain_enabled(withdrawal_account_data.chain_id) {
+            return Err(SolanaBridgeError::ChainDisabled.into());
+        }
+
+        // If token settings account is not created
+        if token_settings_account_info.lamports() == 0 {
+            let funder_account_info = match funder_account_info {
+                Some(funder_account_info) => funder_account_info,
+                None => return Ok(()),
+            };
+
+            // Get Solana decimals
+            let ever_decimals = withdrawal_account_data.event.data.decimals;
+            let solana_decimals = get_solana_decimals(
+                program_id,
+                accounts,
+                &withdrawal_account_data.event.data.token,
+                ever_decimals.min(MAX_SOLANA_DECIMALS),
+            )?;
+
+            // Create Mint Account
+            let token = hash(&withdrawal_account_data.event.data.token.try_to_vec()?);
+
+            let (mint_pubkey, mint_nonce) =
+                Pubkey::find_program_address(&[br"mint", token.as_ref()], program_id);
+            let mint_account_signer_seeds: &[&[_]] = &[br"mint", token.as_ref(), &[mint_nonce]];
+
+            if mint_pubkey != *mint_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    mint_account_info.key,
+                    1.max(rent.minimum_balance(spl_token::state::Mint::LEN)),
+                    spl_token::state::Mint::LEN as u64,
+                    &spl_token::id(),
+                ),
+                accounts,
+                &[mint_account_signer_seeds],
+            )?;
+
+            // Init Mint Account
+            invoke_signed(
+                &spl_token::instruction::initialize_mint(
+                    &spl_token::id(),
+                    mint_account_info.key,
+                    mint_account_info.key,
+                    None,
+                    solana_decimals,
+                )?,
+                accounts,
+                &[mint_account_signer_seeds],
+            )?;
+
+            // Create Token Account
+            if !create_if_needed {
+                invoke(
+                    &spl_associated_token_account::instruction::create_associated_token_account(
+                        funder_account_info.key,
+                        &withdrawal_account_data.event.data.recipient,
+                        mint_account_info.key,
+                        &spl_token::id(),
+                    ),
+                    accounts,
+                )?;
+            }
+
+            // Create Token Settings Account
+            let (token_settings_pubkey, token_settings_nonce) =
+                Pubkey::find_program_address(&[br"settings", token.as_ref()], program_id);
+            let token_settings_account_signer_seeds: &[&[_]] =
+                &[br"settings", token.as_ref(), &[token_settings_nonce]];
+
+            if token_settings_pubkey != *token_settings_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    token_settings_account_info.key,
+                    1.max(rent.minimum_balance(TokenSettings::LEN)),
+                    TokenSettings::LEN as u64,
+                    program_id,
+                ),
+                accounts,
+                &[token_settings_account_signer_seeds],
+            )?;
+
+            // Init Token Settings Account
+            let token_defaults = settings_account_data.ever_token_defaults(mint_account_info.key);
+
+            let token_settings_account_data = TokenSettings {
+                is_initialized: true,
+                account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+                kind: TokenKind::Ever {
+                    mint: *mint_account_info.key,
+                    decimals: ever_decimals,
+                    token: withdrawal_account_data.event.data.token,
+                },
+                name: withdrawal_account_data.event.data.name.clone(),
+                symbol: withdrawal_account_data.event.data.symbol.clone(),
+                withdrawal_epoch: 0,
+                deposit_limit: token_defaults.deposit_limit,
+                withdrawal_limit: token_defaults.withdrawal_limit,
+                withdrawal_daily_limit: token_defaults.withdrawal_daily_limit,
+                withdrawal_daily_amount: 0,
+                emergency: false,
+                fee_supply: Default::default(),
+                fee_deposit_info: token_defaults.fee_deposit_info,
+                fee_withdrawal_info: token_defaults.fee_withdrawal_info,
+                deposit_daily_limit: token_defaults.deposit_daily_limit,
+                deposit_daily_amount: 0,
+                deposit_epoch: 0,
+                withdrawal_epoch_length: None,
+                emergency_expiry: None,
+                emergency_info: None,
+                delisted: false,
+                authority_migration: None,
+                deposits_paused: false,
+                withdrawals_paused: false,
+                vote_reward: 0,
+                withdrawal_limit_exemptions: vec![],
+                version: CURRENT_ACCOUNT_VERSION,
+                fee_deposit_tiers: Default::default(),
+                fee_withdrawal_tiers: Default::default(),
+                min_deposit_amount: 0,
+                fills_restricted: false,
+                referral_fee_bps: 0,
+                pending_withdrawals: 0,
+                closed: false,
+            };
+
+            solana_program::log::sol_log_data(&[&TokenSettingsEvent {
+                account: token_settings_pubkey,
+                symbol: token_settings_account_data.symbol.clone(),
+                name: token_settings_account_data.name.clone(),
+                mint: mint_account_info.key.clone(),
+                vault: None,
+                ever_decimals: Some(ever_decimals),
+                solana_decimals: Some(solana_decimals),
+                root: Some(withdrawal_account_data.event.data.token),
+                fee: token_defaults.fee_withdrawal_info,
+            }
+            .try_to_vec()?]);
+
+            TokenSettings::pack(
+                token_settings_account_data,
+                &mut token_settings_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (_, token, _) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::WithdrawalsPaused.into());
+        }
+
+        if token_settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::TokenWithdrawalsPaused.into());
+        }
+
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+        // Check connection between token and proposal
+        if token != withdrawal_account_data.event.data.token {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Create Recipient Token Account if it doesn't exist
+        let recipient_token_pubkey = if create_if_needed {
+            let funder_account_info =
+                funder_account_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+            let recipient_token_account_info = next_account_info(account_info_iter)?;
+            let recipient_wallet_account_info = next_account_info(account_info_iter)?;
+            let _associated_token_program_info = next_account_info(account_info_iter)?;
+
+            if !funder_account_info.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            // Validate Recipient Token Account
+            if *recipient_wallet_account_info.key != withdrawal_account_data.event.data.recipient {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let recipient_token_pubkey = spl_associated_token_account::get_associated_token_address(
+                recipient_wallet_account_info.key,
+                mint_account_info.key,
+            );
+
+            if recipient_token_pubkey != *recipient_token_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            invoke(
+                &spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                    funder_account_info.key,
+                    recipient_wallet_account_info.key,
+                    mint_account_info.key,
+                    &spl_token::id(),
+                ),
+                accounts,
+            )?;
+
+            Some(recipient_token_pubkey)
+        } else {
+            None
+        };
+
+        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+        let solana_decimals = mint_account_data.decimals;
+        let ever_decimals = withdrawal_account_data.event.data.decimals;
+
+        // Do we have enough signers.
+        let (sig_count, required_votes) = get_proposal_quorum(
+            accounts,
+            withdrawal_account_data.round_number,
+            &withdrawal_account_data.signers,
+            withdrawal_account_data.required_votes,
+        )?;
+
+        if sig_count >= required_votes
+            && withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::New
+        {
+            // Confirmed proposal claims EVER event
+            claim_proposal_index(
+                program_id,
+                accounts,
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                withdrawal_account_data.chain_id,
+                &event_data,
+            )?;
+
+            let current_epoch =
+                token_settings_account_data.get_withdrawal_epoch(clock.unix_timestamp);
+
+            // If current epoch has changed
+            if token_settings_account_data.withdrawal_epoch != current_epoch {
+                token_settings_account_data.withdrawal_epoch = current_epoch;
+                token_settings_account_data.withdrawal_daily_amount = Default::default();
+            }
+
+            // Withdrawal amount is counted in the current epoch
+            withdrawal_account_data.meta.data.epoch = current_epoch;
+
+            // Calculate amount
+            let withdrawal_amount = to_solana_amount(
+                withdrawal_account_data.event.data.amount,
+                ever_decimals,
+                solana_decimals,
+            )?;
+
+            let fee = token_settings_account_data
+                .withdrawal_fee(withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Increase fee supply
+            increase_fee_supply(
+                program_id,
+                accounts,
+                token_settings_account_info,
+                &mut token_settings_account_data,
+                fee,
+            )?;
+
+            // Amount without fee
+            let transfer_withdrawal_amount = withdrawal_amount
+                .checked_sub(fee)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Increase withdrawal daily amount
+            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
+                .withdrawal_daily_amount
+                .checked_add(transfer_withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Exempt recipients are not bound by withdrawal daily limit
+            let daily_limit_exceeded = token_settings_account_data.withdrawal_daily_amount
+                > token_settings_account_data.withdrawal_daily_limit
+                && !token_settings_account_data
+                    .is_withdrawal_limit_exempt(&withdrawal_account_data.event.data.recipient);
+
+            // Denied recipients and authors wait for approve regardless of amount
+            let is_denied = is_denylisted(
+                program_id,
+                accounts,
+                &[
+                    withdrawal_account_data.event.data.recipient,
+                    withdrawal_account_data.author,
+                ],
+            )?;
+
+            if transfer_withdrawal_amount > token_settings_account_data.withdrawal_limit
+                || daily_limit_exceeded
+                || is_denied
+            {
+                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
+
+                enqueue_approval(
+                    program_id,
+                    accounts,
+                    withdrawal_account_info.key,
+                    &withdrawal_account_data.event.data.recipient,
+                    transfer_withdrawal_amount,
+                )?;
+            } else {
+                match withdrawal_account_data.event.data.payload.is_empty() {
+                    true => {
+                        // Validate Recipient Account
+                        if let Some(recipient_token_pubkey) = recipient_token_pubkey {
+                            if recipient_token_pubkey != *recipient_account_info.key {
+                                return Err(ProgramError::InvalidArgument);
+                            }
+                        }
+
+                        let recipient_account_data = spl_token::state::Account::unpack(
+                            &recipient_account_info.data.borrow(),
+                        )?;
+
+                        if recipient_account_data.owner
+                            != withdrawal_account_data.event.data.recipient
+                        {
+                            return Err(ProgramError::InvalidArgument);
+                        }
+
+                        make_ever_transfer(
+                            mint_account_info,
+                            recipient_account_info,
+                            &token_settings_account_data,
+                            accounts,
+                            transfer_withdrawal_amount,
+                        )?;
+
+                        update_bridge_stats(program_id, accounts, |stats| {
+                            stats.record_withdrawal(transfer_withdrawal_amount)
+                        })?;
+
+                        withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+                    }
+                    false => {
+                        // Validate Proxy Account
+                        let proxy_nonce = withdrawal_account_data
+                            .account_kind
+                            .into_proposal()
+                            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                            .1
+                            .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                        validate_proxy_account(
+                            program_id,
+                            mint_account_info.key,
+                            &withdrawal_account_data.event.data.recipient,
+                            proxy_nonce,
+                            recipient_account_info,
+                        )?;
+
+                        // Make transfer to Proxy Account
+                        make_ever_transfer(
+                            mint_account_info,
+                            recipient_account_info,
+                            &token_settings_account_data,
+                            accounts,
+                            transfer_withdrawal_amount,
+                        )?;
+
+                        update_bridge_stats(program_id, accounts, |stats| {
+                            stats.record_withdrawal(transfer_withdrawal_amount)
+                        })?;
+
+                        withdrawal_account_data.meta.data.status =
+                            WithdrawalTokenStatus::WaitingForExecute;
+                    }
+                }
+            }
+
+            token_settings_account_data.track_withdrawal(
+                WithdrawalTokenStatus::New,
+                withdrawal_account_data.meta.data.status,
+            );
+
+            TokenSettings::pack(
+                token_settings_account_data,
+                &mut token_settings_account_info.data.borrow_mut(),
+            )?;
+
+            solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+                account: withdrawal_pubkey,
+                status: withdrawal_account_data.meta.data.status,
+            }
+            .try_to_vec()?]);
+
+            let status = withdrawal_account_data.meta.data.status;
+            let recipient = withdrawal_account_data.event.data.recipient;
+
+            WithdrawalMultiTokenEver::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+
+            // Notify recipient program
+            if status == WithdrawalTokenStatus::Processed {
+                notify_recipient(
+                    program_id,
+                    accounts,
+                    &recipient,
+                    recipient_account_info,
+                    withdrawal_account_info,
+                    mint_account_info.key,
+                    transfer_withdrawal_amount,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn process_withdraw_multi_token_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let vault_account_info = next_account_info(account_info_iter)?;
+        let recipient_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _relay_round_account_info = next_account_info(account_info_iter)?;
+        let _rl_settings_account_info = next_account_info(account_info_iter)?;
+        let _recipient_hook_account_info = next_account_info(account_info_iter)?;
+        let _denylist_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        let _approval_queue_account_info = next_account_info(account_info_iter)?;
+
+        // Accounts to create recipient token account
+        let funder_account_info = next_account_info(account_info_iter)
+            .ok()
+            .filter(|account_info| account_info.is_signer);
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if !settings_account_data.is_chain_enabled(withdrawal_account_data.chain_id) {
+            return Err(SolanaBridgeError::ChainDisabled.into());
+        }
+
+        // Create Recipient Token Account if it doesn't exist
+        if let Some(funder_account_info) = funder_account_info {
+            let recipient_wallet_account_info = next_account_info(account_info_iter)?;
+            let _system_program_info = next_account_info(account_info_iter)?;
+            let _associated_token_program_info = next_account_info(account_info_iter)?;
+
+            if !funder_account_info.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            // Validate Recipient Token Account
+            if *recipient_wallet_account_info.key != withdrawal_account_data.event.data.recipient {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if spl_associated_token_account::get_associated_token_address(
+                recipient_wallet_account_info.key,
+                &withdrawal_account_data.event.data.mint,
+            ) != *recipient_account_info.key
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            invoke(
+                &spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                    funder_account_info.key,
+                    recipient_wallet_account_info.key,
+                    &withdrawal_account_data.event.data.mint,
+                    &spl_token::id(),
+                ),
+                accounts,
+            )?;
+        }
+
+        let withdrawal_status = withdrawal_account_data.meta.data.status;
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::WithdrawalsPaused.into());
+        }
+
+        if token_settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::TokenWithdrawalsPaused.into());
+        }
+
+        // Validate Vault Account
+        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
+
+        // Check connection between token and proposal
+        if mint != withdrawal_account_data.event.data.mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Do we have enough signers.
+        let (sig_count, required_votes) = get_proposal_quorum(
+            accounts,
+            withdrawal_account_data.round_number,
+            &withdrawal_account_data.signers,
+            withdrawal_account_data.required_votes,
+        )?;
+
+        if sig_count >= required_votes {
+            let withdrawal_amount = withdrawal_account_data.event.data.amount as u64;
+
+            let fee = token_settings_account_data
+                .withdrawal_fee(withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Part already covered by fillers is paid without its fee share
+            let filled_amount = withdrawal_account_data.meta.data.filled_amount;
+            let filled_fee = pro_rata_part(fee, 0, filled_amount, withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Amount without fee
+            let transfer_withdrawal_amount = withdrawal_amount
+                .checked_sub(fee)
+                .and_then(|amount| amount.checked_sub(filled_amount - filled_fee))
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            match withdrawal_status {
+                WithdrawalTokenStatus::New => {
+                    // Confirmed proposal claims EVER event
+                    claim_proposal_index(
+                        program_id,
+                        accounts,
+                        event_timestamp,
+                        event_transaction_lt,
+                        &event_configuration,
+                        withdrawal_account_data.chain_id,
+                        &event_data,
+                    )?;
+
+                    let current_epoch =
+                        token_settings_account_data.get_withdrawal_epoch(clock.unix_timestamp);
+
+                    // If current epoch has changed
+                    if token_settings_account_data.withdrawal_epoch != current_epoch {
+                        token_settings_account_data.withdrawal_epoch = current_epoch;
+                        token_settings_account_data.withdrawal_daily_amount = Default::default();
+                    }
+
+                    // Withdrawal amount is counted in the current epoch
+                    withdrawal_account_data.meta.data.epoch = current_epoch;
+
+                    // Increase withdrawal daily amount
+                    token_settings_account_data.withdrawal_daily_amount =
+                        token_settings_account_data
+                            .withdrawal_daily_amount
+                            .checked_add(transfer_withdrawal_amount)
+                            .ok_or(SolanaBridgeError::Overflow)?;
+
+                    // Increase fee supply
+                    increase_fee_supply(
+                        program_id,
+                        accounts,
+                        token_settings_account_info,
+                        &mut token_settings_account_data,
+                        fee,
+                    )?;
+
+                    // Exempt recipients are not bound by withdrawal daily limit
+                    let daily_limit_exceeded = token_settings_account_data.withdrawal_daily_amount
+                        > token_settings_account_data.withdrawal_daily_limit
+                        && !token_settings_account_data.is_withdrawal_limit_exempt(
+                            &withdrawal_account_data.event.data.recipient,
+                        );
+
+                    // Denied recipients and authors wait for approve regardless of amount
+                    let is_denied = is_denylisted(
+                        program_id,
+                        accounts,
+                        &[
+                            withdrawal_account_data.event.data.recipient,
+                            withdrawal_account_data.author,
+                        ],
+                    )?;
+
+                    if transfer_withdrawal_amount > token_settings_account_data.withdrawal_limit
+                        || daily_limit_exceeded
+                        || is_denied
+                    {
+                        withdrawal_account_data.meta.data.status =
+                            WithdrawalTokenStatus::WaitingForApprove;
+
+                        enqueue_approval(
+                            program_id,
+                            accounts,
+                            withdrawal_account_info.key,
+                            &withdrawal_account_data.event.data.recipient,
+                            transfer_withdrawal_amount,
+                        )?;
+                    } else {
+                        match withdrawal_account_data.event.data.payload.is_empty() {
+                            true => {
+                                // Validate Recipient Account
+                                let recipient_account_data = spl_token::state::Account::unpack(
+                                    &recipient_account_info.data.borrow(),
+                                )?;
+
+                                if recipient_account_data.owner
+                                    != withdrawal_account_data.event.data.recipient
+                                {
+                                    return Err(ProgramError::InvalidArgument);
+                                }
+
+                                let vault_account_data = spl_token::state::Account::unpack(
+                                    &vault_account_info.data.borrow(),
+                                )?;
+
+                                if transfer_withdrawal_amount > vault_account_data.amount {
+                                    withdrawal_account_data.meta.data.status =
+                                        WithdrawalTokenStatus::Pending;
+                                } else {
+                                    make_sol_transfer(
+                                        vault_account_info,
+                                        recipient_account_info,
+                                        &token_settings_account_data,
+                                        accounts,
+                                        transfer_withdrawal_amount,
+                                    )?;
+
+                                    update_bridge_stats(program_id, accounts, |stats| {
+                                        stats.record_withdrawal(transfer_withdrawal_amount)
+                                    })?;
+
+                                    withdrawal_account_data.meta.data.status =
+                                        WithdrawalTokenStatus::Processed;
+                                }
+                            }
+                            false => {
+                                // Validate Proxy Account
+                                let proxy_nonce = withdrawal_account_data
+                                    .account_kind
+                                    .into_proposal()
+                                    .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                                    .1
+                                    .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                                validate_proxy_account(
+                                    program_id,
+                                    mint_account_info.key,
+                                    &withdrawal_account_data.event.data.recipient,
+                                    proxy_nonce,
+                                    recipient_account_info,
+                                )?;
+
+                                let vault_account_data = spl_token::state::Account::unpack(
+                                    &vault_account_info.data.borrow(),
+                                )?;
+
+                                if transfer_withdrawal_amount > vault_account_data.amount {
+                                    withdrawal_account_data.meta.data.status =
+                                        WithdrawalTokenStatus::Pending;
+                                } else {
+                                    make_sol_transfer(
+                                        vault_account_info,
+                                        recipient_account_info,
+                                        &token_settings_account_data,
+                                        accounts,
+                                        transfer_withdrawal_amount,
+                                    )?;
+
+                                    update_bridge_stats(program_id, accounts, |stats| {
+                                        stats.record_withdrawal(transfer_withdrawal_amount)
+                                    })?;
+
+                                    withdrawal_account_data.meta.data.status =
+                                        WithdrawalTokenStatus::WaitingForExecute;
+                                }
+                            }
+                        }
+                    }
+
+                    token_settings_account_data.track_withdrawal(
+                        WithdrawalTokenStatus::New,
+                        withdrawal_account_data.meta.data.status,
+                    );
+
+                    TokenSettings::pack(
+                        token_settings_account_data,
+                        &mut token_settings_account_info.data.borrow_mut(),
+                    )?;
+                }
+                WithdrawalTokenStatus::Pending => {
+                    match withdrawal_account_data.event.data.payload.is_empty() {
+                        true => {
+                            // Validate Recipient Account
+                            let recipient_account_data = spl_token::state::Account::unpack(
+                                &recipient_account_info.data.borrow(),
+                            )?;
+
+                            if recipient_account_data.owner
+                                != withdrawal_account_data.event.data.recipient
+                            {
+                                return Err(ProgramError::InvalidArgument);
+                            }
+
+                            let vault_account_data = spl_token::state::Account::unpack(
+                                &vault_account_info.data.borrow(),
+                            )?;
+
+                            if transfer_withdrawal_amount > vault_account_data.amount {
+                                withdrawal_account_data.meta.data.status =
+                                    WithdrawalTokenStatus::Pending;
+                            } else {
+                                make_sol_transfer(
+                                    vault_account_info,
+                                    recipient_account_info,
+                                    &token_settings_account_data,
+                                    accounts,
+                                    transfer_withdrawal_amount,
+                                )?;
+
+                                update_bridge_stats(program_id, accounts, |stats| {
+                                    stats.record_withdrawal(transfer_withdrawal_amount)
+                                })?;
+
+                                withdrawal_account_data.meta.data.status =
+                                    WithdrawalTokenStatus::Processed;
+                            }
+                        }
+                        false => {
+                            // Validate Proxy Account
+                            let proxy_nonce = withdrawal_account_data
+                                .account_kind
+                                .into_proposal()
+                                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                                .1
+                                .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                            validate_proxy_account(
+                                program_id,
+                                mint_account_info.key,
+                                &withdrawal_account_data.event.data.recipient,
+                                proxy_nonce,
+                                recipient_account_info,
+                            )?;
+
+                            let vault_account_data = spl_token::state::Account::unpack(
+                                &vault_account_info.data.borrow(),
+                            )?;
+
+                            if transfer_withdrawal_amount > vault_account_data.amount {
+                                withdrawal_account_data.meta.data.status =
+                                    WithdrawalTokenStatus::Pending;
+                            } else {
+                                make_sol_transfer(
+                                    vault_account_info,
+                                    recipient_account_info,
+                                    &token_settings_account_data,
+                                    accounts,
+                                    transfer_withdrawal_amount,
+                                )?;
+
+                                update_bridge_stats(program_id, accounts, |stats| {
+                                    stats.record_withdrawal(transfer_withdrawal_amount)
+                                })?;
+
+                                withdrawal_account_data.meta.data.status =
+                                    WithdrawalTokenStatus::WaitingForExecute;
+                            }
+                        }
+                    }
+
+                    token_settings_account_data.track_withdrawal(
+                        WithdrawalTokenStatus::Pending,
+                        withdrawal_account_data.meta.data.status,
+                    );
+
+                    TokenSettings::pack(
+                        token_settings_account_data,
+                        &mut token_settings_account_info.data.borrow_mut(),
+                    )?;
+                }
+                _ => (),
+            }
+
+            solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+                account: withdrawal_pubkey,
+                status: withdrawal_account_data.meta.data.status,
+            }
+            .try_to_vec()?]);
+
+            let status = withdrawal_account_data.meta.data.status;
+            let recipient = withdrawal_account_data.event.data.recipient;
+
+            WithdrawalMultiTokenSol::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+
+            // Notify recipient program
+            if withdrawal_status != WithdrawalTokenStatus::Processed
+                && status == WithdrawalTokenStatus::Processed
+            {
+                notify_recipient(
+                    program_id,
+                    accounts,
+                    &recipient,
+                    recipient_account_info,
+                    withdrawal_account_info,
+                    &mint,
+                    transfer_withdrawal_amount,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn process_withdraw_multi_token_ever_to_many(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _relay_round_account_info = next_account_info(account_info_iter)?;
+        let _rl_settings_account_info = next_account_info(account_info_iter)?;
+        let _denylist_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+
+        // Recipient token accounts in the order of event recipients
+        let recipient_account_infos = account_info_iter.as_slice();
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.is_emergency(clock.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenEverToMany::unpack(&withdrawal_account_info.data.borrow())?;
+
+        let withdrawal_pubkey = validate_withdrawal_ever_to_many_account(
+            program_id,
+            &withdrawal_account_data,
+            withdrawal_account_info,
+        )?;
+
+        if !settings_account_data.is_chain_enabled(withdrawal_account_data.chain_id) {
+            return Err(SolanaBridgeError::ChainDisabled.into());
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (_, token, _) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(clock.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::WithdrawalsPaused.into());
+        }
+
+        if token_settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::TokenWithdrawalsPaused.into());
+        }
+
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+        // Check connection between token and proposal
+        if token != withdrawal_account_data.event.data.token {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Do we have enough signers.
+        let (sig_count, required_votes) = get_proposal_quorum(
+            accounts,
+            withdrawal_account_data.round_number,
+            &withdrawal_account_data.signers,
+            withdrawal_account_data.required_votes,
+        )?;
+
+        if sig_count >= required_votes
+            && withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::New
+        {
+            // Confirmed proposal claims EVER event
+            claim_proposal_index(
+                program_id,
+                accounts,
+                withdrawal_account_data.pda.event_timestamp,
+                withdrawal_account_data.pda.event_transaction_lt,
+                &withdrawal_account_data.pda.event_configuration,
+                withdrawal_account_data.chain_id,
+                &hash(&withdrawal_account_data.event.data.try_to_vec()?),
+            )?;
+
+            let current_epoch =
+                token_settings_account_data.get_withdrawal_epoch(clock.unix_timestamp);
+
+            // If current epoch has changed
+            if token_settings_account_data.withdrawal_epoch != current_epoch {
+                token_settings_account_data.withdrawal_epoch = current_epoch;
+                token_settings_account_data.withdrawal_daily_amount = Default::default();
+            }
+
+            // Withdrawal amount is counted in the current epoch
+            withdrawal_account_data.meta.data.epoch = current_epoch;
+
+            // Calculate amounts
+            let mint_account_data =
+                spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+
+            let (transfer_amounts, fee) = get_ever_to_many_transfer_amounts(
+                &withdrawal_account_data.event.data,
+                &token_settings_account_data,
+                mint_account_data.decimals,
+            )?;
+
+            // Increase fee supply
+            increase_fee_supply(
+                program_id,
+                accounts,
+                token_settings_account_info,
+                &mut token_settings_account_data,
+                fee,
+            )?;
+
+            let total_transfer_amount = transfer_amounts
+                .iter()
+                .try_fold(0u64, |total, amount| total.checked_add(*amount))
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Increase withdrawal daily amount
+            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
+                .withdrawal_daily_amount
+                .checked_add(total_transfer_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            let daily_limit_exceeded = token_settings_account_data.withdrawal_daily_amount
+                > token_settings_account_data.withdrawal_daily_limit;
+
+            let limit_exceeded = transfer_amounts
+                .iter()
+                .any(|amount| *amount > token_settings_account_data.withdrawal_limit);
+
+            // Denied recipients and authors wait for approve regardless of amount
+            let mut addresses = withdrawal_account_data
+                .event
+                .data
+                .recipients
+                .iter()
+                .map(|recipient| recipient.recipient)
+                .collect::<Vec<_>>();
+            addresses.push(withdrawal_account_data.author);
+
+            let is_denied = is_denylisted(program_id, accounts, &addresses)?;
+
+            if limit_exceeded || daily_limit_exceeded || is_denied {
+                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
+            } else {
+                make_ever_transfers_to_many(
+                    mint_account_info,
+                    recipient_account_infos,
+                    &token_settings_account_data,
+                    accounts,
+                    &withdrawal_account_data.event.data.recipients,
+                    &transfer_amounts,
+                )?;
+
+                update_bridge_stats(program_id, accounts, |stats| {
+                    stats.record_withdrawal(total_transfer_amount)
+                })?;
+
+                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+            }
+
+            token_settings_account_data.track_withdrawal(
+                WithdrawalTokenStatus::New,
+                withdrawal_account_data.meta.data.status,
+            );
+
+            TokenSettings::pack(
+                token_settings_account_data,
+                &mut token_settings_account_info.data.borrow_mut(),
+            )?;
+
+            solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+                account: withdrawal_pubkey,
+                status: withdrawal_account_data.meta.data.status,
+            }
+            .try_to_vec()?]);
+
+            WithdrawalMultiTokenEverToMany::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_execute_payload_ever(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        count: Option<u32>,
+        proxy_accounts: u8,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
+
+        if withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::WaitingForExecute {
+            let mint = get_associated_mint(program_id, &withdrawal_account_data.event.data.token);
+            let recipient = withdrawal_account_data.event.data.recipient;
+            let (_, nonce) = withdrawal_account_data
+                .account_kind
+                .into_proposal()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            let ixs: Vec<solana_program::instruction::Instruction> =
+                bincode::deserialize(&withdrawal_account_data.event.data.payload)
+                    .map_err(|_| SolanaBridgeError::DeserializePayload)?;
+
+            // Execute payload instructions starting from the saved cursor
+            let begin = withdrawal_account_data.meta.data.payload_cursor as usize;
+            let end = match count {
+                Some(count) => ixs.len().min(begin.saturating_add(count as usize)),
+                None => ixs.len(),
+            };
+
+            invoke_payload(
+                program_id,
+                accounts,
+                &ixs[begin..end],
+                &settings_account_data.payload_allowlist,
+                &mint,
+                &recipient,
+                nonce.unwrap_or_default(),
+                proxy_accounts,
+            )?;
+
+            withdrawal_account_data.meta.data.payload_cursor = end as u32;
+
+            if end == ixs.len() {
+                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+            }
+
+            WithdrawalMultiTokenEver::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_execute_payload_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        count: Option<u32>,
+        proxy_accounts: u8,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+
+        if withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::WaitingForExecute {
+            let mint = withdrawal_account_data.event.data.mint;
+            let recipient = withdrawal_account_data.event.data.recipient;
+            let (_, nonce) = withdrawal_account_data
+                .account_kind
+                .into_proposal()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            let ixs: Vec<solana_program::instruction::Instruction> =
+                bincode::deserialize(&withdrawal_account_data.event.data.payload)
+                    .map_err(|_| SolanaBridgeError::DeserializePayload)?;
+
+            // Execute payload instructions starting from the saved cursor
+            let begin = withdrawal_account_data.meta.data.payload_cursor as usize;
+            let end = match count {
+                Some(count) => ixs.len().min(begin.saturating_add(count as usize)),
+                None => ixs.len(),
+            };
+
+            invoke_payload(
+                program_id,
+                accounts,
+                &ixs[begin..end],
+                &settings_account_data.payload_allowlist,
+                &mint,
+                &recipient,
+                nonce.unwrap_or_default(),
+                proxy_accounts,
+            )?;
+
+            withdrawal_account_data.meta.data.payload_cursor = end as u32;
+
+            if end == ixs.len() {
+                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+            }
+
+            WithdrawalMultiTokenSol::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_change_guardian(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_guardian: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
+
+        settings_account_data.guardian = new_guardian;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_manager(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_manager: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
+
+        settings_account_data.manager = new_manager;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_withdrawal_manager(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_withdrawal_manager: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
+
+        settings_account_data.withdrawal_manager = new_withdrawal_manager;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_propose_role(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        role: Role,
+        new_authority: Option<Pubkey>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
+
+        settings_account_data.pending_role =
+            new_authority.map(|authority| PendingRole { role, authority });
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_accept_role(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Pending Authority
+        let pending_role = settings_account_data
+            .pending_role
+            .ok_or(SolanaBridgeError::RoleTransferNotProposed)?;
+
+        if pending_role.authority != *authority_account_info.key {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        match pending_role.role {
+            Role::Guardian => settings_account_data.guardian = pending_role.authority,
+            Role::Manager => settings_account_data.manager = pending_role.authority,
+            Role::WithdrawalManager => {
+                settings_account_data.withdrawal_manager = pending_role.authority
+            }
+        }
+
+        settings_account_data.pending_role = None;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_schedule_change(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        change_seed: u128,
+        change: TimelockedChange,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let pending_change_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Change is validated the same way as the instruction it replaces
+        match &change {
+            TimelockedChange::Fee { divisor: 0, .. } => {
+                return Err(ProgramError::InvalidArgument);
+            }
+            TimelockedChange::WithdrawalLimitExemptions {
+                new_withdrawal_limit_exemptions,
+                ..
+            } if new_withdrawal_limit_exemptions.len() > MAX_WITHDRAWAL_LIMIT_EXEMPTIONS_LEN => {
+                return Err(SolanaBridgeError::WithdrawalLimitExemptionsLenLimit.into());
+            }
+            TimelockedChange::Wever {
+                new_wever_defaults: Some(token_defaults),
+                ..
+            }
+            | TimelockedChange::TokenDefaults {
+                new_token_defaults: token_defaults,
+            } if token_defaults.fee_deposit_info.divisor == 0
+                || token_defaults.fee_withdrawal_info.divisor == 0 =>
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+            _ => {}
+        }
+
+        // Create Pending Change Account
+        let (pending_change_pubkey, pending_change_nonce) = Pubkey::find_program_address(
+            &[br"pending_change", &change_seed.to_le_bytes()],
+            program_id,
+        );
+        let pending_change_account_signer_seeds: &[&[_]] = &[
+            br"pending_change",
+            &change_seed.to_le_bytes(),
+            &[pending_change_nonce],
+        ];
+
+        if pending_change_pubkey != *pending_change_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                pending_change_account_info.key,
+                1.max(rent.minimum_balance(PendingChange::LEN)),
+                PendingChange::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[pending_change_account_signer_seeds],
+        )?;
+
+        // Init Pending Change Account
+        let eta = Clock::get()?
+            .unix_timestamp
+            .checked_add(settings_account_data.timelock_delay as i64)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let pending_change_account_data = PendingChange {
+            is_initialized: true,
+            account_kind: AccountKind::PendingChange(pending_change_nonce),
+            author: *funder_account_info.key,
+            seed: change_seed,
+            change,
+            eta,
+            version: CURRENT_ACCOUNT_VERSION,
+        };
+
+        PendingChange::pack(
+            pending_change_account_data,
+            &mut pending_change_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_execute_change(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let author_account_info = next_account_info(account_info_iter)?;
+        let pending_change_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Pending Change Account
+        let pending_change_account_data =
+            PendingChange::unpack(&pending_change_account_info.data.borrow())?;
+
+        let pending_change_nonce = pending_change_account_data
+            .account_kind
+            .into_pending_change()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_pending_change_account(
+            program_id,
+            pending_change_account_data.seed,
+            pending_change_nonce,
+            pending_change_account_info,
+        )?;
+
+        if pending_change_account_data.author != *author_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if Clock::get()?.unix_timestamp < pending_change_account_data.eta {
+            return Err(SolanaBridgeError::TimelockNotExpired.into());
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if let Some(token_settings_pubkey) = pending_change_account_data.change.token_settings() {
+            let token_settings_account_info = next_account_info(account_info_iter)?;
+
+            if token_settings_pubkey != *token_settings_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            // Validate Token Settings Account
+            let mut token_settings_account_data =
+                TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+            let (token_settings_nonce, _) = token_settings_account_data
+                .account_kind
+                .into_token_settings()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            match token_settings_account_data.kind {
+                TokenKind::Ever { token, .. } => validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?,
+                TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?,
+            };
+
+            match pending_change_account_data.change {
+                TimelockedChange::WithdrawalLimits {
+                    new_withdrawal_limit,
+                    new_withdrawal_daily_limit,
+                    ..
+                } => set_withdrawal_limits(
+                    &mut token_settings_account_data,
+                    new_withdrawal_limit,
+                    new_withdrawal_daily_limit,
+                ),
+                TimelockedChange::Fee {
+                    fee_type,
+                    multiplier,
+                    divisor,
+                    ..
+                } => set_fee(
+                    token_settings_pubkey,
+                    &mut token_settings_account_data,
+                    fee_type,
+                    multiplier,
+                    divisor,
+                )?,
+                TimelockedChange::WithdrawalLimitExemptions {
+                    new_withdrawal_limit_exemptions,
+                    ..
+                } => {
+                    token_settings_account_data.withdrawal_limit_exemptions =
+                        new_withdrawal_limit_exemptions;
+                }
+                TimelockedChange::Role { .. }
+                | TimelockedChange::Delay { .. }
+                | TimelockedChange::Wever { .. }
+                | TimelockedChange::TokenDefaults { .. } => {}
+            }
+
+            TokenSettings::pack(
+                token_settings_account_data,
+                &mut token_settings_account_info.data.borrow_mut(),
+            )?;
+        } else {
+            match pending_change_account_data.change {
+                TimelockedChange::Role {
+                    role,
+                    new_authority,
+                } => {
+                    settings_account_data.pending_role =
+                        new_authority.map(|authority| PendingRole { role, authority });
+                }
+                TimelockedChange::Delay { delay_seconds } => {
+                    settings_account_data.timelock_delay = delay_seconds;
+                }
+                TimelockedChange::Wever {
+                    new_wever_mint,
+                    new_wever_defaults,
+                } => {
+                    settings_account_data.wever_mint = new_wever_mint;
+                    settings_account_data.wever_defaults = new_wever_defaults;
+                }
+                TimelockedChange::TokenDefaults { new_token_defaults } => {
+                    settings_account_data.token_defaults = Some(new_token_defaults);
+                }
+                TimelockedChange::WithdrawalLimits { .. }
+                | TimelockedChange::Fee { .. }
+                | TimelockedChange::WithdrawalLimitExemptions { .. } => {}
+            }
+
+            Settings::pack(
+                settings_account_data,
+                &mut settings_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        delete_account(pending_change_account_info, author_account_info)?;
+
+        Ok(())
+    }
+
+    fn process_cancel_change(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let pending_change_account_info = next_account_info(account_info_iter)?;
+        let author_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Guardian Account
+        if *authority_account_info.key != settings_account_data.guardian
+            && !settings_account_data
+                .guardians
+                .contains(authority_account_info.key)
+        {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+        }
+
+        // Validate Pending Change Account
+        let pending_change_account_data =
+            PendingChange::unpack(&pending_change_account_info.data.borrow())?;
+
+        let pending_change_nonce = pending_change_account_data
+            .account_kind
+            .into_pending_change()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_pending_change_account(
+            program_id,
+            pending_change_account_data.seed,
+            pending_change_nonce,
+            pending_change_account_info,
+        )?;
+
+        if pending_change_account_data.author != *author_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        delete_account(pending_change_account_info, author_account_info)?;
+
+        Ok(())
+    }
+
+    fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        let account_kind = {
+            // Skip type tag and `is_initialized` flag
+            let data = account_info.data.borrow();
+            let data = bridge_utils::state::strip_discriminator(&data);
+            let mut account_kind_data = data.get(1..).unwrap_or_default();
+            AccountKind::deserialize(&mut account_kind_data)?
+        };
+
+        // Withdrawals and deposits of all tokens share the same layout prefix
+        let account_len = match account_kind {
+            AccountKind::Settings(_, _) => Settings::LEN,
+            AccountKind::Deposit(_) => DepositMultiTokenSol::LEN,
+            AccountKind::Proposal(_, _) => WithdrawalMultiTokenSol::LEN,
+            AccountKind::MultiVault(_) => MultiVault::LEN,
+            AccountKind::TokenSettings(_, _) => TokenSettings::LEN,
+            AccountKind::TokenDecimals(_) => TokenDecimals::LEN,
+            AccountKind::ProposalIndex(_) => ProposalIndex::LEN,
+            AccountKind::FeeLedger(_) => FeeLedger::LEN,
+            AccountKind::RecipientHook(_) => RecipientHook::LEN,
+            AccountKind::PendingChange(_) => PendingChange::LEN,
+            AccountKind::Denylist(_) => Denylist::LEN,
+            AccountKind::RelayReward(_) => RelayReward::LEN,
+            AccountKind::BridgeStats(_) => BridgeStats::LEN,
+            AccountKind::LpRegistry(_) => LpRegistry::LEN,
+            AccountKind::Referral(_) => Referral::LEN,
+            AccountKind::ApprovalQueue(_) => ApprovalQueue::LEN,
+            AccountKind::RelayRound(_)
+            | AccountKind::MerkleRelayRound(_)
+            | AccountKind::RelayEviction(_) => {
+                return Err(SolanaBridgeError::InvalidTokenKind.into())
+            }
+        };
+
+        // New fields are read from zeroed bytes as their defaults
+        if account_info.data_len() < account_len {
+            expand_account(
+                funder_account_info,
+                account_info,
+                accounts,
+                rent,
+                account_len,
+            )?;
+        }
+
+        match account_kind {
+            AccountKind::Settings(_, _) => {
+                migrate_account::<Settings, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::Deposit(_) => {
+                let mut deposit_account_data =
+                    Deposit::unpack_from_slice(&account_info.data.borrow())?;
+                check_account_version(deposit_account_data.version)?;
+                deposit_account_data.version = CURRENT_ACCOUNT_VERSION;
+                deposit_account_data.pack_into_slice(&mut account_info.data.borrow_mut());
+            }
+            AccountKind::Proposal(_, _) => {
+                let mut withdrawal_account_data =
+                    Proposal::unpack_from_slice(&account_info.data.borrow())?;
+                check_account_version(withdrawal_account_data.version)?;
+                withdrawal_account_data.version = CURRENT_ACCOUNT_VERSION;
+                withdrawal_account_data.pack_into_slice(&mut account_info.data.borrow_mut());
+            }
+            AccountKind::MultiVault(_) => {
+                migrate_account::<MultiVault, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::TokenSettings(_, _) => {
+                migrate_account::<TokenSettings, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::TokenDecimals(_) => {
+                migrate_account::<TokenDecimals, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::ProposalIndex(_) => {
+                migrate_account::<ProposalIndex, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::FeeLedger(_) => {
+                migrate_account::<FeeLedger, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::RecipientHook(_) => {
+                migrate_account::<RecipientHook, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::PendingChange(_) => {
+                migrate_account::<PendingChange, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::Denylist(_) => {
+                migrate_account::<Denylist, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::RelayReward(_) => {
+                migrate_account::<RelayReward, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::BridgeStats(_) => {
+                migrate_account::<BridgeStats, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::LpRegistry(_) => {
+                migrate_account::<LpRegistry, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::Referral(_) => {
+                migrate_account::<Referral, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::ApprovalQueue(_) => {
+                migrate_account::<ApprovalQueue, _>(account_info, |data| &mut data.version)?
+            }
+            AccountKind::RelayRound(_)
+            | AccountKind::MerkleRelayRound(_)
+            | AccountKind::RelayEviction(_) => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    fn process_change_deposit_limit(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_deposit_limit: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        token_settings_account_data.deposit_limit = new_deposit_limit;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_min_deposit(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_min_deposit_amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        token_settings_account_data.min_deposit_amount = new_min_deposit_amount;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_fills_restricted(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        fills_restricted: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        token_settings_account_data.fills_restricted = fills_restricted;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_referral_fee(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        referral_fee_bps: u16,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if referral_fee_bps > MAX_REFERRAL_FEE_BPS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        token_settings_account_data.referral_fee_bps = referral_fee_bps;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_claim_referral_fee(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let referrer_account_info = next_account_info(account_info_iter)?;
+        let referral_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let mint_or_vault_account_info = next_account_info(account_info_iter)?;
+        let recipient_token_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+
+        if !referrer_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Referral Account
+        let mut referral_account_data = Referral::unpack(&referral_account_info.data.borrow())?;
+
+        let referral_nonce = referral_account_data
+            .account_kind
+            .into_referral()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_referral_account(
+            program_id,
+            token_settings_account_info.key,
+            referrer_account_info.key,
+            referral_nonce,
+            referral_account_info,
+        )?;
+
+        // Validate Token Settings Account
+        let token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_or_vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+
+                // Validate Mint Account
+                validate_mint_account(
+                    program_id,
+                    &token,
+                    mint_or_vault_nonce,
+                    mint_or_vault_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+
+                // Validate Vault Account
+                validate_vault_account(
+                    program_id,
+                    &mint,
+                    mint_or_vault_nonce,
+                    mint_or_vault_account_info,
+                )?;
+            }
+        }
+
+        if mint_or_vault_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Recipient Account
+        if recipient_token_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let amount = referral_account_data.amount;
+
+        if amount == 0 {
+            return Ok(());
+        }
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                let token_hash = hash(&token.try_to_vec()?);
+                let mint_account_signer_seeds: &[&[_]] =
+                    &[br"mint", token_hash.as_ref(), &[mint_or_vault_nonce]];
+
+                invoke_signed(
+                    &spl_token::instruction::mint_to(
+                        &spl_token::id(),
+                        mint_or_vault_account_info.key,
+                        recipient_token_account_info.key,
+                        mint_or_vault_account_info.key,
+                        &[mint_or_vault_account_info.key],
+                        amount,
+                    )?,
+                    accounts,
+                    &[mint_account_signer_seeds],
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                let vault_account_data =
+                    spl_token::state::Account::unpack(&mint_or_vault_account_info.data.borrow())?;
+
+                if amount > vault_account_data.amount {
+                    return Err(SolanaBridgeError::InsufficientVaultBalance.into());
+                }
+
+                let vault_account_signer_seeds: &[&[_]] =
+                    &[br"vault", &mint.to_bytes(), &[mint_or_vault_nonce]];
+
+                invoke_signed(
+                    &spl_token::instruction::transfer(
+                        &spl_token::id(),
+                        mint_or_vault_account_info.key,
+                        recipient_token_account_info.key,
+                        mint_or_vault_account_info.key,
+                        &[mint_or_vault_account_info.key],
+                        amount,
+                    )?,
+                    accounts,
+                    &[vault_account_signer_seeds],
+                )?;
+            }
+        }
+
+        referral_account_data.amount = 0;
+
+        Referral::pack(
+            referral_account_data,
+            &mut referral_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_create_approval_queue(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let approval_queue_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Approval Queue Account
+        let (approval_queue_pubkey, approval_queue_nonce) =
+            Pubkey::find_program_address(&[br"approval_queue"], program_id);
+
+        if approval_queue_pubkey != *approval_queue_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let approval_queue_account_signer_seeds: &[&[_]] =
+            &[br"approval_queue", &[approval_queue_nonce]];
+
+        if is_program_account(program_id, approval_queue_account_info) {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Lamports sent to its address don't prevent creation
+        create_pda_account(
+            funder_account_info,
+            rent,
+            ApprovalQueue::LEN,
+            program_id,
+            system_program_info,
+            approval_queue_account_info,
+            approval_queue_account_signer_seeds,
+        )?;
+
+        let approval_queue_account_data = ApprovalQueue {
+            is_initialized: true,
+            account_kind: AccountKind::ApprovalQueue(approval_queue_nonce),
+            approvals: vec![],
+            version: CURRENT_ACCOUNT_VERSION,
+        };
+
+        ApprovalQueue::pack(
+            approval_queue_account_data,
+            &mut approval_queue_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_reject_withdraw_ever(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        note: Option<Vec<u8>>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        let _approval_queue_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if *authority_account_info.key != settings_account_data.withdrawal_manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (_, token, _) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Check connection between token and proposal
+        if token != withdrawal_account_data.event.data.token {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+
+        let withdrawal_amount = to_solana_amount(
+            withdrawal_account_data.event.data.amount,
+            withdrawal_account_data.event.data.decimals,
+            mint_account_data.decimals,
+        )?;
+
+        let fee = token_settings_account_data
+            .withdrawal_fee(withdrawal_amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let transfer_withdrawal_amount = withdrawal_amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let epoch = withdrawal_account_data.meta.data.epoch;
+
+        withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Cancelled;
+
+        WithdrawalMultiTokenEver::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        let current_epoch =
+            token_settings_account_data.get_withdrawal_epoch(Clock::get()?.unix_timestamp);
+
+        // If withdrawal is in current epoch
+        if epoch == current_epoch {
+            // Return rejected amount to withdrawal daily limit
+            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
+                .withdrawal_daily_amount
+                .checked_sub(transfer_withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+        }
+
+        token_settings_account_data.track_withdrawal(
+            WithdrawalTokenStatus::WaitingForApprove,
+            WithdrawalTokenStatus::Cancelled,
+        );
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        update_bridge_stats(program_id, accounts, BridgeStats::record_closed_proposal)?;
+
+        // Keep reviewer note next to queued withdrawal
+        review_approval(
+            program_id,
+            accounts,
+            withdrawal_account_info.key,
+            WithdrawalTokenStatus::Cancelled,
+            note,
+        )?;
+
+        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+            account: *withdrawal_account_info.key,
+            status: WithdrawalTokenStatus::Cancelled,
+        }
+        .try_to_vec()?]);
+
+        Ok(())
+    }
+
+    fn process_reject_withdraw_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        note: Option<Vec<u8>>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        let _approval_queue_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if *authority_account_info.key != settings_account_data.withdrawal_manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        // Validate Mint Account
+        if *mint_account_info.key != mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Check connection between token and proposal
+        if mint != withdrawal_account_data.event.data.mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let withdrawal_amount = withdrawal_account_data.event.data.amount as u64;
+
+        let fee = token_settings_account_data
+            .withdrawal_fee(withdrawal_amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Part covered by fillers was not counted in withdrawal daily amount
+        let filled_amount = withdrawal_account_data.meta.data.filled_amount;
+        let filled_fee = pro_rata_part(fee, 0, filled_amount, withdrawal_amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let transfer_withdrawal_amount = withdrawal_amount
+            .checked_sub(fee)
+            .and_then(|amount| amount.checked_sub(filled_amount - filled_fee))
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let epoch = withdrawal_account_data.meta.data.epoch;
+
+        withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Cancelled;
+        // Author can still return tokens with CancelWithdrawSol
+        withdrawal_account_data.meta.data.rejected = true;
+
+        WithdrawalMultiTokenSol::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        let current_epoch =
+            token_settings_account_data.get_withdrawal_epoch(Clock::get()?.unix_timestamp);
+
+        // If withdrawal is in current epoch
+        if epoch == current_epoch {
+            // Return rejected amount to withdrawal daily limit
+            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
+                .withdrawal_daily_amount
+                .checked_sub(transfer_withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+        }
+
+        token_settings_account_data.track_withdrawal(
+            WithdrawalTokenStatus::WaitingForApprove,
+            WithdrawalTokenStatus::Cancelled,
+        );
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        update_bridge_stats(program_id, accounts, BridgeStats::record_closed_proposal)?;
+
+        // Keep reviewer note next to queued withdrawal
+        review_approval(
+            program_id,
+            accounts,
+            withdrawal_account_info.key,
+            WithdrawalTokenStatus::Cancelled,
+            note,
+        )?;
+
+        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+            account: *withdrawal_account_info.key,
+            status: WithdrawalTokenStatus::Cancelled,
+        }
+        .try_to_vec()?]);
+
+        Ok(())
+    }
+
+    fn process_change_deposit_daily_limit(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_deposit_daily_limit: Option<u64>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        token_settings_account_data.deposit_daily_limit = new_deposit_daily_limit;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_withdrawal_epoch_length(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_withdrawal_epoch_length: Option<i64>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if matches!(new_withdrawal_epoch_length, Some(length) if length <= 0) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        // Amount counted in an epoch of the old length doesn't carry over to the new one
+        if token_settings_account_data.withdrawal_epoch_length != new_withdrawal_epoch_length {
+            token_settings_account_data.withdrawal_epoch = Default::default();
+            token_settings_account_data.withdrawal_daily_amount = Default::default();
+        }
+
+        token_settings_account_data.withdrawal_epoch_length = new_withdrawal_epoch_length;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_withdrawal_limits(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_withdrawal_limit: Option<u64>,
+        new_withdrawal_daily_limit: Option<u64>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        set_withdrawal_limits(
+            &mut token_settings_account_data,
+            new_withdrawal_limit,
+            new_withdrawal_daily_limit,
+        );
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_enable_emergency_mode(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        expiry: Option<i64>,
+        reason: Option<u32>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        let guardian = settings_account_data.guardian;
+
+        // Validate Guardian Account
+        if *authority_account_info.key != guardian {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        let clock = Clock::get()?;
+
+        // Emergency that has already expired would never take effect
+        if matches!(expiry, Some(expiry) if expiry <= clock.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyExpiryInPast.into());
+        }
+
+        settings_account_data.emergency = true;
+        settings_account_data.emergency_expiry = expiry;
+        settings_account_data.emergency_info = Some(EmergencyInfo {
+            authority: *authority_account_info.key,
+            timestamp: clock.unix_timestamp,
+            reason,
+        });
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_disable_emergency_mode(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        reason: Option<u32>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
+
+        settings_account_data.emergency = false;
+        settings_account_data.emergency_expiry = None;
+        settings_account_data.emergency_info = Some(EmergencyInfo {
+            authority: *authority_account_info.key,
+            timestamp: Clock::get()?.unix_timestamp,
+            reason,
+        });
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_enable_token_emergency_mode(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        expiry: Option<i64>,
+        reason: Option<u32>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        let guardian = settings_account_data.guardian;
+
+        // Validate Guardian Account
+        if *authority_account_info.key != guardian {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        let clock = Clock::get()?;
+
+        // Emergency that has already expired would never take effect
+        if matches!(expiry, Some(expiry) if expiry <= clock.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyExpiryInPast.into());
+        }
+
+        token_settings_account_data.emergency = true;
+        token_settings_account_data.emergency_expiry = expiry;
+        token_settings_account_data.emergency_info = Some(EmergencyInfo {
+            authority: *authority_account_info.key,
+            timestamp: clock.unix_timestamp,
+            reason,
+        });
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_disable_token_emergency_mode(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        reason: Option<u32>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        token_settings_account_data.emergency = false;
+        token_settings_account_data.emergency_expiry = None;
+        token_settings_account_data.emergency_info = Some(EmergencyInfo {
+            authority: *authority_account_info.key,
+            timestamp: Clock::get()?.unix_timestamp,
+            reason,
+        });
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_approve_withdraw_ever(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        note: Option<Vec<u8>>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let recipient_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _recipient_hook_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        let _approval_queue_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if !settings_account_data.is_chain_enabled(withdrawal_account_data.chain_id) {
+            return Err(SolanaBridgeError::ChainDisabled.into());
+        }
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (_, token, _) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::WithdrawalsPaused.into());
+        }
+
+        if token_settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::TokenWithdrawalsPaused.into());
+        }
+
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+        // Check connection between token and proposal
+        if token != withdrawal_account_data.event.data.token {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if *authority_account_info.key != settings_account_data.withdrawal_manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+        let solana_decimals = mint_account_data.decimals;
+        let ever_decimals = withdrawal_account_data.event.data.decimals;
+
+        let withdrawal_amount = to_solana_amount(
+            withdrawal_account_data.event.data.amount,
+            ever_decimals,
+            solana_decimals,
+        )?;
+
+        let fee = token_settings_account_data
+            .withdrawal_fee(withdrawal_amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Amount without fee
+        let transfer_withdrawal_amount = withdrawal_amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        match withdrawal_account_data.event.data.payload.is_empty() {
+            true => {
+                // Validate Recipient Account
+                let recipient_account_data =
+                    spl_token::state::Account::unpack(&recipient_account_info.data.borrow())?;
+
+                if recipient_account_data.owner != withdrawal_account_data.event.data.recipient {
+                    return Err(ProgramError::InvalidArgument);
+                }
+
+                make_ever_transfer(
+                    mint_account_info,
+                    recipient_account_info,
+                    &token_settings_account_data,
+                    accounts,
+                    transfer_withdrawal_amount,
+                )?;
+
+                update_bridge_stats(program_id, accounts, |stats| {
+                    stats.record_withdrawal(transfer_withdrawal_amount)
+                })?;
+
+                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+            }
+            false => {
+                // Validate Proxy Account
+                let proxy_nonce = withdrawal_account_data
+                    .account_kind
+                    .into_proposal()
+                    .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                    .1
+                    .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                validate_proxy_account(
+                    program_id,
+                    mint_account_info.key,
+                    &withdrawal_account_data.event.data.recipient,
+                    proxy_nonce,
+                    recipient_account_info,
+                )?;
+
+                make_ever_transfer(
+                    mint_account_info,
+                    recipient_account_info,
+                    &token_settings_account_data,
+                    accounts,
+                    transfer_withdrawal_amount,
+                )?;
+
+                update_bridge_stats(program_id, accounts, |stats| {
+                    stats.record_withdrawal(transfer_withdrawal_amount)
+                })?;
+
+                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForExecute;
+            }
+        }
+
+        let current_epoch = token_settings_account_data.get_withdrawal_epoch(clock.unix_timestamp);
+
+        // If withdrawal is in current epoch
+        if withdrawal_account_data.meta.data.epoch == current_epoch {
+            // Decrease withdrawal daily amount
+            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
+                .withdrawal_daily_amount
+                .checked_sub(transfer_withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+        }
+
+        token_settings_account_data.track_withdrawal(
+            WithdrawalTokenStatus::WaitingForApprove,
+            withdrawal_account_data.meta.data.status,
+        );
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        // Keep reviewer note next to queued withdrawal
+        review_approval(
+            program_id,
+            accounts,
+            withdrawal_account_info.key,
+            withdrawal_account_data.meta.data.status,
+            note,
+        )?;
+
+        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+            account: withdrawal_pubkey,
+            status: withdrawal_account_data.meta.data.status,
+        }
+        .try_to_vec()?]);
+
+        let status = withdrawal_account_data.meta.data.status;
+        let recipient = withdrawal_account_data.event.data.recipient;
+
+        WithdrawalMultiTokenEver::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        // Notify recipient program
+        if status == WithdrawalTokenStatus::Processed {
+            notify_recipient(
+                program_id,
+                accounts,
+                &recipient,
+                recipient_account_info,
+                withdrawal_account_info,
+                mint_account_info.key,
+                transfer_withdrawal_amount,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_approve_withdraw_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        note: Option<Vec<u8>>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let vault_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let recipient_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _recipient_hook_account_info = next_account_info(account_info_iter)?;
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+        let _approval_queue_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if !settings_account_data.is_chain_enabled(withdrawal_account_data.chain_id) {
+            return Err(SolanaBridgeError::ChainDisabled.into());
+        }
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        if *authority_account_info.key != settings_account_data.withdrawal_manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::WithdrawalsPaused.into());
+        }
+
+        if token_settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::TokenWithdrawalsPaused.into());
+        }
+
+        // Validate Vault Account
+        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
+
+        // Check connection between token and proposal
+        if mint != withdrawal_account_data.event.data.mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let withdrawal_amount = withdrawal_account_data.event.data.amount as u64;
+
+        let fee = token_settings_account_data
+            .withdrawal_fee(withdrawal_amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Amount without fee
+        let transfer_withdrawal_amount = withdrawal_amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        match withdrawal_account_data.event.data.payload.is_empty() {
+            true => {
+                // Validate Recipient Account
+                let recipient_account_data =
+                    spl_token::state::Account::unpack(&recipient_account_info.data.borrow())?;
+
+                if recipient_account_data.owner != withdrawal_account_data.event.data.recipient {
+                    return Err(ProgramError::InvalidArgument);
+                }
+
+                let vault_account_data =
+                    spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+
+                if transfer_withdrawal_amount > vault_account_data.amount {
+                    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
+                } else {
+                    make_sol_transfer(
+                        vault_account_info,
+                        recipient_account_info,
+                        &token_settings_account_data,
+                        accounts,
+                        transfer_withdrawal_amount,
+                    )?;
+
+                    update_bridge_stats(program_id, accounts, |stats| {
+                        stats.record_withdrawal(transfer_withdrawal_amount)
+                    })?;
+
+                    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+                }
+            }
+            false => {
+                // Validate Proxy Account
+                let proxy_nonce = withdrawal_account_data
+                    .account_kind
+                    .into_proposal()
+                    .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                    .1
+                    .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                validate_proxy_account(
+                    program_id,
+                    &mint,
+                    &withdrawal_account_data.event.data.recipient,
+                    proxy_nonce,
+                    recipient_account_info,
+                )?;
+
+                let vault_account_data =
+                    spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+
+                if transfer_withdrawal_amount > vault_account_data.amount {
+                    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
+                } else {
+                    make_sol_transfer(
+                        vault_account_info,
+                        recipient_account_info,
+                        &token_settings_account_data,
+                        accounts,
+                        transfer_withdrawal_amount,
+                    )?;
+
+                    update_bridge_stats(program_id, accounts, |stats| {
+                        stats.record_withdrawal(transfer_withdrawal_amount)
+                    })?;
+
+                    withdrawal_account_data.meta.data.status =
+                        WithdrawalTokenStatus::WaitingForExecute;
+                }
+            }
+        };
+
+        let current_epoch = token_settings_account_data.get_withdrawal_epoch(clock.unix_timestamp);
+
+        // If withdrawal is in current epoch
+        if withdrawal_account_data.meta.data.epoch == current_epoch {
+            // Decrease withdrawal daily amount
+            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
+                .withdrawal_daily_amount
+                .checked_sub(transfer_withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+        }
+
+        token_settings_account_data.track_withdrawal(
+            WithdrawalTokenStatus::WaitingForApprove,
+            withdrawal_account_data.meta.data.status,
+        );
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        // Keep reviewer note next to queued withdrawal
+        review_approval(
+            program_id,
+            accounts,
+            withdrawal_account_info.key,
+            withdrawal_account_data.meta.data.status,
+            note,
+        )?;
+
+        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+            account: *withdrawal_account_info.key,
+            status: withdrawal_account_data.meta.data.status,
+        }
+        .try_to_vec()?]);
+
+        let status = withdrawal_account_data.meta.data.status;
+        let recipient = withdrawal_account_data.event.data.recipient;
+
+        WithdrawalMultiTokenSol::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        // Notify recipient program
+        if status == WithdrawalTokenStatus::Processed {
+            notify_recipient(
+                program_id,
+                accounts,
+                &recipient,
+                recipient_account_info,
+                withdrawal_account_info,
+                &mint,
+                transfer_withdrawal_amount,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_approve_withdraw_ever_to_many(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.is_emergency(clock.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if *authority_account_info.key != settings_account_data.withdrawal_manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Recipient token accounts in the order of event recipients
+        let recipient_account_infos = account_info_iter.as_slice();
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenEverToMany::unpack(&withdrawal_account_info.data.borrow())?;
+
+        let withdrawal_pubkey = validate_withdrawal_ever_to_many_account(
+            program_id,
+            &withdrawal_account_data,
+            withdrawal_account_info,
+        )?;
+
+        if !settings_account_data.is_chain_enabled(withdrawal_account_data.chain_id) {
+            return Err(SolanaBridgeError::ChainDisabled.into());
+        }
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (_, token, _) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(clock.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::WithdrawalsPaused.into());
+        }
+
+        if token_settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::TokenWithdrawalsPaused.into());
+        }
+
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+        // Check connection between token and proposal
+        if token != withdrawal_account_data.event.data.token {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+
+        let (transfer_amounts, _) = get_ever_to_many_transfer_amounts(
+            &withdrawal_account_data.event.data,
+            &token_settings_account_data,
+            mint_account_data.decimals,
+        )?;
+
+        make_ever_transfers_to_many(
+            mint_account_info,
+            recipient_account_infos,
+            &token_settings_account_data,
+            accounts,
+            &withdrawal_account_data.event.data.recipients,
+            &transfer_amounts,
+        )?;
+
+        let total_transfer_amount = transfer_amounts
+            .iter()
+            .try_fold(0u64, |total, amount| total.checked_add(*amount))
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        update_bridge_stats(program_id, accounts, |stats| {
+            stats.record_withdrawal(total_transfer_amount)
+        })?;
+
+        withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+
+        let current_epoch = token_settings_account_data.get_withdrawal_epoch(clock.unix_timestamp);
+
+        // If withdrawal is in current epoch
+        if withdrawal_account_data.meta.data.epoch == current_epoch {
+            let total_transfer_amount = transfer_amounts
+                .iter()
+                .try_fold(0u64, |total, amount| total.checked_add(*amount))
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Decrease withdrawal daily amount
+            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
+                .withdrawal_daily_amount
+                .checked_sub(total_transfer_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+        }
+
+        token_settings_account_data.track_withdrawal(
+            WithdrawalTokenStatus::WaitingForApprove,
+            withdrawal_account_data.meta.data.status,
+        );
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+            account: withdrawal_pubkey,
+            status: withdrawal_account_data.meta.data.status,
+        }
+        .try_to_vec()?]);
+
+        WithdrawalMultiTokenEverToMany::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_update_fee(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        fee_type: FeeType,
+        multiplier: u64,
+        divisor: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let token_settings_pubkey = match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => validate_token_settings_ever_account(
+                program_id,
+                &token,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+            TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
+                program_id,
+                &mint,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+        };
+
+        set_fee(
+            token_settings_pubkey,
+            &mut token_settings_account_data,
+            fee_type,
+            multiplier,
+            divisor,
+        )?;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_update_fee_tiers(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        fee_type: FeeType,
+        fee_tiers: FeeTiers,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check tiers length
+        if fee_tiers.tiers.len() > MAX_FEE_TIERS_LEN {
+            return Err(SolanaBridgeError::FeeTiersLenLimit.into());
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let token_settings_pubkey = match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => validate_token_settings_ever_account(
+                program_id,
+                &token,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+            TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
+                program_id,
+                &mint,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+        };
+
+        set_fee_tiers(
+            token_settings_pubkey,
+            &mut token_settings_account_data,
+            fee_type,
+            fee_tiers,
+        )?;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_vote_reward(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        vote_reward: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => validate_token_settings_ever_account(
+                program_id,
+                &token,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+            TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
+                program_id,
+                &mint,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+        };
+
+        token_settings_account_data.vote_reward = vote_reward;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_withdrawal_limit_exemptions(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_withdrawal_limit_exemptions: Vec<Pubkey>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check exemptions length
+        if new_withdrawal_limit_exemptions.len() > MAX_WITHDRAWAL_LIMIT_EXEMPTIONS_LEN {
+            return Err(SolanaBridgeError::WithdrawalLimitExemptionsLenLimit.into());
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => validate_token_settings_ever_account(
+                program_id,
+                &token,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+            TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
+                program_id,
+                &mint,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+        };
+
+        token_settings_account_data.withdrawal_limit_exemptions = new_withdrawal_limit_exemptions;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_set_recipient_hook(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        hook_program_id: Pubkey,
+        data: Vec<u8>,
+        hook_accounts: Vec<Pubkey>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let recipient_account_info = next_account_info(account_info_iter)?;
+        let recipient_hook_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !funder_account_info.is_signer || !recipient_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check hook length
+        if data.len() > MAX_RECIPIENT_HOOK_DATA_LEN
+            || hook_accounts.len() > MAX_RECIPIENT_HOOK_ACCOUNTS
+        {
+            return Err(SolanaBridgeError::RecipientHookLenLimit.into());
+        }
+
+        // Bridge must not call itself back
+        if hook_program_id == *program_id {
+            return Err(SolanaBridgeError::RecipientHookProgramNotAllowed.into());
+        }
+
+        // If recipient hook account is not created
+        if recipient_hook_account_info.lamports() == 0 {
+            let (recipient_hook_pubkey, recipient_hook_nonce) = Pubkey::find_program_address(
+                &[br"hook", &recipient_account_info.key.to_bytes()],
+                program_id,
+            );
+            let recipient_hook_account_signer_seeds: &[&[_]] = &[
+                br"hook",
+                &recipient_account_info.key.to_bytes(),
+                &[recipient_hook_nonce],
+            ];
+
+            if recipient_hook_pubkey != *recipient_hook_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            invoke_signed(
+                &system_instruction::create_account(
+                    funder_account_info.key,
+                    recipient_hook_account_info.key,
+                    1.max(rent.minimum_balance(RecipientHook::LEN)),
+                    RecipientHook::LEN as u64,
+                    program_id,
+                ),
+                accounts,
+                &[recipient_hook_account_signer_seeds],
+            )?;
+
+            let recipient_hook_account_data = RecipientHook {
+                is_initialized: true,
+                account_kind: AccountKind::RecipientHook(recipient_hook_nonce),
+                recipient: *recipient_account_info.key,
+                program_id: hook_program_id,
+                data,
+                accounts: hook_accounts,
+                version: CURRENT_ACCOUNT_VERSION,
+            };
+
+            RecipientHook::pack(
+                recipient_hook_account_data,
+                &mut recipient_hook_account_info.data.borrow_mut(),
+            )?;
+        } else {
+            // Validate Recipient Hook Account
+            let mut recipient_hook_account_data =
+                RecipientHook::unpack(&recipient_hook_account_info.data.borrow())?;
+
+            let recipient_hook_nonce = recipient_hook_account_data
+                .account_kind
+                .into_recipient_hook()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            validate_recipient_hook_account(
+                program_id,
+                recipient_account_info.key,
+                recipient_hook_nonce,
+                recipient_hook_account_info,
+            )?;
+
+            recipient_hook_account_data.program_id = hook_program_id;
+            recipient_hook_account_data.data = data;
+            recipient_hook_account_data.accounts = hook_accounts;
+
+            RecipientHook::pack(
+                recipient_hook_account_data,
+                &mut recipient_hook_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_remove_recipient_hook(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let recipient_account_info = next_account_info(account_info_iter)?;
+        let recipient_hook_account_info = next_account_info(account_info_iter)?;
+        let funder_account_info = next_account_info(account_info_iter)?;
+
+        if !recipient_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Recipient Hook Account
+        let recipient_hook_account_data =
+            RecipientHook::unpack(&recipient_hook_account_info.data.borrow())?;
+
+        let recipient_hook_nonce = recipient_hook_account_data
+            .account_kind
+            .into_recipient_hook()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_recipient_hook_account(
+            program_id,
+            recipient_account_info.key,
+            recipient_hook_nonce,
+            recipient_hook_account_info,
+        )?;
+
+        delete_account(recipient_hook_account_info, funder_account_info)?;
+
+        Ok(())
+    }
+
+    fn process_add_to_denylist(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        address: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let denylist_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !funder_account_info.is_signer || !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Guardian Account
+        if *authority_account_info.key != settings_account_data.guardian
+            && !settings_account_data
+                .guardians
+                .contains(authority_account_info.key)
+        {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // If denylist account is not created
+        if !is_program_account(program_id, denylist_account_info) {
+            let (denylist_pubkey, denylist_nonce) =
+                Pubkey::find_program_address(&[br"denylist"], program_id);
+            let denylist_account_signer_seeds: &[&[_]] = &[br"denylist", &[denylist_nonce]];
+
+            if denylist_pubkey != *denylist_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            // Lamports sent to its address don't prevent creation
+            create_pda_account(
+                funder_account_info,
+                rent,
+                Denylist::LEN,
+                program_id,
+                system_program_info,
+                denylist_account_info,
+                denylist_account_signer_seeds,
+            )?;
+
+            let denylist_account_data = Denylist {
+                is_initialized: true,
+                account_kind: AccountKind::Denylist(denylist_nonce),
+                addresses: vec![address],
+                version: CURRENT_ACCOUNT_VERSION,
+            };
+
+            Denylist::pack(
+                denylist_account_data,
+                &mut denylist_account_info.data.borrow_mut(),
+            )?;
+        } else {
+            // Validate Denylist Account
+            let mut denylist_account_data = Denylist::unpack(&denylist_account_info.data.borrow())?;
+
+            let denylist_nonce = denylist_account_data
+                .account_kind
+                .into_denylist()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            validate_denylist_account(program_id, denylist_nonce, denylist_account_info)?;
+
+            if !denylist_account_data.addresses.contains(&address) {
+                if denylist_account_data.addresses.len() >= MAX_DENYLIST_LEN {
+                    return Err(SolanaBridgeError::DenylistLenLimit.into());
+                }
+
+                denylist_account_data.addresses.push(address);
+            }
+
+            Denylist::pack(
+                denylist_account_data,
+                &mut denylist_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn process_remove_from_denylist(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        address: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let denylist_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Guardian Account
+        if *authority_account_info.key != settings_account_data.guardian
+            && !settings_account_data
+                .guardians
+                .contains(authority_account_info.key)
+        {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Denylist Account
+        let mut denylist_account_data = Denylist::unpack(&denylist_account_info.data.borrow())?;
+
+        let denylist_nonce = denylist_account_data
+            .account_kind
+            .into_denylist()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_denylist_account(program_id, denylist_nonce, denylist_account_info)?;
+
+        denylist_account_data
+            .addresses
+            .retain(|denied| *denied != address);
+
+        Denylist::pack(
+            denylist_account_data,
+            &mut denylist_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_create_token_metadata(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        uri: String,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let metadata_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_metadata_program_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let _rent_sysvar_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer || !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (_, token, _) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+        // Validate Metadata Account
+        if get_associated_token_metadata_address(mint_account_info.key)
+            != *metadata_account_info.key
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Create Metadata Account. Mint is both mint and update authority
+        let token_hash = hash(&token.try_to_vec()?);
+
+        invoke_signed(
+            &create_metadata_account_v3_ix(
+                metadata_account_info.key,
+                mint_account_info.key,
+                mint_account_info.key,
+                funder_account_info.key,
+                mint_account_info.key,
+                token_settings_account_data.name,
+                token_settings_account_data.symbol,
+                uri,
+            ),
+            accounts,
+            &[&[br"mint", token_hash.as_ref(), &[mint_nonce]]],
+        )?;
+
+        Ok(())
+    }
+
+    fn process_token_name(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        symbol: String,
+        name: String,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let token_settings_pubkey = match token_settings_account_data.kind {
+            TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
+                program_id,
+                &mint,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?,
+            TokenKind::Ever { .. } => return Err(SolanaBridgeError::InvalidTokenKind.into()),
+        };
+
+        token_settings_account_data.symbol = symbol.clone();
+        token_settings_account_data.name = name.clone();
+
+        solana_program::log::sol_log_data(&[&UpdateTokenNameEvent {
+            token_settings: token_settings_pubkey,
+            symbol,
+            name,
+        }
+        .try_to_vec()?]);
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_withdraw_ever_fee(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let recipient_token_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let fee_ledger_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Anyone can send fee to treasury
+        if !is_treasury_token_account(&settings_account_data, recipient_token_account_info) {
+            if !authority_account_info.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            // Validate Manager Account
+            if *authority_account_info.key != settings_account_data.manager {
+                let programdata_account_info = next_account_info(account_info_iter)?;
+
+                // Validate Initializer Account
+                bridge_utils::helper::validate_programdata_account(
+                    program_id,
+                    programdata_nonce,
+                    programdata_account_info.key,
+                )?;
+                bridge_utils::helper::validate_initializer_account(
+                    authority_account_info.key,
+                    programdata_account_info,
+                )?;
+
+                // Owner changes go through timelock
+                if settings_account_data.timelock_delay != 0 {
+                    return Err(SolanaBridgeError::TimelockEnabled.into());
+                }
+            }
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (_, token, _) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+        if mint_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Recipient Account
+        if recipient_token_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Decrease fee supply
+        decrease_fee_supply(
+            program_id,
+            token_settings_account_info,
+            &mut token_settings_account_data,
+            fee_ledger_account_info,
+            amount,
+        )?;
+
+        let token_hash = hash(&token.try_to_vec()?);
+        let mint_account_signer_seeds: &[&[_]] = &[br"mint", token_hash.as_ref(), &[mint_nonce]];
+
+        invoke_signed(
+            &spl_token::instruction::mint_to(
+                &spl_token::id(),
+                mint_account_info.key,
+                recipient_token_account_info.key,
+                mint_account_info.key,
+                &[mint_account_info.key],
+                amount,
+            )?,
+            accounts,
+            &[mint_account_signer_seeds],
+        )?;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_withdraw_sol_fee(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let vault_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let fee_ledger_account_info = next_account_info(account_info_iter)?;
+        let recipient_token_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Anyone can send fee to treasury
+        if !is_treasury_token_account(&settings_account_data, recipient_token_account_info) {
+            if !authority_account_info.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            // Validate Manager Account
+            if *authority_account_info.key != settings_account_data.manager {
+                let programdata_account_info = next_account_info(account_info_iter)?;
+
+                // Validate Initializer Account
+                bridge_utils::helper::validate_programdata_account(
+                    program_id,
+                    programdata_nonce,
+                    programdata_account_info.key,
+                )?;
+                bridge_utils::helper::validate_initializer_account(
+                    authority_account_info.key,
+                    programdata_account_info,
+                )?;
+
+                // Owner changes go through timelock
+                if settings_account_data.timelock_delay != 0 {
+                    return Err(SolanaBridgeError::TimelockEnabled.into());
+                }
+            }
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        let (token_settings_nonce, vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        // Validate Vault Account
+        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
+
+        if vault_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Recipient Account
+        if recipient_token_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Decrease fee supply
+        decrease_fee_supply(
+            program_id,
+            token_settings_account_info,
+            &mut token_settings_account_data,
+            fee_ledger_account_info,
+            amount,
+        )?;
+
+        let vault_account_data =
+            spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+
+        if amount > vault_account_data.amount {
+            return Err(SolanaBridgeError::InsufficientVaultBalance.into());
+        }
+
+        let vault_account_signer_seeds: &[&[_]] = &[br"vault", &mint.to_bytes(), &[vault_nonce]];
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                &spl_token::id(),
+                vault_account_info.key,
+                recipient_token_account_info.key,
+                vault_account_info.key,
+                &[vault_account_info.key],
+                amount,
+            )?,
+            accounts,
+            &[vault_account_signer_seeds],
+        )?;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_claim_vote_rewards(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let relay_account_info = next_account_info(account_info_iter)?;
+        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let fee_ledger_account_info = next_account_info(account_info_iter)?;
+        let mint_or_vault_account_info = next_account_info(account_info_iter)?;
+        let recipient_token_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+
+        if !relay_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Relay Round Account
+        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+        let relay_round_nonce = relay_round_account_data
+            .account_kind
+            .into_relay_round()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        round_loader::validate_relay_round_account(
+            &round_loader::id(),
+            relay_round_account_data.round_number,
+            relay_round_nonce,
+            relay_round_account_info,
+        )?;
+
+        let index = relay_round_account_data
+            .relays
+            .iter()
+            .position(|pubkey| pubkey == relay_account_info.key)
+            .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, mint_or_vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+
+                // Validate Mint Account
+                validate_mint_account(
+                    program_id,
+                    &token,
+                    mint_or_vault_nonce,
+                    mint_or_vault_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+
+                // Validate Vault Account
+                validate_vault_account(
+                    program_id,
+                    &mint,
+                    mint_or_vault_nonce,
+                    mint_or_vault_account_info,
+                )?;
+            }
+        }
+
+        if mint_or_vault_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Recipient Account
+        if recipient_token_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Collect rewards from processed withdrawals
+        let mut rewards: u64 = 0;
+
+        for withdrawal_account_info in account_info_iter {
+            let mut withdrawal_account_data =
+                Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+            let round_number = withdrawal_account_data.round_number;
+            let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+            let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+            let event_configuration = withdrawal_account_data.pda.event_configuration;
+            let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+            let (nonce, _) = withdrawal_account_data
+                .account_kind
+                .into_proposal()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            bridge_utils::helper::validate_proposal_account(
+                program_id,
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                withdrawal_account_data.chain_id,
+                &event_data,
+                nonce,
+                withdrawal_account_info,
+            )?;
+
+            if round_number != relay_round_account_data.round_number {
+                return Err(SolanaBridgeError::InvalidRelayRound.into());
+            }
+
+            let meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
+            if meta.status != WithdrawalTokenStatus::Processed {
+                return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+            }
+
+            // Validate withdrawal token
+            let is_same_token = match token_settings_account_data.kind {
+                TokenKind::Ever { token, .. } => {
+                    let data = withdrawal_account_info.data.borrow();
+
+                    // Withdrawals to many recipients are the only ones of their size
+                    let withdrawal_token = if data.len() == WithdrawalMultiTokenEverToMany::LEN {
+                        WithdrawalMultiTokenEverToMany::unpack(&data)?
+                            .event
+                            .data
+                            .token
+                    } else {
+                        WithdrawalMultiTokenEver::unpack(&data)?.event.data.token
+                    };
+
+                    withdrawal_token == token
+                }
+                TokenKind::Solana { mint, .. } => {
+                    let withdrawal_account_data =
+                        WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+                    withdrawal_account_data.event.data.mint == mint
+                }
+            };
+
+            if !is_same_token {
+                return Err(SolanaBridgeError::InvalidTokenKind.into());
+            }
+
+            if withdrawal_account_data.vote_rewards.get(index) == Some(&VoteReward::Unclaimed) {
+                withdrawal_account_data.vote_rewards[index] = VoteReward::Claimed;
+                withdrawal_account_data
+                    .pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+
+                rewards = rewards
+                    .checked_add(token_settings_account_data.vote_reward)
+                    .ok_or(SolanaBridgeError::Overflow)?;
+            }
+        }
+
+        if rewards == 0 {
+            return Ok(());
+        }
+
+        // Decrease fee supply
+        decrease_fee_supply(
+            program_id,
+            token_settings_account_info,
+            &mut token_settings_account_data,
+            fee_ledger_account_info,
+            rewards,
+        )?;
+
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                let token_hash = hash(&token.try_to_vec()?);
+                let mint_account_signer_seeds: &[&[_]] =
+                    &[br"mint", token_hash.as_ref(), &[mint_or_vault_nonce]];
+
+                invoke_signed(
+                    &spl_token::instruction::mint_to(
+                        &spl_token::id(),
+                        mint_or_vault_account_info.key,
+                        recipient_token_account_info.key,
+                        mint_or_vault_account_info.key,
+                        &[mint_or_vault_account_info.key],
+                        rewards,
+                    )?,
+                    accounts,
+                    &[mint_account_signer_seeds],
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                let vault_account_data =
+                    spl_token::state::Account::unpack(&mint_or_vault_account_info.data.borrow())?;
+
+                if rewards > vault_account_data.amount {
+                    return Err(SolanaBridgeError::InsufficientVaultBalance.into());
+                }
+
+                let vault_account_signer_seeds: &[&[_]] =
+                    &[br"vault", &mint.to_bytes(), &[mint_or_vault_nonce]];
+
+                invoke_signed(
+                    &spl_token::instruction::transfer(
+                        &spl_token::id(),
+                        mint_or_vault_account_info.key,
+                        recipient_token_account_info.key,
+                        mint_or_vault_account_info.key,
+                        &[mint_or_vault_account_info.key],
+                        rewards,
+                    )?,
+                    accounts,
+                    &[vault_account_signer_seeds],
+                )?;
+            }
+        }
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_change_bounty_for_withdraw_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        bounty: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let author_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        if withdrawal_account_data.author != *author_account_info.key
+            && withdrawal_account_data.event.data.recipient != *author_account_info.key
+        {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        // Bounty is already split between fillers of the covered part
+        if withdrawal_account_data.meta.data.filled_amount != 0 {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        withdrawal_account_data.meta.data.bounty = bounty;
+
+        WithdrawalMultiTokenSol::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_cancel_withdraw_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        deposit_seed: u128,
+        recipient: EverAddress,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let author_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let deposit_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let multi_vault_account_info = next_account_info(account_info_iter)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Deposit Value
+        if let Some(deposit_value_floor) = &settings_account_data.deposit_value_floor {
+            validate_deposit_value(deposit_value_floor, value, &expected_evers)?;
+        }
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if withdrawal_account_data.author != *author_account_info.key {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        // Proposal rejected by withdrawal manager is already cancelled but not returned yet
+        let rejected = withdrawal_account_data.meta.data.rejected;
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending
+            && !(rejected
+                && withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::Cancelled)
+        {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Multi Vault Account
+        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+        let multi_vault_nonce = multi_vault_account_data
+            .account_kind
+            .into_multi_vault()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+        // Send sol amount to multi vault
+        invoke(
+            &system_instruction::transfer(
+                funder_account_info.key,
+                multi_vault_account_info.key,
+                value,
+            ),
+            accounts,
+        )?;
+
+        // Validate Mint Account
+        if *mint_account_info.key != mint && mint_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+        let decimals = mint_account_data.decimals;
+
+        // Check connection between token and proposal
+        if mint != withdrawal_account_data.event.data.mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Create Deposit Account
+        let (deposit_pubkey, deposit_nonce) =
+            Pubkey::find_program_address(&[br"deposit", &deposit_seed.to_le_bytes()], program_id);
+        let deposit_account_signer_seeds: &[&[_]] =
+            &[br"deposit", &deposit_seed.to_le_bytes(), &[deposit_nonce]];
+
+        if deposit_pubkey != *deposit_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                deposit_account_info.key,
+                1.max(rent.minimum_balance(DepositMultiTokenSol::LEN)),
+                DepositMultiTokenSol::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[deposit_account_signer_seeds],
+        )?;
+
+        // Init Deposit Account
+        let name = token_settings_account_data.name.clone();
+        let symbol = token_settings_account_data.symbol.clone();
+        // Only the part not covered by fillers is returned
+        let amount = withdrawal_account_data
+            .event
+            .data
+            .amount
+            .checked_sub(withdrawal_account_data.meta.data.filled_amount as u128)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Calculate fee
+        let fee = token_settings_account_data
+            .deposit_fee(amount.try_into().map_err(|_| SolanaBridgeError::Overflow)?)
+            .ok_or(SolanaBridgeError::Overflow)? as u128;
+
+        // Increase fee supply
+        increase_fee_supply(
+            program_id,
+            accounts,
+            token_settings_account_info,
+            &mut token_settings_account_data,
+            fee.try_into().map_err(|_| SolanaBridgeError::Overflow)?,
+        )?;
+
+        // Amount without fee
+        let transfer_amount = amount.checked_sub(fee).ok_or(SolanaBridgeError::Overflow)?;
+
+        let deposit_account_data = DepositMultiTokenSol {
+            is_initialized: true,
+            account_kind: AccountKind::Deposit(deposit_nonce),
+            author: *author_account_info.key,
+            event: DepositMultiTokenSolEventWithLen::new(
+                mint,
+                name,
+                symbol,
+                decimals,
+                transfer_amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                fee,
+                amount,
+                None,
+            ),
+            meta: DepositTokenMetaWithLen::new(
+                deposit_seed,
+                *funder_account_info.key,
+                Clock::get()?.slot,
+            ),
+            version: CURRENT_ACCOUNT_VERSION,
+        };
+
+        let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
+            .to_bytes()
+            .to_vec();
+
+        solana_program::log::sol_log_data(&[&DepositMultiTokenEvent {
+            account: withdrawal_pubkey,
+            recipient,
+            transfer_amount,
+            seed: deposit_seed,
+            value,
+            expected_evers,
+            event_data,
+        }
+        .try_to_vec()?]);
+
+        DepositMultiTokenSol::pack(
+            deposit_account_data,
+            &mut deposit_account_info.data.borrow_mut(),
+        )?;
+
+        // Refund escrowed bounty to author
+        if let Some((bounty_account_info, bounty_nonce)) = find_bounty_account(
+            program_id,
+            accounts,
+            &withdrawal_pubkey,
+            author_account_info.key,
+        )? {
+            let author_token_pubkey = spl_associated_token_account::get_associated_token_address(
+                author_account_info.key,
+                &mint,
+            );
+            let bounty_balance =
+                spl_token::state::Account::unpack(&bounty_account_info.data.borrow())?.amount;
+
+            release_bounty(
+                accounts,
+                bounty_account_info,
+                bounty_nonce,
+                &withdrawal_pubkey,
+                author_account_info.key,
+                &author_token_pubkey,
+                bounty_balance,
+            )?;
+
+            close_bounty_account(
+                accounts,
+                bounty_account_info,
+                bounty_nonce,
+                &withdrawal_pubkey,
+                author_account_info.key,
+                author_account_info.key,
+            )?;
+        }
+
+        token_settings_account_data.track_withdrawal(
+            withdrawal_account_data.meta.data.status,
+            WithdrawalTokenStatus::Cancelled,
+        );
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Cancelled;
+        withdrawal_account_data.meta.data.rejected = false;
+
+        // Rejected proposal was already counted as closed
+        if !rejected {
+            update_bridge_stats(program_id, accounts, BridgeStats::record_closed_proposal)?;
+        }
+
+        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+            account: withdrawal_pubkey,
+            status: withdrawal_account_data.meta.data.status,
+        }
+        .try_to_vec()?]);
+
+        WithdrawalMultiTokenSol::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_fill_withdraw_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        deposit_seed: u128,
+        recipient: EverAddress,
+        amount: u64,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+        fill_amounts: Option<Vec<u64>>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let author_account_info = next_account_info(account_info_iter)?;
+        let author_token_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let deposit_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let multi_vault_account_info = next_account_info(account_info_iter)?;
+        // Accounts looked up by address are passed ahead of the optional ones
+        let _bridge_stats_account_info = next_account_info(account_info_iter)?;
+
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, _) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        // Validate Deposit Value
+        if let Some(deposit_value_floor) = &settings_account_data.deposit_value_floor {
+            validate_deposit_value(deposit_value_floor, value, &expected_evers)?;
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::WithdrawalsPaused.into());
+        }
+
+        if token_settings_account_data.withdrawals_paused {
+            return Err(SolanaBridgeError::TokenWithdrawalsPaused.into());
+        }
+
+        // Restricted tokens are filled by registered liquidity providers only
+        if token_settings_account_data.fills_restricted
+            && !is_registered_liquidity_provider(program_id, accounts, author_account_info.key)?
+        {
+            return Err(SolanaBridgeError::LiquidityProviderNotRegistered.into());
+        }
+
+        // Validate Multi Vault Account
+        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+        let multi_vault_nonce = multi_vault_account_data
+            .account_kind
+            .into_multi_vault()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+        // Send sol amount to multi vault
+        invoke(
+            &system_instruction::transfer(
+                funder_account_info.key,
+                multi_vault_account_info.key,
+                value,
+            ),
+            accounts,
+        )?;
+
+        // Validate Mint Account
+        if *mint_account_info.key != mint && mint_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+        let decimals = mint_account_data.decimals;
+
+        // Deposit Account
+        let (deposit_pubkey, deposit_nonce) =
+            Pubkey::find_program_address(&[br"deposit", &deposit_seed.to_le_bytes()], program_id);
+        let deposit_account_signer_seeds: &[&[_]] =
+            &[br"deposit", &deposit_seed.to_le_bytes(), &[deposit_nonce]];
+
+        if deposit_pubkey != *deposit_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut fee_amount_sum = 0;
+        let mut withdrawals_amount_sum = 0;
+
+        // Withdrawals are filled completely unless portions are requested
+        let mut fill_amounts = fill_amounts.map(|fill_amounts| fill_amounts.into_iter());
+
+        // collect Withdrawal Accounts until Vault Account
+        while account_info_iter
+            .as_slice()
+            .first()
+            .is_some_and(|account_info| account_info.owner == program_id)
+        {
+            let withdrawal_account_infos = next_account_infos(account_info_iter, 2)?;
+            let withdrawal_account_info = &withdrawal_account_infos[0];
+            let recipient_token_account_info = &withdrawal_account_infos[1];
+
+            let mut withdrawal_account_data =
+                WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+            let round_number = withdrawal_account_data.round_number;
+            let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+            let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+            let event_configuration = withdrawal_account_data.pda.event_configuration;
+            let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+            let (nonce, _) = withdrawal_account_data
+                .account_kind
+                .into_proposal()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+                program_id,
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                withdrawal_account_data.chain_id,
+                &event_data,
+                nonce,
+                withdrawal_account_info,
+            )?;
+
+            if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending {
+                return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+            }
+
+            // Check connection between token and proposal
+            if mint != withdrawal_account_data.event.data.mint {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            // Validate Recipient account
+            let recipient_token_account_data =
+                spl_token::state::Account::unpack(&recipient_token_account_info.data.borrow())?;
+
+            if recipient_token_account_data.owner != withdrawal_account_data.event.data.recipient {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if recipient_token_account_info.owner != &spl_token::id() {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let withdrawal_amount: u64 = withdrawal_account_data
+                .event
+                .data
+                .amount
+                .try_into()
+                .map_err(|_| SolanaBridgeError::Overflow)?;
+
+            let filled_amount = withdrawal_account_data.meta.data.filled_amount;
+            let remaining_amount = withdrawal_amount
+                .checked_sub(filled_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            let fill_amount = match &mut fill_amounts {
+                Some(fill_amounts) => fill_amounts
+                    .next()
+                    .ok_or(ProgramError::InvalidArgument)?
+                    .min(remaining_amount),
+                None => remaining_amount,
+            };
+
+            if fill_amount == 0 {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let new_filled_amount = filled_amount + fill_amount;
+
+            // Fee and bounty are split between fillers pro-rata
+            let fee = token_settings_account_data
+                .withdrawal_fee(withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+            let fee = pro_rata_part(fee, filled_amount, new_filled_amount, withdrawal_amount)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            fee_amount_sum += fee;
+
+            // Amount without fee
+            let fill_amount_without_fee = fill_amount
+                .checked_sub(fee)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            withdrawals_amount_sum += fill_amount_without_fee;
+
+            update_bridge_stats(program_id, accounts, |stats| {
+                stats.record_withdrawal(fill_amount_without_fee)
+            })?;
+
+            let bounty = pro_rata_part(
+                withdrawal_account_data.meta.data.bounty,
+                filled_amount,
+                new_filled_amount,
+                withdrawal_amount,
+            )
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Amount without bounty
+            let transfer_withdrawal_amount = fill_amount_without_fee
+                .checked_sub(bounty)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            // Transfer SOL tokens
+            invoke(
+                &spl_token::instruction::transfer(
+                    &spl_token::id(),
+                    author_token_account_info.key,
+                    recipient_token_account_info.key,
+                    author_account_info.key,
+                    &[author_account_info.key],
+                    transfer_withdrawal_amount,
+                )?,
+                accounts,
+            )?;
+
+            // Pay escrowed bounty share to filler. Escrow is closed by its author
+            if let Some((bounty_account_info, bounty_nonce)) = find_bounty_account(
+                program_id,
+                accounts,
+                &withdrawal_pubkey,
+                &withdrawal_account_data.author,
+            )? {
+                let bounty_balance =
+                    spl_token::state::Account::unpack(&bounty_account_info.data.borrow())?.amount;
+
+                release_bounty(
+                    accounts,
+                    bounty_account_info,
+                    bounty_nonce,
+                    &withdrawal_pubkey,
+                    &withdrawal_account_data.author,
+                    author_token_account_info.key,
+                    pro_rata_part(bounty_balance, 0, fill_amount, remaining_amount)
+                        .ok_or(SolanaBridgeError::Overflow)?,
+                )?;
+            }
+
+            withdrawal_account_data.meta.data.filled_amount = new_filled_amount;
+
+            if new_filled_amount == withdrawal_amount {
+                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+
+                token_settings_account_data.track_withdrawal(
+                    WithdrawalTokenStatus::Pending,
+                    WithdrawalTokenStatus::Processed,
+                );
+
+                solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+                    account: withdrawal_pubkey,
+                    status: withdrawal_account_data.meta.data.status,
+                }
+                .try_to_vec()?]);
+            }
+
+            WithdrawalMultiTokenSol::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+
+            solana_program::log::sol_log_data(&[&LiquidityRequestEvent {
+                deposit: deposit_pubkey,
+                withdrawal: withdrawal_pubkey,
+            }
+            .try_to_vec()?]);
+        }
+
+        // Each requested portion must match a withdrawal
+        if fill_amounts.is_some_and(|mut fill_amounts| fill_amounts.next().is_some()) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                deposit_account_info.key,
+                1.max(rent.minimum_balance(DepositMultiTokenSol::LEN)),
+                DepositMultiTokenSol::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[deposit_account_signer_seeds],
+        )?;
+
+        if fee_amount_sum + withdrawals_amount_sum > amount {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        let deposit_amount = amount - withdrawals_amount_sum;
+        let vault_account_info = next_account_info(account_info_iter)?;
+        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
+
+        // Make transfer
+        let vault_account_data =
+            spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+
+        if vault_account_data
+            .amount
+            .checked_add(amount)
+            .ok_or(SolanaBridgeError::Overflow)?
+            > token_settings_account_data.deposit_limit
+        {
+            return Err(SolanaBridgeError::DepositLimit.into());
+        }
+
+        // Transfer SOL tokens
+        invoke(
+            &spl_token::instruction::transfer(
+                &spl_token::id(),
+                author_token_account_info.key,
+                vault_account_info.key,
+                author_account_info.key,
+                &[author_account_info.key],
+                deposit_amount,
+            )?,
+            accounts,
+        )?;
+
+        // Calculate fee
+        let fee = token_settings_account_data
+            .withdrawal_fee(amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Init Deposit Account
+        let transfer_amount = amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?
+            .try_into()
+            .map_err(|_| SolanaBridgeError::Overflow)?;
+
+        let name = token_settings_account_data.name.clone();
+        let symbol = token_settings_account_data.symbol.clone();
+
+        let deposit_account_data = DepositMultiTokenSol {
+            is_initialized: true,
+            account_kind: AccountKind::Deposit(deposit_nonce),
+            author: *author_account_info.key,
+            event: DepositMultiTokenSolEventWithLen::new(
+                mint,
+                name,
+                symbol,
+                decimals,
+                transfer_amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                fee as u128,
+                amount as u128,
+                None,
+            ),
+            meta: DepositTokenMetaWithLen::new(
+                deposit_seed,
+                *funder_account_info.key,
+                Clock::get()?.slot,
+            ),
+            version: CURRENT_ACCOUNT_VERSION,
+        };
+
+        let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
+            .to_bytes()
+            .to_vec();
+
+        solana_program::log::sol_log_data(&[&DepositMultiTokenEvent {
+            account: deposit_pubkey,
+            recipient,
+            transfer_amount,
+            seed: deposit_seed,
+            value,
+            expected_evers,
+            event_data,
+        }
+        .try_to_vec()?]);
+
+        DepositMultiTokenSol::pack(
+            deposit_account_data,
+            &mut deposit_account_info.data.borrow_mut(),
+        )?;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_withdraw_proxy(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let creator_account_info = next_account_info(account_info_iter)?;
+        let recipient_token_account_info = next_account_info(account_info_iter)?;
+        let proxy_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+
+        if !creator_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let (proxy, nonce) = Pubkey::find_program_address(
+            &[
+                br"proxy",
+                &mint_account_info.key.to_bytes(),
+                &creator_account_info.key.to_bytes(),
+            ],
+            program_id,
+        );
+
+        if proxy != *proxy_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let proxy_signer_seeds: &[&[_]] = &[
+            br"proxy",
+            &mint_account_info.key.to_bytes(),
+            &creator_account_info.key.to_bytes(),
+            &[nonce],
+        ];
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                &spl_token::id(),
+                proxy_account_info.key,
+                recipient_token_account_info.key,
+                proxy_account_info.key,
+                &[proxy_account_info.key],
+                amount,
+            )?,
+            accounts,
+            &[proxy_signer_seeds],
+        )?;
+
+        Ok(())
+    }
+
+    fn process_close_deposit(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let author_account_info = next_account_info(account_info_iter)?;
+        let deposit_account_info = next_account_info(account_info_iter)?;
+        let funder_account_info = next_account_info(account_info_iter)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Deposit Account
+        let deposit_account_data = Deposit::unpack_from_slice(&deposit_account_info.data.borrow())?;
+
+        let meta = DepositTokenMeta::unpack_from_slice(&deposit_account_data.meta)?;
+        let nonce = deposit_account_data
+            .account_kind
+            .into_deposit()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_deposit_account(
+            program_id,
+            meta.seed,
+            nonce,
+            deposit_account_info,
+        )?;
+
+        if *author_account_info.key != deposit_account_data.author {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Funder Account
+        if *funder_account_info.key != get_rent_funder(&meta.funder, &deposit_account_data.author) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        delete_account(deposit_account_info, funder_account_info)?;
+
+        Ok(())
+    }
+
+    fn process_close_withdrawal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let funder_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Withdrawal Account
+        let withdrawal_account_data =
+            Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        let meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
+        if meta.status != WithdrawalTokenStatus::Processed
+            && meta.status != WithdrawalTokenStatus::Cancelled
+            && meta.status != WithdrawalTokenStatus::Expired
+        {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Validate Funder Account
+        if *funder_account_info.key
+            != get_rent_funder(&meta.funder, &withdrawal_account_data.author)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Expired proposal released its index on expiration
+        if meta.status != WithdrawalTokenStatus::Expired {
+            release_proposal_index(
+                program_id,
+                accounts,
+                &withdrawal_account_data,
+                &event_data,
+                funder_account_info,
+            )?;
+        }
+
+        delete_account(withdrawal_account_info, funder_account_info)?;
+
+        Ok(())
+    }
+
+    fn process_sweep_closed_accounts(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        // Each swept account is followed by the funder who paid its rent.
+        // Withdrawal is also followed by its proposal index
+        while let Some(account_info) = account_info_iter.next() {
+            let funder_account_info = next_account_info(account_info_iter)?;
+
+            if account_info.owner != program_id {
+                return Err(ProgramError::IllegalOwner);
+            }
+
+            let account_kind = {
+                // Skip type tag and `is_initialized` flag
+                let data = account_info.data.borrow();
+                let data = bridge_utils::state::strip_discriminator(&data);
+                let mut account_kind_data = data.get(1..).unwrap_or_default();
+                AccountKind::deserialize(&mut account_kind_data)?
+            };
+
+            let (funder, released_proposal) = match account_kind {
+                AccountKind::Deposit(nonce) => {
+                    let deposit_account_data =
+                        Deposit::unpack_from_slice(&account_info.data.borrow())?;
+                    let meta = DepositTokenMeta::unpack_from_slice(&deposit_account_data.meta)?;
+
+                    bridge_utils::helper::validate_deposit_account(
+                        program_id,
+                        meta.seed,
+                        nonce,
+                        account_info,
+                    )?;
+
+                    // Only reversed deposits are no longer needed by relays
+                    if !meta.refunded {
+                        return Err(SolanaBridgeError::DepositNotRefunded.into());
+                    }
+
+                    (
+                        get_rent_funder(&meta.funder, &deposit_account_data.author),
+                        None,
+                    )
+                }
+                AccountKind::Proposal(nonce, _) => {
+                    let withdrawal_account_data =
+                        Proposal::unpack_from_slice(&account_info.data.borrow())?;
+
+                    let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+
+                    bridge_utils::helper::validate_proposal_account(
+                        program_id,
+                        withdrawal_account_data.round_number,
+                        withdrawal_account_data.pda.event_timestamp,
+                        withdrawal_account_data.pda.event_transaction_lt,
+                        &withdrawal_account_data.pda.event_configuration,
+                        withdrawal_account_data.chain_id,
+                        &event_data,
+                        nonce,
+                        account_info,
+                    )?;
+
+                    let meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
+                    if meta.status != WithdrawalTokenStatus::Processed
+                        && meta.status != WithdrawalTokenStatus::Cancelled
+                        && meta.status != WithdrawalTokenStatus::Expired
+                    {
+                        return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+                    }
+
+                    // Relays claim rewards against withdrawal accounts
+                    if withdrawal_account_data
+                        .vote_rewards
+                        .contains(&VoteReward::Unclaimed)
+                    {
+                        return Err(SolanaBridgeError::UnclaimedVoteRewards.into());
+                    }
+
+                    // Validate Proposal Index Account
+                    let proposal_index_account_info = next_account_info(account_info_iter)?;
+
+                    let proposal_index_pubkey = get_associated_proposal_index_address(
+                        program_id,
+                        withdrawal_account_data.pda.event_timestamp,
+                        withdrawal_account_data.pda.event_transaction_lt,
+                        &withdrawal_account_data.pda.event_configuration,
+                        withdrawal_account_data.chain_id,
+                    );
+
+                    if proposal_index_pubkey != *proposal_index_account_info.key {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+
+                    let funder = get_rent_funder(&meta.funder, &withdrawal_account_data.author);
+
+                    // Expired proposal released its index on expiration
+                    let released_proposal = (meta.status != WithdrawalTokenStatus::Expired)
+                        .then_some((withdrawal_account_data, event_data));
+
+                    (funder, released_proposal)
+                }
+                _ => return Err(SolanaBridgeError::InvalidTokenKind.into()),
+            };
+
+            // Validate Funder Account
+            if *funder_account_info.key != funder {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if let Some((withdrawal_account_data, event_data)) = released_proposal {
+                release_proposal_index(
+                    program_id,
+                    accounts,
+                    &withdrawal_account_data,
+                    &event_data,
+                    funder_account_info,
+                )?;
+            }
+
+            delete_account(account_info, funder_account_info)?;
+        }
+
+        Ok(())
+    }
+
+    fn process_expire_withdrawal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let clock_info = next_account_info(account_info_iter)?;
+        let clock = Clock::from_account_info(clock_info)?;
+
+        // Validate Withdrawal Account
+        let mut withdrawal_account_data =
+            Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        let mut meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
+        if meta.status != WithdrawalTokenStatus::New {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        let confirms = bridge_utils::helper::get_recorded_weight(
+            &withdrawal_account_data.signers,
+            withdrawal_account_data.confirmed_weight,
+        );
+
+        if confirms >= withdrawal_account_data.required_votes {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Legacy requests never expire
+        if meta.expires_at == 0 || clock.unix_timestamp < meta.expires_at {
+            return Err(SolanaBridgeError::WithdrawalNotExpired.into());
+        }
+
+        // Validate Funder Account
+        if *funder_account_info.key
+            != get_rent_funder(&meta.funder, &withdrawal_account_data.author)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
-            .to_bytes()
-            .to_vec();
+        meta.status = WithdrawalTokenStatus::Expired;
+        withdrawal_account_data.meta = meta.try_to_vec()?;
 
-        solana_program::log::sol_log_data(&[&*DepositMultiTokenEvent {
-            account: deposit_pubkey,
-            recipient,
-            transfer_amount,
-            seed: deposit_seed,
-            value,
-            expected_evers,
-            event_data,
+        release_proposal_index(
+            program_id,
+            accounts,
+            &withdrawal_account_data,
+            &event_data,
+            funder_account_info,
+        )?;
+
+        update_bridge_stats(program_id, accounts, BridgeStats::record_closed_proposal)?;
+
+        withdrawal_account_data.pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+
+        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+            account: *withdrawal_account_info.key,
+            status: WithdrawalTokenStatus::Expired,
         }
         .try_to_vec()?]);
 
-        DepositMultiTokenEver::pack(
-            deposit_account_data,
-            &mut deposit_account_info.data.borrow_mut(),
-        )?;
+        // Release reparation reserved for relays that have not voted
+        let unvoted = withdrawal_account_data
+            .signers
+            .iter()
+            .filter(|vote| **vote == Vote::None)
+            .count() as u64;
 
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
-        )?;
+        let reparation = RELAY_REPARATION
+            .checked_mul(unvoted)
+            .ok_or(SolanaBridgeError::Overflow)?;
 
-        // Send SOL amount to multi vault
-        invoke(
-            &system_instruction::transfer(
-                funder_account_info.key,
-                multi_vault_account_info.key,
-                value,
-            ),
-            accounts,
-        )?;
+        let withdrawal_starting_lamports = withdrawal_account_info.lamports();
+        **withdrawal_account_info.lamports.borrow_mut() = withdrawal_starting_lamports
+            .checked_sub(reparation)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let funder_starting_lamports = funder_account_info.lamports();
+        **funder_account_info.lamports.borrow_mut() = funder_starting_lamports
+            .checked_add(reparation)
+            .ok_or(SolanaBridgeError::Overflow)?;
 
         Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn process_deposit_multi_token_sol(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        deposit_seed: u128,
-        name: String,
-        symbol: String,
-        amount: u64,
-        recipient: EverAddress,
-        value: u64,
-        expected_evers: UInt256,
-        payload: Vec<u8>,
-    ) -> ProgramResult {
+    fn process_cancel_deposit(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
-        let funder_account_info = next_account_info(account_info_iter)?;
-        let creator_account_info = next_account_info(account_info_iter)?;
-        let creator_token_account_info = next_account_info(account_info_iter)?;
-        let vault_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
         let deposit_account_info = next_account_info(account_info_iter)?;
+        let author_token_account_info = next_account_info(account_info_iter)?;
         let mint_account_info = next_account_info(account_info_iter)?;
-        let multi_vault_account_info = next_account_info(account_info_iter)?;
         let token_settings_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
-        let _system_program_info = next_account_info(account_info_iter)?;
         let _token_program_info = next_account_info(account_info_iter)?;
-        let rent_sysvar_info = next_account_info(account_info_iter)?;
-        let rent = &Rent::from_account_info(rent_sysvar_info)?;
 
-        if !creator_account_info.is_signer {
+        if !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
@@ -650,858 +9822,757 @@ impl Processor {
             settings_account_info,
         )?;
 
-        if settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
-
-        // Check asset name length
-        if name.len() > MAX_NAME_LEN {
-            return Err(SolanaBridgeError::TokenNameLenLimit.into());
-        }
-
-        // Check asset symbol length
-        if symbol.len() > MAX_SYMBOL_LEN {
-            return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
-        }
-
-        // If token settings account is not created
-        if token_settings_account_info.lamports() == 0 {
-            // Create Vault Account
-            let (vault_pubkey, vault_nonce) = Pubkey::find_program_address(
-                &[br"vault", &mint_account_info.key.to_bytes()],
-                program_id,
-            );
-            let vault_account_signer_seeds: &[&[_]] =
-                &[br"vault", &mint_account_info.key.to_bytes(), &[vault_nonce]];
-
-            if vault_pubkey != *vault_account_info.key {
-                return Err(ProgramError::InvalidArgument);
-            }
-
-            invoke_signed(
-                &system_instruction::create_account(
-                    funder_account_info.key,
-                    vault_account_info.key,
-                    1.max(rent.minimum_balance(spl_token::state::Account::LEN)),
-                    spl_token::state::Account::LEN as u64,
-                    &spl_token::id(),
-                ),
-                accounts,
-                &[vault_account_signer_seeds],
-            )?;
-
-            // Init Vault Account
-            invoke_signed(
-                &spl_token::instruction::initialize_account3(
-                    &spl_token::id(),
-                    vault_account_info.key,
-                    mint_account_info.key,
-                    vault_account_info.key,
-                )?,
-                accounts,
-                &[vault_account_signer_seeds],
-            )?;
-
-            // Create Token Settings Account
-            let (token_settings_pubkey, token_settings_nonce) = Pubkey::find_program_address(
-                &[br"settings", &mint_account_info.key.to_bytes()],
-                program_id,
-            );
-            let token_settings_account_signer_seeds: &[&[_]] = &[
-                br"settings",
-                &mint_account_info.key.to_bytes(),
-                &[token_settings_nonce],
-            ];
-
-            if token_settings_pubkey != *token_settings_account_info.key {
-                return Err(ProgramError::InvalidArgument);
-            }
-
-            invoke_signed(
-                &system_instruction::create_account(
-                    funder_account_info.key,
-                    token_settings_account_info.key,
-                    1.max(rent.minimum_balance(TokenSettings::LEN)),
-                    TokenSettings::LEN as u64,
-                    program_id,
-                ),
-                accounts,
-                &[token_settings_account_signer_seeds],
-            )?;
-
-            // Init Settings Account
-            let token_settings_account_data = TokenSettings {
-                is_initialized: true,
-                account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-                kind: TokenKind::Solana {
-                    mint: *mint_account_info.key,
-                    vault: *vault_account_info.key,
-                },
-                name: name.clone(),
-                symbol: symbol.clone(),
-                withdrawal_epoch: 0,
-                deposit_limit: u64::MAX,
-                withdrawal_limit: u64::MAX,
-                withdrawal_daily_limit: u64::MAX,
-                withdrawal_daily_amount: 0,
-                emergency: false,
-                fee_supply: Default::default(),
-                fee_deposit_info: Default::default(),
-                fee_withdrawal_info: Default::default(),
-            };
-
-            solana_program::log::sol_log_data(&[&TokenSettingsEvent {
-                account: token_settings_pubkey,
-                symbol: token_settings_account_data.symbol.clone(),
-                name: token_settings_account_data.name.clone(),
-                mint: mint_account_info.key.clone(),
-                vault: Some(vault_account_info.key.clone()),
-                ever_decimals: None,
-                solana_decimals: None,
-                root: None,
-                fee: Default::default(),
-            }
-            .try_to_vec()?]);
-
-            TokenSettings::pack(
-                token_settings_account_data,
-                &mut token_settings_account_info.data.borrow_mut(),
-            )?;
-        }
-
-        // Validate Token Settings Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
-
-        let (mint, vault) = token_settings_account_data
-            .kind
-            .into_solana()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-        let (token_settings_nonce, _vault_nonce) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        validate_token_settings_sol_account(
-            program_id,
-            &mint,
-            token_settings_nonce,
-            token_settings_account_info,
-        )?;
-
-        if token_settings_account_data.emergency {
+        if settings_account_data.is_emergency(Clock::get()?.unix_timestamp) {
             return Err(SolanaBridgeError::EmergencyEnabled.into());
         }
 
-        // Validate Mint Account
-        if *mint_account_info.key != mint && mint_account_info.owner != &spl_token::id() {
-            return Err(ProgramError::InvalidArgument);
+        if *authority_account_info.key != settings_account_data.withdrawal_manager {
+            return Err(ProgramError::IllegalOwner);
         }
 
-        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
-        let decimals = mint_account_data.decimals;
-
-        // Validate Vault Account
-        if *vault_account_info.key != vault && vault_account_info.owner != &spl_token::id() {
-            return Err(ProgramError::InvalidArgument);
-        }
+        // Validate Deposit Account
+        let mut deposit_account_data =
+            Deposit::unpack_from_slice(&deposit_account_info.data.borrow())?;
 
-        // Validate Multi Vault Account
-        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
-        let multi_vault_nonce = multi_vault_account_data
+        let mut meta = DepositTokenMeta::unpack_from_slice(&deposit_account_data.meta)?;
+        let nonce = deposit_account_data
             .account_kind
-            .into_multi_vault()
+            .into_deposit()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
-
-        // Make transfer
-        let vault_account_data =
-            spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
-
-        if vault_account_data
-            .amount
-            .checked_add(amount)
-            .ok_or(SolanaBridgeError::Overflow)?
-            > token_settings_account_data.deposit_limit
-        {
-            return Err(SolanaBridgeError::DepositLimit.into());
-        }
-
-        // Transfer SOL tokens to Vault Account
-        invoke(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                creator_token_account_info.key,
-                vault_account_info.key,
-                creator_account_info.key,
-                &[creator_account_info.key],
-                amount,
-            )?,
-            accounts,
-        )?;
-
-        // Send sol amount to multi vault
-        invoke(
-            &system_instruction::transfer(
-                funder_account_info.key,
-                multi_vault_account_info.key,
-                value,
-            ),
-            accounts,
+        bridge_utils::helper::validate_deposit_account(
+            program_id,
+            meta.seed,
+            nonce,
+            deposit_account_info,
         )?;
 
-        // Create Deposit Account
-        let (deposit_pubkey, deposit_nonce) =
-            Pubkey::find_program_address(&[br"deposit", &deposit_seed.to_le_bytes()], program_id);
-        let deposit_account_signer_seeds: &[&[_]] =
-            &[br"deposit", &deposit_seed.to_le_bytes(), &[deposit_nonce]];
+        if meta.refunded {
+            return Err(SolanaBridgeError::DepositAlreadyRefunded.into());
+        }
 
-        if deposit_pubkey != *deposit_account_info.key {
+        // Validate Author Token Account
+        let author_token_account_data =
+            spl_token::state::Account::unpack(&author_token_account_info.data.borrow())?;
+
+        if author_token_account_data.owner != deposit_account_data.author
+            || author_token_account_data.mint != *mint_account_info.key
+        {
             return Err(ProgramError::InvalidArgument);
         }
 
-        invoke_signed(
-            &system_instruction::create_account(
-                funder_account_info.key,
-                deposit_account_info.key,
-                1.max(rent.minimum_balance(DepositMultiTokenSol::LEN)),
-                DepositMultiTokenSol::LEN as u64,
-                program_id,
-            ),
-            accounts,
-            &[deposit_account_signer_seeds],
-        )?;
+        // Validate Token Settings Account
+        let token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
 
-        // Init Deposit Account
-        let fee_info = &token_settings_account_data.fee_deposit_info;
+        let (token_settings_nonce, mint_or_vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        let fee = 1.max(
-            amount
-                .checked_div(fee_info.divisor)
-                .ok_or(SolanaBridgeError::Overflow)?
-                .checked_mul(fee_info.multiplier)
-                .ok_or(SolanaBridgeError::Overflow)?,
-        );
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
 
-        // Increase fee supply
-        token_settings_account_data.fee_supply = token_settings_account_data
-            .fee_supply
-            .checked_add(fee)
-            .ok_or(SolanaBridgeError::Overflow)?;
+                // Validate Mint Account
+                validate_mint_account(program_id, &token, mint_or_vault_nonce, mint_account_info)?;
 
-        // Amount without fee
-        let transfer_amount = amount
-            .checked_sub(fee)
-            .ok_or(SolanaBridgeError::Overflow)?
-            .try_into()
-            .map_err(|_| SolanaBridgeError::Overflow)?;
+                let deposit_event =
+                    DepositMultiTokenEver::unpack(&deposit_account_info.data.borrow())?
+                        .event
+                        .data;
 
-        let name = token_settings_account_data.name.clone();
-        let symbol = token_settings_account_data.symbol.clone();
+                // Check connection between token and deposit
+                if deposit_event.token != token {
+                    return Err(ProgramError::InvalidArgument);
+                }
 
-        let deposit_account_data = DepositMultiTokenSol {
-            is_initialized: true,
-            account_kind: AccountKind::Deposit(deposit_nonce),
-            author: *creator_account_info.key,
-            event: DepositMultiTokenSolEventWithLen::new(
-                *mint_account_info.key,
-                name,
-                symbol,
-                decimals,
-                transfer_amount,
-                recipient,
-                value,
-                expected_evers,
-                payload,
-            ),
-            meta: DepositTokenMetaWithLen::new(deposit_seed),
-        };
+                let refund_amount = deposit_event
+                    .gross_amount
+                    .checked_sub(deposit_event.fee)
+                    .and_then(|amount| u64::try_from(amount).ok())
+                    .ok_or(SolanaBridgeError::Overflow)?;
 
-        let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
-            .to_bytes()
-            .to_vec();
+                // Mint back burnt EVER tokens
+                make_ever_transfer(
+                    mint_account_info,
+                    author_token_account_info,
+                    &token_settings_account_data,
+                    accounts,
+                    refund_amount,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                let vault_account_info = next_account_info(account_info_iter)?;
 
-        solana_program::log::sol_log_data(&[&DepositMultiTokenEvent {
-            account: deposit_pubkey,
-            recipient,
-            transfer_amount,
-            seed: deposit_seed,
-            value,
-            expected_evers,
-            event_data,
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+
+                // Validate Vault Account
+                validate_vault_account(program_id, &mint, mint_or_vault_nonce, vault_account_info)?;
+
+                let deposit_event =
+                    DepositMultiTokenSol::unpack(&deposit_account_info.data.borrow())?
+                        .event
+                        .data;
+
+                // Check connection between token and deposit
+                if mint != deposit_event.base_token || mint != *mint_account_info.key {
+                    return Err(ProgramError::InvalidArgument);
+                }
+
+                let refund_amount = deposit_event
+                    .gross_amount
+                    .checked_sub(deposit_event.fee)
+                    .and_then(|amount| u64::try_from(amount).ok())
+                    .ok_or(SolanaBridgeError::Overflow)?;
+
+                // Release SOL tokens from Vault
+                make_sol_transfer(
+                    vault_account_info,
+                    author_token_account_info,
+                    &token_settings_account_data,
+                    accounts,
+                    refund_amount,
+                )?;
+            }
         }
-        .try_to_vec()?]);
 
-        DepositMultiTokenSol::pack(
-            deposit_account_data,
-            &mut deposit_account_info.data.borrow_mut(),
-        )?;
+        // Mark Deposit as refunded
+        meta.refunded = true;
+        deposit_account_data.meta = meta.try_to_vec()?;
 
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
-        )?;
+        deposit_account_data.pack_into_slice(&mut deposit_account_info.data.borrow_mut());
 
         Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn process_withdraw_multi_token_ever_request(
+    fn process_cancel_withdrawal_request(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        event_timestamp: u32,
-        event_transaction_lt: u64,
-        event_configuration: Pubkey,
-        token: EverAddress,
-        name: String,
-        symbol: String,
-        decimals: u8,
-        recipient: Pubkey,
-        amount: u128,
-        payload: Vec<u8>,
-        attached_amount: u64,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
-        let funder_account_info = next_account_info(account_info_iter)?;
         let author_account_info = next_account_info(account_info_iter)?;
         let withdrawal_account_info = next_account_info(account_info_iter)?;
-        let rl_settings_account_info = next_account_info(account_info_iter)?;
-        let relay_round_account_info = next_account_info(account_info_iter)?;
-        let system_program_info = next_account_info(account_info_iter)?;
-
-        let rent_sysvar_info = next_account_info(account_info_iter)?;
-        let rent = &Rent::from_account_info(rent_sysvar_info)?;
-
-        let clock_info = next_account_info(account_info_iter)?;
-        let clock = Clock::from_account_info(clock_info)?;
+        let proposal_index_account_info = next_account_info(account_info_iter)?;
+        let funder_account_info = next_account_info(account_info_iter)?;
 
         if !author_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Check asset name length
-        if name.len() > MAX_NAME_LEN {
-            return Err(SolanaBridgeError::TokenNameLenLimit.into());
-        }
-
-        // Check asset symbol length
-        if symbol.len() > MAX_SYMBOL_LEN {
-            return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
-        }
-
-        // Validate Round Loader Settings Account
-        let rl_settings_account_data =
-            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+        // Validate Withdrawal Account
+        let withdrawal_account_data =
+            Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
 
-        let (rl_settings_nonce, _) = rl_settings_account_data
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+        let (nonce, _) = withdrawal_account_data
             .account_kind
-            .into_settings()
+            .into_proposal()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        bridge_utils::helper::validate_settings_account(
-            &round_loader::id(),
-            rl_settings_nonce,
-            rl_settings_account_info,
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
         )?;
 
-        // Validate Relay Round Account
-        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
-
-        let relay_round_nonce = relay_round_account_data
-            .account_kind
-            .into_relay_round()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        if *author_account_info.key != withdrawal_account_data.author {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        let round_number = relay_round_account_data.round_number;
+        let meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
+        if meta.status != WithdrawalTokenStatus::New {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
 
-        round_loader::validate_relay_round_account(
-            &round_loader::id(),
-            round_number,
-            relay_round_nonce,
-            relay_round_account_info,
-        )?;
+        if withdrawal_account_data
+            .signers
+            .iter()
+            .any(|vote| *vote != Vote::None)
+        {
+            return Err(SolanaBridgeError::WithdrawalHasVotes.into());
+        }
 
-        if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
-            return Err(SolanaBridgeError::RelayRoundExpired.into());
+        // Validate Funder Account
+        if *funder_account_info.key
+            != get_rent_funder(&meta.funder, &withdrawal_account_data.author)
+        {
+            return Err(ProgramError::InvalidArgument);
         }
 
-        let mut required_votes = (relay_round_account_data.relays.len() * 2 / 3 + 1) as u32;
-        if rl_settings_account_data.min_required_votes > required_votes {
-            required_votes = rl_settings_account_data.min_required_votes;
+        // Validate Proposal Index Account
+        let proposal_index_pubkey = get_associated_proposal_index_address(
+            program_id,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+        );
+
+        if proposal_index_pubkey != *proposal_index_account_info.key {
+            return Err(ProgramError::InvalidArgument);
         }
 
-        let epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
+        release_proposal_index(
+            program_id,
+            accounts,
+            &withdrawal_account_data,
+            &event_data,
+            funder_account_info,
+        )?;
 
-        // Create Proxy Account
-        let proxy_nonce = match payload.is_empty() {
-            true => None,
-            false => {
-                let proxy_account_info = next_account_info(account_info_iter)?;
-                let mint_account_info = next_account_info(account_info_iter)?;
-                let spl_token_program_info = next_account_info(account_info_iter)?;
+        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
+            account: *withdrawal_account_info.key,
+            status: WithdrawalTokenStatus::Cancelled,
+        }
+        .try_to_vec()?]);
 
-                let mint = get_associated_mint(program_id, &token);
-                if mint != *mint_account_info.key {
-                    return Err(ProgramError::InvalidArgument);
-                }
+        update_bridge_stats(program_id, accounts, BridgeStats::record_closed_proposal)?;
 
-                let nonce = create_proxy_account(
-                    program_id,
-                    &recipient,
-                    funder_account_info,
-                    proxy_account_info,
-                    mint_account_info,
-                    system_program_info,
-                    spl_token_program_info,
-                )?;
+        delete_account(withdrawal_account_info, funder_account_info)?;
 
-                // Attach SOL to proxy account
-                invoke(
-                    &system_instruction::transfer(
-                        funder_account_info.key,
-                        proxy_account_info.key,
-                        attached_amount,
-                    ),
-                    accounts,
-                )?;
+        Ok(())
+    }
+
+    fn process_withdraw_multi_vault(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
 
-                Some(nonce)
-            }
-        };
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let recipient_account_info = next_account_info(account_info_iter)?;
+        let multi_vault_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
 
-        // Create Withdraw Account
-        let event = WithdrawalMultiTokenEverEventWithLen::new(
-            token, name, symbol, decimals, amount, recipient, payload,
-        );
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
-        let event_data = hash(&event.data.try_to_vec()?);
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-        let (withdrawal_pubkey, withdrawal_nonce) = Pubkey::find_program_address(
-            &[
-                br"proposal",
-                &round_number.to_le_bytes(),
-                &event_timestamp.to_le_bytes(),
-                &event_transaction_lt.to_le_bytes(),
-                &event_configuration.to_bytes(),
-                &event_data.to_bytes(),
-            ],
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
             program_id,
-        );
-        let withdrawal_account_signer_seeds: &[&[_]] = &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data.to_bytes(),
-            &[withdrawal_nonce],
-        ];
+            settings_nonce,
+            settings_account_info,
+        )?;
 
-        if withdrawal_pubkey != *withdrawal_account_info.key {
-            return Err(ProgramError::InvalidArgument);
-        }
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
 
-        if withdrawal_account_info.lamports() == 0 {
-            invoke_signed(
-                &system_instruction::create_account(
-                    funder_account_info.key,
-                    withdrawal_account_info.key,
-                    1.max(rent.minimum_balance(WithdrawalMultiTokenEver::LEN)),
-                    WithdrawalMultiTokenEver::LEN as u64,
-                    program_id,
-                ),
-                accounts,
-                &[withdrawal_account_signer_seeds],
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
             )?;
-
-            let withdrawal_account_data = WithdrawalMultiTokenEver {
-                is_initialized: true,
-                account_kind: AccountKind::Proposal(withdrawal_nonce, proxy_nonce),
-                author: *author_account_info.key,
-                round_number,
-                required_votes,
-                event,
-                pda: PDA {
-                    event_timestamp,
-                    event_transaction_lt,
-                    event_configuration,
-                },
-                meta: WithdrawalTokenMetaWithLen::new(0, epoch),
-                signers: vec![Vote::None; relay_round_account_data.relays.len()],
-            };
-
-            WithdrawalMultiTokenEver::pack(
-                withdrawal_account_data,
-                &mut withdrawal_account_info.data.borrow_mut(),
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
             )?;
 
-            let event_data = event_data.to_bytes().to_vec();
-
-            solana_program::log::sol_log_data(&[&WithdrawMultiTokenRequestEvent {
-                account: withdrawal_pubkey,
-                token: token.to_string(),
-                recipient,
-                amount,
-                event_timestamp,
-                event_transaction_lt,
-                event_configuration,
-                event_data,
-                bounty: 0,
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
             }
-            .try_to_vec()?]);
+        }
 
-            // Send voting reparation for Relay to withdrawal account
-            let relays_lamports = RELAY_REPARATION * relay_round_account_data.relays.len() as u64;
+        // Validate Multi Vault Account
+        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+        let multi_vault_nonce = multi_vault_account_data
+            .account_kind
+            .into_multi_vault()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-            invoke(
-                &system_instruction::transfer(
-                    funder_account_info.key,
-                    withdrawal_account_info.key,
-                    relays_lamports,
-                ),
-                accounts,
-            )?;
-        }
+        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+        // Transfer
+        let multi_vault_starting_lamports = multi_vault_account_info.lamports();
+        **multi_vault_account_info.lamports.borrow_mut() = multi_vault_starting_lamports
+            .checked_sub(amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let recipient_starting_lamports = recipient_account_info.lamports();
+        **recipient_account_info.lamports.borrow_mut() = recipient_starting_lamports
+            .checked_add(amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
 
         Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn process_withdraw_multi_token_sol_request(
+    fn process_change_payload_allowlist(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        event_timestamp: u32,
-        event_transaction_lt: u64,
-        event_configuration: Pubkey,
-        recipient: Pubkey,
-        amount: u128,
-        payload: Vec<u8>,
-        attached_amount: u64,
+        new_payload_allowlist: Vec<Pubkey>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
-        let funder_account_info = next_account_info(account_info_iter)?;
-        let author_account_info = next_account_info(account_info_iter)?;
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
-        let rl_settings_account_info = next_account_info(account_info_iter)?;
-        let relay_round_account_info = next_account_info(account_info_iter)?;
-        let system_program_info = next_account_info(account_info_iter)?;
-
-        let rent_sysvar_info = next_account_info(account_info_iter)?;
-        let rent = &Rent::from_account_info(rent_sysvar_info)?;
-
-        let clock_info = next_account_info(account_info_iter)?;
-        let clock = Clock::from_account_info(clock_info)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
 
-        if !author_account_info.is_signer {
+        if !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Validate Token Setting Account
-        let token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+        // Check allowlist length
+        if new_payload_allowlist.len() > MAX_PAYLOAD_ALLOWLIST_LEN {
+            return Err(SolanaBridgeError::PayloadAllowlistLenLimit.into());
+        }
 
-        let (token_settings_nonce, _) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-        let (mint, _) = token_settings_account_data
-            .kind
-            .into_solana()
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        validate_token_settings_sol_account(
+        bridge_utils::helper::validate_settings_account(
             program_id,
-            &mint,
-            token_settings_nonce,
-            token_settings_account_info,
+            settings_nonce,
+            settings_account_info,
         )?;
 
-        // Validate Round Loader Settings Account
-        let rl_settings_account_data =
-            round_loader::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
 
-        let (rl_settings_nonce, _) = rl_settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
 
-        bridge_utils::helper::validate_settings_account(
-            &round_loader::id(),
-            rl_settings_nonce,
-            rl_settings_account_info,
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        settings_account_data.payload_allowlist = new_payload_allowlist;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
         )?;
 
-        // Validate Relay Round Account
-        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
-        let relay_round_nonce = relay_round_account_data
-            .account_kind
-            .into_relay_round()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        Ok(())
+    }
 
-        let round_number = relay_round_account_data.round_number;
+    fn process_change_deposit_caller_allowlist(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_deposit_caller_allowlist: Option<Vec<Pubkey>>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
 
-        round_loader::validate_relay_round_account(
-            &round_loader::id(),
-            round_number,
-            relay_round_nonce,
-            relay_round_account_info,
-        )?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
 
-        if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
-            return Err(SolanaBridgeError::RelayRoundExpired.into());
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
         }
 
-        let mut required_votes = (relay_round_account_data.relays.len() * 2 / 3 + 1) as u32;
-        if rl_settings_account_data.min_required_votes > required_votes {
-            required_votes = rl_settings_account_data.min_required_votes;
+        // Check allowlist length
+        if let Some(new_deposit_caller_allowlist) = &new_deposit_caller_allowlist {
+            if new_deposit_caller_allowlist.len() > MAX_DEPOSIT_CALLER_ALLOWLIST_LEN {
+                return Err(SolanaBridgeError::DepositCallerAllowlistLenLimit.into());
+            }
         }
 
-        let epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-        // Create Proxy Account
-        let proxy_nonce = match payload.is_empty() {
-            true => None,
-            false => {
-                let proxy_account_info = next_account_info(account_info_iter)?;
-                let mint_account_info = next_account_info(account_info_iter)?;
-                let spl_token_program_info = next_account_info(account_info_iter)?;
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
 
-                if mint != *mint_account_info.key {
-                    return Err(ProgramError::InvalidArgument);
-                }
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
 
-                let nonce = create_proxy_account(
-                    program_id,
-                    &recipient,
-                    funder_account_info,
-                    proxy_account_info,
-                    mint_account_info,
-                    system_program_info,
-                    spl_token_program_info,
-                )?;
+        settings_account_data.deposit_caller_allowlist = new_deposit_caller_allowlist;
 
-                // Attach SOL to proxy account
-                invoke(
-                    &system_instruction::transfer(
-                        funder_account_info.key,
-                        proxy_account_info.key,
-                        attached_amount,
-                    ),
-                    accounts,
-                )?;
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
 
-                Some(nonce)
-            }
-        };
+        Ok(())
+    }
 
-        // Create Withdraw Account
-        let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+    fn process_change_token_defaults(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        new_token_defaults: TokenDefaults,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
 
-        let event_data = hash(&event.data.try_to_vec()?);
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
 
-        let (withdrawal_pubkey, withdrawal_nonce) = Pubkey::find_program_address(
-            &[
-                br"proposal",
-                &round_number.to_le_bytes(),
-                &event_timestamp.to_le_bytes(),
-                &event_transaction_lt.to_le_bytes(),
-                &event_configuration.to_bytes(),
-                &event_data.to_bytes(),
-            ],
-            program_id,
-        );
-        let withdrawal_account_signer_seeds: &[&[_]] = &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data.to_bytes(),
-            &[withdrawal_nonce],
-        ];
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
-        if withdrawal_pubkey != *withdrawal_account_info.key {
+        if new_token_defaults.fee_deposit_info.divisor == 0
+            || new_token_defaults.fee_withdrawal_info.divisor == 0
+        {
             return Err(ProgramError::InvalidArgument);
         }
 
-        if withdrawal_account_info.lamports() == 0 {
-            invoke_signed(
-                &system_instruction::create_account(
-                    funder_account_info.key,
-                    withdrawal_account_info.key,
-                    1.max(rent.minimum_balance(WithdrawalMultiTokenSol::LEN)),
-                    WithdrawalMultiTokenSol::LEN as u64,
-                    program_id,
-                ),
-                accounts,
-                &[withdrawal_account_signer_seeds],
-            )?;
-
-            let withdrawal_account_data = WithdrawalMultiTokenSol {
-                is_initialized: true,
-                account_kind: AccountKind::Proposal(withdrawal_nonce, proxy_nonce),
-                author: *author_account_info.key,
-                round_number,
-                required_votes,
-                pda: PDA {
-                    event_timestamp,
-                    event_transaction_lt,
-                    event_configuration,
-                },
-                event,
-                meta: WithdrawalTokenMetaWithLen::new(0, epoch),
-                signers: vec![Vote::None; relay_round_account_data.relays.len()],
-            };
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-            WithdrawalMultiTokenSol::pack(
-                withdrawal_account_data,
-                &mut withdrawal_account_info.data.borrow_mut(),
-            )?;
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-            let event_data = event_data.to_bytes().to_vec();
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
 
-            solana_program::log::sol_log_data(&[&WithdrawMultiTokenRequestEvent {
-                account: withdrawal_pubkey,
-                token: mint.to_string(),
-                recipient,
-                amount,
-                event_timestamp,
-                event_transaction_lt,
-                event_configuration,
-                event_data,
-                bounty: 0,
-            }
-            .try_to_vec()?]);
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
 
-            // Send voting reparation for Relay to withdrawal account
-            invoke(
-                &system_instruction::transfer(
-                    funder_account_info.key,
-                    withdrawal_account_info.key,
-                    RELAY_REPARATION * relay_round_account_data.relays.len() as u64,
-                ),
-                accounts,
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
             )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
+        settings_account_data.token_defaults = Some(new_token_defaults);
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
         Ok(())
     }
 
-    fn process_vote_for_withdraw_request(
+    #[allow(clippy::too_many_arguments)]
+    fn process_register_token_ever(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        vote: Vote,
+        token: EverAddress,
+        name: String,
+        symbol: String,
+        ever_decimals: u8,
+        solana_decimals: u8,
+        token_defaults: Option<TokenDefaults>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
-        let relay_account_info = next_account_info(account_info_iter)?;
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
-        let relay_round_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
 
-        if !relay_account_info.is_signer {
+        if !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Validate vote
-        if vote == Vote::None {
-            return Err(SolanaBridgeError::InvalidVote.into());
+        // Check asset name length
+        if name.len() > MAX_NAME_LEN {
+            return Err(SolanaBridgeError::TokenNameLenLimit.into());
         }
 
-        // Validate Withdrawal Account
-        let mut withdrawal_account_data =
-            Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+        // Check asset symbol length
+        if symbol.len() > MAX_SYMBOL_LEN {
+            return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+        }
 
-        let round_number = withdrawal_account_data.round_number;
-        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-        let event_configuration = withdrawal_account_data.pda.event_configuration;
-        let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
-        let (nonce, _) = withdrawal_account_data
+        if solana_decimals > MAX_SOLANA_DECIMALS {
+            return Err(SolanaBridgeError::InvalidTokenDecimals.into());
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
             .account_kind
-            .into_proposal()
+            .into_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        bridge_utils::helper::validate_proposal_account(
+        bridge_utils::helper::validate_settings_account(
             program_id,
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            &event_data,
-            nonce,
-            withdrawal_account_info,
+            settings_nonce,
+            settings_account_info,
         )?;
 
-        // Validate Relay Round Account
-        let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
-        let relay_round_nonce = relay_round_account_data
-            .account_kind
-            .into_relay_round()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
 
-        round_loader::validate_relay_round_account(
-            &round_loader::id(),
-            withdrawal_account_data.round_number,
-            relay_round_nonce,
-            relay_round_account_info,
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
+        }
+
+        let token_defaults = token_defaults
+            .unwrap_or_else(|| settings_account_data.ever_token_defaults(mint_account_info.key));
+
+        if token_defaults.fee_deposit_info.divisor == 0
+            || token_defaults.fee_withdrawal_info.divisor == 0
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if token_settings_account_info.lamports() != 0 || mint_account_info.lamports() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Decimals set by manager take precedence, as for the mint created on withdrawal
+        let solana_decimals = get_solana_decimals(program_id, accounts, &token, solana_decimals)?;
+
+        // Create Mint Account
+        let token_hash = hash(&token.try_to_vec()?);
+
+        let (mint_pubkey, mint_nonce) =
+            Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], program_id);
+        let mint_account_signer_seeds: &[&[_]] = &[br"mint", token_hash.as_ref(), &[mint_nonce]];
+
+        if mint_pubkey != *mint_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                mint_account_info.key,
+                1.max(rent.minimum_balance(spl_token::state::Mint::LEN)),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::id(),
+            ),
+            accounts,
+            &[mint_account_signer_seeds],
+        )?;
+
+        // Init Mint Account
+        invoke_signed(
+            &spl_token::instruction::initialize_mint(
+                &spl_token::id(),
+                mint_account_info.key,
+                mint_account_info.key,
+                None,
+                solana_decimals,
+            )?,
+            accounts,
+            &[mint_account_signer_seeds],
         )?;
 
-        // Vote for withdraw request
-        let index = relay_round_account_data
-            .relays
-            .iter()
-            .position(|pubkey| pubkey == relay_account_info.key)
-            .ok_or(SolanaBridgeError::InvalidRelay)?;
+        // Create Token Settings Account
+        let (token_settings_pubkey, token_settings_nonce) =
+            Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], program_id);
+        let token_settings_account_signer_seeds: &[&[_]] =
+            &[br"settings", token_hash.as_ref(), &[token_settings_nonce]];
 
-        if withdrawal_account_data.signers[index] == Vote::None {
-            // Vote for proposal
-            withdrawal_account_data.signers[index] = vote;
-            withdrawal_account_data.pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+        if token_settings_pubkey != *token_settings_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-            // Get back voting reparation to Relay
-            let withdrawal_starting_lamports = withdrawal_account_info.lamports();
-            **withdrawal_account_info.lamports.borrow_mut() = withdrawal_starting_lamports
-                .checked_sub(RELAY_REPARATION)
-                .ok_or(SolanaBridgeError::Overflow)?;
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                token_settings_account_info.key,
+                1.max(rent.minimum_balance(TokenSettings::LEN)),
+                TokenSettings::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[token_settings_account_signer_seeds],
+        )?;
 
-            let relay_starting_lamports = relay_account_info.lamports();
-            **relay_account_info.lamports.borrow_mut() = relay_starting_lamports
-                .checked_add(RELAY_REPARATION)
-                .ok_or(SolanaBridgeError::Overflow)?;
+        // Init Token Settings Account
+        let token_settings_account_data = TokenSettings {
+            is_initialized: true,
+            account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+            kind: TokenKind::Ever {
+                mint: *mint_account_info.key,
+                decimals: ever_decimals,
+                token,
+            },
+            name,
+            symbol,
+            withdrawal_epoch: 0,
+            deposit_limit: token_defaults.deposit_limit,
+            withdrawal_limit: token_defaults.withdrawal_limit,
+            withdrawal_daily_limit: token_defaults.withdrawal_daily_limit,
+            withdrawal_daily_amount: 0,
+            emergency: false,
+            fee_supply: Default::default(),
+            fee_deposit_info: token_defaults.fee_deposit_info,
+            fee_withdrawal_info: token_defaults.fee_withdrawal_info,
+            deposit_daily_limit: token_defaults.deposit_daily_limit,
+            deposit_daily_amount: 0,
+            deposit_epoch: 0,
+            withdrawal_epoch_length: None,
+            emergency_expiry: None,
+            emergency_info: None,
+            delisted: false,
+            authority_migration: None,
+            deposits_paused: false,
+            withdrawals_paused: false,
+            vote_reward: 0,
+            withdrawal_limit_exemptions: vec![],
+            version: CURRENT_ACCOUNT_VERSION,
+            fee_deposit_tiers: Default::default(),
+            fee_withdrawal_tiers: Default::default(),
+            min_deposit_amount: 0,
+            fills_restricted: false,
+            referral_fee_bps: 0,
+            pending_withdrawals: 0,
+            closed: false,
+        };
+
+        solana_program::log::sol_log_data(&[&TokenSettingsEvent {
+            account: token_settings_pubkey,
+            symbol: token_settings_account_data.symbol.clone(),
+            name: token_settings_account_data.name.clone(),
+            mint: *mint_account_info.key,
+            vault: None,
+            ever_decimals: Some(ever_decimals),
+            solana_decimals: Some(solana_decimals),
+            root: Some(token),
+            fee: token_defaults.fee_withdrawal_info,
         }
+        .try_to_vec()?]);
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
 
         Ok(())
     }
 
-    fn process_withdraw_multi_token_ever(
+    fn process_register_token_sol(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        name: String,
+        symbol: String,
+        token_defaults: Option<TokenDefaults>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let vault_account_info = next_account_info(account_info_iter)?;
         let mint_account_info = next_account_info(account_info_iter)?;
-        let recipient_account_info = next_account_info(account_info_iter)?;
         let token_settings_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
         let _system_program_info = next_account_info(account_info_iter)?;
         let _token_program_info = next_account_info(account_info_iter)?;
-
         let rent_sysvar_info = next_account_info(account_info_iter)?;
         let rent = &Rent::from_account_info(rent_sysvar_info)?;
 
-        let clock_info = next_account_info(account_info_iter)?;
-        let clock = Clock::from_account_info(clock_info)?;
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Check asset name length
+        if name.len() > MAX_NAME_LEN {
+            return Err(SolanaBridgeError::TokenNameLenLimit.into());
+        }
+
+        // Check asset symbol length
+        if symbol.len() > MAX_SYMBOL_LEN {
+            return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+        }
 
         // Validate Settings Account
         let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-        let (settings_nonce, _) = settings_account_data
+        let (settings_nonce, programdata_nonce) = settings_account_data
             .account_kind
             .into_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
@@ -1512,347 +10583,452 @@ impl Processor {
             settings_account_info,
         )?;
 
-        if settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
-        // Validate Withdrawal Account
-        let mut withdrawal_account_data =
-            WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
-        let round_number = withdrawal_account_data.round_number;
-        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-        let event_configuration = withdrawal_account_data.pda.event_configuration;
-        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
-        let (nonce, _) = withdrawal_account_data
-            .account_kind
-            .into_proposal()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        let token_defaults = token_defaults
+            .or(settings_account_data.token_defaults)
+            .unwrap_or_default();
 
-        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+        if token_defaults.fee_deposit_info.divisor == 0
+            || token_defaults.fee_withdrawal_info.divisor == 0
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if token_settings_account_info.lamports() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Validate Mint Account
+        if mint_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
+
+        // Create Vault Account
+        let (vault_pubkey, vault_nonce) = Pubkey::find_program_address(
+            &[br"vault", &mint_account_info.key.to_bytes()],
             program_id,
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            &event_data,
-            nonce,
-            withdrawal_account_info,
+        );
+        let vault_account_signer_seeds: &[&[_]] =
+            &[br"vault", &mint_account_info.key.to_bytes(), &[vault_nonce]];
+
+        if vault_pubkey != *vault_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                vault_account_info.key,
+                1.max(rent.minimum_balance(spl_token::state::Account::LEN)),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::id(),
+            ),
+            accounts,
+            &[vault_account_signer_seeds],
         )?;
 
-        // If token settings account is not created
-        if token_settings_account_info.lamports() == 0 {
-            let funder_account_info = match next_account_info(account_info_iter) {
-                Ok(funder_account_info) => funder_account_info,
-                Err(_) => return Ok(()),
-            };
+        // Init Vault Account
+        invoke_signed(
+            &spl_token::instruction::initialize_account3(
+                &spl_token::id(),
+                vault_account_info.key,
+                mint_account_info.key,
+                vault_account_info.key,
+            )?,
+            accounts,
+            &[vault_account_signer_seeds],
+        )?;
 
-            // Create Mint Account
-            let ever_decimals = withdrawal_account_data.event.data.decimals;
-            let solana_decimals = if ever_decimals > spl_token::native_mint::DECIMALS {
-                spl_token::native_mint::DECIMALS
-            } else {
-                ever_decimals
-            };
-            let token = hash(&withdrawal_account_data.event.data.token.try_to_vec()?);
+        // Create Token Settings Account
+        let (token_settings_pubkey, token_settings_nonce) = Pubkey::find_program_address(
+            &[br"settings", &mint_account_info.key.to_bytes()],
+            program_id,
+        );
+        let token_settings_account_signer_seeds: &[&[_]] = &[
+            br"settings",
+            &mint_account_info.key.to_bytes(),
+            &[token_settings_nonce],
+        ];
 
-            let (mint_pubkey, mint_nonce) =
-                Pubkey::find_program_address(&[br"mint", token.as_ref()], program_id);
-            let mint_account_signer_seeds: &[&[_]] = &[br"mint", token.as_ref(), &[mint_nonce]];
+        if token_settings_pubkey != *token_settings_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-            if mint_pubkey != *mint_account_info.key {
-                return Err(ProgramError::InvalidArgument);
-            }
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                token_settings_account_info.key,
+                1.max(rent.minimum_balance(TokenSettings::LEN)),
+                TokenSettings::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[token_settings_account_signer_seeds],
+        )?;
 
-            invoke_signed(
-                &system_instruction::create_account(
-                    funder_account_info.key,
-                    mint_account_info.key,
-                    1.max(rent.minimum_balance(spl_token::state::Mint::LEN)),
-                    spl_token::state::Mint::LEN as u64,
-                    &spl_token::id(),
-                ),
-                accounts,
-                &[mint_account_signer_seeds],
-            )?;
+        // Init Token Settings Account
+        let token_settings_account_data = TokenSettings {
+            is_initialized: true,
+            account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+            kind: TokenKind::Solana {
+                mint: *mint_account_info.key,
+                vault: *vault_account_info.key,
+            },
+            name,
+            symbol,
+            withdrawal_epoch: 0,
+            deposit_limit: token_defaults.deposit_limit,
+            withdrawal_limit: token_defaults.withdrawal_limit,
+            withdrawal_daily_limit: token_defaults.withdrawal_daily_limit,
+            withdrawal_daily_amount: 0,
+            emergency: false,
+            fee_supply: Default::default(),
+            fee_deposit_info: token_defaults.fee_deposit_info,
+            fee_withdrawal_info: token_defaults.fee_withdrawal_info,
+            deposit_daily_limit: token_defaults.deposit_daily_limit,
+            deposit_daily_amount: 0,
+            deposit_epoch: 0,
+            withdrawal_epoch_length: None,
+            emergency_expiry: None,
+            emergency_info: None,
+            delisted: false,
+            authority_migration: None,
+            deposits_paused: false,
+            withdrawals_paused: false,
+            vote_reward: 0,
+            withdrawal_limit_exemptions: vec![],
+            version: CURRENT_ACCOUNT_VERSION,
+            fee_deposit_tiers: Default::default(),
+            fee_withdrawal_tiers: Default::default(),
+            min_deposit_amount: 0,
+            fills_restricted: false,
+            referral_fee_bps: 0,
+            pending_withdrawals: 0,
+            closed: false,
+        };
 
-            // Init Mint Account
-            invoke_signed(
-                &spl_token::instruction::initialize_mint(
-                    &spl_token::id(),
-                    mint_account_info.key,
-                    mint_account_info.key,
-                    None,
-                    solana_decimals,
-                )?,
-                accounts,
-                &[mint_account_signer_seeds],
-            )?;
+        solana_program::log::sol_log_data(&[&TokenSettingsEvent {
+            account: token_settings_pubkey,
+            symbol: token_settings_account_data.symbol.clone(),
+            name: token_settings_account_data.name.clone(),
+            mint: *mint_account_info.key,
+            vault: Some(*vault_account_info.key),
+            ever_decimals: None,
+            solana_decimals: None,
+            root: None,
+            fee: token_defaults.fee_deposit_info,
+        }
+        .try_to_vec()?]);
 
-            // Create Token Account
-            invoke(
-                &spl_associated_token_account::instruction::create_associated_token_account(
-                    funder_account_info.key,
-                    &withdrawal_account_data.event.data.recipient,
-                    mint_account_info.key,
-                    &spl_token::id(),
-                ),
-                accounts,
-            )?;
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
 
-            // Create Token Settings Account
-            let (token_settings_pubkey, token_settings_nonce) =
-                Pubkey::find_program_address(&[br"settings", token.as_ref()], program_id);
-            let token_settings_account_signer_seeds: &[&[_]] =
-                &[br"settings", token.as_ref(), &[token_settings_nonce]];
+        Ok(())
+    }
 
-            if token_settings_pubkey != *token_settings_account_info.key {
-                return Err(ProgramError::InvalidArgument);
-            }
+    fn process_delist_token(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
 
-            invoke_signed(
-                &system_instruction::create_account(
-                    funder_account_info.key,
-                    token_settings_account_info.key,
-                    1.max(rent.minimum_balance(TokenSettings::LEN)),
-                    TokenSettings::LEN as u64,
-                    program_id,
-                ),
-                accounts,
-                &[token_settings_account_signer_seeds],
-            )?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
 
-            // Init Token Settings Account
-            let token_settings_account_data = TokenSettings {
-                is_initialized: true,
-                account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
-                kind: TokenKind::Ever {
-                    mint: *mint_account_info.key,
-                    decimals: ever_decimals,
-                    token: withdrawal_account_data.event.data.token,
-                },
-                name: withdrawal_account_data.event.data.name.clone(),
-                symbol: withdrawal_account_data.event.data.symbol.clone(),
-                withdrawal_epoch: 0,
-                deposit_limit: u64::MAX,
-                withdrawal_limit: u64::MAX,
-                withdrawal_daily_limit: u64::MAX,
-                withdrawal_daily_amount: 0,
-                emergency: false,
-                fee_supply: Default::default(),
-                fee_deposit_info: Default::default(),
-                fee_withdrawal_info: Default::default(),
-            };
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-            solana_program::log::sol_log_data(&[&TokenSettingsEvent {
-                account: token_settings_pubkey,
-                symbol: token_settings_account_data.symbol.clone(),
-                name: token_settings_account_data.name.clone(),
-                mint: mint_account_info.key.clone(),
-                vault: None,
-                ever_decimals: Some(ever_decimals),
-                solana_decimals: Some(solana_decimals),
-                root: Some(withdrawal_account_data.event.data.token),
-                fee: Default::default(),
-            }
-            .try_to_vec()?]);
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-            TokenSettings::pack(
-                token_settings_account_data,
-                &mut token_settings_account_info.data.borrow_mut(),
-            )?;
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
+
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
         }
 
-        // Validate Token Setting Account
+        // Validate Token Settings Account
         let mut token_settings_account_data =
             TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
 
-        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+        let (token_settings_nonce, _) = token_settings_account_data
             .account_kind
             .into_token_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        let (_, token, _) = token_settings_account_data
-            .kind
-            .into_ever()
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
+            }
+        }
+
+        token_settings_account_data.delisted = true;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_close_token(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let fee_ledger_account_info = next_account_info(account_info_iter)?;
+        let mint_or_vault_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        validate_token_settings_ever_account(
+        bridge_utils::helper::validate_settings_account(
             program_id,
-            &token,
-            token_settings_nonce,
-            token_settings_account_info,
+            settings_nonce,
+            settings_account_info,
         )?;
 
-        if token_settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
         }
 
-        // Validate Mint Account
-        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
 
-        // Check connection between token and proposal
-        if token != withdrawal_account_data.event.data.token {
-            return Err(ProgramError::InvalidArgument);
-        }
+        let (token_settings_nonce, mint_or_vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
-        let solana_decimals = mint_account_data.decimals;
-        let ever_decimals = withdrawal_account_data.event.data.decimals;
+        if !token_settings_account_data.delisted {
+            return Err(SolanaBridgeError::TokenNotDelisted.into());
+        }
 
-        // Do we have enough signers.
-        let sig_count = withdrawal_account_data
-            .signers
-            .iter()
-            .filter(|vote| **vote == Vote::Confirm)
-            .count() as u32;
+        if token_settings_account_data.closed {
+            return Err(SolanaBridgeError::TokenClosed.into());
+        }
 
-        if sig_count >= withdrawal_account_data.required_votes
-            && withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::New
-        {
-            let current_epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
+        // Confirmed withdrawals must be paid out or cancelled before closing
+        if token_settings_account_data.pending_withdrawals != 0 {
+            return Err(SolanaBridgeError::WithdrawalsPending.into());
+        }
 
-            // If current epoch has changed
-            if token_settings_account_data.withdrawal_epoch != current_epoch {
-                token_settings_account_data.withdrawal_epoch = current_epoch;
-                token_settings_account_data.withdrawal_daily_amount = Default::default();
-            }
+        // Fee must be withdrawn before closing
+        if token_settings_account_data.fee_supply != 0 {
+            return Err(SolanaBridgeError::InsufficientBalance.into());
+        }
 
-            // Calculate amount
-            let withdrawal_amount = get_withdrawal_amount(
-                withdrawal_account_data.event.data.amount,
-                ever_decimals,
-                solana_decimals,
-            )?;
+        // Validate Fee Ledger Account
+        let fee_ledger_pubkey =
+            get_associated_fee_ledger_address(program_id, token_settings_account_info.key);
 
-            let fee_info = &token_settings_account_data.fee_withdrawal_info;
+        if fee_ledger_pubkey != *fee_ledger_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-            let fee = 1.max(
-                withdrawal_amount
-                    .checked_div(fee_info.divisor)
-                    .ok_or(SolanaBridgeError::Overflow)?
-                    .checked_mul(fee_info.multiplier)
-                    .ok_or(SolanaBridgeError::Overflow)?,
-            );
+        let is_fee_ledger_created = is_program_account(program_id, fee_ledger_account_info);
 
-            // Increase fee supply
-            token_settings_account_data.fee_supply = token_settings_account_data
-                .fee_supply
-                .checked_add(fee)
-                .ok_or(SolanaBridgeError::Overflow)?;
+        if is_fee_ledger_created {
+            let fee_ledger_account_data = unpack_fee_ledger(
+                program_id,
+                token_settings_account_info,
+                fee_ledger_account_info,
+            )?;
 
-            // Amount without fee
-            let transfer_withdrawal_amount = withdrawal_amount
-                .checked_sub(fee)
-                .ok_or(SolanaBridgeError::Overflow)?;
+            if fee_ledger_account_data.supply != 0 {
+                return Err(SolanaBridgeError::InsufficientBalance.into());
+            }
+        }
 
-            // Increase withdrawal daily amount
-            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
-                .withdrawal_daily_amount
-                .checked_add(transfer_withdrawal_amount)
-                .ok_or(SolanaBridgeError::Overflow)?;
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
 
-            if transfer_withdrawal_amount > token_settings_account_data.withdrawal_limit
-                || token_settings_account_data.withdrawal_daily_amount
-                    > token_settings_account_data.withdrawal_daily_limit
-            {
-                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
-            } else {
-                match withdrawal_account_data.event.data.payload.is_empty() {
-                    true => {
-                        // Validate Recipient Account
-                        let recipient_account_data = spl_token::state::Account::unpack(
-                            &recipient_account_info.data.borrow(),
-                        )?;
+                // Validate Mint Account
+                validate_mint_account(
+                    program_id,
+                    &token,
+                    mint_or_vault_nonce,
+                    mint_or_vault_account_info,
+                )?;
 
-                        if recipient_account_data.owner
-                            != withdrawal_account_data.event.data.recipient
-                        {
-                            return Err(ProgramError::InvalidArgument);
-                        }
+                // Tokens in circulation must be bridged back before closing
+                let mint_account_data =
+                    spl_token::state::Mint::unpack(&mint_or_vault_account_info.data.borrow())?;
 
-                        make_ever_transfer(
-                            mint_account_info,
-                            recipient_account_info,
-                            &token_settings_account_data,
-                            accounts,
-                            transfer_withdrawal_amount,
-                        )?;
+                if mint_account_data.supply != 0 {
+                    return Err(SolanaBridgeError::InsufficientBalance.into());
+                }
+            }
+            TokenKind::Solana { mint, vault } => {
+                validate_token_settings_sol_account(
+                    program_id,
+                    &mint,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
 
-                        withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
-                    }
-                    false => {
-                        // Validate Proxy Account
-                        let proxy_nonce = withdrawal_account_data
-                            .account_kind
-                            .into_proposal()
-                            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
-                            .1
-                            .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+                // Validate Vault Account
+                if *mint_or_vault_account_info.key != vault {
+                    return Err(ProgramError::InvalidArgument);
+                }
 
-                        validate_proxy_account(
-                            program_id,
-                            mint_account_info.key,
-                            &withdrawal_account_data.event.data.recipient,
-                            proxy_nonce,
-                            recipient_account_info,
-                        )?;
+                validate_vault_account(
+                    program_id,
+                    &mint,
+                    mint_or_vault_nonce,
+                    mint_or_vault_account_info,
+                )?;
 
-                        // Make transfer to Proxy Account
-                        make_ever_transfer(
-                            mint_account_info,
-                            recipient_account_info,
-                            &token_settings_account_data,
-                            accounts,
-                            transfer_withdrawal_amount,
-                        )?;
+                // Vault must be drained before closing
+                let vault_account_data =
+                    spl_token::state::Account::unpack(&mint_or_vault_account_info.data.borrow())?;
 
-                        withdrawal_account_data.meta.data.status =
-                            WithdrawalTokenStatus::WaitingForExecute;
-                    }
+                if vault_account_data.amount != 0 {
+                    return Err(SolanaBridgeError::InsufficientBalance.into());
                 }
+
+                // Close Vault Account
+                invoke_signed(
+                    &spl_token::instruction::close_account(
+                        &spl_token::id(),
+                        mint_or_vault_account_info.key,
+                        authority_account_info.key,
+                        mint_or_vault_account_info.key,
+                        &[mint_or_vault_account_info.key],
+                    )?,
+                    accounts,
+                    &[&[br"vault", &mint.to_bytes(), &[mint_or_vault_nonce]]],
+                )?;
             }
+        }
 
-            TokenSettings::pack(
-                token_settings_account_data,
-                &mut token_settings_account_info.data.borrow_mut(),
-            )?;
+        // Settings are kept so that the token can't be listed again by deposit or withdrawal
+        token_settings_account_data.closed = true;
+        token_settings_account_data.deposits_paused = true;
+        token_settings_account_data.withdrawals_paused = true;
 
-            solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
-                account: withdrawal_pubkey,
-                status: withdrawal_account_data.meta.data.status,
-            }
-            .try_to_vec()?]);
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
 
-            WithdrawalMultiTokenEver::pack(
-                withdrawal_account_data,
-                &mut withdrawal_account_info.data.borrow_mut(),
-            )?;
+        if is_fee_ledger_created {
+            delete_account(fee_ledger_account_info, authority_account_info)?;
         }
 
         Ok(())
     }
 
-    fn process_withdraw_multi_token_sol(
+    fn process_propose_mint_authority(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        new_authority: Option<Pubkey>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
-        let vault_account_info = next_account_info(account_info_iter)?;
-        let recipient_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
         let token_settings_account_info = next_account_info(account_info_iter)?;
-        let mint_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
-        let _token_program_info = next_account_info(account_info_iter)?;
-        let clock_info = next_account_info(account_info_iter)?;
-        let clock = Clock::from_account_info(clock_info)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
         // Validate Settings Account
         let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-        let (settings_nonce, _) = settings_account_data
+        let (settings_nonce, programdata_nonce) = settings_account_data
             .account_kind
             .into_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
@@ -1863,437 +11039,453 @@ impl Processor {
             settings_account_info,
         )?;
 
-        if settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
-
-        // Validate Withdrawal Account
-        let mut withdrawal_account_data =
-            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
-        let round_number = withdrawal_account_data.round_number;
-        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-        let event_configuration = withdrawal_account_data.pda.event_configuration;
-        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
-        let (nonce, _) = withdrawal_account_data
-            .account_kind
-            .into_proposal()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
             program_id,
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            &event_data,
-            nonce,
-            withdrawal_account_info,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
         )?;
 
-        let withdrawal_status = withdrawal_account_data.meta.data.status;
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
 
-        // Validate Token Setting Account
+        // Validate Token Settings Account
         let mut token_settings_account_data =
             TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
 
-        let (token_settings_nonce, vault_nonce) = token_settings_account_data
+        let (token_settings_nonce, _) = token_settings_account_data
             .account_kind
             .into_token_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        let (mint, _) = token_settings_account_data
+        let (_, token, _) = token_settings_account_data
             .kind
-            .into_solana()
+            .into_ever()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        validate_token_settings_sol_account(
+        validate_token_settings_ever_account(
             program_id,
-            &mint,
+            &token,
             token_settings_nonce,
             token_settings_account_info,
         )?;
 
-        if token_settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
-
-        // Validate Vault Account
-        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
-
-        // Check connection between token and proposal
-        if mint != withdrawal_account_data.event.data.mint {
-            return Err(ProgramError::InvalidArgument);
-        }
-
-        // Do we have enough signers.
-        let sig_count = withdrawal_account_data
-            .signers
-            .iter()
-            .filter(|vote| **vote == Vote::Confirm)
-            .count() as u32;
-
-        if sig_count >= withdrawal_account_data.required_votes {
-            let withdrawal_amount = withdrawal_account_data.event.data.amount as u64;
-
-            let fee_info = &mut token_settings_account_data.fee_withdrawal_info;
-
-            let fee = 1.max(
-                withdrawal_amount
-                    .checked_div(fee_info.divisor)
-                    .ok_or(SolanaBridgeError::Overflow)?
-                    .checked_mul(fee_info.multiplier)
+        token_settings_account_data.authority_migration = match new_authority {
+            Some(new_authority) => Some(AuthorityMigration {
+                new_authority,
+                unlock_timestamp: Clock::get()?
+                    .unix_timestamp
+                    .checked_add(AUTHORITY_MIGRATION_TIMELOCK)
                     .ok_or(SolanaBridgeError::Overflow)?,
-            );
-
-            // Amount without fee
-            let transfer_withdrawal_amount = withdrawal_amount
-                .checked_sub(fee)
-                .ok_or(SolanaBridgeError::Overflow)?;
-
-            match withdrawal_status {
-                WithdrawalTokenStatus::New => {
-                    let current_epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
-
-                    // If current epoch has changed
-                    if token_settings_account_data.withdrawal_epoch != current_epoch {
-                        token_settings_account_data.withdrawal_epoch = current_epoch;
-                        token_settings_account_data.withdrawal_daily_amount = Default::default();
-                    }
-
-                    // Increase withdrawal daily amount
-                    token_settings_account_data.withdrawal_daily_amount =
-                        token_settings_account_data
-                            .withdrawal_daily_amount
-                            .checked_add(transfer_withdrawal_amount)
-                            .ok_or(SolanaBridgeError::Overflow)?;
-
-                    // Increase fee supply
-                    token_settings_account_data.fee_supply = token_settings_account_data
-                        .fee_supply
-                        .checked_add(fee)
-                        .ok_or(SolanaBridgeError::Overflow)?;
-
-                    if transfer_withdrawal_amount > token_settings_account_data.withdrawal_limit
-                        || token_settings_account_data.withdrawal_daily_amount
-                            > token_settings_account_data.withdrawal_daily_limit
-                    {
-                        withdrawal_account_data.meta.data.status =
-                            WithdrawalTokenStatus::WaitingForApprove;
-                    } else {
-                        match withdrawal_account_data.event.data.payload.is_empty() {
-                            true => {
-                                // Validate Recipient Account
-                                let recipient_account_data = spl_token::state::Account::unpack(
-                                    &recipient_account_info.data.borrow(),
-                                )?;
-
-                                if recipient_account_data.owner
-                                    != withdrawal_account_data.event.data.recipient
-                                {
-                                    return Err(ProgramError::InvalidArgument);
-                                }
-
-                                let vault_account_data = spl_token::state::Account::unpack(
-                                    &vault_account_info.data.borrow(),
-                                )?;
+            }),
+            None => None,
+        };
 
-                                if transfer_withdrawal_amount > vault_account_data.amount {
-                                    withdrawal_account_data.meta.data.status =
-                                        WithdrawalTokenStatus::Pending;
-                                } else {
-                                    make_sol_transfer(
-                                        vault_account_info,
-                                        recipient_account_info,
-                                        &token_settings_account_data,
-                                        accounts,
-                                        transfer_withdrawal_amount,
-                                    )?;
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
 
-                                    withdrawal_account_data.meta.data.status =
-                                        WithdrawalTokenStatus::Processed;
-                                }
-                            }
-                            false => {
-                                // Validate Proxy Account
-                                let proxy_nonce = withdrawal_account_data
-                                    .account_kind
-                                    .into_proposal()
-                                    .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
-                                    .1
-                                    .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+        Ok(())
+    }
 
-                                validate_proxy_account(
-                                    program_id,
-                                    mint_account_info.key,
-                                    &withdrawal_account_data.event.data.recipient,
-                                    proxy_nonce,
-                                    recipient_account_info,
-                                )?;
+    fn process_migrate_mint_authority(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
 
-                                let vault_account_data = spl_token::state::Account::unpack(
-                                    &vault_account_info.data.borrow(),
-                                )?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
 
-                                if transfer_withdrawal_amount > vault_account_data.amount {
-                                    withdrawal_account_data.meta.data.status =
-                                        WithdrawalTokenStatus::Pending;
-                                } else {
-                                    make_sol_transfer(
-                                        vault_account_info,
-                                        recipient_account_info,
-                                        &token_settings_account_data,
-                                        accounts,
-                                        transfer_withdrawal_amount,
-                                    )?;
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
-                                    withdrawal_account_data.meta.data.status =
-                                        WithdrawalTokenStatus::WaitingForExecute;
-                                }
-                            }
-                        }
-                    }
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-                    TokenSettings::pack(
-                        token_settings_account_data,
-                        &mut token_settings_account_info.data.borrow_mut(),
-                    )?;
-                }
-                WithdrawalTokenStatus::Pending => {
-                    match withdrawal_account_data.event.data.payload.is_empty() {
-                        true => {
-                            // Validate Recipient Account
-                            let recipient_account_data = spl_token::state::Account::unpack(
-                                &recipient_account_info.data.borrow(),
-                            )?;
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-                            if recipient_account_data.owner
-                                != withdrawal_account_data.event.data.recipient
-                            {
-                                return Err(ProgramError::InvalidArgument);
-                            }
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
 
-                            let vault_account_data = spl_token::state::Account::unpack(
-                                &vault_account_info.data.borrow(),
-                            )?;
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
 
-                            if transfer_withdrawal_amount > vault_account_data.amount {
-                                withdrawal_account_data.meta.data.status =
-                                    WithdrawalTokenStatus::Pending;
-                            } else {
-                                make_sol_transfer(
-                                    vault_account_info,
-                                    recipient_account_info,
-                                    &token_settings_account_data,
-                                    accounts,
-                                    transfer_withdrawal_amount,
-                                )?;
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
 
-                                withdrawal_account_data.meta.data.status =
-                                    WithdrawalTokenStatus::Processed;
-                            }
-                        }
-                        false => {
-                            // Validate Proxy Account
-                            let proxy_nonce = withdrawal_account_data
-                                .account_kind
-                                .into_proposal()
-                                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
-                                .1
-                                .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
 
-                            validate_proxy_account(
-                                program_id,
-                                mint_account_info.key,
-                                &withdrawal_account_data.event.data.recipient,
-                                proxy_nonce,
-                                recipient_account_info,
-                            )?;
+        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-                            let vault_account_data = spl_token::state::Account::unpack(
-                                &vault_account_info.data.borrow(),
-                            )?;
+        let (_, token, _) = token_settings_account_data
+            .kind
+            .into_ever()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-                            if transfer_withdrawal_amount > vault_account_data.amount {
-                                withdrawal_account_data.meta.data.status =
-                                    WithdrawalTokenStatus::Pending;
-                            } else {
-                                make_sol_transfer(
-                                    vault_account_info,
-                                    recipient_account_info,
-                                    &token_settings_account_data,
-                                    accounts,
-                                    transfer_withdrawal_amount,
-                                )?;
+        validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
 
-                                withdrawal_account_data.meta.data.status =
-                                    WithdrawalTokenStatus::WaitingForExecute;
-                            }
-                        }
-                    }
-                }
-                _ => (),
-            }
+        // Validate Mint Account
+        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
 
-            solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
-                account: withdrawal_pubkey,
-                status: withdrawal_account_data.meta.data.status,
-            }
-            .try_to_vec()?]);
+        // Check timelock
+        let authority_migration = token_settings_account_data
+            .authority_migration
+            .ok_or(SolanaBridgeError::AuthorityMigrationNotProposed)?;
 
-            WithdrawalMultiTokenSol::pack(
-                withdrawal_account_data,
-                &mut withdrawal_account_info.data.borrow_mut(),
-            )?;
+        if Clock::get()?.unix_timestamp < authority_migration.unlock_timestamp {
+            return Err(SolanaBridgeError::AuthorityMigrationTimelocked.into());
         }
 
+        // Set new Mint Authority
+        let token_hash = hash(&token.try_to_vec()?);
+
+        invoke_signed(
+            &spl_token::instruction::set_authority(
+                &spl_token::id(),
+                mint_account_info.key,
+                Some(&authority_migration.new_authority),
+                spl_token::instruction::AuthorityType::MintTokens,
+                mint_account_info.key,
+                &[mint_account_info.key],
+            )?,
+            accounts,
+            &[&[br"mint", token_hash.as_ref(), &[mint_nonce]]],
+        )?;
+
+        // Token can't be minted anymore
+        token_settings_account_data.delisted = true;
+        token_settings_account_data.authority_migration = None;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
         Ok(())
     }
 
-    fn process_execute_payload_ever(
+    fn process_propose_vault_authority(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        new_authority: Option<Pubkey>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
-
-        let mut withdrawal_account_data =
-            WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
 
-        if withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::WaitingForExecute {
-            let mint = get_associated_mint(program_id, &withdrawal_account_data.event.data.token);
-            let recipient = withdrawal_account_data.event.data.recipient;
-            let (_, nonce) = withdrawal_account_data
-                .account_kind
-                .into_proposal()
-                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
-            let proxy_signer_seeds: &[&[_]] = &[
-                br"proxy",
-                &mint.to_bytes(),
-                &recipient.to_bytes(),
-                &[nonce.unwrap_or_default()],
-            ];
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-            let ixs: Vec<solana_program::instruction::Instruction> =
-                bincode::deserialize(&withdrawal_account_data.event.data.payload)
-                    .map_err(|_| SolanaBridgeError::DeserializePayload)?;
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-            for ix in ixs {
-                invoke_signed(&ix, accounts, &[proxy_signer_seeds])?;
-            }
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
 
-            withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
 
-            WithdrawalMultiTokenEver::pack(
-                withdrawal_account_data,
-                &mut withdrawal_account_info.data.borrow_mut(),
-            )?;
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
         }
 
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        token_settings_account_data.authority_migration = match new_authority {
+            Some(new_authority) => Some(AuthorityMigration {
+                new_authority,
+                unlock_timestamp: Clock::get()?
+                    .unix_timestamp
+                    .checked_add(AUTHORITY_MIGRATION_TIMELOCK)
+                    .ok_or(SolanaBridgeError::Overflow)?,
+            }),
+            None => None,
+        };
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
         Ok(())
     }
 
-    fn process_execute_payload_sol(
-        _program_id: &Pubkey,
+    fn process_migrate_vault_authority(
+        program_id: &Pubkey,
         accounts: &[AccountInfo],
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let vault_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
 
-        let mut withdrawal_account_data =
-            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
-        if withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::WaitingForExecute {
-            let mint = withdrawal_account_data.event.data.mint;
-            let recipient = withdrawal_account_data.event.data.recipient;
-            let (_, nonce) = withdrawal_account_data
-                .account_kind
-                .into_proposal()
-                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        // Validate Settings Account
+        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-            let proxy_signer_seeds: &[&[_]] = &[
-                br"proxy",
-                &mint.to_bytes(),
-                &recipient.to_bytes(),
-                &[nonce.unwrap_or_default()],
-            ];
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-            let ixs: Vec<solana_program::instruction::Instruction> =
-                bincode::deserialize(&withdrawal_account_data.event.data.payload)
-                    .map_err(|_| SolanaBridgeError::DeserializePayload)?;
+        bridge_utils::helper::validate_settings_account(
+            program_id,
+            settings_nonce,
+            settings_account_info,
+        )?;
 
-            for ix in ixs {
-                invoke_signed(&ix, accounts, &[proxy_signer_seeds])?;
-            }
+        // Validate Owner Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
 
-            withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
 
-            WithdrawalMultiTokenSol::pack(
-                withdrawal_account_data,
-                &mut withdrawal_account_info.data.borrow_mut(),
-            )?;
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (token_settings_nonce, vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, vault) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        // Validate Vault Account
+        if *vault_account_info.key != vault {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
+
+        // Check timelock
+        let authority_migration = token_settings_account_data
+            .authority_migration
+            .ok_or(SolanaBridgeError::AuthorityMigrationNotProposed)?;
+
+        if Clock::get()?.unix_timestamp < authority_migration.unlock_timestamp {
+            return Err(SolanaBridgeError::AuthorityMigrationTimelocked.into());
         }
 
+        // Set new Vault Owner
+        invoke_signed(
+            &spl_token::instruction::set_authority(
+                &spl_token::id(),
+                vault_account_info.key,
+                Some(&authority_migration.new_authority),
+                spl_token::instruction::AuthorityType::AccountOwner,
+                vault_account_info.key,
+                &[vault_account_info.key],
+            )?,
+            accounts,
+            &[&[br"vault", &mint.to_bytes(), &[vault_nonce]]],
+        )?;
+
+        // Vault can't be spent by this program anymore
+        token_settings_account_data.delisted = true;
+        token_settings_account_data.authority_migration = None;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
         Ok(())
     }
 
-    fn process_change_guardian(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        new_guardian: Pubkey,
-    ) -> ProgramResult {
+    fn process_migrate_settings_v2(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
+        let funder_account_info = next_account_info(account_info_iter)?;
         let authority_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
         let programdata_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+        let denylist_account_info = next_account_info(account_info_iter)?;
 
         if !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Validate Settings Account
-        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
-
-        let (settings_nonce, programdata_nonce) = settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        // Validate Owner Account
+        let (programdata_pubkey, _) =
+            Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
 
-        bridge_utils::helper::validate_settings_account(
-            program_id,
-            settings_nonce,
-            settings_account_info,
-        )?;
+        if programdata_pubkey != *programdata_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        // Validate Initializer Account
-        bridge_utils::helper::validate_programdata_account(
-            program_id,
-            programdata_nonce,
-            programdata_account_info.key,
-        )?;
         bridge_utils::helper::validate_initializer_account(
             authority_account_info.key,
             programdata_account_info,
         )?;
 
-        settings_account_data.guardian = new_guardian;
+        // Validate Settings Account
+        let (settings_pubkey, _) = Pubkey::find_program_address(&[br"settings"], program_id);
+
+        if settings_pubkey != *settings_account_info.key
+            || settings_account_info.owner != program_id
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if settings_account_info.data_len() >= Settings::LEN {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Expand Settings Account. New fields are read from zeroed bytes as their defaults
+        expand_account(
+            funder_account_info,
+            settings_account_info,
+            accounts,
+            rent,
+            Settings::LEN,
+        )?;
+
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        settings_account_data
+            .account_kind
+            .into_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        // Keep existing token payloads working until the manager configures the allowlist
+        if settings_account_data.payload_allowlist.is_empty() {
+            settings_account_data.payload_allowlist = vec![spl_token::id()];
+        }
 
         Settings::pack(
             settings_account_data,
             &mut settings_account_info.data.borrow_mut(),
         )?;
 
+        // Create Denylist Account unless an address was already added
+        if !is_program_account(program_id, denylist_account_info) {
+            create_denylist_account(
+                program_id,
+                funder_account_info,
+                denylist_account_info,
+                system_program_info,
+                rent,
+            )?;
+        }
+
         Ok(())
     }
 
-    fn process_change_manager(
+    fn process_change_wever(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        new_manager: Pubkey,
+        new_wever_mint: Option<Pubkey>,
+        new_wever_defaults: Option<TokenDefaults>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
@@ -2305,6 +11497,14 @@ impl Processor {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        if let Some(new_wever_defaults) = &new_wever_defaults {
+            if new_wever_defaults.fee_deposit_info.divisor == 0
+                || new_wever_defaults.fee_withdrawal_info.divisor == 0
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
+
         // Validate Settings Account
         let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
@@ -2330,7 +11530,13 @@ impl Processor {
             programdata_account_info,
         )?;
 
-        settings_account_data.manager = new_manager;
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
+
+        settings_account_data.wever_mint = new_wever_mint;
+        settings_account_data.wever_defaults = new_wever_defaults;
 
         Settings::pack(
             settings_account_data,
@@ -2340,10 +11546,10 @@ impl Processor {
         Ok(())
     }
 
-    fn process_change_withdrawal_manager(
+    fn process_set_treasury(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        new_withdrawal_manager: Pubkey,
+        treasury: Option<Pubkey>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
@@ -2380,7 +11586,12 @@ impl Processor {
             programdata_account_info,
         )?;
 
-        settings_account_data.withdrawal_manager = new_withdrawal_manager;
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
+        }
+
+        settings_account_data.treasury = treasury;
 
         Settings::pack(
             settings_account_data,
@@ -2390,23 +11601,27 @@ impl Processor {
         Ok(())
     }
 
-    fn process_change_deposit_limit(
+    fn process_change_enabled_chains(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        new_deposit_limit: u64,
+        enabled_chains: Vec<u64>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
         let authority_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
 
         if !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        if enabled_chains.len() > MAX_ENABLED_CHAINS_LEN {
+            return Err(SolanaBridgeError::EnabledChainsLenLimit.into());
+        }
+
         // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
         let (settings_nonce, programdata_nonce) = settings_account_data
             .account_kind
@@ -2419,65 +11634,37 @@ impl Processor {
             settings_account_info,
         )?;
 
-        // Validate Manager Account
-        if *authority_account_info.key != settings_account_data.manager {
-            let programdata_account_info = next_account_info(account_info_iter)?;
-
-            // Validate Initializer Account
-            bridge_utils::helper::validate_programdata_account(
-                program_id,
-                programdata_nonce,
-                programdata_account_info.key,
-            )?;
-            bridge_utils::helper::validate_initializer_account(
-                authority_account_info.key,
-                programdata_account_info,
-            )?;
-        }
-
-        // Validate Token Settings Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
-
-        let (token_settings_nonce, _) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
 
-        match token_settings_account_data.kind {
-            TokenKind::Ever { token, .. } => {
-                validate_token_settings_ever_account(
-                    program_id,
-                    &token,
-                    token_settings_nonce,
-                    token_settings_account_info,
-                )?;
-            }
-            TokenKind::Solana { mint, .. } => {
-                validate_token_settings_sol_account(
-                    program_id,
-                    &mint,
-                    token_settings_nonce,
-                    token_settings_account_info,
-                )?;
-            }
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
         }
 
-        token_settings_account_data.deposit_limit = new_deposit_limit;
+        settings_account_data.enabled_chains = enabled_chains;
 
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
         )?;
 
         Ok(())
     }
 
-    fn process_change_withdrawal_limits(
+    fn process_pause_token(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        new_withdrawal_limit: Option<u64>,
-        new_withdrawal_daily_limit: Option<u64>,
+        deposits: bool,
+        withdrawals: bool,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
@@ -2503,8 +11690,12 @@ impl Processor {
             settings_account_info,
         )?;
 
-        // Validate Manager Account
-        if *authority_account_info.key != settings_account_data.manager {
+        // Validate Guardian Account
+        if *authority_account_info.key != settings_account_data.guardian
+            && !settings_account_data
+                .guardians
+                .contains(authority_account_info.key)
+        {
             let programdata_account_info = next_account_info(account_info_iter)?;
 
             // Validate Initializer Account
@@ -2517,6 +11708,11 @@ impl Processor {
                 authority_account_info.key,
                 programdata_account_info,
             )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
         // Validate Token Settings Account
@@ -2547,129 +11743,27 @@ impl Processor {
             }
         }
 
-        if let Some(new_withdrawal_limit) = new_withdrawal_limit {
-            token_settings_account_data.withdrawal_limit = new_withdrawal_limit;
-        }
-
-        if let Some(new_withdrawal_daily_limit) = new_withdrawal_daily_limit {
-            token_settings_account_data.withdrawal_daily_limit = new_withdrawal_daily_limit;
-        }
-
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
-        )?;
-
-        Ok(())
-    }
-
-    fn process_enable_emergency_mode(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
-
-        let authority_account_info = next_account_info(account_info_iter)?;
-        let settings_account_info = next_account_info(account_info_iter)?;
-
-        if !authority_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        // Validate Settings Account
-        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
-
-        let (settings_nonce, programdata_nonce) = settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        bridge_utils::helper::validate_settings_account(
-            program_id,
-            settings_nonce,
-            settings_account_info,
-        )?;
-
-        let guardian = settings_account_data.guardian;
-
-        // Validate Guardian Account
-        if *authority_account_info.key != guardian {
-            let programdata_account_info = next_account_info(account_info_iter)?;
-
-            // Validate Initializer Account
-            bridge_utils::helper::validate_programdata_account(
-                program_id,
-                programdata_nonce,
-                programdata_account_info.key,
-            )?;
-            bridge_utils::helper::validate_initializer_account(
-                authority_account_info.key,
-                programdata_account_info,
-            )?;
-        }
-
-        settings_account_data.emergency = true;
-
-        Settings::pack(
-            settings_account_data,
-            &mut settings_account_info.data.borrow_mut(),
-        )?;
-
-        Ok(())
-    }
-
-    fn process_disable_emergency_mode(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
-
-        let authority_account_info = next_account_info(account_info_iter)?;
-        let settings_account_info = next_account_info(account_info_iter)?;
-        let programdata_account_info = next_account_info(account_info_iter)?;
-
-        if !authority_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
+        if deposits {
+            token_settings_account_data.deposits_paused = true;
         }
 
-        // Validate Settings Account
-        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
-
-        let (settings_nonce, programdata_nonce) = settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        bridge_utils::helper::validate_settings_account(
-            program_id,
-            settings_nonce,
-            settings_account_info,
-        )?;
-
-        // Validate Owner Account
-        bridge_utils::helper::validate_programdata_account(
-            program_id,
-            programdata_nonce,
-            programdata_account_info.key,
-        )?;
-        bridge_utils::helper::validate_initializer_account(
-            authority_account_info.key,
-            programdata_account_info,
-        )?;
-
-        settings_account_data.emergency = false;
+        if withdrawals {
+            token_settings_account_data.withdrawals_paused = true;
+        }
 
-        Settings::pack(
-            settings_account_data,
-            &mut settings_account_info.data.borrow_mut(),
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
         )?;
 
         Ok(())
     }
 
-    fn process_enable_token_emergency_mode(
+    fn process_unpause_token(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        deposits: bool,
+        withdrawals: bool,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
@@ -2695,10 +11789,8 @@ impl Processor {
             settings_account_info,
         )?;
 
-        let guardian = settings_account_data.guardian;
-
-        // Validate Guardian Account
-        if *authority_account_info.key != guardian {
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
             let programdata_account_info = next_account_info(account_info_iter)?;
 
             // Validate Initializer Account
@@ -2711,6 +11803,11 @@ impl Processor {
                 authority_account_info.key,
                 programdata_account_info,
             )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
         // Validate Token Settings Account
@@ -2741,7 +11838,17 @@ impl Processor {
             }
         }
 
-        token_settings_account_data.emergency = true;
+        if token_settings_account_data.closed {
+            return Err(SolanaBridgeError::TokenClosed.into());
+        }
+
+        if deposits {
+            token_settings_account_data.deposits_paused = false;
+        }
+
+        if withdrawals {
+            token_settings_account_data.withdrawals_paused = false;
+        }
 
         TokenSettings::pack(
             token_settings_account_data,
@@ -2751,14 +11858,14 @@ impl Processor {
         Ok(())
     }
 
-    fn process_disable_token_emergency_mode(
+    fn process_change_guardians(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        guardians: Vec<Pubkey>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
         let authority_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
         let programdata_account_info = next_account_info(account_info_iter)?;
 
@@ -2766,8 +11873,12 @@ impl Processor {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        if guardians.len() > MAX_GUARDIANS_LEN {
+            return Err(SolanaBridgeError::GuardiansLenLimit.into());
+        }
+
         // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
         let (settings_nonce, programdata_nonce) = settings_account_data
             .account_kind
@@ -2780,7 +11891,7 @@ impl Processor {
             settings_account_info,
         )?;
 
-        // Validate Owner Account
+        // Validate Initializer Account
         bridge_utils::helper::validate_programdata_account(
             program_id,
             programdata_nonce,
@@ -2791,66 +11902,38 @@ impl Processor {
             programdata_account_info,
         )?;
 
-        // Validate Token Settings Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
-
-        let (token_settings_nonce, _) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        match token_settings_account_data.kind {
-            TokenKind::Ever { token, .. } => {
-                validate_token_settings_ever_account(
-                    program_id,
-                    &token,
-                    token_settings_nonce,
-                    token_settings_account_info,
-                )?;
-            }
-            TokenKind::Solana { mint, .. } => {
-                validate_token_settings_sol_account(
-                    program_id,
-                    &mint,
-                    token_settings_nonce,
-                    token_settings_account_info,
-                )?;
-            }
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
         }
 
-        token_settings_account_data.emergency = false;
+        settings_account_data.guardians = guardians;
 
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
         )?;
 
         Ok(())
     }
 
-    fn process_approve_withdraw_ever(
+    fn process_pause_bridge(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        deposits: bool,
+        withdrawals: bool,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
         let authority_account_info = next_account_info(account_info_iter)?;
-        let mint_account_info = next_account_info(account_info_iter)?;
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
-        let recipient_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
-        let _token_program_info = next_account_info(account_info_iter)?;
-        let clock_info = next_account_info(account_info_iter)?;
-        let clock = Clock::from_account_info(clock_info)?;
 
         if !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
         // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
         let (settings_nonce, programdata_nonce) = settings_account_data
             .account_kind
@@ -2863,72 +11946,78 @@ impl Processor {
             settings_account_info,
         )?;
 
-        if settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        // Validate Guardian Account
+        if *authority_account_info.key != settings_account_data.guardian
+            && !settings_account_data
+                .guardians
+                .contains(authority_account_info.key)
+        {
+            let programdata_account_info = next_account_info(account_info_iter)?;
+
+            // Validate Initializer Account
+            bridge_utils::helper::validate_programdata_account(
+                program_id,
+                programdata_nonce,
+                programdata_account_info.key,
+            )?;
+            bridge_utils::helper::validate_initializer_account(
+                authority_account_info.key,
+                programdata_account_info,
+            )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
-        // Validate Withdrawal Account
-        let mut withdrawal_account_data =
-            WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
-        let round_number = withdrawal_account_data.round_number;
-        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-        let event_configuration = withdrawal_account_data.pda.event_configuration;
-        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
-        let (nonce, _) = withdrawal_account_data
-            .account_kind
-            .into_proposal()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        if deposits {
+            settings_account_data.deposits_paused = true;
+        }
 
-        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
-            program_id,
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            &event_data,
-            nonce,
-            withdrawal_account_info,
+        if withdrawals {
+            settings_account_data.withdrawals_paused = true;
+        }
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
         )?;
 
-        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
-            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        Ok(())
+    }
+
+    fn process_unpause_bridge(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        deposits: bool,
+        withdrawals: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Validate Token Setting Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
-        let (token_settings_nonce, mint_nonce) = token_settings_account_data
+        let (settings_nonce, programdata_nonce) = settings_account_data
             .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        let (_, token, _) = token_settings_account_data
-            .kind
-            .into_ever()
+            .into_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        validate_token_settings_ever_account(
+        bridge_utils::helper::validate_settings_account(
             program_id,
-            &token,
-            token_settings_nonce,
-            token_settings_account_info,
+            settings_nonce,
+            settings_account_info,
         )?;
 
-        if token_settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
-
-        // Validate Mint Account
-        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
-
-        // Check connection between token and proposal
-        if token != withdrawal_account_data.event.data.token {
-            return Err(ProgramError::InvalidArgument);
-        }
-
-        if *authority_account_info.key != settings_account_data.withdrawal_manager {
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
             let programdata_account_info = next_account_info(account_info_iter)?;
 
             // Validate Initializer Account
@@ -2941,135 +12030,51 @@ impl Processor {
                 authority_account_info.key,
                 programdata_account_info,
             )?;
-        }
-
-        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
-        let solana_decimals = mint_account_data.decimals;
-        let ever_decimals = withdrawal_account_data.event.data.decimals;
-
-        let withdrawal_amount = get_withdrawal_amount(
-            withdrawal_account_data.event.data.amount,
-            ever_decimals,
-            solana_decimals,
-        )?;
-
-        let fee_info = &token_settings_account_data.fee_withdrawal_info;
-
-        let fee = 1.max(
-            withdrawal_amount
-                .checked_div(fee_info.divisor)
-                .ok_or(SolanaBridgeError::Overflow)?
-                .checked_mul(fee_info.multiplier)
-                .ok_or(SolanaBridgeError::Overflow)?,
-        );
-
-        // Amount without fee
-        let transfer_withdrawal_amount = withdrawal_amount
-            .checked_sub(fee)
-            .ok_or(SolanaBridgeError::Overflow)?;
-
-        match withdrawal_account_data.event.data.payload.is_empty() {
-            true => {
-                // Validate Recipient Account
-                let recipient_account_data =
-                    spl_token::state::Account::unpack(&recipient_account_info.data.borrow())?;
-
-                if recipient_account_data.owner != withdrawal_account_data.event.data.recipient {
-                    return Err(ProgramError::InvalidArgument);
-                }
-
-                make_ever_transfer(
-                    mint_account_info,
-                    recipient_account_info,
-                    &token_settings_account_data,
-                    accounts,
-                    transfer_withdrawal_amount,
-                )?;
-
-                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
-            }
-            false => {
-                // Validate Proxy Account
-                let proxy_nonce = withdrawal_account_data
-                    .account_kind
-                    .into_proposal()
-                    .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
-                    .1
-                    .ok_or(SolanaBridgeError::InvalidTokenKind)?;
-
-                validate_proxy_account(
-                    program_id,
-                    mint_account_info.key,
-                    &withdrawal_account_data.event.data.recipient,
-                    proxy_nonce,
-                    recipient_account_info,
-                )?;
-
-                make_ever_transfer(
-                    mint_account_info,
-                    recipient_account_info,
-                    &token_settings_account_data,
-                    accounts,
-                    transfer_withdrawal_amount,
-                )?;
 
-                withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForExecute;
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
             }
         }
 
-        let current_epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
-
-        // If withdrawal is in current epoch
-        if withdrawal_account_data.meta.data.epoch == current_epoch {
-            // Decrease withdrawal daily amount
-            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
-                .withdrawal_daily_amount
-                .checked_sub(transfer_withdrawal_amount)
-                .ok_or(SolanaBridgeError::Overflow)?;
-
-            TokenSettings::pack(
-                token_settings_account_data,
-                &mut token_settings_account_info.data.borrow_mut(),
-            )?;
-        }
-
-        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
-            account: withdrawal_pubkey,
-            status: withdrawal_account_data.meta.data.status,
+        if deposits {
+            settings_account_data.deposits_paused = false;
         }
-        .try_to_vec()?]);
 
-        WithdrawalMultiTokenEver::pack(
-            withdrawal_account_data,
-            &mut withdrawal_account_info.data.borrow_mut(),
+        if withdrawals {
+            settings_account_data.withdrawals_paused = false;
+        }
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
         )?;
 
         Ok(())
     }
 
-    fn process_approve_withdraw_sol(
+    fn process_change_deposit_value_floor(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        new_deposit_value_floor: Option<DepositValueFloor>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
         let authority_account_info = next_account_info(account_info_iter)?;
-        let vault_account_info = next_account_info(account_info_iter)?;
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
-        let recipient_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
 
-        let _token_program_info = next_account_info(account_info_iter)?;
-        let clock_info = next_account_info(account_info_iter)?;
-        let clock = Clock::from_account_info(clock_info)?;
-
         if !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        if let Some(new_deposit_value_floor) = &new_deposit_value_floor {
+            if new_deposit_value_floor.divisor == 0 {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
+
         // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
         let (settings_nonce, programdata_nonce) = settings_account_data
             .account_kind
@@ -3082,39 +12087,8 @@ impl Processor {
             settings_account_info,
         )?;
 
-        if settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
-
-        // Validate Withdrawal Account
-        let mut withdrawal_account_data =
-            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
-        let round_number = withdrawal_account_data.round_number;
-        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-        let event_configuration = withdrawal_account_data.pda.event_configuration;
-        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
-        let (nonce, _) = withdrawal_account_data
-            .account_kind
-            .into_proposal()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-        bridge_utils::helper::validate_proposal_account(
-            program_id,
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            &event_data,
-            nonce,
-            withdrawal_account_info,
-        )?;
-
-        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
-            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
-        }
-
-        if *authority_account_info.key != settings_account_data.withdrawal_manager {
+        // Validate Manager Account
+        if *authority_account_info.key != settings_account_data.manager {
             let programdata_account_info = next_account_info(account_info_iter)?;
 
             // Validate Initializer Account
@@ -3127,160 +12101,196 @@ impl Processor {
                 authority_account_info.key,
                 programdata_account_info,
             )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
-        // Validate Token Setting Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+        settings_account_data.deposit_value_floor = new_deposit_value_floor;
 
-        let (token_settings_nonce, vault_nonce) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
 
-        let (mint, _) = token_settings_account_data
-            .kind
-            .into_solana()
+        Ok(())
+    }
+
+    fn process_change_min_supported_ix_version(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        min_supported_ix_version: u8,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let settings_account_info = next_account_info(account_info_iter)?;
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        if !authority_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Current instructions must stay available
+        if min_supported_ix_version > CURRENT_IX_VERSION {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Settings Account
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        let (settings_nonce, programdata_nonce) = settings_account_data
+            .account_kind
+            .into_settings()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        validate_token_settings_sol_account(
+        bridge_utils::helper::validate_settings_account(
             program_id,
-            &mint,
-            token_settings_nonce,
-            token_settings_account_info,
+            settings_nonce,
+            settings_account_info,
         )?;
 
-        if token_settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
-
-        // Validate Vault Account
-        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
 
-        // Check connection between token and proposal
-        if mint != withdrawal_account_data.event.data.mint {
-            return Err(ProgramError::InvalidArgument);
+        // Owner changes go through timelock
+        if settings_account_data.timelock_delay != 0 {
+            return Err(SolanaBridgeError::TimelockEnabled.into());
         }
 
-        let withdrawal_amount = withdrawal_account_data.event.data.amount as u64;
+        settings_account_data.min_supported_ix_version = min_supported_ix_version;
 
-        let fee_info = &token_settings_account_data.fee_withdrawal_info;
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
 
-        let fee = 1.max(
-            withdrawal_amount
-                .checked_div(fee_info.divisor)
-                .ok_or(SolanaBridgeError::Overflow)?
-                .checked_mul(fee_info.multiplier)
-                .ok_or(SolanaBridgeError::Overflow)?,
-        );
+        Ok(())
+    }
 
-        // Amount without fee
-        let transfer_withdrawal_amount = withdrawal_amount
-            .checked_sub(fee)
-            .ok_or(SolanaBridgeError::Overflow)?;
+    fn process_create_fee_ledger(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
 
-        match withdrawal_account_data.event.data.payload.is_empty() {
-            true => {
-                // Validate Recipient Account
-                let recipient_account_data =
-                    spl_token::state::Account::unpack(&recipient_account_info.data.borrow())?;
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let fee_ledger_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
 
-                if recipient_account_data.owner != withdrawal_account_data.event.data.recipient {
-                    return Err(ProgramError::InvalidArgument);
-                }
+        if !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
 
-                let vault_account_data =
-                    spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
 
-                if transfer_withdrawal_amount > vault_account_data.amount {
-                    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
-                } else {
-                    make_sol_transfer(
-                        vault_account_info,
-                        recipient_account_info,
-                        &token_settings_account_data,
-                        accounts,
-                        transfer_withdrawal_amount,
-                    )?;
+        let (token_settings_nonce, _) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-                    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
-                }
+        match token_settings_account_data.kind {
+            TokenKind::Ever { token, .. } => {
+                validate_token_settings_ever_account(
+                    program_id,
+                    &token,
+                    token_settings_nonce,
+                    token_settings_account_info,
+                )?;
             }
-            false => {
-                // Validate Proxy Account
-                let proxy_nonce = withdrawal_account_data
-                    .account_kind
-                    .into_proposal()
-                    .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
-                    .1
-                    .ok_or(SolanaBridgeError::InvalidTokenKind)?;
-
-                validate_proxy_account(
+            TokenKind::Solana { mint, .. } => {
+                validate_token_settings_sol_account(
                     program_id,
                     &mint,
-                    &withdrawal_account_data.event.data.recipient,
-                    proxy_nonce,
-                    recipient_account_info,
+                    token_settings_nonce,
+                    token_settings_account_info,
                 )?;
+            }
+        }
 
-                let vault_account_data =
-                    spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+        // Validate Fee Ledger Account
+        let (fee_ledger_pubkey, fee_ledger_nonce) = Pubkey::find_program_address(
+            &[br"fee_ledger", &token_settings_account_info.key.to_bytes()],
+            program_id,
+        );
 
-                if transfer_withdrawal_amount > vault_account_data.amount {
-                    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
-                } else {
-                    make_sol_transfer(
-                        vault_account_info,
-                        recipient_account_info,
-                        &token_settings_account_data,
-                        accounts,
-                        transfer_withdrawal_amount,
-                    )?;
+        if fee_ledger_pubkey != *fee_ledger_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-                    withdrawal_account_data.meta.data.status =
-                        WithdrawalTokenStatus::WaitingForExecute;
-                }
-            }
-        };
+        if is_program_account(program_id, fee_ledger_account_info) {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
 
-        let current_epoch = clock.unix_timestamp / SECONDS_PER_DAY as i64;
+        let fee_ledger_account_signer_seeds: &[&[_]] = &[
+            br"fee_ledger",
+            &token_settings_account_info.key.to_bytes(),
+            &[fee_ledger_nonce],
+        ];
 
-        // If withdrawal is in current epoch
-        if withdrawal_account_data.meta.data.epoch == current_epoch {
-            // Decrease withdrawal daily amount
-            token_settings_account_data.withdrawal_daily_amount = token_settings_account_data
-                .withdrawal_daily_amount
-                .checked_sub(transfer_withdrawal_amount)
-                .ok_or(SolanaBridgeError::Overflow)?;
+        // Create Fee Ledger Account. Lamports sent to its address don't prevent creation
+        create_pda_account(
+            funder_account_info,
+            rent,
+            FeeLedger::LEN,
+            program_id,
+            system_program_info,
+            fee_ledger_account_info,
+            fee_ledger_account_signer_seeds,
+        )?;
 
-            TokenSettings::pack(
-                token_settings_account_data,
-                &mut token_settings_account_info.data.borrow_mut(),
-            )?;
-        }
+        // Move fee accrued in token settings
+        let fee_ledger_account_data = FeeLedger {
+            is_initialized: true,
+            account_kind: AccountKind::FeeLedger(fee_ledger_nonce),
+            token_settings: *token_settings_account_info.key,
+            supply: token_settings_account_data.fee_supply,
+            version: CURRENT_ACCOUNT_VERSION,
+        };
 
-        WithdrawalMultiTokenSol::pack(
-            withdrawal_account_data,
-            &mut withdrawal_account_info.data.borrow_mut(),
+        FeeLedger::pack(
+            fee_ledger_account_data,
+            &mut fee_ledger_account_info.data.borrow_mut(),
+        )?;
+
+        token_settings_account_data.fee_supply = 0;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
         )?;
 
         Ok(())
     }
 
-    fn process_update_fee(
+    fn process_register_liquidity_provider(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        fee_type: FeeType,
-        multiplier: u64,
-        divisor: u64,
+        provider: Pubkey,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
+        let funder_account_info = next_account_info(account_info_iter)?;
         let authority_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let lp_registry_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
 
-        if !authority_account_info.is_signer {
+        if !funder_account_info.is_signer || !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
@@ -3312,69 +12322,84 @@ impl Processor {
                 authority_account_info.key,
                 programdata_account_info,
             )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
-        // Validate Token Settings Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+        // If LP registry account is not created
+        if !is_program_account(program_id, lp_registry_account_info) {
+            let (lp_registry_pubkey, lp_registry_nonce) =
+                Pubkey::find_program_address(&[br"lp_registry"], program_id);
+            let lp_registry_account_signer_seeds: &[&[_]] =
+                &[br"lp_registry", &[lp_registry_nonce]];
 
-        let (token_settings_nonce, _) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+            if lp_registry_pubkey != *lp_registry_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
 
-        let token_settings_pubkey = match token_settings_account_data.kind {
-            TokenKind::Ever { token, .. } => validate_token_settings_ever_account(
-                program_id,
-                &token,
-                token_settings_nonce,
-                token_settings_account_info,
-            )?,
-            TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
+            // Lamports sent to its address don't prevent creation
+            create_pda_account(
+                funder_account_info,
+                rent,
+                LpRegistry::LEN,
                 program_id,
-                &mint,
-                token_settings_nonce,
-                token_settings_account_info,
-            )?,
-        };
+                system_program_info,
+                lp_registry_account_info,
+                lp_registry_account_signer_seeds,
+            )?;
 
-        match fee_type {
-            FeeType::Deposit => {
-                token_settings_account_data.fee_deposit_info.multiplier = multiplier;
-                token_settings_account_data.fee_deposit_info.divisor = divisor;
-            }
-            FeeType::Withdrawal => {
-                token_settings_account_data.fee_withdrawal_info.multiplier = multiplier;
-                token_settings_account_data.fee_withdrawal_info.divisor = divisor;
+            let lp_registry_account_data = LpRegistry {
+                is_initialized: true,
+                account_kind: AccountKind::LpRegistry(lp_registry_nonce),
+                providers: vec![provider],
+                version: CURRENT_ACCOUNT_VERSION,
+            };
+
+            LpRegistry::pack(
+                lp_registry_account_data,
+                &mut lp_registry_account_info.data.borrow_mut(),
+            )?;
+        } else {
+            // Validate LP Registry Account
+            let mut lp_registry_account_data =
+                LpRegistry::unpack(&lp_registry_account_info.data.borrow())?;
+
+            let lp_registry_nonce = lp_registry_account_data
+                .account_kind
+                .into_lp_registry()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            validate_lp_registry_account(program_id, lp_registry_nonce, lp_registry_account_info)?;
+
+            if !lp_registry_account_data.providers.contains(&provider) {
+                if lp_registry_account_data.providers.len() >= MAX_LP_REGISTRY_LEN {
+                    return Err(SolanaBridgeError::LpRegistryLenLimit.into());
+                }
+
+                lp_registry_account_data.providers.push(provider);
             }
-        }
 
-        solana_program::log::sol_log_data(&[&UpdateFeeEvent {
-            token_settings: token_settings_pubkey,
-            fee_type,
-            divisor,
-            multiplier,
+            LpRegistry::pack(
+                lp_registry_account_data,
+                &mut lp_registry_account_info.data.borrow_mut(),
+            )?;
         }
-        .try_to_vec()?]);
-
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
-        )?;
 
         Ok(())
     }
 
-    fn process_token_name(
+    fn process_unregister_liquidity_provider(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        symbol: String,
-        name: String,
+        provider: Pubkey,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
         let authority_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let lp_registry_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
 
         if !authority_account_info.is_signer {
@@ -3409,63 +12434,330 @@ impl Processor {
                 authority_account_info.key,
                 programdata_account_info,
             )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
-        // Validate Token Settings Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+        // Validate LP Registry Account
+        let mut lp_registry_account_data =
+            LpRegistry::unpack(&lp_registry_account_info.data.borrow())?;
 
-        let (token_settings_nonce, _) = token_settings_account_data
+        let lp_registry_nonce = lp_registry_account_data
             .account_kind
-            .into_token_settings()
+            .into_lp_registry()
             .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        let token_settings_pubkey = match token_settings_account_data.kind {
-            TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
-                program_id,
-                &mint,
-                token_settings_nonce,
-                token_settings_account_info,
-            )?,
-            TokenKind::Ever { .. } => return Err(SolanaBridgeError::InvalidTokenKind.into()),
+        validate_lp_registry_account(program_id, lp_registry_nonce, lp_registry_account_info)?;
+
+        lp_registry_account_data
+            .providers
+            .retain(|registered| *registered != provider);
+
+        LpRegistry::pack(
+            lp_registry_account_data,
+            &mut lp_registry_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_create_bridge_stats(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let bridge_stats_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Bridge Stats Account
+        let (bridge_stats_pubkey, bridge_stats_nonce) =
+            Pubkey::find_program_address(&[br"bridge_stats"], program_id);
+
+        if bridge_stats_pubkey != *bridge_stats_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let bridge_stats_account_signer_seeds: &[&[_]] = &[br"bridge_stats", &[bridge_stats_nonce]];
+
+        if is_program_account(program_id, bridge_stats_account_info) {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Lamports sent to its address don't prevent creation
+        create_pda_account(
+            funder_account_info,
+            rent,
+            BridgeStats::LEN,
+            program_id,
+            system_program_info,
+            bridge_stats_account_info,
+            bridge_stats_account_signer_seeds,
+        )?;
+
+        let bridge_stats_account_data = BridgeStats {
+            is_initialized: true,
+            account_kind: AccountKind::BridgeStats(bridge_stats_nonce),
+            deposits: 0,
+            withdrawals: 0,
+            deposit_volume: 0,
+            withdrawal_volume: 0,
+            pending_proposals: 0,
+            version: CURRENT_ACCOUNT_VERSION,
         };
 
-        token_settings_account_data.symbol = symbol.clone();
-        token_settings_account_data.name = name.clone();
+        BridgeStats::pack(
+            bridge_stats_account_data,
+            &mut bridge_stats_account_info.data.borrow_mut(),
+        )?;
 
-        solana_program::log::sol_log_data(&[&UpdateTokenNameEvent {
-            token_settings: token_settings_pubkey,
-            symbol,
-            name,
+        Ok(())
+    }
+
+    fn process_deposit_bounty_for_withdraw_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let author_account_info = next_account_info(account_info_iter)?;
+        let author_token_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let bounty_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
         }
-        .try_to_vec()?]);
 
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
+        // Validate Withdrawal Account
+        let withdrawal_account_data =
+            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            withdrawal_account_data.chain_id,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::New
+            && withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending
+            && withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove
+        {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        if withdrawal_account_data.author != *author_account_info.key {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        // Check connection between token and proposal
+        if *mint_account_info.key != withdrawal_account_data.event.data.mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Bounty Account
+        let (bounty_pubkey, bounty_nonce) = Pubkey::find_program_address(
+            &[
+                br"bounty",
+                &withdrawal_account_info.key.to_bytes(),
+                &author_account_info.key.to_bytes(),
+            ],
+            program_id,
+        );
+        let bounty_account_signer_seeds: &[&[_]] = &[
+            br"bounty",
+            &withdrawal_account_info.key.to_bytes(),
+            &author_account_info.key.to_bytes(),
+            &[bounty_nonce],
+        ];
+
+        if bounty_pubkey != *bounty_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Create Bounty Account on first deposit. Lamports sent to its address don't prevent creation
+        if !is_program_account(&spl_token::id(), bounty_account_info) {
+            create_pda_account(
+                author_account_info,
+                rent,
+                spl_token::state::Account::LEN,
+                &spl_token::id(),
+                system_program_info,
+                bounty_account_info,
+                bounty_account_signer_seeds,
+            )?;
+
+            // Init Bounty Account
+            invoke_signed(
+                &spl_token::instruction::initialize_account3(
+                    &spl_token::id(),
+                    bounty_account_info.key,
+                    mint_account_info.key,
+                    bounty_account_info.key,
+                )?,
+                accounts,
+                &[bounty_account_signer_seeds],
+            )?;
+        }
+
+        // Transfer bounty tokens to escrow
+        invoke(
+            &spl_token::instruction::transfer(
+                &spl_token::id(),
+                author_token_account_info.key,
+                bounty_account_info.key,
+                author_account_info.key,
+                &[author_account_info.key],
+                amount,
+            )?,
+            accounts,
+        )?;
+
+        Ok(())
+    }
+
+    fn process_refund_bounty_for_withdraw_sol(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let author_account_info = next_account_info(account_info_iter)?;
+        let author_token_account_info = next_account_info(account_info_iter)?;
+        let withdrawal_account_info = next_account_info(account_info_iter)?;
+        let bounty_account_info = next_account_info(account_info_iter)?;
+        let _token_program_info = next_account_info(account_info_iter)?;
+
+        if !author_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Withdrawal Account may be already closed
+        if withdrawal_account_info.lamports() != 0 {
+            let withdrawal_account_data =
+                WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+            let round_number = withdrawal_account_data.round_number;
+            let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+            let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+            let event_configuration = withdrawal_account_data.pda.event_configuration;
+            let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+            let (nonce, _) = withdrawal_account_data
+                .account_kind
+                .into_proposal()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            bridge_utils::helper::validate_proposal_account(
+                program_id,
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                withdrawal_account_data.chain_id,
+                &event_data,
+                nonce,
+                withdrawal_account_info,
+            )?;
+
+            if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Processed
+                && withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Cancelled
+                && withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Expired
+            {
+                return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+            }
+        }
+
+        // Validate Bounty Account
+        let (bounty_pubkey, bounty_nonce) = Pubkey::find_program_address(
+            &[
+                br"bounty",
+                &withdrawal_account_info.key.to_bytes(),
+                &author_account_info.key.to_bytes(),
+            ],
+            program_id,
+        );
+
+        if bounty_pubkey != *bounty_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let bounty_balance =
+            spl_token::state::Account::unpack(&bounty_account_info.data.borrow())?.amount;
+
+        release_bounty(
+            accounts,
+            bounty_account_info,
+            bounty_nonce,
+            withdrawal_account_info.key,
+            author_account_info.key,
+            author_token_account_info.key,
+            bounty_balance,
+        )?;
+
+        close_bounty_account(
+            accounts,
+            bounty_account_info,
+            bounty_nonce,
+            withdrawal_account_info.key,
+            author_account_info.key,
+            author_account_info.key,
         )?;
 
         Ok(())
     }
 
-    fn process_withdraw_ever_fee(
+    fn process_change_token_decimals(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        amount: u64,
+        token: EverAddress,
+        solana_decimals: u8,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
 
         let authority_account_info = next_account_info(account_info_iter)?;
+        let token_decimals_account_info = next_account_info(account_info_iter)?;
         let mint_account_info = next_account_info(account_info_iter)?;
-        let recipient_token_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
         let settings_account_info = next_account_info(account_info_iter)?;
-        let _token_program_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let rent_sysvar_info = next_account_info(account_info_iter)?;
+        let rent = &Rent::from_account_info(rent_sysvar_info)?;
 
         if !authority_account_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        if solana_decimals > MAX_SOLANA_DECIMALS {
+            return Err(SolanaBridgeError::InvalidTokenDecimals.into());
+        }
+
         // Validate Settings Account
         let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
 
@@ -3494,1149 +12786,1515 @@ impl Processor {
                 authority_account_info.key,
                 programdata_account_info,
             )?;
+
+            // Owner changes go through timelock
+            if settings_account_data.timelock_delay != 0 {
+                return Err(SolanaBridgeError::TimelockEnabled.into());
+            }
         }
 
-        // Validate Token Setting Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+        // Mint decimals can't be changed once it is created
+        if get_associated_mint_address(program_id, &token) != *mint_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        let (token_settings_nonce, mint_nonce) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        if !mint_account_info.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
 
-        let (_, token, _) = token_settings_account_data
-            .kind
-            .into_ever()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        // If token decimals account is not created
+        if !is_program_account(program_id, token_decimals_account_info) {
+            let token_hash = hash(&token.try_to_vec()?);
 
-        validate_token_settings_ever_account(
-            program_id,
-            &token,
-            token_settings_nonce,
-            token_settings_account_info,
-        )?;
+            let (token_decimals_pubkey, token_decimals_nonce) =
+                Pubkey::find_program_address(&[br"decimals", token_hash.as_ref()], program_id);
+            let token_decimals_account_signer_seeds: &[&[_]] =
+                &[br"decimals", token_hash.as_ref(), &[token_decimals_nonce]];
 
-        // Validate Mint Account
-        validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+            if token_decimals_pubkey != *token_decimals_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
 
-        if mint_account_info.owner != &spl_token::id() {
-            return Err(ProgramError::InvalidArgument);
-        }
+            create_pda_account(
+                authority_account_info,
+                rent,
+                TokenDecimals::LEN,
+                program_id,
+                system_program_info,
+                token_decimals_account_info,
+                token_decimals_account_signer_seeds,
+            )?;
 
-        // Validate Recipient Account
-        if recipient_token_account_info.owner != &spl_token::id() {
-            return Err(ProgramError::InvalidArgument);
-        }
+            let token_decimals_account_data = TokenDecimals {
+                is_initialized: true,
+                account_kind: AccountKind::TokenDecimals(token_decimals_nonce),
+                token,
+                solana_decimals,
+                version: CURRENT_ACCOUNT_VERSION,
+            };
 
-        if amount > token_settings_account_data.fee_supply {
-            return Err(SolanaBridgeError::InsufficientBalance.into());
-        }
+            TokenDecimals::pack(
+                token_decimals_account_data,
+                &mut token_decimals_account_info.data.borrow_mut(),
+            )?;
+        } else {
+            // Validate Token Decimals Account
+            let mut token_decimals_account_data =
+                TokenDecimals::unpack(&token_decimals_account_info.data.borrow())?;
 
-        let token_hash = hash(&token.try_to_vec()?);
-        let mint_account_signer_seeds: &[&[_]] = &[br"mint", token_hash.as_ref(), &[mint_nonce]];
+            let token_decimals_nonce = token_decimals_account_data
+                .account_kind
+                .into_token_decimals()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        invoke_signed(
-            &spl_token::instruction::mint_to(
-                &spl_token::id(),
-                mint_account_info.key,
-                recipient_token_account_info.key,
-                mint_account_info.key,
-                &[mint_account_info.key],
-                amount,
-            )?,
-            accounts,
-            &[mint_account_signer_seeds],
-        )?;
+            validate_token_decimals_account(
+                program_id,
+                &token,
+                token_decimals_nonce,
+                token_decimals_account_info,
+            )?;
 
-        // Decrease fee supply
-        token_settings_account_data.fee_supply = token_settings_account_data
-            .fee_supply
-            .checked_sub(amount)
-            .ok_or(SolanaBridgeError::Overflow)?;
+            token_decimals_account_data.solana_decimals = solana_decimals;
 
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
-        )?;
+            TokenDecimals::pack(
+                token_decimals_account_data,
+                &mut token_decimals_account_info.data.borrow_mut(),
+            )?;
+        }
 
         Ok(())
     }
+}
 
-    fn process_withdraw_sol_fee(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        amount: u64,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
+/// Program accounts are told apart by owner and data, lamports can be sent to any address
+fn is_program_account(program_id: &Pubkey, account_info: &AccountInfo) -> bool {
+    account_info.owner == program_id && !account_info.data_is_empty()
+}
 
-        let authority_account_info = next_account_info(account_info_iter)?;
-        let vault_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
-        let recipient_token_account_info = next_account_info(account_info_iter)?;
-        let settings_account_info = next_account_info(account_info_iter)?;
-        let _token_program_info = next_account_info(account_info_iter)?;
+/// Solana decimals of EVER token are overridden by manager until its mint is created.
+/// Token decimals account must be passed whenever the mint is created, `default_decimals`
+/// are used only if manager hasn't set them
+fn get_solana_decimals(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    token: &EverAddress,
+    default_decimals: u8,
+) -> Result<u8, ProgramError> {
+    let token_decimals_pubkey = get_associated_token_decimals_address(program_id, token);
 
-        if !authority_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+    let token_decimals_account_info = accounts
+        .iter()
+        .find(|account_info| *account_info.key == token_decimals_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
-        // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+    if !is_program_account(program_id, token_decimals_account_info) {
+        return Ok(default_decimals);
+    }
 
-        let (settings_nonce, programdata_nonce) = settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let token_decimals_account_data =
+        TokenDecimals::unpack(&token_decimals_account_info.data.borrow())?;
 
-        bridge_utils::helper::validate_settings_account(
-            program_id,
-            settings_nonce,
-            settings_account_info,
-        )?;
+    let token_decimals_nonce = token_decimals_account_data
+        .account_kind
+        .into_token_decimals()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        // Validate Manager Account
-        if *authority_account_info.key != settings_account_data.manager {
-            let programdata_account_info = next_account_info(account_info_iter)?;
+    validate_token_decimals_account(
+        program_id,
+        token,
+        token_decimals_nonce,
+        token_decimals_account_info,
+    )?;
 
-            // Validate Initializer Account
-            bridge_utils::helper::validate_programdata_account(
-                program_id,
-                programdata_nonce,
-                programdata_account_info.key,
-            )?;
-            bridge_utils::helper::validate_initializer_account(
-                authority_account_info.key,
-                programdata_account_info,
-            )?;
+    Ok(token_decimals_account_data.solana_decimals)
+}
+
+fn make_ever_transfer<'a>(
+    mint_account_info: &AccountInfo<'a>,
+    recipient_account_info: &AccountInfo<'a>,
+    settings_account_data: &TokenSettings,
+    accounts: &[AccountInfo],
+    withdrawal_amount: u64,
+) -> ProgramResult {
+    // Mint EVER tokens to Proxy Account
+    let (_, token, _) = settings_account_data
+        .kind
+        .into_ever()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (_, mint_nonce) = settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let token_hash = hash(&token.try_to_vec()?);
+    let mint_account_signer_seeds: &[&[_]] = &[br"mint", token_hash.as_ref(), &[mint_nonce]];
+
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            &spl_token::id(),
+            mint_account_info.key,
+            recipient_account_info.key,
+            mint_account_info.key,
+            &[mint_account_info.key],
+            withdrawal_amount,
+        )?,
+        accounts,
+        &[mint_account_signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+fn validate_withdrawal_ever_to_many_account(
+    program_id: &Pubkey,
+    withdrawal_account_data: &WithdrawalMultiTokenEverToMany,
+    withdrawal_account_info: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        withdrawal_account_data.round_number,
+        withdrawal_account_data.pda.event_timestamp,
+        withdrawal_account_data.pda.event_transaction_lt,
+        &withdrawal_account_data.pda.event_configuration,
+        withdrawal_account_data.chain_id,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )
+}
+
+/// Amounts to transfer to every recipient without fee, and the total fee
+fn get_ever_to_many_transfer_amounts(
+    event: &WithdrawalMultiTokenEverToManyEvent,
+    token_settings_account_data: &TokenSettings,
+    solana_decimals: u8,
+) -> Result<(Vec<u64>, u64), ProgramError> {
+    let mut transfer_amounts = Vec::with_capacity(event.recipients.len());
+    let mut total_fee: u64 = 0;
+
+    for recipient in &event.recipients {
+        let withdrawal_amount =
+            to_solana_amount(recipient.amount, event.decimals, solana_decimals)?;
+
+        let fee = token_settings_account_data
+            .withdrawal_fee(withdrawal_amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        total_fee = total_fee
+            .checked_add(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Amount without fee
+        transfer_amounts.push(
+            withdrawal_amount
+                .checked_sub(fee)
+                .ok_or(SolanaBridgeError::Overflow)?,
+        );
+    }
+
+    Ok((transfer_amounts, total_fee))
+}
+
+fn make_ever_transfers_to_many<'a>(
+    mint_account_info: &AccountInfo<'a>,
+    recipient_account_infos: &[AccountInfo<'a>],
+    settings_account_data: &TokenSettings,
+    accounts: &[AccountInfo],
+    recipients: &[WithdrawalRecipient],
+    transfer_amounts: &[u64],
+) -> ProgramResult {
+    if recipient_account_infos.len() < recipients.len() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    for ((recipient, recipient_account_info), transfer_amount) in recipients
+        .iter()
+        .zip(recipient_account_infos)
+        .zip(transfer_amounts)
+    {
+        // Validate Recipient Account
+        let recipient_token_pubkey = spl_associated_token_account::get_associated_token_address(
+            &recipient.recipient,
+            mint_account_info.key,
+        );
+
+        if recipient_token_pubkey != *recipient_account_info.key {
+            return Err(ProgramError::InvalidArgument);
         }
 
-        // Validate Token Settings Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+        let recipient_account_data =
+            spl_token::state::Account::unpack(&recipient_account_info.data.borrow())?;
 
-        let (mint, _) = token_settings_account_data
-            .kind
-            .into_solana()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-        let (token_settings_nonce, vault_nonce) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        if recipient_account_data.owner != recipient.recipient {
+            return Err(ProgramError::InvalidArgument);
+        }
 
-        validate_token_settings_sol_account(
-            program_id,
-            &mint,
-            token_settings_nonce,
-            token_settings_account_info,
+        make_ever_transfer(
+            mint_account_info,
+            recipient_account_info,
+            settings_account_data,
+            accounts,
+            *transfer_amount,
         )?;
+    }
 
-        // Validate Vault Account
-        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
+    Ok(())
+}
+
+fn make_sol_transfer<'a>(
+    vault_account_info: &AccountInfo<'a>,
+    recipient_account_info: &AccountInfo<'a>,
+    settings_account_data: &TokenSettings,
+    accounts: &[AccountInfo],
+    withdrawal_amount: u64,
+) -> ProgramResult {
+    // Transfer tokens from Vault Account to Recipient Account
+    let (mint, _) = settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (_, vault_nonce) = settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let vault_account_signer_seeds: &[&[_]] = &[br"vault", &mint.to_bytes(), &[vault_nonce]];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            &spl_token::id(),
+            vault_account_info.key,
+            recipient_account_info.key,
+            vault_account_info.key,
+            &[vault_account_info.key],
+            withdrawal_amount,
+        )?,
+        accounts,
+        &[vault_account_signer_seeds],
+    )?;
 
-        if vault_account_info.owner != &spl_token::id() {
-            return Err(ProgramError::InvalidArgument);
-        }
+    Ok(())
+}
 
-        // Validate Recipient Account
-        if recipient_token_account_info.owner != &spl_token::id() {
-            return Err(ProgramError::InvalidArgument);
-        }
+fn update_deposit_daily_amount(
+    token_settings_account_data: &mut TokenSettings,
+    amount: u64,
+) -> ProgramResult {
+    let current_epoch = Clock::get()?.unix_timestamp / SECONDS_PER_DAY as i64;
 
-        if amount > token_settings_account_data.fee_supply {
-            return Err(SolanaBridgeError::InsufficientBalance.into());
-        }
+    // If current epoch has changed
+    if token_settings_account_data.deposit_epoch != current_epoch {
+        token_settings_account_data.deposit_epoch = current_epoch;
+        token_settings_account_data.deposit_daily_amount = Default::default();
+    }
 
-        let vault_account_data =
-            spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+    // Increase deposit daily amount
+    token_settings_account_data.deposit_daily_amount = token_settings_account_data
+        .deposit_daily_amount
+        .checked_add(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
 
-        if amount > vault_account_data.amount {
-            return Err(SolanaBridgeError::InsufficientVaultBalance.into());
+    if let Some(deposit_daily_limit) = token_settings_account_data.deposit_daily_limit {
+        if token_settings_account_data.deposit_daily_amount > deposit_daily_limit {
+            return Err(SolanaBridgeError::DepositDailyLimit.into());
         }
+    }
 
-        let vault_account_signer_seeds: &[&[_]] = &[br"vault", &mint.to_bytes(), &[vault_nonce]];
-
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                vault_account_info.key,
-                recipient_token_account_info.key,
-                vault_account_info.key,
-                &[vault_account_info.key],
-                amount,
-            )?,
-            accounts,
-            &[vault_account_signer_seeds],
-        )?;
+    Ok(())
+}
 
-        // Decrease fee supply
-        token_settings_account_data.fee_supply = token_settings_account_data
-            .fee_supply
-            .checked_sub(amount)
-            .ok_or(SolanaBridgeError::Overflow)?;
+fn add_expected_evers(expected_evers: UInt256, amount: u128) -> Result<UInt256, ProgramError> {
+    let mut data = *expected_evers.as_slice();
+    let (high, low) = data.split_at_mut(16);
 
-        TokenSettings::pack(
-            token_settings_account_data,
-            &mut token_settings_account_info.data.borrow_mut(),
-        )?;
+    let low_value = u128::from_be_bytes(low.try_into().expect("slice with incorrect length"));
+    let (low_value, carry) = low_value.overflowing_add(amount);
+    low.copy_from_slice(&low_value.to_be_bytes());
 
-        Ok(())
+    if carry {
+        let high_value = u128::from_be_bytes(high.try_into().expect("slice with incorrect length"))
+            .checked_add(1)
+            .ok_or(SolanaBridgeError::Overflow)?;
+        high.copy_from_slice(&high_value.to_be_bytes());
     }
 
-    fn process_change_bounty_for_withdraw_sol(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        bounty: u64,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
-
-        let author_account_info = next_account_info(account_info_iter)?;
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
+    Ok(UInt256::from(data))
+}
 
-        if !author_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+fn validate_deposit_value(
+    deposit_value_floor: &DepositValueFloor,
+    value: u64,
+    expected_evers: &UInt256,
+) -> ProgramResult {
+    let (high, low) = expected_evers.as_slice().split_at(16);
 
-        // Validate Withdrawal Account
-        let mut withdrawal_account_data =
-            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
-        let round_number = withdrawal_account_data.round_number;
-        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-        let event_configuration = withdrawal_account_data.pda.event_configuration;
-        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
-        let (nonce, _) = withdrawal_account_data
-            .account_kind
-            .into_proposal()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    // Such amount of evers can never be paid for
+    if high.iter().any(|byte| *byte != 0) {
+        return Err(SolanaBridgeError::DepositValueTooLow.into());
+    }
 
-        bridge_utils::helper::validate_proposal_account(
-            program_id,
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            &event_data,
-            nonce,
-            withdrawal_account_info,
-        )?;
+    let expected_evers = u128::from_be_bytes(low.try_into().expect("slice with incorrect length"));
 
-        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending {
-            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
-        }
+    let min_value = expected_evers
+        .checked_mul(deposit_value_floor.multiplier as u128)
+        .ok_or(SolanaBridgeError::DepositValueTooLow)?
+        .checked_div(deposit_value_floor.divisor as u128)
+        .ok_or(SolanaBridgeError::Overflow)?;
 
-        if withdrawal_account_data.author != *author_account_info.key
-            && withdrawal_account_data.event.data.recipient != *author_account_info.key
-        {
-            return Err(ProgramError::IllegalOwner);
-        }
+    if (value as u128) < min_value {
+        return Err(SolanaBridgeError::DepositValueTooLow.into());
+    }
 
-        withdrawal_account_data.meta.data.bounty = bounty;
+    Ok(())
+}
 
-        WithdrawalMultiTokenSol::pack(
-            withdrawal_account_data,
-            &mut withdrawal_account_info.data.borrow_mut(),
-        )?;
+fn validate_deposit_caller(
+    deposit_caller_allowlist: &[Pubkey],
+    instructions_sysvar_info: Option<&AccountInfo>,
+) -> ProgramResult {
+    // Direct call signed by the user
+    if get_stack_height() <= TRANSACTION_LEVEL_STACK_HEIGHT {
+        return Ok(());
+    }
 
-        Ok(())
+    // Immediate caller is known only for CPI made by a top-level instruction,
+    // deeper calls could be routed through any intermediate program
+    if get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+        return Err(SolanaBridgeError::DepositCallerNotAllowed.into());
     }
 
-    fn process_cancel_withdraw_sol(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        deposit_seed: u128,
-        recipient: EverAddress,
-        value: u64,
-        expected_evers: UInt256,
-        payload: Vec<u8>,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
+    let instructions_sysvar_info =
+        instructions_sysvar_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
 
-        let funder_account_info = next_account_info(account_info_iter)?;
-        let author_account_info = next_account_info(account_info_iter)?;
-        let mint_account_info = next_account_info(account_info_iter)?;
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
-        let deposit_account_info = next_account_info(account_info_iter)?;
-        let settings_account_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
-        let _system_program_info = next_account_info(account_info_iter)?;
-        let rent_sysvar_info = next_account_info(account_info_iter)?;
-        let multi_vault_account_info = next_account_info(account_info_iter)?;
+    // Top-level instruction is the immediate caller
+    let current_index = load_current_index_checked(instructions_sysvar_info)?;
+    let current_ix = load_instruction_at_checked(current_index as usize, instructions_sysvar_info)?;
 
-        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+    if !deposit_caller_allowlist.contains(&current_ix.program_id) {
+        return Err(SolanaBridgeError::DepositCallerNotAllowed.into());
+    }
 
-        if !author_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+    Ok(())
+}
 
-        // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+#[allow(clippy::too_many_arguments)]
+fn invoke_payload(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    ixs: &[solana_program::instruction::Instruction],
+    payload_allowlist: &[Pubkey],
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    proxy_nonce: u8,
+    proxy_accounts: u8,
+) -> ProgramResult {
+    if proxy_accounts > MAX_PROXY_ACCOUNTS {
+        return Err(ProgramError::InvalidArgument);
+    }
 
-        let (settings_nonce, _) = settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let mint_bytes = mint.to_bytes();
+    let recipient_bytes = recipient.to_bytes();
+    let proxy_nonce = [proxy_nonce];
 
-        bridge_utils::helper::validate_settings_account(
-            program_id,
-            settings_nonce,
-            settings_account_info,
-        )?;
+    // Additional proxy accounts are derived from the proxy seeds and an index
+    let proxy_accounts_seeds = (0..proxy_accounts)
+        .map(|index| {
+            let (_, nonce) = Pubkey::find_program_address(
+                &[br"proxy", &mint_bytes, &recipient_bytes, &[index]],
+                program_id,
+            );
+            ([index], [nonce])
+        })
+        .collect::<Vec<_>>();
 
-        if settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
+    let mut signers_seeds: Vec<Vec<&[u8]>> =
+        vec![vec![br"proxy", &mint_bytes, &recipient_bytes, &proxy_nonce]];
 
-        // Validate Withdrawal Account
-        let mut withdrawal_account_data =
-            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
-        let round_number = withdrawal_account_data.round_number;
-        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-        let event_configuration = withdrawal_account_data.pda.event_configuration;
-        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
-        let (nonce, _) = withdrawal_account_data
-            .account_kind
-            .into_proposal()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    for (index, nonce) in &proxy_accounts_seeds {
+        signers_seeds.push(vec![br"proxy", &mint_bytes, &recipient_bytes, index, nonce]);
+    }
 
-        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
-            program_id,
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            &event_data,
-            nonce,
-            withdrawal_account_info,
-        )?;
+    let signers_seeds = signers_seeds
+        .iter()
+        .map(|seeds| seeds.as_slice())
+        .collect::<Vec<_>>();
 
-        if withdrawal_account_data.author != *author_account_info.key {
-            return Err(ProgramError::IllegalOwner);
+    for ix in ixs {
+        if !payload_allowlist.contains(&ix.program_id) {
+            return Err(SolanaBridgeError::PayloadProgramNotAllowed.into());
         }
 
-        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending {
-            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
-        }
+        invoke_signed(ix, accounts, &signers_seeds)?;
+    }
 
-        // Validate Token Setting Account
-        let mut token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+    Ok(())
+}
 
-        let (token_settings_nonce, _) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+fn set_withdrawal_limits(
+    token_settings_account_data: &mut TokenSettings,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
+) {
+    if let Some(new_withdrawal_limit) = new_withdrawal_limit {
+        token_settings_account_data.withdrawal_limit = new_withdrawal_limit;
+    }
 
-        let (mint, _) = token_settings_account_data
-            .kind
-            .into_solana()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    if let Some(new_withdrawal_daily_limit) = new_withdrawal_daily_limit {
+        token_settings_account_data.withdrawal_daily_limit = new_withdrawal_daily_limit;
+    }
+}
 
-        validate_token_settings_sol_account(
-            program_id,
-            &mint,
-            token_settings_nonce,
-            token_settings_account_info,
-        )?;
+fn set_fee(
+    token_settings_pubkey: Pubkey,
+    token_settings_account_data: &mut TokenSettings,
+    fee_type: FeeType,
+    multiplier: u64,
+    divisor: u64,
+) -> ProgramResult {
+    if divisor == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
 
-        if token_settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
+    match fee_type {
+        FeeType::Deposit => {
+            token_settings_account_data.fee_deposit_info.multiplier = multiplier;
+            token_settings_account_data.fee_deposit_info.divisor = divisor;
         }
+        FeeType::Withdrawal => {
+            token_settings_account_data.fee_withdrawal_info.multiplier = multiplier;
+            token_settings_account_data.fee_withdrawal_info.divisor = divisor;
+        }
+    }
 
-        // Validate Multi Vault Account
-        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
-        let multi_vault_nonce = multi_vault_account_data
-            .account_kind
-            .into_multi_vault()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    solana_program::log::sol_log_data(&[&UpdateFeeEvent {
+        token_settings: token_settings_pubkey,
+        fee_type,
+        divisor,
+        multiplier,
+    }
+    .try_to_vec()?]);
 
-        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+    Ok(())
+}
 
-        // Send sol amount to multi vault
-        invoke(
-            &system_instruction::transfer(
-                funder_account_info.key,
-                multi_vault_account_info.key,
-                value,
-            ),
-            accounts,
-        )?;
+fn set_fee_tiers(
+    token_settings_pubkey: Pubkey,
+    token_settings_account_data: &mut TokenSettings,
+    fee_type: FeeType,
+    fee_tiers: FeeTiers,
+) -> ProgramResult {
+    // Tiers must be ascending by threshold
+    let is_sorted = fee_tiers
+        .tiers
+        .windows(2)
+        .all(|pair| pair[0].amount_threshold < pair[1].amount_threshold);
+
+    if !is_sorted
+        || fee_tiers
+            .tiers
+            .iter()
+            .any(|tier| tier.fee_info.divisor == 0)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
 
-        // Validate Mint Account
-        if *mint_account_info.key != mint && mint_account_info.owner != &spl_token::id() {
-            return Err(ProgramError::InvalidArgument);
+    match fee_type {
+        FeeType::Deposit => {
+            token_settings_account_data.fee_deposit_tiers = fee_tiers.clone();
         }
-
-        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
-        let decimals = mint_account_data.decimals;
-
-        // Check connection between token and proposal
-        if mint != withdrawal_account_data.event.data.mint {
-            return Err(ProgramError::InvalidArgument);
+        FeeType::Withdrawal => {
+            token_settings_account_data.fee_withdrawal_tiers = fee_tiers.clone();
         }
+    }
 
-        // Create Deposit Account
-        let (deposit_pubkey, deposit_nonce) =
-            Pubkey::find_program_address(&[br"deposit", &deposit_seed.to_le_bytes()], program_id);
-        let deposit_account_signer_seeds: &[&[_]] =
-            &[br"deposit", &deposit_seed.to_le_bytes(), &[deposit_nonce]];
+    solana_program::log::sol_log_data(&[&UpdateFeeTiersEvent {
+        token_settings: token_settings_pubkey,
+        fee_type,
+        fee_tiers,
+    }
+    .try_to_vec()?]);
 
-        if deposit_pubkey != *deposit_account_info.key {
-            return Err(ProgramError::InvalidArgument);
-        }
+    Ok(())
+}
+
+fn expand_account<'a>(
+    funder_account_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    accounts: &[AccountInfo<'a>],
+    rent: &Rent,
+    len: usize,
+) -> ProgramResult {
+    // Top up rent for the new layout
+    let required_lamports = rent
+        .minimum_balance(len)
+        .saturating_sub(account_info.lamports());
 
-        invoke_signed(
-            &system_instruction::create_account(
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(
                 funder_account_info.key,
-                deposit_account_info.key,
-                1.max(rent.minimum_balance(DepositMultiTokenSol::LEN)),
-                DepositMultiTokenSol::LEN as u64,
-                program_id,
+                account_info.key,
+                required_lamports,
             ),
             accounts,
-            &[deposit_account_signer_seeds],
         )?;
+    }
 
-        // Init Deposit Account
-        let name = token_settings_account_data.name.clone();
-        let symbol = token_settings_account_data.symbol.clone();
-        let amount = withdrawal_account_data.event.data.amount;
+    account_info.realloc(len, true)
+}
 
-        // Calculate fee
-        let fee_info = &token_settings_account_data.fee_deposit_info;
+fn migrate_account<T, F>(account_info: &AccountInfo, version: F) -> ProgramResult
+where
+    T: Pack + IsInitialized,
+    F: FnOnce(&mut T) -> &mut u8,
+{
+    let mut account_data = T::unpack(&account_info.data.borrow())?;
 
-        let fee = 1.max(
-            amount
-                .checked_div(fee_info.divisor as u128)
-                .ok_or(SolanaBridgeError::Overflow)?
-                .checked_mul(fee_info.multiplier as u128)
-                .ok_or(SolanaBridgeError::Overflow)?,
-        );
+    let account_version = version(&mut account_data);
+    check_account_version(*account_version)?;
+    *account_version = CURRENT_ACCOUNT_VERSION;
 
-        // Increase fee supply
-        token_settings_account_data.fee_supply = token_settings_account_data
-            .fee_supply
-            .checked_add(fee.try_into().map_err(|_| SolanaBridgeError::Overflow)?)
-            .ok_or(SolanaBridgeError::Overflow)?;
+    T::pack(account_data, &mut account_info.data.borrow_mut())
+}
 
-        // Amount without fee
-        let transfer_amount = amount.checked_sub(fee).ok_or(SolanaBridgeError::Overflow)?;
+fn check_account_version(version: u8) -> ProgramResult {
+    if version >= CURRENT_ACCOUNT_VERSION {
+        return Err(SolanaBridgeError::AccountVersionUpToDate.into());
+    }
 
-        let deposit_account_data = DepositMultiTokenSol {
-            is_initialized: true,
-            account_kind: AccountKind::Deposit(deposit_nonce),
-            author: *author_account_info.key,
-            event: DepositMultiTokenSolEventWithLen::new(
-                mint,
-                name,
-                symbol,
-                decimals,
-                transfer_amount,
-                recipient,
-                value,
-                expected_evers,
-                payload,
-            ),
-            meta: DepositTokenMetaWithLen::new(deposit_seed),
-        };
+    Ok(())
+}
 
-        let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
-            .to_bytes()
-            .to_vec();
+/// Account that paid rent. Accounts created before the funder was recorded
+/// have it zeroed and were paid by the author
+fn get_rent_funder(funder: &Pubkey, author: &Pubkey) -> Pubkey {
+    if *funder == Pubkey::default() {
+        *author
+    } else {
+        *funder
+    }
+}
 
-        solana_program::log::sol_log_data(&[&DepositMultiTokenEvent {
-            account: withdrawal_pubkey,
-            recipient,
-            transfer_amount,
-            seed: deposit_seed,
-            value,
-            expected_evers,
-            event_data,
-        }
-        .try_to_vec()?]);
+fn delete_account(
+    account_info: &AccountInfo,
+    funder_account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let funder_starting_lamports = funder_account_info.lamports();
+    **funder_account_info.lamports.borrow_mut() = funder_starting_lamports
+        .checked_add(account_info.lamports())
+        .ok_or(SolanaBridgeError::Overflow)?;
 
-        DepositMultiTokenSol::pack(
-            deposit_account_data,
-            &mut deposit_account_info.data.borrow_mut(),
-        )?;
+    **account_info.lamports.borrow_mut() = 0;
 
-        withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Cancelled;
+    bridge_utils::helper::delete_account(account_info);
 
-        solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
-            account: withdrawal_pubkey,
-            status: withdrawal_account_data.meta.data.status,
-        }
-        .try_to_vec()?]);
+    Ok(())
+}
 
-        WithdrawalMultiTokenSol::pack(
-            withdrawal_account_data,
-            &mut withdrawal_account_info.data.borrow_mut(),
-        )?;
+fn notify_recipient(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    recipient: &Pubkey,
+    recipient_token_account_info: &AccountInfo,
+    withdrawal_account_info: &AccountInfo,
+    mint: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    let recipient_hook_pubkey = get_associated_recipient_hook_address(program_id, recipient);
 
-        Ok(())
+    // Hook account is required, so executor can't skip notification
+    let recipient_hook_account_info = accounts
+        .iter()
+        .find(|account_info| *account_info.key == recipient_hook_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    // Recipient hasn't set a hook
+    if !is_program_account(program_id, recipient_hook_account_info) {
+        return Ok(());
     }
 
-    fn process_fill_withdraw_sol(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        deposit_seed: u128,
-        recipient: EverAddress,
-        amount: u64,
-        value: u64,
-        expected_evers: UInt256,
-        payload: Vec<u8>,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
+    let recipient_hook_account_data =
+        RecipientHook::unpack(&recipient_hook_account_info.data.borrow())?;
 
-        let funder_account_info = next_account_info(account_info_iter)?;
-        let author_account_info = next_account_info(account_info_iter)?;
-        let author_token_account_info = next_account_info(account_info_iter)?;
-        let mint_account_info = next_account_info(account_info_iter)?;
-        let deposit_account_info = next_account_info(account_info_iter)?;
-        let settings_account_info = next_account_info(account_info_iter)?;
-        let _system_program_info = next_account_info(account_info_iter)?;
-        let token_settings_account_info = next_account_info(account_info_iter)?;
-        let _token_program_info = next_account_info(account_info_iter)?;
-        let rent_sysvar_info = next_account_info(account_info_iter)?;
-        let multi_vault_account_info = next_account_info(account_info_iter)?;
+    let mut hook_accounts = vec![
+        AccountMeta::new(*recipient_token_account_info.key, false),
+        AccountMeta::new_readonly(*withdrawal_account_info.key, false),
+    ];
 
-        let rent = &Rent::from_account_info(rent_sysvar_info)?;
+    for pubkey in recipient_hook_account_data.accounts {
+        let account_info = accounts
+            .iter()
+            .find(|account_info| *account_info.key == pubkey)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
-        if !author_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+        hook_accounts.push(match account_info.is_writable {
+            true => AccountMeta::new(pubkey, false),
+            false => AccountMeta::new_readonly(pubkey, false),
+        });
+    }
 
-        // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+    let mut data = recipient_hook_account_data.data;
+    data.extend(
+        OnBridgeReceive {
+            withdrawal: *withdrawal_account_info.key,
+            mint: *mint,
+            amount,
+        }
+        .try_to_vec()?,
+    );
 
-        let (settings_nonce, _) = settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    invoke(
+        &Instruction {
+            program_id: recipient_hook_account_data.program_id,
+            accounts: hook_accounts,
+            data,
+        },
+        accounts,
+    )
+}
 
-        bridge_utils::helper::validate_settings_account(
-            program_id,
-            settings_nonce,
-            settings_account_info,
-        )?;
+#[allow(clippy::too_many_arguments)]
+fn accrue_referral_fee<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    funder_account_info: &AccountInfo<'a>,
+    creator_account_info: &AccountInfo,
+    system_program_info: &AccountInfo<'a>,
+    token_settings_account_info: &AccountInfo,
+    token_settings_account_data: &TokenSettings,
+    referrer: &Pubkey,
+    fee: u64,
+) -> Result<u64, ProgramError> {
+    // Depositor can not refer itself
+    if referrer == creator_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
 
-        if settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
-        }
+    let referral_fee = token_settings_account_data.referral_fee(fee);
 
-        // Validate Token Setting Account
-        let token_settings_account_data =
-            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+    if referral_fee == 0 {
+        return Ok(0);
+    }
 
-        let (token_settings_nonce, vault_nonce) = token_settings_account_data
-            .account_kind
-            .into_token_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (referral_pubkey, referral_nonce) = Pubkey::find_program_address(
+        &[
+            br"referral",
+            &token_settings_account_info.key.to_bytes(),
+            &referrer.to_bytes(),
+        ],
+        program_id,
+    );
 
-        let (mint, _) = token_settings_account_data
-            .kind
-            .into_solana()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let referral_account_info = accounts
+        .iter()
+        .find(|account_info| *account_info.key == referral_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let mut referral_account_data = if !is_program_account(program_id, referral_account_info) {
+        let referral_account_signer_seeds: &[&[_]] = &[
+            br"referral",
+            &token_settings_account_info.key.to_bytes(),
+            &referrer.to_bytes(),
+            &[referral_nonce],
+        ];
 
-        validate_token_settings_sol_account(
+        // Lamports sent to its address don't prevent creation
+        create_pda_account(
+            funder_account_info,
+            &Rent::get()?,
+            Referral::LEN,
             program_id,
-            &mint,
-            token_settings_nonce,
-            token_settings_account_info,
+            system_program_info,
+            referral_account_info,
+            referral_account_signer_seeds,
         )?;
 
-        if token_settings_account_data.emergency {
-            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        Referral {
+            is_initialized: true,
+            account_kind: AccountKind::Referral(referral_nonce),
+            referrer: *referrer,
+            token_settings: *token_settings_account_info.key,
+            amount: 0,
+            version: CURRENT_ACCOUNT_VERSION,
         }
+    } else {
+        Referral::unpack(&referral_account_info.data.borrow())?
+    };
 
-        // Validate Multi Vault Account
-        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
-        let multi_vault_nonce = multi_vault_account_data
-            .account_kind
-            .into_multi_vault()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    referral_account_data.amount = referral_account_data
+        .amount
+        .checked_add(referral_fee)
+        .ok_or(SolanaBridgeError::Overflow)?;
 
-        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+    Referral::pack(
+        referral_account_data,
+        &mut referral_account_info.data.borrow_mut(),
+    )?;
 
-        // Send sol amount to multi vault
-        invoke(
-            &system_instruction::transfer(
-                funder_account_info.key,
-                multi_vault_account_info.key,
-                value,
-            ),
-            accounts,
-        )?;
+    Ok(referral_fee)
+}
 
-        // Validate Mint Account
-        if *mint_account_info.key != mint && mint_account_info.owner != &spl_token::id() {
-            return Err(ProgramError::InvalidArgument);
-        }
+fn invoke_deposit_callback(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    callback_account_infos: &[AccountInfo],
+    callback_program: &Pubkey,
+    deposit: &Pubkey,
+    deposit_seed: u128,
+    event_hash: [u8; 32],
+) -> ProgramResult {
+    // Bridge must not call itself back
+    if callback_program == program_id {
+        return Err(SolanaBridgeError::DepositCallbackProgramNotAllowed.into());
+    }
 
-        let mint_account_data = spl_token::state::Mint::unpack(&mint_account_info.data.borrow())?;
-        let decimals = mint_account_data.decimals;
+    // Callback program is passed after Instructions sysvar and is followed by its accounts
+    let callback_program_index = callback_account_infos
+        .iter()
+        .position(|account_info| account_info.key == callback_program)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
-        // Deposit Account
-        let (deposit_pubkey, deposit_nonce) =
-            Pubkey::find_program_address(&[br"deposit", &deposit_seed.to_le_bytes()], program_id);
-        let deposit_account_signer_seeds: &[&[_]] =
-            &[br"deposit", &deposit_seed.to_le_bytes(), &[deposit_nonce]];
+    let mut callback_accounts = vec![AccountMeta::new_readonly(*deposit, false)];
 
-        if deposit_pubkey != *deposit_account_info.key {
-            return Err(ProgramError::InvalidArgument);
-        }
+    // Signatures of deposit accounts are not forwarded to callback
+    for account_info in &callback_account_infos[callback_program_index + 1..] {
+        callback_accounts.push(match account_info.is_writable {
+            true => AccountMeta::new(*account_info.key, false),
+            false => AccountMeta::new_readonly(*account_info.key, false),
+        });
+    }
 
-        let mut fee_amount_sum = 0;
-        let mut withdrawals_amount_sum = 0;
+    invoke(
+        &Instruction {
+            program_id: *callback_program,
+            accounts: callback_accounts,
+            data: OnBridgeDeposit {
+                deposit_seed,
+                event_hash,
+            }
+            .try_to_vec()?,
+        },
+        accounts,
+    )
+}
 
-        let fee_info = &token_settings_account_data.fee_withdrawal_info;
+fn find_bounty_account<'a, 'b>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'b>],
+    withdrawal: &Pubkey,
+    author: &Pubkey,
+) -> Result<Option<(&'a AccountInfo<'b>, u8)>, ProgramError> {
+    let (bounty_pubkey, bounty_nonce) = Pubkey::find_program_address(
+        &[br"bounty", &withdrawal.to_bytes(), &author.to_bytes()],
+        program_id,
+    );
 
-        // collect Withdrawal Accounts
-        while let Some(withdrawal_account_infos) = next_account_infos(account_info_iter, 2).ok() {
-            let withdrawal_account_info = &withdrawal_account_infos[0];
-            let recipient_token_account_info = &withdrawal_account_infos[1];
+    // Bounty is escrowed only if its token account is passed to instruction
+    let bounty_account_info = match accounts
+        .iter()
+        .find(|account_info| *account_info.key == bounty_pubkey)
+    {
+        Some(account_info) if is_program_account(&spl_token::id(), account_info) => account_info,
+        _ => return Ok(None),
+    };
 
-            let mut withdrawal_account_data =
-                WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
-            let round_number = withdrawal_account_data.round_number;
-            let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-            let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-            let event_configuration = withdrawal_account_data.pda.event_configuration;
-            let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
-            let (nonce, _) = withdrawal_account_data
-                .account_kind
-                .into_proposal()
-                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    Ok(Some((bounty_account_info, bounty_nonce)))
+}
 
-            let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
-                program_id,
-                round_number,
-                event_timestamp,
-                event_transaction_lt,
-                &event_configuration,
-                &event_data,
-                nonce,
-                withdrawal_account_info,
-            )?;
+fn release_bounty(
+    accounts: &[AccountInfo],
+    bounty_account_info: &AccountInfo,
+    bounty_nonce: u8,
+    withdrawal: &Pubkey,
+    author: &Pubkey,
+    recipient_token: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    if amount != 0 {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                &spl_token::id(),
+                bounty_account_info.key,
+                recipient_token,
+                bounty_account_info.key,
+                &[bounty_account_info.key],
+                amount,
+            )?,
+            accounts,
+            &[&[
+                br"bounty",
+                &withdrawal.to_bytes(),
+                &author.to_bytes(),
+                &[bounty_nonce],
+            ]],
+        )?;
+    }
 
-            if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending {
-                return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
-            }
+    Ok(())
+}
+
+// Part of `total` that falls on the fill from `filled` to `new_filled` out of `whole`.
+// Parts of consecutive fills sum up to `total` exactly
+fn pro_rata_part(total: u64, filled: u64, new_filled: u64, whole: u64) -> Option<u64> {
+    let share = |filled: u64| {
+        (total as u128)
+            .checked_mul(filled as u128)?
+            .checked_div(whole as u128)
+    };
 
-            // Check connection between token and proposal
-            if mint != withdrawal_account_data.event.data.mint {
-                return Err(ProgramError::InvalidArgument);
-            }
+    share(new_filled)?
+        .checked_sub(share(filled)?)?
+        .try_into()
+        .ok()
+}
 
-            // Validate Recipient account
-            let recipient_token_account_data =
-                spl_token::state::Account::unpack(&recipient_token_account_info.data.borrow())?;
+fn close_bounty_account(
+    accounts: &[AccountInfo],
+    bounty_account_info: &AccountInfo,
+    bounty_nonce: u8,
+    withdrawal: &Pubkey,
+    author: &Pubkey,
+    destination: &Pubkey,
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            &spl_token::id(),
+            bounty_account_info.key,
+            destination,
+            bounty_account_info.key,
+            &[bounty_account_info.key],
+        )?,
+        accounts,
+        &[&[
+            br"bounty",
+            &withdrawal.to_bytes(),
+            &author.to_bytes(),
+            &[bounty_nonce],
+        ]],
+    )
+}
 
-            if recipient_token_account_data.owner != withdrawal_account_data.event.data.recipient {
-                return Err(ProgramError::InvalidArgument);
-            }
+fn update_bridge_stats<F>(program_id: &Pubkey, accounts: &[AccountInfo], update: F) -> ProgramResult
+where
+    F: FnOnce(&mut BridgeStats) -> Option<()>,
+{
+    let bridge_stats_pubkey = get_associated_bridge_stats_address(program_id);
+
+    // Bridge stats account is required, so executor can't skip recording stats
+    let bridge_stats_account_info = accounts
+        .iter()
+        .find(|account_info| *account_info.key == bridge_stats_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    // Nothing to record until the account is created
+    if !is_program_account(program_id, bridge_stats_account_info) {
+        return Ok(());
+    }
 
-            if recipient_token_account_info.owner != &spl_token::id() {
-                return Err(ProgramError::InvalidArgument);
-            }
+    let mut bridge_stats_account_data =
+        BridgeStats::unpack(&bridge_stats_account_info.data.borrow())?;
 
-            let withdrawal_amount: u64 = withdrawal_account_data
-                .event
-                .data
-                .amount
-                .try_into()
-                .map_err(|_| SolanaBridgeError::Overflow)?;
+    update(&mut bridge_stats_account_data).ok_or(SolanaBridgeError::Overflow)?;
 
-            let fee = 1.max(
-                withdrawal_amount
-                    .checked_div(fee_info.divisor)
-                    .ok_or(SolanaBridgeError::Overflow)?
-                    .checked_mul(fee_info.multiplier)
-                    .ok_or(SolanaBridgeError::Overflow)?,
-            );
+    BridgeStats::pack(
+        bridge_stats_account_data,
+        &mut bridge_stats_account_info.data.borrow_mut(),
+    )?;
 
-            fee_amount_sum += fee;
+    Ok(())
+}
 
-            // Amount without fee
-            let withdrawal_amount = withdrawal_amount
-                .checked_sub(fee)
-                .ok_or(SolanaBridgeError::Overflow)?;
+fn update_approval_queue<F>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    update: F,
+) -> ProgramResult
+where
+    F: FnOnce(&mut ApprovalQueue) -> ProgramResult,
+{
+    let approval_queue_pubkey = get_associated_approval_queue_address(program_id);
+
+    // Approval queue account is required, so executor can't skip queueing
+    let approval_queue_account_info = accounts
+        .iter()
+        .find(|account_info| *account_info.key == approval_queue_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    // Withdrawal can't wait for approve outside of the queue
+    if !is_program_account(program_id, approval_queue_account_info) {
+        return Err(ProgramError::UninitializedAccount);
+    }
 
-            withdrawals_amount_sum += withdrawal_amount;
+    let mut approval_queue_account_data =
+        ApprovalQueue::unpack(&approval_queue_account_info.data.borrow())?;
 
-            // Amount without bounty
-            let transfer_withdrawal_amount = withdrawal_amount
-                .checked_sub(withdrawal_account_data.meta.data.bounty)
-                .ok_or(SolanaBridgeError::Overflow)?;
+    update(&mut approval_queue_account_data)?;
 
-            // Transfer SOL tokens
-            invoke(
-                &spl_token::instruction::transfer(
-                    &spl_token::id(),
-                    author_token_account_info.key,
-                    recipient_token_account_info.key,
-                    author_account_info.key,
-                    &[author_account_info.key],
-                    transfer_withdrawal_amount,
-                )?,
-                accounts,
-            )?;
+    ApprovalQueue::pack(
+        approval_queue_account_data,
+        &mut approval_queue_account_info.data.borrow_mut(),
+    )?;
 
-            withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Processed;
+    Ok(())
+}
 
-            solana_program::log::sol_log_data(&[&UpdateWithdrawalStatusEvent {
-                account: withdrawal_pubkey,
-                status: withdrawal_account_data.meta.data.status,
-            }
-            .try_to_vec()?]);
+fn enqueue_approval(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    withdrawal: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    update_approval_queue(program_id, accounts, |approval_queue| {
+        approval_queue
+            .push(Approval {
+                withdrawal: *withdrawal,
+                recipient: *recipient,
+                amount,
+                status: WithdrawalTokenStatus::WaitingForApprove,
+                note: None,
+            })
+            .ok_or_else(|| SolanaBridgeError::ApprovalQueueLenLimit.into())
+    })
+}
 
-            WithdrawalMultiTokenSol::pack(
-                withdrawal_account_data,
-                &mut withdrawal_account_info.data.borrow_mut(),
-            )?;
+fn review_approval(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    withdrawal: &Pubkey,
+    status: WithdrawalTokenStatus,
+    note: Option<Vec<u8>>,
+) -> ProgramResult {
+    if note
+        .as_ref()
+        .is_some_and(|note| note.len() > MAX_APPROVAL_NOTE_LEN)
+    {
+        return Err(SolanaBridgeError::ApprovalNoteTooLong.into());
+    }
 
-            solana_program::log::sol_log_data(&[&LiquidityRequestEvent {
-                deposit: deposit_pubkey,
-                withdrawal: withdrawal_pubkey,
-            }
-            .try_to_vec()?]);
-        }
+    update_approval_queue(program_id, accounts, |approval_queue| {
+        approval_queue.review(withdrawal, status, note);
+        Ok(())
+    })
+}
 
-        invoke_signed(
-            &system_instruction::create_account(
-                funder_account_info.key,
-                deposit_account_info.key,
-                1.max(rent.minimum_balance(DepositMultiTokenSol::LEN)),
-                DepositMultiTokenSol::LEN as u64,
+fn accrue_relay_reward<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    relay_account_info: &AccountInfo<'a>,
+    relay_reward_account_info: &AccountInfo<'a>,
+    round_number: u32,
+    rewarded: bool,
+) -> ProgramResult {
+    let mut relay_reward_account_data =
+        if !is_program_account(program_id, relay_reward_account_info) {
+            let (relay_reward_pubkey, relay_reward_nonce) = Pubkey::find_program_address(
+                &[
+                    br"relay_reward",
+                    &relay_account_info.key.to_bytes(),
+                    &round_number.to_le_bytes(),
+                ],
                 program_id,
-            ),
-            accounts,
-            &[deposit_account_signer_seeds],
-        )?;
+            );
+            let relay_reward_account_signer_seeds: &[&[_]] = &[
+                br"relay_reward",
+                &relay_account_info.key.to_bytes(),
+                &round_number.to_le_bytes(),
+                &[relay_reward_nonce],
+            ];
 
-        if fee_amount_sum + withdrawals_amount_sum > amount {
-            return Err(ProgramError::InsufficientFunds);
-        }
+            if relay_reward_pubkey != *relay_reward_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
 
-        let deposit_amount = amount - withdrawals_amount_sum;
-        let vault_account_info = next_account_info(account_info_iter)?;
-        validate_vault_account(program_id, &mint, vault_nonce, vault_account_info)?;
+            let system_program_info = accounts
+                .iter()
+                .find(|account_info| system_program::check_id(account_info.key))
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
-        // Make transfer
-        let vault_account_data =
-            spl_token::state::Account::unpack(&vault_account_info.data.borrow())?;
+            // Relay pays for its own ledger. Lamports sent to its address don't prevent creation
+            create_pda_account(
+                relay_account_info,
+                &Rent::get()?,
+                RelayReward::LEN,
+                program_id,
+                system_program_info,
+                relay_reward_account_info,
+                relay_reward_account_signer_seeds,
+            )?;
 
-        if vault_account_data
-            .amount
-            .checked_add(amount)
-            .ok_or(SolanaBridgeError::Overflow)?
-            > token_settings_account_data.deposit_limit
-        {
-            return Err(SolanaBridgeError::DepositLimit.into());
-        }
+            RelayReward {
+                is_initialized: true,
+                account_kind: AccountKind::RelayReward(relay_reward_nonce),
+                relay: *relay_account_info.key,
+                round_number,
+                votes: 0,
+                rewarded_votes: 0,
+                reparation: 0,
+                version: CURRENT_ACCOUNT_VERSION,
+            }
+        } else {
+            // Validate Relay Reward Account
+            let relay_reward_account_data =
+                RelayReward::unpack(&relay_reward_account_info.data.borrow())?;
+            let relay_reward_nonce = relay_reward_account_data
+                .account_kind
+                .into_relay_reward()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-        // Transfer SOL tokens
-        invoke(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                author_token_account_info.key,
-                vault_account_info.key,
-                author_account_info.key,
-                &[author_account_info.key],
-                deposit_amount,
-            )?,
-            accounts,
-        )?;
+            validate_relay_reward_account(
+                program_id,
+                relay_account_info.key,
+                round_number,
+                relay_reward_nonce,
+                relay_reward_account_info,
+            )?;
 
-        // Calculate fee
-        let fee = 1.max(
-            amount
-                .checked_div(fee_info.divisor)
-                .ok_or(SolanaBridgeError::Overflow)?
-                .checked_mul(fee_info.multiplier)
-                .ok_or(SolanaBridgeError::Overflow)?,
-        );
+            relay_reward_account_data
+        };
 
-        // Init Deposit Account
-        let amount = amount
-            .checked_sub(fee)
-            .ok_or(SolanaBridgeError::Overflow)?
-            .try_into()
-            .map_err(|_| SolanaBridgeError::Overflow)?;
+    relay_reward_account_data.votes = relay_reward_account_data
+        .votes
+        .checked_add(1)
+        .ok_or(SolanaBridgeError::Overflow)?;
 
-        let name = token_settings_account_data.name.clone();
-        let symbol = token_settings_account_data.symbol.clone();
+    if rewarded {
+        relay_reward_account_data.rewarded_votes = relay_reward_account_data
+            .rewarded_votes
+            .checked_add(1)
+            .ok_or(SolanaBridgeError::Overflow)?;
+    }
 
-        let deposit_account_data = DepositMultiTokenSol {
-            is_initialized: true,
-            account_kind: AccountKind::Deposit(deposit_nonce),
-            author: *author_account_info.key,
-            event: DepositMultiTokenSolEventWithLen::new(
-                mint,
-                name,
-                symbol,
-                decimals,
-                amount,
-                recipient,
-                value,
-                expected_evers,
-                payload,
-            ),
-            meta: DepositTokenMetaWithLen::new(deposit_seed),
-        };
+    relay_reward_account_data.reparation = relay_reward_account_data
+        .reparation
+        .checked_add(RELAY_REPARATION)
+        .ok_or(SolanaBridgeError::Overflow)?;
 
-        let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
-            .to_bytes()
-            .to_vec();
+    RelayReward::pack(
+        relay_reward_account_data,
+        &mut relay_reward_account_info.data.borrow_mut(),
+    )?;
 
-        solana_program::log::sol_log_data(&[&DepositMultiTokenEvent {
-            account: deposit_pubkey,
-            recipient,
-            transfer_amount: amount,
-            seed: deposit_seed,
-            value,
-            expected_evers,
-            event_data,
-        }
-        .try_to_vec()?]);
+    Ok(())
+}
 
-        DepositMultiTokenSol::pack(
-            deposit_account_data,
-            &mut deposit_account_info.data.borrow_mut(),
-        )?;
+fn create_denylist_account<'a>(
+    program_id: &Pubkey,
+    funder_account_info: &AccountInfo<'a>,
+    denylist_account_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    rent: &Rent,
+) -> ProgramResult {
+    let (denylist_pubkey, denylist_nonce) =
+        Pubkey::find_program_address(&[br"denylist"], program_id);
+    let denylist_account_signer_seeds: &[&[_]] = &[br"denylist", &[denylist_nonce]];
 
-        Ok(())
+    if denylist_pubkey != *denylist_account_info.key {
+        return Err(ProgramError::InvalidArgument);
     }
 
-    fn process_withdraw_proxy(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        amount: u64,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
+    // Lamports sent to its address don't prevent creation
+    create_pda_account(
+        funder_account_info,
+        rent,
+        Denylist::LEN,
+        program_id,
+        system_program_info,
+        denylist_account_info,
+        denylist_account_signer_seeds,
+    )?;
 
-        let creator_account_info = next_account_info(account_info_iter)?;
-        let recipient_token_account_info = next_account_info(account_info_iter)?;
-        let proxy_account_info = next_account_info(account_info_iter)?;
-        let mint_account_info = next_account_info(account_info_iter)?;
+    let denylist_account_data = Denylist {
+        is_initialized: true,
+        account_kind: AccountKind::Denylist(denylist_nonce),
+        addresses: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+    };
 
-        if !creator_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+    Denylist::pack(
+        denylist_account_data,
+        &mut denylist_account_info.data.borrow_mut(),
+    )
+}
 
-        let (proxy, nonce) = Pubkey::find_program_address(
-            &[
-                br"proxy",
-                &mint_account_info.key.to_bytes(),
-                &creator_account_info.key.to_bytes(),
-            ],
-            program_id,
-        );
+fn is_denylisted(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    addresses: &[Pubkey],
+) -> Result<bool, ProgramError> {
+    // Denylist account is required, so executor can't skip the check
+    let denylist_pubkey = get_associated_denylist_address(program_id);
+    let denylist_account_info = accounts
+        .iter()
+        .find(|account_info| *account_info.key == denylist_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    // Denylist account is created at initialization and settings migration,
+    // programs migrated earlier get it with the first added address
+    if !is_program_account(program_id, denylist_account_info) {
+        return Ok(false);
+    }
 
-        if proxy != *proxy_account_info.key {
-            return Err(ProgramError::InvalidArgument);
-        }
+    let denylist_account_data = Denylist::unpack(&denylist_account_info.data.borrow())?;
 
-        let proxy_signer_seeds: &[&[_]] = &[
-            br"proxy",
-            &mint_account_info.key.to_bytes(),
-            &creator_account_info.key.to_bytes(),
-            &[nonce],
-        ];
+    Ok(addresses
+        .iter()
+        .any(|address| denylist_account_data.addresses.contains(address)))
+}
 
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                &spl_token::id(),
-                proxy_account_info.key,
-                recipient_token_account_info.key,
-                proxy_account_info.key,
-                &[proxy_account_info.key],
-                amount,
-            )?,
-            accounts,
-            &[proxy_signer_seeds],
-        )?;
+fn is_registered_liquidity_provider(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    provider: &Pubkey,
+) -> Result<bool, ProgramError> {
+    let lp_registry_pubkey = get_associated_lp_registry_address(program_id);
+
+    // Registry is checked only if its account is passed to instruction
+    let lp_registry_account_info = match accounts
+        .iter()
+        .find(|account_info| *account_info.key == lp_registry_pubkey)
+    {
+        Some(account_info) if is_program_account(program_id, account_info) => account_info,
+        _ => return Ok(false),
+    };
 
-        Ok(())
-    }
+    let lp_registry_account_data = LpRegistry::unpack(&lp_registry_account_info.data.borrow())?;
 
-    fn process_close_deposit(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
+    Ok(lp_registry_account_data.providers.contains(provider))
+}
+
+fn is_treasury_token_account(settings: &Settings, token_account_info: &AccountInfo) -> bool {
+    settings.treasury.is_some()
+        && token_account_info.owner == &spl_token::id()
+        && spl_token::state::Account::unpack(&token_account_info.data.borrow())
+            .map(|token_account| Some(token_account.owner) == settings.treasury)
+            .unwrap_or_default()
+}
 
-        let author_account_info = next_account_info(account_info_iter)?;
-        let deposit_account_info = next_account_info(account_info_iter)?;
+fn increase_fee_supply(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    token_settings_account_info: &AccountInfo,
+    token_settings_account_data: &mut TokenSettings,
+    fee: u64,
+) -> Result<(), ProgramError> {
+    let fee_ledger_pubkey =
+        get_associated_fee_ledger_address(program_id, token_settings_account_info.key);
+
+    let fee_ledger_account_info = match accounts
+        .iter()
+        .find(|account_info| *account_info.key == fee_ledger_pubkey)
+    {
+        Some(account_info) if is_program_account(program_id, account_info) => account_info,
+        // Tokens without a fee ledger still accrue fee in token settings
+        _ => {
+            token_settings_account_data.fee_supply = token_settings_account_data
+                .fee_supply
+                .checked_add(fee)
+                .ok_or(SolanaBridgeError::Overflow)?;
 
-        if !author_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
+            return Ok(());
         }
+    };
 
-        // Validate Deposit Account
-        let deposit_account_data = Deposit::unpack_from_slice(&deposit_account_info.data.borrow())?;
+    let mut fee_ledger_account_data = unpack_fee_ledger(
+        program_id,
+        token_settings_account_info,
+        fee_ledger_account_info,
+    )?;
 
-        let meta = DepositTokenMeta::try_from_slice(&deposit_account_data.meta)?;
-        let nonce = deposit_account_data
-            .account_kind
-            .into_deposit()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    fee_ledger_account_data.supply = fee_ledger_account_data
+        .supply
+        .checked_add(fee)
+        .ok_or(SolanaBridgeError::Overflow)?;
 
-        bridge_utils::helper::validate_deposit_account(
-            program_id,
-            meta.seed,
-            nonce,
-            deposit_account_info,
-        )?;
+    FeeLedger::pack(
+        fee_ledger_account_data,
+        &mut fee_ledger_account_info.data.borrow_mut(),
+    )?;
 
-        if *author_account_info.key != deposit_account_data.author {
-            return Err(ProgramError::InvalidArgument);
-        }
+    Ok(())
+}
 
-        delete_account(deposit_account_info, author_account_info)?;
+fn decrease_fee_supply(
+    program_id: &Pubkey,
+    token_settings_account_info: &AccountInfo,
+    token_settings_account_data: &mut TokenSettings,
+    fee_ledger_account_info: &AccountInfo,
+    amount: u64,
+) -> Result<(), ProgramError> {
+    let fee_ledger_pubkey =
+        get_associated_fee_ledger_address(program_id, token_settings_account_info.key);
 
-        Ok(())
+    if fee_ledger_pubkey != *fee_ledger_account_info.key {
+        return Err(ProgramError::InvalidArgument);
     }
 
-    fn process_close_withdrawal(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
+    // Fee accrued in token settings is spent first
+    let settings_amount = amount.min(token_settings_account_data.fee_supply);
+    token_settings_account_data.fee_supply -= settings_amount;
 
-        let withdrawal_account_info = next_account_info(account_info_iter)?;
-        let withdrawal_author_account_info = next_account_info(account_info_iter)?;
+    let amount = amount - settings_amount;
+    if amount == 0 {
+        return Ok(());
+    }
 
-        // Validate Withdrawal Account
-        let withdrawal_account_data =
-            Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+    if !is_program_account(program_id, fee_ledger_account_info) {
+        return Err(SolanaBridgeError::InsufficientBalance.into());
+    }
 
-        let round_number = withdrawal_account_data.round_number;
-        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
-        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
-        let event_configuration = withdrawal_account_data.pda.event_configuration;
-        let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
-        let (nonce, _) = withdrawal_account_data
-            .account_kind
-            .into_proposal()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let mut fee_ledger_account_data = unpack_fee_ledger(
+        program_id,
+        token_settings_account_info,
+        fee_ledger_account_info,
+    )?;
 
-        bridge_utils::helper::validate_proposal_account(
-            program_id,
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            &event_data,
-            nonce,
-            withdrawal_account_info,
-        )?;
+    fee_ledger_account_data.supply = fee_ledger_account_data
+        .supply
+        .checked_sub(amount)
+        .ok_or(SolanaBridgeError::InsufficientBalance)?;
 
-        if *withdrawal_author_account_info.key != withdrawal_account_data.author {
-            return Err(ProgramError::InvalidArgument);
-        }
+    FeeLedger::pack(
+        fee_ledger_account_data,
+        &mut fee_ledger_account_info.data.borrow_mut(),
+    )?;
 
-        let meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
-        if meta.status != WithdrawalTokenStatus::Processed
-            && meta.status != WithdrawalTokenStatus::Cancelled
-        {
-            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
-        }
+    Ok(())
+}
 
-        delete_account(withdrawal_account_info, withdrawal_author_account_info)?;
+fn unpack_fee_ledger(
+    program_id: &Pubkey,
+    token_settings_account_info: &AccountInfo,
+    fee_ledger_account_info: &AccountInfo,
+) -> Result<FeeLedger, ProgramError> {
+    let fee_ledger_account_data = FeeLedger::unpack(&fee_ledger_account_info.data.borrow())?;
 
-        Ok(())
-    }
+    let fee_ledger_nonce = fee_ledger_account_data
+        .account_kind
+        .into_fee_ledger()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
 
-    fn process_withdraw_multi_vault(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        amount: u64,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
+    validate_fee_ledger_account(
+        program_id,
+        token_settings_account_info.key,
+        fee_ledger_nonce,
+        fee_ledger_account_info,
+    )?;
 
-        let authority_account_info = next_account_info(account_info_iter)?;
-        let recipient_account_info = next_account_info(account_info_iter)?;
-        let multi_vault_account_info = next_account_info(account_info_iter)?;
-        let settings_account_info = next_account_info(account_info_iter)?;
+    Ok(fee_ledger_account_data)
+}
 
-        if !authority_account_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+/// Indexes proposal by EVER event. Proposals of the event requested in other rounds must
+/// carry the same event data, divergent ones are rejected
+#[allow(clippy::too_many_arguments)]
+fn record_proposal_index<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    chain_id: Option<u64>,
+    event_data: &Hash,
+    funder_account_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    rent: &Rent,
+) -> ProgramResult {
+    let mut proposal_index_seeds = get_proposal_index_seeds(
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        chain_id,
+    );
 
-        // Validate Settings Account
-        let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+    let (proposal_index_pubkey, proposal_index_nonce) = Pubkey::find_program_address(
+        &proposal_index_seeds
+            .iter()
+            .map(Vec::as_slice)
+            .collect::<Vec<_>>(),
+        program_id,
+    );
 
-        let (settings_nonce, programdata_nonce) = settings_account_data
-            .account_kind
-            .into_settings()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    // Proposal index account is required, so divergent proposal can't skip the check
+    let proposal_index_account_info = accounts
+        .iter()
+        .find(|account_info| *account_info.key == proposal_index_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
-        bridge_utils::helper::validate_settings_account(
+    if !is_program_account(program_id, proposal_index_account_info) {
+        proposal_index_seeds.push(vec![proposal_index_nonce]);
+        let proposal_index_account_signer_seeds = proposal_index_seeds
+            .iter()
+            .map(Vec::as_slice)
+            .collect::<Vec<_>>();
+
+        create_pda_account(
+            funder_account_info,
+            rent,
+            ProposalIndex::LEN,
             program_id,
-            settings_nonce,
-            settings_account_info,
+            system_program_info,
+            proposal_index_account_info,
+            &proposal_index_account_signer_seeds,
         )?;
 
-        // Validate Manager Account
-        if *authority_account_info.key != settings_account_data.manager {
-            let programdata_account_info = next_account_info(account_info_iter)?;
-
-            // Validate Initializer Account
-            bridge_utils::helper::validate_programdata_account(
-                program_id,
-                programdata_nonce,
-                programdata_account_info.key,
-            )?;
-            bridge_utils::helper::validate_initializer_account(
-                authority_account_info.key,
-                programdata_account_info,
-            )?;
-        }
-
-        // Validate Multi Vault Account
-        let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
-        let multi_vault_nonce = multi_vault_account_data
-            .account_kind
-            .into_multi_vault()
-            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        let proposal_index_account_data = ProposalIndex {
+            is_initialized: true,
+            account_kind: AccountKind::ProposalIndex(proposal_index_nonce),
+            event_data: *event_data,
+            version: CURRENT_ACCOUNT_VERSION,
+            proposals: 1,
+        };
 
-        validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+        ProposalIndex::pack(
+            proposal_index_account_data,
+            &mut proposal_index_account_info.data.borrow_mut(),
+        )?;
+    } else {
+        let mut proposal_index_account_data =
+            ProposalIndex::unpack(&proposal_index_account_info.data.borrow())?;
 
-        // Transfer
-        let multi_vault_starting_lamports = multi_vault_account_info.lamports();
-        **multi_vault_account_info.lamports.borrow_mut() = multi_vault_starting_lamports
-            .checked_sub(amount)
-            .ok_or(SolanaBridgeError::Overflow)?;
+        if proposal_index_account_data.event_data != *event_data {
+            return Err(SolanaBridgeError::DivergentProposal.into());
+        }
 
-        let recipient_starting_lamports = recipient_account_info.lamports();
-        **recipient_account_info.lamports.borrow_mut() = recipient_starting_lamports
-            .checked_add(amount)
+        proposal_index_account_data.proposals = proposal_index_account_data
+            .proposals
+            .checked_add(1)
             .ok_or(SolanaBridgeError::Overflow)?;
 
-        Ok(())
+        ProposalIndex::pack(
+            proposal_index_account_data,
+            &mut proposal_index_account_info.data.borrow_mut(),
+        )?;
     }
-}
-
-fn make_ever_transfer<'a>(
-    mint_account_info: &AccountInfo<'a>,
-    recipient_account_info: &AccountInfo<'a>,
-    settings_account_data: &TokenSettings,
-    accounts: &[AccountInfo],
-    withdrawal_amount: u64,
-) -> ProgramResult {
-    // Mint EVER tokens to Proxy Account
-    let (_, token, _) = settings_account_data
-        .kind
-        .into_ever()
-        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-    let (_, mint_nonce) = settings_account_data
-        .account_kind
-        .into_token_settings()
-        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
-
-    let token_hash = hash(&token.try_to_vec()?);
-    let mint_account_signer_seeds: &[&[_]] = &[br"mint", token_hash.as_ref(), &[mint_nonce]];
-
-    invoke_signed(
-        &spl_token::instruction::mint_to(
-            &spl_token::id(),
-            mint_account_info.key,
-            recipient_account_info.key,
-            mint_account_info.key,
-            &[mint_account_info.key],
-            withdrawal_amount,
-        )?,
-        accounts,
-        &[mint_account_signer_seeds],
-    )?;
 
     Ok(())
 }
 
-fn make_sol_transfer<'a>(
-    vault_account_info: &AccountInfo<'a>,
-    recipient_account_info: &AccountInfo<'a>,
-    settings_account_data: &TokenSettings,
+/// Checks that executed proposal carries the indexed event data.
+/// Proposals requested before the index was introduced have no index
+fn claim_proposal_index(
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
-    withdrawal_amount: u64,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    chain_id: Option<u64>,
+    event_data: &Hash,
 ) -> ProgramResult {
-    // Transfer tokens from Vault Account to Recipient Account
-    let (mint, _) = settings_account_data
-        .kind
-        .into_solana()
-        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let proposal_index_pubkey = get_associated_proposal_index_address(
+        program_id,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        chain_id,
+    );
 
-    let (_, vault_nonce) = settings_account_data
-        .account_kind
-        .into_token_settings()
-        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let proposal_index_account_info = accounts
+        .iter()
+        .find(|account_info| *account_info.key == proposal_index_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
-    let vault_account_signer_seeds: &[&[_]] = &[br"vault", &mint.to_bytes(), &[vault_nonce]];
+    if !is_program_account(program_id, proposal_index_account_info) {
+        return Ok(());
+    }
 
-    invoke_signed(
-        &spl_token::instruction::transfer(
-            &spl_token::id(),
-            vault_account_info.key,
-            recipient_account_info.key,
-            vault_account_info.key,
-            &[vault_account_info.key],
-            withdrawal_amount,
-        )?,
-        accounts,
-        &[vault_account_signer_seeds],
-    )?;
+    let proposal_index_account_data =
+        ProposalIndex::unpack(&proposal_index_account_info.data.borrow())?;
+
+    if proposal_index_account_data.event_data != *event_data {
+        return Err(SolanaBridgeError::DivergentProposal.into());
+    }
 
     Ok(())
 }
 
-fn get_withdrawal_amount(
-    amount: u128,
-    ever_decimals: u8,
-    solana_decimals: u8,
-) -> Result<u64, ProgramError> {
-    let amount = if ever_decimals > solana_decimals {
-        let trunc_divisor = 10u128.pow((ever_decimals - solana_decimals) as u32);
-        amount
-            .checked_div(trunc_divisor)
-            .ok_or(SolanaBridgeError::Overflow)?
-    } else {
-        let trunc_multiplier = 10u128.pow((solana_decimals - ever_decimals) as u32);
-        amount
-            .checked_mul(trunc_multiplier)
-            .ok_or(SolanaBridgeError::Overflow)?
-    } as u64;
+/// Confirmed and required vote weight of proposal. Votes of Relays evicted from the round
+/// are not counted and its quorum is reduced accordingly
+fn get_proposal_quorum(
+    accounts: &[AccountInfo],
+    round_number: u32,
+    signers: &[Vote],
+    required_votes: u32,
+) -> Result<(u32, u32), ProgramError> {
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+
+    let find_account = |pubkey: &Pubkey| {
+        accounts
+            .iter()
+            .find(|account_info| account_info.key == pubkey)
+            .filter(|account_info| *account_info.owner == round_loader::id())
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    };
 
-    Ok(amount)
+    let relay_round_account_data =
+        RelayRound::unpack(&find_account(&relay_round_pubkey)?.data.borrow())?;
+    let rl_settings_account_data =
+        round_loader::Settings::unpack(&find_account(&rl_settings_pubkey)?.data.borrow())?;
+
+    Ok((
+        relay_round_account_data.confirmed_weight(signers),
+        round_loader::get_round_required_votes(
+            required_votes,
+            &relay_round_account_data,
+            &rl_settings_account_data,
+        ),
+    ))
 }
 
-fn get_deposit_amount(
-    amount: u64,
-    ever_decimals: u8,
-    solana_decimals: u8,
-) -> Result<u128, ProgramError> {
-    let amount = if ever_decimals > solana_decimals {
-        let trunc_multiplier = 10u128.pow((ever_decimals - solana_decimals) as u32);
-        (amount as u128)
-            .checked_mul(trunc_multiplier)
-            .ok_or(SolanaBridgeError::Overflow)?
-    } else {
-        let trunc_divisor = 10u128.pow((solana_decimals - ever_decimals) as u32);
-        (amount as u128)
-            .checked_div(trunc_divisor)
-            .ok_or(SolanaBridgeError::Overflow)?
+/// Releases proposal index of cancelled, expired or closed proposal. Index is deleted
+/// once the last requested proposal is gone
+fn release_proposal_index<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    withdrawal_account_data: &Proposal,
+    event_data: &Hash,
+    funder_account_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let proposal_index_pubkey = get_associated_proposal_index_address(
+        program_id,
+        withdrawal_account_data.pda.event_timestamp,
+        withdrawal_account_data.pda.event_transaction_lt,
+        &withdrawal_account_data.pda.event_configuration,
+        withdrawal_account_data.chain_id,
+    );
+
+    let proposal_index_account_info = match accounts
+        .iter()
+        .find(|account_info| *account_info.key == proposal_index_pubkey)
+    {
+        Some(account_info) if is_program_account(program_id, account_info) => account_info,
+        _ => return Ok(()),
     };
 
-    Ok(amount)
-}
+    let mut proposal_index_account_data =
+        ProposalIndex::unpack(&proposal_index_account_info.data.borrow())?;
 
-fn delete_account(
-    account_info: &AccountInfo,
-    author_account_info: &AccountInfo,
-) -> Result<(), ProgramError> {
-    let authority_starting_lamports = author_account_info.lamports();
-    **author_account_info.lamports.borrow_mut() = authority_starting_lamports
-        .checked_add(account_info.lamports())
-        .ok_or(SolanaBridgeError::Overflow)?;
+    // Proposal requested before the index was introduced
+    if proposal_index_account_data.event_data != *event_data {
+        return Ok(());
+    }
 
-    **account_info.lamports.borrow_mut() = 0;
+    proposal_index_account_data.proposals = proposal_index_account_data.proposals.saturating_sub(1);
 
-    bridge_utils::helper::delete_account(account_info);
+    if proposal_index_account_data.proposals == 0 {
+        delete_account(proposal_index_account_info, funder_account_info)?;
+    } else {
+        ProposalIndex::pack(
+            proposal_index_account_data,
+            &mut proposal_index_account_info.data.borrow_mut(),
+        )?;
+    }
 
     Ok(())
 }
@@ -4687,7 +14345,7 @@ fn create_proxy_account<'a>(
             &[spl_token_2022::extension::ExtensionType::ImmutableOwner],
         )?;*/
 
-        spl_associated_token_account::tools::account::create_pda_account(
+        create_pda_account(
             funder_account_info,
             &rent,
             account_len,
@@ -1,18 +1,42 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use bridge_derive::BridgePack;
+use bridge_utils::errors::SolanaBridgeError;
 use bridge_utils::state::{AccountKind, PDA};
-use bridge_utils::types::{EverAddress, UInt256, Vote};
+use bridge_utils::types::{EverAddress, UInt256, Vote, VoteReward};
 use enum_as_inner::EnumAsInner;
 use serde::{Deserialize, Serialize};
+use std::io::{self, Read};
 
+use solana_program::hash::{hashv, Hash};
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::{IsInitialized, Pack, Sealed};
 use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
 
 pub const MAX_NAME_LEN: usize = 32;
 pub const MAX_SYMBOL_LEN: usize = 32;
+pub const MAX_PAYLOAD_ALLOWLIST_LEN: usize = 20;
+pub const MAX_DEPOSIT_CALLER_ALLOWLIST_LEN: usize = 5;
+pub const MAX_PROXY_ACCOUNTS: u8 = 8;
+pub const MAX_GUARDIANS_LEN: usize = 5;
+pub const MAX_ENABLED_CHAINS_LEN: usize = 10;
+pub const MAX_WITHDRAWAL_LIMIT_EXEMPTIONS_LEN: usize = 10;
+pub const MAX_FEE_TIERS_LEN: usize = 4;
+pub const MAX_RECIPIENT_HOOK_DATA_LEN: usize = 32;
+pub const MAX_RECIPIENT_HOOK_ACCOUNTS: usize = 8;
+pub const MAX_DENYLIST_LEN: usize = 100;
+pub const MAX_LP_REGISTRY_LEN: usize = 100;
+pub const MAX_REFERRAL_FEE_BPS: u16 = 10_000;
+pub const MAX_APPROVAL_QUEUE_LEN: usize = 20;
+pub const MAX_APPROVAL_NOTE_LEN: usize = 64;
+pub const MAX_WITHDRAWAL_RECIPIENTS: usize = 40;
+pub const MAX_SOLANA_DECIMALS: u8 = 9;
+
+/// Layout version of new accounts. Accounts created before versioning read it as zero
+pub const CURRENT_ACCOUNT_VERSION: u8 = 1;
 
 pub const WITHDRAWAL_TOKEN_PERIOD: i64 = 86400;
+pub const WITHDRAWAL_REQUEST_TTL: i64 = 30 * 86400;
+pub const AUTHORITY_MIGRATION_TIMELOCK: i64 = 259200;
 
 const WITHDRAWAL_MULTI_TOKEN_EVER_EVENT_LEN: usize =
     1 + 1 + PUBKEY_BYTES                      // ever token root address
@@ -21,6 +45,15 @@ const WITHDRAWAL_MULTI_TOKEN_EVER_EVENT_LEN: usize =
     + PUBKEY_BYTES                            // solana recipient address
 ;
 
+const WITHDRAWAL_MULTI_TOKEN_EVER_TO_MANY_EVENT_LEN: usize =
+    1 + 1 + PUBKEY_BYTES                      // ever token root address
+    + 1                                       // decimals
+;
+
+const WITHDRAWAL_RECIPIENT_LEN: usize = PUBKEY_BYTES    // solana recipient address
+    + 16                                                // amount
+;
+
 const WITHDRAWAL_MULTI_TOKEN_SOL_EVENT_LEN: usize =
     PUBKEY_BYTES                              // solana mint address
     + 16                                      // amount
@@ -30,6 +63,11 @@ const WITHDRAWAL_MULTI_TOKEN_SOL_EVENT_LEN: usize =
 const WITHDRAWAL_TOKEN_META_LEN: usize = 1  // status
     + 8                                     // bounty
     + 8                                     // epoch
+    + PUBKEY_BYTES                          // funder
+    + 4                                     // payload cursor
+    + 8                                     // expires at
+    + 8                                     // filled amount
+    + 1                                     // rejected
 ;
 
 const DEPOSIT_MULTI_TOKEN_SOL_EVENT_LEN: usize = PUBKEY_BYTES   // solana mint address
@@ -38,6 +76,9 @@ const DEPOSIT_MULTI_TOKEN_SOL_EVENT_LEN: usize = PUBKEY_BYTES   // solana mint a
     + 8                                                         // value
     + 32                                                        // expected evers
     + 1 + 1 + PUBKEY_BYTES                                      // ever recipient address
+    + 16                                                        // fee
+    + 16                                                        // gross amount
+    + 1                                                         // reference flag
 ;
 
 const DEPOSIT_MULTI_TOKEN_EVER_EVENT_LEN: usize =
@@ -46,16 +87,22 @@ const DEPOSIT_MULTI_TOKEN_EVER_EVENT_LEN: usize =
     + 8                                                     // value
     + 32                                                    // expected evers
     + 1 + 1 + PUBKEY_BYTES                                  // ever recipient address
+    + 16                                                    // fee
+    + 16                                                    // gross amount
+    + 1                                                     // reference flag
 ;
 
 const DEPOSIT_TOKEN_META_LEN: usize = 16    // seed
+    + PUBKEY_BYTES                          // funder
+    + 8                                     // slot
+    + 1                                     // refunded
 ;
 
 const DEFAULT_MULTIPLIER: u64 = 5;
 const DEFAULT_DIVISOR: u64 = 10_000;
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
-#[bridge_pack(length = 1000)]
+#[bridge_pack(length = 1500)]
 pub struct Settings {
     pub is_initialized: bool,
     pub account_kind: AccountKind,
@@ -63,6 +110,51 @@ pub struct Settings {
     pub guardian: Pubkey,
     pub manager: Pubkey,
     pub withdrawal_manager: Pubkey,
+    pub payload_allowlist: Vec<Pubkey>,
+    pub deposit_caller_allowlist: Option<Vec<Pubkey>>,
+    pub emergency_expiry: Option<i64>,
+    pub emergency_info: Option<EmergencyInfo>,
+    pub token_defaults: Option<TokenDefaults>,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+    pub guardians: Vec<Pubkey>,
+    pub treasury: Option<Pubkey>,
+    pub wever_mint: Option<Pubkey>,
+    pub wever_defaults: Option<TokenDefaults>,
+    pub enabled_chains: Vec<u64>,
+    pub deposit_value_floor: Option<DepositValueFloor>,
+    pub min_supported_ix_version: u8,
+    pub pending_role: Option<PendingRole>,
+    // Delay before scheduled owner changes can be executed. Zero to disable timelock
+    pub timelock_delay: u32,
+    pub version: u8,
+}
+
+impl Settings {
+    pub fn is_emergency(&self, timestamp: i64) -> bool {
+        match self.emergency_expiry {
+            Some(expiry) => self.emergency && timestamp < expiry,
+            None => self.emergency,
+        }
+    }
+
+    pub fn ever_token_defaults(&self, mint: &Pubkey) -> TokenDefaults {
+        match self.wever_mint {
+            Some(wever_mint) if wever_mint == *mint => self
+                .wever_defaults
+                .or(self.token_defaults)
+                .unwrap_or_default(),
+            _ => self.token_defaults.unwrap_or_default(),
+        }
+    }
+
+    /// EVER chain is always enabled
+    pub fn is_chain_enabled(&self, chain_id: Option<u64>) -> bool {
+        match chain_id {
+            Some(chain_id) => self.enabled_chains.contains(&chain_id),
+            None => true,
+        }
+    }
 }
 
 impl Sealed for Settings {}
@@ -78,6 +170,7 @@ impl IsInitialized for Settings {
 pub struct MultiVault {
     pub is_initialized: bool,
     pub account_kind: AccountKind,
+    pub version: u8,
 }
 
 impl Sealed for MultiVault {}
@@ -105,6 +198,153 @@ pub struct TokenSettings {
     pub fee_supply: u64,
     pub fee_deposit_info: FeeInfo,
     pub fee_withdrawal_info: FeeInfo,
+    pub deposit_daily_limit: Option<u64>,
+    pub deposit_daily_amount: u64,
+    pub deposit_epoch: i64,
+    pub withdrawal_epoch_length: Option<i64>,
+    pub emergency_expiry: Option<i64>,
+    pub emergency_info: Option<EmergencyInfo>,
+    pub delisted: bool,
+    pub authority_migration: Option<AuthorityMigration>,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+    // Claimable reward for each confirming vote, taken from fee supply
+    pub vote_reward: u64,
+    // Recipients whose withdrawals are not bound by withdrawal daily limit
+    pub withdrawal_limit_exemptions: Vec<Pubkey>,
+    pub version: u8,
+    // Amount brackets and flat minimum of deposit fee
+    pub fee_deposit_tiers: FeeTiers,
+    // Amount brackets and flat minimum of withdrawal fee
+    pub fee_withdrawal_tiers: FeeTiers,
+    // Smallest accepted deposit amount. Zero to accept any amount
+    pub min_deposit_amount: u64,
+    // Withdrawals are filled by registered liquidity providers only
+    pub fills_restricted: bool,
+    // Share of deposit fee credited to referrer, in basis points
+    pub referral_fee_bps: u16,
+    // Confirmed withdrawals still to be paid out, token can't be closed until they drain
+    pub pending_withdrawals: u32,
+    // Token is closed and its settings are kept to prevent relisting
+    pub closed: bool,
+}
+
+impl TokenSettings {
+    pub fn is_emergency(&self, timestamp: i64) -> bool {
+        match self.emergency_expiry {
+            Some(expiry) => self.emergency && timestamp < expiry,
+            None => self.emergency,
+        }
+    }
+
+    pub fn get_withdrawal_epoch(&self, timestamp: i64) -> i64 {
+        timestamp
+            / self
+                .withdrawal_epoch_length
+                .unwrap_or(WITHDRAWAL_TOKEN_PERIOD)
+    }
+
+    pub fn is_withdrawal_limit_exempt(&self, recipient: &Pubkey) -> bool {
+        self.withdrawal_limit_exemptions.contains(recipient)
+    }
+
+    /// Counts withdrawals moving in and out of outstanding statuses. Withdrawals
+    /// confirmed before the counter was introduced are not counted
+    pub fn track_withdrawal(&mut self, old: WithdrawalTokenStatus, new: WithdrawalTokenStatus) {
+        match (old.is_outstanding(), new.is_outstanding()) {
+            (false, true) => {
+                self.pending_withdrawals = self.pending_withdrawals.saturating_add(1);
+            }
+            (true, false) => {
+                self.pending_withdrawals = self.pending_withdrawals.saturating_sub(1);
+            }
+            _ => (),
+        }
+    }
+
+    pub fn deposit_fee(&self, amount: u64) -> Option<u64> {
+        self.fee_deposit_info
+            .calculate_fee(&self.fee_deposit_tiers, amount)
+    }
+
+    pub fn withdrawal_fee(&self, amount: u64) -> Option<u64> {
+        self.fee_withdrawal_info
+            .calculate_fee(&self.fee_withdrawal_tiers, amount)
+    }
+
+    /// Referrer's share of deposit `fee`
+    pub fn referral_fee(&self, fee: u64) -> u64 {
+        (fee as u128 * self.referral_fee_bps as u128 / MAX_REFERRAL_FEE_BPS as u128) as u64
+    }
+
+    /// Outcome of a confirmed withdrawal executed at `timestamp`, computed the
+    /// same way as the withdraw instructions do. Amount is in Solana decimals
+    #[allow(clippy::too_many_arguments)]
+    pub fn preview_withdrawal(
+        &self,
+        settings: &Settings,
+        denylist: Option<&Denylist>,
+        author: &Pubkey,
+        recipient: &Pubkey,
+        amount: u64,
+        chain_id: Option<u64>,
+        vault_balance: Option<u64>,
+        timestamp: i64,
+    ) -> Result<WithdrawalPreview, SolanaBridgeError> {
+        if settings.is_emergency(timestamp) || self.is_emergency(timestamp) {
+            return Err(SolanaBridgeError::EmergencyEnabled);
+        }
+
+        if !settings.is_chain_enabled(chain_id) {
+            return Err(SolanaBridgeError::ChainDisabled);
+        }
+
+        if self.withdrawals_paused {
+            return Err(SolanaBridgeError::TokenWithdrawalsPaused);
+        }
+
+        let fee = self
+            .withdrawal_fee(amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Amount without fee
+        let transfer_amount = amount.checked_sub(fee).ok_or(SolanaBridgeError::Overflow)?;
+
+        // Daily amount is reset by the first withdrawal of a new epoch
+        let withdrawal_daily_amount =
+            if self.withdrawal_epoch == self.get_withdrawal_epoch(timestamp) {
+                self.withdrawal_daily_amount
+            } else {
+                0
+            }
+            .checked_add(transfer_amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let limit_exceeded = transfer_amount > self.withdrawal_limit;
+
+        let daily_limit_exceeded = withdrawal_daily_amount > self.withdrawal_daily_limit
+            && !self.is_withdrawal_limit_exempt(recipient);
+
+        let denylisted = denylist.is_some_and(|denylist| {
+            denylist.addresses.contains(recipient) || denylist.addresses.contains(author)
+        });
+
+        let waiting_for_approve = limit_exceeded || daily_limit_exceeded || denylisted;
+
+        // Approved Solana token withdrawal waits for vault to be refilled
+        let insufficient_vault_balance = matches!(self.kind, TokenKind::Solana { .. })
+            && vault_balance.is_some_and(|vault_balance| transfer_amount > vault_balance);
+
+        Ok(WithdrawalPreview {
+            fee,
+            transfer_amount,
+            limit_exceeded,
+            daily_limit_exceeded,
+            denylisted,
+            waiting_for_approve,
+            insufficient_vault_balance,
+        })
+    }
 }
 
 impl Sealed for TokenSettings {}
@@ -115,6 +355,351 @@ impl IsInitialized for TokenSettings {
     }
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 100)]
+pub struct TokenDecimals {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub token: EverAddress,
+    pub solana_decimals: u8,
+    pub version: u8,
+}
+
+impl Sealed for TokenDecimals {}
+
+impl IsInitialized for TokenDecimals {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 100)]
+pub struct ProposalIndex {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Event data of indexed proposals
+    pub event_data: Hash,
+    pub version: u8,
+    // Proposals requested for the event in different rounds
+    pub proposals: u32,
+}
+
+impl Sealed for ProposalIndex {}
+
+impl IsInitialized for ProposalIndex {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 100)]
+pub struct FeeLedger {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub token_settings: Pubkey,
+    pub supply: u64,
+    pub version: u8,
+}
+
+impl Sealed for FeeLedger {}
+
+impl IsInitialized for FeeLedger {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 500)]
+pub struct RecipientHook {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub recipient: Pubkey,
+    // Program notified about withdrawals paid to recipient
+    pub program_id: Pubkey,
+    // Instruction data prefix, e.g. `on_bridge_receive` discriminator
+    pub data: Vec<u8>,
+    // Additional accounts passed to the program
+    pub accounts: Vec<Pubkey>,
+    pub version: u8,
+}
+
+impl Sealed for RecipientHook {}
+
+impl IsInitialized for RecipientHook {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 500)]
+pub struct PendingChange {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub seed: u128,
+    pub change: TimelockedChange,
+    // Change can't be executed before this timestamp
+    pub eta: i64,
+    pub version: u8,
+}
+
+impl Sealed for PendingChange {}
+
+impl IsInitialized for PendingChange {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 3300)]
+pub struct Denylist {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Withdrawals to or requested by these addresses wait for approve
+    pub addresses: Vec<Pubkey>,
+    pub version: u8,
+}
+
+impl Sealed for Denylist {}
+
+impl IsInitialized for Denylist {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 3300)]
+pub struct LpRegistry {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Liquidity providers allowed to fill withdrawals of restricted tokens
+    pub providers: Vec<Pubkey>,
+    pub version: u8,
+}
+
+impl Sealed for LpRegistry {}
+
+impl IsInitialized for LpRegistry {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 100)]
+pub struct BridgeStats {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub deposits: u64,
+    pub withdrawals: u64,
+    // Sum of deposited amounts in Solana decimals
+    pub deposit_volume: u128,
+    // Sum of withdrawn amounts without fee in Solana decimals
+    pub withdrawal_volume: u128,
+    // Withdrawal requests neither released nor cancelled or expired
+    pub pending_proposals: u64,
+    pub version: u8,
+}
+
+impl BridgeStats {
+    pub fn record_deposit(&mut self, amount: u64) -> Option<()> {
+        self.deposits = self.deposits.checked_add(1)?;
+        self.deposit_volume = self.deposit_volume.checked_add(amount as u128)?;
+        Some(())
+    }
+
+    pub fn record_proposal(&mut self) -> Option<()> {
+        self.pending_proposals = self.pending_proposals.checked_add(1)?;
+        Some(())
+    }
+
+    /// Withdrawal tokens are released to recipient or its proxy
+    pub fn record_withdrawal(&mut self, amount: u64) -> Option<()> {
+        self.withdrawals = self.withdrawals.checked_add(1)?;
+        self.withdrawal_volume = self.withdrawal_volume.checked_add(amount as u128)?;
+        self.record_closed_proposal()
+    }
+
+    /// Proposals created before the stats account are not counted
+    pub fn record_closed_proposal(&mut self) -> Option<()> {
+        self.pending_proposals = self.pending_proposals.saturating_sub(1);
+        Some(())
+    }
+}
+
+impl Sealed for BridgeStats {}
+
+impl IsInitialized for BridgeStats {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Expected result of withdrawal execution
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct WithdrawalPreview {
+    // Withdrawal fee taken from amount
+    pub fee: u64,
+    // Amount transferred to recipient
+    pub transfer_amount: u64,
+    pub limit_exceeded: bool,
+    pub daily_limit_exceeded: bool,
+    pub denylisted: bool,
+    // Withdrawal waits for approve of withdrawal manager instead of transfer
+    pub waiting_for_approve: bool,
+    // Transfer waits for vault liquidity
+    pub insufficient_vault_balance: bool,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 100)]
+pub struct RelayReward {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub relay: Pubkey,
+    pub round_number: u32,
+    // Votes for withdrawals of the round
+    pub votes: u32,
+    // Confirmations credited with token vote reward, claimed by `ClaimVoteRewards`
+    pub rewarded_votes: u32,
+    // Lamports of voting reparation paid to relay
+    pub reparation: u64,
+    pub version: u8,
+}
+
+impl Sealed for RelayReward {}
+
+impl IsInitialized for RelayReward {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 100)]
+pub struct Referral {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub referrer: Pubkey,
+    pub token_settings: Pubkey,
+    // Deposit fee share not claimed yet by `ClaimReferralFee`
+    pub amount: u64,
+    pub version: u8,
+}
+
+impl Sealed for Referral {}
+
+impl IsInitialized for Referral {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 3000)]
+pub struct ApprovalQueue {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Withdrawals waiting for approve followed by reviewed ones, in order of arrival
+    pub approvals: Vec<Approval>,
+    pub version: u8,
+}
+
+impl ApprovalQueue {
+    /// Oldest reviewed withdrawal is dropped when the queue is full
+    pub fn push(&mut self, approval: Approval) -> Option<()> {
+        if self.approvals.len() >= MAX_APPROVAL_QUEUE_LEN {
+            let index = self
+                .approvals
+                .iter()
+                .position(|approval| approval.status != WithdrawalTokenStatus::WaitingForApprove)?;
+            self.approvals.remove(index);
+        }
+
+        self.approvals.push(approval);
+        Some(())
+    }
+
+    /// Withdrawals queued before the queue account are not tracked
+    pub fn review(
+        &mut self,
+        withdrawal: &Pubkey,
+        status: WithdrawalTokenStatus,
+        note: Option<Vec<u8>>,
+    ) {
+        if let Some(approval) = self.approvals.iter_mut().find(|approval| {
+            approval.withdrawal == *withdrawal
+                && approval.status == WithdrawalTokenStatus::WaitingForApprove
+        }) {
+            approval.status = status;
+            approval.note = note;
+        }
+    }
+}
+
+impl Sealed for ApprovalQueue {}
+
+impl IsInitialized for ApprovalQueue {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Approval {
+    pub withdrawal: Pubkey,
+    pub recipient: Pubkey,
+    // Withdrawal amount without fee in Solana decimals
+    pub amount: u64,
+    // Withdrawal status once reviewed
+    pub status: WithdrawalTokenStatus,
+    // Reviewer note left on approve or reject
+    pub note: Option<Vec<u8>>,
+}
+
+/// Appended to the recipient hook instruction data
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct OnBridgeReceive {
+    pub withdrawal: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Instruction data of the deposit callback
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct OnBridgeDeposit {
+    pub deposit_seed: u128,
+    // Hash of the deposit event data
+    pub event_hash: [u8; 32],
+}
+
+/// Metaplex `CreateMetadataAccountV3` instruction arguments
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct CreateMetadataAccountArgsV3 {
+    pub data: MetadataDataV2,
+    pub is_mutable: bool,
+    // Collection details are not used by bridged tokens
+    pub collection_details: Option<()>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct MetadataDataV2 {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    // Creators, collection and uses are not used by bridged tokens
+    pub creators: Option<()>,
+    pub collection: Option<()>,
+    pub uses: Option<()>,
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub struct Deposit {
     pub is_initialized: bool,
@@ -122,16 +707,17 @@ pub struct Deposit {
     pub author: Pubkey,
     pub event: Vec<u8>,
     pub meta: Vec<u8>,
+    pub version: u8,
 }
 
 impl Deposit {
     pub fn pack_into_slice(&self, dst: &mut [u8]) {
         let data = self.try_to_vec().unwrap();
-        let (left, _) = dst.split_at_mut(data.len());
-        left.copy_from_slice(&data);
+        bridge_utils::state::pack_with_discriminator(&self.account_kind, &data, dst);
     }
 
-    pub fn unpack_from_slice(mut src: &[u8]) -> Result<Self, ProgramError> {
+    pub fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let mut src = bridge_utils::state::strip_discriminator(src);
         let unpacked = Self::deserialize(&mut src)?;
         Ok(unpacked)
     }
@@ -153,6 +739,7 @@ pub struct DepositMultiTokenSol {
     pub author: Pubkey,
     pub event: DepositMultiTokenSolEventWithLen,
     pub meta: DepositTokenMetaWithLen,
+    pub version: u8,
 }
 
 impl Sealed for DepositMultiTokenSol {}
@@ -163,7 +750,7 @@ impl IsInitialized for DepositMultiTokenSol {
     }
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[derive(Debug, BorshSerialize, Serialize, Deserialize)]
 pub struct DepositMultiTokenSolEvent {
     pub base_token: Pubkey,
     pub name: String,
@@ -174,9 +761,33 @@ pub struct DepositMultiTokenSolEvent {
     pub value: u64,
     pub expected_evers: UInt256,
     pub payload: Vec<u8>,
+    // Fee taken and amount before fee in Solana token units
+    pub fee: u128,
+    pub gross_amount: u128,
+    // Off-chain reference to match the deposit with ledger entries
+    pub reference: Option<[u8; 32]>,
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+impl BorshDeserialize for DepositMultiTokenSolEvent {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            base_token: Pubkey::deserialize_reader(reader)?,
+            name: String::deserialize_reader(reader)?,
+            symbol: String::deserialize_reader(reader)?,
+            decimals: u8::deserialize_reader(reader)?,
+            amount: u128::deserialize_reader(reader)?,
+            recipient: EverAddress::deserialize_reader(reader)?,
+            value: u64::deserialize_reader(reader)?,
+            expected_evers: UInt256::deserialize_reader(reader)?,
+            payload: Vec::deserialize_reader(reader)?,
+            fee: deserialize_deposit_fee(reader)?,
+            gross_amount: deserialize_deposit_fee(reader)?,
+            reference: deserialize_deposit_reference(reader)?,
+        })
+    }
+}
+
+#[derive(Debug, BorshSerialize, Serialize, Deserialize)]
 pub struct DepositMultiTokenSolEventWithLen {
     pub len: u32,
     pub data: DepositMultiTokenSolEvent,
@@ -194,6 +805,9 @@ impl DepositMultiTokenSolEventWithLen {
         value: u64,
         expected_evers: UInt256,
         payload: Vec<u8>,
+        fee: u128,
+        gross_amount: u128,
+        reference: Option<[u8; 32]>,
     ) -> Self {
         Self {
             len: (DEPOSIT_MULTI_TOKEN_SOL_EVENT_LEN as u32)
@@ -202,7 +816,8 @@ impl DepositMultiTokenSolEventWithLen {
                 + 4
                 + (symbol.len() as u32)
                 + 4
-                + (payload.len() as u32),
+                + (payload.len() as u32)
+                + (reference.map_or(0, |reference| reference.len()) as u32),
             data: DepositMultiTokenSolEvent {
                 base_token,
                 name,
@@ -213,11 +828,28 @@ impl DepositMultiTokenSolEventWithLen {
                 value,
                 expected_evers,
                 payload,
+                fee,
+                gross_amount,
+                reference,
             },
         }
     }
 }
 
+impl BorshDeserialize for DepositMultiTokenSolEventWithLen {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let data = read_with_len(reader)?;
+
+        let data = DepositMultiTokenSolEvent::try_from_slice(&data)?;
+
+        // Legacy event is rewritten with the reference flag
+        Ok(Self {
+            len: data.try_to_vec()?.len() as u32,
+            data,
+        })
+    }
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
 #[bridge_pack(length = 1000)]
 pub struct DepositMultiTokenEver {
@@ -226,6 +858,7 @@ pub struct DepositMultiTokenEver {
     pub author: Pubkey,
     pub event: DepositMultiTokenEverEventWithLen,
     pub meta: DepositTokenMetaWithLen,
+    pub version: u8,
 }
 
 impl Sealed for DepositMultiTokenEver {}
@@ -236,7 +869,7 @@ impl IsInitialized for DepositMultiTokenEver {
     }
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[derive(Debug, BorshSerialize, Serialize, Deserialize)]
 pub struct DepositMultiTokenEverEvent {
     pub token: EverAddress,
     pub amount: u128,
@@ -244,15 +877,37 @@ pub struct DepositMultiTokenEverEvent {
     pub value: u64,
     pub expected_evers: UInt256,
     pub payload: Vec<u8>,
+    // Fee taken and amount before fee in Solana token units
+    pub fee: u128,
+    pub gross_amount: u128,
+    // Off-chain reference to match the deposit with ledger entries
+    pub reference: Option<[u8; 32]>,
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+impl BorshDeserialize for DepositMultiTokenEverEvent {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        Ok(Self {
+            token: EverAddress::deserialize_reader(reader)?,
+            amount: u128::deserialize_reader(reader)?,
+            recipient: EverAddress::deserialize_reader(reader)?,
+            value: u64::deserialize_reader(reader)?,
+            expected_evers: UInt256::deserialize_reader(reader)?,
+            payload: Vec::deserialize_reader(reader)?,
+            fee: deserialize_deposit_fee(reader)?,
+            gross_amount: deserialize_deposit_fee(reader)?,
+            reference: deserialize_deposit_reference(reader)?,
+        })
+    }
+}
+
+#[derive(Debug, BorshSerialize, Serialize, Deserialize)]
 pub struct DepositMultiTokenEverEventWithLen {
     pub len: u32,
     pub data: DepositMultiTokenEverEvent,
 }
 
 impl DepositMultiTokenEverEventWithLen {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         token: EverAddress,
         amount: u128,
@@ -260,9 +915,15 @@ impl DepositMultiTokenEverEventWithLen {
         value: u64,
         expected_evers: UInt256,
         payload: Vec<u8>,
+        fee: u128,
+        gross_amount: u128,
+        reference: Option<[u8; 32]>,
     ) -> Self {
         Self {
-            len: (DEPOSIT_MULTI_TOKEN_EVER_EVENT_LEN + 4 + payload.len()) as u32,
+            len: (DEPOSIT_MULTI_TOKEN_EVER_EVENT_LEN
+                + 4
+                + payload.len()
+                + reference.map_or(0, |reference| reference.len())) as u32,
             data: DepositMultiTokenEverEvent {
                 token,
                 amount,
@@ -270,31 +931,126 @@ impl DepositMultiTokenEverEventWithLen {
                 value,
                 expected_evers,
                 payload,
+                fee,
+                gross_amount,
+                reference,
             },
         }
     }
 }
 
+impl BorshDeserialize for DepositMultiTokenEverEventWithLen {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let data = read_with_len(reader)?;
+
+        let data = DepositMultiTokenEverEvent::try_from_slice(&data)?;
+
+        // Legacy event is rewritten with the reference flag
+        Ok(Self {
+            len: data.try_to_vec()?.len() as u32,
+            data,
+        })
+    }
+}
+
+/// Length prefix comes from account data, so it is not trusted for allocation
+fn read_with_len<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let len = u32::deserialize_reader(reader)? as usize;
+
+    let mut data = Vec::new();
+    reader.by_ref().take(len as u64).read_to_end(&mut data)?;
+
+    if data.len() != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(data)
+}
+
+/// Legacy deposit events end before the fee and gross amount
+fn deserialize_deposit_fee<R: Read>(reader: &mut R) -> io::Result<u128> {
+    let mut data = Vec::new();
+    reader.by_ref().take(16).read_to_end(&mut data)?;
+
+    match data.len() {
+        0 => Ok(0),
+        _ => u128::try_from_slice(&data),
+    }
+}
+
+/// Legacy deposit events end before the reference
+fn deserialize_deposit_reference<R: Read>(reader: &mut R) -> io::Result<Option<[u8; 32]>> {
+    let mut flag = [0; 1];
+    match reader.read_exact(&mut flag) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+
+    match flag[0] {
+        0 => Ok(None),
+        1 => Ok(Some(<[u8; 32]>::deserialize_reader(reader)?)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Invalid deposit reference flag",
+        )),
+    }
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 pub struct DepositTokenMeta {
     pub seed: u128,
+    pub funder: Pubkey,
+    // Slot of the transaction that created the deposit
+    pub slot: u64,
+    // Deposit was reversed by the withdrawal manager
+    pub refunded: bool,
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+impl DepositTokenMeta {
+    /// Deserialize meta stored in deposit. Legacy deposits have no slot and refund flag recorded
+    pub fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let mut data = src.to_vec();
+        data.resize(DEPOSIT_TOKEN_META_LEN.max(src.len()), 0);
+
+        let unpacked = Self::try_from_slice(&data)?;
+        Ok(unpacked)
+    }
+}
+
+#[derive(Debug, BorshSerialize, Serialize, Deserialize)]
 pub struct DepositTokenMetaWithLen {
     pub len: u32,
     pub data: DepositTokenMeta,
 }
 
 impl DepositTokenMetaWithLen {
-    pub fn new(seed: u128) -> Self {
+    pub fn new(seed: u128, funder: Pubkey, slot: u64) -> Self {
         Self {
             len: DEPOSIT_TOKEN_META_LEN as u32,
-            data: DepositTokenMeta { seed },
+            data: DepositTokenMeta {
+                seed,
+                funder,
+                slot,
+                refunded: false,
+            },
         }
     }
 }
 
+impl BorshDeserialize for DepositTokenMetaWithLen {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let data = read_with_len(reader)?;
+
+        // Meta is rewritten with the current length
+        Ok(Self {
+            len: DEPOSIT_TOKEN_META_LEN as u32,
+            data: DepositTokenMeta::unpack_from_slice(&data)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        })
+    }
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
 #[bridge_pack(length = 1000)]
 pub struct WithdrawalMultiTokenEver {
@@ -307,6 +1063,10 @@ pub struct WithdrawalMultiTokenEver {
     pub event: WithdrawalMultiTokenEverEventWithLen,
     pub meta: WithdrawalTokenMetaWithLen,
     pub signers: Vec<Vote>,
+    pub chain_id: Option<u64>,
+    pub vote_rewards: Vec<VoteReward>,
+    pub version: u8,
+    pub confirmed_weight: u32,
 }
 
 impl Sealed for WithdrawalMultiTokenEver {}
@@ -328,6 +1088,24 @@ pub struct WithdrawalMultiTokenEverEvent {
     pub payload: Vec<u8>,
 }
 
+impl WithdrawalMultiTokenEverEvent {
+    /// Hash of borsh encoded event computed without serializing the payload
+    pub fn hash(&self) -> Result<Hash, ProgramError> {
+        Ok(hashv(&[
+            &self.token.try_to_vec()?,
+            &(self.name.len() as u32).to_le_bytes(),
+            self.name.as_bytes(),
+            &(self.symbol.len() as u32).to_le_bytes(),
+            self.symbol.as_bytes(),
+            &[self.decimals],
+            &self.amount.to_le_bytes(),
+            self.recipient.as_ref(),
+            &(self.payload.len() as u32).to_le_bytes(),
+            &self.payload,
+        ]))
+    }
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 pub struct WithdrawalMultiTokenEverEventWithLen {
     pub len: u32,
@@ -365,6 +1143,82 @@ impl WithdrawalMultiTokenEverEventWithLen {
     }
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 3000)]
+pub struct WithdrawalMultiTokenEverToMany {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: WithdrawalMultiTokenEverToManyEventWithLen,
+    pub meta: WithdrawalTokenMetaWithLen,
+    pub signers: Vec<Vote>,
+    pub chain_id: Option<u64>,
+    pub vote_rewards: Vec<VoteReward>,
+    pub version: u8,
+    pub confirmed_weight: u32,
+}
+
+impl Sealed for WithdrawalMultiTokenEverToMany {}
+
+impl IsInitialized for WithdrawalMultiTokenEverToMany {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(
+    Debug, Copy, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize, PartialEq, Eq,
+)]
+pub struct WithdrawalRecipient {
+    pub recipient: Pubkey,
+    pub amount: u128,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct WithdrawalMultiTokenEverToManyEvent {
+    pub token: EverAddress,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub recipients: Vec<WithdrawalRecipient>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct WithdrawalMultiTokenEverToManyEventWithLen {
+    pub len: u32,
+    pub data: WithdrawalMultiTokenEverToManyEvent,
+}
+
+impl WithdrawalMultiTokenEverToManyEventWithLen {
+    pub fn new(
+        token: EverAddress,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        recipients: Vec<WithdrawalRecipient>,
+    ) -> Self {
+        Self {
+            len: WITHDRAWAL_MULTI_TOKEN_EVER_TO_MANY_EVENT_LEN as u32
+                + 4
+                + name.len() as u32
+                + 4
+                + symbol.len() as u32
+                + 4
+                + (recipients.len() * WITHDRAWAL_RECIPIENT_LEN) as u32,
+            data: WithdrawalMultiTokenEverToManyEvent {
+                token,
+                name,
+                symbol,
+                decimals,
+                recipients,
+            },
+        }
+    }
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
 #[bridge_pack(length = 1000)]
 pub struct WithdrawalMultiTokenSol {
@@ -377,6 +1231,10 @@ pub struct WithdrawalMultiTokenSol {
     pub event: WithdrawalMultiTokenSolEventWithLen,
     pub meta: WithdrawalTokenMetaWithLen,
     pub signers: Vec<Vote>,
+    pub chain_id: Option<u64>,
+    pub vote_rewards: Vec<VoteReward>,
+    pub version: u8,
+    pub confirmed_weight: u32,
 }
 
 impl Sealed for WithdrawalMultiTokenSol {}
@@ -395,6 +1253,19 @@ pub struct WithdrawalMultiTokenSolEvent {
     pub payload: Vec<u8>,
 }
 
+impl WithdrawalMultiTokenSolEvent {
+    /// Hash of borsh encoded event computed without serializing the payload
+    pub fn hash(&self) -> Hash {
+        hashv(&[
+            self.mint.as_ref(),
+            &self.amount.to_le_bytes(),
+            self.recipient.as_ref(),
+            &(self.payload.len() as u32).to_le_bytes(),
+            &self.payload,
+        ])
+    }
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 pub struct WithdrawalMultiTokenSolEventWithLen {
     pub len: u32,
@@ -415,35 +1286,101 @@ impl WithdrawalMultiTokenSolEventWithLen {
     }
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+#[derive(Debug, BorshSerialize, Serialize, Deserialize)]
 pub struct WithdrawalTokenMeta {
     pub status: WithdrawalTokenStatus,
     pub bounty: u64,
     pub epoch: i64,
+    pub funder: Pubkey,
+    pub payload_cursor: u32,
+    // Request can be expired after this timestamp. Zero for requests that never expire
+    pub expires_at: i64,
+    // Part of withdrawal amount already covered by fillers
+    pub filled_amount: u64,
+    // Rejected by withdrawal manager and not returned to author yet
+    pub rejected: bool,
 }
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+impl BorshDeserialize for WithdrawalTokenMeta {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let status = WithdrawalTokenStatus::deserialize_reader(reader)?;
+        let bounty = u64::deserialize_reader(reader)?;
+        let epoch = i64::deserialize_reader(reader)?;
+
+        // Legacy meta ends after epoch, fields added later are read as zeros
+        let funder = read_or_zeroed::<_, PUBKEY_BYTES>(reader)?;
+        let payload_cursor = read_or_zeroed(reader)?;
+        let expires_at = read_or_zeroed(reader)?;
+        let filled_amount = read_or_zeroed(reader)?;
+        let rejected = read_or_zeroed::<_, 1>(reader)?;
+
+        Ok(Self {
+            status,
+            bounty,
+            epoch,
+            funder: Pubkey::new_from_array(funder),
+            payload_cursor: u32::from_le_bytes(payload_cursor),
+            expires_at: i64::from_le_bytes(expires_at),
+            filled_amount: u64::from_le_bytes(filled_amount),
+            rejected: rejected[0] != 0,
+        })
+    }
+}
+
+/// Reads a field missing from legacy layouts that end before it
+fn read_or_zeroed<R: Read, const N: usize>(reader: &mut R) -> io::Result<[u8; N]> {
+    let mut buf = [0; N];
+    match reader.read_exact(&mut buf) {
+        Ok(()) => Ok(buf),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok([0; N]),
+        Err(e) => Err(e),
+    }
+}
+
+#[derive(Debug, BorshSerialize, Serialize, Deserialize)]
 pub struct WithdrawalTokenMetaWithLen {
     pub len: u32,
     pub data: WithdrawalTokenMeta,
 }
 
 impl WithdrawalTokenMetaWithLen {
-    pub fn new(bounty: u64, epoch: i64) -> Self {
+    pub fn new(bounty: u64, epoch: i64, funder: Pubkey, expires_at: i64) -> Self {
         Self {
             len: WITHDRAWAL_TOKEN_META_LEN as u32,
             data: WithdrawalTokenMeta {
                 epoch,
                 bounty,
+                funder,
+                payload_cursor: 0,
                 status: WithdrawalTokenStatus::New,
+                expires_at,
+                filled_amount: 0,
+                rejected: false,
             },
         }
     }
 }
 
+impl BorshDeserialize for WithdrawalTokenMetaWithLen {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let data = read_with_len(reader)?;
+
+        // Meta is rewritten with the current length
+        Ok(Self {
+            len: WITHDRAWAL_TOKEN_META_LEN as u32,
+            data: WithdrawalTokenMeta::try_from_slice(&data)?,
+        })
+    }
+}
+
 impl Default for WithdrawalTokenMetaWithLen {
     fn default() -> Self {
-        Self::new(Default::default(), Default::default())
+        Self::new(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
     }
 }
 
@@ -481,6 +1418,26 @@ pub enum WithdrawalTokenStatus {
     Pending,
     WaitingForApprove,
     WaitingForExecute,
+    Expired,
+}
+
+impl WithdrawalTokenStatus {
+    /// Withdrawal is confirmed but not paid out of the token yet
+    pub fn is_outstanding(&self) -> bool {
+        matches!(
+            self,
+            WithdrawalTokenStatus::Pending | WithdrawalTokenStatus::WaitingForApprove
+        )
+    }
+}
+
+/// Minimal deposit value per unit of expected evers
+#[derive(
+    Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Eq, PartialEq,
+)]
+pub struct DepositValueFloor {
+    pub multiplier: u64,
+    pub divisor: u64,
 }
 
 #[derive(
@@ -491,6 +1448,38 @@ pub struct FeeInfo {
     pub divisor: u64,
 }
 
+impl FeeInfo {
+    /// Zero multiplier disables the fee, otherwise at least one unit is charged
+    pub fn min_fee(&self) -> u64 {
+        if self.multiplier == 0 {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Rate of the highest tier reached by the amount overrides the base rate.
+    /// Flat minimum is charged unless the rate is zero
+    pub fn calculate_fee(&self, tiers: &FeeTiers, amount: u64) -> Option<u64> {
+        let fee_info = tiers
+            .tiers
+            .iter()
+            .rev()
+            .find(|tier| amount >= tier.amount_threshold)
+            .map(|tier| tier.fee_info)
+            .unwrap_or(*self);
+
+        let fee = amount
+            .checked_div(fee_info.divisor)?
+            .checked_mul(fee_info.multiplier)?;
+
+        match fee_info.multiplier {
+            0 => Some(0),
+            _ => Some(fee.max(fee_info.min_fee()).max(tiers.min_fee)),
+        }
+    }
+}
+
 impl Default for FeeInfo {
     fn default() -> Self {
         FeeInfo {
@@ -500,7 +1489,156 @@ impl Default for FeeInfo {
     }
 }
 
-#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+#[derive(
+    Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Eq, PartialEq,
+)]
+pub struct FeeTier {
+    // Tier applies to amounts starting from this threshold
+    pub amount_threshold: u64,
+    pub fee_info: FeeInfo,
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Default, Clone, Eq, PartialEq,
+)]
+pub struct FeeTiers {
+    // Ascending by amount threshold
+    pub tiers: Vec<FeeTier>,
+    // Flat minimum fee in token units
+    pub min_fee: u64,
+}
+
+#[derive(
+    Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Eq, PartialEq,
+)]
+pub struct TokenDefaults {
+    pub deposit_limit: u64,
+    pub withdrawal_limit: u64,
+    pub withdrawal_daily_limit: u64,
+    pub deposit_daily_limit: Option<u64>,
+    pub fee_deposit_info: FeeInfo,
+    pub fee_withdrawal_info: FeeInfo,
+}
+
+impl Default for TokenDefaults {
+    fn default() -> Self {
+        TokenDefaults {
+            deposit_limit: u64::MAX,
+            withdrawal_limit: u64::MAX,
+            withdrawal_daily_limit: u64::MAX,
+            deposit_daily_limit: None,
+            fee_deposit_info: Default::default(),
+            fee_withdrawal_info: Default::default(),
+        }
+    }
+}
+
+#[derive(
+    Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Eq, PartialEq,
+)]
+pub enum Role {
+    Guardian,
+    Manager,
+    WithdrawalManager,
+}
+
+/// Owner change that is executed after timelock delay
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Eq, PartialEq)]
+pub enum TimelockedChange {
+    WithdrawalLimits {
+        token_settings: Pubkey,
+        new_withdrawal_limit: Option<u64>,
+        new_withdrawal_daily_limit: Option<u64>,
+    },
+    Fee {
+        token_settings: Pubkey,
+        fee_type: FeeType,
+        multiplier: u64,
+        divisor: u64,
+    },
+    Role {
+        role: Role,
+        new_authority: Option<Pubkey>,
+    },
+    Delay {
+        delay_seconds: u32,
+    },
+    WithdrawalLimitExemptions {
+        token_settings: Pubkey,
+        new_withdrawal_limit_exemptions: Vec<Pubkey>,
+    },
+    Wever {
+        new_wever_mint: Option<Pubkey>,
+        new_wever_defaults: Option<TokenDefaults>,
+    },
+    TokenDefaults {
+        new_token_defaults: TokenDefaults,
+    },
+}
+
+impl TimelockedChange {
+    pub fn token_settings(&self) -> Option<Pubkey> {
+        match self {
+            TimelockedChange::WithdrawalLimits { token_settings, .. }
+            | TimelockedChange::Fee { token_settings, .. }
+            | TimelockedChange::WithdrawalLimitExemptions { token_settings, .. } => {
+                Some(*token_settings)
+            }
+            TimelockedChange::Role { .. }
+            | TimelockedChange::Delay { .. }
+            | TimelockedChange::Wever { .. }
+            | TimelockedChange::TokenDefaults { .. } => None,
+        }
+    }
+}
+
+impl std::str::FromStr for Role {
+    type Err = Box<dyn std::error::Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "Guardian" => Ok(Role::Guardian),
+            "Manager" => Ok(Role::Manager),
+            "WithdrawalManager" => Ok(Role::WithdrawalManager),
+            _ => Err("wrong role".to_string().into()),
+        }
+    }
+}
+
+#[derive(
+    Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Eq, PartialEq,
+)]
+pub struct PendingRole {
+    pub role: Role,
+    // Authority that has to accept the role
+    pub authority: Pubkey,
+}
+
+#[derive(
+    Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Eq, PartialEq,
+)]
+pub struct EmergencyInfo {
+    // Who toggled emergency mode
+    pub authority: Pubkey,
+    // When emergency mode was toggled
+    pub timestamp: i64,
+    // Optional reason code
+    pub reason: Option<u32>,
+}
+
+#[derive(
+    Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, Eq, PartialEq,
+)]
+pub struct AuthorityMigration {
+    // Authority to hand the token over to
+    pub new_authority: Pubkey,
+    // Migration is not allowed before this timestamp
+    pub unlock_timestamp: i64,
+}
+
+#[derive(
+    Debug, Clone, Copy, BorshSerialize, BorshDeserialize, Serialize, Deserialize, Eq, PartialEq,
+)]
 pub enum FeeType {
     Deposit,
     Withdrawal,
@@ -543,6 +1681,17 @@ pub struct WithdrawMultiTokenRequestEvent {
     pub bounty: i64,
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct WithdrawMultiTokenToManyRequestEvent {
+    pub account: Pubkey,
+    pub token: String,
+    pub recipients: Vec<WithdrawalRecipient>,
+    pub event_timestamp: u32,
+    pub event_transaction_lt: u64,
+    pub event_configuration: Pubkey,
+    pub event_data: Vec<u8>,
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub struct TokenSettingsEvent {
     pub account: Pubkey,
@@ -556,6 +1705,13 @@ pub struct TokenSettingsEvent {
     pub fee: FeeInfo,
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct VoteForWithdrawalEvent {
+    pub account: Pubkey,
+    pub relay: Pubkey,
+    pub vote: Vote,
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub struct UpdateWithdrawalStatusEvent {
     pub account: Pubkey,
@@ -570,6 +1726,13 @@ pub struct UpdateFeeEvent {
     pub divisor: u64,
 }
 
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct UpdateFeeTiersEvent {
+    pub token_settings: Pubkey,
+    pub fee_type: FeeType,
+    pub fee_tiers: FeeTiers,
+}
+
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
 pub struct UpdateTokenNameEvent {
     pub token_settings: Pubkey,
@@ -2,8 +2,19 @@ use borsh::BorshSerialize;
 use bridge_utils::types::EverAddress;
 use solana_program::account_info::AccountInfo;
 use solana_program::hash::hash;
+use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
+
+use crate::{CreateMetadataAccountArgsV3, MetadataDataV2};
+
+pub mod token_metadata {
+    solana_program::declare_id!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+}
+
+// Metaplex `CreateMetadataAccountV3` instruction discriminator
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
 
 pub fn get_associated_settings_address(program_id: &Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[br"settings"], program_id).0
@@ -25,6 +36,11 @@ pub fn get_associated_token_settings_sol_address(program_id: &Pubkey, mint: &Pub
     Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], program_id).0
 }
 
+pub fn get_associated_token_decimals_address(program_id: &Pubkey, token: &EverAddress) -> Pubkey {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+    Pubkey::find_program_address(&[br"decimals", token_hash.as_ref()], program_id).0
+}
+
 pub fn get_associated_mint_address(program_id: &Pubkey, token: &EverAddress) -> Pubkey {
     let token_hash = hash(&token.try_to_vec().expect("pack"));
     Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], program_id).0
@@ -38,6 +54,131 @@ pub fn get_associated_deposit_address(program_id: &Pubkey, seed: u128) -> Pubkey
     Pubkey::find_program_address(&[br"deposit", &seed.to_le_bytes()], program_id).0
 }
 
+pub fn get_proposal_index_seeds(
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    chain_id: Option<u64>,
+) -> Vec<Vec<u8>> {
+    let mut seeds = vec![
+        br"proposal_index".to_vec(),
+        event_timestamp.to_le_bytes().to_vec(),
+        event_transaction_lt.to_le_bytes().to_vec(),
+        event_configuration.to_bytes().to_vec(),
+    ];
+
+    if let Some(chain_id) = chain_id {
+        seeds.push(chain_id.to_le_bytes().to_vec());
+    }
+
+    seeds
+}
+
+pub fn get_associated_proposal_index_address(
+    program_id: &Pubkey,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    chain_id: Option<u64>,
+) -> Pubkey {
+    let seeds = get_proposal_index_seeds(
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        chain_id,
+    );
+
+    Pubkey::find_program_address(
+        &seeds.iter().map(Vec::as_slice).collect::<Vec<_>>(),
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_fee_ledger_address(program_id: &Pubkey, token_settings: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"fee_ledger", &token_settings.to_bytes()], program_id).0
+}
+
+pub fn get_associated_recipient_hook_address(program_id: &Pubkey, recipient: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"hook", &recipient.to_bytes()], program_id).0
+}
+
+pub fn get_associated_relay_reward_address(
+    program_id: &Pubkey,
+    relay: &Pubkey,
+    round_number: u32,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            br"relay_reward",
+            &relay.to_bytes(),
+            &round_number.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_referral_address(
+    program_id: &Pubkey,
+    token_settings: &Pubkey,
+    referrer: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            br"referral",
+            &token_settings.to_bytes(),
+            &referrer.to_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_bridge_stats_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"bridge_stats"], program_id).0
+}
+
+pub fn get_associated_denylist_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"denylist"], program_id).0
+}
+
+pub fn get_associated_lp_registry_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"lp_registry"], program_id).0
+}
+
+pub fn get_associated_approval_queue_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"approval_queue"], program_id).0
+}
+
+pub fn get_associated_pending_change_address(program_id: &Pubkey, seed: u128) -> Pubkey {
+    Pubkey::find_program_address(&[br"pending_change", &seed.to_le_bytes()], program_id).0
+}
+
+pub fn get_associated_bounty_address(
+    program_id: &Pubkey,
+    withdrawal: &Pubkey,
+    author: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"bounty", &withdrawal.to_bytes(), &author.to_bytes()],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_token_metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            br"metadata",
+            &token_metadata::id().to_bytes(),
+            &mint.to_bytes(),
+        ],
+        &token_metadata::id(),
+    )
+    .0
+}
+
 pub fn get_associated_proxy_address(
     program_id: &Pubkey,
     mint: &Pubkey,
@@ -50,6 +191,19 @@ pub fn get_associated_proxy_address(
     .0
 }
 
+pub fn get_associated_proxy_account_address(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    index: u8,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"proxy", &mint.to_bytes(), &recipient.to_bytes(), &[index]],
+        program_id,
+    )
+    .0
+}
+
 pub fn get_associated_mint(program_id: &Pubkey, token: &EverAddress) -> Pubkey {
     let token_hash = hash(&token.try_to_vec().expect("pack"));
     Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], program_id).0
@@ -119,6 +273,28 @@ pub fn validate_mint_account(
     Ok(())
 }
 
+pub fn validate_token_decimals_account(
+    program_id: &Pubkey,
+    token: &EverAddress,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+
+    let (account, expected_nonce) =
+        Pubkey::find_program_address(&[br"decimals", token_hash.as_ref()], program_id);
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if expected_nonce != nonce {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
 pub fn validate_vault_account(
     program_id: &Pubkey,
     mint: &Pubkey,
@@ -157,6 +333,156 @@ pub fn validate_multi_vault_account(
     Ok(())
 }
 
+pub fn validate_fee_ledger_account(
+    program_id: &Pubkey,
+    token_settings: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let (account, expected_nonce) =
+        Pubkey::find_program_address(&[br"fee_ledger", &token_settings.to_bytes()], program_id);
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if expected_nonce != nonce {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_recipient_hook_account(
+    program_id: &Pubkey,
+    recipient: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let (account, expected_nonce) =
+        Pubkey::find_program_address(&[br"hook", &recipient.to_bytes()], program_id);
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if expected_nonce != nonce {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_relay_reward_account(
+    program_id: &Pubkey,
+    relay: &Pubkey,
+    round_number: u32,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let (account, expected_nonce) = Pubkey::find_program_address(
+        &[
+            br"relay_reward",
+            &relay.to_bytes(),
+            &round_number.to_le_bytes(),
+        ],
+        program_id,
+    );
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if expected_nonce != nonce {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_referral_account(
+    program_id: &Pubkey,
+    token_settings: &Pubkey,
+    referrer: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let (account, expected_nonce) = Pubkey::find_program_address(
+        &[
+            br"referral",
+            &token_settings.to_bytes(),
+            &referrer.to_bytes(),
+        ],
+        program_id,
+    );
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if expected_nonce != nonce {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_denylist_account(
+    program_id: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let (account, expected_nonce) = Pubkey::find_program_address(&[br"denylist"], program_id);
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if expected_nonce != nonce {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_lp_registry_account(
+    program_id: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let (account, expected_nonce) = Pubkey::find_program_address(&[br"lp_registry"], program_id);
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if expected_nonce != nonce {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_pending_change_account(
+    program_id: &Pubkey,
+    seed: u128,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let (account, expected_nonce) =
+        Pubkey::find_program_address(&[br"pending_change", &seed.to_le_bytes()], program_id);
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if expected_nonce != nonce {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
 pub fn validate_proxy_account(
     program_id: &Pubkey,
     mint: &Pubkey,
@@ -179,3 +505,48 @@ pub fn validate_proxy_account(
 
     Ok(())
 }
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_metadata_account_v3_ix(
+    metadata_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    mint_authority_pubkey: &Pubkey,
+    payer_pubkey: &Pubkey,
+    update_authority_pubkey: &Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+    data.extend(
+        CreateMetadataAccountArgsV3 {
+            data: MetadataDataV2 {
+                name,
+                symbol,
+                uri,
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            },
+            is_mutable: true,
+            collection_details: None,
+        }
+        .try_to_vec()
+        .expect("pack"),
+    );
+
+    Instruction {
+        program_id: token_metadata::id(),
+        accounts: vec![
+            AccountMeta::new(*metadata_pubkey, false),
+            AccountMeta::new_readonly(*mint_pubkey, false),
+            AccountMeta::new_readonly(*mint_authority_pubkey, true),
+            AccountMeta::new(*payer_pubkey, true),
+            AccountMeta::new_readonly(*update_authority_pubkey, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
@@ -1,10 +1,12 @@
 #![cfg(feature = "test-bpf")]
 
-use borsh::BorshSerialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use bridge_utils::state::{AccountKind, Proposal, PDA};
-use bridge_utils::types::{EverAddress, UInt256, Vote, RELAY_REPARATION};
+use bridge_utils::types::{EverAddress, UInt256, Vote, VoteReward, RELAY_REPARATION};
 
+use solana_program::account_info::AccountInfo;
 use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
+use solana_program::entrypoint::ProgramResult;
 use solana_program::hash::hash;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::rent::Rent;
@@ -16,6 +18,7 @@ use solana_sdk::transaction::Transaction;
 use spl_token::native_mint::ID as NATIVE_MINT;
 use spl_token::state::AccountState;
 
+use bridge_test_utils::TestBridge;
 use token_proxy::*;
 
 #[tokio::test]
@@ -90,6 +93,8 @@ async fn test_init_settings() {
     assert_eq!(settings_data.guardian, guardian);
     assert_eq!(settings_data.manager, manager);
     assert_eq!(settings_data.withdrawal_manager, withdrawal_manager);
+    assert_eq!(settings_data.payload_allowlist, vec![spl_token::id()]);
+    assert_eq!(settings_data.deposit_caller_allowlist, None);
 
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
     assert_eq!(
@@ -113,6 +118,273 @@ async fn test_init_settings() {
         multivault_data.account_kind,
         AccountKind::MultiVault(multivault_nonce)
     );
+
+    // Check Denylist Account
+    let denylist_info = banks_client
+        .get_account(get_denylist_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let denylist_data = Denylist::unpack(denylist_info.data()).expect("denylist unpack");
+    assert_eq!(denylist_data.addresses, vec![]);
+}
+
+#[tokio::test]
+async fn test_migrate_settings_v2() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account in V1 layout
+    let settings_v1_len = 1000;
+
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: 0,
+    };
+
+    let mut settings_packed = settings_account_data.try_to_vec().unwrap();
+    settings_packed.resize(settings_v1_len, 0);
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(settings_v1_len),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_settings_v2_ix(funder.pubkey(), owner.pubkey())],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Settings Account
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(settings_info.data.len(), Settings::LEN);
+    assert_eq!(
+        settings_info.lamports,
+        Rent::default().minimum_balance(Settings::LEN)
+    );
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.guardian, guardian);
+    assert_eq!(settings_data.manager, manager);
+    assert_eq!(settings_data.withdrawal_manager, withdrawal_manager);
+    // Payload allowlist is seeded with token program
+    assert_eq!(settings_data.payload_allowlist, vec![spl_token::id()]);
+    assert_eq!(settings_data.deposits_paused, false);
+    assert_eq!(settings_data.withdrawals_paused, false);
+    assert_eq!(settings_data.guardians, vec![]);
+    assert_eq!(settings_data.treasury, None);
+
+    // Check Denylist Account
+    let denylist_info = banks_client
+        .get_account(get_denylist_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let denylist_data = Denylist::unpack(denylist_info.data()).expect("denylist unpack");
+    assert_eq!(denylist_data.addresses, vec![]);
+}
+
+#[tokio::test]
+async fn test_migrate_account() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Add Token Settings Account in legacy layout
+    let legacy_len = 500;
+
+    let mint = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    let withdrawal_limit = 10000;
+
+    let token_settings_address = get_token_settings_sol_address(&mint);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(0, 0),
+        kind: TokenKind::Solana { mint, vault },
+        name: "USDT".to_string(),
+        symbol: "USDT".to_string(),
+        deposit_limit: 0,
+        withdrawal_limit,
+        withdrawal_daily_limit: 0,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: 0,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = token_settings_account_data.try_to_vec().unwrap();
+    token_settings_packed.resize(legacy_len, 0);
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(legacy_len),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_account_ix(funder.pubkey(), token_settings_address)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Token Settings Account
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(token_settings_info.data.len(), TokenSettings::LEN);
+    assert_eq!(
+        token_settings_info.lamports,
+        Rent::default().minimum_balance(TokenSettings::LEN)
+    );
+
+    // Migrated account is tagged
+    assert!(token_settings_info
+        .data()
+        .starts_with(&TOKEN_SETTINGS_DISCRIMINATOR));
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.version, CURRENT_ACCOUNT_VERSION);
+    assert_eq!(token_settings_data.withdrawal_limit, withdrawal_limit);
+
+    // Migrated account can't be migrated again. Another signer keeps
+    // the transaction from being deduplicated
+    let migrator = Keypair::new();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_account_ix(
+            migrator.pubkey(),
+            token_settings_address,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &migrator], recent_blockhash);
+
+    let _ = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("account already migrated");
 }
 
 #[tokio::test]
@@ -140,6 +412,23 @@ async fn test_deposit_ever() {
         guardian,
         manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -217,6 +506,26 @@ async fn test_deposit_ever() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
     let fee_info = token_settings_account_data.fee_deposit_info.clone();
@@ -242,6 +551,7 @@ async fn test_deposit_ever() {
     let multivault_account_data = MultiVault {
         is_initialized: true,
         account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut multivault_packed = vec![0; MultiVault::LEN];
@@ -387,6 +697,8 @@ async fn test_deposit_ever() {
     let transfer_amount = amount - fee;
 
     assert_eq!(deposit_data.event.data.amount, transfer_amount as u128);
+    assert_eq!(deposit_data.event.data.fee, fee as u128);
+    assert_eq!(deposit_data.event.data.gross_amount, amount as u128);
 }
 
 #[tokio::test]
@@ -414,6 +726,23 @@ async fn test_deposit_ever_for_18_decimals() {
         guardian,
         manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -491,6 +820,26 @@ async fn test_deposit_ever_for_18_decimals() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
     let fee_info = token_settings_account_data.fee_deposit_info.clone();
@@ -516,6 +865,7 @@ async fn test_deposit_ever_for_18_decimals() {
     let multivault_account_data = MultiVault {
         is_initialized: true,
         account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut multivault_packed = vec![0; MultiVault::LEN];
@@ -664,6 +1014,8 @@ async fn test_deposit_ever_for_18_decimals() {
         deposit_data.event.data.amount,
         (transfer_amount * 1_000_000_000) as u128
     );
+    assert_eq!(deposit_data.event.data.fee, fee as u128);
+    assert_eq!(deposit_data.event.data.gross_amount, amount as u128);
 
     // Check Deposit Account to unpack
     let raw_deposit_data =
@@ -679,6 +1031,94 @@ async fn test_deposit_ever_for_18_decimals() {
     );
 }
 
+#[tokio::test]
+async fn test_deposit_ever_with_truncated_amount() {
+    // Setup environment
+    let sender = Keypair::new();
+
+    let settings_account_data = bridge_test_utils::settings(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    // EVER token of 6 decimals minted on Solana with 9 decimals
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let mint = get_mint_address(&token);
+
+    let mut token_settings_data = bridge_test_utils::ever_token_settings(token, 6);
+    token_settings_data.fee_deposit_info.multiplier = 0;
+
+    let sender_token_address =
+        spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint);
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_mint(mint, mint, 9, 2_000_000_000)
+        .with_packed_account(
+            get_token_settings_ever_address(&token),
+            token_proxy::id(),
+            token_settings_data,
+        )
+        .with_funded_account(sender.pubkey(), 1_000_000_000)
+        .with_associated_token_account(sender.pubkey(), mint, 2_000_000_000);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    // Amount that can't be represented in EVER decimals is rejected
+    for (amount, accepted) in [(1_000_000_001, false), (1_000_000_000, true)] {
+        let deposit_seed = uuid::Uuid::new_v4().as_u128();
+
+        let mut transaction = Transaction::new_with_payer(
+            &[deposit_multi_token_ever_ix(
+                funder.pubkey(),
+                sender.pubkey(),
+                sender_token_address,
+                &token,
+                deposit_seed,
+                amount,
+                recipient,
+                1000,
+                UInt256::default(),
+                vec![],
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, &sender], recent_blockhash);
+
+        let result = banks_client.process_transaction(transaction).await;
+        assert_eq!(result.is_ok(), accepted);
+
+        if accepted {
+            let deposit_info = banks_client
+                .get_account(get_deposit_address(deposit_seed))
+                .await
+                .expect("get_account")
+                .expect("account");
+
+            let deposit_data =
+                DepositMultiTokenEver::unpack(deposit_info.data()).expect("deposit token unpack");
+
+            assert_eq!(deposit_data.event.data.amount, 1_000_000);
+            assert_eq!(deposit_data.event.data.fee, 0);
+        }
+    }
+
+    // Check Sender Balance
+    let sender_info = banks_client
+        .get_account(sender_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let sender_data = spl_token::state::Account::unpack(sender_info.data()).expect("token unpack");
+    assert_eq!(sender_data.amount, 1_000_000_000);
+}
+
 #[tokio::test]
 async fn test_deposit_sol() {
     let mut program_test = ProgramTest::new(
@@ -704,6 +1144,23 @@ async fn test_deposit_sol() {
         guardian,
         manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -752,6 +1209,7 @@ async fn test_deposit_sol() {
     let multivault_account_data = MultiVault {
         is_initialized: true,
         account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut multivault_packed = vec![0; MultiVault::LEN];
@@ -817,9 +1275,10 @@ async fn test_deposit_sol() {
     let expected_evers = UInt256::default();
     let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
     let symbol = "USDC".to_string();
+    let reference = [7; 32];
 
     let mut transaction = Transaction::new_with_payer(
-        &[deposit_multi_token_sol_ix(
+        &[deposit_multi_token_sol_v2_ix(
             funder.pubkey(),
             sender.pubkey(),
             sender_associated_token_address,
@@ -831,7 +1290,8 @@ async fn test_deposit_sol() {
             recipient,
             value,
             expected_evers,
-            payload.clone(),
+            Some(payload.clone()),
+            Some(reference),
         )],
         Some(&funder.pubkey()),
     );
@@ -932,6 +1392,8 @@ async fn test_deposit_sol() {
     assert_eq!(deposit_data.event.data.payload, payload);
 
     assert_eq!(deposit_data.meta.data.seed, deposit_seed);
+    assert_eq!(deposit_data.meta.data.funder, funder.pubkey());
+    assert_ne!(deposit_data.meta.data.slot, 0);
 
     let fee_info = &token_settings_data.fee_deposit_info;
     let fee = 1.max(
@@ -945,6 +1407,9 @@ async fn test_deposit_sol() {
     let transfer_amount = amount - fee;
 
     assert_eq!(deposit_data.event.data.amount, transfer_amount as u128);
+    assert_eq!(deposit_data.event.data.fee, fee as u128);
+    assert_eq!(deposit_data.event.data.gross_amount, amount as u128);
+    assert_eq!(deposit_data.event.data.reference, Some(reference));
 
     // Check Deposit Account to unpack
     let raw_deposit_data =
@@ -961,41 +1426,131 @@ async fn test_deposit_sol() {
 }
 
 #[tokio::test]
-async fn test_withdraw_ever_request() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+async fn test_deposit_sol_fee_floor() {
+    // Setup environment
+    let sender = Keypair::new();
+
+    let settings_account_data = bridge_test_utils::settings(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
     );
 
-    // Setup environment
+    // Default rate charges the one unit minimum on small amounts, zero rate charges nothing
+    let mint = Pubkey::new_unique();
+    let zero_fee_mint = Pubkey::new_unique();
 
-    // Add Round Loader Settings Account
-    let round_number = 12;
+    let mut zero_fee_token_settings_data = bridge_test_utils::sol_token_settings(zero_fee_mint);
+    zero_fee_token_settings_data.fee_deposit_info.multiplier = 0;
 
-    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_sol_token(bridge_test_utils::sol_token_settings(mint), 9, 0)
+        .with_sol_token(zero_fee_token_settings_data, 9, 0)
+        .with_funded_account(sender.pubkey(), 1_000_000_000)
+        .with_associated_token_account(sender.pubkey(), mint, 100)
+        .with_associated_token_account(sender.pubkey(), zero_fee_mint, 100);
 
-    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
 
-    let round_ttl = 1209600;
-    let rl_settings_account_data = round_loader::Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
-        current_round_number: round_number,
-        round_submitter: Pubkey::new_unique(),
-        min_required_votes: 1,
-        round_ttl: 0,
-    };
+    for (mint, expected_fee) in [(mint, 1), (zero_fee_mint, 0)] {
+        let sender_token_address =
+            spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint);
 
-    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
-    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
-    program_test.add_account(
-        rl_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
-            data: rl_settings_packed,
-            owner: round_loader::id(),
-            executable: false,
+        let deposit_seed = uuid::Uuid::new_v4().as_u128();
+
+        let mut transaction = Transaction::new_with_payer(
+            &[deposit_multi_token_sol_ix(
+                funder.pubkey(),
+                sender.pubkey(),
+                sender_token_address,
+                mint,
+                deposit_seed,
+                "USDC ETHEREUM OCTUSBRIDGE".to_string(),
+                "USDC".to_string(),
+                100,
+                EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+                1000,
+                UInt256::default(),
+                vec![],
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, &sender], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+
+        // Check Deposit Account
+        let deposit_info = banks_client
+            .get_account(get_deposit_address(deposit_seed))
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let deposit_data =
+            DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit token unpack");
+
+        assert_eq!(deposit_data.event.data.amount, (100 - expected_fee) as u128);
+
+        // Check Fee Supply
+        let token_settings_info = banks_client
+            .get_account(get_token_settings_sol_address(&mint))
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let token_settings_data =
+            TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+        assert_eq!(token_settings_data.fee_supply, expected_fee);
+    }
+}
+
+#[tokio::test]
+async fn test_withdraw_ever_request() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Round Loader Settings Account
+    let round_number = 12;
+
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let round_ttl = 1209600;
+    let rl_settings_account_data = round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    };
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
             rent_epoch: 0,
         },
     );
@@ -1023,6 +1578,9 @@ async fn test_withdraw_ever_request() {
         relays: relays.clone(),
         round_number,
         round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
     let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
@@ -1177,10 +1735,53 @@ async fn test_withdraw_ever_request() {
         proposal_data.meta,
         withdrawal_data.meta.data.try_to_vec().unwrap()
     );
+
+    // Check Proposal Index Account
+    let proposal_index_address = get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
+    let proposal_index_info = banks_client
+        .get_account(proposal_index_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_index_data =
+        ProposalIndex::unpack(proposal_index_info.data()).expect("proposal index unpack");
+
+    assert_eq!(proposal_index_data.event_data, hash(&proposal_data.event));
+    assert_eq!(proposal_index_data.proposals, 1);
+
+    // Divergent proposal for the same event is rejected
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_multi_token_ever_request_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            token,
+            "USDC Fake".to_string(),
+            symbol,
+            decimals,
+            recipient,
+            amount,
+            vec![],
+            attached_amount,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
 }
 
 #[tokio::test]
-async fn test_withdraw_ever_request_with_fake_payload() {
+async fn test_withdraw_ever_request_v2() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -1204,6 +1805,10 @@ async fn test_withdraw_ever_request_with_fake_payload() {
         round_submitter: Pubkey::new_unique(),
         min_required_votes: 1,
         round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
     };
 
     let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
@@ -1242,6 +1847,9 @@ async fn test_withdraw_ever_request_with_fake_payload() {
         relays: relays.clone(),
         round_number,
         round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
     let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
@@ -1271,57 +1879,26 @@ async fn test_withdraw_ever_request_with_fake_payload() {
         },
     );
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
-
-    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
-
-    let mint = get_mint_address(&token);
-
-    let mint_account_data = spl_token::state::Mint {
-        is_initialized: true,
-        mint_authority: program_option::COption::Some(mint),
-        supply: 0,
-        decimals,
-        ..Default::default()
-    };
-
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
-    program_test.add_account(
-        mint,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 1,
-        },
-    );
-
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
     let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
     let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
     let symbol = "USDC".to_string();
+    let decimals = spl_token::native_mint::DECIMALS;
 
     let recipient = Pubkey::new_unique();
     let amount = 32;
 
-    let payload = bincode::serialize(&vec![solana_program::system_instruction::allocate(
-        &Pubkey::new_unique(),
-        0,
-    )])
-    .unwrap();
-
-    let attached_amount = 10;
+    let attached_amount = 0;
 
     let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_multi_token_ever_request_ix(
+        &[withdrawal_multi_token_ever_request_v2_ix(
             funder.pubkey(),
             author.pubkey(),
             round_number,
@@ -1334,7 +1911,7 @@ async fn test_withdraw_ever_request_with_fake_payload() {
             decimals,
             recipient,
             amount,
-            payload.clone(),
+            None,
             attached_amount,
         )],
         Some(&funder.pubkey()),
@@ -1357,8 +1934,8 @@ async fn test_withdraw_ever_request_with_fake_payload() {
         symbol.clone(),
         decimals,
         recipient,
-        amount,
-        payload,
+        amount as u128,
+        vec![],
     );
     let withdrawal_info = banks_client
         .get_account(withdrawal_address)
@@ -1369,108 +1946,228 @@ async fn test_withdraw_ever_request_with_fake_payload() {
     let withdrawal_data =
         WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
 
-    assert_eq!(withdrawal_data.is_initialized, true);
     assert_eq!(withdrawal_data.author, author.pubkey());
-    assert_eq!(withdrawal_data.round_number, round_number);
-
-    assert_eq!(
-        withdrawal_data.required_votes,
-        (relays.len() * 2 / 3 + 1) as u32
-    );
-
-    assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
-    assert_eq!(
-        withdrawal_data.pda.event_transaction_lt,
-        event_transaction_lt
-    );
-    assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
-
     assert_eq!(withdrawal_data.event.data.token, token);
     assert_eq!(withdrawal_data.event.data.name, name);
     assert_eq!(withdrawal_data.event.data.symbol, symbol);
-    assert_eq!(withdrawal_data.event.data.decimals, decimals);
-    assert_eq!(withdrawal_data.event.data.amount, amount);
+    assert_eq!(withdrawal_data.event.data.amount, amount as u128);
     assert_eq!(withdrawal_data.event.data.recipient, recipient);
+    assert!(withdrawal_data.event.data.payload.is_empty());
+}
 
-    assert_ne!(withdrawal_data.meta.data.epoch, 0);
-    assert_eq!(withdrawal_data.meta.data.bounty, 0);
-    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
-
-    let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
-
-    let (_, withdrawal_nonce) = Pubkey::find_program_address(
-        &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data,
-        ],
-        &token_proxy::id(),
-    );
+#[tokio::test]
+async fn test_withdraw_ever_to_many() {
+    // Setup environment
+    let relays = vec![Keypair::new(), Keypair::new(), Keypair::new()];
 
-    let (_, proxy_nonce) = Pubkey::find_program_address(
-        &[br"proxy", &mint.to_bytes(), &recipient.to_bytes()],
-        &token_proxy::id(),
-    );
+    let mut test_bridge = relays.iter().fold(TestBridge::new(), |test_bridge, relay| {
+        test_bridge.with_funded_account(relay.pubkey(), 1_000_000_000)
+    });
 
-    assert_eq!(
-        withdrawal_data.account_kind,
-        AccountKind::Proposal(withdrawal_nonce, Some(proxy_nonce))
-    );
+    let round_number = 7;
+    let round_end = 1209600 + chrono::Utc::now().timestamp() as u32;
 
-    // Check Proposal Account to unpack
-    let proposal_data =
-        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let decimals = spl_token::native_mint::DECIMALS;
+    let mint = get_mint_address(&token);
 
-    assert_eq!(
-        proposal_data.event,
-        withdrawal_data.event.data.try_to_vec().unwrap()
-    );
-    assert_eq!(
-        proposal_data.meta,
-        withdrawal_data.meta.data.try_to_vec().unwrap()
-    );
+    let token_settings_account_data = bridge_test_utils::ever_token_settings(token, decimals);
 
-    // Check Proxy Account
-    let proxy_address = get_proxy_address(&mint, &recipient);
+    let recipients = vec![
+        WithdrawalRecipient {
+            recipient: Pubkey::new_unique(),
+            amount: 1000,
+        },
+        WithdrawalRecipient {
+            recipient: Pubkey::new_unique(),
+            amount: 2000,
+        },
+    ];
 
-    let proxy_info = banks_client
-        .get_account(proxy_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let expected_amounts = recipients
+        .iter()
+        .map(|recipient| {
+            let amount = recipient.amount as u64;
+            amount - token_settings_account_data.withdrawal_fee(amount).unwrap()
+        })
+        .collect::<Vec<_>>();
 
-    assert_eq!(proxy_info.data.len(), spl_token::state::Account::LEN);
-}
+    let author = Keypair::new();
 
-#[tokio::test]
-async fn test_withdraw_sol_request() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+    test_bridge = recipients.iter().fold(
+        test_bridge
+            .with_settings(bridge_test_utils::settings(
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ))
+            .with_relay_round(
+                round_number,
+                relays.iter().map(|pair| pair.pubkey()).collect(),
+                round_end,
+            )
+            .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number))
+            .with_ever_token(token_settings_account_data, 0)
+            .with_funded_account(author.pubkey(), 1_000_000_000),
+        |test_bridge, recipient| {
+            test_bridge.with_associated_token_account(recipient.recipient, mint, 0)
+        },
     );
 
-    // Setup environment
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
 
-    // Add Round Loader Settings Account
-    let round_number = 12;
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "USDC".to_string();
 
-    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_multi_token_ever_to_many_request_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            token,
+            name.clone(),
+            symbol.clone(),
+            decimals,
+            recipients.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
 
-    let round_ttl = 1209600;
-    let rl_settings_account_data = round_loader::Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
-        current_round_number: round_number,
-        round_submitter: Pubkey::new_unique(),
-        min_required_votes: 1,
-        round_ttl: 0,
-    };
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_address = get_withdrawal_ever_to_many_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        token,
+        name,
+        symbol,
+        decimals,
+        recipients.clone(),
+    );
+
+    // Vote for withdrawal request
+    for relay in &relays {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_withdrawal_request_ix(
+                relay.pubkey(),
+                withdrawal_address,
+                round_number,
+                Vote::Confirm,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, relay], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Withdraw to all recipients
+    let recipient_wallets = recipients
+        .iter()
+        .map(|recipient| recipient.recipient)
+        .collect::<Vec<_>>();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_ever_to_many_ix(
+            withdrawal_address,
+            token,
+            &recipient_wallets,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Recipients Balances
+    for (recipient, expected_amount) in recipient_wallets.iter().zip(expected_amounts) {
+        let recipient_token_info = banks_client
+            .get_account(spl_associated_token_account::get_associated_token_address(
+                recipient, &mint,
+            ))
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let recipient_token_data = spl_token::state::Account::unpack(recipient_token_info.data())
+            .expect("recipient token unpack");
+        assert_eq!(recipient_token_data.amount, expected_amount);
+    }
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data = WithdrawalMultiTokenEverToMany::unpack(withdrawal_info.data())
+        .expect("withdrawal token unpack");
+
+    assert_eq!(withdrawal_data.event.data.recipients, recipients);
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_withdrawal_request() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Round Loader Settings Account
+    let round_number = 12;
+
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let round_ttl = 1209600;
+    let rl_settings_account_data = round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    };
 
     let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
     round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
@@ -1508,6 +2205,9 @@ async fn test_withdraw_sol_request() {
         relays: relays.clone(),
         round_number,
         round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
     let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
@@ -1524,104 +2224,6 @@ async fn test_withdraw_sol_request() {
         },
     );
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
-
-    let mint_address = Pubkey::new_unique();
-
-    let mint_account_data = spl_token::state::Mint {
-        is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
-        decimals,
-        ..Default::default()
-    };
-
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
-    program_test.add_account(
-        mint_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 1,
-        },
-    );
-
-    // Add Vault Account
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
-
-    let vault_address = get_vault_address(&mint_address);
-
-    let vault_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: vault_address,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
-    program_test.add_account(
-        vault_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: vault_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = 10_000_000;
-    let withdrawal_limit = 10_000;
-    let withdrawal_daily_limit = 1_000;
-
-    let (_, token_settings_nonce) = Pubkey::find_program_address(
-        &[br"settings", &mint_address.to_bytes()],
-        &token_proxy::id(),
-    );
-
-    let token_settings_address = get_token_settings_sol_address(&mint_address);
-
-    let token_settings_account_data = TokenSettings {
-        is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-        kind: TokenKind::Solana {
-            mint: mint_address,
-            vault: vault_address,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
-    };
-
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
-    program_test.add_account(
-        token_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
     // Add Author Account
     let author = Keypair::new();
     program_test.add_account(
@@ -1638,28 +2240,36 @@ async fn test_withdraw_sol_request() {
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
     let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
+    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "USDC".to_string();
+    let decimals = spl_token::native_mint::DECIMALS;
+
     let recipient = Pubkey::new_unique();
     let amount = 32;
 
-    let payload: Vec<u8> = vec![];
     let attached_amount = 0;
 
     let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_multi_token_sol_request_ix(
+        &[withdrawal_multi_token_ever_request_v2_ix(
             funder.pubkey(),
             author.pubkey(),
+            round_number,
             event_timestamp,
             event_transaction_lt,
             event_configuration,
-            mint_address,
-            round_number,
+            token,
+            name.clone(),
+            symbol.clone(),
+            decimals,
             recipient,
             amount,
-            payload.clone(),
+            None,
             attached_amount,
         )],
         Some(&funder.pubkey()),
@@ -1672,50 +2282,109 @@ async fn test_withdraw_sol_request() {
         .expect("process_transaction");
 
     // Check Withdrawal Account
-    let withdrawal_address = get_withdrawal_sol_address(
+    let withdrawal_address = get_withdrawal_ever_address(
         round_number,
         event_timestamp,
         event_transaction_lt,
         &event_configuration,
-        mint_address,
+        token,
+        name.clone(),
+        symbol.clone(),
+        decimals,
         recipient,
-        amount,
-        payload,
+        amount as u128,
+        vec![],
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_withdrawal_request_ix(
+            author.pubkey(),
+            withdrawal_address,
+            funder.pubkey(),
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            None,
+        )],
+        Some(&funder.pubkey()),
     );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
     let withdrawal_info = banks_client
         .get_account(withdrawal_address)
         .await
-        .expect("get_account")
-        .expect("account");
-
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+        .expect("get_account");
 
-    assert_eq!(withdrawal_data.is_initialized, true);
-    assert_eq!(withdrawal_data.author, author.pubkey());
-    assert_eq!(withdrawal_data.round_number, round_number);
+    assert!(withdrawal_info.is_none());
 
-    assert_eq!(
-        withdrawal_data.required_votes,
-        (relays.len() * 2 / 3 + 1) as u32
+    let proposal_index_address = get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
     );
+    let proposal_index_info = banks_client
+        .get_account(proposal_index_address)
+        .await
+        .expect("get_account");
 
-    assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
-    assert_eq!(
-        withdrawal_data.pda.event_transaction_lt,
-        event_transaction_lt
-    );
-    assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
+    assert!(proposal_index_info.is_none());
+}
 
-    assert_eq!(withdrawal_data.event.data.mint, mint_address);
-    assert_eq!(withdrawal_data.event.data.recipient, recipient);
-    assert_eq!(withdrawal_data.event.data.amount, amount);
+#[tokio::test]
+async fn test_expire_withdrawal() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
 
-    assert_ne!(withdrawal_data.meta.data.epoch, 0);
-    assert_eq!(withdrawal_data.meta.data.bounty, 0);
-    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
+    // Setup environment
 
-    let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
+    // Add Funder Account
+    let withdrawal_funder = Pubkey::new_unique();
+    program_test.add_account(
+        withdrawal_funder,
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let mint_address = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
     let (_, withdrawal_nonce) = Pubkey::find_program_address(
         &[
@@ -1728,27 +2397,347 @@ async fn test_withdraw_sol_request() {
         ],
         &token_proxy::id(),
     );
+
+    let signers = vec![Vote::None, Vote::Confirm, Vote::None];
+
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::new(0, 0, withdrawal_funder, 1),
+        required_votes: 2,
+        signers,
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+
+    let withdrawal_rent = Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN);
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: withdrawal_rent + RELAY_REPARATION * 2,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[expire_withdrawal_ix(withdrawal_address, withdrawal_funder)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data = WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("unpack");
+
     assert_eq!(
-        withdrawal_data.account_kind,
-        AccountKind::Proposal(withdrawal_nonce, None)
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Expired
     );
+    assert_eq!(withdrawal_info.lamports, withdrawal_rent);
 
-    // Check Proposal Account to unpack
-    let proposal_data =
-        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+    // Check Funder Account
+    let funder_info = banks_client
+        .get_account(withdrawal_funder)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(funder_info.lamports, 1_000_000_000 + RELAY_REPARATION * 2);
+
+    // Close Withdrawal Account
+    let mut transaction = Transaction::new_with_payer(
+        &[close_withdrawal_ix(withdrawal_address, withdrawal_funder)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account");
+
+    assert!(withdrawal_info.is_none());
+
+    let funder_info = banks_client
+        .get_account(withdrawal_funder)
+        .await
+        .expect("get_account")
+        .expect("account");
 
     assert_eq!(
-        proposal_data.event,
-        withdrawal_data.event.data.try_to_vec().unwrap()
+        funder_info.lamports,
+        1_000_000_000 + RELAY_REPARATION * 2 + withdrawal_rent
+    );
+}
+
+#[tokio::test]
+async fn test_sweep_closed_accounts() {
+    // Setup environment
+    let withdrawal_funder = Pubkey::new_unique();
+
+    let round_number = 7;
+    let event_configuration = Pubkey::new_unique();
+    let mint_address = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
+
+    let withdrawal_account = |event_timestamp: u32, vote_rewards: Vec<VoteReward>| {
+        let event_transaction_lt = 1650988334;
+        let payload: Vec<u8> = vec![];
+
+        let withdrawal_address = get_withdrawal_sol_address(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            mint_address,
+            recipient,
+            amount,
+            payload.clone(),
+        );
+
+        let event =
+            WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+        let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+        let (_, withdrawal_nonce) = Pubkey::find_program_address(
+            &[
+                br"proposal",
+                &round_number.to_le_bytes(),
+                &event_timestamp.to_le_bytes(),
+                &event_transaction_lt.to_le_bytes(),
+                &event_configuration.to_bytes(),
+                &event_data,
+            ],
+            &token_proxy::id(),
+        );
+
+        let mut meta = WithdrawalTokenMetaWithLen::new(0, 0, withdrawal_funder, 0);
+        meta.data.status = WithdrawalTokenStatus::Processed;
+
+        let withdrawal_account_data = WithdrawalMultiTokenSol {
+            is_initialized: true,
+            account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+            author: Pubkey::new_unique(),
+            round_number,
+            event,
+            meta,
+            required_votes: 1,
+            signers: vec![Vote::Confirm],
+            chain_id: None,
+            vote_rewards,
+            pda: PDA {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+            },
+            version: CURRENT_ACCOUNT_VERSION,
+            confirmed_weight: 0,
+        };
+
+        // Proposal Index recorded by the withdrawal request
+        let proposal_index_seeds = get_proposal_index_seeds(
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            None,
+        );
+        let (proposal_index_address, proposal_index_nonce) = Pubkey::find_program_address(
+            &proposal_index_seeds
+                .iter()
+                .map(Vec::as_slice)
+                .collect::<Vec<_>>(),
+            &token_proxy::id(),
+        );
+
+        let proposal_index_account_data = ProposalIndex {
+            is_initialized: true,
+            account_kind: AccountKind::ProposalIndex(proposal_index_nonce),
+            event_data: solana_program::hash::Hash::new_from_array(event_data),
+            version: CURRENT_ACCOUNT_VERSION,
+            proposals: 1,
+        };
+
+        (
+            withdrawal_address,
+            withdrawal_account_data,
+            proposal_index_address,
+            proposal_index_account_data,
+        )
+    };
+
+    let (
+        first_withdrawal_address,
+        first_withdrawal_data,
+        first_proposal_index_address,
+        first_proposal_index_data,
+    ) = withdrawal_account(1650988297, vec![VoteReward::Claimed]);
+    let (
+        second_withdrawal_address,
+        second_withdrawal_data,
+        second_proposal_index_address,
+        second_proposal_index_data,
+    ) = withdrawal_account(1650988298, vec![]);
+    let (
+        unclaimed_withdrawal_address,
+        unclaimed_withdrawal_data,
+        unclaimed_proposal_index_address,
+        _,
+    ) = withdrawal_account(1650988299, vec![VoteReward::Unclaimed]);
+
+    let withdrawal_rent = Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN);
+    let proposal_index_rent = Rent::default().minimum_balance(ProposalIndex::LEN);
+
+    let test_bridge = TestBridge::new()
+        .with_funded_account(withdrawal_funder, 1_000_000_000)
+        .with_packed_account(
+            first_withdrawal_address,
+            token_proxy::id(),
+            first_withdrawal_data,
+        )
+        .with_packed_account(
+            second_withdrawal_address,
+            token_proxy::id(),
+            second_withdrawal_data,
+        )
+        .with_packed_account(
+            first_proposal_index_address,
+            token_proxy::id(),
+            first_proposal_index_data,
+        )
+        .with_packed_account(
+            second_proposal_index_address,
+            token_proxy::id(),
+            second_proposal_index_data,
+        )
+        .with_packed_account(
+            unclaimed_withdrawal_address,
+            token_proxy::id(),
+            unclaimed_withdrawal_data,
+        );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    // Withdrawal with unclaimed vote rewards can't be swept
+    let mut transaction = Transaction::new_with_payer(
+        &[sweep_closed_accounts_ix(vec![
+            (
+                first_withdrawal_address,
+                withdrawal_funder,
+                Some(first_proposal_index_address),
+            ),
+            (
+                unclaimed_withdrawal_address,
+                withdrawal_funder,
+                Some(unclaimed_proposal_index_address),
+            ),
+        ])],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let result = banks_client.process_transaction(transaction).await;
+    assert!(result.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[sweep_closed_accounts_ix(vec![
+            (
+                first_withdrawal_address,
+                withdrawal_funder,
+                Some(first_proposal_index_address),
+            ),
+            (
+                second_withdrawal_address,
+                withdrawal_funder,
+                Some(second_proposal_index_address),
+            ),
+        ])],
+        Some(&funder.pubkey()),
     );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Withdrawal Accounts
+    for withdrawal_address in [first_withdrawal_address, second_withdrawal_address] {
+        let withdrawal_info = banks_client
+            .get_account(withdrawal_address)
+            .await
+            .expect("get_account");
+
+        assert!(withdrawal_info.is_none());
+    }
+
+    let unclaimed_withdrawal_info = banks_client
+        .get_account(unclaimed_withdrawal_address)
+        .await
+        .expect("get_account");
+
+    assert!(unclaimed_withdrawal_info.is_some());
+
+    // Check Proposal Index Accounts are released
+    for proposal_index_address in [first_proposal_index_address, second_proposal_index_address] {
+        let proposal_index_info = banks_client
+            .get_account(proposal_index_address)
+            .await
+            .expect("get_account");
+
+        assert!(proposal_index_info.is_none());
+    }
+
+    // Check Funder Account
+    let funder_info = banks_client
+        .get_account(withdrawal_funder)
+        .await
+        .expect("get_account")
+        .expect("account");
+
     assert_eq!(
-        proposal_data.meta,
-        withdrawal_data.meta.data.try_to_vec().unwrap()
+        funder_info.lamports,
+        1_000_000_000 + withdrawal_rent * 2 + proposal_index_rent * 2
     );
 }
 
 #[tokio::test]
-async fn test_withdraw_sol_request_with_fake_payload() {
+async fn test_withdraw_ever_request_with_fake_payload() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -1772,6 +2761,10 @@ async fn test_withdraw_sol_request_with_fake_payload() {
         round_submitter: Pubkey::new_unique(),
         min_required_votes: 1,
         round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
     };
 
     let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
@@ -1810,6 +2803,9 @@ async fn test_withdraw_sol_request_with_fake_payload() {
         relays: relays.clone(),
         round_number,
         round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
     let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
@@ -1826,14 +2822,30 @@ async fn test_withdraw_sol_request_with_fake_payload() {
         },
     );
 
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     // Add Mint Account
     let decimals = spl_token::native_mint::DECIMALS;
 
-    let mint_address = Pubkey::new_unique();
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    let mint = get_mint_address(&token);
 
     let mint_account_data = spl_token::state::Mint {
         is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
+        mint_authority: program_option::COption::Some(mint),
+        supply: 0,
         decimals,
         ..Default::default()
     };
@@ -1841,7 +2853,7 @@ async fn test_withdraw_sol_request_with_fake_payload() {
     let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
     spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
     program_test.add_account(
-        mint_address,
+        mint,
         Account {
             lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
             data: mint_packed,
@@ -1851,92 +2863,6 @@ async fn test_withdraw_sol_request_with_fake_payload() {
         },
     );
 
-    // Add Vault Account
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
-
-    let vault_address = get_vault_address(&mint_address);
-
-    let vault_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: vault_address,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
-    program_test.add_account(
-        vault_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: vault_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = 10_000_000;
-    let withdrawal_limit = 10_000;
-    let withdrawal_daily_limit = 1_000;
-
-    let (_, token_settings_nonce) = Pubkey::find_program_address(
-        &[br"settings", &mint_address.to_bytes()],
-        &token_proxy::id(),
-    );
-
-    let token_settings_address = get_token_settings_sol_address(&mint_address);
-
-    let token_settings_account_data = TokenSettings {
-        is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-        kind: TokenKind::Solana {
-            mint: mint_address,
-            vault: vault_address,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
-    };
-
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
-    program_test.add_account(
-        token_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Author Account
-    let author = Keypair::new();
-    program_test.add_account(
-        author.pubkey(),
-        Account {
-            lamports: 1_000_000_000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
@@ -1944,6 +2870,9 @@ async fn test_withdraw_sol_request_with_fake_payload() {
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
+    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "USDC".to_string();
+
     let recipient = Pubkey::new_unique();
     let amount = 32;
 
@@ -1953,17 +2882,20 @@ async fn test_withdraw_sol_request_with_fake_payload() {
     )])
     .unwrap();
 
-    let attached_amount = 5;
+    let attached_amount = 10;
 
     let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_multi_token_sol_request_ix(
+        &[withdrawal_multi_token_ever_request_ix(
             funder.pubkey(),
             author.pubkey(),
+            round_number,
             event_timestamp,
             event_transaction_lt,
             event_configuration,
-            mint_address,
-            round_number,
+            token,
+            name.clone(),
+            symbol.clone(),
+            decimals,
             recipient,
             amount,
             payload.clone(),
@@ -1979,12 +2911,15 @@ async fn test_withdraw_sol_request_with_fake_payload() {
         .expect("process_transaction");
 
     // Check Withdrawal Account
-    let withdrawal_address = get_withdrawal_sol_address(
+    let withdrawal_address = get_withdrawal_ever_address(
         round_number,
         event_timestamp,
         event_transaction_lt,
         &event_configuration,
-        mint_address,
+        token,
+        name.clone(),
+        symbol.clone(),
+        decimals,
         recipient,
         amount,
         payload,
@@ -1996,7 +2931,7 @@ async fn test_withdraw_sol_request_with_fake_payload() {
         .expect("account");
 
     let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
 
     assert_eq!(withdrawal_data.is_initialized, true);
     assert_eq!(withdrawal_data.author, author.pubkey());
@@ -2014,9 +2949,12 @@ async fn test_withdraw_sol_request_with_fake_payload() {
     );
     assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
 
-    assert_eq!(withdrawal_data.event.data.mint, mint_address);
-    assert_eq!(withdrawal_data.event.data.recipient, recipient);
+    assert_eq!(withdrawal_data.event.data.token, token);
+    assert_eq!(withdrawal_data.event.data.name, name);
+    assert_eq!(withdrawal_data.event.data.symbol, symbol);
+    assert_eq!(withdrawal_data.event.data.decimals, decimals);
     assert_eq!(withdrawal_data.event.data.amount, amount);
+    assert_eq!(withdrawal_data.event.data.recipient, recipient);
 
     assert_ne!(withdrawal_data.meta.data.epoch, 0);
     assert_eq!(withdrawal_data.meta.data.bounty, 0);
@@ -2037,7 +2975,7 @@ async fn test_withdraw_sol_request_with_fake_payload() {
     );
 
     let (_, proxy_nonce) = Pubkey::find_program_address(
-        &[br"proxy", &mint_address.to_bytes(), &recipient.to_bytes()],
+        &[br"proxy", &mint.to_bytes(), &recipient.to_bytes()],
         &token_proxy::id(),
     );
 
@@ -2060,7 +2998,7 @@ async fn test_withdraw_sol_request_with_fake_payload() {
     );
 
     // Check Proxy Account
-    let proxy_address = get_proxy_address(&mint_address, &recipient);
+    let proxy_address = get_proxy_address(&mint, &recipient);
 
     let proxy_info = banks_client
         .get_account(proxy_address)
@@ -2072,7 +3010,7 @@ async fn test_withdraw_sol_request_with_fake_payload() {
 }
 
 #[tokio::test]
-async fn test_vote_for_withdrawal_request() {
+async fn test_withdraw_sol_request() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -2081,36 +3019,46 @@ async fn test_vote_for_withdrawal_request() {
 
     // Setup environment
 
-    // Add Relay Accounts
-    let relays = vec![
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-    ];
+    // Add Round Loader Settings Account
+    let round_number = 12;
 
-    for relay in &relays {
-        program_test.add_account(
-            relay.pubkey(),
-            Account {
-                lamports: 1_000_000_000,
-                data: vec![],
-                owner: solana_program::system_program::id(),
-                executable: false,
-                rent_epoch: 0,
-            },
-        );
-    }
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
 
-    // Add Relay Round Account
-    let round_number = 7;
     let round_ttl = 1209600;
+    let rl_settings_account_data = round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    };
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let relays = vec![
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
 
     let relay_round_address =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
@@ -2125,9 +3073,12 @@ async fn test_vote_for_withdrawal_request() {
     let relay_round_data = round_loader::RelayRound {
         is_initialized: true,
         account_kind: AccountKind::RelayRound(relay_round_nonce),
-        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays: relays.clone(),
         round_number,
         round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
     let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
@@ -2144,169 +3095,130 @@ async fn test_vote_for_withdrawal_request() {
         },
     );
 
-    // Add Author Account
-    let author = Keypair::new();
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
     program_test.add_account(
-        author.pubkey(),
+        mint_address,
         Account {
-            lamports: 1_000_000_000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
             executable: false,
-            rent_epoch: 0,
+            rent_epoch: 1,
         },
     );
 
-    // Add Withdrawal Account
-    let event_timestamp = 1650988297;
-    let event_transaction_lt = 1650988334;
-    let event_configuration = Pubkey::new_unique();
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
 
-    let mint = Pubkey::new_unique();
-    let recipient = Pubkey::new_unique();
-    let amount = 32;
+    let vault_address = get_vault_address(&mint_address);
 
-    let payload: Vec<u8> = vec![];
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
 
-    let withdrawal_address = get_withdrawal_sol_address(
-        round_number,
-        event_timestamp,
-        event_transaction_lt,
-        &event_configuration,
-        mint,
-        recipient,
-        amount,
-        payload.clone(),
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
 
-    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
-    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
-
-    let (_, withdrawal_nonce) = Pubkey::find_program_address(
-        &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data,
-        ],
-        &token_proxy::id(),
-    );
-
-    let withdrawal_account_data = WithdrawalMultiTokenSol {
-        is_initialized: true,
-        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
-        author: author.pubkey(),
-        round_number,
-        event,
-        meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: (relays.len() * 2 / 3 + 1) as u32,
-        signers: relays.iter().map(|_| Vote::None).collect(),
-        pda: PDA {
-            event_timestamp,
-            event_transaction_lt,
-            event_configuration,
-        },
-    };
-
-    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
-    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
-    program_test.add_account(
-        withdrawal_address,
-        Account {
-            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
-                + RELAY_REPARATION * relays.len() as u64,
-            data: withdrawal_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
-    // Vote for withdrawal request
-    for relay in &relays {
-        let mut transaction = Transaction::new_with_payer(
-            &[vote_for_withdrawal_request_ix(
-                relay.pubkey(),
-                withdrawal_address,
-                round_number,
-                Vote::Confirm,
-            )],
-            Some(&funder.pubkey()),
-        );
-        transaction.sign(&[&funder, &relay], recent_blockhash);
-
-        let _ = banks_client.process_transaction(transaction).await;
-    }
-
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let withdrawal_data =
-        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal unpack");
-
-    let sig_count = withdrawal_data
-        .signers
-        .iter()
-        .filter(|vote| **vote == Vote::Confirm)
-        .count();
-
-    assert_eq!(sig_count, relays.len());
-}
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = 10_000_000;
+    let withdrawal_limit = 10_000;
+    let withdrawal_daily_limit = 1_000;
 
-#[tokio::test]
-async fn test_create_token_ever() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
     );
 
-    // Setup environment
-
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
-
-    let settings_address = get_settings_address();
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
 
-    let settings_account_data = Settings {
+    let token_settings_account_data = TokenSettings {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
         emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
-        settings_address,
+        token_settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Recipient Account
-    let recipient = Keypair::new();
+    // Add Author Account
+    let author = Keypair::new();
     program_test.add_account(
-        recipient.pubkey(),
+        author.pubkey(),
         Account {
-            lamports: 100000000,
+            lamports: 1_000_000_000,
             data: vec![],
             owner: solana_program::system_program::id(),
             executable: false,
@@ -2314,51 +3226,87 @@ async fn test_create_token_ever() {
         },
     );
 
-    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
-    let mint = get_mint_address(&token);
-
-    let token_wallet =
-        spl_associated_token_account::get_associated_token_address(&recipient.pubkey(), &mint);
-
-    // Add Withdrawal Account
-    let round_number = 7;
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
     let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
-    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
-    let symbol = "USDC".to_string();
-    let decimals = spl_token::native_mint::DECIMALS;
-
+    let recipient = Pubkey::new_unique();
     let amount = 32;
 
     let payload: Vec<u8> = vec![];
+    let attached_amount = 0;
 
-    let withdrawal_address = get_withdrawal_ever_address(
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_multi_token_sol_request_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            mint_address,
+            round_number,
+            recipient,
+            amount,
+            payload.clone(),
+            attached_amount,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Withdrawal Account
+    let withdrawal_address = get_withdrawal_sol_address(
         round_number,
         event_timestamp,
         event_transaction_lt,
         &event_configuration,
-        token,
-        name.clone(),
-        symbol.clone(),
-        decimals,
-        recipient.pubkey(),
+        mint_address,
+        recipient,
         amount,
-        payload.clone(),
+        payload,
     );
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    let event = WithdrawalMultiTokenEverEventWithLen::new(
-        token,
-        name,
-        symbol,
-        decimals,
-        amount,
-        recipient.pubkey(),
-        payload,
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(withdrawal_data.is_initialized, true);
+    assert_eq!(withdrawal_data.author, author.pubkey());
+    assert_eq!(withdrawal_data.round_number, round_number);
+
+    assert_eq!(
+        withdrawal_data.required_votes,
+        (relays.len() * 2 / 3 + 1) as u32
     );
-    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
+    assert_eq!(
+        withdrawal_data.pda.event_transaction_lt,
+        event_transaction_lt
+    );
+    assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
+
+    assert_eq!(withdrawal_data.event.data.mint, mint_address);
+    assert_eq!(withdrawal_data.event.data.recipient, recipient);
+    assert_eq!(withdrawal_data.event.data.amount, amount);
+
+    assert_ne!(withdrawal_data.meta.data.epoch, 0);
+    assert_eq!(withdrawal_data.meta.data.bounty, 0);
+    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
+
+    let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
 
     let (_, withdrawal_nonce) = Pubkey::find_program_address(
         &[
@@ -2371,150 +3319,58 @@ async fn test_create_token_ever() {
         ],
         &token_proxy::id(),
     );
+    assert_eq!(
+        withdrawal_data.account_kind,
+        AccountKind::Proposal(withdrawal_nonce, None)
+    );
 
-    let signers = vec![Vote::Confirm; 3];
-
-    let withdrawal_account_data = WithdrawalMultiTokenEver {
-        is_initialized: true,
-        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
-        author: Pubkey::new_unique(),
-        round_number,
-        event,
-        meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: signers.len() as u32,
-        signers: signers.clone(),
-        pda: PDA {
-            event_timestamp,
-            event_transaction_lt,
-            event_configuration,
-        },
-    };
-
-    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenEver::LEN];
-    WithdrawalMultiTokenEver::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
-    program_test.add_account(
-        withdrawal_address,
-        Account {
-            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenEver::LEN)
-                + Rent::default().minimum_balance(TokenSettings::LEN)
-                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: withdrawal_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
-    let mut transaction = Transaction::new_with_payer(
-        &[create_ever_token_ix(
-            funder.pubkey(),
-            withdrawal_address,
-            recipient.pubkey(),
-            token,
-        )],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
-
-    // Check Token Settings Account
-    let token_settings_address = get_token_settings_ever_address(&token);
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("deposit token unpack");
-
-    assert_eq!(token_settings_data.is_initialized, true);
-    assert_eq!(token_settings_data.deposit_limit, u64::MAX);
-    assert_eq!(token_settings_data.withdrawal_limit, u64::MAX);
-    assert_eq!(token_settings_data.withdrawal_daily_limit, u64::MAX);
-    assert_eq!(token_settings_data.emergency, false);
+    // Check Proposal Account to unpack
+    let proposal_data =
+        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
 
     assert_eq!(
-        token_settings_data.kind,
-        TokenKind::Ever {
-            mint,
-            token,
-            decimals,
-        }
+        proposal_data.event,
+        withdrawal_data.event.data.try_to_vec().unwrap()
     );
-
-    let token_hash = hash(&token.try_to_vec().unwrap());
-
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
-    let (_, mint_nonce) =
-        Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], &token_proxy::id());
-
     assert_eq!(
-        token_settings_data.account_kind,
-        AccountKind::TokenSettings(token_settings_nonce, mint_nonce)
+        proposal_data.meta,
+        withdrawal_data.meta.data.try_to_vec().unwrap()
     );
 
-    let fee_info = &token_settings_data.fee_withdrawal_info;
+    // Proposal index is required to request
+    let event_timestamp = event_timestamp + 1;
 
-    let fee = 1.max(
-        (amount as u64)
-            .checked_div(fee_info.divisor)
-            .unwrap()
-            .checked_mul(fee_info.multiplier)
-            .unwrap(),
+    let proposal_index_address = get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
     );
 
-    let transfer_amount = amount as u64 - fee;
-
-    assert_eq!(token_settings_data.withdrawal_daily_amount, transfer_amount);
-
-    // Check Mint Supply
-    let mint_info = banks_client
-        .get_account(mint)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
-    assert_eq!(mint_data.supply, transfer_amount);
-
-    // Check Recipient Account
-    let recipient_token_info = banks_client
-        .get_account(token_wallet)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let recipient_token_data = spl_token::state::Account::unpack(recipient_token_info.data())
-        .expect("recipient token unpack");
-    assert_eq!(recipient_token_data.amount, transfer_amount);
-
-    // Check Withdrawal Account
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let mut ix = withdrawal_multi_token_sol_request_ix(
+        funder.pubkey(),
+        author.pubkey(),
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        mint_address,
+        round_number,
+        recipient,
+        amount,
+        vec![],
+        attached_amount,
+    );
+    ix.accounts
+        .retain(|account| account.pubkey != proposal_index_address);
 
-    let withdrawal_data =
-        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &author], recent_blockhash);
 
-    assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Processed
-    );
+    assert!(banks_client.process_transaction(transaction).await.is_err());
 }
 
 #[tokio::test]
-async fn test_withdrawal_sol() {
+async fn test_withdraw_sol_request_with_fake_payload() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -2523,31 +3379,77 @@ async fn test_withdrawal_sol() {
 
     // Setup environment
 
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    // Add Round Loader Settings Account
+    let round_number = 12;
 
-    let settings_address = get_settings_address();
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
 
-    let settings_account_data = Settings {
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let round_ttl = 1209600;
+    let rl_settings_account_data = round_loader::Settings {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
-        emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
     };
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
     program_test.add_account(
-        settings_address,
+        rl_settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let relays = vec![
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: relays.clone(),
+        round_number,
+        round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
             executable: false,
             rent_epoch: 0,
         },
@@ -2587,7 +3489,6 @@ async fn test_withdrawal_sol() {
     let vault_account_data = spl_token::state::Account {
         mint: mint_address,
         owner: vault_address,
-        amount: 100,
         state: AccountState::Initialized,
         ..Default::default()
     };
@@ -2605,38 +3506,12 @@ async fn test_withdrawal_sol() {
         },
     );
 
-    // Add Recipient Token Account
-    let recipient = Pubkey::new_unique();
-
-    let token_wallet =
-        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
-
-    let token_wallet_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: recipient,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
-    program_test.add_account(
-        token_wallet,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: token_wallet_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
     // Add Token Settings Account
     let symbol = "USDT".to_string();
     let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
+    let deposit_limit = 10_000_000;
+    let withdrawal_limit = 10_000;
+    let withdrawal_daily_limit = 1_000;
 
     let (_, token_settings_nonce) = Pubkey::find_program_address(
         &[br"settings", &mint_address.to_bytes()],
@@ -2663,10 +3538,28 @@ async fn test_withdrawal_sol() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
-    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
-
     let mut token_settings_packed = vec![0; TokenSettings::LEN];
     TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
@@ -2680,17 +3573,61 @@ async fn test_withdrawal_sol() {
         },
     );
 
-    // Add Withdrawal Account
-    let round_number = 7;
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
 
-    let event_timestamp = 1650988297;
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
+    let recipient = Pubkey::new_unique();
     let amount = 32;
 
-    let payload: Vec<u8> = vec![];
+    let payload = bincode::serialize(&vec![solana_program::system_instruction::allocate(
+        &Pubkey::new_unique(),
+        0,
+    )])
+    .unwrap();
+
+    let attached_amount = 5;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_multi_token_sol_request_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            mint_address,
+            round_number,
+            recipient,
+            amount,
+            payload.clone(),
+            attached_amount,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
+    // Check Withdrawal Account
     let withdrawal_address = get_withdrawal_sol_address(
         round_number,
         event_timestamp,
@@ -2699,10 +3636,153 @@ async fn test_withdrawal_sol() {
         mint_address,
         recipient,
         amount,
+        payload,
+    );
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(withdrawal_data.is_initialized, true);
+    assert_eq!(withdrawal_data.author, author.pubkey());
+    assert_eq!(withdrawal_data.round_number, round_number);
+
+    assert_eq!(
+        withdrawal_data.required_votes,
+        (relays.len() * 2 / 3 + 1) as u32
+    );
+
+    assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
+    assert_eq!(
+        withdrawal_data.pda.event_transaction_lt,
+        event_transaction_lt
+    );
+    assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
+
+    assert_eq!(withdrawal_data.event.data.mint, mint_address);
+    assert_eq!(withdrawal_data.event.data.recipient, recipient);
+    assert_eq!(withdrawal_data.event.data.amount, amount);
+
+    assert_ne!(withdrawal_data.meta.data.epoch, 0);
+    assert_eq!(withdrawal_data.meta.data.bounty, 0);
+    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
+
+    let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let (_, proxy_nonce) = Pubkey::find_program_address(
+        &[br"proxy", &mint_address.to_bytes(), &recipient.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    assert_eq!(
+        withdrawal_data.account_kind,
+        AccountKind::Proposal(withdrawal_nonce, Some(proxy_nonce))
+    );
+
+    // Check Proposal Account to unpack
+    let proposal_data =
+        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        proposal_data.event,
+        withdrawal_data.event.data.try_to_vec().unwrap()
+    );
+    assert_eq!(
+        proposal_data.meta,
+        withdrawal_data.meta.data.try_to_vec().unwrap()
+    );
+
+    // Check Proxy Account
+    let proxy_address = get_proxy_address(&mint_address, &recipient);
+
+    let proxy_info = banks_client
+        .get_account(proxy_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(proxy_info.data.len(), spl_token::state::Account::LEN);
+}
+
+#[tokio::test]
+async fn test_vote_for_withdrawal_request() {
+    // Setup environment
+
+    // Add Relay Accounts
+    let relays = vec![
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+    ];
+
+    let mut test_bridge = relays.iter().fold(TestBridge::new(), |test_bridge, relay| {
+        test_bridge.with_funded_account(relay.pubkey(), 1_000_000_000)
+    });
+
+    // Add Relay Round Account
+    let round_number = 7;
+    let round_ttl = 1209600;
+
+    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
+
+    test_bridge = test_bridge
+        .with_relay_round(
+            round_number,
+            relays.iter().map(|pair| pair.pubkey()).collect(),
+            round_end,
+        )
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number));
+
+    // Add Author Account
+    let author = Keypair::new();
+    test_bridge = test_bridge.with_funded_account(author.pubkey(), 1_000_000_000);
+
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint,
+        recipient,
+        amount,
         payload.clone(),
     );
 
-    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
     let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
     let (_, withdrawal_nonce) = Pubkey::find_program_address(
@@ -2717,32 +3797,33 @@ async fn test_withdrawal_sol() {
         &token_proxy::id(),
     );
 
-    let signers = vec![Vote::Confirm; 3];
-
     let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
         account_kind: AccountKind::Proposal(withdrawal_nonce, None),
-        author: Pubkey::new_unique(),
+        author: author.pubkey(),
         round_number,
         event,
         meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: signers.len() as u32,
-        signers: signers.clone(),
+        required_votes: (relays.len() * 2 / 3 + 1) as u32,
+        signers: relays.iter().map(|_| Vote::None).collect(),
+        chain_id: None,
+        vote_rewards: vec![],
         pda: PDA {
             event_timestamp,
             event_transaction_lt,
             event_configuration,
         },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
     let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
     WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
-    program_test.add_account(
+    test_bridge = test_bridge.with_account(
         withdrawal_address,
         Account {
             lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
-                + Rent::default().minimum_balance(TokenSettings::LEN)
-                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+                + RELAY_REPARATION * relays.len() as u64,
             data: withdrawal_packed,
             owner: token_proxy::id(),
             executable: false,
@@ -2751,242 +3832,242 @@ async fn test_withdrawal_sol() {
     );
 
     // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_sol_ix(
-            withdrawal_address,
-            token_wallet,
-            mint_address,
-        )],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder], recent_blockhash);
+    // Vote for withdrawal request
+    for relay in &relays {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_withdrawal_request_ix(
+                relay.pubkey(),
+                withdrawal_address,
+                round_number,
+                Vote::Confirm,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, &relay], recent_blockhash);
 
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
+        let _ = banks_client.process_transaction(transaction).await;
+    }
 
-    // Check Vault Balance
-    let vault_info = banks_client
-        .get_account(vault_address)
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
-
-    let fee = 1.max(
-        (amount as u64)
-            .checked_div(fee_info.divisor)
-            .unwrap()
-            .checked_mul(fee_info.multiplier)
-            .unwrap(),
-    );
+    let withdrawal_data =
+        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal unpack");
 
-    let transfer_amount = amount as u64 - fee;
+    let sig_count = withdrawal_data
+        .signers
+        .iter()
+        .filter(|vote| **vote == Vote::Confirm)
+        .count();
 
-    assert_eq!(vault_data.amount, 100 - transfer_amount);
+    assert_eq!(sig_count, relays.len());
+}
 
-    // Check Recipient Balance
-    let recipient_info = banks_client
-        .get_account(token_wallet)
-        .await
-        .expect("get_account")
-        .expect("account");
+#[tokio::test]
+async fn test_vote_for_withdrawal_request_with_relay_reward() {
+    // Setup environment
 
-    let recipient_data =
-        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
-    assert_eq!(recipient_data.amount, transfer_amount);
+    // Add Relay Accounts
+    let relays = vec![Keypair::new(), Keypair::new(), Keypair::new()];
 
-    // Check Withdrawal Account
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let mut test_bridge = relays.iter().fold(TestBridge::new(), |test_bridge, relay| {
+        test_bridge.with_funded_account(relay.pubkey(), 1_000_000_000)
+    });
 
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+    // Add Relay Round Account
+    let round_number = 7;
+    let round_ttl = 1209600;
 
-    assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Processed
-    );
-}
+    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
 
-#[tokio::test]
-async fn test_withdrawal_sol_with_empty_vault() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
-    );
+    test_bridge = test_bridge
+        .with_relay_round(
+            round_number,
+            relays.iter().map(|pair| pair.pubkey()).collect(),
+            round_end,
+        )
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number));
 
-    // Setup environment
+    // Add Author Account
+    let author = Keypair::new();
+    test_bridge = test_bridge.with_funded_account(author.pubkey(), 1_000_000_000);
 
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    let settings_address = get_settings_address();
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
 
-    let settings_account_data = Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
-        emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager,
-    };
+    let payload: Vec<u8> = vec![];
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
-    program_test.add_account(
-        settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint,
+        recipient,
+        amount,
+        payload.clone(),
     );
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
-    let mint_address = Pubkey::new_unique();
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
 
-    let mint_account_data = spl_token::state::Mint {
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
-        decimals,
-        ..Default::default()
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: 2,
+        signers: relays.iter().map(|_| Vote::None).collect(),
+        chain_id: None,
+        vote_rewards: relays.iter().map(|_| VoteReward::None).collect(),
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
-    program_test.add_account(
-        mint_address,
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    test_bridge = test_bridge.with_account(
+        withdrawal_address,
         Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION * relays.len() as u64,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
             executable: false,
-            rent_epoch: 1,
+            rent_epoch: 0,
         },
     );
 
-    // Add Vault Account
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+    // Lamports sent to the relay reward address don't prevent its creation
+    test_bridge = test_bridge.with_funded_account(
+        get_relay_reward_address(&relays[0].pubkey(), round_number),
+        1,
+    );
 
-    let vault_address = get_vault_address(&mint_address);
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
 
-    let vault_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: vault_address,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
+    // Vote for withdrawal request with relay reward ledger
+    for relay in &relays {
+        let mut ix = vote_for_withdrawal_request_ix(
+            relay.pubkey(),
+            withdrawal_address,
+            round_number,
+            Vote::Confirm,
+        );
+        append_relay_reward_accounts(&mut ix, &relay.pubkey(), round_number);
 
-    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
-    program_test.add_account(
-        vault_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: vault_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+        let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+        transaction.sign(&[&funder, relay], recent_blockhash);
 
-    // Add Recipient Token Account
-    let recipient = Pubkey::new_unique();
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
 
-    let token_wallet =
-        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+    // Check Relay Reward Accounts
+    for (index, relay) in relays.iter().enumerate() {
+        let relay_reward_address = get_relay_reward_address(&relay.pubkey(), round_number);
 
-    let token_wallet_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: recipient,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
+        let relay_reward_info = banks_client
+            .get_account(relay_reward_address)
+            .await
+            .expect("get_account")
+            .expect("account");
 
-    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
-    program_test.add_account(
-        token_wallet,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: token_wallet_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+        let relay_reward_data =
+            RelayReward::unpack(relay_reward_info.data()).expect("relay reward unpack");
 
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
+        assert!(relay_reward_data.is_initialized);
+        assert_eq!(relay_reward_data.relay, relay.pubkey());
+        assert_eq!(relay_reward_data.round_number, round_number);
+        assert_eq!(relay_reward_data.votes, 1);
+        assert_eq!(relay_reward_data.reparation, RELAY_REPARATION);
 
-    let (_, token_settings_nonce) = Pubkey::find_program_address(
-        &[br"settings", &mint_address.to_bytes()],
-        &token_proxy::id(),
-    );
+        // Only confirmations before the quorum are credited with reward
+        let rewarded_votes = if index < 2 { 1 } else { 0 };
+        assert_eq!(relay_reward_data.rewarded_votes, rewarded_votes);
+    }
+}
 
-    let token_settings_address = get_token_settings_sol_address(&mint_address);
+#[tokio::test]
+async fn test_vote_for_withdrawal_request_with_weighted_relays() {
+    // Setup environment
 
-    let token_settings_account_data = TokenSettings {
-        is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-        kind: TokenKind::Solana {
-            mint: mint_address,
-            vault: vault_address,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
-    };
+    // Add Relay Accounts
+    let relays = vec![Keypair::new(), Keypair::new(), Keypair::new()];
+    let weights = vec![4, 1, 1];
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
-    program_test.add_account(
-        token_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+    let mut test_bridge = relays.iter().fold(TestBridge::new(), |test_bridge, relay| {
+        test_bridge.with_funded_account(relay.pubkey(), 1_000_000_000)
+    });
 
-    // Add Withdrawal Account
+    // Add Relay Round Account
     let round_number = 7;
+    let round_ttl = 1209600;
+
+    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
+
+    // Add Round Loader Settings Account
+    let rl_settings_account_data = bridge_test_utils::round_loader_settings(round_number);
+
+    // Two thirds of the total weight
+    let total_weight: u32 = weights.iter().sum();
+    let required_votes =
+        round_loader::get_required_votes(total_weight as usize, &rl_settings_account_data);
+    assert_eq!(required_votes, 5);
+
+    test_bridge = test_bridge
+        .with_weighted_relay_round(
+            round_number,
+            relays.iter().map(|pair| pair.pubkey()).collect(),
+            weights,
+            round_end,
+        )
+        .with_round_loader_settings(rl_settings_account_data);
+
+    // Add Author Account
+    let author = Keypair::new();
+    test_bridge = test_bridge.with_funded_account(author.pubkey(), 1_000_000_000);
 
+    // Add Withdrawal Account
     let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
     let amount = 32;
 
     let payload: Vec<u8> = vec![];
@@ -2996,13 +4077,13 @@ async fn test_withdrawal_sol_with_empty_vault() {
         event_timestamp,
         event_transaction_lt,
         &event_configuration,
-        mint_address,
+        mint,
         recipient,
         amount,
         payload.clone(),
     );
 
-    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
     let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
     let (_, withdrawal_nonce) = Pubkey::find_program_address(
@@ -3017,32 +4098,33 @@ async fn test_withdrawal_sol_with_empty_vault() {
         &token_proxy::id(),
     );
 
-    let signers = vec![Vote::Confirm; 3];
-
     let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
         account_kind: AccountKind::Proposal(withdrawal_nonce, None),
-        author: Pubkey::new_unique(),
+        author: author.pubkey(),
         round_number,
         event,
         meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: signers.len() as u32,
-        signers: signers.clone(),
+        required_votes,
+        signers: relays.iter().map(|_| Vote::None).collect(),
+        chain_id: None,
+        vote_rewards: vec![],
         pda: PDA {
             event_timestamp,
             event_transaction_lt,
             event_configuration,
         },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
     let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
     WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
-    program_test.add_account(
+    test_bridge = test_bridge.with_account(
         withdrawal_address,
         Account {
             lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
-                + Rent::default().minimum_balance(TokenSettings::LEN)
-                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+                + RELAY_REPARATION * relays.len() as u64,
             data: withdrawal_packed,
             owner: token_proxy::id(),
             executable: false,
@@ -3051,45 +4133,27 @@ async fn test_withdrawal_sol_with_empty_vault() {
     );
 
     // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_sol_ix(
-            withdrawal_address,
-            token_wallet,
-            mint_address,
-        )],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
-
-    // Check Vault Balance
-    let vault_info = banks_client
-        .get_account(vault_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
-    assert_eq!(vault_data.amount, 0);
-
-    // Check Recipient Balance
-    let recipient_info = banks_client
-        .get_account(token_wallet)
-        .await
-        .expect("get_account")
-        .expect("account");
+    // Heaviest and one light Relay reach the quorum
+    for relay in &relays[..2] {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_withdrawal_request_ix(
+                relay.pubkey(),
+                withdrawal_address,
+                round_number,
+                Vote::Confirm,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, relay], recent_blockhash);
 
-    let recipient_data =
-        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
-    assert_eq!(recipient_data.amount, 0);
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
 
-    // Check Withdrawal Account
     let withdrawal_info = banks_client
         .get_account(withdrawal_address)
         .await
@@ -3097,16 +4161,20 @@ async fn test_withdrawal_sol_with_empty_vault() {
         .expect("account");
 
     let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal unpack");
 
+    assert_eq!(withdrawal_data.confirmed_weight, 5);
     assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Pending
+        bridge_utils::helper::get_recorded_weight(
+            &withdrawal_data.signers,
+            withdrawal_data.confirmed_weight
+        ),
+        withdrawal_data.required_votes
     );
 }
 
 #[tokio::test]
-async fn test_change_guardian() {
+async fn test_vote_for_withdrawal_request_after_round_handover() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -3114,237 +4182,376 @@ async fn test_change_guardian() {
     );
 
     // Setup environment
-    let owner = Keypair::new();
 
-    // Add Program Data Account
-    let (programdata_address, programdata_nonce) =
-        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+    // Add Relay Accounts
+    let relays = vec![Keypair::new(), Keypair::new(), Keypair::new()];
 
-    let programdata_data = UpgradeableLoaderState::ProgramData {
-        slot: 0,
-        upgrade_authority_address: Some(owner.pubkey()),
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Relay Round Account
+    let round_number = 7;
+
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    // Round ended before the next one was published
+    let round_end = chrono::Utc::now().timestamp() as u32 - 3600;
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        round_number,
+        round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
-    let programdata_data_serialized =
-        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
 
     program_test.add_account(
-        programdata_address,
+        relay_round_address,
         Account {
-            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
-            data: programdata_data_serialized,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    // Add Round Loader Settings Account
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
 
-    let settings_address = get_settings_address();
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
 
-    let settings_account_data = Settings {
+    let rl_settings_account_data = round_loader::Settings {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
-        emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number + 1,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
     };
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
     program_test.add_account(
-        settings_address,
+        rl_settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
-    let new_guardian = Pubkey::new_unique();
-
-    let mut transaction = Transaction::new_with_payer(
-        &[change_guardian_ix(owner.pubkey(), new_guardian)],
-        Some(&funder.pubkey()),
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
-    transaction.sign(&[&funder, &owner], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
 
-    let settings_info = banks_client
-        .get_account(settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
 
-    assert_eq!(settings_data.guardian, new_guardian);
-}
+    let payload: Vec<u8> = vec![];
 
-#[tokio::test]
-async fn test_change_manager() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint,
+        recipient,
+        amount,
+        payload.clone(),
     );
 
-    // Setup environment
-    let owner = Keypair::new();
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
-    // Add Program Data Account
-    let (programdata_address, programdata_nonce) =
-        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
 
-    let programdata_data = UpgradeableLoaderState::ProgramData {
-        slot: 0,
-        upgrade_authority_address: Some(owner.pubkey()),
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: (relays.len() * 2 / 3 + 1) as u32,
+        signers: relays.iter().map(|_| Vote::None).collect(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
-    let programdata_data_serialized =
-        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
-
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
     program_test.add_account(
-        programdata_address,
+        withdrawal_address,
         Account {
-            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
-            data: programdata_data_serialized,
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION * relays.len() as u64,
+            data: withdrawal_packed,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let settings_address = get_settings_address();
+    // Vote for withdrawal request with the previous round
+    let mut transaction = Transaction::new_with_payer(
+        &[vote_for_withdrawal_request_ix(
+            relays[0].pubkey(),
+            withdrawal_address,
+            round_number,
+            Vote::Confirm,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &relays[0]], recent_blockhash);
 
-    let settings_account_data = Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
-        emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager,
-    };
+    let result = banks_client.process_transaction(transaction).await;
+    assert!(result.is_err());
+}
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
-    program_test.add_account(
-        settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+#[tokio::test]
+async fn test_vote_for_withdrawal_request_by_signatures() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    // Setup environment
 
-    let new_manager = Pubkey::new_unique();
+    // Add Relay Accounts
+    let relays = vec![
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+    ];
 
-    let mut transaction = Transaction::new_with_payer(
-        &[change_manager_ix(owner.pubkey(), new_manager)],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder, &owner], recent_blockhash);
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
 
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
+    // Add Relay Round Account
+    let round_number = 7;
+    let round_ttl = 1209600;
 
-    let settings_info = banks_client
-        .get_account(settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
-    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
 
-    assert_eq!(settings_data.manager, new_manager);
-}
+    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
 
-#[tokio::test]
-async fn test_change_withdrawal_manager() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        round_number,
+        round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
 
-    // Setup environment
-    let owner = Keypair::new();
+    // Add Round Loader Settings Account
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
 
-    // Add Program Data Account
-    let (programdata_address, programdata_nonce) =
-        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
 
-    let programdata_data = UpgradeableLoaderState::ProgramData {
-        slot: 0,
-        upgrade_authority_address: Some(owner.pubkey()),
+    let rl_settings_account_data = round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
     };
 
-    let programdata_data_serialized =
-        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
 
+    // Add Author Account
+    let author = Keypair::new();
     program_test.add_account(
-        programdata_address,
+        author.pubkey(),
         Account {
-            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
-            data: programdata_data_serialized,
-            owner: token_proxy::id(),
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    let settings_address = get_settings_address();
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
 
-    let settings_account_data = Settings {
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
-        emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: (relays.len() * 2 / 3 + 1) as u32,
+        signers: relays.iter().map(|_| Vote::None).collect(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
     program_test.add_account(
-        settings_address,
+        withdrawal_address,
         Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION * relays.len() as u64,
+            data: withdrawal_packed,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
@@ -3354,35 +4561,66 @@ async fn test_change_withdrawal_manager() {
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let new_withdrawal_manager = Pubkey::new_unique();
+    // Relays sign withdrawal confirmation off-chain
+    let message = bridge_utils::helper::get_vote_message(
+        &withdrawal_address,
+        &solana_program::hash::Hash::new_from_array(event_data),
+    );
 
+    let signatures = relays
+        .iter()
+        .map(|relay| {
+            let signature = relay.sign_message(message.as_ref());
+            (
+                relay.pubkey(),
+                <[u8; 64]>::try_from(signature.as_ref()).expect("signature"),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // Submit all signatures in one transaction
     let mut transaction = Transaction::new_with_payer(
-        &[change_withdrawal_manager_ix(
-            owner.pubkey(),
-            new_withdrawal_manager,
-        )],
+        &[
+            bridge_utils::helper::ed25519_signatures_ix(message.as_ref(), &signatures),
+            vote_for_withdrawal_request_by_signatures_ix(
+                funder.pubkey(),
+                withdrawal_address,
+                round_number,
+            ),
+        ],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &owner], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let settings_info = banks_client
-        .get_account(settings_address)
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+    let withdrawal_data =
+        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal unpack");
 
-    assert_eq!(settings_data.withdrawal_manager, new_withdrawal_manager);
+    let sig_count = withdrawal_data
+        .signers
+        .iter()
+        .filter(|vote| **vote == Vote::Confirm)
+        .count();
+
+    assert_eq!(sig_count, relays.len());
+    assert_eq!(
+        withdrawal_info.lamports,
+        Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+    );
 }
 
 #[tokio::test]
-async fn test_change_deposit_limit() {
+async fn test_create_token_ever() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -3392,11 +4630,9 @@ async fn test_change_deposit_limit() {
     // Setup environment
 
     // Add Settings Account
-    let manager = Keypair::new();
-
     let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
     let withdrawal_manager = Pubkey::new_unique();
-
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
     let settings_address = get_settings_address();
@@ -3405,9 +4641,26 @@ async fn test_change_deposit_limit() {
         is_initialized: true,
         account_kind: AccountKind::Settings(settings_nonce, 0),
         emergency: false,
-        manager: manager.pubkey(),
         guardian,
+        manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -3423,78 +4676,165 @@ async fn test_change_deposit_limit() {
         },
     );
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
-
-    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
-    let token_hash = hash(&token.try_to_vec().unwrap());
+    // Add Recipient Account
+    let recipient = Keypair::new();
+    program_test.add_account(
+        recipient.pubkey(),
+        Account {
+            lamports: 100000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
 
-    let (_, mint_nonce) =
-        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let mint = get_mint_address(&token);
 
-    let mint_address = get_mint_address(&token);
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient.pubkey(), &mint);
 
-    let mint_account_data = spl_token::state::Mint {
-        is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
-        supply: 100,
+    // Add Withdrawal Account
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "USDC".to_string();
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_ever_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        token,
+        name.clone(),
+        symbol.clone(),
         decimals,
-        ..Default::default()
+        recipient.pubkey(),
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenEverEventWithLen::new(
+        token,
+        name,
+        symbol,
+        decimals,
+        amount,
+        recipient.pubkey(),
+        payload,
+    );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let signers = vec![Vote::Confirm; 3];
+
+    let withdrawal_account_data = WithdrawalMultiTokenEver {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenEver::LEN];
+    WithdrawalMultiTokenEver::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
     program_test.add_account(
-        mint_address,
+        withdrawal_address,
         Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenEver::LEN)
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
             executable: false,
-            rent_epoch: 1,
+            rent_epoch: 0,
         },
     );
 
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+    // Add Relay Round Accounts
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
 
-    let token_settings_address = get_token_settings_ever_address(&token);
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(
+        bridge_test_utils::round_loader_settings(round_number),
+        &mut rl_settings_packed,
+    )
+    .unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
 
-    let token_settings_account_data = TokenSettings {
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_data = round_loader::RelayRound {
         is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
-        kind: TokenKind::Ever {
-            mint: mint_address,
-            token,
-            decimals,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        round_number,
+        round_end: u32::MAX,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
     program_test.add_account(
-        token_settings_address,
+        relay_round_address,
         Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
             executable: false,
             rent_epoch: 0,
         },
@@ -3503,23 +4843,31 @@ async fn test_change_deposit_limit() {
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let new_deposit_limit = 1_000_000;
-
     let mut transaction = Transaction::new_with_payer(
-        &[change_deposit_limit_ix(
-            manager.pubkey(),
-            token_settings_address,
-            new_deposit_limit,
+        &[create_ever_token_ix(
+            funder.pubkey(),
+            withdrawal_address,
+            recipient.pubkey(),
+            token,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &manager], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
+    // Check Token Settings Account
+    let token_settings_address = get_token_settings_ever_address(&token);
     let token_settings_info = banks_client
         .get_account(token_settings_address)
         .await
@@ -3527,193 +4875,318 @@ async fn test_change_deposit_limit() {
         .expect("account");
 
     let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+        TokenSettings::unpack(token_settings_info.data()).expect("deposit token unpack");
 
-    assert_eq!(token_settings_data.deposit_limit, new_deposit_limit);
-}
+    assert_eq!(token_settings_data.is_initialized, true);
+    assert_eq!(token_settings_data.deposit_limit, u64::MAX);
+    assert_eq!(token_settings_data.withdrawal_limit, u64::MAX);
+    assert_eq!(token_settings_data.withdrawal_daily_limit, u64::MAX);
+    assert_eq!(token_settings_data.emergency, false);
 
-#[tokio::test]
-async fn test_change_withdrawal_limits() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+    assert_eq!(
+        token_settings_data.kind,
+        TokenKind::Ever {
+            mint,
+            token,
+            decimals,
+        }
     );
 
-    // Setup environment
-    let owner = Keypair::new();
+    let token_hash = hash(&token.try_to_vec().unwrap());
 
-    // Add Program Data Account
-    let (programdata_address, programdata_nonce) =
-        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], &token_proxy::id());
 
-    let programdata_data = UpgradeableLoaderState::ProgramData {
-        slot: 0,
-        upgrade_authority_address: Some(owner.pubkey()),
-    };
+    assert_eq!(
+        token_settings_data.account_kind,
+        AccountKind::TokenSettings(token_settings_nonce, mint_nonce)
+    );
 
-    let programdata_data_serialized =
-        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+    let fee_info = &token_settings_data.fee_withdrawal_info;
 
-    program_test.add_account(
-        programdata_address,
-        Account {
-            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
-            data: programdata_data_serialized,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
     );
 
-    // Add Settings Account
-    let manager = Pubkey::new_unique();
-    let guardian = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
+    let transfer_amount = amount as u64 - fee;
 
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    assert_eq!(token_settings_data.withdrawal_daily_amount, transfer_amount);
 
-    let settings_address = get_settings_address();
+    // Check Mint Supply
+    let mint_info = banks_client
+        .get_account(mint)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    let settings_account_data = Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
-        emergency: false,
-        manager,
-        guardian,
-        withdrawal_manager,
-    };
+    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
+    assert_eq!(mint_data.supply, transfer_amount);
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
-    program_test.add_account(
-        settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    // Check Recipient Account
+    let recipient_token_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data = spl_token::state::Account::unpack(recipient_token_info.data())
+        .expect("recipient token unpack");
+    assert_eq!(recipient_token_data.amount, transfer_amount);
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
     );
+}
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
+#[tokio::test]
+async fn test_change_token_decimals() {
+    // Setup environment
+    let manager = Keypair::new();
+
+    let settings_account_data =
+        bridge_test_utils::settings(Pubkey::new_unique(), manager.pubkey(), Pubkey::new_unique());
 
     let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
-    let token_hash = hash(&token.try_to_vec().unwrap());
+    let mint = get_mint_address(&token);
+    let token_decimals_address = get_token_decimals_address(&token);
 
-    let (_, mint_nonce) =
-        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+    // Add Withdrawal Account
+    let round_number = 7;
 
-    let mint_address = get_mint_address(&token);
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    let mint_account_data = spl_token::state::Mint {
-        is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
-        supply: 100,
+    let name = "WETH ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "WETH".to_string();
+    let decimals = 18;
+
+    let amount = 5_000_000_000_000;
+
+    let recipient = Pubkey::new_unique();
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_ever_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        token,
+        name.clone(),
+        symbol.clone(),
         decimals,
-        ..Default::default()
-    };
+        recipient,
+        amount,
+        payload.clone(),
+    );
 
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
-    program_test.add_account(
-        mint_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 1,
-        },
+    let event = WithdrawalMultiTokenEverEventWithLen::new(
+        token, name, symbol, decimals, amount, recipient, payload,
     );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
 
-    let token_settings_address = get_token_settings_ever_address(&token);
+    let signers = vec![Vote::Confirm; 3];
 
-    let token_settings_account_data = TokenSettings {
+    let withdrawal_account_data = WithdrawalMultiTokenEver {
         is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
-        kind: TokenKind::Ever {
-            mint: mint_address,
-            token,
-            decimals,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
         },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
-    program_test.add_account(
-        token_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenEver::LEN];
+    WithdrawalMultiTokenEver::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+
+    // Lamports sent to the token decimals address don't prevent its creation
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_funded_account(manager.pubkey(), 1_000_000_000)
+        .with_funded_account(recipient, 1_000_000_000)
+        .with_funded_account(token_decimals_address, 1)
+        .with_account(
+            withdrawal_address,
+            Account {
+                lamports: Rent::default().minimum_balance(WithdrawalMultiTokenEver::LEN)
+                    + Rent::default().minimum_balance(TokenSettings::LEN)
+                    + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+                data: withdrawal_packed,
+                owner: token_proxy::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+    // Add Relay Round Accounts
+    let test_bridge = test_bridge
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number))
+        .with_relay_round(
+            round_number,
+            vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ],
+            u32::MAX,
+        );
 
     // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
 
-    let new_withdrawal_limit = 10000000;
-    let new_withdrawal_daily_limit = 12345;
+    // Decimals above maximum are rejected, valid decimals are set and then updated
+    for (solana_decimals, accepted) in [(MAX_SOLANA_DECIMALS + 1, false), (9, true), (6, true)] {
+        let mut transaction = Transaction::new_with_payer(
+            &[change_token_decimals_ix(
+                manager.pubkey(),
+                token,
+                solana_decimals,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, &manager], recent_blockhash);
+
+        let result = banks_client.process_transaction(transaction).await;
+        assert_eq!(result.is_ok(), accepted);
+    }
+
+    // Check Token Decimals Account
+    let token_decimals_info = banks_client
+        .get_account(token_decimals_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(token_decimals_info.owner, token_proxy::id());
+
+    let token_decimals_data =
+        TokenDecimals::unpack(token_decimals_info.data()).expect("token decimals unpack");
+
+    assert_eq!(token_decimals_data.token, token);
+    assert_eq!(token_decimals_data.solana_decimals, 6);
+
+    // Mint can't be created with default decimals by omitting Token Decimals Account
+    let mut ix = create_ever_token_ix(
+        funder.pubkey(),
+        withdrawal_address,
+        recipient,
+        token,
+        round_number,
+        get_proposal_index_address(
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            None,
+        ),
+    );
+    ix.accounts
+        .retain(|account| account.pubkey != token_decimals_address);
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
 
+    // Create Token with overridden decimals
     let mut transaction = Transaction::new_with_payer(
-        &[change_withdrawal_limits_by_owner_ix(
-            owner.pubkey(),
-            token_settings_address,
-            Some(new_withdrawal_limit),
-            Some(new_withdrawal_daily_limit),
+        &[create_ever_token_ix(
+            funder.pubkey(),
+            withdrawal_address,
+            recipient,
+            token,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &owner], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
+    // Check Mint Decimals
+    let mint_info = banks_client
+        .get_account(mint)
         .await
         .expect("get_account")
         .expect("account");
 
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
+    assert_eq!(mint_data.decimals, 6);
 
-    assert_eq!(token_settings_data.withdrawal_limit, new_withdrawal_limit);
-    assert_eq!(
-        token_settings_data.withdrawal_daily_limit,
-        new_withdrawal_daily_limit
+    // Check Recipient Account
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint);
+
+    let recipient_token_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data = spl_token::state::Account::unpack(recipient_token_info.data())
+        .expect("recipient token unpack");
+    assert_eq!(recipient_token_data.amount, mint_data.supply);
+
+    // Decimals can't be changed once mint is created
+    let mut transaction = Transaction::new_with_payer(
+        &[change_token_decimals_ix(manager.pubkey(), token, 8)],
+        Some(&funder.pubkey()),
     );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
 }
 
 #[tokio::test]
-async fn test_enable_emergency() {
+async fn test_withdrawal_ever_v2_create_if_needed() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -3723,9 +5196,8 @@ async fn test_enable_emergency() {
     // Setup environment
 
     // Add Settings Account
-    let guardian = Keypair::new();
+    let guardian = Pubkey::new_unique();
     let manager = Pubkey::new_unique();
-
     let withdrawal_manager = Pubkey::new_unique();
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
@@ -3735,9 +5207,26 @@ async fn test_enable_emergency() {
         is_initialized: true,
         account_kind: AccountKind::Settings(settings_nonce, 0),
         emergency: false,
-        guardian: guardian.pubkey(),
+        guardian,
         manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -3753,179 +5242,165 @@ async fn test_enable_emergency() {
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
-    let mut transaction = Transaction::new_with_payer(
-        &[enable_emergency_ix(guardian.pubkey())],
-        Some(&funder.pubkey()),
+    // Add Recipient Account
+    let recipient = Keypair::new();
+    program_test.add_account(
+        recipient.pubkey(),
+        Account {
+            lamports: 100000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
-    transaction.sign(&[&funder, &guardian], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
 
-    let settings_info = banks_client
-        .get_account(settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let mint = get_mint_address(&token);
 
-    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient.pubkey(), &mint);
 
-    assert_eq!(settings_data.emergency, true);
-}
+    // Add Withdrawal Account
+    let round_number = 7;
 
-#[tokio::test]
-async fn test_enable_emergency_by_owner() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
-    );
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    // Setup environment
-    let owner = Keypair::new();
+    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "USDC".to_string();
+    let decimals = spl_token::native_mint::DECIMALS;
 
-    // Add Program Data Account
-    let (programdata_address, programdata_nonce) =
-        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+    let amount = 32;
 
-    let programdata_data = UpgradeableLoaderState::ProgramData {
-        slot: 0,
-        upgrade_authority_address: Some(owner.pubkey()),
-    };
+    let payload: Vec<u8> = vec![];
 
-    let programdata_data_serialized =
-        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+    let withdrawal_address = get_withdrawal_ever_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        token,
+        name.clone(),
+        symbol.clone(),
+        decimals,
+        recipient.pubkey(),
+        amount,
+        payload.clone(),
+    );
 
-    program_test.add_account(
-        programdata_address,
-        Account {
-            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
-            data: programdata_data_serialized,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    let event = WithdrawalMultiTokenEverEventWithLen::new(
+        token,
+        name,
+        symbol,
+        decimals,
+        amount,
+        recipient.pubkey(),
+        payload,
     );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
 
-    let settings_address = get_settings_address();
+    let signers = vec![Vote::Confirm; 3];
 
-    let settings_account_data = Settings {
+    let withdrawal_account_data = WithdrawalMultiTokenEver {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
-        emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenEver::LEN];
+    WithdrawalMultiTokenEver::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
     program_test.add_account(
-        settings_address,
+        withdrawal_address,
         Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenEver::LEN)
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: withdrawal_packed,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
-    let mut transaction = Transaction::new_with_payer(
-        &[enable_emergency_by_owner_ix(owner.pubkey())],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder, &owner], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
-
-    let settings_info = banks_client
-        .get_account(settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
-
-    assert_eq!(settings_data.emergency, true);
-}
-
-#[tokio::test]
-async fn test_disable_emergency() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
-    );
-
-    // Setup environment
-    let owner = Keypair::new();
-
-    // Add Program Data Account
-    let (programdata_address, programdata_nonce) =
-        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
-
-    let programdata_data = UpgradeableLoaderState::ProgramData {
-        slot: 0,
-        upgrade_authority_address: Some(owner.pubkey()),
-    };
-
-    let programdata_data_serialized =
-        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+    // Add Relay Round Accounts
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
 
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(
+        bridge_test_utils::round_loader_settings(round_number),
+        &mut rl_settings_packed,
+    )
+    .unwrap();
     program_test.add_account(
-        programdata_address,
+        rl_settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
-            data: programdata_data_serialized,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
-    let settings_address = get_settings_address();
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
 
-    let settings_account_data = Settings {
+    let relay_round_data = round_loader::RelayRound {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
-        emergency: true,
-        guardian,
-        manager,
-        withdrawal_manager,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        round_number,
+        round_end: u32::MAX,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
     };
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
     program_test.add_account(
-        settings_address,
+        relay_round_address,
         Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
             executable: false,
             rent_epoch: 0,
         },
@@ -3935,420 +5410,640 @@ async fn test_disable_emergency() {
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
     let mut transaction = Transaction::new_with_payer(
-        &[disable_emergency_ix(owner.pubkey())],
+        &[withdrawal_ever_v2_ix(
+            funder.pubkey(),
+            withdrawal_address,
+            recipient.pubkey(),
+            token,
+            false,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
+        )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &owner], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let settings_info = banks_client
-        .get_account(settings_address)
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+    let withdrawal_data =
+        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal unpack");
 
-    assert_eq!(settings_data.emergency, false);
-}
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
 
-#[tokio::test]
-async fn test_enable_token_emergency() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+    // Check Recipient Account
+    let token_settings_info = banks_client
+        .get_account(get_token_settings_ever_address(&token))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    let recipient_token_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data = spl_token::state::Account::unpack(recipient_token_info.data())
+        .expect("recipient token unpack");
+    assert_eq!(recipient_token_data.owner, recipient.pubkey());
+    assert_eq!(
+        recipient_token_data.amount,
+        token_settings_data.withdrawal_daily_amount
     );
+}
 
+#[tokio::test]
+async fn test_withdrawal_sol() {
     // Setup environment
-
-    // Add Settings Account
-    let guardian = Keypair::new();
+    let guardian = Pubkey::new_unique();
     let manager = Pubkey::new_unique();
-
     let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
-    let settings_address = get_settings_address();
+    let decimals = spl_token::native_mint::DECIMALS;
 
-    let settings_account_data = Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
-        emergency: false,
-        guardian: guardian.pubkey(),
-        manager,
-        withdrawal_manager,
-    };
+    let mint_address = Pubkey::new_unique();
+    let vault_address = get_vault_address(&mint_address);
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
-    program_test.add_account(
-        settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    let recipient = Pubkey::new_unique();
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_settings_account_data = bridge_test_utils::sol_token_settings(mint_address);
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let test_bridge = TestBridge::new()
+        .with_settings(bridge_test_utils::settings(
+            guardian,
+            manager,
+            withdrawal_manager,
+        ))
+        .with_sol_token(token_settings_account_data, decimals, 100)
+        .with_associated_token_account(recipient, mint_address, 0);
+
+    // Add Withdrawal Account
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient,
+        amount,
+        payload.clone(),
     );
 
-    // Add Token Settings Account
-    let mint = Pubkey::new_unique();
-    let vault = Pubkey::new_unique();
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
-    let token_settings_address = get_token_settings_sol_address(&mint);
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
 
-    let token_settings_account_data = TokenSettings {
+    let signers = vec![Vote::Confirm; 3];
+
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, 0),
-        kind: TokenKind::Solana { mint, vault },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
-    program_test.add_account(
-        token_settings_address,
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    let test_bridge = test_bridge.with_account(
+        withdrawal_address,
         Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: withdrawal_packed,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
+    // Add Relay Round Accounts
+    let test_bridge = test_bridge
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number))
+        .with_relay_round(
+            round_number,
+            vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ],
+            u32::MAX,
+        );
+
     // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
 
     let mut transaction = Transaction::new_with_payer(
-        &[enable_emergency_token_ix(
-            guardian.pubkey(),
-            token_settings_address,
+        &[withdrawal_sol_ix(
+            withdrawal_address,
+            token_wallet,
+            recipient,
+            mint_address,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &guardian], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
-
-    assert_eq!(token_settings_data.emergency, true);
-}
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
 
-#[tokio::test]
-async fn test_disable_token_emergency() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
     );
 
-    // Setup environment
-    let owner = Keypair::new();
+    let transfer_amount = amount as u64 - fee;
 
-    // Add Program Data Account
-    let (programdata_address, programdata_nonce) =
-        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+    assert_eq!(vault_data.amount, 100 - transfer_amount);
 
-    let programdata_data = UpgradeableLoaderState::ProgramData {
-        slot: 0,
-        upgrade_authority_address: Some(owner.pubkey()),
-    };
+    // Check Recipient Balance
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    let programdata_data_serialized =
-        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
+    assert_eq!(recipient_data.amount, transfer_amount);
 
-    program_test.add_account(
-        programdata_address,
-        Account {
-            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
-            data: programdata_data_serialized,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
     );
+}
 
-    // Add Settings Account
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+#[tokio::test]
+async fn test_withdrawal_sol_divergent_proposal() {
+    // Setup environment
+    let mint_address = Pubkey::new_unique();
 
-    let settings_address = get_settings_address();
+    let recipient = Pubkey::new_unique();
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
 
-    let settings_account_data = Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
-        emergency: true,
-        guardian,
-        manager,
-        withdrawal_manager,
-    };
+    let mut test_bridge = TestBridge::new()
+        .with_settings(bridge_test_utils::settings(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ))
+        .with_sol_token(bridge_test_utils::sol_token_settings(mint_address), 9, 100)
+        .with_associated_token_account(recipient, mint_address, 0);
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
-    program_test.add_account(
-        settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+    // Add confirmed Withdrawal Accounts with divergent event data
+    let round_number = 7;
 
-    // Add Token Settings Account
-    let mint = Pubkey::new_unique();
-    let vault = Pubkey::new_unique();
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    let token_settings_address = get_token_settings_sol_address(&mint);
+    let mut withdrawal_addresses = vec![];
+    let mut event_datas = vec![];
 
-    let token_settings_account_data = TokenSettings {
+    for amount in [32, 33] {
+        let withdrawal_address = get_withdrawal_sol_address(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            mint_address,
+            recipient,
+            amount,
+            vec![],
+        );
+
+        let event =
+            WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, vec![]);
+        let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+        let (_, withdrawal_nonce) = Pubkey::find_program_address(
+            &[
+                br"proposal",
+                &round_number.to_le_bytes(),
+                &event_timestamp.to_le_bytes(),
+                &event_transaction_lt.to_le_bytes(),
+                &event_configuration.to_bytes(),
+                &event_data,
+            ],
+            &token_proxy::id(),
+        );
+
+        let signers = vec![Vote::Confirm; 3];
+
+        let withdrawal_account_data = WithdrawalMultiTokenSol {
+            is_initialized: true,
+            account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+            author: Pubkey::new_unique(),
+            round_number,
+            event,
+            meta: WithdrawalTokenMetaWithLen::default(),
+            required_votes: signers.len() as u32,
+            signers,
+            chain_id: None,
+            vote_rewards: vec![],
+            pda: PDA {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+            },
+            version: CURRENT_ACCOUNT_VERSION,
+            confirmed_weight: 0,
+        };
+
+        test_bridge = test_bridge.with_packed_account(
+            withdrawal_address,
+            token_proxy::id(),
+            withdrawal_account_data,
+        );
+
+        withdrawal_addresses.push(withdrawal_address);
+        event_datas.push(event_data);
+    }
+
+    // Add Proposal Index Account recorded by the first request
+    let proposal_index_seeds = get_proposal_index_seeds(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
+    let (proposal_index_address, proposal_index_nonce) = Pubkey::find_program_address(
+        &proposal_index_seeds
+            .iter()
+            .map(Vec::as_slice)
+            .collect::<Vec<_>>(),
+        &token_proxy::id(),
+    );
+
+    let proposal_index_account_data = ProposalIndex {
         is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, programdata_nonce),
-        kind: TokenKind::Solana { mint, vault },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: true,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
+        account_kind: AccountKind::ProposalIndex(proposal_index_nonce),
+        event_data: solana_program::hash::Hash::new_from_array(event_datas[0]),
+        version: CURRENT_ACCOUNT_VERSION,
+        proposals: 1,
     };
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
-    program_test.add_account(
-        token_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    let test_bridge = test_bridge.with_packed_account(
+        proposal_index_address,
+        token_proxy::id(),
+        proposal_index_account_data,
     );
 
+    // Add Relay Round Accounts
+    let test_bridge = test_bridge
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number))
+        .with_relay_round(
+            round_number,
+            vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ],
+            u32::MAX,
+        );
+
     // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
 
+    // Divergent proposal can't be requested
     let mut transaction = Transaction::new_with_payer(
-        &[disable_emergency_token_ix(
-            owner.pubkey(),
-            token_settings_address,
+        &[withdrawal_multi_token_sol_request_ix(
+            funder.pubkey(),
+            funder.pubkey(),
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            mint_address,
+            round_number,
+            recipient,
+            34,
+            vec![],
+            0,
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &owner], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Indexed proposal is executed
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_sol_ix(
+            withdrawal_addresses[0],
+            token_wallet,
+            recipient,
+            mint_address,
+            round_number,
+            proposal_index_address,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
+    let proposal_index_info = banks_client
+        .get_account(proposal_index_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+    let proposal_index_data =
+        ProposalIndex::unpack(proposal_index_info.data()).expect("proposal index unpack");
 
-    assert_eq!(token_settings_data.emergency, false);
-}
+    assert_eq!(proposal_index_data.event_data.to_bytes(), event_datas[0]);
 
-#[tokio::test]
-async fn test_approve_withdrawal_ever() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
+    // Divergent proposal requested before the index can't be executed
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_sol_ix(
+            withdrawal_addresses[1],
+            token_wallet,
+            recipient,
+            mint_address,
+            round_number,
+            proposal_index_address,
+        )],
+        Some(&funder.pubkey()),
     );
+    transaction.sign(&[&funder], recent_blockhash);
 
-    // Setup environment
+    assert!(banks_client.process_transaction(transaction).await.is_err());
 
-    // Add Settings Account
-    let withdrawal_manager = Keypair::new();
+    // Proposal index is required to execute
+    let mut ix = withdrawal_sol_ix(
+        withdrawal_addresses[1],
+        token_wallet,
+        recipient,
+        mint_address,
+        round_number,
+        proposal_index_address,
+    );
+    ix.accounts
+        .retain(|account| account.pubkey != proposal_index_address);
 
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
 
-    let settings_address = get_settings_address();
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
 
-    let settings_account_data = Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
-        emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager: withdrawal_manager.pubkey(),
-    };
+#[tokio::test]
+async fn test_withdrawal_sol_with_evicted_relay() {
+    // Setup environment
+    let decimals = spl_token::native_mint::DECIMALS;
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
-    program_test.add_account(
-        settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+    let mint_address = Pubkey::new_unique();
+    let vault_address = get_vault_address(&mint_address);
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
+    let recipient = Pubkey::new_unique();
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
 
-    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
-    let token_hash = hash(&token.try_to_vec().unwrap());
+    let token_settings_account_data = bridge_test_utils::sol_token_settings(mint_address);
 
-    let (_, mint_nonce) =
-        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+    let test_bridge = TestBridge::new()
+        .with_settings(bridge_test_utils::settings(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ))
+        .with_sol_token(token_settings_account_data, decimals, 100)
+        .with_associated_token_account(recipient, mint_address, 0);
 
-    let mint_address = get_mint_address(&token);
+    // Add Withdrawal Account confirmed by 3 of 4 Relays
+    let round_number = 7;
 
-    let mint_account_data = spl_token::state::Mint {
-        is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
-        decimals,
-        ..Default::default()
-    };
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
-    program_test.add_account(
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
         mint_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 1,
-        },
+        recipient,
+        amount,
+        payload.clone(),
     );
 
-    // Add Recipient Token Account
-    let recipient = Pubkey::new_unique();
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
-    let token_wallet =
-        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
 
-    let token_wallet_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: recipient,
-        state: AccountState::Initialized,
-        ..Default::default()
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: 3,
+        signers: vec![Vote::Confirm, Vote::Confirm, Vote::None, Vote::Confirm],
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
 
-    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
-    program_test.add_account(
-        token_wallet,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: token_wallet_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    // Last confirming Relay is evicted from the round
+    let test_bridge = test_bridge
+        .with_packed_account(
+            withdrawal_address,
+            token_proxy::id(),
+            withdrawal_account_data,
+        )
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number))
+        .with_relay_round(
+            round_number,
+            vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::default(),
+            ],
+            u32::MAX,
+        );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_sol_ix(
+            withdrawal_address,
+            token_wallet,
+            recipient,
+            mint_address,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
+        )],
+        Some(&funder.pubkey()),
     );
+    transaction.sign(&[&funder], recent_blockhash);
 
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
-    let token_settings_address = get_token_settings_ever_address(&token);
+    // Vote of evicted Relay isn't counted
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    let token_settings_account_data = TokenSettings {
-        is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
-        kind: TokenKind::Ever {
-            mint: mint_address,
-            token,
-            decimals,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
-    };
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, 100);
 
-    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
-    program_test.add_account(
-        token_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
+}
+
+#[tokio::test]
+async fn test_vote_for_withdrawal_by_signatures_with_evicted_relay() {
+    // Setup environment
+    let mint_address = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    let test_bridge = TestBridge::new()
+        .with_settings(bridge_test_utils::settings(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ))
+        .with_sol_token(
+            bridge_test_utils::sol_token_settings(mint_address),
+            spl_token::native_mint::DECIMALS,
+            100,
+        );
 
     // Add Withdrawal Account
     let round_number = 7;
@@ -4357,32 +6052,23 @@ async fn test_approve_withdrawal_ever() {
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
-    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
-    let symbol = "USDC".to_string();
-    let decimals = spl_token::native_mint::DECIMALS;
-
     let amount = 32;
 
     let payload: Vec<u8> = vec![];
 
-    let withdrawal_address = get_withdrawal_ever_address(
+    let withdrawal_address = get_withdrawal_sol_address(
         round_number,
         event_timestamp,
         event_transaction_lt,
         &event_configuration,
-        token,
-        name.clone(),
-        symbol.clone(),
-        decimals,
+        mint_address,
         recipient,
         amount,
         payload.clone(),
     );
 
-    let event = WithdrawalMultiTokenEverEventWithLen::new(
-        token, name, symbol, decimals, amount, recipient, payload,
-    );
-    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack"));
 
     let (_, withdrawal_nonce) = Pubkey::find_program_address(
         &[
@@ -4391,64 +6077,73 @@ async fn test_approve_withdrawal_ever() {
             &event_timestamp.to_le_bytes(),
             &event_transaction_lt.to_le_bytes(),
             &event_configuration.to_bytes(),
-            &event_data,
+            &event_data.to_bytes(),
         ],
         &token_proxy::id(),
     );
 
-    let signers = vec![Vote::Confirm; 3];
-
-    let mut withdrawal_account_data = WithdrawalMultiTokenEver {
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
         account_kind: AccountKind::Proposal(withdrawal_nonce, None),
         author: Pubkey::new_unique(),
         round_number,
         event,
         meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: signers.len() as u32,
-        signers: signers.clone(),
+        required_votes: 3,
+        signers: vec![Vote::Confirm, Vote::Confirm, Vote::None, Vote::None],
+        chain_id: None,
+        vote_rewards: vec![],
         pda: PDA {
             event_timestamp,
             event_transaction_lt,
             event_configuration,
         },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
-    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
 
-    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenEver::LEN];
-    WithdrawalMultiTokenEver::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
-    program_test.add_account(
-        withdrawal_address,
-        Account {
-            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenEver::LEN)
-                + Rent::default().minimum_balance(TokenSettings::LEN)
-                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: withdrawal_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+    // Last Relay is evicted from the round
+    let test_bridge = test_bridge
+        .with_packed_account(
+            withdrawal_address,
+            token_proxy::id(),
+            withdrawal_account_data,
+        )
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number))
+        .with_relay_round(
+            round_number,
+            vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::default(),
+            ],
+            u32::MAX,
+        );
 
     // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    // Zeroed slot can't vote by signature
+    let message = bridge_utils::helper::get_vote_message(&withdrawal_address, &event_data);
 
     let mut transaction = Transaction::new_with_payer(
-        &[approve_withdrawal_ever_ix(
-            withdrawal_manager.pubkey(),
-            withdrawal_address,
-            token_wallet,
-            mint_address,
-            &token,
-        )],
+        &[
+            bridge_utils::helper::ed25519_signatures_ix(
+                message.as_ref(),
+                &[(Pubkey::default(), [0; 64])],
+            ),
+            vote_for_withdrawal_request_by_signatures_ix(
+                funder.pubkey(),
+                withdrawal_address,
+                round_number,
+            ),
+        ],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
+    assert!(banks_client.process_transaction(transaction).await.is_err());
 
     let withdrawal_info = banks_client
         .get_account(withdrawal_address)
@@ -4457,74 +6152,221 @@ async fn test_approve_withdrawal_ever() {
         .expect("account");
 
     let withdrawal_data =
-        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal unpack");
-    assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Processed
-    );
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
 
-    let fee = 1.max(
-        (amount as u64)
-            .checked_div(fee_info.divisor)
-            .unwrap()
-            .checked_mul(fee_info.multiplier)
-            .unwrap(),
+    assert_eq!(withdrawal_data.signers[3], Vote::None);
+}
+
+#[tokio::test]
+async fn test_withdrawal_sol_fee_floor() {
+    // Setup environment
+    let settings_account_data = bridge_test_utils::settings(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
     );
 
-    let transfer_amount = amount as u64 - fee;
+    let recipient = Pubkey::new_unique();
 
-    let mint_info = banks_client
-        .get_account(mint_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    // Default rate charges the one unit minimum on small amounts, zero rate charges nothing
+    let mint = Pubkey::new_unique();
+    let zero_fee_mint = Pubkey::new_unique();
 
-    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
-    assert_eq!(mint_data.supply, transfer_amount as u64);
+    let mut zero_fee_token_settings_data = bridge_test_utils::sol_token_settings(zero_fee_mint);
+    zero_fee_token_settings_data.fee_withdrawal_info.multiplier = 0;
 
-    let recipient_info = banks_client
-        .get_account(token_wallet)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let mut test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_sol_token(bridge_test_utils::sol_token_settings(mint), 9, 100)
+        .with_sol_token(zero_fee_token_settings_data, 9, 100)
+        .with_associated_token_account(recipient, mint, 0)
+        .with_associated_token_account(recipient, zero_fee_mint, 0);
 
-    let recipient_data =
-        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
-    assert_eq!(recipient_data.amount, transfer_amount as u64);
-}
+    // Add Withdrawal Accounts
+    let round_number = 7;
 
-#[tokio::test]
-async fn test_approve_withdrawal_sol() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
-    );
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    // Setup environment
+    let amount = 32;
 
-    // Add Settings Account
-    let withdrawal_manager = Keypair::new();
+    let mut withdrawals = vec![];
 
-    let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    for (mint, expected_fee) in [(mint, 1), (zero_fee_mint, 0)] {
+        let payload: Vec<u8> = vec![];
 
-    let settings_address = get_settings_address();
+        let withdrawal_address = get_withdrawal_sol_address(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            mint,
+            recipient,
+            amount,
+            payload.clone(),
+        );
 
-    let settings_account_data = Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
-        emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager: withdrawal_manager.pubkey(),
-    };
+        let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+        let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
-    program_test.add_account(
-        settings_address,
+        let (_, withdrawal_nonce) = Pubkey::find_program_address(
+            &[
+                br"proposal",
+                &round_number.to_le_bytes(),
+                &event_timestamp.to_le_bytes(),
+                &event_transaction_lt.to_le_bytes(),
+                &event_configuration.to_bytes(),
+                &event_data,
+            ],
+            &token_proxy::id(),
+        );
+
+        let signers = vec![Vote::Confirm; 3];
+
+        test_bridge = test_bridge.with_packed_account(
+            withdrawal_address,
+            token_proxy::id(),
+            WithdrawalMultiTokenSol {
+                is_initialized: true,
+                account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+                author: Pubkey::new_unique(),
+                round_number,
+                event,
+                meta: WithdrawalTokenMetaWithLen::default(),
+                required_votes: signers.len() as u32,
+                signers,
+                chain_id: None,
+                vote_rewards: vec![],
+                pda: PDA {
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                },
+                version: CURRENT_ACCOUNT_VERSION,
+                confirmed_weight: 0,
+            },
+        );
+
+        withdrawals.push((withdrawal_address, mint, expected_fee));
+    }
+
+    // Add Relay Round Accounts
+    let test_bridge = test_bridge
+        .with_round_loader_settings(bridge_test_utils::round_loader_settings(round_number))
+        .with_relay_round(
+            round_number,
+            vec![
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            ],
+            u32::MAX,
+        );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    for (withdrawal_address, mint, expected_fee) in withdrawals {
+        let token_wallet =
+            spl_associated_token_account::get_associated_token_address(&recipient, &mint);
+
+        let mut transaction = Transaction::new_with_payer(
+            &[withdrawal_sol_ix(
+                withdrawal_address,
+                token_wallet,
+                recipient,
+                mint,
+                round_number,
+                get_proposal_index_address(
+                    event_timestamp,
+                    event_transaction_lt,
+                    &event_configuration,
+                    None,
+                ),
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+
+        // Check Recipient Balance
+        let recipient_info = banks_client
+            .get_account(token_wallet)
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let recipient_data = spl_token::state::Account::unpack(recipient_info.data())
+            .expect("recipient token unpack");
+        assert_eq!(recipient_data.amount, amount as u64 - expected_fee);
+
+        // Check Fee Supply
+        let token_settings_info = banks_client
+            .get_account(get_token_settings_sol_address(&mint))
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let token_settings_data =
+            TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+        assert_eq!(token_settings_data.fee_supply, expected_fee);
+    }
+}
+
+#[tokio::test]
+async fn test_withdrawal_sol_with_ata_creation() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
         Account {
             lamports: Rent::default().minimum_balance(Settings::LEN),
             data: settings_packed,
@@ -4586,32 +6428,12 @@ async fn test_approve_withdrawal_sol() {
         },
     );
 
-    // Add Recipient Token Account
+    // Recipient has no Token Account yet
     let recipient = Pubkey::new_unique();
 
     let token_wallet =
         spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
 
-    let token_wallet_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: recipient,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
-    program_test.add_account(
-        token_wallet,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: token_wallet_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
     // Add Token Settings Account
     let symbol = "USDT".to_string();
     let name = "USDT Solana Octusbridge".to_string();
@@ -4644,6 +6466,26 @@ async fn test_approve_withdrawal_sol() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
     let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
@@ -4700,7 +6542,7 @@ async fn test_approve_withdrawal_sol() {
 
     let signers = vec![Vote::Confirm; 3];
 
-    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
         account_kind: AccountKind::Proposal(withdrawal_nonce, None),
         author: Pubkey::new_unique(),
@@ -4709,13 +6551,16 @@ async fn test_approve_withdrawal_sol() {
         meta: WithdrawalTokenMetaWithLen::default(),
         required_votes: signers.len() as u32,
         signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
         pda: PDA {
             event_timestamp,
             event_transaction_lt,
             event_configuration,
         },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
-    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
 
     let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
     WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
@@ -4732,44 +6577,97 @@ async fn test_approve_withdrawal_sol() {
         },
     );
 
+    // Add Relay Round Accounts
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(
+        bridge_test_utils::round_loader_settings(round_number),
+        &mut rl_settings_packed,
+    )
+    .unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        round_number,
+        round_end: u32::MAX,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
     let mut transaction = Transaction::new_with_payer(
-        &[approve_withdrawal_sol_ix(
-            withdrawal_manager.pubkey(),
+        &[withdrawal_sol_with_ata_ix(
+            funder.pubkey(),
             withdrawal_address,
-            token_wallet,
+            recipient,
             mint_address,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("settings unpack");
-    assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Processed
-    );
-
+    // Check Vault Balance
     let vault_info = banks_client
         .get_account(vault_address)
         .await
         .expect("get_account")
         .expect("account");
 
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+
     let fee = 1.max(
         (amount as u64)
             .checked_div(fee_info.divisor)
@@ -4780,9 +6678,9 @@ async fn test_approve_withdrawal_sol() {
 
     let transfer_amount = amount as u64 - fee;
 
-    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("mint unpack");
-    assert_eq!(vault_data.amount, 100 - transfer_amount as u64);
+    assert_eq!(vault_data.amount, 100 - transfer_amount);
 
+    // Check Recipient Balance
     let recipient_info = banks_client
         .get_account(token_wallet)
         .await
@@ -4790,12 +6688,27 @@ async fn test_approve_withdrawal_sol() {
         .expect("account");
 
     let recipient_data =
-        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
-    assert_eq!(recipient_data.amount, transfer_amount as u64);
+        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
+    assert_eq!(recipient_data.amount, transfer_amount);
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
 }
 
 #[tokio::test]
-async fn test_approve_withdrawal_sol_with_empty_vault() {
+async fn test_withdrawal_sol_with_limit_exemption() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -4805,10 +6718,9 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
     // Setup environment
 
     // Add Settings Account
-    let withdrawal_manager = Keypair::new();
-
     let guardian = Pubkey::new_unique();
     let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
     let settings_address = get_settings_address();
@@ -4819,7 +6731,24 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
         emergency: false,
         guardian,
         manager,
-        withdrawal_manager: withdrawal_manager.pubkey(),
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -4869,6 +6798,7 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
     let vault_account_data = spl_token::state::Account {
         mint: mint_address,
         owner: vault_address,
+        amount: 100,
         state: AccountState::Initialized,
         ..Default::default()
     };
@@ -4917,7 +6847,7 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
     let name = "USDT Solana Octusbridge".to_string();
     let deposit_limit = u64::MAX;
     let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
+    let withdrawal_daily_limit = 1;
 
     let (_, token_settings_nonce) = Pubkey::find_program_address(
         &[br"settings", &mint_address.to_bytes()],
@@ -4944,8 +6874,30 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![recipient],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
     let mut token_settings_packed = vec![0; TokenSettings::LEN];
     TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
@@ -4998,7 +6950,7 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
 
     let signers = vec![Vote::Confirm; 3];
 
-    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
         account_kind: AccountKind::Proposal(withdrawal_nonce, None),
         author: Pubkey::new_unique(),
@@ -5007,13 +6959,16 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
         meta: WithdrawalTokenMetaWithLen::default(),
         required_votes: signers.len() as u32,
         signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
         pda: PDA {
             event_timestamp,
             event_transaction_lt,
             event_configuration,
         },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
-    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
 
     let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
     WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
@@ -5030,47 +6985,110 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    // Add Relay Round Accounts
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
 
-    let mut transaction = Transaction::new_with_payer(
-        &[approve_withdrawal_sol_ix(
-            withdrawal_manager.pubkey(),
-            withdrawal_address,
-            token_wallet,
-            mint_address,
-        )],
-        Some(&funder.pubkey()),
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(
+        bridge_test_utils::round_loader_settings(round_number),
+        &mut rl_settings_packed,
+    )
+    .unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
-    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
 
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
 
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("settings unpack");
-    assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Pending
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        round_number,
+        round_end: u32::MAX,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_sol_ix(
+            withdrawal_address,
+            token_wallet,
+            recipient,
+            mint_address,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
+        )],
+        Some(&funder.pubkey()),
     );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
+    // Check Vault Balance
     let vault_info = banks_client
         .get_account(vault_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("mint unpack");
-    assert_eq!(vault_data.amount, 0);
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee;
 
+    assert_eq!(vault_data.amount, 100 - transfer_amount);
+
+    // Check Recipient Balance
     let recipient_info = banks_client
         .get_account(token_wallet)
         .await
@@ -5078,12 +7096,27 @@ async fn test_approve_withdrawal_sol_with_empty_vault() {
         .expect("account");
 
     let recipient_data =
-        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
-    assert_eq!(recipient_data.amount, 0);
+        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
+    assert_eq!(recipient_data.amount, transfer_amount);
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
 }
 
 #[tokio::test]
-async fn test_update_fee() {
+async fn test_withdrawal_sol_with_denylisted_recipient() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -5093,9 +7126,8 @@ async fn test_update_fee() {
     // Setup environment
 
     // Add Settings Account
-    let manager = Keypair::new();
-
-    let guardian = Pubkey::new_unique();
+    let guardian = Keypair::new();
+    let manager = Pubkey::new_unique();
     let withdrawal_manager = Pubkey::new_unique();
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
@@ -5105,9 +7137,26 @@ async fn test_update_fee() {
         is_initialized: true,
         account_kind: AccountKind::Settings(settings_nonce, 0),
         emergency: false,
-        guardian,
+        guardian: guardian.pubkey(),
+        manager,
         withdrawal_manager,
-        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -5123,131 +7172,79 @@ async fn test_update_fee() {
         },
     );
 
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
     let mint_address = Pubkey::new_unique();
 
-    let (_, token_settings_nonce) = Pubkey::find_program_address(
-        &[br"settings", &mint_address.to_bytes()],
-        &token_proxy::id(),
-    );
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
 
-    let token_settings_address = get_token_settings_sol_address(&mint_address);
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
 
+    // Add Vault Account
     let (_, vault_nonce) =
         Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
 
     let vault_address = get_vault_address(&mint_address);
 
-    let token_settings_account_data = TokenSettings {
-        is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-        kind: TokenKind::Solana {
-            mint: mint_address,
-            vault: vault_address,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        amount: 100,
+        state: AccountState::Initialized,
+        ..Default::default()
     };
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
     program_test.add_account(
-        token_settings_address,
+        vault_address,
         Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
-    let multiplier = 1;
-    let divisor = 100;
-
-    let mut transaction = Transaction::new_with_payer(
-        &[update_fee_ix(
-            manager.pubkey(),
-            token_settings_address,
-            FeeType::Deposit,
-            multiplier,
-            divisor,
-        )],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder, &manager], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
-
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
-
-    assert_eq!(token_settings_data.fee_deposit_info.multiplier, multiplier);
-    assert_eq!(token_settings_data.fee_deposit_info.divisor, divisor);
-}
-
-#[tokio::test]
-async fn test_update_token_name() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
-    );
-
-    // Setup environment
-
-    // Add Settings Account
-    let manager = Keypair::new();
-
-    let guardian = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
 
-    let settings_address = get_settings_address();
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
 
-    let settings_account_data = Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
-        emergency: false,
-        guardian,
-        withdrawal_manager,
-        manager: manager.pubkey(),
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
     };
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
     program_test.add_account(
-        settings_address,
+        token_wallet,
         Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
             executable: false,
             rent_epoch: 0,
         },
@@ -5259,7 +7256,6 @@ async fn test_update_token_name() {
     let deposit_limit = u64::MAX;
     let withdrawal_limit = u64::MAX;
     let withdrawal_daily_limit = u64::MAX;
-    let mint_address = Pubkey::new_unique();
 
     let (_, token_settings_nonce) = Pubkey::find_program_address(
         &[br"settings", &mint_address.to_bytes()],
@@ -5268,11 +7264,6 @@ async fn test_update_token_name() {
 
     let token_settings_address = get_token_settings_sol_address(&mint_address);
 
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
-
-    let vault_address = get_vault_address(&mint_address);
-
     let token_settings_account_data = TokenSettings {
         is_initialized: true,
         account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
@@ -5291,6 +7282,26 @@ async fn test_update_token_name() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
     let mut token_settings_packed = vec![0; TokenSettings::LEN];
@@ -5306,55 +7317,319 @@ async fn test_update_token_name() {
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    // Add Withdrawal Account
+    let round_number = 7;
 
-    let new_symbol = "newUSDT".to_string();
-    let new_name = "New USDT Solana Octusbridge".to_string();
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    let mut transaction = Transaction::new_with_payer(
-        &[update_token_name_ix(
-            manager.pubkey(),
-            token_settings_address,
-            new_symbol.clone(),
-            new_name.clone(),
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let signers = vec![Vote::Confirm; 3];
+
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Accounts
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(
+        bridge_test_utils::round_loader_settings(round_number),
+        &mut rl_settings_packed,
+    )
+    .unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        round_number,
+        round_end: u32::MAX,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Lamports sent to the denylist address don't prevent its creation
+    program_test.add_account(
+        get_denylist_address(),
+        Account {
+            lamports: 1,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Approval Queue Account
+    let (_, approval_queue_nonce) =
+        Pubkey::find_program_address(&[br"approval_queue"], &token_proxy::id());
+
+    let approval_queue_account_data = ApprovalQueue {
+        is_initialized: true,
+        account_kind: AccountKind::ApprovalQueue(approval_queue_nonce),
+        approvals: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut approval_queue_packed = vec![0; ApprovalQueue::LEN];
+    ApprovalQueue::pack(approval_queue_account_data, &mut approval_queue_packed).unwrap();
+    program_test.add_account(
+        get_approval_queue_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(ApprovalQueue::LEN),
+            data: approval_queue_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Only guardian can deny addresses
+    let mut transaction = Transaction::new_with_payer(
+        &[add_to_denylist_ix(
+            funder.pubkey(),
+            funder.pubkey(),
+            recipient,
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &manager], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let _err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("Not a guardian");
+
+    let mut transaction = Transaction::new_with_payer(
+        &[add_to_denylist_ix(
+            funder.pubkey(),
+            guardian.pubkey(),
+            recipient,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
+    // Check Denylist Account
+    let denylist_info = banks_client
+        .get_account(get_denylist_address())
         .await
         .expect("get_account")
         .expect("account");
 
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+    let denylist_data = Denylist::unpack(denylist_info.data()).expect("denylist unpack");
 
-    assert_eq!(token_settings_data.symbol, new_symbol);
-    assert_eq!(token_settings_data.name, new_name);
+    assert_eq!(denylist_data.addresses, vec![recipient]);
+
+    let withdrawal_ix = withdrawal_sol_ix(
+        withdrawal_address,
+        token_wallet,
+        recipient,
+        mint_address,
+        round_number,
+        get_proposal_index_address(
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            None,
+        ),
+    );
+
+    // Executor can't skip the denylist
+    let mut skipped_ix = withdrawal_ix.clone();
+    skipped_ix
+        .accounts
+        .retain(|account| account.pubkey != get_denylist_address());
+
+    let mut transaction = Transaction::new_with_payer(&[skipped_ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(&[withdrawal_ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+
+    assert_eq!(vault_data.amount, 100);
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::WaitingForApprove
+    );
+
+    // Remove Recipient from Denylist
+    let mut transaction = Transaction::new_with_payer(
+        &[remove_from_denylist_ix(guardian.pubkey(), recipient)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let denylist_info = banks_client
+        .get_account(get_denylist_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let denylist_data = Denylist::unpack(denylist_info.data()).expect("denylist unpack");
+
+    assert!(denylist_data.addresses.is_empty());
 }
 
 #[tokio::test]
-async fn test_withdrawal_ever_fee() {
+async fn test_withdrawal_sol_with_recipient_hook() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
         processor!(Processor::process),
     );
 
+    let hook_program_id = Pubkey::new_unique();
+    program_test.add_program(
+        "recipient_hook",
+        hook_program_id,
+        processor!(process_recipient_hook),
+    );
+
     // Setup environment
 
     // Add Settings Account
-    let manager = Keypair::new();
-
     let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
     let withdrawal_manager = Pubkey::new_unique();
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
@@ -5365,8 +7640,25 @@ async fn test_withdrawal_ever_fee() {
         account_kind: AccountKind::Settings(settings_nonce, 0),
         emergency: false,
         guardian,
+        manager,
         withdrawal_manager,
-        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -5385,13 +7677,7 @@ async fn test_withdrawal_ever_fee() {
     // Add Mint Account
     let decimals = spl_token::native_mint::DECIMALS;
 
-    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
-    let token_hash = hash(&token.try_to_vec().unwrap());
-
-    let (_, mint_nonce) =
-        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
-
-    let mint_address = get_mint_address(&token);
+    let mint_address = Pubkey::new_unique();
 
     let mint_account_data = spl_token::state::Mint {
         is_initialized: true,
@@ -5413,249 +7699,97 @@ async fn test_withdrawal_ever_fee() {
         },
     );
 
-    // Add Recipient Token Account
-    let recipient = Pubkey::new_unique();
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
 
-    let token_wallet =
-        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+    let vault_address = get_vault_address(&mint_address);
 
-    let token_wallet_account_data = spl_token::state::Account {
+    let vault_account_data = spl_token::state::Account {
         mint: mint_address,
-        owner: recipient,
+        owner: vault_address,
+        amount: 100,
         state: AccountState::Initialized,
         ..Default::default()
     };
 
-    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
     program_test.add_account(
-        token_wallet,
+        vault_address,
         Account {
             lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: token_wallet_packed,
+            data: vault_packed,
             owner: spl_token::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
-
-    let token_settings_address = get_token_settings_ever_address(&token);
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
 
-    let fee_supply = 1_000_000;
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
 
-    let token_settings_account_data = TokenSettings {
-        is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
-        kind: TokenKind::Ever {
-            mint: mint_address,
-            token,
-            decimals,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply,
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: FeeInfo {
-            multiplier: 5,
-            divisor: 10_000,
-        },
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
     };
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
     program_test.add_account(
-        token_settings_address,
+        token_wallet,
         Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
-    let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_ever_fee_ix(
-            manager.pubkey(),
-            mint_address,
-            token_wallet,
-            &token,
-            fee_supply,
-        )],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder, &manager], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
-
-    let mint_info = banks_client
-        .get_account(mint_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
-    assert_eq!(mint_data.supply, fee_supply);
-
-    let recipient_info = banks_client
-        .get_account(token_wallet)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let recipient_data =
-        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
-    assert_eq!(recipient_data.amount, fee_supply);
-
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
-
-    assert_eq!(token_settings_data.fee_supply, 0);
-}
-
-#[tokio::test]
-async fn test_withdrawal_sol_fee() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
-    );
-
-    // Setup environment
-
-    // Add Settings Account
-    let manager = Keypair::new();
+    // Add Recipient Hook Account
+    let (_, recipient_hook_nonce) =
+        Pubkey::find_program_address(&[br"hook", &recipient.to_bytes()], &token_proxy::id());
 
-    let guardian = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
-    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+    let recipient_hook_address = get_recipient_hook_address(&recipient);
 
-    let settings_address = get_settings_address();
+    let hook_storage_address = Pubkey::new_unique();
 
-    let settings_account_data = Settings {
+    let recipient_hook_account_data = RecipientHook {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
-        emergency: false,
-        guardian,
-        withdrawal_manager,
-        manager: manager.pubkey(),
+        account_kind: AccountKind::RecipientHook(recipient_hook_nonce),
+        recipient,
+        program_id: hook_program_id,
+        data: vec![1, 2, 3, 4],
+        accounts: vec![hook_storage_address],
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    let mut recipient_hook_packed = vec![0; RecipientHook::LEN];
+    RecipientHook::pack(recipient_hook_account_data, &mut recipient_hook_packed).unwrap();
     program_test.add_account(
-        settings_address,
+        recipient_hook_address,
         Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
+            lamports: Rent::default().minimum_balance(RecipientHook::LEN),
+            data: recipient_hook_packed,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
-
-    let mint_address = Pubkey::new_unique();
-
-    let mint_account_data = spl_token::state::Mint {
-        is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
-        decimals,
-        ..Default::default()
-    };
-
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
-    program_test.add_account(
-        mint_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 1,
-        },
-    );
-
-    let fee_supply = 100;
-
-    // Add Vault Account
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
-
-    let vault_address = get_vault_address(&mint_address);
-
-    let vault_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: vault_address,
-        amount: fee_supply,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
-    program_test.add_account(
-        vault_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: vault_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Recipient Token Account
-    let recipient = Pubkey::new_unique();
-
-    let token_wallet =
-        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
-
-    let token_wallet_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: recipient,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    // Add Hook Storage Account
     program_test.add_account(
-        token_wallet,
+        hook_storage_address,
         Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: token_wallet_packed,
-            owner: spl_token::id(),
+            lamports: Rent::default().minimum_balance(8),
+            data: vec![0; 8],
+            owner: hook_program_id,
             executable: false,
             rent_epoch: 0,
         },
@@ -5690,14 +7824,33 @@ async fn test_withdrawal_sol_fee() {
         withdrawal_daily_amount: 0,
         withdrawal_epoch: 0,
         emergency: false,
-        fee_supply,
+        fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
-        fee_withdrawal_info: FeeInfo {
-            multiplier: 1,
-            divisor: 1,
-        },
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
     let mut token_settings_packed = vec![0; TokenSettings::LEN];
     TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
@@ -5711,96 +7864,29 @@ async fn test_withdrawal_sol_fee() {
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    // Add Withdrawal Account
+    let round_number = 7;
 
-    let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_sol_fee_ix(
-            manager.pubkey(),
-            token_wallet,
-            mint_address,
-            fee_supply,
-        )],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder, &manager], recent_blockhash);
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
 
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
+    let amount = 32;
 
-    // Check Vault Balance
-    let vault_info = banks_client
-        .get_account(vault_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let payload: Vec<u8> = vec![];
 
-    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient,
+        amount,
+        payload.clone(),
+    );
 
-    assert_eq!(vault_data.amount, 0);
-
-    // Check Recipient Balance
-    let recipient_info = banks_client
-        .get_account(token_wallet)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let recipient_data =
-        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
-
-    assert_eq!(recipient_data.amount, fee_supply);
-
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
-
-    assert_eq!(token_settings_data.fee_supply, 0);
-}
-
-#[tokio::test]
-async fn test_change_bounty_for_withdrawal_sol() {
-    let mut program_test = ProgramTest::new(
-        "token_proxy",
-        token_proxy::id(),
-        processor!(Processor::process),
-    );
-
-    // Setup environment
-
-    let author = Keypair::new();
-
-    // Add Withdrawal Account
-    let event_timestamp = 1650988297;
-    let event_transaction_lt = 1650988334;
-    let event_configuration = Pubkey::new_unique();
-
-    let round_number = 1;
-    let mint = Pubkey::new_unique();
-    let recipient = Pubkey::new_unique();
-    let amount = 32;
-
-    let payload: Vec<u8> = vec![];
-
-    let withdrawal_address = get_withdrawal_sol_address(
-        round_number,
-        event_timestamp,
-        event_transaction_lt,
-        &event_configuration,
-        mint,
-        recipient,
-        amount,
-        payload.clone(),
-    );
-
-    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
     let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
     let (_, withdrawal_nonce) = Pubkey::find_program_address(
@@ -5815,22 +7901,27 @@ async fn test_change_bounty_for_withdrawal_sol() {
         &token_proxy::id(),
     );
 
-    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+    let signers = vec![Vote::Confirm; 3];
+
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
         account_kind: AccountKind::Proposal(withdrawal_nonce, None),
-        author: author.pubkey(),
+        author: Pubkey::new_unique(),
         round_number,
         event,
         meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: 1,
-        signers: vec![Vote::Confirm],
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
         pda: PDA {
             event_timestamp,
             event_transaction_lt,
             event_configuration,
         },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
-    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
 
     let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
     WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
@@ -5838,7 +7929,8 @@ async fn test_change_bounty_for_withdrawal_sol() {
         withdrawal_address,
         Account {
             lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
-                + RELAY_REPARATION,
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
             data: withdrawal_packed,
             owner: token_proxy::id(),
             executable: false,
@@ -5846,25 +7938,136 @@ async fn test_change_bounty_for_withdrawal_sol() {
         },
     );
 
+    // Add Relay Round Accounts
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(
+        bridge_test_utils::round_loader_settings(round_number),
+        &mut rl_settings_packed,
+    )
+    .unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        round_number,
+        round_end: u32::MAX,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let bounty = 5;
-    let mut transaction = Transaction::new_with_payer(
-        &[change_bounty_for_withdrawal_sol_ix(
-            &author.pubkey(),
-            &withdrawal_address,
-            bounty,
-        )],
-        Some(&funder.pubkey()),
+    let mut ix = withdrawal_sol_ix(
+        withdrawal_address,
+        token_wallet,
+        recipient,
+        mint_address,
+        round_number,
+        get_proposal_index_address(
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            None,
+        ),
     );
-    transaction.sign(&[&funder, &author], recent_blockhash);
+    append_recipient_hook_accounts(
+        &mut ix,
+        hook_program_id,
+        vec![AccountMeta::new(hook_storage_address, false)],
+    );
+
+    // Executor can't skip the hook
+    let mut skip_hook_ix = ix.clone();
+    skip_hook_ix
+        .accounts
+        .retain(|account| account.pubkey != recipient_hook_address);
+
+    let mut transaction = Transaction::new_with_payer(&[skip_hook_ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee;
+
+    assert_eq!(vault_data.amount, 100 - transfer_amount);
+
+    // Check Recipient Balance
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
+    assert_eq!(recipient_data.amount, transfer_amount);
+
+    // Check Withdrawal Account
     let withdrawal_info = banks_client
         .get_account(withdrawal_address)
         .await
@@ -5872,12 +8075,45 @@ async fn test_change_bounty_for_withdrawal_sol() {
         .expect("account");
 
     let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
-    assert_eq!(withdrawal_data.meta.data.bounty, bounty);
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+
+    // Check Hook Storage
+    let hook_storage_info = banks_client
+        .get_account(hook_storage_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(
+        u64::from_le_bytes(hook_storage_info.data()[..8].try_into().unwrap()),
+        transfer_amount
+    );
+}
+
+fn process_recipient_hook(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (prefix, data) = instruction_data.split_at(4);
+    assert_eq!(prefix, &[1, 2, 3, 4]);
+
+    let on_bridge_receive = OnBridgeReceive::try_from_slice(data)?;
+
+    let hook_storage_account_info = &accounts[2];
+    hook_storage_account_info.data.borrow_mut()[..8]
+        .copy_from_slice(&on_bridge_receive.amount.to_le_bytes());
+
+    Ok(())
 }
 
 #[tokio::test]
-async fn test_cancel_withdrawal_sol() {
+async fn test_withdrawal_sol_with_empty_vault() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -5887,8 +8123,8 @@ async fn test_cancel_withdrawal_sol() {
     // Setup environment
 
     // Add Settings Account
-    let manager = Pubkey::new_unique();
     let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
     let withdrawal_manager = Pubkey::new_unique();
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
@@ -5898,9 +8134,26 @@ async fn test_cancel_withdrawal_sol() {
         is_initialized: true,
         account_kind: AccountKind::Settings(settings_nonce, 0),
         emergency: false,
-        manager,
         guardian,
+        manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -5967,26 +8220,27 @@ async fn test_cancel_withdrawal_sol() {
         },
     );
 
-    // Add MultiVault  Account
-    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
 
-    let multivault_address = get_multivault_address();
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
 
-    let multivault_account_data = MultiVault {
-        is_initialized: true,
-        account_kind: AccountKind::MultiVault(multivault_nonce),
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
     };
 
-    let mut multivault_packed = vec![0; MultiVault::LEN];
-    MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
-
-    let multivault_balance = Rent::default().minimum_balance(MultiVault::LEN);
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
     program_test.add_account(
-        multivault_address,
+        token_wallet,
         Account {
-            lamports: multivault_balance,
-            data: multivault_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
             executable: false,
             rent_epoch: 0,
         },
@@ -6024,10 +8278,28 @@ async fn test_cancel_withdrawal_sol() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
-    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
-
     let mut token_settings_packed = vec![0; TokenSettings::LEN];
     TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
@@ -6041,26 +8313,13 @@ async fn test_cancel_withdrawal_sol() {
         },
     );
 
-    // Add Author Account
-    let author = Keypair::new();
-    program_test.add_account(
-        author.pubkey(),
-        Account {
-            lamports: 100000000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
     // Add Withdrawal Account
+    let round_number = 7;
+
     let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
-    let round_number = 1;
-    let recipient = Pubkey::new_unique();
     let amount = 32;
 
     let payload: Vec<u8> = vec![];
@@ -6091,22 +8350,27 @@ async fn test_cancel_withdrawal_sol() {
         &token_proxy::id(),
     );
 
-    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+    let signers = vec![Vote::Confirm; 3];
+
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
         is_initialized: true,
         account_kind: AccountKind::Proposal(withdrawal_nonce, None),
-        author: author.pubkey(),
+        author: Pubkey::new_unique(),
         round_number,
         event,
         meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: 1,
-        signers: vec![Vote::Confirm],
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
         pda: PDA {
             event_timestamp,
             event_transaction_lt,
             event_configuration,
         },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
     };
-    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
 
     let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
     WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
@@ -6114,7 +8378,8 @@ async fn test_cancel_withdrawal_sol() {
         withdrawal_address,
         Account {
             lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
-                + RELAY_REPARATION,
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
             data: withdrawal_packed,
             owner: token_proxy::id(),
             executable: false,
@@ -6122,85 +8387,127 @@ async fn test_cancel_withdrawal_sol() {
         },
     );
 
+    // Add Relay Round Accounts
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(
+        bridge_test_utils::round_loader_settings(round_number),
+        &mut rl_settings_packed,
+    )
+    .unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        round_number,
+        round_end: u32::MAX,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let deposit_seed = uuid::Uuid::new_v4().as_u128();
-    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
-    let value = 1000;
-    let expected_evers = Default::default();
-    let payload = Default::default();
-
     let mut transaction = Transaction::new_with_payer(
-        &[cancel_withdrawal_sol_ix(
-            funder.pubkey(),
-            author.pubkey(),
+        &[withdrawal_sol_ix(
             withdrawal_address,
-            mint_address,
-            deposit_seed,
+            token_wallet,
             recipient,
-            value,
-            expected_evers,
-            payload,
+            mint_address,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &author], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
-
-    assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Cancelled
-    );
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, 0);
 
-    let new_deposit_address = get_deposit_address(deposit_seed);
-    let new_deposit_info = banks_client
-        .get_account(new_deposit_address)
+    // Check Recipient Balance
+    let recipient_info = banks_client
+        .get_account(token_wallet)
         .await
         .expect("get_account")
         .expect("account");
 
-    let deposit_data =
-        DepositMultiTokenSol::unpack(new_deposit_info.data()).expect("deposit unpack");
-    assert_eq!(deposit_data.is_initialized, true);
-    assert_eq!(deposit_data.event.data.recipient, recipient);
-    assert_eq!(deposit_data.meta.data.seed, deposit_seed);
-
-    let fee = 1.max(
-        (amount as u64)
-            .checked_div(fee_info.divisor)
-            .unwrap()
-            .checked_mul(fee_info.multiplier)
-            .unwrap(),
-    );
-
-    let transfer_amount = amount as u64 - fee;
-    assert_eq!(deposit_data.event.data.amount, transfer_amount as u128);
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
+    assert_eq!(recipient_data.amount, 0);
 
-    // Check MultiVault Balance
-    let multivault_info = banks_client
-        .get_account(multivault_address)
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
         .await
         .expect("get_account")
         .expect("account");
-    assert_eq!(multivault_info.lamports, multivault_balance + value);
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Pending
+    );
 }
 
 #[tokio::test]
-async fn test_fill_withdrawal_sol() {
+async fn test_change_guardian() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -6208,10 +8515,34 @@ async fn test_fill_withdrawal_sol() {
     );
 
     // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
 
     // Add Settings Account
-    let manager = Pubkey::new_unique();
     let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
     let withdrawal_manager = Pubkey::new_unique();
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
@@ -6219,11 +8550,28 @@ async fn test_fill_withdrawal_sol() {
 
     let settings_account_data = Settings {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
         emergency: false,
-        manager,
         guardian,
+        manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -6239,266 +8587,216 @@ async fn test_fill_withdrawal_sol() {
         },
     );
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
-
-    let mint_address = Pubkey::new_unique();
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let mint_account_data = spl_token::state::Mint {
-        is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
-        decimals,
-        ..Default::default()
-    };
+    let new_guardian = Pubkey::new_unique();
 
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
-    program_test.add_account(
-        mint_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 1,
-        },
+    let mut transaction = Transaction::new_with_payer(
+        &[change_guardian_ix(owner.pubkey(), new_guardian)],
+        Some(&funder.pubkey()),
     );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
 
-    // Add Author Account
-    let author = Keypair::new();
-    program_test.add_account(
-        author.pubkey(),
-        Account {
-            lamports: 100000000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.guardian, new_guardian);
+}
+
+#[tokio::test]
+async fn test_change_manager() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
     );
 
-    // Add Author Token Account
-    let author_token_address =
-        spl_associated_token_account::get_associated_token_address(&author.pubkey(), &mint_address);
+    // Setup environment
+    let owner = Keypair::new();
 
-    let author_token_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: author.pubkey(),
-        amount: 100,
-        state: AccountState::Initialized,
-        ..Default::default()
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
     };
 
-    let mut author_token_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(author_token_account_data, &mut author_token_packed).unwrap();
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
     program_test.add_account(
-        author_token_address,
+        programdata_address,
         Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: author_token_packed,
-            owner: spl_token::id(),
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Recipient Token Account
-    let recipient_address = Pubkey::new_unique();
-    let recipient_token_address = spl_associated_token_account::get_associated_token_address(
-        &recipient_address,
-        &mint_address,
-    );
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
-    let recipient_token_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: recipient_address,
-        state: AccountState::Initialized,
-        ..Default::default()
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
-    let mut recipient_token_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(recipient_token_account_data, &mut recipient_token_packed)
-        .unwrap();
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
     program_test.add_account(
-        recipient_token_address,
+        settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: recipient_token_packed,
-            owner: spl_token::id(),
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
-
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let vault_address = get_vault_address(&mint_address);
-    let vault_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: vault_address,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
+    let new_manager = Pubkey::new_unique();
 
-    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
-    program_test.add_account(
-        vault_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: vault_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+    let mut transaction = Transaction::new_with_payer(
+        &[change_manager_ix(owner.pubkey(), new_manager)],
+        Some(&funder.pubkey()),
     );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
 
-    let (_, token_settings_nonce) = Pubkey::find_program_address(
-        &[br"settings", &mint_address.to_bytes()],
-        &token_proxy::id(),
-    );
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
-    let token_settings_address = get_token_settings_sol_address(&mint_address);
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    let token_settings_account_data = TokenSettings {
-        is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-        kind: TokenKind::Solana {
-            mint: mint_address,
-            vault: vault_address,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
-    };
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
 
-    let d_fee_info = token_settings_account_data.fee_deposit_info.clone();
-    let w_fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+    assert_eq!(settings_data.manager, new_manager);
+}
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
-    program_test.add_account(
-        token_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
+#[tokio::test]
+async fn test_propose_and_accept_manager_role() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
     );
 
-    // Add MultiVault  Account
-    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+    // Setup environment
+    let owner = Keypair::new();
 
-    let multivault_address = get_multivault_address();
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
 
-    let multivault_account_data = MultiVault {
-        is_initialized: true,
-        account_kind: AccountKind::MultiVault(multivault_nonce),
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
     };
 
-    let mut multivault_packed = vec![0; MultiVault::LEN];
-    MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
 
-    let multivault_balance = Rent::default().minimum_balance(MultiVault::LEN);
     program_test.add_account(
-        multivault_address,
+        programdata_address,
         Account {
-            lamports: multivault_balance,
-            data: multivault_packed,
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Withdrawal Account
-    let event_timestamp = 1650988297;
-    let event_transaction_lt = 1650988334;
-    let event_configuration = Pubkey::new_unique();
-
-    let round_number = 1;
-    let amount = 32;
-    let bounty = 2;
-
-    let value = 1000;
-    let payload: Vec<u8> = vec![];
-    let expected_evers = UInt256::default();
-
-    let withdrawal_address = get_withdrawal_sol_address(
-        round_number,
-        event_timestamp,
-        event_transaction_lt,
-        &event_configuration,
-        mint_address,
-        recipient_address,
-        amount,
-        payload.clone(),
-    );
-
-    let event = WithdrawalMultiTokenSolEventWithLen::new(
-        mint_address,
-        amount,
-        recipient_address,
-        payload.clone(),
-    );
-    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
-    let (_, withdrawal_nonce) = Pubkey::find_program_address(
-        &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data,
-        ],
-        &token_proxy::id(),
-    );
+    let settings_address = get_settings_address();
 
-    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+    let settings_account_data = Settings {
         is_initialized: true,
-        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
-        author: author.pubkey(),
-        round_number,
-        event,
-        meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: 1,
-        signers: vec![Vote::Confirm],
-        pda: PDA {
-            event_timestamp,
-            event_transaction_lt,
-            event_configuration,
-        },
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
-    withdrawal_account_data.meta.data.bounty = bounty;
-    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
 
-    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
-    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
     program_test.add_account(
-        withdrawal_address,
+        settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
-                + RELAY_REPARATION,
-            data: withdrawal_packed,
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
@@ -6508,133 +8806,176 @@ async fn test_fill_withdrawal_sol() {
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let deposit_amount = 100;
-    let deposit_seed = uuid::Uuid::new_v4().as_u128();
-    let ever_recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let new_manager = Keypair::new();
 
     let mut transaction = Transaction::new_with_payer(
-        &[fill_withdrawal_sol_ix(
-            funder.pubkey(),
-            author.pubkey(),
-            mint_address,
-            deposit_seed,
-            ever_recipient,
-            deposit_amount,
-            vec![(withdrawal_address, recipient_address)],
-            Some(vault_address),
-            value,
-            expected_evers,
-            payload.clone(),
+        &[propose_role_ix(
+            owner.pubkey(),
+            Role::Manager,
+            Some(new_manager.pubkey()),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &author], recent_blockhash);
+    transaction.sign(&[&funder, &owner], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
+    let settings_info = banks_client
+        .get_account(settings_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
 
+    assert_eq!(settings_data.manager, manager);
     assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Processed
+        settings_data.pending_role,
+        Some(PendingRole {
+            role: Role::Manager,
+            authority: new_manager.pubkey(),
+        })
     );
 
-    let author_token_info = banks_client
-        .get_account(author_token_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let mut transaction = Transaction::new_with_payer(
+        &[accept_role_ix(new_manager.pubkey())],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &new_manager], recent_blockhash);
 
-    let author_token_data =
-        spl_token::state::Account::unpack(author_token_info.data()).expect("sender unpack");
-    assert_eq!(author_token_data.amount, bounty);
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
-    let recipient_token_info = banks_client
-        .get_account(recipient_token_address)
+    let settings_info = banks_client
+        .get_account(settings_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let recipient_token_data =
-        spl_token::state::Account::unpack(recipient_token_info.data()).expect("recipient unpack");
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
 
-    let fee = 1.max(
-        (amount as u64)
-            .checked_div(w_fee_info.divisor)
-            .unwrap()
-            .checked_mul(w_fee_info.multiplier)
-            .unwrap(),
+    assert_eq!(settings_data.manager, new_manager.pubkey());
+    assert_eq!(settings_data.pending_role, None);
+}
+
+#[tokio::test]
+async fn test_change_withdrawal_manager() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
     );
 
-    let transfer_amount = amount as u64 - fee - bounty;
-    assert_eq!(recipient_token_data.amount, transfer_amount);
+    // Setup environment
+    let owner = Keypair::new();
 
-    let deposit_address = get_deposit_address(deposit_seed);
-    let deposit_info = banks_client
-        .get_account(deposit_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
 
-    let deposit_data = DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit unpack");
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
 
-    assert_eq!(deposit_data.is_initialized, true);
-    assert_eq!(deposit_data.meta.data.seed, deposit_seed);
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
 
-    let fee = 1.max(
-        (deposit_amount)
-            .checked_div(d_fee_info.divisor)
-            .unwrap()
-            .checked_mul(d_fee_info.multiplier)
-            .unwrap(),
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
-    assert_eq!(
-        deposit_data.event.data.amount,
-        (deposit_amount - fee).into()
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
 
-    // Check MultiVault Balance
-    let multivault_info = banks_client
-        .get_account(multivault_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-    assert_eq!(multivault_info.lamports, multivault_balance + value);
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    // Check Vault Balance
-    let vault_info = banks_client
-        .get_account(vault_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let new_withdrawal_manager = Pubkey::new_unique();
 
-    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
-    assert_eq!(vault_data.amount, deposit_amount - amount as u64 + fee);
+    let mut transaction = Transaction::new_with_payer(
+        &[change_withdrawal_manager_ix(
+            owner.pubkey(),
+            new_withdrawal_manager,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
 
-    // Sender balance
-    let sender_token_info = banks_client
-        .get_account(author_token_address)
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let sender_token_data =
-        spl_token::state::Account::unpack(sender_token_info.data()).expect("recipient unpack");
-    assert_eq!(sender_token_data.amount, bounty);
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.withdrawal_manager, new_withdrawal_manager);
 }
 
 #[tokio::test]
-async fn test_withdraw_sol_with_payload() {
+async fn test_change_token_defaults() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -6642,22 +8983,41 @@ async fn test_withdraw_sol_with_payload() {
     );
 
     // Setup environment
+    let manager = Keypair::new();
 
     // Add Settings Account
     let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
     let withdrawal_manager = Pubkey::new_unique();
+    let (_, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
     let settings_address = get_settings_address();
 
     let settings_account_data = Settings {
         is_initialized: true,
-        account_kind: AccountKind::Settings(settings_nonce, 0),
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
         emergency: false,
         guardian,
-        manager,
+        manager: manager.pubkey(),
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -6673,233 +9033,329 @@ async fn test_withdraw_sol_with_payload() {
         },
     );
 
-    // Add Round Loader Settings Account
-    let round_number = 12;
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+    let new_token_defaults = TokenDefaults {
+        deposit_limit: 1_000_000,
+        withdrawal_limit: 100_000,
+        withdrawal_daily_limit: 500_000,
+        deposit_daily_limit: Some(500_000),
+        fee_deposit_info: FeeInfo {
+            multiplier: 1,
+            divisor: 1_000,
+        },
+        fee_withdrawal_info: FeeInfo {
+            multiplier: 0,
+            divisor: 1_000,
+        },
+    };
 
-    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+    let mut transaction = Transaction::new_with_payer(
+        &[change_token_defaults_ix(
+            manager.pubkey(),
+            new_token_defaults,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
 
-    let round_ttl = 1209600;
-    let rl_settings_account_data = round_loader::Settings {
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.token_defaults, Some(new_token_defaults));
+}
+
+#[tokio::test]
+async fn test_change_deposit_value_floor() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let manager = Keypair::new();
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
         is_initialized: true,
-        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
-        current_round_number: round_number,
-        round_submitter: Pubkey::new_unique(),
-        min_required_votes: 1,
-        round_ttl: 0,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager: manager.pubkey(),
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
-    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
-    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
     program_test.add_account(
-        rl_settings_address,
+        settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
-            data: rl_settings_packed,
-            owner: round_loader::id(),
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Relay Accounts
-    let relays = vec![
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-    ];
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    for relay in &relays {
-        program_test.add_account(
-            relay.pubkey(),
-            Account {
-                lamports: 1_000_000_000,
-                data: vec![],
-                owner: solana_program::system_program::id(),
-                executable: false,
-                rent_epoch: 0,
-            },
-        );
-    }
+    let new_deposit_value_floor = DepositValueFloor {
+        multiplier: 1,
+        divisor: 1_000,
+    };
 
-    // Add Relay Round Account
-    let relay_round_address =
-        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let mut transaction = Transaction::new_with_payer(
+        &[change_deposit_value_floor_ix(
+            manager.pubkey(),
+            Some(new_deposit_value_floor),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
 
-    let (_, relay_round_nonce) = Pubkey::find_program_address(
-        &[br"relay_round", &round_number.to_le_bytes()],
-        &round_loader::id(),
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(
+        settings_data.deposit_value_floor,
+        Some(new_deposit_value_floor)
     );
+}
 
-    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
+#[tokio::test]
+async fn test_change_min_supported_ix_version() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
 
-    let relay_round_data = round_loader::RelayRound {
-        is_initialized: true,
-        account_kind: AccountKind::RelayRound(relay_round_nonce),
-        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
-        round_number,
-        round_end,
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
     };
 
-    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
-    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
 
     program_test.add_account(
-        relay_round_address,
+        programdata_address,
         Account {
-            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
-            data: relay_round_packed,
-            owner: round_loader::id(),
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
-    let mint_address = Pubkey::new_unique();
+    let settings_address = get_settings_address();
 
-    let mint_account_data = spl_token::state::Mint {
+    let settings_account_data = Settings {
         is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
-        decimals,
-        ..Default::default()
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
-    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
-    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
     program_test.add_account(
-        mint_address,
+        settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: mint_packed,
-            owner: spl_token::id(),
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
             executable: false,
-            rent_epoch: 1,
+            rent_epoch: 0,
         },
     );
 
-    // Add Vault Account
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let vault_address = get_vault_address(&mint_address);
+    let mut transaction = Transaction::new_with_payer(
+        &[change_min_supported_ix_version_ix(
+            owner.pubkey(),
+            CURRENT_IX_VERSION,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
 
-    let vault_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: vault_address,
-        state: AccountState::Initialized,
-        amount: 100,
-        ..Default::default()
-    };
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
-    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
-    program_test.add_account(
-        vault_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: vault_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = 10_000_000;
-    let withdrawal_limit = 10_000;
-    let withdrawal_daily_limit = 1_000;
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
 
-    let (_, token_settings_nonce) = Pubkey::find_program_address(
-        &[br"settings", &mint_address.to_bytes()],
-        &token_proxy::id(),
+    assert_eq!(settings_data.min_supported_ix_version, CURRENT_IX_VERSION);
+}
+
+#[tokio::test]
+async fn test_change_wever() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
     );
 
-    let token_settings_address = get_token_settings_sol_address(&mint_address);
+    // Setup environment
+    let owner = Keypair::new();
 
-    let token_settings_account_data = TokenSettings {
-        is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-        kind: TokenKind::Solana {
-            mint: mint_address,
-            vault: vault_address,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
     };
 
-    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
 
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
-        token_settings_address,
+        programdata_address,
         Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Recipient Token Account
-    let recipient = Keypair::new();
-    let recipient_token_address = spl_associated_token_account::get_associated_token_address(
-        &recipient.pubkey(),
-        &mint_address,
-    );
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
-    let recipient_token_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: recipient.pubkey(),
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
+    let settings_address = get_settings_address();
 
-    let mut recipient_token_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(recipient_token_account_data, &mut recipient_token_packed)
-        .unwrap();
-    program_test.add_account(
-        recipient_token_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: recipient_token_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
 
-    // Add Author Account
-    let author = Keypair::new();
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
     program_test.add_account(
-        author.pubkey(),
+        settings_address,
         Account {
-            lamports: 1_000_000_000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
@@ -6908,298 +9364,381 @@ async fn test_withdraw_sol_with_payload() {
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    // Create withdrawal request
-    let event_timestamp = 1650988297;
-    let event_transaction_lt = 1650988334;
-    let event_configuration = Pubkey::new_unique();
-
-    let amount = 32;
-
-    let (proxy_address, proxy_nonce) = Pubkey::find_program_address(
-        &[
-            br"proxy",
-            &mint_address.to_bytes(),
-            &recipient.pubkey().to_bytes(),
-        ],
-        &token_proxy::id(),
-    );
-
-    let payload = bincode::serialize(&vec![spl_token::instruction::transfer(
-        &spl_token::id(),
-        &proxy_address,
-        &recipient_token_address,
-        &proxy_address,
-        &[&proxy_address],
-        16,
-    )
-    .unwrap()])
-    .unwrap();
-
-    let attached_amount = 0;
+    let wever = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let wever_mint = get_mint_address(&wever);
+
+    let wever_defaults = TokenDefaults {
+        deposit_limit: 1_000_000,
+        withdrawal_limit: 100_000,
+        withdrawal_daily_limit: 500_000,
+        deposit_daily_limit: None,
+        fee_deposit_info: FeeInfo {
+            multiplier: 0,
+            divisor: 1_000,
+        },
+        fee_withdrawal_info: FeeInfo {
+            multiplier: 0,
+            divisor: 1_000,
+        },
+    };
 
     let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_multi_token_sol_request_ix(
-            funder.pubkey(),
-            author.pubkey(),
-            event_timestamp,
-            event_transaction_lt,
-            event_configuration,
-            mint_address,
-            round_number,
-            recipient.pubkey(),
-            amount,
-            payload.clone(),
-            attached_amount,
+        &[change_wever_ix(
+            owner.pubkey(),
+            Some(wever_mint),
+            Some(wever_defaults),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &author], recent_blockhash);
+    transaction.sign(&[&funder, &owner], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    // Check Withdrawal Account
-    let withdrawal_address = get_withdrawal_sol_address(
-        round_number,
-        event_timestamp,
-        event_transaction_lt,
-        &event_configuration,
-        mint_address,
-        recipient.pubkey(),
-        amount,
-        payload,
-    );
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
+    let settings_info = banks_client
+        .get_account(settings_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
-
-    assert_eq!(withdrawal_data.is_initialized, true);
-    assert_eq!(withdrawal_data.author, author.pubkey());
-    assert_eq!(withdrawal_data.round_number, round_number);
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
 
+    assert_eq!(settings_data.wever_mint, Some(wever_mint));
+    assert_eq!(settings_data.wever_defaults, Some(wever_defaults));
     assert_eq!(
-        withdrawal_data.required_votes,
-        (relays.len() * 2 / 3 + 1) as u32
+        settings_data.ever_token_defaults(&wever_mint),
+        wever_defaults
     );
-
-    assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
     assert_eq!(
-        withdrawal_data.pda.event_transaction_lt,
-        event_transaction_lt
+        settings_data.ever_token_defaults(&Pubkey::new_unique()),
+        TokenDefaults::default()
     );
-    assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
+}
 
-    assert_eq!(withdrawal_data.event.data.mint, mint_address);
-    assert_eq!(withdrawal_data.event.data.recipient, recipient.pubkey());
-    assert_eq!(withdrawal_data.event.data.amount, amount);
+#[tokio::test]
+async fn test_change_enabled_chains() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
 
-    assert_ne!(withdrawal_data.meta.data.epoch, 0);
-    assert_eq!(withdrawal_data.meta.data.bounty, 0);
-    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
+    // Setup environment
+    let owner = Keypair::new();
 
-    let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
 
-    let (_, withdrawal_nonce) = Pubkey::find_program_address(
-        &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data,
-        ],
-        &token_proxy::id(),
-    );
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
 
-    assert_eq!(
-        withdrawal_data.account_kind,
-        AccountKind::Proposal(withdrawal_nonce, Some(proxy_nonce))
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
 
-    // Check Proposal Account to unpack
-    let proposal_data =
-        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
-    assert_eq!(
-        proposal_data.event,
-        withdrawal_data.event.data.try_to_vec().unwrap()
-    );
-    assert_eq!(
-        proposal_data.meta,
-        withdrawal_data.meta.data.try_to_vec().unwrap()
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
 
-    // Vote for withdrawal request
-    for relay in &relays {
-        let mut transaction = Transaction::new_with_payer(
-            &[vote_for_withdrawal_request_ix(
-                relay.pubkey(),
-                withdrawal_address,
-                round_number,
-                Vote::Confirm,
-            )],
-            Some(&funder.pubkey()),
-        );
-        transaction.sign(&[&funder, &relay], recent_blockhash);
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-        let _ = banks_client.process_transaction(transaction).await;
-    }
+    let enabled_chains = vec![1, 56];
 
-    // Execute withdrawal
     let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_sol_with_payload_ix(
-            withdrawal_address,
-            recipient.pubkey(),
-            mint_address,
+        &[change_enabled_chains_ix(
+            owner.pubkey(),
+            enabled_chains.clone(),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder], recent_blockhash);
+    transaction.sign(&[&funder, &owner], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    // Check Vault Balance
-    let vault_info = banks_client
-        .get_account(vault_address)
+    let settings_info = banks_client
+        .get_account(settings_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
-
-    let fee = 1.max(
-        (amount as u64)
-            .checked_div(fee_info.divisor)
-            .unwrap()
-            .checked_mul(fee_info.multiplier)
-            .unwrap(),
-    );
-
-    let transfer_amount = amount as u64 - fee;
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
 
-    assert_eq!(vault_data.amount, 100 - transfer_amount);
+    assert_eq!(settings_data.enabled_chains, enabled_chains);
+    assert!(settings_data.is_chain_enabled(None));
+    assert!(settings_data.is_chain_enabled(Some(56)));
+    assert!(!settings_data.is_chain_enabled(Some(137)));
+}
 
-    // Check Proxy Balance
-    let proxy_info = banks_client
-        .get_account(proxy_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+#[tokio::test]
+async fn test_register_token_ever() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
 
-    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
-    assert_eq!(proxy_data.amount, transfer_amount);
+    // Setup environment
+    let manager = Keypair::new();
 
-    // Withdrawal token from Proxy Account
-    let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_proxy_ix(
-            recipient.pubkey(),
-            recipient_token_address,
-            mint_address,
-            15,
-        )],
-        Some(&funder.pubkey()),
+    program_test.add_account(
+        manager.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
-    transaction.sign(&[&funder, &recipient], recent_blockhash);
 
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
-    // Check Proxy Balance
-    let proxy_info = banks_client
-        .get_account(proxy_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let settings_address = get_settings_address();
 
-    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
-    assert_eq!(proxy_data.amount, 16);
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager: manager.pubkey(),
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
 
-    // Check Recipient Balance
-    let recipient_token_info = banks_client
-        .get_account(recipient_token_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let recipient_token_data =
-        spl_token::state::Account::unpack(recipient_token_info.data()).expect("proxy unpack");
-    assert_eq!(recipient_token_data.amount, 15);
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
 
-    // Execute payload
-    let data = TokenProxyInstruction::ExecutePayloadSol
-        .try_to_vec()
-        .expect("pack");
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    let ix = Instruction {
-        program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_address, false),
-            AccountMeta::new(proxy_address, false),
-            AccountMeta::new(recipient_token_address, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
-        data,
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let name = "USDT ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "USDT_ETH".to_string();
+    let ever_decimals = 18;
+    let solana_decimals = 9;
+
+    let token_defaults = TokenDefaults {
+        deposit_limit: 1_000_000,
+        withdrawal_limit: 100_000,
+        withdrawal_daily_limit: 500_000,
+        deposit_daily_limit: None,
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
     };
 
-    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
-    transaction.sign(&[&funder], recent_blockhash);
+    let mut transaction = Transaction::new_with_payer(
+        &[register_token_ever_ix(
+            manager.pubkey(),
+            token,
+            name.clone(),
+            symbol.clone(),
+            ever_decimals,
+            solana_decimals,
+            Some(token_defaults),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    // Check Proxy Balance
-    let proxy_info = banks_client
-        .get_account(proxy_address)
+    let mint_address = get_mint_address(&token);
+    let mint_info = banks_client
+        .get_account(mint_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
-    assert_eq!(proxy_data.amount, 0);
+    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
+    assert_eq!(mint_data.decimals, solana_decimals);
+    assert_eq!(
+        mint_data.mint_authority,
+        program_option::COption::Some(mint_address)
+    );
 
-    // Check Proxy Balance
-    let recipient_token_info = banks_client
-        .get_account(recipient_token_address)
+    let token_settings_address = get_token_settings_ever_address(&token);
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let recipient_token_data =
-        spl_token::state::Account::unpack(recipient_token_info.data()).expect("proxy unpack");
-    assert_eq!(recipient_token_data.amount, transfer_amount);
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
 
-    // Check status
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
+    assert_eq!(token_settings_data.name, name);
+    assert_eq!(token_settings_data.symbol, symbol);
+    assert_eq!(
+        token_settings_data.kind,
+        TokenKind::Ever {
+            mint: mint_address,
+            token,
+            decimals: ever_decimals,
+        }
+    );
+    assert_eq!(
+        token_settings_data.deposit_limit,
+        token_defaults.deposit_limit
+    );
+    assert_eq!(
+        token_settings_data.withdrawal_limit,
+        token_defaults.withdrawal_limit
+    );
+    assert_eq!(
+        token_settings_data.withdrawal_daily_limit,
+        token_defaults.withdrawal_daily_limit
+    );
+}
+
+#[tokio::test]
+async fn test_register_token_ever_with_decimals_override() {
+    // Setup environment
+    let manager = Keypair::new();
+
+    let settings_account_data =
+        bridge_test_utils::settings(Pubkey::new_unique(), manager.pubkey(), Pubkey::new_unique());
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_funded_account(manager.pubkey(), 1_000_000_000);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    // Override decimals and register token with different ones
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            change_token_decimals_ix(manager.pubkey(), token, 6),
+            register_token_ever_ix(
+                manager.pubkey(),
+                token,
+                "WETH ETHEREUM OCTUSBRIDGE".to_string(),
+                "WETH".to_string(),
+                18,
+                9,
+                None,
+            ),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Mint Decimals
+    let mint_info = banks_client
+        .get_account(get_mint_address(&token))
         .await
         .expect("get_account")
         .expect("account");
 
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
-
-    assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Processed
-    );
+    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
+    assert_eq!(mint_data.decimals, 6);
 }
 
 #[tokio::test]
-async fn test_withdraw_sol_with_payload_unwrap() {
+async fn test_change_deposit_limit() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -7209,9 +9748,11 @@ async fn test_withdraw_sol_with_payload_unwrap() {
     // Setup environment
 
     // Add Settings Account
+    let manager = Keypair::new();
+
     let guardian = Pubkey::new_unique();
-    let manager = Pubkey::new_unique();
     let withdrawal_manager = Pubkey::new_unique();
+
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
     let settings_address = get_settings_address();
@@ -7220,9 +9761,26 @@ async fn test_withdraw_sol_with_payload_unwrap() {
         is_initialized: true,
         account_kind: AccountKind::Settings(settings_nonce, 0),
         emergency: false,
+        manager: manager.pubkey(),
         guardian,
-        manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -7238,146 +9796,244 @@ async fn test_withdraw_sol_with_payload_unwrap() {
         },
     );
 
-    // Add Round Loader Settings Account
-    let round_number = 12;
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
 
-    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let token_hash = hash(&token.try_to_vec().unwrap());
 
-    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
 
-    let round_ttl = 1209600;
-    let rl_settings_account_data = round_loader::Settings {
+    let mint_address = get_mint_address(&token);
+
+    let mint_account_data = spl_token::state::Mint {
         is_initialized: true,
-        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
-        current_round_number: round_number,
-        round_submitter: Pubkey::new_unique(),
-        min_required_votes: 1,
-        round_ttl: 0,
+        mint_authority: program_option::COption::Some(mint_address),
+        supply: 100,
+        decimals,
+        ..Default::default()
     };
 
-    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
-    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
     program_test.add_account(
-        rl_settings_address,
+        mint_address,
         Account {
-            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
-            data: rl_settings_packed,
-            owner: round_loader::id(),
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
             executable: false,
-            rent_epoch: 0,
+            rent_epoch: 1,
         },
     );
 
-    // Add Relay Accounts
-    let relays = vec![
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-    ];
-
-    for relay in &relays {
-        program_test.add_account(
-            relay.pubkey(),
-            Account {
-                lamports: 1_000_000_000,
-                data: vec![],
-                owner: solana_program::system_program::id(),
-                executable: false,
-                rent_epoch: 0,
-            },
-        );
-    }
-
-    // Add Relay Round Account
-    let relay_round_address =
-        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
-
-    let (_, relay_round_nonce) = Pubkey::find_program_address(
-        &[br"relay_round", &round_number.to_le_bytes()],
-        &round_loader::id(),
-    );
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
 
-    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
+    let token_settings_address = get_token_settings_ever_address(&token);
 
-    let relay_round_data = round_loader::RelayRound {
+    let token_settings_account_data = TokenSettings {
         is_initialized: true,
-        account_kind: AccountKind::RelayRound(relay_round_nonce),
-        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
-        round_number,
-        round_end,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind: TokenKind::Ever {
+            mint: mint_address,
+            token,
+            decimals,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
-    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
-    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
-
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
-        relay_round_address,
+        token_settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
-            data: relay_round_packed,
-            owner: round_loader::id(),
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Mint Account
-    let mint_address = NATIVE_MINT;
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    // Add Vault Account
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+    let new_deposit_limit = 1_000_000;
 
-    let vault_address = get_vault_address(&mint_address);
+    let mut transaction = Transaction::new_with_payer(
+        &[change_deposit_limit_ix(
+            manager.pubkey(),
+            token_settings_address,
+            new_deposit_limit,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
 
-    let vault_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: vault_address,
-        state: AccountState::Initialized,
-        amount: 1000,
-        ..Default::default()
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.deposit_limit, new_deposit_limit);
+}
+
+#[tokio::test]
+async fn test_change_deposit_daily_limit() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        manager: manager.pubkey(),
+        guardian,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
-    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
     program_test.add_account(
-        vault_address,
+        settings_address,
         Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: vault_packed,
-            owner: spl_token::id(),
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Token Settings Account
-    let symbol = "wSOL".to_string();
-    let name = "Wrapped SOL".to_string();
-    let deposit_limit = 10_000_000;
-    let withdrawal_limit = 10_000;
-    let withdrawal_daily_limit = 1_000;
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
 
-    let (_, token_settings_nonce) = Pubkey::find_program_address(
-        &[br"settings", &mint_address.to_bytes()],
-        &token_proxy::id(),
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let token_hash = hash(&token.try_to_vec().unwrap());
+
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+
+    let mint_address = get_mint_address(&token);
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        supply: 100,
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
     );
 
-    let token_settings_address = get_token_settings_sol_address(&mint_address);
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_ever_address(&token);
 
     let token_settings_account_data = TokenSettings {
         is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-        kind: TokenKind::Solana {
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind: TokenKind::Ever {
             mint: mint_address,
-            vault: vault_address,
+            token,
+            decimals,
         },
         name,
         symbol,
@@ -7390,10 +10046,28 @@ async fn test_withdraw_sol_with_payload_unwrap() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
-    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
-
     let mut token_settings_packed = vec![0; TokenSettings::LEN];
     TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
@@ -7407,282 +10081,10313 @@ async fn test_withdraw_sol_with_payload_unwrap() {
         },
     );
 
-    // Add Recipient Token Account
-    let recipient = Keypair::new();
-
-    // Add Author Account
-    let author = Keypair::new();
-    program_test.add_account(
-        author.pubkey(),
-        Account {
-            lamports: 1_000_000_000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    for i in 0..5 {
-        // Create withdrawal request
-        let event_timestamp = 1650988297 + i;
-        let event_transaction_lt = 1650988334;
-        let event_configuration = Pubkey::new_unique();
+    let new_deposit_daily_limit = Some(1_000_000);
 
-        let amount = 32;
+    let mut transaction = Transaction::new_with_payer(
+        &[change_deposit_daily_limit_ix(
+            manager.pubkey(),
+            token_settings_address,
+            new_deposit_daily_limit,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
 
-        let (proxy_address, proxy_nonce) = Pubkey::find_program_address(
-            &[
-                br"proxy",
-                &mint_address.to_bytes(),
-                &recipient.pubkey().to_bytes(),
-            ],
-            &id(),
-        );
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
-        let payload = bincode::serialize(&vec![spl_token::instruction::close_account(
-            &spl_token::id(),
-            &proxy_address,
-            &recipient.pubkey(),
-            &proxy_address,
-            &[],
-        )
-        .unwrap()])
-        .unwrap();
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-        let attached_amount = 0;
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
 
-        let mut transaction = Transaction::new_with_payer(
-            &[withdrawal_multi_token_sol_request_ix(
-                funder.pubkey(),
-                author.pubkey(),
-                event_timestamp,
-                event_transaction_lt,
-                event_configuration,
-                mint_address,
-                round_number,
-                recipient.pubkey(),
-                amount,
-                payload.clone(),
-                attached_amount,
-            )],
-            Some(&funder.pubkey()),
-        );
-        transaction.sign(&[&funder, &author], recent_blockhash);
+    assert_eq!(
+        token_settings_data.deposit_daily_limit,
+        new_deposit_daily_limit
+    );
+}
 
-        banks_client
-            .process_transaction(transaction)
-            .await
-            .expect("process_transaction");
+#[tokio::test]
+async fn test_change_withdrawal_limits() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
 
-        // Check Withdrawal Account
-        let withdrawal_address = get_withdrawal_sol_address(
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            &event_configuration,
-            mint_address,
-            recipient.pubkey(),
-            amount,
-            payload,
-        );
-        let withdrawal_info = banks_client
-            .get_account(withdrawal_address)
-            .await
-            .expect("get_account")
-            .expect("account");
+    // Setup environment
+    let owner = Keypair::new();
 
-        let withdrawal_data = WithdrawalMultiTokenSol::unpack(withdrawal_info.data())
-            .expect("withdrawal token unpack");
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
 
-        assert_eq!(withdrawal_data.is_initialized, true);
-        assert_eq!(withdrawal_data.author, author.pubkey());
-        assert_eq!(withdrawal_data.round_number, round_number);
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
 
-        assert_eq!(
-            withdrawal_data.required_votes,
-            (relays.len() * 2 / 3 + 1) as u32
-        );
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
 
-        assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
-        assert_eq!(
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let manager = Pubkey::new_unique();
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        manager,
+        guardian,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let token_hash = hash(&token.try_to_vec().unwrap());
+
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+
+    let mint_address = get_mint_address(&token);
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        supply: 100,
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_ever_address(&token);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind: TokenKind::Ever {
+            mint: mint_address,
+            token,
+            decimals,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let new_withdrawal_limit = 10000000;
+    let new_withdrawal_daily_limit = 12345;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[change_withdrawal_limits_by_owner_ix(
+            owner.pubkey(),
+            token_settings_address,
+            Some(new_withdrawal_limit),
+            Some(new_withdrawal_daily_limit),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.withdrawal_limit, new_withdrawal_limit);
+    assert_eq!(
+        token_settings_data.withdrawal_daily_limit,
+        new_withdrawal_daily_limit
+    );
+}
+
+#[tokio::test]
+async fn test_change_withdrawal_epoch_length() {
+    // Setup environment
+    let manager = Keypair::new();
+
+    let settings_account_data =
+        bridge_test_utils::settings(Pubkey::new_unique(), manager.pubkey(), Pubkey::new_unique());
+
+    let mint = Pubkey::new_unique();
+    let token_settings_address = get_token_settings_sol_address(&mint);
+
+    let mut token_settings_account_data = bridge_test_utils::sol_token_settings(mint);
+    token_settings_account_data.withdrawal_epoch = 19000;
+    token_settings_account_data.withdrawal_daily_amount = 100;
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_sol_token(token_settings_account_data, 9, 0)
+        .with_funded_account(manager.pubkey(), 1_000_000_000);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let new_withdrawal_epoch_length = 3600;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[change_withdrawal_epoch_length_ix(
+            manager.pubkey(),
+            token_settings_address,
+            Some(new_withdrawal_epoch_length),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Amount counted in the old epoch is reset
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(
+        token_settings_data.withdrawal_epoch_length,
+        Some(new_withdrawal_epoch_length)
+    );
+    assert_eq!(token_settings_data.withdrawal_epoch, 0);
+    assert_eq!(token_settings_data.withdrawal_daily_amount, 0);
+}
+
+#[tokio::test]
+async fn test_enable_emergency() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let guardian = Keypair::new();
+    let manager = Pubkey::new_unique();
+
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian: guardian.pubkey(),
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Expiry in the past is rejected
+    let mut transaction = Transaction::new_with_payer(
+        &[enable_emergency_ix(guardian.pubkey(), Some(1), Some(1))],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[enable_emergency_ix(guardian.pubkey(), None, Some(1))],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.emergency, true);
+    assert_eq!(settings_data.emergency_expiry, None);
+
+    let emergency_info = settings_data.emergency_info.expect("emergency info");
+    assert_eq!(emergency_info.authority, guardian.pubkey());
+    assert_eq!(emergency_info.reason, Some(1));
+}
+
+#[tokio::test]
+async fn test_enable_emergency_by_owner() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[enable_emergency_by_owner_ix(owner.pubkey(), None, None)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.emergency, true);
+}
+
+#[tokio::test]
+async fn test_disable_emergency() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: true,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disable_emergency_ix(owner.pubkey(), None)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.emergency, false);
+}
+
+#[tokio::test]
+async fn test_enable_token_emergency() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let guardian = Keypair::new();
+    let manager = Pubkey::new_unique();
+
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian: guardian.pubkey(),
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let mint = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_sol_address(&mint);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, 0),
+        kind: TokenKind::Solana { mint, vault },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[enable_emergency_token_ix(
+            guardian.pubkey(),
+            token_settings_address,
+            None,
+            None,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.emergency, true);
+}
+
+#[tokio::test]
+async fn test_pause_token() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let guardian = Keypair::new();
+    let manager = Keypair::new();
+
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian: guardian.pubkey(),
+        manager: manager.pubkey(),
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let mint = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_sol_address(&mint);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, 0),
+        kind: TokenKind::Solana { mint, vault },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[pause_token_ix(
+            guardian.pubkey(),
+            token_settings_address,
+            true,
+            true,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.deposits_paused, true);
+    assert_eq!(token_settings_data.withdrawals_paused, true);
+
+    // Resume withdrawals only
+    let mut transaction = Transaction::new_with_payer(
+        &[unpause_token_ix(
+            manager.pubkey(),
+            token_settings_address,
+            false,
+            true,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.deposits_paused, true);
+    assert_eq!(token_settings_data.withdrawals_paused, false);
+}
+
+#[tokio::test]
+async fn test_pause_bridge() {
+    // Setup environment
+    let owner = Keypair::new();
+    let manager = Keypair::new();
+    let extra_guardian = Keypair::new();
+    let sender = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let mut settings_account_data =
+        bridge_test_utils::settings(Pubkey::new_unique(), manager.pubkey(), Pubkey::new_unique());
+    settings_account_data.account_kind = AccountKind::Settings(settings_nonce, programdata_nonce);
+
+    let mint = Pubkey::new_unique();
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_sol_token(bridge_test_utils::sol_token_settings(mint), 9, 0)
+        .with_account(
+            programdata_address,
+            Account {
+                lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+                data: programdata_data_serialized,
+                owner: token_proxy::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .with_funded_account(owner.pubkey(), 1_000_000_000)
+        .with_funded_account(manager.pubkey(), 1_000_000_000)
+        .with_funded_account(extra_guardian.pubkey(), 1_000_000_000)
+        .with_funded_account(sender.pubkey(), 1_000_000_000)
+        .with_associated_token_account(sender.pubkey(), mint, 200);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    // Guardians list is bounded
+    let mut transaction = Transaction::new_with_payer(
+        &[change_guardians_ix(
+            owner.pubkey(),
+            vec![Pubkey::new_unique(); MAX_GUARDIANS_LEN + 1],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[change_guardians_ix(
+            owner.pubkey(),
+            vec![extra_guardian.pubkey()],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Extra guardian pauses deposits of all tokens
+    let mut transaction = Transaction::new_with_payer(
+        &[pause_bridge_ix(extra_guardian.pubkey(), true, false)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &extra_guardian], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(get_settings_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.guardians, vec![extra_guardian.pubkey()]);
+    assert!(settings_data.deposits_paused);
+    assert!(!settings_data.withdrawals_paused);
+
+    let sender_token_address =
+        spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint);
+
+    let deposit_ix = || {
+        deposit_multi_token_sol_ix(
+            funder.pubkey(),
+            sender.pubkey(),
+            sender_token_address,
+            mint,
+            uuid::Uuid::new_v4().as_u128(),
+            "USDC ETHEREUM OCTUSBRIDGE".to_string(),
+            "USDC".to_string(),
+            100,
+            EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+            1000,
+            UInt256::default(),
+            vec![],
+        )
+    };
+
+    let mut transaction = Transaction::new_with_payer(&[deposit_ix()], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Manager resumes deposits
+    let mut transaction = Transaction::new_with_payer(
+        &[unpause_bridge_ix(manager.pubkey(), true, false)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let mut transaction = Transaction::new_with_payer(&[deposit_ix()], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+}
+
+#[tokio::test]
+async fn test_disable_token_emergency() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: true,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let mint = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_sol_address(&mint);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, programdata_nonce),
+        kind: TokenKind::Solana { mint, vault },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: true,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[disable_emergency_token_ix(
+            owner.pubkey(),
+            token_settings_address,
+            None,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.emergency, false);
+}
+
+#[tokio::test]
+async fn test_delist_token() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let mint = Pubkey::new_unique();
+    let vault = Pubkey::new_unique();
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_sol_address(&mint);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, programdata_nonce),
+        kind: TokenKind::Solana { mint, vault },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[delist_token_ix(owner.pubkey(), token_settings_address)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.delisted, true);
+}
+
+#[tokio::test]
+async fn test_close_token() {
+    // Setup environment
+    let owner = Keypair::new();
+    let manager = Keypair::new();
+    let sender = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let mut settings_account_data =
+        bridge_test_utils::settings(Pubkey::new_unique(), manager.pubkey(), Pubkey::new_unique());
+    settings_account_data.account_kind = AccountKind::Settings(settings_nonce, programdata_nonce);
+
+    // Delisted tokens, one of them with a withdrawal waiting for liquidity
+    let mint = Pubkey::new_unique();
+    let pending_mint = Pubkey::new_unique();
+
+    let mut token_settings_account_data = bridge_test_utils::sol_token_settings(mint);
+    token_settings_account_data.delisted = true;
+
+    let mut pending_token_settings_account_data =
+        bridge_test_utils::sol_token_settings(pending_mint);
+    pending_token_settings_account_data.delisted = true;
+    pending_token_settings_account_data.pending_withdrawals = 1;
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_sol_token(token_settings_account_data, 9, 0)
+        .with_sol_token(pending_token_settings_account_data, 9, 0)
+        .with_account(
+            programdata_address,
+            Account {
+                lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+                data: programdata_data_serialized,
+                owner: token_proxy::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .with_funded_account(owner.pubkey(), 1_000_000_000)
+        .with_funded_account(manager.pubkey(), 1_000_000_000)
+        .with_funded_account(sender.pubkey(), 1_000_000_000)
+        .with_associated_token_account(sender.pubkey(), mint, 100);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    // Token with pending withdrawals can't be closed
+    let mut transaction = Transaction::new_with_payer(
+        &[close_token_sol_ix(owner.pubkey(), pending_mint)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_token_sol_ix(owner.pubkey(), mint)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Vault is closed, Token Settings are kept
+    let vault_info = banks_client
+        .get_account(get_vault_address(&mint))
+        .await
+        .expect("get_account");
+
+    assert!(vault_info.is_none());
+
+    let token_settings_address = get_token_settings_sol_address(&mint);
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert!(token_settings_data.closed);
+    assert!(token_settings_data.deposits_paused);
+    assert!(token_settings_data.withdrawals_paused);
+
+    // Closed token can't be resumed or listed again by deposit
+    let mut transaction = Transaction::new_with_payer(
+        &[unpause_token_ix(
+            manager.pubkey(),
+            token_settings_address,
+            true,
+            true,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[deposit_multi_token_sol_ix(
+            funder.pubkey(),
+            sender.pubkey(),
+            spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint),
+            mint,
+            uuid::Uuid::new_v4().as_u128(),
+            "USDC ETHEREUM OCTUSBRIDGE".to_string(),
+            "USDC".to_string(),
+            100,
+            EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+            1000,
+            UInt256::default(),
+            vec![],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_propose_mint_authority() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let mint = get_mint_address(&token);
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let token_hash = hash(&token.try_to_vec().unwrap());
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_ever_address(&token);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind: TokenKind::Ever {
+            mint,
+            token,
+            decimals: 9,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let new_authority = Pubkey::new_unique();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[propose_mint_authority_ix(
+            owner.pubkey(),
+            token,
+            Some(new_authority),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    let authority_migration = token_settings_data
+        .authority_migration
+        .expect("authority migration");
+    assert_eq!(authority_migration.new_authority, new_authority);
+    assert!(authority_migration.unlock_timestamp >= AUTHORITY_MIGRATION_TIMELOCK);
+}
+
+#[tokio::test]
+async fn test_propose_vault_authority() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let mint = Pubkey::new_unique();
+    let vault = get_vault_address(&mint);
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint.to_bytes()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_sol_address(&mint);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana { mint, vault },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let new_authority = Pubkey::new_unique();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[propose_vault_authority_ix(
+            owner.pubkey(),
+            mint,
+            Some(new_authority),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    let authority_migration = token_settings_data
+        .authority_migration
+        .expect("authority migration");
+    assert_eq!(authority_migration.new_authority, new_authority);
+    assert!(authority_migration.unlock_timestamp >= AUTHORITY_MIGRATION_TIMELOCK);
+}
+
+#[tokio::test]
+async fn test_create_approval_queue() {
+    // Lamports sent to queue address in advance don't prevent creation
+    let test_bridge = TestBridge::new().with_funded_account(get_approval_queue_address(), 1);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_approval_queue_ix(funder.pubkey())],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let approval_queue_info = banks_client
+        .get_account(get_approval_queue_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(approval_queue_info.owner, token_proxy::id());
+
+    let approval_queue_data =
+        ApprovalQueue::unpack(approval_queue_info.data()).expect("approval queue unpack");
+
+    assert!(approval_queue_data.is_initialized);
+    assert!(approval_queue_data.approvals.is_empty());
+}
+
+#[tokio::test]
+async fn test_approve_withdrawal_ever() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let withdrawal_manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager: withdrawal_manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let token_hash = hash(&token.try_to_vec().unwrap());
+
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+
+    let mint_address = get_mint_address(&token);
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
+
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_ever_address(&token);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind: TokenKind::Ever {
+            mint: mint_address,
+            token,
+            decimals,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "USDC".to_string();
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_ever_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        token,
+        name.clone(),
+        symbol.clone(),
+        decimals,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenEverEventWithLen::new(
+        token, name, symbol, decimals, amount, recipient, payload,
+    );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let signers = vec![Vote::Confirm; 3];
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenEver {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenEver::LEN];
+    WithdrawalMultiTokenEver::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenEver::LEN)
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Approval Queue Account
+    let (_, approval_queue_nonce) =
+        Pubkey::find_program_address(&[br"approval_queue"], &token_proxy::id());
+
+    let approval_queue_account_data = ApprovalQueue {
+        is_initialized: true,
+        account_kind: AccountKind::ApprovalQueue(approval_queue_nonce),
+        approvals: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut approval_queue_packed = vec![0; ApprovalQueue::LEN];
+    ApprovalQueue::pack(approval_queue_account_data, &mut approval_queue_packed).unwrap();
+    program_test.add_account(
+        get_approval_queue_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(ApprovalQueue::LEN),
+            data: approval_queue_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_withdrawal_ever_ix(
+            withdrawal_manager.pubkey(),
+            withdrawal_address,
+            token_wallet,
+            recipient,
+            mint_address,
+            &token,
+            None,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee;
+
+    let mint_info = banks_client
+        .get_account(mint_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
+    assert_eq!(mint_data.supply, transfer_amount as u64);
+
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
+    assert_eq!(recipient_data.amount, transfer_amount as u64);
+}
+
+#[tokio::test]
+async fn test_approve_withdrawal_sol() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let withdrawal_manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager: withdrawal_manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        amount: 100,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
+
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let signers = vec![Vote::Confirm; 3];
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Approval Queue Account
+    let (_, approval_queue_nonce) =
+        Pubkey::find_program_address(&[br"approval_queue"], &token_proxy::id());
+
+    let approval_queue_account_data = ApprovalQueue {
+        is_initialized: true,
+        account_kind: AccountKind::ApprovalQueue(approval_queue_nonce),
+        approvals: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut approval_queue_packed = vec![0; ApprovalQueue::LEN];
+    ApprovalQueue::pack(approval_queue_account_data, &mut approval_queue_packed).unwrap();
+    program_test.add_account(
+        get_approval_queue_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(ApprovalQueue::LEN),
+            data: approval_queue_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_withdrawal_sol_ix(
+            withdrawal_manager.pubkey(),
+            withdrawal_address,
+            token_wallet,
+            recipient,
+            mint_address,
+            None,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("settings unpack");
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee;
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("mint unpack");
+    assert_eq!(vault_data.amount, 100 - transfer_amount as u64);
+
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
+    assert_eq!(recipient_data.amount, transfer_amount as u64);
+}
+
+#[tokio::test]
+async fn test_approve_withdrawal_sol_with_empty_vault() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let withdrawal_manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager: withdrawal_manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
+
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let signers = vec![Vote::Confirm; 3];
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Approval Queue Account
+    let (_, approval_queue_nonce) =
+        Pubkey::find_program_address(&[br"approval_queue"], &token_proxy::id());
+
+    let approval_queue_account_data = ApprovalQueue {
+        is_initialized: true,
+        account_kind: AccountKind::ApprovalQueue(approval_queue_nonce),
+        approvals: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut approval_queue_packed = vec![0; ApprovalQueue::LEN];
+    ApprovalQueue::pack(approval_queue_account_data, &mut approval_queue_packed).unwrap();
+    program_test.add_account(
+        get_approval_queue_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(ApprovalQueue::LEN),
+            data: approval_queue_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[approve_withdrawal_sol_ix(
+            withdrawal_manager.pubkey(),
+            withdrawal_address,
+            token_wallet,
+            recipient,
+            mint_address,
+            None,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("settings unpack");
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Pending
+    );
+
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("mint unpack");
+    assert_eq!(vault_data.amount, 0);
+
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
+    assert_eq!(recipient_data.amount, 0);
+}
+
+#[tokio::test]
+async fn test_reject_withdrawal_sol() {
+    // Setup environment
+    let withdrawal_manager = Keypair::new();
+    let author = Keypair::new();
+
+    let settings_account_data = bridge_test_utils::settings(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        withdrawal_manager.pubkey(),
+    );
+
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    let amount = 32;
+
+    let mut token_settings_account_data = bridge_test_utils::sol_token_settings(mint);
+    token_settings_account_data.withdrawal_epoch_length = Some(i64::MAX);
+
+    let fee = token_settings_account_data
+        .withdrawal_fee(amount as u64)
+        .unwrap();
+    let transfer_amount = amount as u64 - fee;
+
+    // Withdrawal amount is counted in daily amount when it is queued for approve
+    token_settings_account_data.withdrawal_daily_amount = transfer_amount;
+
+    // Add Withdrawal Account
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let signers = vec![Vote::Confirm; 3];
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: signers.len() as u32,
+        signers,
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::WaitingForApprove;
+
+    // Add Approval Queue Account
+    let (_, approval_queue_nonce) =
+        Pubkey::find_program_address(&[br"approval_queue"], &token_proxy::id());
+
+    let approval_queue_account_data = ApprovalQueue {
+        is_initialized: true,
+        account_kind: AccountKind::ApprovalQueue(approval_queue_nonce),
+        approvals: vec![Approval {
+            withdrawal: withdrawal_address,
+            recipient,
+            amount: transfer_amount,
+            status: WithdrawalTokenStatus::WaitingForApprove,
+            note: None,
+        }],
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_sol_token(token_settings_account_data, 9, 0)
+        .with_multivault()
+        .with_packed_account(
+            withdrawal_address,
+            token_proxy::id(),
+            withdrawal_account_data,
+        )
+        .with_packed_account(
+            get_approval_queue_address(),
+            token_proxy::id(),
+            approval_queue_account_data,
+        );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    // Executor can't skip the approval queue
+    let mut ix =
+        reject_withdrawal_sol_ix(withdrawal_manager.pubkey(), withdrawal_address, mint, None);
+    ix.accounts
+        .retain(|account| account.pubkey != get_approval_queue_address());
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Note is limited in length
+    let ix = reject_withdrawal_sol_ix(
+        withdrawal_manager.pubkey(),
+        withdrawal_address,
+        mint,
+        Some(vec![0; MAX_APPROVAL_NOTE_LEN + 1]),
+    );
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let note = b"Recipient is under review".to_vec();
+
+    let ix = reject_withdrawal_sol_ix(
+        withdrawal_manager.pubkey(),
+        withdrawal_address,
+        mint,
+        Some(note.clone()),
+    );
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Cancelled
+    );
+    assert!(withdrawal_data.meta.data.rejected);
+
+    // Check Token Settings Account
+    let token_settings_info = banks_client
+        .get_account(get_token_settings_sol_address(&mint))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.withdrawal_daily_amount, 0);
+
+    // Check Approval Queue Account
+    let approval_queue_info = banks_client
+        .get_account(get_approval_queue_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let approval_queue_data =
+        ApprovalQueue::unpack(approval_queue_info.data()).expect("approval queue unpack");
+
+    assert_eq!(approval_queue_data.approvals.len(), 1);
+    assert_eq!(
+        approval_queue_data.approvals[0].status,
+        WithdrawalTokenStatus::Cancelled
+    );
+    assert_eq!(approval_queue_data.approvals[0].note, Some(note));
+
+    // Rejected withdrawal can not be rejected again
+    let mut transaction = Transaction::new_with_payer(
+        &[reject_withdrawal_sol_ix(
+            withdrawal_manager.pubkey(),
+            withdrawal_address,
+            mint,
+            None,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Author returns rejected withdrawal back to Everscale
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+    let ever_recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_withdrawal_sol_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            withdrawal_address,
+            mint,
+            deposit_seed,
+            ever_recipient,
+            0,
+            Default::default(),
+            Default::default(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Cancelled
+    );
+    assert!(!withdrawal_data.meta.data.rejected);
+
+    // Check Deposit Account
+    let deposit_info = banks_client
+        .get_account(get_deposit_address(deposit_seed))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let deposit_data = DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit unpack");
+
+    assert_eq!(deposit_data.event.data.recipient, ever_recipient);
+    assert_eq!(deposit_data.event.data.gross_amount, amount);
+
+    // Rejected withdrawal can be returned only once
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_withdrawal_sol_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            withdrawal_address,
+            mint,
+            uuid::Uuid::new_v4().as_u128(),
+            ever_recipient,
+            0,
+            Default::default(),
+            Default::default(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+}
+
+#[tokio::test]
+async fn test_update_fee() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let mint_address = Pubkey::new_unique();
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let multiplier = 1;
+    let divisor = 100;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_fee_ix(
+            manager.pubkey(),
+            token_settings_address,
+            FeeType::Deposit,
+            multiplier,
+            divisor,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.fee_deposit_info.multiplier, multiplier);
+    assert_eq!(token_settings_data.fee_deposit_info.divisor, divisor);
+}
+
+#[tokio::test]
+async fn test_update_fee_tiers() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let mint_address = Pubkey::new_unique();
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let fee_tiers = FeeTiers {
+        tiers: vec![
+            FeeTier {
+                amount_threshold: 1_000,
+                fee_info: FeeInfo {
+                    multiplier: 2,
+                    divisor: 1_000,
+                },
+            },
+            FeeTier {
+                amount_threshold: 1_000_000,
+                fee_info: FeeInfo {
+                    multiplier: 1,
+                    divisor: 1_000,
+                },
+            },
+        ],
+        min_fee: 5,
+    };
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_fee_tiers_ix(
+            manager.pubkey(),
+            token_settings_address,
+            FeeType::Deposit,
+            fee_tiers.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.fee_deposit_tiers, fee_tiers);
+
+    // Flat minimum below the first tier
+    assert_eq!(token_settings_data.deposit_fee(100), Some(5));
+    // First tier rate
+    assert_eq!(token_settings_data.deposit_fee(100_000), Some(200));
+    // Second tier rate
+    assert_eq!(token_settings_data.deposit_fee(10_000_000), Some(10_000));
+}
+
+#[tokio::test]
+async fn test_schedule_and_execute_fee_change() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let manager = Pubkey::new_unique();
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let mint_address = Pubkey::new_unique();
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let multiplier = 1;
+    let divisor = 100;
+
+    let change_seed = uuid::Uuid::new_v4().as_u128();
+    let change = TimelockedChange::Fee {
+        token_settings: token_settings_address,
+        fee_type: FeeType::Deposit,
+        multiplier,
+        divisor,
+    };
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            schedule_change_ix(funder.pubkey(), owner.pubkey(), change_seed, change.clone()),
+            execute_change_ix(funder.pubkey(), change_seed, &change),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.fee_deposit_info.multiplier, multiplier);
+    assert_eq!(token_settings_data.fee_deposit_info.divisor, divisor);
+
+    let pending_change_info = banks_client
+        .get_account(get_pending_change_address(change_seed))
+        .await
+        .expect("get_account");
+
+    assert!(pending_change_info.is_none());
+}
+
+#[tokio::test]
+async fn test_change_withdrawal_limit_exemptions_by_owner_with_timelock() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let owner = Keypair::new();
+
+    // Add Program Data Account
+    let (programdata_address, programdata_nonce) =
+        Pubkey::find_program_address(&[token_proxy::id().as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(owner.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let manager = Pubkey::new_unique();
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 3600,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let mint_address = Pubkey::new_unique();
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let exempted_recipient = Pubkey::new_unique();
+
+    // Owner can't bypass timelock
+    let mut transaction = Transaction::new_with_payer(
+        &[change_withdrawal_limit_exemptions_by_owner_ix(
+            owner.pubkey(),
+            token_settings_address,
+            vec![exempted_recipient],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Other owner paths are locked as well
+    let mut transaction = Transaction::new_with_payer(
+        &[set_treasury_ix(owner.pubkey(), Some(Pubkey::new_unique()))],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[change_deposit_limit_by_owner_ix(
+            owner.pubkey(),
+            token_settings_address,
+            0,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Schedule the same change
+    let change_seed = uuid::Uuid::new_v4().as_u128();
+    let change = TimelockedChange::WithdrawalLimitExemptions {
+        token_settings: token_settings_address,
+        new_withdrawal_limit_exemptions: vec![exempted_recipient],
+    };
+
+    let mut transaction = Transaction::new_with_payer(
+        &[schedule_change_ix(
+            funder.pubkey(),
+            owner.pubkey(),
+            change_seed,
+            change.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &owner], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Execution waits for the delay
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_change_ix(funder.pubkey(), change_seed, &change)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert!(token_settings_data.withdrawal_limit_exemptions.is_empty());
+}
+
+#[tokio::test]
+async fn test_update_token_name() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let mint_address = Pubkey::new_unique();
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let new_symbol = "newUSDT".to_string();
+    let new_name = "New USDT Solana Octusbridge".to_string();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_token_name_ix(
+            manager.pubkey(),
+            token_settings_address,
+            new_symbol.clone(),
+            new_name.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.symbol, new_symbol);
+    assert_eq!(token_settings_data.name, new_name);
+}
+
+#[tokio::test]
+async fn test_withdrawal_ever_fee() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let token_hash = hash(&token.try_to_vec().unwrap());
+
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+
+    let mint_address = get_mint_address(&token);
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
+
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_ever_address(&token);
+
+    let fee_supply = 1_000_000;
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind: TokenKind::Ever {
+            mint: mint_address,
+            token,
+            decimals,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply,
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: FeeInfo {
+            multiplier: 5,
+            divisor: 10_000,
+        },
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_ever_fee_ix(
+            manager.pubkey(),
+            mint_address,
+            token_wallet,
+            &token,
+            fee_supply,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let mint_info = banks_client
+        .get_account(mint_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
+    assert_eq!(mint_data.supply, fee_supply);
+
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
+    assert_eq!(recipient_data.amount, fee_supply);
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.fee_supply, 0);
+}
+
+#[tokio::test]
+async fn test_create_fee_ledger() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let token_hash = hash(&token.try_to_vec().unwrap());
+
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+
+    let mint_address = get_mint_address(&token);
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
+
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_ever_address(&token);
+
+    let fee_supply = 1_000_000;
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind: TokenKind::Ever {
+            mint: mint_address,
+            token,
+            decimals,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply,
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: FeeInfo {
+            multiplier: 5,
+            divisor: 10_000,
+        },
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Lamports sent to the fee ledger address don't prevent its creation
+    program_test.add_account(
+        get_fee_ledger_address(&token_settings_address),
+        Account {
+            lamports: 1,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_fee_ledger_ix(
+            funder.pubkey(),
+            token_settings_address,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.fee_supply, 0);
+
+    let fee_ledger_address = get_fee_ledger_address(&token_settings_address);
+
+    let fee_ledger_info = banks_client
+        .get_account(fee_ledger_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let fee_ledger_data = FeeLedger::unpack(fee_ledger_info.data()).expect("fee ledger unpack");
+
+    assert!(fee_ledger_data.is_initialized);
+    assert_eq!(fee_ledger_data.token_settings, token_settings_address);
+    assert_eq!(fee_ledger_data.supply, fee_supply);
+
+    // Withdraw fee from the ledger
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_ever_fee_ix(
+            manager.pubkey(),
+            mint_address,
+            token_wallet,
+            &token,
+            fee_supply,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("token unpack");
+    assert_eq!(recipient_data.amount, fee_supply);
+
+    let fee_ledger_info = banks_client
+        .get_account(fee_ledger_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let fee_ledger_data = FeeLedger::unpack(fee_ledger_info.data()).expect("fee ledger unpack");
+
+    assert_eq!(fee_ledger_data.supply, 0);
+}
+
+#[tokio::test]
+async fn test_withdrawal_sol_fee() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    let fee_supply = 100;
+
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        amount: fee_supply,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
+
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply,
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: FeeInfo {
+            multiplier: 1,
+            divisor: 1,
+        },
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_sol_fee_ix(
+            manager.pubkey(),
+            token_wallet,
+            mint_address,
+            fee_supply,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+
+    assert_eq!(vault_data.amount, 0);
+
+    // Check Recipient Balance
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
+
+    assert_eq!(recipient_data.amount, fee_supply);
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.fee_supply, 0);
+}
+
+#[tokio::test]
+async fn test_min_deposit_amount() {
+    // Setup environment
+    let manager = Keypair::new();
+    let sender = Keypair::new();
+
+    let settings_account_data =
+        bridge_test_utils::settings(Pubkey::new_unique(), manager.pubkey(), Pubkey::new_unique());
+
+    let mint = Pubkey::new_unique();
+    let token_settings_address = get_token_settings_sol_address(&mint);
+
+    let sender_token_address =
+        spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint);
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_sol_token(bridge_test_utils::sol_token_settings(mint), 9, 0)
+        .with_funded_account(manager.pubkey(), 1_000_000_000)
+        .with_funded_account(sender.pubkey(), 1_000_000_000)
+        .with_associated_token_account(sender.pubkey(), mint, 100);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    // Change minimum deposit amount
+    let min_deposit_amount = 50;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[change_min_deposit_ix(
+            manager.pubkey(),
+            token_settings_address,
+            min_deposit_amount,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.min_deposit_amount, min_deposit_amount);
+
+    // Deposit below minimum is rejected, deposit of minimum is accepted
+    for (amount, accepted) in [(min_deposit_amount - 1, false), (min_deposit_amount, true)] {
+        let mut transaction = Transaction::new_with_payer(
+            &[deposit_multi_token_sol_ix(
+                funder.pubkey(),
+                sender.pubkey(),
+                sender_token_address,
+                mint,
+                uuid::Uuid::new_v4().as_u128(),
+                "USDC ETHEREUM OCTUSBRIDGE".to_string(),
+                "USDC".to_string(),
+                amount,
+                EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+                1000,
+                UInt256::default(),
+                vec![],
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, &sender], recent_blockhash);
+
+        let result = banks_client.process_transaction(transaction).await;
+        assert_eq!(result.is_ok(), accepted);
+    }
+
+    let vault_info = banks_client
+        .get_account(get_vault_address(&mint))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, min_deposit_amount);
+}
+
+#[tokio::test]
+async fn test_deposit_multi_token_sol_with_callback() {
+    // Setup environment
+    let sender = Keypair::new();
+
+    let settings_account_data = bridge_test_utils::settings(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    let mint = Pubkey::new_unique();
+
+    let sender_token_address =
+        spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint);
+
+    let callback_program_id = Pubkey::new_unique();
+    let callback_storage_address = Pubkey::new_unique();
+
+    let mut test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_sol_token(bridge_test_utils::sol_token_settings(mint), 9, 0)
+        .with_funded_account(sender.pubkey(), 1_000_000_000)
+        .with_associated_token_account(sender.pubkey(), mint, 100)
+        .with_account(
+            callback_storage_address,
+            Account {
+                lamports: Rent::default().minimum_balance(48),
+                data: vec![0; 48],
+                owner: callback_program_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+    test_bridge.program_test_mut().add_program(
+        "deposit_callback",
+        callback_program_id,
+        processor!(process_deposit_callback),
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+
+    let mut ix = deposit_multi_token_sol_v2_ix(
+        funder.pubkey(),
+        sender.pubkey(),
+        sender_token_address,
+        mint,
+        deposit_seed,
+        "USDC ETHEREUM OCTUSBRIDGE".to_string(),
+        "USDC".to_string(),
+        50,
+        EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+        1000,
+        UInt256::default(),
+        None,
+        None,
+    );
+    set_deposit_callback(
+        &mut ix,
+        callback_program_id,
+        vec![AccountMeta::new(callback_storage_address, false)],
+    );
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let deposit_info = banks_client
+        .get_account(get_deposit_address(deposit_seed))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let deposit_data = DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit unpack");
+
+    // Check Callback Storage
+    let callback_storage_info = banks_client
+        .get_account(callback_storage_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let on_bridge_deposit =
+        OnBridgeDeposit::try_from_slice(callback_storage_info.data()).expect("callback unpack");
+
+    assert_eq!(on_bridge_deposit.deposit_seed, deposit_seed);
+    assert_eq!(
+        on_bridge_deposit.event_hash,
+        hash(&deposit_data.event.data.try_to_vec().unwrap()).to_bytes()
+    );
+
+    // Legacy instruction doesn't pass instructions sysvar
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+
+    let mut ix = deposit_multi_token_sol_v2_ix(
+        funder.pubkey(),
+        sender.pubkey(),
+        sender_token_address,
+        mint,
+        deposit_seed,
+        "USDC ETHEREUM OCTUSBRIDGE".to_string(),
+        "USDC".to_string(),
+        50,
+        EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+        1000,
+        UInt256::default(),
+        None,
+        None,
+    );
+    set_deposit_callback(
+        &mut ix,
+        callback_program_id,
+        vec![AccountMeta::new(callback_storage_address, false)],
+    );
+
+    ix.accounts
+        .retain(|account| account.pubkey != solana_program::sysvar::instructions::id());
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let callback_storage_info = banks_client
+        .get_account(callback_storage_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let on_bridge_deposit =
+        OnBridgeDeposit::try_from_slice(callback_storage_info.data()).expect("callback unpack");
+
+    assert_eq!(on_bridge_deposit.deposit_seed, deposit_seed);
+}
+
+fn process_deposit_callback(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let deposit_account_info = &accounts[0];
+    assert!(!deposit_account_info.data_is_empty());
+
+    let callback_storage_account_info = &accounts[1];
+    callback_storage_account_info
+        .data
+        .borrow_mut()
+        .copy_from_slice(instruction_data);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_multi_token_sol_with_referrer() {
+    // Setup environment
+    let sender = Keypair::new();
+    let referrer = Keypair::new();
+
+    let settings_account_data = bridge_test_utils::settings(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    let mint = Pubkey::new_unique();
+
+    let mut token_settings_account_data = bridge_test_utils::sol_token_settings(mint);
+    token_settings_account_data.fee_deposit_info = FeeInfo {
+        multiplier: 10,
+        divisor: 100,
+    };
+    token_settings_account_data.referral_fee_bps = 5_000;
+
+    let sender_token_address =
+        spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint);
+    let referrer_token_address =
+        spl_associated_token_account::get_associated_token_address(&referrer.pubkey(), &mint);
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_sol_token(token_settings_account_data, 9, 0)
+        .with_funded_account(sender.pubkey(), 1_000_000_000)
+        .with_funded_account(referrer.pubkey(), 1_000_000_000)
+        .with_associated_token_account(sender.pubkey(), mint, 100)
+        .with_associated_token_account(referrer.pubkey(), mint, 0)
+        // Lamports sent to the referral address don't prevent its creation
+        .with_funded_account(
+            get_referral_address(&get_token_settings_sol_address(&mint), &referrer.pubkey()),
+            1,
+        );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let mut ix = deposit_multi_token_sol_v2_ix(
+        funder.pubkey(),
+        sender.pubkey(),
+        sender_token_address,
+        mint,
+        uuid::Uuid::new_v4().as_u128(),
+        "USDC ETHEREUM OCTUSBRIDGE".to_string(),
+        "USDC".to_string(),
+        100,
+        EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+        1000,
+        UInt256::default(),
+        None,
+        None,
+    );
+    set_deposit_referrer(&mut ix, referrer.pubkey());
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Referral Account
+    let token_settings_address = get_token_settings_sol_address(&mint);
+    let referral_address = get_referral_address(&token_settings_address, &referrer.pubkey());
+
+    let referral_info = banks_client
+        .get_account(referral_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let referral_data = Referral::unpack(referral_info.data()).expect("referral unpack");
+
+    assert!(referral_data.is_initialized);
+    assert_eq!(referral_data.referrer, referrer.pubkey());
+    assert_eq!(referral_data.token_settings, token_settings_address);
+    assert_eq!(referral_data.amount, 5);
+
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.fee_supply, 5);
+
+    // Claim Referral Fee
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_sol_referral_fee_ix(
+            referrer.pubkey(),
+            referrer_token_address,
+            mint,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &referrer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let referrer_token_info = banks_client
+        .get_account(referrer_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let referrer_token_data =
+        spl_token::state::Account::unpack(referrer_token_info.data()).expect("token unpack");
+
+    assert_eq!(referrer_token_data.amount, 5);
+
+    let referral_info = banks_client
+        .get_account(referral_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let referral_data = Referral::unpack(referral_info.data()).expect("referral unpack");
+
+    assert_eq!(referral_data.amount, 0);
+}
+
+#[tokio::test]
+async fn test_bridge_stats() {
+    // Setup environment
+    let sender = Keypair::new();
+
+    let settings_account_data = bridge_test_utils::settings(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    let mint = Pubkey::new_unique();
+
+    let sender_token_address =
+        spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint);
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_multivault()
+        .with_sol_token(bridge_test_utils::sol_token_settings(mint), 9, 0)
+        .with_funded_account(sender.pubkey(), 1_000_000_000)
+        .with_associated_token_account(sender.pubkey(), mint, 100)
+        // Lamports sent to the stats address don't prevent its creation
+        .with_funded_account(get_bridge_stats_address(), 1);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    // Create Bridge Stats
+    let mut transaction = Transaction::new_with_payer(
+        &[create_bridge_stats_ix(funder.pubkey())],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Executor can't skip the stats account
+    let amounts = [32, 16];
+
+    for (index, amount) in amounts.into_iter().enumerate() {
+        let mut ix = deposit_multi_token_sol_ix(
+            funder.pubkey(),
+            sender.pubkey(),
+            sender_token_address,
+            mint,
+            uuid::Uuid::new_v4().as_u128(),
+            "USDC ETHEREUM OCTUSBRIDGE".to_string(),
+            "USDC".to_string(),
+            amount,
+            EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+            1000,
+            UInt256::default(),
+            vec![],
+        );
+
+        if index == 1 {
+            ix.accounts
+                .retain(|account| account.pubkey != get_bridge_stats_address());
+        }
+
+        let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+        transaction.sign(&[&funder, &sender], recent_blockhash);
+
+        let result = banks_client.process_transaction(transaction).await;
+
+        match index {
+            0 => result.expect("process_transaction"),
+            _ => assert!(result.is_err()),
+        }
+    }
+
+    let bridge_stats_info = banks_client
+        .get_account(get_bridge_stats_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let bridge_stats_data =
+        BridgeStats::unpack(bridge_stats_info.data()).expect("bridge stats unpack");
+
+    assert!(bridge_stats_data.is_initialized);
+    assert_eq!(bridge_stats_data.deposits, 1);
+    assert_eq!(bridge_stats_data.deposit_volume, amounts[0] as u128);
+    assert_eq!(bridge_stats_data.withdrawals, 0);
+    assert_eq!(bridge_stats_data.withdrawal_volume, 0);
+    assert_eq!(bridge_stats_data.pending_proposals, 0);
+}
+
+#[tokio::test]
+async fn test_withdrawal_sol_fee_to_treasury() {
+    // Setup environment
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    let mut settings_account_data =
+        bridge_test_utils::settings(guardian, manager, withdrawal_manager);
+    settings_account_data.treasury = Some(treasury);
+
+    let mint_address = Pubkey::new_unique();
+    let vault_address = get_vault_address(&mint_address);
+
+    let fee_supply = 100;
+
+    let mut token_settings_account_data = bridge_test_utils::sol_token_settings(mint_address);
+    token_settings_account_data.fee_supply = fee_supply;
+
+    let treasury_token_wallet =
+        spl_associated_token_account::get_associated_token_address(&treasury, &mint_address);
+    let recipient_token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_sol_token(token_settings_account_data, 9, fee_supply)
+        .with_associated_token_account(treasury, mint_address, 0)
+        .with_associated_token_account(recipient, mint_address, 0);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    // Fee can't be sent to other accounts without manager
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_sol_fee_ix(
+            funder.pubkey(),
+            recipient_token_wallet,
+            mint_address,
+            fee_supply,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let result = banks_client.process_transaction(transaction).await;
+    assert!(result.is_err());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_sol_fee_ix(
+            funder.pubkey(),
+            treasury_token_wallet,
+            mint_address,
+            fee_supply,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+
+    assert_eq!(vault_data.amount, 0);
+
+    // Check Treasury Balance
+    let treasury_info = banks_client
+        .get_account(treasury_token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let treasury_data =
+        spl_token::state::Account::unpack(treasury_info.data()).expect("treasury token unpack");
+
+    assert_eq!(treasury_data.amount, fee_supply);
+}
+
+#[tokio::test]
+async fn test_claim_vote_rewards() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Keypair::new();
+
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        withdrawal_manager,
+        manager: manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    let fee_supply = 100;
+    let vote_reward = 10;
+
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        amount: fee_supply,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
+
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply,
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: FeeInfo {
+            multiplier: 1,
+            divisor: 1,
+        },
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let round_number = 5;
+    let relay = Keypair::new();
+
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![relay.pubkey()],
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let mut meta = WithdrawalTokenMetaWithLen::default();
+    meta.data.status = WithdrawalTokenStatus::Processed;
+
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: Pubkey::new_unique(),
+        round_number,
+        event,
+        meta,
+        required_votes: 1,
+        signers: vec![Vote::Confirm],
+        chain_id: None,
+        vote_rewards: vec![VoteReward::Unclaimed],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN),
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[claim_sol_vote_rewards_ix(
+            relay.pubkey(),
+            token_wallet,
+            mint_address,
+            round_number,
+            vec![withdrawal_address, withdrawal_address],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &relay], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Recipient Balance
+    let recipient_info = banks_client
+        .get_account(token_wallet)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
+
+    assert_eq!(recipient_data.amount, vote_reward);
+
+    // Check Fee Supply
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("token settings unpack");
+
+    assert_eq!(token_settings_data.fee_supply, fee_supply - vote_reward);
+
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(withdrawal_data.vote_rewards, vec![VoteReward::Claimed]);
+}
+
+#[tokio::test]
+async fn test_change_bounty_for_withdrawal_sol() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    let author = Keypair::new();
+
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let round_number = 1;
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: 1,
+        signers: vec![Vote::Confirm],
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let bounty = 5;
+    let mut transaction = Transaction::new_with_payer(
+        &[change_bounty_for_withdrawal_sol_ix(
+            &author.pubkey(),
+            &withdrawal_address,
+            bounty,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+    assert_eq!(withdrawal_data.meta.data.bounty, bounty);
+}
+
+#[tokio::test]
+async fn test_cancel_withdrawal_sol() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Pubkey::new_unique();
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        manager,
+        guardian,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add MultiVault  Account
+    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+
+    let multivault_address = get_multivault_address();
+
+    let multivault_account_data = MultiVault {
+        is_initialized: true,
+        account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut multivault_packed = vec![0; MultiVault::LEN];
+    MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
+
+    let multivault_balance = Rent::default().minimum_balance(MultiVault::LEN);
+    program_test.add_account(
+        multivault_address,
+        Account {
+            lamports: multivault_balance,
+            data: multivault_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 100000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let round_number = 1;
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
+
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: 1,
+        signers: vec![Vote::Confirm],
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let value = 1000;
+    let expected_evers = Default::default();
+    let payload = Default::default();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_withdrawal_sol_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            withdrawal_address,
+            mint_address,
+            deposit_seed,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Cancelled
+    );
+
+    let new_deposit_address = get_deposit_address(deposit_seed);
+    let new_deposit_info = banks_client
+        .get_account(new_deposit_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let deposit_data =
+        DepositMultiTokenSol::unpack(new_deposit_info.data()).expect("deposit unpack");
+    assert_eq!(deposit_data.is_initialized, true);
+    assert_eq!(deposit_data.event.data.recipient, recipient);
+    assert_eq!(deposit_data.meta.data.seed, deposit_seed);
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee;
+    assert_eq!(deposit_data.event.data.amount, transfer_amount as u128);
+    assert_eq!(deposit_data.event.data.fee, fee as u128);
+    assert_eq!(deposit_data.event.data.gross_amount, amount as u128);
+
+    // Check MultiVault Balance
+    let multivault_info = banks_client
+        .get_account(multivault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(multivault_info.lamports, multivault_balance + value);
+}
+
+#[tokio::test]
+async fn test_fill_withdrawal_sol() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Pubkey::new_unique();
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        manager,
+        guardian,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 100000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Author Token Account
+    let author_token_address =
+        spl_associated_token_account::get_associated_token_address(&author.pubkey(), &mint_address);
+
+    let author_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: author.pubkey(),
+        amount: 100,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut author_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(author_token_account_data, &mut author_token_packed).unwrap();
+    program_test.add_account(
+        author_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: author_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient_address = Pubkey::new_unique();
+    let recipient_token_address = spl_associated_token_account::get_associated_token_address(
+        &recipient_address,
+        &mint_address,
+    );
+
+    let recipient_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut recipient_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(recipient_token_account_data, &mut recipient_token_packed)
+        .unwrap();
+    program_test.add_account(
+        recipient_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: recipient_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let d_fee_info = token_settings_account_data.fee_deposit_info.clone();
+    let w_fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add MultiVault  Account
+    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+
+    let multivault_address = get_multivault_address();
+
+    let multivault_account_data = MultiVault {
+        is_initialized: true,
+        account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut multivault_packed = vec![0; MultiVault::LEN];
+    MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
+
+    let multivault_balance = Rent::default().minimum_balance(MultiVault::LEN);
+    program_test.add_account(
+        multivault_address,
+        Account {
+            lamports: multivault_balance,
+            data: multivault_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let round_number = 1;
+    let amount = 32;
+    let bounty = 2;
+
+    let value = 1000;
+    let payload: Vec<u8> = vec![];
+    let expected_evers = UInt256::default();
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient_address,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(
+        mint_address,
+        amount,
+        recipient_address,
+        payload.clone(),
+    );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: 1,
+        signers: vec![Vote::Confirm],
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.bounty = bounty;
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let deposit_amount = 100;
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+    let ever_recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[fill_withdrawal_sol_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            mint_address,
+            deposit_seed,
+            ever_recipient,
+            deposit_amount,
+            vec![(withdrawal_address, recipient_address)],
+            Some(vault_address),
+            value,
+            expected_evers,
+            payload.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+
+    let author_token_info = banks_client
+        .get_account(author_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let author_token_data =
+        spl_token::state::Account::unpack(author_token_info.data()).expect("sender unpack");
+    assert_eq!(author_token_data.amount, bounty);
+
+    let recipient_token_info = banks_client
+        .get_account(recipient_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data =
+        spl_token::state::Account::unpack(recipient_token_info.data()).expect("recipient unpack");
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(w_fee_info.divisor)
+            .unwrap()
+            .checked_mul(w_fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee - bounty;
+    assert_eq!(recipient_token_data.amount, transfer_amount);
+
+    let deposit_address = get_deposit_address(deposit_seed);
+    let deposit_info = banks_client
+        .get_account(deposit_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let deposit_data = DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit unpack");
+
+    assert_eq!(deposit_data.is_initialized, true);
+    assert_eq!(deposit_data.meta.data.seed, deposit_seed);
+
+    let fee = 1.max(
+        (deposit_amount)
+            .checked_div(d_fee_info.divisor)
+            .unwrap()
+            .checked_mul(d_fee_info.multiplier)
+            .unwrap(),
+    );
+    assert_eq!(
+        deposit_data.event.data.amount,
+        (deposit_amount - fee).into()
+    );
+
+    // Check MultiVault Balance
+    let multivault_info = banks_client
+        .get_account(multivault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(multivault_info.lamports, multivault_balance + value);
+
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, deposit_amount - amount as u64 + fee);
+
+    // Sender balance
+    let sender_token_info = banks_client
+        .get_account(author_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let sender_token_data =
+        spl_token::state::Account::unpack(sender_token_info.data()).expect("recipient unpack");
+    assert_eq!(sender_token_data.amount, bounty);
+}
+
+#[tokio::test]
+async fn test_register_liquidity_provider() {
+    // Setup environment
+    let manager = Keypair::new();
+    let provider = Pubkey::new_unique();
+
+    let settings_account_data =
+        bridge_test_utils::settings(Pubkey::new_unique(), manager.pubkey(), Pubkey::new_unique());
+
+    // Lamports sent to the registry address don't prevent its creation
+    let test_bridge = TestBridge::new()
+        .with_settings(settings_account_data)
+        .with_funded_account(get_lp_registry_address(), 1);
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = test_bridge.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[register_liquidity_provider_ix(
+            funder.pubkey(),
+            manager.pubkey(),
+            provider,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &manager], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let lp_registry_info = banks_client
+        .get_account(get_lp_registry_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(lp_registry_info.owner, token_proxy::id());
+
+    let lp_registry_data = LpRegistry::unpack(lp_registry_info.data()).expect("lp registry unpack");
+
+    assert_eq!(lp_registry_data.providers, vec![provider]);
+}
+
+#[tokio::test]
+async fn test_fill_withdrawal_sol_restricted_to_liquidity_providers() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Pubkey::new_unique();
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        manager,
+        guardian,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 100000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Author Token Account
+    let author_token_address =
+        spl_associated_token_account::get_associated_token_address(&author.pubkey(), &mint_address);
+
+    let author_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: author.pubkey(),
+        amount: 100,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut author_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(author_token_account_data, &mut author_token_packed).unwrap();
+    program_test.add_account(
+        author_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: author_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient_address = Pubkey::new_unique();
+    let recipient_token_address = spl_associated_token_account::get_associated_token_address(
+        &recipient_address,
+        &mint_address,
+    );
+
+    let recipient_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut recipient_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(recipient_token_account_data, &mut recipient_token_packed)
+        .unwrap();
+    program_test.add_account(
+        recipient_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: recipient_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: true,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add MultiVault  Account
+    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+
+    let multivault_address = get_multivault_address();
+
+    let multivault_account_data = MultiVault {
+        is_initialized: true,
+        account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut multivault_packed = vec![0; MultiVault::LEN];
+    MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
+
+    let multivault_balance = Rent::default().minimum_balance(MultiVault::LEN);
+    program_test.add_account(
+        multivault_address,
+        Account {
+            lamports: multivault_balance,
+            data: multivault_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let round_number = 1;
+    let amount = 32;
+    let bounty = 2;
+
+    let value = 1000;
+    let payload: Vec<u8> = vec![];
+    let expected_evers = UInt256::default();
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient_address,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(
+        mint_address,
+        amount,
+        recipient_address,
+        payload.clone(),
+    );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: 1,
+        signers: vec![Vote::Confirm],
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.bounty = bounty;
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add LP Registry Account
+    let (_, lp_registry_nonce) =
+        Pubkey::find_program_address(&[br"lp_registry"], &token_proxy::id());
+
+    let lp_registry_address = get_lp_registry_address();
+
+    let lp_registry_account_data = LpRegistry {
+        is_initialized: true,
+        account_kind: AccountKind::LpRegistry(lp_registry_nonce),
+        providers: vec![author.pubkey()],
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut lp_registry_packed = vec![0; LpRegistry::LEN];
+    LpRegistry::pack(lp_registry_account_data, &mut lp_registry_packed).unwrap();
+    program_test.add_account(
+        lp_registry_address,
+        Account {
+            lamports: Rent::default().minimum_balance(LpRegistry::LEN),
+            data: lp_registry_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let deposit_amount = 100;
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+    let ever_recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    let fill_ix = fill_withdrawal_sol_ix(
+        funder.pubkey(),
+        author.pubkey(),
+        mint_address,
+        deposit_seed,
+        ever_recipient,
+        deposit_amount,
+        vec![(withdrawal_address, recipient_address)],
+        Some(vault_address),
+        value,
+        expected_evers,
+        payload.clone(),
+    );
+
+    // Fill without proof of registration is rejected
+    let mut transaction = Transaction::new_with_payer(&[fill_ix.clone()], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    let mut fill_ix = fill_ix;
+    append_lp_registry_account(&mut fill_ix);
+
+    let mut transaction = Transaction::new_with_payer(&[fill_ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+
+    let author_token_info = banks_client
+        .get_account(author_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let author_token_data =
+        spl_token::state::Account::unpack(author_token_info.data()).expect("sender unpack");
+    assert_eq!(author_token_data.amount, bounty);
+}
+
+#[tokio::test]
+async fn test_fill_withdrawal_sol_with_escrowed_bounty() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Pubkey::new_unique();
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        manager,
+        guardian,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 100000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Author Token Account
+    let author_token_address =
+        spl_associated_token_account::get_associated_token_address(&author.pubkey(), &mint_address);
+
+    let author_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: author.pubkey(),
+        amount: 100,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut author_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(author_token_account_data, &mut author_token_packed).unwrap();
+    program_test.add_account(
+        author_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: author_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Author Account
+    let requester = Keypair::new();
+    program_test.add_account(
+        requester.pubkey(),
+        Account {
+            lamports: 100000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Author Token Account
+    let requester_token_address = spl_associated_token_account::get_associated_token_address(
+        &requester.pubkey(),
+        &mint_address,
+    );
+
+    let requester_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: requester.pubkey(),
+        amount: 10,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut requester_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(requester_token_account_data, &mut requester_token_packed)
+        .unwrap();
+    program_test.add_account(
+        requester_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: requester_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient_address = Pubkey::new_unique();
+    let recipient_token_address = spl_associated_token_account::get_associated_token_address(
+        &recipient_address,
+        &mint_address,
+    );
+
+    let recipient_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut recipient_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(recipient_token_account_data, &mut recipient_token_packed)
+        .unwrap();
+    program_test.add_account(
+        recipient_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: recipient_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let w_fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add MultiVault  Account
+    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+
+    let multivault_address = get_multivault_address();
+
+    let multivault_account_data = MultiVault {
+        is_initialized: true,
+        account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut multivault_packed = vec![0; MultiVault::LEN];
+    MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
+
+    let multivault_balance = Rent::default().minimum_balance(MultiVault::LEN);
+    program_test.add_account(
+        multivault_address,
+        Account {
+            lamports: multivault_balance,
+            data: multivault_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let round_number = 1;
+    let amount = 32;
+    let bounty = 2;
+
+    let value = 1000;
+    let payload: Vec<u8> = vec![];
+    let expected_evers = UInt256::default();
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient_address,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(
+        mint_address,
+        amount,
+        recipient_address,
+        payload.clone(),
+    );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: requester.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: 1,
+        signers: vec![Vote::Confirm],
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.bounty = bounty;
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Lamports sent to the bounty address don't prevent its creation
+    program_test.add_account(
+        get_bounty_address(&withdrawal_address, &requester.pubkey()),
+        Account {
+            lamports: 1,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Deposit bounty to escrow
+    let escrowed_bounty = 5;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[deposit_bounty_for_withdrawal_sol_ix(
+            &requester.pubkey(),
+            &withdrawal_address,
+            &mint_address,
+            escrowed_bounty,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &requester], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let bounty_address = get_bounty_address(&withdrawal_address, &requester.pubkey());
+    let bounty_info = banks_client
+        .get_account(bounty_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let bounty_data = spl_token::state::Account::unpack(bounty_info.data()).expect("bounty unpack");
+    assert_eq!(bounty_data.amount, escrowed_bounty);
+
+    // Fill withdrawal
+    let deposit_amount = 100;
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+    let ever_recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    let mut fill_ix = fill_withdrawal_sol_ix(
+        funder.pubkey(),
+        author.pubkey(),
+        mint_address,
+        deposit_seed,
+        ever_recipient,
+        deposit_amount,
+        vec![(withdrawal_address, recipient_address)],
+        Some(vault_address),
+        value,
+        expected_evers,
+        payload.clone(),
+    );
+    append_bounty_account(&mut fill_ix, &withdrawal_address, &requester.pubkey());
+
+    let mut transaction = Transaction::new_with_payer(&[fill_ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+
+    let author_token_info = banks_client
+        .get_account(author_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let author_token_data =
+        spl_token::state::Account::unpack(author_token_info.data()).expect("sender unpack");
+    assert_eq!(author_token_data.amount, bounty + escrowed_bounty);
+
+    let recipient_token_info = banks_client
+        .get_account(recipient_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data =
+        spl_token::state::Account::unpack(recipient_token_info.data()).expect("recipient unpack");
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(w_fee_info.divisor)
+            .unwrap()
+            .checked_mul(w_fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee - bounty;
+    assert_eq!(recipient_token_data.amount, transfer_amount);
+
+    // Refund closes empty escrow
+    let mut transaction = Transaction::new_with_payer(
+        &[refund_bounty_for_withdrawal_sol_ix(
+            &requester.pubkey(),
+            &withdrawal_address,
+            &mint_address,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &requester], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let bounty_info = banks_client
+        .get_account(bounty_address)
+        .await
+        .expect("get_account");
+    assert!(bounty_info.is_none());
+
+    let requester_token_info = banks_client
+        .get_account(requester_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let requester_token_data =
+        spl_token::state::Account::unpack(requester_token_info.data()).expect("requester unpack");
+    assert_eq!(requester_token_data.amount, 10 - escrowed_bounty);
+}
+
+#[tokio::test]
+async fn test_partial_fill_withdrawal_sol() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let manager = Pubkey::new_unique();
+    let guardian = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        manager,
+        guardian,
+        withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 100000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Author Token Account
+    let author_token_address =
+        spl_associated_token_account::get_associated_token_address(&author.pubkey(), &mint_address);
+
+    let author_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: author.pubkey(),
+        amount: 200,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut author_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(author_token_account_data, &mut author_token_packed).unwrap();
+    program_test.add_account(
+        author_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: author_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient_address = Pubkey::new_unique();
+    let recipient_token_address = spl_associated_token_account::get_associated_token_address(
+        &recipient_address,
+        &mint_address,
+    );
+
+    let recipient_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut recipient_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(recipient_token_account_data, &mut recipient_token_packed)
+        .unwrap();
+    program_test.add_account(
+        recipient_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: recipient_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let w_fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add MultiVault  Account
+    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
+
+    let multivault_address = get_multivault_address();
+
+    let multivault_account_data = MultiVault {
+        is_initialized: true,
+        account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut multivault_packed = vec![0; MultiVault::LEN];
+    MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
+
+    let multivault_balance = Rent::default().minimum_balance(MultiVault::LEN);
+    program_test.add_account(
+        multivault_address,
+        Account {
+            lamports: multivault_balance,
+            data: multivault_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let round_number = 1;
+    let amount = 32;
+    let bounty = 2;
+
+    let value = 1000;
+    let payload: Vec<u8> = vec![];
+    let expected_evers = UInt256::default();
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient_address,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(
+        mint_address,
+        amount,
+        recipient_address,
+        payload.clone(),
+    );
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    let mut withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author: author.pubkey(),
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        required_votes: 1,
+        signers: vec![Vote::Confirm],
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
+    withdrawal_account_data.meta.data.bounty = bounty;
+    withdrawal_account_data.meta.data.status = WithdrawalTokenStatus::Pending;
+
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + RELAY_REPARATION,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // First filler covers a half
+    let deposit_amount = 100;
+    let fill_amount = 16;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[partial_fill_withdrawal_sol_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            mint_address,
+            uuid::Uuid::new_v4().as_u128(),
+            EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+            deposit_amount,
+            vec![(withdrawal_address, recipient_address, fill_amount)],
+            Some(vault_address),
+            value,
+            expected_evers,
+            payload.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Pending
+    );
+    assert_eq!(withdrawal_data.meta.data.filled_amount, fill_amount);
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(w_fee_info.divisor)
+            .unwrap()
+            .checked_mul(w_fee_info.multiplier)
+            .unwrap(),
+    );
+    let fee_part = fee * fill_amount / amount as u64;
+    let bounty_part = bounty * fill_amount / amount as u64;
+
+    let recipient_token_info = banks_client
+        .get_account(recipient_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data =
+        spl_token::state::Account::unpack(recipient_token_info.data()).expect("recipient unpack");
+    assert_eq!(
+        recipient_token_data.amount,
+        fill_amount - fee_part - bounty_part
+    );
+
+    // Second filler covers the rest
+    let mut transaction = Transaction::new_with_payer(
+        &[fill_withdrawal_sol_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            mint_address,
+            uuid::Uuid::new_v4().as_u128(),
+            EverAddress::with_standart(0, Pubkey::new_unique().to_bytes()),
+            deposit_amount,
+            vec![(withdrawal_address, recipient_address)],
+            Some(vault_address),
+            value,
+            expected_evers,
+            payload.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+    assert_eq!(withdrawal_data.meta.data.filled_amount, amount as u64);
+
+    let recipient_token_info = banks_client
+        .get_account(recipient_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data =
+        spl_token::state::Account::unpack(recipient_token_info.data()).expect("recipient unpack");
+    assert_eq!(recipient_token_data.amount, amount as u64 - fee - bounty);
+
+    // Both fillers together get the whole bounty
+    let author_token_info = banks_client
+        .get_account(author_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let author_token_data =
+        spl_token::state::Account::unpack(author_token_info.data()).expect("sender unpack");
+    assert_eq!(author_token_data.amount, bounty);
+}
+
+#[tokio::test]
+async fn test_withdraw_sol_with_payload() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![spl_token::id()],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Round Loader Settings Account
+    let round_number = 12;
+
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let round_ttl = 1209600;
+    let rl_settings_account_data = round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    };
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Accounts
+    let relays = vec![
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+    ];
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Relay Round Account
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        round_number,
+        round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let mint_address = Pubkey::new_unique();
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint_address),
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        amount: 100,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = 10_000_000;
+    let withdrawal_limit = 10_000;
+    let withdrawal_daily_limit = 1_000;
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Keypair::new();
+    let recipient_token_address = spl_associated_token_account::get_associated_token_address(
+        &recipient.pubkey(),
+        &mint_address,
+    );
+
+    let recipient_token_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient.pubkey(),
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut recipient_token_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(recipient_token_account_data, &mut recipient_token_packed)
+        .unwrap();
+    program_test.add_account(
+        recipient_token_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: recipient_token_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create withdrawal request
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let amount = 32;
+
+    let (proxy_address, proxy_nonce) = Pubkey::find_program_address(
+        &[
+            br"proxy",
+            &mint_address.to_bytes(),
+            &recipient.pubkey().to_bytes(),
+        ],
+        &token_proxy::id(),
+    );
+
+    let payload = bincode::serialize(&vec![spl_token::instruction::transfer(
+        &spl_token::id(),
+        &proxy_address,
+        &recipient_token_address,
+        &proxy_address,
+        &[&proxy_address],
+        16,
+    )
+    .unwrap()])
+    .unwrap();
+
+    let attached_amount = 0;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_multi_token_sol_request_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            mint_address,
+            round_number,
+            recipient.pubkey(),
+            amount,
+            payload.clone(),
+            attached_amount,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Withdrawal Account
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient.pubkey(),
+        amount,
+        payload,
+    );
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(withdrawal_data.is_initialized, true);
+    assert_eq!(withdrawal_data.author, author.pubkey());
+    assert_eq!(withdrawal_data.round_number, round_number);
+
+    assert_eq!(
+        withdrawal_data.required_votes,
+        (relays.len() * 2 / 3 + 1) as u32
+    );
+
+    assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
+    assert_eq!(
+        withdrawal_data.pda.event_transaction_lt,
+        event_transaction_lt
+    );
+    assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
+
+    assert_eq!(withdrawal_data.event.data.mint, mint_address);
+    assert_eq!(withdrawal_data.event.data.recipient, recipient.pubkey());
+    assert_eq!(withdrawal_data.event.data.amount, amount);
+
+    assert_ne!(withdrawal_data.meta.data.epoch, 0);
+    assert_eq!(withdrawal_data.meta.data.bounty, 0);
+    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
+
+    let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    assert_eq!(
+        withdrawal_data.account_kind,
+        AccountKind::Proposal(withdrawal_nonce, Some(proxy_nonce))
+    );
+
+    // Check Proposal Account to unpack
+    let proposal_data =
+        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        proposal_data.event,
+        withdrawal_data.event.data.try_to_vec().unwrap()
+    );
+    assert_eq!(
+        proposal_data.meta,
+        withdrawal_data.meta.data.try_to_vec().unwrap()
+    );
+
+    // Vote for withdrawal request
+    for relay in &relays {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_withdrawal_request_ix(
+                relay.pubkey(),
+                withdrawal_address,
+                round_number,
+                Vote::Confirm,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, &relay], recent_blockhash);
+
+        let _ = banks_client.process_transaction(transaction).await;
+    }
+
+    // Execute withdrawal
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_sol_with_payload_ix(
+            withdrawal_address,
+            recipient.pubkey(),
+            mint_address,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee;
+
+    assert_eq!(vault_data.amount, 100 - transfer_amount);
+
+    // Check Proxy Balance
+    let proxy_info = banks_client
+        .get_account(proxy_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
+    assert_eq!(proxy_data.amount, transfer_amount);
+
+    // Withdrawal token from Proxy Account
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_proxy_ix(
+            recipient.pubkey(),
+            recipient_token_address,
+            mint_address,
+            15,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &recipient], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Proxy Balance
+    let proxy_info = banks_client
+        .get_account(proxy_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
+    assert_eq!(proxy_data.amount, 16);
+
+    // Check Recipient Balance
+    let recipient_token_info = banks_client
+        .get_account(recipient_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data =
+        spl_token::state::Account::unpack(recipient_token_info.data()).expect("proxy unpack");
+    assert_eq!(recipient_token_data.amount, 15);
+
+    // Execute payload
+    let data = TokenProxyInstruction::ExecutePayloadSol
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_address, false),
+            AccountMeta::new_readonly(settings_address, false),
+            AccountMeta::new(proxy_address, false),
+            AccountMeta::new(recipient_token_address, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Proxy Balance
+    let proxy_info = banks_client
+        .get_account(proxy_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
+    assert_eq!(proxy_data.amount, 0);
+
+    // Check Proxy Balance
+    let recipient_token_info = banks_client
+        .get_account(recipient_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let recipient_token_data =
+        spl_token::state::Account::unpack(recipient_token_info.data()).expect("proxy unpack");
+    assert_eq!(recipient_token_data.amount, transfer_amount);
+
+    // Check status
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+}
+
+#[tokio::test]
+async fn test_withdraw_sol_with_payload_unwrap() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![spl_token::id()],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Round Loader Settings Account
+    let round_number = 12;
+
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let round_ttl = 1209600;
+    let rl_settings_account_data = round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    };
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Accounts
+    let relays = vec![
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+    ];
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Relay Round Account
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        round_number,
+        round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let mint_address = NATIVE_MINT;
+
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        state: AccountState::Initialized,
+        amount: 1000,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "wSOL".to_string();
+    let name = "Wrapped SOL".to_string();
+    let deposit_limit = 10_000_000;
+    let withdrawal_limit = 10_000;
+    let withdrawal_daily_limit = 1_000;
+
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
+        },
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Keypair::new();
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    for i in 0..5 {
+        // Create withdrawal request
+        let event_timestamp = 1650988297 + i;
+        let event_transaction_lt = 1650988334;
+        let event_configuration = Pubkey::new_unique();
+
+        let amount = 32;
+
+        let (proxy_address, proxy_nonce) = Pubkey::find_program_address(
+            &[
+                br"proxy",
+                &mint_address.to_bytes(),
+                &recipient.pubkey().to_bytes(),
+            ],
+            &id(),
+        );
+
+        let payload = bincode::serialize(&vec![spl_token::instruction::close_account(
+            &spl_token::id(),
+            &proxy_address,
+            &recipient.pubkey(),
+            &proxy_address,
+            &[],
+        )
+        .unwrap()])
+        .unwrap();
+
+        let attached_amount = 0;
+
+        let mut transaction = Transaction::new_with_payer(
+            &[withdrawal_multi_token_sol_request_ix(
+                funder.pubkey(),
+                author.pubkey(),
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                mint_address,
+                round_number,
+                recipient.pubkey(),
+                amount,
+                payload.clone(),
+                attached_amount,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, &author], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+
+        // Check Withdrawal Account
+        let withdrawal_address = get_withdrawal_sol_address(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            mint_address,
+            recipient.pubkey(),
+            amount,
+            payload,
+        );
+        let withdrawal_info = banks_client
+            .get_account(withdrawal_address)
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let withdrawal_data = WithdrawalMultiTokenSol::unpack(withdrawal_info.data())
+            .expect("withdrawal token unpack");
+
+        assert_eq!(withdrawal_data.is_initialized, true);
+        assert_eq!(withdrawal_data.author, author.pubkey());
+        assert_eq!(withdrawal_data.round_number, round_number);
+
+        assert_eq!(
+            withdrawal_data.required_votes,
+            (relays.len() * 2 / 3 + 1) as u32
+        );
+
+        assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
+        assert_eq!(
             withdrawal_data.pda.event_transaction_lt,
             event_transaction_lt
         );
-        assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
+        assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
+
+        assert_eq!(withdrawal_data.event.data.mint, mint_address);
+        assert_eq!(withdrawal_data.event.data.recipient, recipient.pubkey());
+        assert_eq!(withdrawal_data.event.data.amount, amount);
+
+        assert_ne!(withdrawal_data.meta.data.epoch, 0);
+        assert_eq!(withdrawal_data.meta.data.bounty, 0);
+        assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
+
+        let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
+
+        let (_, withdrawal_nonce) = Pubkey::find_program_address(
+            &[
+                br"proposal",
+                &round_number.to_le_bytes(),
+                &event_timestamp.to_le_bytes(),
+                &event_transaction_lt.to_le_bytes(),
+                &event_configuration.to_bytes(),
+                &event_data,
+            ],
+            &token_proxy::id(),
+        );
+
+        assert_eq!(
+            withdrawal_data.account_kind,
+            AccountKind::Proposal(withdrawal_nonce, Some(proxy_nonce))
+        );
+
+        // Check Proposal Account to unpack
+        let proposal_data =
+            Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+
+        assert_eq!(
+            proposal_data.event,
+            withdrawal_data.event.data.try_to_vec().unwrap()
+        );
+        assert_eq!(
+            proposal_data.meta,
+            withdrawal_data.meta.data.try_to_vec().unwrap()
+        );
+
+        // Vote for withdrawal request
+        for relay in &relays {
+            let mut transaction = Transaction::new_with_payer(
+                &[vote_for_withdrawal_request_ix(
+                    relay.pubkey(),
+                    withdrawal_address,
+                    round_number,
+                    Vote::Confirm,
+                )],
+                Some(&funder.pubkey()),
+            );
+            transaction.sign(&[&funder, &relay], recent_blockhash);
+
+            let _ = banks_client.process_transaction(transaction).await;
+        }
+
+        // Execute withdrawal
+        let mut transaction = Transaction::new_with_payer(
+            &[withdrawal_sol_with_payload_ix(
+                withdrawal_address,
+                recipient.pubkey(),
+                mint_address,
+                round_number,
+                get_proposal_index_address(
+                    event_timestamp,
+                    event_transaction_lt,
+                    &event_configuration,
+                    None,
+                ),
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+
+        // Check Vault Balance
+        let vault_info = banks_client
+            .get_account(vault_address)
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let vault_data =
+            spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+
+        let fee = 1.max(
+            (amount as u64)
+                .checked_div(fee_info.divisor)
+                .unwrap()
+                .checked_mul(fee_info.multiplier)
+                .unwrap(),
+        );
+
+        let transfer_amount = amount as u64 - fee;
+
+        assert_eq!(vault_data.amount, 1000 - transfer_amount * (1 + i) as u64);
+
+        // Check Proxy Balance
+        let proxy_info = banks_client
+            .get_account(proxy_address)
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let proxy_data =
+            spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
+        assert_eq!(proxy_data.amount, transfer_amount);
+        let proxy_info_balance = proxy_info.lamports;
+
+        // Execute payload
+        let data = TokenProxyInstruction::ExecutePayloadSolV2 {
+            count: None,
+            proxy_accounts: 0,
+        }
+        .try_to_vec()
+        .expect("pack");
+
+        let ix = Instruction {
+            program_id: id(),
+            accounts: vec![
+                AccountMeta::new(withdrawal_address, false),
+                AccountMeta::new_readonly(settings_address, false),
+                AccountMeta::new(proxy_address, false),
+                AccountMeta::new(recipient.pubkey(), false),
+                AccountMeta::new_readonly(spl_token::id(), false),
+            ],
+            data,
+        };
+
+        let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+        transaction.sign(&[&funder], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+
+        // Check Recipient Balance
+        let recipient_account = banks_client
+            .get_account(recipient.pubkey())
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        assert_eq!(
+            recipient_account.lamports,
+            proxy_info_balance * (1 + i) as u64
+        );
+
+        // Check status
+        let withdrawal_info = banks_client
+            .get_account(withdrawal_address)
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let withdrawal_data = WithdrawalMultiTokenSol::unpack(withdrawal_info.data())
+            .expect("withdrawal token unpack");
+
+        assert_eq!(
+            withdrawal_data.meta.data.status,
+            WithdrawalTokenStatus::Processed
+        );
+
+        // Check closed proxy account
+        let proxy_info = banks_client
+            .get_account(proxy_address)
+            .await
+            .expect("get_account");
+
+        assert_eq!(proxy_info, None);
+    }
+}
+
+#[tokio::test]
+async fn test_withdraw_ever_request_with_payload() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Settings Account
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
+
+    let settings_address = get_settings_address();
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        emergency: false,
+        guardian,
+        manager,
+        withdrawal_manager,
+        payload_allowlist: vec![spl_token::id()],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Round Loader Settings Account
+    let round_number = 12;
+
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let round_ttl = 1209600;
+    let rl_settings_account_data = round_loader::Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 0,
+        guardian: Pubkey::default(),
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+        round_handover_period: 0,
+    };
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Accounts
+    let relays = vec![
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+    ];
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 1_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Relay Round Account
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
+
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        round_number,
+        round_end,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
+
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Author Account
+    let author = Keypair::new();
+    program_test.add_account(
+        author.pubkey(),
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Mint Account
+    let decimals = spl_token::native_mint::DECIMALS;
+
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let token_hash = hash(&token.try_to_vec().unwrap());
+
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
+
+    let mint = get_mint_address(&token);
+
+    let mint_account_data = spl_token::state::Mint {
+        is_initialized: true,
+        mint_authority: program_option::COption::Some(mint),
+        supply: 0,
+        decimals,
+        ..Default::default()
+    };
+
+    let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
+    program_test.add_account(
+        mint,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: mint_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 1,
+        },
+    );
+
+    // Add Token Settings Account
+    let symbol = "USDT".to_string();
+    let name = "USDT Solana Octusbridge".to_string();
+    let deposit_limit = u64::MAX;
+    let withdrawal_limit = u64::MAX;
+    let withdrawal_daily_limit = u64::MAX;
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+
+    let token_settings_address = get_token_settings_ever_address(&token);
+
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+        kind: TokenKind::Ever {
+            mint,
+            token,
+            decimals,
+        },
+        name: name.clone(),
+        symbol: symbol.clone(),
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        withdrawal_daily_amount: 0,
+        withdrawal_epoch: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
+    };
+
+    let mut token_settings_packed = vec![0; TokenSettings::LEN];
+    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    program_test.add_account(
+        token_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
+            data: token_settings_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add recipient
+    let recipient = Keypair::new();
+    program_test.add_account(
+        recipient.pubkey(),
+        Account {
+            lamports: 1000000000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient.pubkey(), &mint);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint,
+        owner: recipient.pubkey(),
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let recipient_token_address =
+        spl_associated_token_account::get_associated_token_address(&recipient.pubkey(), &mint);
+
+    let amount = 32;
+
+    let (proxy_address, proxy_nonce) = Pubkey::find_program_address(
+        &[br"proxy", &mint.to_bytes(), &recipient.pubkey().to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let payload = bincode::serialize(&vec![spl_token::instruction::transfer(
+        &spl_token::id(),
+        &proxy_address,
+        &recipient_token_address,
+        &proxy_address,
+        &[&proxy_address],
+        16,
+    )
+    .unwrap()])
+    .unwrap();
+
+    let attached_amount = 0;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_multi_token_ever_request_ix(
+            funder.pubkey(),
+            author.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            token,
+            name.clone(),
+            symbol.clone(),
+            decimals,
+            recipient.pubkey(),
+            amount,
+            payload.clone(),
+            attached_amount,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &author], recent_blockhash);
 
-        assert_eq!(withdrawal_data.event.data.mint, mint_address);
-        assert_eq!(withdrawal_data.event.data.recipient, recipient.pubkey());
-        assert_eq!(withdrawal_data.event.data.amount, amount);
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
-        assert_ne!(withdrawal_data.meta.data.epoch, 0);
-        assert_eq!(withdrawal_data.meta.data.bounty, 0);
-        assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
+    // Check Withdrawal Account
+    let withdrawal_address = get_withdrawal_ever_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        token,
+        name.clone(),
+        symbol.clone(),
+        decimals,
+        recipient.pubkey(),
+        amount,
+        payload,
+    );
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-        let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
+    let withdrawal_data =
+        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
 
-        let (_, withdrawal_nonce) = Pubkey::find_program_address(
-            &[
-                br"proposal",
-                &round_number.to_le_bytes(),
-                &event_timestamp.to_le_bytes(),
-                &event_transaction_lt.to_le_bytes(),
-                &event_configuration.to_bytes(),
-                &event_data,
-            ],
-            &token_proxy::id(),
-        );
+    assert_eq!(withdrawal_data.is_initialized, true);
+    assert_eq!(withdrawal_data.author, author.pubkey());
+    assert_eq!(withdrawal_data.round_number, round_number);
 
-        assert_eq!(
-            withdrawal_data.account_kind,
-            AccountKind::Proposal(withdrawal_nonce, Some(proxy_nonce))
-        );
+    assert_eq!(
+        withdrawal_data.required_votes,
+        (relays.len() * 2 / 3 + 1) as u32
+    );
 
-        // Check Proposal Account to unpack
-        let proposal_data =
-            Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+    assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
+    assert_eq!(
+        withdrawal_data.pda.event_transaction_lt,
+        event_transaction_lt
+    );
+    assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
 
-        assert_eq!(
-            proposal_data.event,
-            withdrawal_data.event.data.try_to_vec().unwrap()
-        );
-        assert_eq!(
-            proposal_data.meta,
-            withdrawal_data.meta.data.try_to_vec().unwrap()
-        );
+    assert_eq!(withdrawal_data.event.data.token, token);
+    assert_eq!(withdrawal_data.event.data.name, name);
+    assert_eq!(withdrawal_data.event.data.symbol, symbol);
+    assert_eq!(withdrawal_data.event.data.decimals, decimals);
+    assert_eq!(withdrawal_data.event.data.amount, amount);
+    assert_eq!(withdrawal_data.event.data.recipient, recipient.pubkey());
 
-        // Vote for withdrawal request
-        for relay in &relays {
-            let mut transaction = Transaction::new_with_payer(
-                &[vote_for_withdrawal_request_ix(
-                    relay.pubkey(),
-                    withdrawal_address,
-                    round_number,
-                    Vote::Confirm,
-                )],
-                Some(&funder.pubkey()),
-            );
-            transaction.sign(&[&funder, &relay], recent_blockhash);
+    assert_ne!(withdrawal_data.meta.data.epoch, 0);
+    assert_eq!(withdrawal_data.meta.data.bounty, 0);
+    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
 
-            let _ = banks_client.process_transaction(transaction).await;
-        }
+    let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
 
-        // Execute withdrawal
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
+
+    assert_eq!(
+        withdrawal_data.account_kind,
+        AccountKind::Proposal(withdrawal_nonce, Some(proxy_nonce))
+    );
+
+    // Check Proposal Account
+    let proposal_data =
+        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+
+    assert_eq!(
+        proposal_data.event,
+        withdrawal_data.event.data.try_to_vec().unwrap()
+    );
+    assert_eq!(
+        proposal_data.meta,
+        withdrawal_data.meta.data.try_to_vec().unwrap()
+    );
+
+    // Check Proxy Account
+    let proxy_address = get_proxy_address(&mint, &recipient.pubkey());
+
+    let proxy_info = banks_client
+        .get_account(proxy_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(proxy_info.data.len(), spl_token::state::Account::LEN);
+
+    // Vote for withdrawal request
+    for relay in &relays {
         let mut transaction = Transaction::new_with_payer(
-            &[withdrawal_sol_with_payload_ix(
+            &[vote_for_withdrawal_request_ix(
+                relay.pubkey(),
                 withdrawal_address,
-                recipient.pubkey(),
-                mint_address,
+                round_number,
+                Vote::Confirm,
             )],
             Some(&funder.pubkey()),
         );
-        transaction.sign(&[&funder], recent_blockhash);
+        transaction.sign(&[&funder, &relay], recent_blockhash);
 
-        banks_client
-            .process_transaction(transaction)
-            .await
-            .expect("process_transaction");
+        let _ = banks_client.process_transaction(transaction).await;
+    }
 
-        // Check Vault Balance
-        let vault_info = banks_client
-            .get_account(vault_address)
-            .await
-            .expect("get_account")
-            .expect("account");
+    // Execute withdrawal
+    let mut transaction = Transaction::new_with_payer(
+        &[create_ever_token_with_payload_ix(
+            funder.pubkey(),
+            withdrawal_address,
+            recipient.pubkey(),
+            token,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Token Settings Account
+    let token_settings_address = get_token_settings_ever_address(&token);
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("deposit token unpack");
+
+    assert_eq!(token_settings_data.is_initialized, true);
+    assert_eq!(token_settings_data.deposit_limit, u64::MAX);
+    assert_eq!(token_settings_data.withdrawal_limit, u64::MAX);
+    assert_eq!(token_settings_data.withdrawal_daily_limit, u64::MAX);
+    assert_eq!(token_settings_data.emergency, false);
+
+    assert_eq!(
+        token_settings_data.kind,
+        TokenKind::Ever {
+            mint,
+            token,
+            decimals,
+        }
+    );
+
+    let token_hash = hash(&token.try_to_vec().unwrap());
+
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
+    let (_, mint_nonce) =
+        Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], &token_proxy::id());
+
+    assert_eq!(
+        token_settings_data.account_kind,
+        AccountKind::TokenSettings(token_settings_nonce, mint_nonce)
+    );
+
+    let fee_info = &token_settings_data.fee_withdrawal_info;
+
+    let fee = 1.max(
+        (amount as u64)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount as u64 - fee;
+
+    assert_eq!(token_settings_data.withdrawal_daily_amount, transfer_amount);
+
+    // Check Mint supply
+    let mint_info = banks_client
+        .get_account(mint)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
+    assert_eq!(mint_data.supply, transfer_amount);
+
+    // Check Proxy Balance
+    let proxy_info = banks_client
+        .get_account(proxy_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
+    assert_eq!(proxy_data.amount, transfer_amount);
 
-        let vault_data =
-            spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    // Withdrawal token from Proxy Account
+    let mut transaction = Transaction::new_with_payer(
+        &[withdrawal_proxy_ix(
+            recipient.pubkey(),
+            recipient_token_address,
+            mint,
+            15,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &recipient], recent_blockhash);
 
-        let fee = 1.max(
-            (amount as u64)
-                .checked_div(fee_info.divisor)
-                .unwrap()
-                .checked_mul(fee_info.multiplier)
-                .unwrap(),
-        );
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
-        let transfer_amount = amount as u64 - fee;
+    // Check Proxy Balance
+    let proxy_info = banks_client
+        .get_account(proxy_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-        assert_eq!(vault_data.amount, 1000 - transfer_amount * (1 + i) as u64);
+    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
+    assert_eq!(proxy_data.amount, 16);
 
-        // Check Proxy Balance
-        let proxy_info = banks_client
-            .get_account(proxy_address)
-            .await
-            .expect("get_account")
-            .expect("account");
+    // Check Recipient Balance
+    let recipient_token_info = banks_client
+        .get_account(recipient_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-        let proxy_data =
-            spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
-        assert_eq!(proxy_data.amount, transfer_amount);
-        let proxy_info_balance = proxy_info.lamports;
+    let recipient_token_data =
+        spl_token::state::Account::unpack(recipient_token_info.data()).expect("proxy unpack");
+    assert_eq!(recipient_token_data.amount, 15);
 
-        // Execute payload
-        let data = TokenProxyInstruction::ExecutePayloadSol
-            .try_to_vec()
-            .expect("pack");
+    // Execute payload
+    let data = TokenProxyInstruction::ExecutePayloadEver
+        .try_to_vec()
+        .expect("pack");
 
-        let ix = Instruction {
-            program_id: id(),
-            accounts: vec![
-                AccountMeta::new(withdrawal_address, false),
-                AccountMeta::new(proxy_address, false),
-                AccountMeta::new(recipient.pubkey(), false),
-                AccountMeta::new_readonly(spl_token::id(), false),
-            ],
-            data,
-        };
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_address, false),
+            AccountMeta::new_readonly(settings_address, false),
+            AccountMeta::new(proxy_address, false),
+            AccountMeta::new(recipient_token_address, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
 
-        let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
-        transaction.sign(&[&funder], recent_blockhash);
+    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
 
-        banks_client
-            .process_transaction(transaction)
-            .await
-            .expect("process_transaction");
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
 
-        // Check Recipient Balance
-        let recipient_account = banks_client
-            .get_account(recipient.pubkey())
-            .await
-            .expect("get_account")
-            .expect("account");
+    // Check Proxy Balance
+    let proxy_info = banks_client
+        .get_account(proxy_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-        assert_eq!(
-            recipient_account.lamports,
-            proxy_info_balance * (1 + i) as u64
-        );
+    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
+    assert_eq!(proxy_data.amount, 0);
 
-        // Check status
-        let withdrawal_info = banks_client
-            .get_account(withdrawal_address)
-            .await
-            .expect("get_account")
-            .expect("account");
+    // Check Recipient Balance
+    let recipient_token_info = banks_client
+        .get_account(recipient_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-        let withdrawal_data = WithdrawalMultiTokenSol::unpack(withdrawal_info.data())
-            .expect("withdrawal token unpack");
+    let recipient_token_data =
+        spl_token::state::Account::unpack(recipient_token_info.data()).expect("proxy unpack");
+    assert_eq!(recipient_token_data.amount, transfer_amount);
 
-        assert_eq!(
-            withdrawal_data.meta.data.status,
-            WithdrawalTokenStatus::Processed
-        );
+    // Check status
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-        // Check closed proxy account
-        let proxy_info = banks_client
-            .get_account(proxy_address)
-            .await
-            .expect("get_account");
+    let withdrawal_data =
+        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
 
-        assert_eq!(proxy_info, None);
-    }
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
 }
 
 #[tokio::test]
-async fn test_withdraw_ever_request_with_payload() {
+async fn close_withdrawal() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -7706,119 +20411,33 @@ async fn test_withdraw_ever_request_with_payload() {
         guardian,
         manager,
         withdrawal_manager,
-    };
-
-    let mut settings_packed = vec![0; Settings::LEN];
-    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
-    program_test.add_account(
-        settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(Settings::LEN),
-            data: settings_packed,
-            owner: token_proxy::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Round Loader Settings Account
-    let round_number = 12;
-
-    let rl_settings_address = get_associated_settings_address(&round_loader::id());
-
-    let (_, rl_settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
-
-    let round_ttl = 1209600;
-    let rl_settings_account_data = round_loader::Settings {
-        is_initialized: true,
-        account_kind: AccountKind::Settings(rl_settings_nonce, 0),
-        current_round_number: round_number,
-        round_submitter: Pubkey::new_unique(),
-        min_required_votes: 1,
-        round_ttl: 0,
-    };
-
-    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
-    round_loader::Settings::pack(rl_settings_account_data, &mut rl_settings_packed).unwrap();
-    program_test.add_account(
-        rl_settings_address,
-        Account {
-            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
-            data: rl_settings_packed,
-            owner: round_loader::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Relay Accounts
-    let relays = vec![
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-        Keypair::new(),
-    ];
-
-    for relay in &relays {
-        program_test.add_account(
-            relay.pubkey(),
-            Account {
-                lamports: 1_000_000_000,
-                data: vec![],
-                owner: solana_program::system_program::id(),
-                executable: false,
-                rent_epoch: 0,
-            },
-        );
-    }
-
-    // Add Relay Round Account
-    let relay_round_address =
-        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
-
-    let (_, relay_round_nonce) = Pubkey::find_program_address(
-        &[br"relay_round", &round_number.to_le_bytes()],
-        &round_loader::id(),
-    );
-
-    let round_end = round_ttl + chrono::Utc::now().timestamp() as u32;
-
-    let relay_round_data = round_loader::RelayRound {
-        is_initialized: true,
-        account_kind: AccountKind::RelayRound(relay_round_nonce),
-        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
-        round_number,
-        round_end,
-    };
-
-    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
-    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
-
-    program_test.add_account(
-        relay_round_address,
-        Account {
-            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
-            data: relay_round_packed,
-            owner: round_loader::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
+    };
 
-    // Add Author Account
-    let author = Keypair::new();
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
     program_test.add_account(
-        author.pubkey(),
+        settings_address,
         Account {
-            lamports: 1_000_000_000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
@@ -7827,18 +20446,11 @@ async fn test_withdraw_ever_request_with_payload() {
     // Add Mint Account
     let decimals = spl_token::native_mint::DECIMALS;
 
-    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
-    let token_hash = hash(&token.try_to_vec().unwrap());
-
-    let (_, mint_nonce) =
-        Pubkey::find_program_address(&[br"mint", &token_hash.as_ref()], &token_proxy::id());
-
-    let mint = get_mint_address(&token);
+    let mint_address = Pubkey::new_unique();
 
     let mint_account_data = spl_token::state::Mint {
         is_initialized: true,
-        mint_authority: program_option::COption::Some(mint),
-        supply: 0,
+        mint_authority: program_option::COption::Some(mint_address),
         decimals,
         ..Default::default()
     };
@@ -7846,7 +20458,7 @@ async fn test_withdraw_ever_request_with_payload() {
     let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
     spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
     program_test.add_account(
-        mint,
+        mint_address,
         Account {
             lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
             data: mint_packed,
@@ -7856,27 +20468,82 @@ async fn test_withdraw_ever_request_with_payload() {
         },
     );
 
+    // Add Vault Account
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
+
+    let vault_address = get_vault_address(&mint_address);
+
+    let vault_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: vault_address,
+        amount: 100,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: vault_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Recipient Token Account
+    let recipient = Pubkey::new_unique();
+
+    let token_wallet =
+        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
+
+    let token_wallet_account_data = spl_token::state::Account {
+        mint: mint_address,
+        owner: recipient,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+
+    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
+    program_test.add_account(
+        token_wallet,
+        Account {
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: token_wallet_packed,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     // Add Token Settings Account
     let symbol = "USDT".to_string();
     let name = "USDT Solana Octusbridge".to_string();
     let deposit_limit = u64::MAX;
     let withdrawal_limit = u64::MAX;
     let withdrawal_daily_limit = u64::MAX;
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
 
-    let token_settings_address = get_token_settings_ever_address(&token);
+    let (_, token_settings_nonce) = Pubkey::find_program_address(
+        &[br"settings", &mint_address.to_bytes()],
+        &token_proxy::id(),
+    );
+
+    let token_settings_address = get_token_settings_sol_address(&mint_address);
 
     let token_settings_account_data = TokenSettings {
         is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
-        kind: TokenKind::Ever {
-            mint,
-            token,
-            decimals,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: mint_address,
+            vault: vault_address,
         },
-        name: name.clone(),
-        symbol: symbol.clone(),
+        name,
+        symbol,
         deposit_limit,
         withdrawal_limit,
         withdrawal_daily_limit,
@@ -7886,8 +20553,30 @@ async fn test_withdraw_ever_request_with_payload() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        deposit_daily_limit: None,
+        deposit_daily_amount: 0,
+        deposit_epoch: 0,
+        withdrawal_epoch_length: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        delisted: false,
+        authority_migration: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        vote_reward: 0,
+        withdrawal_limit_exemptions: vec![],
+        version: CURRENT_ACCOUNT_VERSION,
+        fee_deposit_tiers: Default::default(),
+        fee_withdrawal_tiers: Default::default(),
+        min_deposit_amount: 0,
+        fills_restricted: false,
+        referral_fee_bps: 0,
+        pending_withdrawals: 0,
+        closed: false,
     };
 
+    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
+
     let mut token_settings_packed = vec![0; TokenSettings::LEN];
     TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
     program_test.add_account(
@@ -7901,150 +20590,30 @@ async fn test_withdraw_ever_request_with_payload() {
         },
     );
 
-    // Add recipient
-    let recipient = Keypair::new();
-    program_test.add_account(
-        recipient.pubkey(),
-        Account {
-            lamports: 1000000000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Recipient Token Account
-    let token_wallet =
-        spl_associated_token_account::get_associated_token_address(&recipient.pubkey(), &mint);
-
-    let token_wallet_account_data = spl_token::state::Account {
-        mint,
-        owner: recipient.pubkey(),
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
-    program_test.add_account(
-        token_wallet,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: token_wallet_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+    // Add Withdrawal Account
+    let round_number = 7;
 
     let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
     let event_configuration = Pubkey::new_unique();
 
-    let recipient_token_address =
-        spl_associated_token_account::get_associated_token_address(&recipient.pubkey(), &mint);
-
     let amount = 32;
 
-    let (proxy_address, proxy_nonce) = Pubkey::find_program_address(
-        &[br"proxy", &mint.to_bytes(), &recipient.pubkey().to_bytes()],
-        &token_proxy::id(),
-    );
-
-    let payload = bincode::serialize(&vec![spl_token::instruction::transfer(
-        &spl_token::id(),
-        &proxy_address,
-        &recipient_token_address,
-        &proxy_address,
-        &[&proxy_address],
-        16,
-    )
-    .unwrap()])
-    .unwrap();
-
-    let attached_amount = 0;
-
-    let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_multi_token_ever_request_ix(
-            funder.pubkey(),
-            author.pubkey(),
-            round_number,
-            event_timestamp,
-            event_transaction_lt,
-            event_configuration,
-            token,
-            name.clone(),
-            symbol.clone(),
-            decimals,
-            recipient.pubkey(),
-            amount,
-            payload.clone(),
-            attached_amount,
-        )],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder, &author], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
+    let payload: Vec<u8> = vec![];
 
-    // Check Withdrawal Account
-    let withdrawal_address = get_withdrawal_ever_address(
+    let withdrawal_address = get_withdrawal_sol_address(
         round_number,
         event_timestamp,
         event_transaction_lt,
         &event_configuration,
-        token,
-        name.clone(),
-        symbol.clone(),
-        decimals,
-        recipient.pubkey(),
+        mint_address,
+        recipient,
         amount,
-        payload,
-    );
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let withdrawal_data =
-        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
-
-    assert_eq!(withdrawal_data.is_initialized, true);
-    assert_eq!(withdrawal_data.author, author.pubkey());
-    assert_eq!(withdrawal_data.round_number, round_number);
-
-    assert_eq!(
-        withdrawal_data.required_votes,
-        (relays.len() * 2 / 3 + 1) as u32
-    );
-
-    assert_eq!(withdrawal_data.pda.event_timestamp, event_timestamp);
-    assert_eq!(
-        withdrawal_data.pda.event_transaction_lt,
-        event_transaction_lt
+        payload.clone(),
     );
-    assert_eq!(withdrawal_data.pda.event_configuration, event_configuration);
-
-    assert_eq!(withdrawal_data.event.data.token, token);
-    assert_eq!(withdrawal_data.event.data.name, name);
-    assert_eq!(withdrawal_data.event.data.symbol, symbol);
-    assert_eq!(withdrawal_data.event.data.decimals, decimals);
-    assert_eq!(withdrawal_data.event.data.amount, amount);
-    assert_eq!(withdrawal_data.event.data.recipient, recipient.pubkey());
-
-    assert_ne!(withdrawal_data.meta.data.epoch, 0);
-    assert_eq!(withdrawal_data.meta.data.bounty, 0);
-    assert_eq!(withdrawal_data.meta.data.status, WithdrawalTokenStatus::New);
 
-    let event_data = hash(&withdrawal_data.event.data.try_to_vec().expect("pack")).to_bytes();
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
 
     let (_, withdrawal_nonce) = Pubkey::find_program_address(
         &[
@@ -8058,58 +20627,128 @@ async fn test_withdraw_ever_request_with_payload() {
         &token_proxy::id(),
     );
 
-    assert_eq!(
-        withdrawal_data.account_kind,
-        AccountKind::Proposal(withdrawal_nonce, Some(proxy_nonce))
+    let signers = vec![Vote::Confirm; 3];
+
+    // Add Author Account
+    let author = Pubkey::new_unique();
+    program_test.add_account(
+        author,
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
 
-    // Check Proposal Account
-    let proposal_data =
-        Proposal::unpack_from_slice(withdrawal_info.data()).expect("withdrawal token unpack");
+    let withdrawal_account_data = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author,
+        round_number,
+        event,
+        meta: WithdrawalTokenMetaWithLen::new(0, 0, author, 0),
+        required_votes: signers.len() as u32,
+        signers: signers.clone(),
+        chain_id: None,
+        vote_rewards: vec![],
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        version: CURRENT_ACCOUNT_VERSION,
+        confirmed_weight: 0,
+    };
 
-    assert_eq!(
-        proposal_data.event,
-        withdrawal_data.event.data.try_to_vec().unwrap()
+    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+                + Rent::default().minimum_balance(TokenSettings::LEN)
+                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
-    assert_eq!(
-        proposal_data.meta,
-        withdrawal_data.meta.data.try_to_vec().unwrap()
+
+    // Add Relay Round Accounts
+    let rl_settings_address = get_associated_settings_address(&round_loader::id());
+
+    let mut rl_settings_packed = vec![0; round_loader::Settings::LEN];
+    round_loader::Settings::pack(
+        bridge_test_utils::round_loader_settings(round_number),
+        &mut rl_settings_packed,
+    )
+    .unwrap();
+    program_test.add_account(
+        rl_settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::Settings::LEN),
+            data: rl_settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
     );
 
-    // Check Proxy Account
-    let proxy_address = get_proxy_address(&mint, &recipient.pubkey());
+    let relay_round_address =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
-    let proxy_info = banks_client
-        .get_account(proxy_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
 
-    assert_eq!(proxy_info.data.len(), spl_token::state::Account::LEN);
+    let relay_round_data = round_loader::RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        relays: vec![
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ],
+        round_number,
+        round_end: u32::MAX,
+        weights: vec![],
+        quorum_numerator: 0,
+        quorum_denominator: 0,
+    };
 
-    // Vote for withdrawal request
-    for relay in &relays {
-        let mut transaction = Transaction::new_with_payer(
-            &[vote_for_withdrawal_request_ix(
-                relay.pubkey(),
-                withdrawal_address,
-                round_number,
-                Vote::Confirm,
-            )],
-            Some(&funder.pubkey()),
-        );
-        transaction.sign(&[&funder, &relay], recent_blockhash);
+    let mut relay_round_packed = vec![0; round_loader::RelayRound::LEN];
+    round_loader::RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(round_loader::RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
 
-        let _ = banks_client.process_transaction(transaction).await;
-    }
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
-    // Execute withdrawal
     let mut transaction = Transaction::new_with_payer(
-        &[create_ever_token_with_payload_ix(
-            funder.pubkey(),
+        &[withdrawal_sol_ix(
             withdrawal_address,
-            recipient.pubkey(),
-            token,
+            token_wallet,
+            recipient,
+            mint_address,
+            round_number,
+            get_proposal_index_address(
+                event_timestamp,
+                event_transaction_lt,
+                &event_configuration,
+                None,
+            ),
         )],
         Some(&funder.pubkey()),
     );
@@ -8120,45 +20759,14 @@ async fn test_withdraw_ever_request_with_payload() {
         .await
         .expect("process_transaction");
 
-    // Check Token Settings Account
-    let token_settings_address = get_token_settings_ever_address(&token);
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
+    // Check Vault Balance
+    let vault_info = banks_client
+        .get_account(vault_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("deposit token unpack");
-
-    assert_eq!(token_settings_data.is_initialized, true);
-    assert_eq!(token_settings_data.deposit_limit, u64::MAX);
-    assert_eq!(token_settings_data.withdrawal_limit, u64::MAX);
-    assert_eq!(token_settings_data.withdrawal_daily_limit, u64::MAX);
-    assert_eq!(token_settings_data.emergency, false);
-
-    assert_eq!(
-        token_settings_data.kind,
-        TokenKind::Ever {
-            mint,
-            token,
-            decimals,
-        }
-    );
-
-    let token_hash = hash(&token.try_to_vec().unwrap());
-
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], &token_proxy::id());
-    let (_, mint_nonce) =
-        Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], &token_proxy::id());
-
-    assert_eq!(
-        token_settings_data.account_kind,
-        AccountKind::TokenSettings(token_settings_nonce, mint_nonce)
-    );
-
-    let fee_info = &token_settings_data.fee_withdrawal_info;
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
 
     let fee = 1.max(
         (amount as u64)
@@ -8170,83 +20778,162 @@ async fn test_withdraw_ever_request_with_payload() {
 
     let transfer_amount = amount as u64 - fee;
 
-    assert_eq!(token_settings_data.withdrawal_daily_amount, transfer_amount);
+    assert_eq!(vault_data.amount, 100 - transfer_amount);
 
-    // Check Mint supply
-    let mint_info = banks_client
-        .get_account(mint)
+    // Check Recipient Balance
+    let recipient_info = banks_client
+        .get_account(token_wallet)
         .await
         .expect("get_account")
         .expect("account");
 
-    let mint_data = spl_token::state::Mint::unpack(mint_info.data()).expect("mint unpack");
-    assert_eq!(mint_data.supply, transfer_amount);
+    let recipient_data =
+        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
+    assert_eq!(recipient_data.amount, transfer_amount);
 
-    // Check Proxy Balance
-    let proxy_info = banks_client
-        .get_account(proxy_address)
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
-    assert_eq!(proxy_data.amount, transfer_amount);
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
 
-    // Withdrawal token from Proxy Account
+    assert_eq!(
+        withdrawal_data.meta.data.status,
+        WithdrawalTokenStatus::Processed
+    );
+
+    // Close Withdrawal
     let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_proxy_ix(
-            recipient.pubkey(),
-            recipient_token_address,
-            mint,
-            15,
-        )],
+        &[close_withdrawal_ix(withdrawal_address, author)],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &recipient], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    // Check Proxy Balance
-    let proxy_info = banks_client
-        .get_account(proxy_address)
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
         .await
-        .expect("get_account")
-        .expect("account");
+        .expect("get_account");
 
-    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
-    assert_eq!(proxy_data.amount, 16);
+    assert_eq!(withdrawal_info, None);
+}
 
-    // Check Recipient Balance
-    let recipient_token_info = banks_client
-        .get_account(recipient_token_address)
-        .await
-        .expect("get_account")
-        .expect("account");
+#[tokio::test]
+async fn close_legacy_withdrawal() {
+    let mut program_test = ProgramTest::new(
+        "token_proxy",
+        token_proxy::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Author Account
+    let author = Pubkey::new_unique();
+    program_test.add_account(
+        author,
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Withdrawal Account in legacy layout
+    let round_number = 7;
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 32;
+    let payload: Vec<u8> = vec![];
+
+    let withdrawal_address = get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, amount, recipient, payload);
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+
+    let (_, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data,
+        ],
+        &token_proxy::id(),
+    );
 
-    let recipient_token_data =
-        spl_token::state::Account::unpack(recipient_token_info.data()).expect("proxy unpack");
-    assert_eq!(recipient_token_data.amount, 15);
+    // Legacy meta ends after epoch, so no funder is recorded
+    let legacy_meta = (WithdrawalTokenStatus::Processed, 0u64, 0i64)
+        .try_to_vec()
+        .expect("pack");
 
-    // Execute payload
-    let data = TokenProxyInstruction::ExecutePayloadEver
+    let mut withdrawal_packed = (
+        true,
+        AccountKind::Proposal(withdrawal_nonce, None),
+        author,
+        round_number,
+        1u32,
+        PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        event,
+        legacy_meta.len() as u32,
+    )
         .try_to_vec()
         .expect("pack");
+    withdrawal_packed.extend_from_slice(&legacy_meta);
+    withdrawal_packed.extend_from_slice(&vec![Vote::Confirm].try_to_vec().expect("pack"));
+    withdrawal_packed.resize(WithdrawalMultiTokenSol::LEN, 0);
 
-    let ix = Instruction {
-        program_id: id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_address, false),
-            AccountMeta::new(proxy_address, false),
-            AccountMeta::new(recipient_token_address, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
-        data,
-    };
+    let withdrawal_lamports = Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN);
 
-    let mut transaction = Transaction::new_with_payer(&[ix], Some(&funder.pubkey()));
+    program_test.add_account(
+        withdrawal_address,
+        Account {
+            lamports: withdrawal_lamports,
+            data: withdrawal_packed,
+            owner: token_proxy::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Close Withdrawal, rent goes back to author
+    let mut transaction = Transaction::new_with_payer(
+        &[close_withdrawal_ix(withdrawal_address, author)],
+        Some(&funder.pubkey()),
+    );
     transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
@@ -8254,45 +20941,26 @@ async fn test_withdraw_ever_request_with_payload() {
         .await
         .expect("process_transaction");
 
-    // Check Proxy Balance
-    let proxy_info = banks_client
-        .get_account(proxy_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let proxy_data = spl_token::state::Account::unpack(proxy_info.data()).expect("proxy unpack");
-    assert_eq!(proxy_data.amount, 0);
-
-    // Check Recipient Balance
-    let recipient_token_info = banks_client
-        .get_account(recipient_token_address)
+    // Check Withdrawal Account
+    let withdrawal_info = banks_client
+        .get_account(withdrawal_address)
         .await
-        .expect("get_account")
-        .expect("account");
+        .expect("get_account");
 
-    let recipient_token_data =
-        spl_token::state::Account::unpack(recipient_token_info.data()).expect("proxy unpack");
-    assert_eq!(recipient_token_data.amount, transfer_amount);
+    assert_eq!(withdrawal_info, None);
 
-    // Check status
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
+    // Check Author Balance
+    let author_info = banks_client
+        .get_account(author)
         .await
         .expect("get_account")
         .expect("account");
 
-    let withdrawal_data =
-        WithdrawalMultiTokenEver::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
-
-    assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Processed
-    );
+    assert_eq!(author_info.lamports, 1_000_000_000 + withdrawal_lamports);
 }
 
 #[tokio::test]
-async fn close_withdrawal() {
+async fn test_close_deposit() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -8316,6 +20984,23 @@ async fn close_withdrawal() {
         guardian,
         manager,
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -8332,13 +21017,13 @@ async fn close_withdrawal() {
     );
 
     // Add Mint Account
-    let decimals = spl_token::native_mint::DECIMALS;
+    let mint = Pubkey::new_unique();
 
-    let mint_address = Pubkey::new_unique();
+    let decimals = spl_token::native_mint::DECIMALS;
 
     let mint_account_data = spl_token::state::Mint {
         is_initialized: true,
-        mint_authority: program_option::COption::Some(mint_address),
+        mint_authority: program_option::COption::Some(mint),
         decimals,
         ..Default::default()
     };
@@ -8346,7 +21031,7 @@ async fn close_withdrawal() {
     let mut mint_packed = vec![0; spl_token::state::Mint::LEN];
     spl_token::state::Mint::pack(mint_account_data, &mut mint_packed).unwrap();
     program_test.add_account(
-        mint_address,
+        mint,
         Account {
             lamports: Rent::default().minimum_balance(spl_token::state::Mint::LEN),
             data: mint_packed,
@@ -8356,151 +21041,35 @@ async fn close_withdrawal() {
         },
     );
 
-    // Add Vault Account
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint_address.to_bytes()], &token_proxy::id());
-
-    let vault_address = get_vault_address(&mint_address);
-
-    let vault_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: vault_address,
-        amount: 100,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut vault_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(vault_account_data, &mut vault_packed).unwrap();
-    program_test.add_account(
-        vault_address,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: vault_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Recipient Token Account
-    let recipient = Pubkey::new_unique();
-
-    let token_wallet =
-        spl_associated_token_account::get_associated_token_address(&recipient, &mint_address);
-
-    let token_wallet_account_data = spl_token::state::Account {
-        mint: mint_address,
-        owner: recipient,
-        state: AccountState::Initialized,
-        ..Default::default()
-    };
-
-    let mut token_wallet_packed = vec![0; spl_token::state::Account::LEN];
-    spl_token::state::Account::pack(token_wallet_account_data, &mut token_wallet_packed).unwrap();
-    program_test.add_account(
-        token_wallet,
-        Account {
-            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
-            data: token_wallet_packed,
-            owner: spl_token::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
-    // Add Token Settings Account
-    let symbol = "USDT".to_string();
-    let name = "USDT Solana Octusbridge".to_string();
-    let deposit_limit = u64::MAX;
-    let withdrawal_limit = u64::MAX;
-    let withdrawal_daily_limit = u64::MAX;
-
-    let (_, token_settings_nonce) = Pubkey::find_program_address(
-        &[br"settings", &mint_address.to_bytes()],
-        &token_proxy::id(),
-    );
+    // Add MultiVault Account
+    let (_, multivault_nonce) = Pubkey::find_program_address(&[br"multivault"], &token_proxy::id());
 
-    let token_settings_address = get_token_settings_sol_address(&mint_address);
+    let multivault_address = get_multivault_address();
 
-    let token_settings_account_data = TokenSettings {
+    let multivault_account_data = MultiVault {
         is_initialized: true,
-        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
-        kind: TokenKind::Solana {
-            mint: mint_address,
-            vault: vault_address,
-        },
-        name,
-        symbol,
-        deposit_limit,
-        withdrawal_limit,
-        withdrawal_daily_limit,
-        withdrawal_daily_amount: 0,
-        withdrawal_epoch: 0,
-        emergency: false,
-        fee_supply: Default::default(),
-        fee_deposit_info: Default::default(),
-        fee_withdrawal_info: Default::default(),
+        account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
-    let fee_info = token_settings_account_data.fee_withdrawal_info.clone();
-
-    let mut token_settings_packed = vec![0; TokenSettings::LEN];
-    TokenSettings::pack(token_settings_account_data, &mut token_settings_packed).unwrap();
+    let mut multivault_packed = vec![0; MultiVault::LEN];
+    MultiVault::pack(multivault_account_data, &mut multivault_packed).unwrap();
     program_test.add_account(
-        token_settings_address,
+        multivault_address,
         Account {
-            lamports: Rent::default().minimum_balance(TokenSettings::LEN),
-            data: token_settings_packed,
+            lamports: Rent::default().minimum_balance(MultiVault::LEN),
+            data: multivault_packed,
             owner: token_proxy::id(),
             executable: false,
             rent_epoch: 0,
         },
     );
 
-    // Add Withdrawal Account
-    let round_number = 7;
-
-    let event_timestamp = 1650988297;
-    let event_transaction_lt = 1650988334;
-    let event_configuration = Pubkey::new_unique();
-
-    let amount = 32;
-
-    let payload: Vec<u8> = vec![];
-
-    let withdrawal_address = get_withdrawal_sol_address(
-        round_number,
-        event_timestamp,
-        event_transaction_lt,
-        &event_configuration,
-        mint_address,
-        recipient,
-        amount,
-        payload.clone(),
-    );
-
-    let event = WithdrawalMultiTokenSolEventWithLen::new(mint_address, amount, recipient, payload);
-    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
-
-    let (_, withdrawal_nonce) = Pubkey::find_program_address(
-        &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data,
-        ],
-        &token_proxy::id(),
-    );
-
-    let signers = vec![Vote::Confirm; 3];
+    // Add Sender Account
+    let sender = Keypair::new();
 
-    // Add Author Account
-    let author = Pubkey::new_unique();
     program_test.add_account(
-        author,
+        sender.pubkey(),
         Account {
             lamports: 1_000_000_000,
             data: vec![],
@@ -8508,34 +21077,28 @@ async fn close_withdrawal() {
             executable: false,
             rent_epoch: 0,
         },
-    );
-
-    let withdrawal_account_data = WithdrawalMultiTokenSol {
-        is_initialized: true,
-        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
-        author,
-        round_number,
-        event,
-        meta: WithdrawalTokenMetaWithLen::default(),
-        required_votes: signers.len() as u32,
-        signers: signers.clone(),
-        pda: PDA {
-            event_timestamp,
-            event_transaction_lt,
-            event_configuration,
-        },
+    );
+
+    // Add Sender Token Account
+    let sender_associated_token_address =
+        spl_associated_token_account::get_associated_token_address(&sender.pubkey(), &mint);
+
+    let sender_account_data = spl_token::state::Account {
+        mint,
+        owner: sender.pubkey(),
+        amount: 100,
+        state: AccountState::Initialized,
+        ..Default::default()
     };
 
-    let mut withdrawal_packed = vec![0; WithdrawalMultiTokenSol::LEN];
-    WithdrawalMultiTokenSol::pack(withdrawal_account_data, &mut withdrawal_packed).unwrap();
+    let mut sender_packed = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account::pack(sender_account_data, &mut sender_packed).unwrap();
     program_test.add_account(
-        withdrawal_address,
+        sender_associated_token_address,
         Account {
-            lamports: Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
-                + Rent::default().minimum_balance(TokenSettings::LEN)
-                + Rent::default().minimum_balance(spl_token::state::Mint::LEN),
-            data: withdrawal_packed,
-            owner: token_proxy::id(),
+            lamports: Rent::default().minimum_balance(spl_token::state::Account::LEN),
+            data: sender_packed,
+            owner: spl_token::id(),
             executable: false,
             rent_epoch: 0,
         },
@@ -8544,15 +21107,33 @@ async fn close_withdrawal() {
     // Start Program Test
     let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
 
+    let deposit_seed = uuid::Uuid::new_v4().as_u128();
+    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let amount = 32;
+    let value = 1000;
+    let payload: Vec<u8> = vec![];
+    let expected_evers = UInt256::default();
+    let name = "USDC ETHEREUM OCTUSBRIDGE".to_string();
+    let symbol = "USDC".to_string();
+
     let mut transaction = Transaction::new_with_payer(
-        &[withdrawal_sol_ix(
-            withdrawal_address,
-            token_wallet,
-            mint_address,
+        &[deposit_multi_token_sol_ix(
+            funder.pubkey(),
+            sender.pubkey(),
+            sender_associated_token_address,
+            mint,
+            deposit_seed,
+            name.clone(),
+            symbol.clone(),
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload.clone(),
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder], recent_blockhash);
+    transaction.sign(&[&funder, &sender], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
@@ -8560,6 +21141,8 @@ async fn close_withdrawal() {
         .expect("process_transaction");
 
     // Check Vault Balance
+    let vault_address = get_vault_address(&mint);
+
     let vault_info = banks_client
         .get_account(vault_address)
         .await
@@ -8567,68 +21150,144 @@ async fn close_withdrawal() {
         .expect("account");
 
     let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, amount);
 
-    let fee = 1.max(
-        (amount as u64)
-            .checked_div(fee_info.divisor)
-            .unwrap()
-            .checked_mul(fee_info.multiplier)
-            .unwrap(),
-    );
-
-    let transfer_amount = amount as u64 - fee;
+    // Check Sender Valance
+    let sender_info = banks_client
+        .get_account(sender_associated_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    assert_eq!(vault_data.amount, 100 - transfer_amount);
+    let sender_data = spl_token::state::Account::unpack(sender_info.data()).expect("token unpack");
+    assert_eq!(sender_data.amount, 100 - amount);
 
-    // Check Recipient Balance
-    let recipient_info = banks_client
-        .get_account(token_wallet)
+    // Check Token Settings Account
+    let token_settings_address = get_token_settings_sol_address(&mint);
+    let token_settings_info = banks_client
+        .get_account(token_settings_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let recipient_data =
-        spl_token::state::Account::unpack(recipient_info.data()).expect("recipient token unpack");
-    assert_eq!(recipient_data.amount, transfer_amount);
+    let token_settings_data =
+        TokenSettings::unpack(token_settings_info.data()).expect("deposit token unpack");
 
-    // Check Withdrawal Account
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
+    assert_eq!(token_settings_data.is_initialized, true);
+    assert_eq!(token_settings_data.withdrawal_epoch, 0);
+    assert_eq!(token_settings_data.deposit_limit, u64::MAX);
+    assert_eq!(token_settings_data.withdrawal_limit, u64::MAX);
+    assert_eq!(token_settings_data.withdrawal_daily_limit, u64::MAX);
+    assert_eq!(token_settings_data.withdrawal_daily_amount, 0);
+    assert_eq!(token_settings_data.emergency, false);
+
+    assert_eq!(
+        token_settings_data.kind,
+        TokenKind::Solana {
+            mint,
+            vault: vault_address
+        }
+    );
+
+    let (_, token_settings_nonce) =
+        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
+    let (_, vault_nonce) =
+        Pubkey::find_program_address(&[br"vault", &mint.to_bytes()], &token_proxy::id());
+
+    assert_eq!(
+        token_settings_data.account_kind,
+        AccountKind::TokenSettings(token_settings_nonce, vault_nonce)
+    );
+
+    // Check Deposit Account
+    let deposit_address = get_deposit_address(deposit_seed);
+    let deposit_info = banks_client
+        .get_account(deposit_address)
         .await
         .expect("get_account")
         .expect("account");
 
-    let withdrawal_data =
-        WithdrawalMultiTokenSol::unpack(withdrawal_info.data()).expect("withdrawal token unpack");
+    let deposit_data =
+        DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit token unpack");
+
+    assert_eq!(deposit_data.is_initialized, true);
+
+    let (_, deposit_nonce) = Pubkey::find_program_address(
+        &[br"deposit", &deposit_seed.to_le_bytes()],
+        &token_proxy::id(),
+    );
+    assert_eq!(
+        deposit_data.account_kind,
+        AccountKind::Deposit(deposit_nonce)
+    );
+
+    assert_eq!(deposit_data.event.data.base_token, mint);
+    assert_eq!(deposit_data.event.data.name, name);
+    assert_eq!(deposit_data.event.data.symbol, symbol);
+    assert_eq!(deposit_data.event.data.decimals, decimals);
+    assert_eq!(deposit_data.event.data.value, value);
+    assert_eq!(deposit_data.event.data.recipient, recipient);
+    assert_eq!(deposit_data.event.data.payload, payload);
+
+    assert_eq!(deposit_data.meta.data.seed, deposit_seed);
+    assert_eq!(deposit_data.meta.data.funder, funder.pubkey());
+    assert_ne!(deposit_data.meta.data.slot, 0);
+
+    let fee_info = &token_settings_data.fee_deposit_info;
+    let fee = 1.max(
+        (amount)
+            .checked_div(fee_info.divisor)
+            .unwrap()
+            .checked_mul(fee_info.multiplier)
+            .unwrap(),
+    );
+
+    let transfer_amount = amount - fee;
+
+    assert_eq!(deposit_data.event.data.amount, transfer_amount as u128);
+    assert_eq!(deposit_data.event.data.fee, fee as u128);
+    assert_eq!(deposit_data.event.data.gross_amount, amount as u128);
+
+    // Check Deposit Account to unpack
+    let raw_deposit_data =
+        Deposit::unpack_from_slice(deposit_info.data()).expect("deposit token unpack");
 
     assert_eq!(
-        withdrawal_data.meta.data.status,
-        WithdrawalTokenStatus::Processed
+        raw_deposit_data.event,
+        deposit_data.event.data.try_to_vec().unwrap()
+    );
+    assert_eq!(
+        raw_deposit_data.meta,
+        deposit_data.meta.data.try_to_vec().unwrap()
     );
 
-    // Close Withdrawal
+    // Close Deposit
     let mut transaction = Transaction::new_with_payer(
-        &[close_withdrawal_ix(withdrawal_address, author)],
+        &[close_deposit_ix(
+            sender.pubkey(),
+            deposit_address,
+            funder.pubkey(),
+        )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder], recent_blockhash);
+    transaction.sign(&[&funder, &sender], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    // Check Withdrawal Account
-    let withdrawal_info = banks_client
-        .get_account(withdrawal_address)
+    // Check Deposit Account
+    let deposit_info = banks_client
+        .get_account(deposit_address)
         .await
         .expect("get_account");
 
-    assert_eq!(withdrawal_info, None);
+    assert_eq!(deposit_info, None);
 }
 
 #[tokio::test]
-async fn test_close_deposit() {
+async fn test_cancel_deposit_sol() {
     let mut program_test = ProgramTest::new(
         "token_proxy",
         token_proxy::id(),
@@ -8640,7 +21299,7 @@ async fn test_close_deposit() {
     // Add Settings Account
     let guardian = Pubkey::new_unique();
     let manager = Pubkey::new_unique();
-    let withdrawal_manager = Pubkey::new_unique();
+    let withdrawal_manager = Keypair::new();
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &token_proxy::id());
 
     let settings_address = get_settings_address();
@@ -8651,7 +21310,24 @@ async fn test_close_deposit() {
         emergency: false,
         guardian,
         manager,
-        withdrawal_manager,
+        withdrawal_manager: withdrawal_manager.pubkey(),
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -8700,6 +21376,7 @@ async fn test_close_deposit() {
     let multivault_account_data = MultiVault {
         is_initialized: true,
         account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut multivault_packed = vec![0; MultiVault::LEN];
@@ -8773,17 +21450,85 @@ async fn test_close_deposit() {
             sender_associated_token_address,
             mint,
             deposit_seed,
-            name.clone(),
-            symbol.clone(),
-            amount,
-            recipient,
-            value,
-            expected_evers,
-            payload.clone(),
+            name.clone(),
+            symbol.clone(),
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Vault Balance
+    let vault_address = get_vault_address(&mint);
+
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
+    assert_eq!(vault_data.amount, amount);
+
+    // Check Sender Balance
+    let sender_info = banks_client
+        .get_account(sender_associated_token_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let sender_data = spl_token::state::Account::unpack(sender_info.data()).expect("token unpack");
+    assert_eq!(sender_data.amount, 100 - amount);
+
+    // Check Deposit Account
+    let deposit_address = get_deposit_address(deposit_seed);
+    let deposit_info = banks_client
+        .get_account(deposit_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let deposit_data =
+        DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit token unpack");
+
+    assert_eq!(deposit_data.meta.data.refunded, false);
+
+    let fee = deposit_data.event.data.fee as u64;
+
+    // Cancel Deposit by non withdrawal manager
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_deposit_sol_ix(
+            sender.pubkey(),
+            sender_associated_token_address,
+            mint,
+            deposit_seed,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &sender], recent_blockhash);
+
+    assert!(banks_client.process_transaction(transaction).await.is_err());
+
+    // Cancel Deposit
+    let mut transaction = Transaction::new_with_payer(
+        &[cancel_deposit_sol_ix(
+            withdrawal_manager.pubkey(),
+            sender_associated_token_address,
+            mint,
+            deposit_seed,
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &sender], recent_blockhash);
+    transaction.sign(&[&funder, &withdrawal_manager], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
@@ -8791,8 +21536,6 @@ async fn test_close_deposit() {
         .expect("process_transaction");
 
     // Check Vault Balance
-    let vault_address = get_vault_address(&mint);
-
     let vault_info = banks_client
         .get_account(vault_address)
         .await
@@ -8800,9 +21543,9 @@ async fn test_close_deposit() {
         .expect("account");
 
     let vault_data = spl_token::state::Account::unpack(vault_info.data()).expect("vault unpack");
-    assert_eq!(vault_data.amount, amount);
+    assert_eq!(vault_data.amount, fee);
 
-    // Check Sender Valance
+    // Check Sender Balance
     let sender_info = banks_client
         .get_account(sender_associated_token_address)
         .await
@@ -8810,47 +21553,9 @@ async fn test_close_deposit() {
         .expect("account");
 
     let sender_data = spl_token::state::Account::unpack(sender_info.data()).expect("token unpack");
-    assert_eq!(sender_data.amount, 100 - amount);
-
-    // Check Token Settings Account
-    let token_settings_address = get_token_settings_sol_address(&mint);
-    let token_settings_info = banks_client
-        .get_account(token_settings_address)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let token_settings_data =
-        TokenSettings::unpack(token_settings_info.data()).expect("deposit token unpack");
-
-    assert_eq!(token_settings_data.is_initialized, true);
-    assert_eq!(token_settings_data.withdrawal_epoch, 0);
-    assert_eq!(token_settings_data.deposit_limit, u64::MAX);
-    assert_eq!(token_settings_data.withdrawal_limit, u64::MAX);
-    assert_eq!(token_settings_data.withdrawal_daily_limit, u64::MAX);
-    assert_eq!(token_settings_data.withdrawal_daily_amount, 0);
-    assert_eq!(token_settings_data.emergency, false);
-
-    assert_eq!(
-        token_settings_data.kind,
-        TokenKind::Solana {
-            mint,
-            vault: vault_address
-        }
-    );
-
-    let (_, token_settings_nonce) =
-        Pubkey::find_program_address(&[br"settings", &mint.to_bytes()], &token_proxy::id());
-    let (_, vault_nonce) =
-        Pubkey::find_program_address(&[br"vault", &mint.to_bytes()], &token_proxy::id());
-
-    assert_eq!(
-        token_settings_data.account_kind,
-        AccountKind::TokenSettings(token_settings_nonce, vault_nonce)
-    );
+    assert_eq!(sender_data.amount, 100 - fee);
 
     // Check Deposit Account
-    let deposit_address = get_deposit_address(deposit_seed);
     let deposit_info = banks_client
         .get_account(deposit_address)
         .await
@@ -8860,72 +21565,9 @@ async fn test_close_deposit() {
     let deposit_data =
         DepositMultiTokenSol::unpack(deposit_info.data()).expect("deposit token unpack");
 
-    assert_eq!(deposit_data.is_initialized, true);
-
-    let (_, deposit_nonce) = Pubkey::find_program_address(
-        &[br"deposit", &deposit_seed.to_le_bytes()],
-        &token_proxy::id(),
-    );
-    assert_eq!(
-        deposit_data.account_kind,
-        AccountKind::Deposit(deposit_nonce)
-    );
-
-    assert_eq!(deposit_data.event.data.base_token, mint);
-    assert_eq!(deposit_data.event.data.name, name);
-    assert_eq!(deposit_data.event.data.symbol, symbol);
-    assert_eq!(deposit_data.event.data.decimals, decimals);
-    assert_eq!(deposit_data.event.data.value, value);
-    assert_eq!(deposit_data.event.data.recipient, recipient);
-    assert_eq!(deposit_data.event.data.payload, payload);
-
+    assert_eq!(deposit_data.meta.data.refunded, true);
     assert_eq!(deposit_data.meta.data.seed, deposit_seed);
-
-    let fee_info = &token_settings_data.fee_deposit_info;
-    let fee = 1.max(
-        (amount)
-            .checked_div(fee_info.divisor)
-            .unwrap()
-            .checked_mul(fee_info.multiplier)
-            .unwrap(),
-    );
-
-    let transfer_amount = amount - fee;
-
-    assert_eq!(deposit_data.event.data.amount, transfer_amount as u128);
-
-    // Check Deposit Account to unpack
-    let raw_deposit_data =
-        Deposit::unpack_from_slice(deposit_info.data()).expect("deposit token unpack");
-
-    assert_eq!(
-        raw_deposit_data.event,
-        deposit_data.event.data.try_to_vec().unwrap()
-    );
-    assert_eq!(
-        raw_deposit_data.meta,
-        deposit_data.meta.data.try_to_vec().unwrap()
-    );
-
-    // Close Deposit
-    let mut transaction = Transaction::new_with_payer(
-        &[close_deposit_ix(sender.pubkey(), deposit_address)],
-        Some(&funder.pubkey()),
-    );
-    transaction.sign(&[&funder, &sender], recent_blockhash);
-
-    banks_client
-        .process_transaction(transaction)
-        .await
-        .expect("process_transaction");
-
-    // Check Deposit Account
-    let deposit_info = banks_client
-        .get_account(deposit_address)
-        .await
-        .expect("get_account");
-
-    assert_eq!(deposit_info, None);
+    assert_eq!(deposit_data.version, CURRENT_ACCOUNT_VERSION);
 }
 
 #[tokio::test]
@@ -8954,6 +21596,23 @@ async fn test_withdrawal_multi_vault() {
         guardian,
         manager: manager.pubkey(),
         withdrawal_manager,
+        payload_allowlist: vec![],
+        deposit_caller_allowlist: None,
+        emergency_expiry: None,
+        emergency_info: None,
+        token_defaults: None,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        guardians: vec![],
+        treasury: None,
+        wever_mint: None,
+        wever_defaults: None,
+        enabled_chains: vec![],
+        deposit_value_floor: None,
+        min_supported_ix_version: 0,
+        pending_role: None,
+        timelock_delay: 0,
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -8990,6 +21649,7 @@ async fn test_withdrawal_multi_vault() {
     let multivault_account_data = MultiVault {
         is_initialized: true,
         account_kind: AccountKind::MultiVault(multivault_nonce),
+        version: CURRENT_ACCOUNT_VERSION,
     };
 
     let rent = Rent::default().minimum_balance(MultiVault::LEN);
@@ -9045,3 +21705,422 @@ async fn test_withdrawal_multi_vault() {
 
     assert_eq!(recipient_info.lamports(), amount);
 }
+
+#[test]
+fn test_compile_message_v0_with_lookup_table() {
+    let payer = Pubkey::new_unique();
+    let withdrawal_address = Pubkey::new_unique();
+    let recipient_token_address = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let proposal_index_address = Pubkey::new_unique();
+    let lookup_table_address = Pubkey::new_unique();
+
+    let ix = withdrawal_sol_ix(
+        withdrawal_address,
+        recipient_token_address,
+        Pubkey::new_unique(),
+        mint,
+        7,
+        proposal_index_address,
+    );
+
+    let message = compile_message_v0(
+        &payer,
+        &[ix],
+        lookup_table_address,
+        &[mint],
+        &[],
+        solana_program::hash::Hash::default(),
+    )
+    .expect("compile");
+
+    // Bridge accounts are resolved through the lookup table
+    assert_eq!(message.address_table_lookups.len(), 1);
+    assert_eq!(
+        message.address_table_lookups[0].account_key,
+        lookup_table_address
+    );
+
+    let vault_address = get_vault_address(&mint);
+    assert!(!message.account_keys.contains(&vault_address));
+
+    // Accounts specific to withdrawal stay in the message
+    assert!(message.account_keys.contains(&payer));
+    assert!(message.account_keys.contains(&withdrawal_address));
+    assert!(message.account_keys.contains(&recipient_token_address));
+    assert!(message.account_keys.contains(&proposal_index_address));
+}
+
+#[test]
+fn test_decode_instruction() {
+    let owner = Pubkey::new_unique();
+    let token_settings_address = Pubkey::new_unique();
+
+    let ix = change_deposit_limit_by_owner_ix(owner, token_settings_address, 100);
+
+    let decoded = TokenProxyInstruction::decode(&ix.data).expect("decode");
+    assert_eq!(decoded.name(), "ChangeDepositLimit");
+    assert!(matches!(
+        decoded,
+        TokenProxyInstruction::ChangeDepositLimit {
+            new_deposit_limit: 100
+        }
+    ));
+
+    // Owner signs with program data appended
+    let roles = decoded.expected_accounts();
+    assert_eq!(roles.len(), ix.accounts.len());
+    for (role, account) in roles.iter().zip(&ix.accounts) {
+        assert_eq!(role.is_signer, account.is_signer);
+        assert_eq!(role.is_writable, account.is_writable);
+    }
+    assert_eq!(roles[0].name, "authority");
+    assert_eq!(roles[3].name, "program_data");
+    assert!(roles[3].is_optional);
+
+    let proposal_index_address = Pubkey::new_unique();
+
+    let ix = withdrawal_sol_ix(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        7,
+        proposal_index_address,
+    );
+
+    let decoded = TokenProxyInstruction::decode(&ix.data).expect("decode");
+    assert_eq!(decoded.name(), "WithdrawMultiTokenSol");
+
+    // Required accounts go first
+    let roles = decoded.expected_accounts();
+    let required = roles
+        .iter()
+        .take_while(|role| !role.is_optional && !role.is_list)
+        .count();
+    assert!(roles[required..]
+        .iter()
+        .all(|role| role.is_optional || role.is_list));
+    assert_eq!(
+        roles[required - 6..required]
+            .iter()
+            .map(|role| role.name)
+            .collect::<Vec<_>>(),
+        vec![
+            "relay_round",
+            "rl_settings",
+            "recipient_hook",
+            "denylist",
+            "bridge_stats",
+            "approval_queue"
+        ]
+    );
+
+    // Proposal index and fee ledger accounts are optional
+    assert_eq!(required + 2, ix.accounts.len());
+    assert_eq!(ix.accounts[required - 2].pubkey, get_bridge_stats_address());
+    assert_eq!(ix.accounts[required].pubkey, proposal_index_address);
+
+    assert!(TokenProxyInstruction::decode(&[u8::MAX]).is_err());
+}
+
+#[test]
+fn test_legacy_instruction_encodings() {
+    // Instructions built by existing clients keep decoding
+    let data = TokenProxyInstruction::ExecutePayloadEver
+        .try_to_vec()
+        .expect("pack");
+    assert_eq!(data, vec![3]);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::ExecutePayloadEver
+    ));
+
+    let data = TokenProxyInstruction::ExecutePayloadSol
+        .try_to_vec()
+        .expect("pack");
+    assert_eq!(data, vec![4]);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::ExecutePayloadSol
+    ));
+
+    let data = TokenProxyInstruction::EnableEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+    assert_eq!(data, vec![15]);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::EnableEmergencyMode
+    ));
+
+    let data = TokenProxyInstruction::EnableTokenEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+    assert_eq!(data, vec![17]);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::EnableTokenEmergencyMode
+    ));
+
+    let data = TokenProxyInstruction::DisableEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+    assert_eq!(data, vec![16]);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::DisableEmergencyMode
+    ));
+
+    let data = TokenProxyInstruction::DisableTokenEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+    assert_eq!(data, vec![18]);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::DisableTokenEmergencyMode
+    ));
+
+    let data = TokenProxyInstruction::ApproveWithdrawEver
+        .try_to_vec()
+        .expect("pack");
+    assert_eq!(data, vec![19]);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::ApproveWithdrawEver
+    ));
+
+    let data = TokenProxyInstruction::ApproveWithdrawSol
+        .try_to_vec()
+        .expect("pack");
+    assert_eq!(data, vec![20]);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::ApproveWithdrawSol
+    ));
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenSolRequest {
+        event_timestamp: 1650988297,
+        event_transaction_lt: 1650988334,
+        event_configuration: Pubkey::new_unique(),
+        recipient: Pubkey::new_unique(),
+        amount: 32,
+        payload: vec![],
+        attached_amount: 0,
+    }
+    .try_to_vec()
+    .expect("pack");
+    assert_eq!(data[0], 9);
+    assert_eq!(data.len(), 105);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::WithdrawMultiTokenSolRequest { amount: 32, .. }
+    ));
+
+    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    let data = TokenProxyInstruction::DepositMultiTokenEver {
+        deposit_seed: 1,
+        amount: 32,
+        recipient,
+        value: 0,
+        expected_evers: UInt256::default(),
+        payload: vec![],
+    }
+    .try_to_vec()
+    .expect("pack");
+    assert_eq!(data[0], 6);
+    assert_eq!(data.len(), 103);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::DepositMultiTokenEver { amount: 32, .. }
+    ));
+
+    let data = TokenProxyInstruction::DepositMultiTokenSol {
+        deposit_seed: 1,
+        name: "USDT".to_string(),
+        symbol: "USDT".to_string(),
+        amount: 32,
+        recipient,
+        value: 0,
+        expected_evers: UInt256::default(),
+        payload: vec![],
+    }
+    .try_to_vec()
+    .expect("pack");
+    assert_eq!(data[0], 7);
+    assert_eq!(data.len(), 119);
+    assert!(matches!(
+        TokenProxyInstruction::decode(&data).expect("decode"),
+        TokenProxyInstruction::DepositMultiTokenSol { amount: 32, .. }
+    ));
+}
+
+#[test]
+fn test_instruction_version() {
+    // Legacy encodings have no version
+    let ix = TokenProxyInstruction::WithdrawMultiTokenEver;
+    assert_eq!(ix.version(), LEGACY_IX_VERSION);
+
+    // Versioned encodings carry it in the leading byte
+    let data = TokenProxyInstruction::WithdrawMultiTokenEverV2 {
+        version: CURRENT_IX_VERSION,
+        create_if_needed: true,
+    }
+    .try_to_vec()
+    .expect("pack");
+    assert_eq!(data[1], CURRENT_IX_VERSION);
+
+    let ix = TokenProxyInstruction::decode(&data).expect("decode");
+    assert_eq!(ix.version(), CURRENT_IX_VERSION);
+}
+
+#[test]
+fn test_unpack_legacy_withdrawal() {
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let author = Pubkey::new_unique();
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(mint, 32, recipient, vec![]);
+    let pda = PDA {
+        event_timestamp: 1650988297,
+        event_transaction_lt: 1650988334,
+        event_configuration: Pubkey::new_unique(),
+    };
+
+    // Legacy meta ends after epoch
+    let legacy_meta = (WithdrawalTokenStatus::Pending, 5u64, 19000i64)
+        .try_to_vec()
+        .expect("pack");
+
+    let mut legacy_packed = (
+        true,
+        AccountKind::Proposal(254, None),
+        author,
+        7u32,
+        1u32,
+        pda,
+        event,
+        legacy_meta.len() as u32,
+    )
+        .try_to_vec()
+        .expect("pack");
+    legacy_packed.extend_from_slice(&legacy_meta);
+    legacy_packed.extend_from_slice(&vec![Vote::Confirm].try_to_vec().expect("pack"));
+    legacy_packed.resize(WithdrawalMultiTokenSol::LEN, 0);
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(&legacy_packed).expect("withdrawal unpack");
+
+    assert_eq!(withdrawal_data.author, author);
+    assert_eq!(withdrawal_data.event.data.recipient, recipient);
+    assert_eq!(withdrawal_data.signers, vec![Vote::Confirm]);
+
+    let meta = withdrawal_data.meta.data;
+    assert_eq!(meta.status, WithdrawalTokenStatus::Pending);
+    assert_eq!(meta.bounty, 5);
+    assert_eq!(meta.epoch, 19000);
+    assert_eq!(meta.funder, Pubkey::default());
+    assert_eq!(meta.payload_cursor, 0);
+    assert_eq!(meta.expires_at, 0);
+    assert_eq!(meta.filled_amount, 0);
+    assert!(!meta.rejected);
+
+    // Meta is also readable on its own
+    let meta = WithdrawalTokenMeta::try_from_slice(&legacy_meta).expect("meta unpack");
+    assert_eq!(meta.status, WithdrawalTokenStatus::Pending);
+    assert_eq!(meta.funder, Pubkey::default());
+}
+
+#[test]
+fn test_unpack_legacy_deposit_event() {
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+    let recipient = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    // Legacy event ends after payload
+    let legacy_event = (
+        token,
+        32u128,
+        recipient,
+        0u64,
+        UInt256::default(),
+        vec![1u8, 2, 3],
+    )
+        .try_to_vec()
+        .expect("pack");
+
+    let event =
+        DepositMultiTokenEverEvent::try_from_slice(&legacy_event).expect("deposit event unpack");
+
+    assert_eq!(event.amount, 32);
+    assert_eq!(event.payload, vec![1, 2, 3]);
+    assert_eq!(event.fee, 0);
+    assert_eq!(event.gross_amount, 0);
+    assert_eq!(event.reference, None);
+}
+
+#[test]
+fn test_track_pending_withdrawals() {
+    let mut token_settings = bridge_test_utils::sol_token_settings(Pubkey::new_unique());
+
+    // Withdrawal waits for approve, then for liquidity, then is filled
+    token_settings.track_withdrawal(
+        WithdrawalTokenStatus::New,
+        WithdrawalTokenStatus::WaitingForApprove,
+    );
+    assert_eq!(token_settings.pending_withdrawals, 1);
+
+    token_settings.track_withdrawal(
+        WithdrawalTokenStatus::WaitingForApprove,
+        WithdrawalTokenStatus::Pending,
+    );
+    assert_eq!(token_settings.pending_withdrawals, 1);
+
+    token_settings.track_withdrawal(
+        WithdrawalTokenStatus::Pending,
+        WithdrawalTokenStatus::Processed,
+    );
+    assert_eq!(token_settings.pending_withdrawals, 0);
+
+    // Withdrawals confirmed before counting are not subtracted below zero
+    token_settings.track_withdrawal(
+        WithdrawalTokenStatus::Pending,
+        WithdrawalTokenStatus::Cancelled,
+    );
+    assert_eq!(token_settings.pending_withdrawals, 0);
+
+    // Withdrawals paid out right away are not counted
+    token_settings.track_withdrawal(WithdrawalTokenStatus::New, WithdrawalTokenStatus::Processed);
+    assert_eq!(token_settings.pending_withdrawals, 0);
+}
+
+#[test]
+fn test_withdrawal_event_hash() {
+    let token = EverAddress::with_standart(0, Pubkey::new_unique().to_bytes());
+
+    // Streamed hash matches hash of borsh encoded event
+    let event = WithdrawalMultiTokenEverEventWithLen::new(
+        token,
+        "USDT".to_string(),
+        "USDT".to_string(),
+        6,
+        u128::MAX,
+        Pubkey::new_unique(),
+        vec![1, 2, 3],
+    );
+    assert_eq!(
+        event.data.hash().expect("hash"),
+        hash(&event.data.try_to_vec().expect("pack"))
+    );
+
+    let event = WithdrawalMultiTokenSolEventWithLen::new(
+        Pubkey::new_unique(),
+        u128::MAX,
+        Pubkey::new_unique(),
+        vec![1, 2, 3],
+    );
+    assert_eq!(
+        event.data.hash(),
+        hash(&event.data.try_to_vec().expect("pack"))
+    );
+}
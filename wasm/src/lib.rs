@@ -2,27 +2,34 @@ use std::str::FromStr;
 
 use base64::engine::general_purpose;
 use base64::Engine;
-use borsh::BorshSerialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::{JsCast, JsValue};
 
+use solana_program::address_lookup_table_account::AddressLookupTableAccount;
+use solana_program::clock::SECONDS_PER_DAY;
+use solana_program::hash::Hash;
 use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::message::{v0, Message, VersionedMessage};
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 use solana_program::{system_program, sysvar};
 
+use bridge_utils::errors::SolanaBridgeError;
 use bridge_utils::state::*;
 use bridge_utils::types::*;
 
-#[wasm_bindgen(js_name = "getMintAddress")]
+const SIGNATURE_BYTES: usize = 64;
+
+#[wasm_bindgen(js_name = "getMintAddress", unchecked_return_type = "Pubkey")]
 pub fn get_mint_address_request(token: String) -> Result<JsValue, JsValue> {
     let token = EverAddress::from_str(&token).handle_error()?;
     let mint_pubkey = token_proxy::get_mint_address(&token);
     return serde_wasm_bindgen::to_value(&mint_pubkey).handle_error();
 }
 
-#[wasm_bindgen(js_name = "getTokenSettingsAddress")]
+#[wasm_bindgen(js_name = "getTokenSettingsAddress", unchecked_return_type = "Pubkey")]
 pub fn get_token_settings_request(token: String, is_sol: bool) -> Result<JsValue, JsValue> {
     let token_settings_pubkey = if is_sol {
         let mint = Pubkey::from_str(token.as_str()).handle_error()?;
@@ -34,7 +41,72 @@ pub fn get_token_settings_request(token: String, is_sol: bool) -> Result<JsValue
     return serde_wasm_bindgen::to_value(&token_settings_pubkey).handle_error();
 }
 
-#[wasm_bindgen(js_name = "initializeSettings")]
+#[wasm_bindgen(
+    js_name = "deriveAllAddressesForToken",
+    unchecked_return_type = "WasmTokenAddresses"
+)]
+pub fn derive_all_addresses_for_token(token: String, is_sol: bool) -> Result<JsValue, JsValue> {
+    let (mint, vault, token_settings, token_decimals) = if is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        (
+            mint,
+            Some(token_proxy::get_vault_address(&mint)),
+            token_proxy::get_token_settings_sol_address(&mint),
+            None,
+        )
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        (
+            token_proxy::get_mint_address(&token),
+            None,
+            token_proxy::get_token_settings_ever_address(&token),
+            Some(token_proxy::get_token_decimals_address(&token)),
+        )
+    };
+
+    let addresses = WasmTokenAddresses {
+        mint,
+        vault,
+        settings: token_proxy::get_settings_address(),
+        token_settings,
+        multivault: token_proxy::get_multivault_address(),
+        fee_ledger: token_proxy::get_fee_ledger_address(&token_settings),
+        token_decimals,
+    };
+
+    return serde_wasm_bindgen::to_value(&addresses).handle_error();
+}
+
+#[wasm_bindgen(js_name = "toEverAmount", unchecked_return_type = "string")]
+pub fn to_ever_amount(
+    amount: String,
+    ever_decimals: u8,
+    solana_decimals: u8,
+) -> Result<JsValue, JsValue> {
+    let amount = u64::from_str(&amount).handle_error()?;
+
+    let ever_amount = bridge_utils::amount::to_ever_amount(amount, ever_decimals, solana_decimals)
+        .handle_error()?;
+
+    return serde_wasm_bindgen::to_value(&ever_amount.to_string()).handle_error();
+}
+
+#[wasm_bindgen(js_name = "toSolanaAmount", unchecked_return_type = "string")]
+pub fn to_solana_amount(
+    amount: String,
+    ever_decimals: u8,
+    solana_decimals: u8,
+) -> Result<JsValue, JsValue> {
+    let amount = u128::from_str(&amount).handle_error()?;
+
+    let solana_amount =
+        bridge_utils::amount::to_solana_amount(amount, ever_decimals, solana_decimals)
+            .handle_error()?;
+
+    return serde_wasm_bindgen::to_value(&solana_amount.to_string()).handle_error();
+}
+
+#[wasm_bindgen(js_name = "initializeSettings", unchecked_return_type = "Instruction")]
 pub fn initialize_settings_ix(
     funder_pubkey: String,
     initializer_pubkey: String,
@@ -51,6 +123,7 @@ pub fn initialize_settings_ix(
     let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
     let initializer_pubkey = Pubkey::from_str(initializer_pubkey.as_str()).handle_error()?;
     let multivault_pubkey = token_proxy::get_multivault_address();
+    let denylist_pubkey = token_proxy::get_denylist_address();
 
     let data = token_proxy::TokenProxyInstruction::Initialize {
         guardian,
@@ -70,6 +143,7 @@ pub fn initialize_settings_ix(
             AccountMeta::new_readonly(program_data_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(denylist_pubkey, false),
         ],
         data,
     };
@@ -77,7 +151,10 @@ pub fn initialize_settings_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "withdrawalMultiTokenEverRequest")]
+#[wasm_bindgen(
+    js_name = "withdrawalMultiTokenEverRequest",
+    unchecked_return_type = "Instruction"
+)]
 pub fn withdrawal_multi_token_ever_request_ix(
     funder_pubkey: String,
     author_pubkey: String,
@@ -120,8 +197,16 @@ pub fn withdrawal_multi_token_ever_request_ix(
         payload.clone(),
     );
 
+    let proposal_index_pubkey = token_proxy::get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
+
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
 
     let mut accounts = vec![
         AccountMeta::new(funder_pubkey, true),
@@ -132,6 +217,8 @@ pub fn withdrawal_multi_token_ever_request_ix(
         AccountMeta::new_readonly(system_program::id(), false),
         AccountMeta::new_readonly(sysvar::rent::id(), false),
         AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new(proposal_index_pubkey, false),
+        AccountMeta::new(bridge_stats_pubkey, false),
     ];
 
     if !payload.is_empty() {
@@ -168,7 +255,10 @@ pub fn withdrawal_multi_token_ever_request_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "withdrawalMultiTokenSolRequest")]
+#[wasm_bindgen(
+    js_name = "withdrawalMultiTokenSolRequest",
+    unchecked_return_type = "Instruction"
+)]
 pub fn withdrawal_multi_token_sol_request_ix(
     funder_pubkey: String,
     author_pubkey: String,
@@ -206,8 +296,16 @@ pub fn withdrawal_multi_token_sol_request_ix(
         payload.clone(),
     );
 
+    let proposal_index_pubkey = token_proxy::get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        None,
+    );
+
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
 
     let mut accounts = vec![
         AccountMeta::new(funder_pubkey, true),
@@ -219,6 +317,8 @@ pub fn withdrawal_multi_token_sol_request_ix(
         AccountMeta::new_readonly(system_program::id(), false),
         AccountMeta::new_readonly(sysvar::rent::id(), false),
         AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new(proposal_index_pubkey, false),
+        AccountMeta::new(bridge_stats_pubkey, false),
     ];
 
     if !payload.is_empty() {
@@ -250,20 +350,35 @@ pub fn withdrawal_multi_token_sol_request_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "withdrawalMultiTokenEver")]
+#[wasm_bindgen(
+    js_name = "withdrawalMultiTokenEver",
+    unchecked_return_type = "Instruction"
+)]
 pub fn withdrawal_multi_token_ever_ix(
     withdrawal_pubkey: String,
     recipient_token_pubkey: String,
+    recipient_pubkey: String,
     token: String,
+    round_number: u32,
+    proposal_index_pubkey: String,
 ) -> Result<JsValue, JsValue> {
     let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let proposal_index_pubkey = Pubkey::from_str(proposal_index_pubkey.as_str()).handle_error()?;
+    let relay_round_pubkey = token_proxy::get_relay_round_address(round_number);
+    let rl_settings_pubkey = token_proxy::get_rl_settings_address();
     let recipient_token_pubkey =
         Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
     let token = EverAddress::from_str(&token).handle_error()?;
 
     let settings_pubkey = token_proxy::get_settings_address();
     let mint_pubkey = token_proxy::get_mint_address(&token);
     let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = token_proxy::get_denylist_address();
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
 
     let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenEver
         .try_to_vec()
@@ -281,6 +396,14 @@ pub fn withdrawal_multi_token_ever_ix(
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
+            AccountMeta::new_readonly(recipient_hook_pubkey, false),
+            AccountMeta::new_readonly(denylist_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
+            AccountMeta::new(proposal_index_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
         ],
         data,
     };
@@ -288,21 +411,35 @@ pub fn withdrawal_multi_token_ever_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "createMultiTokenEver")]
+#[wasm_bindgen(
+    js_name = "createMultiTokenEver",
+    unchecked_return_type = "Instruction"
+)]
 pub fn create_ever_token_ix(
     funder_pubkey: String,
     withdrawal_pubkey: String,
     recipient_pubkey: String,
     token: String,
+    round_number: u32,
+    proposal_index_pubkey: String,
 ) -> Result<JsValue, JsValue> {
     let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
     let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let proposal_index_pubkey = Pubkey::from_str(proposal_index_pubkey.as_str()).handle_error()?;
+    let relay_round_pubkey = token_proxy::get_relay_round_address(round_number);
+    let rl_settings_pubkey = token_proxy::get_rl_settings_address();
     let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
     let token = EverAddress::from_str(&token).handle_error()?;
 
     let settings_pubkey = token_proxy::get_settings_address();
     let mint_pubkey = token_proxy::get_mint_address(&token);
     let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = token_proxy::get_denylist_address();
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+    let token_decimals_pubkey = token_proxy::get_token_decimals_address(&token);
 
     let recipient_token_pubkey =
         spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
@@ -323,10 +460,97 @@ pub fn create_ever_token_ix(
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
+            AccountMeta::new_readonly(recipient_hook_pubkey, false),
+            AccountMeta::new_readonly(denylist_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
             // If token settings account is not created
             AccountMeta::new(funder_pubkey, true),
             AccountMeta::new_readonly(recipient_pubkey, false),
             AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(token_decimals_pubkey, false),
+            AccountMeta::new(proposal_index_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "withdrawalMultiTokenEverV2",
+    unchecked_return_type = "Instruction"
+)]
+pub fn withdrawal_multi_token_ever_v2_ix(
+    funder_pubkey: String,
+    withdrawal_pubkey: String,
+    recipient_pubkey: String,
+    token: String,
+    with_payload: bool,
+    round_number: u32,
+    proposal_index_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let proposal_index_pubkey = Pubkey::from_str(proposal_index_pubkey.as_str()).handle_error()?;
+    let relay_round_pubkey = token_proxy::get_relay_round_address(round_number);
+    let rl_settings_pubkey = token_proxy::get_rl_settings_address();
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let token = EverAddress::from_str(&token).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let mint_pubkey = token_proxy::get_mint_address(&token);
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = token_proxy::get_denylist_address();
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+    let token_decimals_pubkey = token_proxy::get_token_decimals_address(&token);
+
+    let recipient_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
+
+    let recipient_account_pubkey = match with_payload {
+        true => token_proxy::get_proxy_address(&mint_pubkey, &recipient_pubkey),
+        false => recipient_token_pubkey,
+    };
+
+    let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenEverV2 {
+        version: token_proxy::CURRENT_IX_VERSION,
+        create_if_needed: true,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(recipient_account_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
+            AccountMeta::new_readonly(recipient_hook_pubkey, false),
+            AccountMeta::new_readonly(denylist_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new_readonly(recipient_pubkey, false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(token_decimals_pubkey, false),
+            AccountMeta::new(proposal_index_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
         ],
         data,
     };
@@ -334,20 +558,35 @@ pub fn create_ever_token_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "withdrawalMultiTokenSol")]
+#[wasm_bindgen(
+    js_name = "withdrawalMultiTokenSol",
+    unchecked_return_type = "Instruction"
+)]
 pub fn withdrawal_multi_token_sol_ix(
     withdrawal_pubkey: String,
     recipient_token_pubkey: String,
+    recipient_pubkey: String,
     mint: String,
+    round_number: u32,
+    proposal_index_pubkey: String,
 ) -> Result<JsValue, JsValue> {
     let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let proposal_index_pubkey = Pubkey::from_str(proposal_index_pubkey.as_str()).handle_error()?;
+    let relay_round_pubkey = token_proxy::get_relay_round_address(round_number);
+    let rl_settings_pubkey = token_proxy::get_rl_settings_address();
     let mint = Pubkey::from_str(mint.as_str()).handle_error()?;
     let recipient_token_pubkey =
         Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
 
     let settings_pubkey = token_proxy::get_settings_address();
     let vault_pubkey = token_proxy::get_vault_address(&mint);
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = token_proxy::get_denylist_address();
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
 
     let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenSol
         .try_to_vec()
@@ -364,6 +603,14 @@ pub fn withdrawal_multi_token_sol_ix(
             AccountMeta::new_readonly(settings_pubkey, false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
+            AccountMeta::new_readonly(recipient_hook_pubkey, false),
+            AccountMeta::new_readonly(denylist_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
+            AccountMeta::new(proposal_index_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
         ],
         data,
     };
@@ -371,7 +618,133 @@ pub fn withdrawal_multi_token_sol_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "depositNativeSol")]
+// Token is an Ever address for Ever tokens and a mint address for Solana tokens
+#[wasm_bindgen(
+    js_name = "withdrawalWithAtaCreation",
+    unchecked_return_type = "Instruction"
+)]
+pub fn withdrawal_with_ata_creation_ix(
+    funder_pubkey: String,
+    withdrawal_pubkey: String,
+    recipient_pubkey: String,
+    token: String,
+    round_number: u32,
+    proposal_index_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let proposal_index_pubkey = Pubkey::from_str(proposal_index_pubkey.as_str()).handle_error()?;
+    let relay_round_pubkey = token_proxy::get_relay_round_address(round_number);
+    let rl_settings_pubkey = token_proxy::get_rl_settings_address();
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+
+    let ix = match EverAddress::from_str(&token) {
+        Ok(token) => {
+            let settings_pubkey = token_proxy::get_settings_address();
+            let mint_pubkey = token_proxy::get_mint_address(&token);
+            let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+            let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+            let denylist_pubkey = token_proxy::get_denylist_address();
+            let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+            let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+            let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+            let token_decimals_pubkey = token_proxy::get_token_decimals_address(&token);
+
+            let recipient_token_pubkey = spl_associated_token_account::get_associated_token_address(
+                &recipient_pubkey,
+                &mint_pubkey,
+            );
+
+            let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenEverV2 {
+                version: token_proxy::CURRENT_IX_VERSION,
+                create_if_needed: true,
+            }
+            .try_to_vec()
+            .handle_error()?;
+
+            Instruction {
+                program_id: token_proxy::id(),
+                accounts: vec![
+                    AccountMeta::new(withdrawal_pubkey, false),
+                    AccountMeta::new(mint_pubkey, false),
+                    AccountMeta::new(recipient_token_pubkey, false),
+                    AccountMeta::new(token_settings_pubkey, false),
+                    AccountMeta::new_readonly(settings_pubkey, false),
+                    AccountMeta::new_readonly(system_program::id(), false),
+                    AccountMeta::new_readonly(spl_token::id(), false),
+                    AccountMeta::new_readonly(sysvar::rent::id(), false),
+                    AccountMeta::new_readonly(sysvar::clock::id(), false),
+                    AccountMeta::new_readonly(relay_round_pubkey, false),
+                    AccountMeta::new_readonly(rl_settings_pubkey, false),
+                    AccountMeta::new_readonly(recipient_hook_pubkey, false),
+                    AccountMeta::new_readonly(denylist_pubkey, false),
+                    AccountMeta::new(bridge_stats_pubkey, false),
+                    AccountMeta::new(approval_queue_pubkey, false),
+                    AccountMeta::new(funder_pubkey, true),
+                    AccountMeta::new(recipient_token_pubkey, false),
+                    AccountMeta::new_readonly(recipient_pubkey, false),
+                    AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                    AccountMeta::new_readonly(token_decimals_pubkey, false),
+                    AccountMeta::new(proposal_index_pubkey, false),
+                    AccountMeta::new(fee_ledger_pubkey, false),
+                ],
+                data,
+            }
+        }
+        Err(_) => {
+            let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+
+            let settings_pubkey = token_proxy::get_settings_address();
+            let vault_pubkey = token_proxy::get_vault_address(&mint);
+            let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint);
+            let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+            let denylist_pubkey = token_proxy::get_denylist_address();
+            let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+            let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+            let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+
+            let recipient_token_pubkey = spl_associated_token_account::get_associated_token_address(
+                &recipient_pubkey,
+                &mint,
+            );
+
+            let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenSol
+                .try_to_vec()
+                .handle_error()?;
+
+            Instruction {
+                program_id: token_proxy::id(),
+                accounts: vec![
+                    AccountMeta::new(withdrawal_pubkey, false),
+                    AccountMeta::new(vault_pubkey, false),
+                    AccountMeta::new(recipient_token_pubkey, false),
+                    AccountMeta::new(token_settings_pubkey, false),
+                    AccountMeta::new_readonly(mint, false),
+                    AccountMeta::new_readonly(settings_pubkey, false),
+                    AccountMeta::new_readonly(spl_token::id(), false),
+                    AccountMeta::new_readonly(sysvar::clock::id(), false),
+                    AccountMeta::new_readonly(relay_round_pubkey, false),
+                    AccountMeta::new_readonly(rl_settings_pubkey, false),
+                    AccountMeta::new_readonly(recipient_hook_pubkey, false),
+                    AccountMeta::new_readonly(denylist_pubkey, false),
+                    AccountMeta::new(bridge_stats_pubkey, false),
+                    AccountMeta::new(approval_queue_pubkey, false),
+                    AccountMeta::new(funder_pubkey, true),
+                    AccountMeta::new_readonly(recipient_pubkey, false),
+                    AccountMeta::new_readonly(system_program::id(), false),
+                    AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+                    AccountMeta::new(proposal_index_pubkey, false),
+                    AccountMeta::new(fee_ledger_pubkey, false),
+                ],
+                data,
+            }
+        }
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "depositNativeSol", unchecked_return_type = "Instruction")]
 pub fn deposit_native_sol_ix(
     funder_pubkey: String,
     author_pubkey: String,
@@ -381,6 +754,7 @@ pub fn deposit_native_sol_ix(
     value: u64,
     expected_evers: u64,
     payload: String,
+    reference: Option<String>,
 ) -> Result<JsValue, JsValue> {
     let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
         .handle_error()?
@@ -401,18 +775,117 @@ pub fn deposit_native_sol_ix(
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+    let reference = parse_deposit_reference(reference)?;
+
+    // Legacy encoding is kept for deposits without reference
+    let data = match reference {
+        Some(reference) => native_proxy::NativeProxyInstruction::DepositV2 {
+            deposit_seed,
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+            reference: Some(reference),
+        },
+        None => native_proxy::NativeProxyInstruction::Deposit {
+            deposit_seed,
+            amount,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        },
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: native_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(token_proxy::id(), false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "wrapAndDepositNativeSol",
+    unchecked_return_type = "Instruction"
+)]
+pub fn wrap_and_deposit_native_sol_ix(
+    funder_pubkey: String,
+    author_pubkey: String,
+    deposit_seed: String,
+    amount: u64,
+    recipient_address: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    reference: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+
+    let mint_pubkey = spl_token::native_mint::id();
+
+    let wrap_pubkey = Pubkey::find_program_address(
+        &[br"wrap", &deposit_seed.to_le_bytes()],
+        &native_proxy::id(),
+    )
+    .0;
+
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multivault_pubkey = token_proxy::get_multivault_address();
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
 
     let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
 
     let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+    let reference = parse_deposit_reference(reference)?;
 
-    let data = native_proxy::NativeProxyInstruction::Deposit {
+    let data = native_proxy::NativeProxyInstruction::WrapAndDeposit {
         deposit_seed,
         amount,
         recipient,
         value,
         expected_evers,
         payload,
+        reference,
     }
     .try_to_vec()
     .handle_error()?;
@@ -422,7 +895,7 @@ pub fn deposit_native_sol_ix(
         accounts: vec![
             AccountMeta::new(funder_pubkey, true),
             AccountMeta::new(author_pubkey, true),
-            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(wrap_pubkey, false),
             AccountMeta::new(vault_pubkey, false),
             AccountMeta::new(deposit_pubkey, false),
             AccountMeta::new(mint_pubkey, false),
@@ -433,7 +906,76 @@ pub fn deposit_native_sol_ix(
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(token_proxy::id(), false),
-            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "withdrawNativeSol", unchecked_return_type = "Instruction")]
+pub fn withdraw_native_sol_ix(
+    funder_pubkey: String,
+    recipient_pubkey: String,
+    withdrawal_pubkey: String,
+    round_number: u32,
+    proposal_index_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let proposal_index_pubkey = Pubkey::from_str(proposal_index_pubkey.as_str()).handle_error()?;
+    let relay_round_pubkey = token_proxy::get_relay_round_address(round_number);
+    let rl_settings_pubkey = token_proxy::get_rl_settings_address();
+
+    let mint_pubkey = spl_token::native_mint::id();
+
+    let unwrap_pubkey = Pubkey::find_program_address(
+        &[br"unwrap", &withdrawal_pubkey.to_bytes()],
+        &native_proxy::id(),
+    )
+    .0;
+
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let settings_pubkey = token_proxy::get_settings_address();
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let denylist_pubkey = token_proxy::get_denylist_address();
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+
+    let data = native_proxy::NativeProxyInstruction::WithdrawSol
+        .try_to_vec()
+        .handle_error()?;
+
+    let ix = Instruction {
+        program_id: native_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(recipient_pubkey, true),
+            AccountMeta::new(unwrap_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(token_proxy::id(), false),
+            AccountMeta::new(proposal_index_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new_readonly(recipient_hook_pubkey, false),
+            AccountMeta::new_readonly(denylist_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
         ],
         data,
     };
@@ -441,7 +983,10 @@ pub fn deposit_native_sol_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "depositMultiTokenEver")]
+#[wasm_bindgen(
+    js_name = "depositMultiTokenEver",
+    unchecked_return_type = "Instruction"
+)]
 pub fn deposit_multi_token_ever_ix(
     funder_pubkey: String,
     author_pubkey: String,
@@ -470,6 +1015,8 @@ pub fn deposit_multi_token_ever_ix(
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
     let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
     let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
 
     let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
@@ -499,6 +1046,9 @@ pub fn deposit_multi_token_ever_ix(
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
         ],
         data,
     };
@@ -506,7 +1056,10 @@ pub fn deposit_multi_token_ever_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "depositMultiTokenSol")]
+#[wasm_bindgen(
+    js_name = "depositMultiTokenSol",
+    unchecked_return_type = "Instruction"
+)]
 pub fn deposit_multi_token_sol_ix(
     funder_pubkey: String,
     author_pubkey: String,
@@ -537,6 +1090,8 @@ pub fn deposit_multi_token_sol_ix(
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
 
     let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
 
@@ -570,6 +1125,9 @@ pub fn deposit_multi_token_sol_ix(
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
         ],
         data,
     };
@@ -577,44 +1135,290 @@ pub fn deposit_multi_token_sol_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "executePayloadSol")]
-pub fn execute_payload_sol_ix(
-    withdrawal_pubkey: String,
+#[wasm_bindgen(
+    js_name = "depositMultiTokenEverV2",
+    unchecked_return_type = "Instruction"
+)]
+pub fn deposit_multi_token_ever_v2_ix(
+    funder_pubkey: String,
+    author_pubkey: String,
+    author_token_pubkey: String,
+    token_address: String,
+    deposit_seed: String,
+    amount: u64,
     recipient_address: String,
-    mint_address: String,
-    recipient_token_pubkey: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    reference: Option<String>,
+    callback_program: Option<String>,
+    callback_accounts: Option<Vec<JsValue>>,
+    referrer: Option<String>,
 ) -> Result<JsValue, JsValue> {
-    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
-    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
-    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
-    let proxy_address = token_proxy::get_proxy_address(&mint_address, &recipient_address);
-    let recipient_token_pubkey =
-        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
-
-    let data = token_proxy::TokenProxyInstruction::ExecutePayloadSol
-        .try_to_vec()
-        .expect("pack");
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
 
-    let ix = Instruction {
-        program_id: token_proxy::id(),
-        accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(proxy_address, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-        ],
-        data,
-    };
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+    let token = EverAddress::from_str(&token_address).handle_error()?;
+    let author_token_pubkey = Pubkey::from_str(author_token_pubkey.as_str()).handle_error()?;
 
-    return serde_wasm_bindgen::to_value(&ix).handle_error();
-}
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
 
-#[wasm_bindgen(js_name = "executePayloadEver")]
+    let mint_pubkey = token_proxy::get_mint_address(&token);
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multivault_pubkey = token_proxy::get_multivault_address();
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+    let payload = (!payload.is_empty()).then_some(payload);
+    let reference = parse_deposit_reference(reference)?;
+    let callback_program = callback_program
+        .map(|callback_program| Pubkey::from_str(callback_program.as_str()).handle_error())
+        .transpose()?;
+    let referrer = referrer
+        .map(|referrer| Pubkey::from_str(referrer.as_str()).handle_error())
+        .transpose()?;
+
+    let data = token_proxy::TokenProxyInstruction::DepositMultiTokenEverV2 {
+        version: token_proxy::CURRENT_IX_VERSION,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+        callback_program,
+        referrer,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let mut ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+        ],
+        data,
+    };
+
+    // Referral account is passed before callback program
+    if let Some(referrer) = referrer {
+        ix.accounts.push(AccountMeta::new(
+            token_proxy::get_referral_address(&token_settings_pubkey, &referrer),
+            false,
+        ));
+    }
+
+    // Callback program is followed by accounts passed to it
+    if let Some(callback_program) = callback_program {
+        ix.accounts
+            .push(AccountMeta::new_readonly(callback_program, false));
+
+        for account in callback_accounts.unwrap_or_default() {
+            let account: String = serde_wasm_bindgen::from_value(account).handle_error()?;
+            let account = Pubkey::from_str(account.as_str()).handle_error()?;
+            ix.accounts.push(AccountMeta::new(account, false));
+        }
+    }
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "depositMultiTokenSolV2",
+    unchecked_return_type = "Instruction"
+)]
+pub fn deposit_multi_token_sol_v2_ix(
+    funder_pubkey: String,
+    author_pubkey: String,
+    author_token_pubkey: String,
+    mint_pubkey: String,
+    deposit_seed: String,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient_address: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    reference: Option<String>,
+    callback_program: Option<String>,
+    callback_accounts: Option<Vec<JsValue>>,
+    referrer: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let author_token_pubkey = Pubkey::from_str(author_token_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multivault_pubkey = token_proxy::get_multivault_address();
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+    let payload = (!payload.is_empty()).then_some(payload);
+    let reference = parse_deposit_reference(reference)?;
+    let callback_program = callback_program
+        .map(|callback_program| Pubkey::from_str(callback_program.as_str()).handle_error())
+        .transpose()?;
+    let referrer = referrer
+        .map(|referrer| Pubkey::from_str(referrer.as_str()).handle_error())
+        .transpose()?;
+
+    let data = token_proxy::TokenProxyInstruction::DepositMultiTokenSolV2 {
+        version: token_proxy::CURRENT_IX_VERSION,
+        deposit_seed,
+        name: name.into(),
+        symbol: symbol.into(),
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        reference,
+        callback_program,
+        referrer,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let mut ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+        ],
+        data,
+    };
+
+    // Referral account is passed before callback program
+    if let Some(referrer) = referrer {
+        ix.accounts.push(AccountMeta::new(
+            token_proxy::get_referral_address(&token_settings_pubkey, &referrer),
+            false,
+        ));
+    }
+
+    // Callback program is followed by accounts passed to it
+    if let Some(callback_program) = callback_program {
+        ix.accounts
+            .push(AccountMeta::new_readonly(callback_program, false));
+
+        for account in callback_accounts.unwrap_or_default() {
+            let account: String = serde_wasm_bindgen::from_value(account).handle_error()?;
+            let account = Pubkey::from_str(account.as_str()).handle_error()?;
+            ix.accounts.push(AccountMeta::new(account, false));
+        }
+    }
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+/// Decode base64 deposit reference
+fn parse_deposit_reference(reference: Option<String>) -> Result<Option<[u8; 32]>, JsValue> {
+    reference
+        .map(|reference| {
+            let reference = general_purpose::STANDARD.decode(reference).handle_error()?;
+            <[u8; 32]>::try_from(reference)
+                .map_err(|_| "Invalid reference length")
+                .handle_error()
+        })
+        .transpose()
+}
+
+#[wasm_bindgen(js_name = "executePayloadSol", unchecked_return_type = "Instruction")]
+pub fn execute_payload_sol_ix(
+    withdrawal_pubkey: String,
+    recipient_address: String,
+    mint_address: String,
+    recipient_token_pubkey: String,
+    count: Option<u32>,
+    proxy_accounts: u8,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
+    let proxy_address = token_proxy::get_proxy_address(&mint_address, &recipient_address);
+    let recipient_token_pubkey =
+        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let data = token_proxy::TokenProxyInstruction::ExecutePayloadSolV2 {
+        count,
+        proxy_accounts,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(proxy_address, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "executePayloadEver", unchecked_return_type = "Instruction")]
 pub fn execute_payload_ever_ix(
     withdrawal_pubkey: String,
     recipient_address: String,
     mint_address: String,
     recipient_token_pubkey: String,
+    count: Option<u32>,
+    proxy_accounts: u8,
 ) -> Result<JsValue, JsValue> {
     let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
     let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
@@ -623,14 +1427,20 @@ pub fn execute_payload_ever_ix(
     let recipient_token_pubkey =
         Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::ExecutePayloadEver
-        .try_to_vec()
-        .expect("pack");
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let data = token_proxy::TokenProxyInstruction::ExecutePayloadEverV2 {
+        count,
+        proxy_accounts,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
             AccountMeta::new(proxy_address, false),
             AccountMeta::new(recipient_token_pubkey, false),
             AccountMeta::new_readonly(spl_token::id(), false),
@@ -641,7 +1451,10 @@ pub fn execute_payload_ever_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "voteForWithdrawRequest")]
+#[wasm_bindgen(
+    js_name = "voteForWithdrawRequest",
+    unchecked_return_type = "Instruction"
+)]
 pub fn vote_for_withdraw_request_ix(
     authority_pubkey: String,
     withdrawal_pubkey: String,
@@ -652,6 +1465,8 @@ pub fn vote_for_withdraw_request_ix(
 
     let relay_round_pubkey =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
 
     let data = token_proxy::TokenProxyInstruction::VoteForWithdrawRequest {
         vote: Vote::Confirm,
@@ -665,6 +1480,43 @@ pub fn vote_for_withdraw_request_ix(
             AccountMeta::new(authority_pubkey, true),
             AccountMeta::new(withdrawal_pubkey, false),
             AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "voteForWithdrawRequestBySignatures",
+    unchecked_return_type = "Instruction"
+)]
+pub fn vote_for_withdraw_request_by_signatures_ix(
+    submitter_pubkey: String,
+    withdrawal_pubkey: String,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let submitter_pubkey = Pubkey::from_str(submitter_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+
+    let data = token_proxy::TokenProxyInstruction::VoteForWithdrawRequestBySignatures
+        .try_to_vec()
+        .handle_error()?;
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(submitter_pubkey, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(sysvar::instructions::id(), false),
+            AccountMeta::new_readonly(rl_settings_pubkey, false),
         ],
         data,
     };
@@ -672,7 +1524,54 @@ pub fn vote_for_withdraw_request_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeGuardian")]
+#[wasm_bindgen(js_name = "getVoteMessage", unchecked_return_type = "number[]")]
+pub fn get_vote_message(withdrawal_pubkey: String, event_data: String) -> Result<JsValue, JsValue> {
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let event_data = Hash::from_str(event_data.as_str()).handle_error()?;
+
+    let message = bridge_utils::helper::get_vote_message(&withdrawal_pubkey, &event_data);
+
+    return serde_wasm_bindgen::to_value(&message.to_bytes()).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "verifyRelaySignatures",
+    unchecked_return_type = "Instruction"
+)]
+pub fn verify_relay_signatures_ix(
+    withdrawal_pubkey: String,
+    event_data: String,
+    relays: Vec<JsValue>,
+    signatures: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let event_data = Hash::from_str(event_data.as_str()).handle_error()?;
+
+    let message = bridge_utils::helper::get_vote_message(&withdrawal_pubkey, &event_data);
+
+    let signatures = relays
+        .into_iter()
+        .zip(signatures)
+        .map(|(relay, signature)| {
+            let relay: String = serde_wasm_bindgen::from_value(relay).handle_error()?;
+            let relay = Pubkey::from_str(relay.as_str()).handle_error()?;
+
+            let signature: String = serde_wasm_bindgen::from_value(signature).handle_error()?;
+            let signature = general_purpose::STANDARD.decode(signature).handle_error()?;
+            let signature = <[u8; 64]>::try_from(signature)
+                .map_err(|_| "Invalid signature length")
+                .handle_error()?;
+
+            Ok((relay, signature))
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+
+    let ix = bridge_utils::helper::ed25519_signatures_ix(message.as_ref(), &signatures);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeGuardian", unchecked_return_type = "Instruction")]
 pub fn change_guardian_ix(
     authority_pubkey: String,
     new_guardian: String,
@@ -700,7 +1599,38 @@ pub fn change_guardian_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeWithdrawalManager")]
+#[wasm_bindgen(js_name = "changeManager", unchecked_return_type = "Instruction")]
+pub fn change_manager_ix(
+    authority_pubkey: String,
+    new_manager: String,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let new_manager = Pubkey::from_str(new_manager.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeManager { new_manager }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeWithdrawalManager",
+    unchecked_return_type = "Instruction"
+)]
 pub fn change_withdrawal_manager_ix(
     authority_pubkey: String,
     new_withdrawal_manager: String,
@@ -731,36 +1661,32 @@ pub fn change_withdrawal_manager_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeDepositLimit")]
-pub fn change_deposit_limit_ix(
+#[wasm_bindgen(js_name = "proposeRole", unchecked_return_type = "Instruction")]
+pub fn propose_role_ix(
     authority_pubkey: String,
-    token: String,
-    token_is_sol: bool,
-    new_deposit_limit: u64,
+    role: String,
+    new_authority: Option<String>,
 ) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let new_authority = new_authority
+        .map(|new_authority| Pubkey::from_str(new_authority.as_str()).handle_error())
+        .transpose()?;
 
-    let token_settings_pubkey = if token_is_sol {
-        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
-        token_proxy::get_token_settings_sol_address(&mint)
-    } else {
-        let token = EverAddress::from_str(&token).handle_error()?;
-        token_proxy::get_token_settings_ever_address(&token)
-    };
-    let program_data_pubkey = token_proxy::get_programdata_address();
-
-    let data = token_proxy::TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
-        .try_to_vec()
-        .expect("pack");
+    let data = token_proxy::TokenProxyInstruction::ProposeRole {
+        role: token_proxy::Role::from_str(&role).handle_error()?,
+        new_authority,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
@@ -769,30 +1695,28 @@ pub fn change_deposit_limit_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeWithdrawalLimits")]
-pub fn change_withdrawal_limits_ix(
+#[wasm_bindgen(js_name = "scheduleChange", unchecked_return_type = "Instruction")]
+pub fn schedule_change_ix(
+    funder_pubkey: String,
     authority_pubkey: String,
-    token: String,
-    token_is_sol: bool,
-    new_withdrawal_limit: Option<u64>,
-    new_withdrawal_daily_limit: Option<u64>,
+    change_seed: String,
+    #[wasm_bindgen(unchecked_param_type = "TimelockedChange")] change: JsValue,
 ) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
-
-    let token_settings_pubkey = if token_is_sol {
-        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
-        token_proxy::get_token_settings_sol_address(&mint)
-    } else {
-        let token = EverAddress::from_str(&token).handle_error()?;
-        token_proxy::get_token_settings_ever_address(&token)
-    };
     let program_data_pubkey = token_proxy::get_programdata_address();
 
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::ChangeWithdrawalLimits {
-        new_withdrawal_limit,
-        new_withdrawal_daily_limit,
+    let change_seed = uuid::Uuid::from_str(&change_seed).handle_error()?.as_u128();
+    let pending_change_pubkey = token_proxy::get_pending_change_address(change_seed);
+
+    let change: token_proxy::TimelockedChange =
+        serde_wasm_bindgen::from_value(change).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ScheduleChange {
+        change_seed,
+        change,
     }
     .try_to_vec()
     .expect("pack");
@@ -800,10 +1724,13 @@ pub fn change_withdrawal_limits_ix(
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(pending_change_pubkey, false),
             AccountMeta::new_readonly(settings_pubkey, false),
             AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
         ],
         data,
     };
@@ -811,36 +1738,59 @@ pub fn change_withdrawal_limits_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "enableEmergency")]
-pub fn enable_emergency_ix(authority_pubkey: String) -> Result<JsValue, JsValue> {
+#[wasm_bindgen(js_name = "executeChange", unchecked_return_type = "Instruction")]
+pub fn execute_change_ix(
+    author_pubkey: String,
+    change_seed: String,
+    token_settings_pubkey: Option<String>,
+) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
 
-    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+
+    let change_seed = uuid::Uuid::from_str(&change_seed).handle_error()?.as_u128();
+    let pending_change_pubkey = token_proxy::get_pending_change_address(change_seed);
 
-    let data = token_proxy::TokenProxyInstruction::EnableEmergencyMode
+    let data = token_proxy::TokenProxyInstruction::ExecuteChange
         .try_to_vec()
         .expect("pack");
 
-    let ix = Instruction {
+    let mut ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(author_pubkey, false),
+            AccountMeta::new(pending_change_pubkey, false),
             AccountMeta::new(settings_pubkey, false),
         ],
         data,
     };
 
+    if let Some(token_settings_pubkey) = token_settings_pubkey {
+        let token_settings_pubkey =
+            Pubkey::from_str(token_settings_pubkey.as_str()).handle_error()?;
+        ix.accounts
+            .push(AccountMeta::new(token_settings_pubkey, false));
+    }
+
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "enableEmergencyByOwner")]
-pub fn enable_emergency_by_owner_ix(authority_pubkey: String) -> Result<JsValue, JsValue> {
+#[wasm_bindgen(js_name = "cancelChange", unchecked_return_type = "Instruction")]
+pub fn cancel_change_ix(
+    authority_pubkey: String,
+    author_pubkey: String,
+    change_seed: String,
+) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
     let program_data_pubkey = token_proxy::get_programdata_address();
 
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::EnableEmergencyMode
+    let change_seed = uuid::Uuid::from_str(&change_seed).handle_error()?.as_u128();
+    let pending_change_pubkey = token_proxy::get_pending_change_address(change_seed);
+
+    let data = token_proxy::TokenProxyInstruction::CancelChange
         .try_to_vec()
         .expect("pack");
 
@@ -848,7 +1798,9 @@ pub fn enable_emergency_by_owner_ix(authority_pubkey: String) -> Result<JsValue,
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(pending_change_pubkey, false),
+            AccountMeta::new(author_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
             AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
@@ -857,14 +1809,13 @@ pub fn enable_emergency_by_owner_ix(authority_pubkey: String) -> Result<JsValue,
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "disableEmergency")]
-pub fn disable_emergency_ix(authority_pubkey: String) -> Result<JsValue, JsValue> {
+#[wasm_bindgen(js_name = "acceptRole", unchecked_return_type = "Instruction")]
+pub fn accept_role_ix(authority_pubkey: String) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
-    let program_data_pubkey = token_proxy::get_programdata_address();
 
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::DisableEmergencyMode
+    let data = token_proxy::TokenProxyInstruction::AcceptRole
         .try_to_vec()
         .expect("pack");
 
@@ -873,7 +1824,6 @@ pub fn disable_emergency_ix(authority_pubkey: String) -> Result<JsValue, JsValue
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
             AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -881,33 +1831,39 @@ pub fn disable_emergency_ix(authority_pubkey: String) -> Result<JsValue, JsValue
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "enableTokenEmergency")]
-pub fn enable_token_emergency_ix(
+#[wasm_bindgen(
+    js_name = "changePayloadAllowlist",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_payload_allowlist_ix(
     authority_pubkey: String,
-    token: String,
-    token_is_sol: bool,
+    new_payload_allowlist: Vec<JsValue>,
 ) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
-    let token_settings_pubkey = if token_is_sol {
-        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
-        token_proxy::get_token_settings_sol_address(&mint)
-    } else {
-        let token = EverAddress::from_str(&token).handle_error()?;
-        token_proxy::get_token_settings_ever_address(&token)
-    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::EnableTokenEmergencyMode
-        .try_to_vec()
-        .expect("pack");
+    let new_payload_allowlist = new_payload_allowlist
+        .into_iter()
+        .map(|program_id| {
+            let program_id: String = serde_wasm_bindgen::from_value(program_id).handle_error()?;
+            Pubkey::from_str(program_id.as_str()).handle_error()
+        })
+        .collect::<Result<Vec<Pubkey>, JsValue>>()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangePayloadAllowlist {
+        new_payload_allowlist,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -915,34 +1871,43 @@ pub fn enable_token_emergency_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "enableTokenEmergencyByOwner")]
-pub fn enable_token_emergency_by_owner_ix(
+#[wasm_bindgen(
+    js_name = "changeDepositCallerAllowlist",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_deposit_caller_allowlist_ix(
     authority_pubkey: String,
-    token: String,
-    token_is_sol: bool,
+    new_deposit_caller_allowlist: Option<Vec<JsValue>>,
 ) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
-    let token_settings_pubkey = if token_is_sol {
-        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
-        token_proxy::get_token_settings_sol_address(&mint)
-    } else {
-        let token = EverAddress::from_str(&token).handle_error()?;
-        token_proxy::get_token_settings_ever_address(&token)
-    };
     let program_data_pubkey = token_proxy::get_programdata_address();
 
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::EnableTokenEmergencyMode
-        .try_to_vec()
-        .expect("pack");
+    let new_deposit_caller_allowlist = new_deposit_caller_allowlist
+        .map(|allowlist| {
+            allowlist
+                .into_iter()
+                .map(|program_id| {
+                    let program_id: String =
+                        serde_wasm_bindgen::from_value(program_id).handle_error()?;
+                    Pubkey::from_str(program_id.as_str()).handle_error()
+                })
+                .collect::<Result<Vec<Pubkey>, JsValue>>()
+        })
+        .transpose()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeDepositCallerAllowlist {
+        new_deposit_caller_allowlist,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
@@ -951,32 +1916,37 @@ pub fn enable_token_emergency_by_owner_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "disableTokenEmergency")]
-pub fn disable_token_emergency_ix(
+#[wasm_bindgen(js_name = "changeTokenDecimals", unchecked_return_type = "Instruction")]
+pub fn change_token_decimals_ix(
     authority_pubkey: String,
     token: String,
-    token_is_sol: bool,
+    solana_decimals: u8,
 ) -> Result<JsValue, JsValue> {
-    let token_settings_pubkey = if token_is_sol {
-        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
-        token_proxy::get_token_settings_sol_address(&mint)
-    } else {
-        let token = EverAddress::from_str(&token).handle_error()?;
-        token_proxy::get_token_settings_ever_address(&token)
-    };
+    let settings_pubkey = token_proxy::get_settings_address();
     let program_data_pubkey = token_proxy::get_programdata_address();
 
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let token = EverAddress::from_str(&token).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::DisableTokenEmergencyMode
-        .try_to_vec()
-        .expect("pack");
+    let mint_pubkey = token_proxy::get_mint_address(&token);
+    let token_decimals_pubkey = token_proxy::get_token_decimals_address(&token);
+
+    let data = token_proxy::TokenProxyInstruction::ChangeTokenDecimals {
+        token,
+        solana_decimals,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(token_decimals_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
@@ -985,32 +1955,48 @@ pub fn disable_token_emergency_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "withdrawalProxy")]
-pub fn withdrawal_proxy_ix(
-    recipient_pubkey: String,
-    recipient_token_pubkey: String,
-    mint_pubkey: String,
-    amount: u64,
+#[wasm_bindgen(js_name = "changeTokenDefaults", unchecked_return_type = "Instruction")]
+pub fn change_token_defaults_ix(
+    authority_pubkey: String,
+    deposit_limit: u64,
+    withdrawal_limit: u64,
+    withdrawal_daily_limit: u64,
+    deposit_daily_limit: Option<u64>,
+    fee_deposit_multiplier: u64,
+    fee_deposit_divisor: u64,
+    fee_withdrawal_multiplier: u64,
+    fee_withdrawal_divisor: u64,
 ) -> Result<JsValue, JsValue> {
-    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
-    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
-    let recipient_token_pubkey =
-        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let proxy_pubkey = token_proxy::get_proxy_address(&mint_pubkey, &recipient_pubkey);
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::WithdrawProxy { amount }
+    let new_token_defaults = token_proxy::TokenDefaults {
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        deposit_daily_limit,
+        fee_deposit_info: token_proxy::FeeInfo {
+            multiplier: fee_deposit_multiplier,
+            divisor: fee_deposit_divisor,
+        },
+        fee_withdrawal_info: token_proxy::FeeInfo {
+            multiplier: fee_withdrawal_multiplier,
+            divisor: fee_withdrawal_divisor,
+        },
+    };
+
+    let data = token_proxy::TokenProxyInstruction::ChangeTokenDefaults { new_token_defaults }
         .try_to_vec()
         .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(recipient_pubkey, true),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(proxy_pubkey, false),
-            AccountMeta::new_readonly(mint_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1018,20 +2004,51 @@ pub fn withdrawal_proxy_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "closeDeposit")]
-pub fn close_deposit(author_address: String, deposit_address: String) -> Result<JsValue, JsValue> {
-    let author_address = Pubkey::from_str(author_address.as_str()).handle_error()?;
-    let deposit_address = Pubkey::from_str(deposit_address.as_str()).handle_error()?;
+#[wasm_bindgen(js_name = "registerTokenEver", unchecked_return_type = "Instruction")]
+pub fn register_token_ever_ix(
+    authority_pubkey: String,
+    token: String,
+    name: String,
+    symbol: String,
+    ever_decimals: u8,
+    solana_decimals: u8,
+    #[wasm_bindgen(unchecked_param_type = "TokenDefaults | undefined")] token_defaults: JsValue,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let data = token_proxy::TokenProxyInstruction::CloseDeposit
-        .try_to_vec()
-        .expect("pack");
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let token = EverAddress::from_str(&token).handle_error()?;
+    let token_defaults: Option<token_proxy::TokenDefaults> =
+        serde_wasm_bindgen::from_value(token_defaults).handle_error()?;
+
+    let mint_pubkey = token_proxy::get_mint_address(&token);
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let token_decimals_pubkey = token_proxy::get_token_decimals_address(&token);
+
+    let data = token_proxy::TokenProxyInstruction::RegisterTokenEver {
+        token,
+        name,
+        symbol,
+        ever_decimals,
+        solana_decimals,
+        token_defaults,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(author_address, true),
-            AccountMeta::new(deposit_address, false),
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(token_decimals_pubkey, false),
         ],
         data,
     };
@@ -1039,24 +2056,45 @@ pub fn close_deposit(author_address: String, deposit_address: String) -> Result<
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "closeWithdrawal")]
-pub fn close_withdrawal(
-    withdrawal_address: String,
-    withdrawal_author_address: String,
+#[wasm_bindgen(js_name = "registerTokenSol", unchecked_return_type = "Instruction")]
+pub fn register_token_sol_ix(
+    authority_pubkey: String,
+    mint: String,
+    name: String,
+    symbol: String,
+    #[wasm_bindgen(unchecked_param_type = "TokenDefaults | undefined")] token_defaults: JsValue,
 ) -> Result<JsValue, JsValue> {
-    let withdrawal_address = Pubkey::from_str(withdrawal_address.as_str()).handle_error()?;
-    let withdrawal_author_address =
-        Pubkey::from_str(withdrawal_author_address.as_str()).handle_error()?;
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let data = token_proxy::TokenProxyInstruction::CloseWithdrawal
-        .try_to_vec()
-        .expect("pack");
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint.as_str()).handle_error()?;
+    let token_defaults: Option<token_proxy::TokenDefaults> =
+        serde_wasm_bindgen::from_value(token_defaults).handle_error()?;
+
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::RegisterTokenSol {
+        name,
+        symbol,
+        token_defaults,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(withdrawal_address, false),
-            AccountMeta::new(withdrawal_author_address, false),
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1064,16 +2102,17 @@ pub fn close_withdrawal(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "updateFee")]
-pub fn update_fee(
+#[wasm_bindgen(js_name = "delistToken", unchecked_return_type = "Instruction")]
+pub fn delist_token_ix(
     authority_pubkey: String,
     token: String,
     token_is_sol: bool,
-    fee_type: String,
-    multiplier: u64,
-    divisor: u64,
 ) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
     let token_settings_pubkey = if token_is_sol {
         let mint = Pubkey::from_str(token.as_str()).handle_error()?;
         token_proxy::get_token_settings_sol_address(&mint)
@@ -1082,15 +2121,9 @@ pub fn update_fee(
         token_proxy::get_token_settings_ever_address(&token)
     };
 
-    let settings_pubkey = token_proxy::get_settings_address();
-
-    let data = token_proxy::TokenProxyInstruction::UpdateFee {
-        fee_type: token_proxy::FeeType::from_str(&fee_type).handle_error()?,
-        multiplier,
-        divisor,
-    }
-    .try_to_vec()
-    .expect("pack");
+    let data = token_proxy::TokenProxyInstruction::DelistToken
+        .try_to_vec()
+        .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
@@ -1098,6 +2131,7 @@ pub fn update_fee(
             AccountMeta::new(authority_pubkey, true),
             AccountMeta::new(token_settings_pubkey, false),
             AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1105,22 +2139,33 @@ pub fn update_fee(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "updateTokenName")]
-pub fn update_token_name(
+#[wasm_bindgen(js_name = "closeToken", unchecked_return_type = "Instruction")]
+pub fn close_token_ix(
     authority_pubkey: String,
     token: String,
-    symbol: String,
-    name: String,
+    token_is_sol: bool,
 ) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let token_settings_pubkey = {
+
+    let (token_settings_pubkey, mint_or_vault_meta) = if token_is_sol {
         let mint = Pubkey::from_str(token.as_str()).handle_error()?;
-        token_proxy::get_token_settings_sol_address(&mint)
+        (
+            token_proxy::get_token_settings_sol_address(&mint),
+            AccountMeta::new(token_proxy::get_vault_address(&mint), false),
+        )
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        (
+            token_proxy::get_token_settings_ever_address(&token),
+            AccountMeta::new_readonly(token_proxy::get_mint_address(&token), false),
+        )
     };
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
 
-    let settings_pubkey = token_proxy::get_settings_address();
-
-    let data = token_proxy::TokenProxyInstruction::UpdateTokenName { symbol, name }
+    let data = token_proxy::TokenProxyInstruction::CloseToken
         .try_to_vec()
         .expect("pack");
 
@@ -1129,7 +2174,11 @@ pub fn update_token_name(
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
             AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            mint_or_vault_meta,
             AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1137,25 +2186,27 @@ pub fn update_token_name(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "approveWithdrawalEver")]
-pub fn approve_withdrawal_ever(
+#[wasm_bindgen(
+    js_name = "proposeMintAuthority",
+    unchecked_return_type = "Instruction"
+)]
+pub fn propose_mint_authority_ix(
     authority_pubkey: String,
-    withdrawal_pubkey: String,
-    recipient_token_pubkey: String,
-    mint_pubkey: String,
     token: String,
+    new_authority: Option<String>,
 ) -> Result<JsValue, JsValue> {
-    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
-    let recipient_token_pubkey =
-        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
-    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
-
     let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
     let token = EverAddress::from_str(&token).handle_error()?;
+    let new_authority = new_authority
+        .map(|new_authority| Pubkey::from_str(new_authority.as_str()).handle_error())
+        .transpose()?;
+
     let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
 
-    let data = token_proxy::TokenProxyInstruction::ApproveWithdrawEver
+    let data = token_proxy::TokenProxyInstruction::ProposeMintAuthority { new_authority }
         .try_to_vec()
         .expect("pack");
 
@@ -1163,13 +2214,9 @@ pub fn approve_withdrawal_ever(
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
             AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1177,24 +2224,24 @@ pub fn approve_withdrawal_ever(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "approveWithdrawalSol")]
-pub fn approve_withdrawal_sol(
+#[wasm_bindgen(
+    js_name = "migrateMintAuthority",
+    unchecked_return_type = "Instruction"
+)]
+pub fn migrate_mint_authority_ix(
     authority_pubkey: String,
-    withdrawal_pubkey: String,
-    recipient_token_pubkey: String,
-    mint_pubkey: String,
+    token: String,
 ) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
-    let recipient_token_pubkey =
-        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
-    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+    let token = EverAddress::from_str(&token).handle_error()?;
 
-    let settings_pubkey = token_proxy::get_settings_address();
-    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
-    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let mint_pubkey = token_proxy::get_mint_address(&token);
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
 
-    let data = token_proxy::TokenProxyInstruction::ApproveWithdrawSol
+    let data = token_proxy::TokenProxyInstruction::MigrateMintAuthority
         .try_to_vec()
         .expect("pack");
 
@@ -1202,13 +2249,11 @@ pub fn approve_withdrawal_sol(
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(vault_pubkey, false),
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
             AccountMeta::new_readonly(settings_pubkey, false),
             AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1216,60 +2261,37 @@ pub fn approve_withdrawal_sol(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "cancelWithdrawalSol")]
-pub fn cancel_withdrawal_sol(
-    funder_pubkey: String,
-    author_pubkey: String,
-    withdrawal_pubkey: String,
-    mint_pubkey: String,
-    deposit_seed: String,
-    recipient: String,
-    value: u64,
-    expected_evers: u64,
-    payload: String,
+#[wasm_bindgen(
+    js_name = "proposeVaultAuthority",
+    unchecked_return_type = "Instruction"
+)]
+pub fn propose_vault_authority_ix(
+    authority_pubkey: String,
+    mint: String,
+    new_authority: Option<String>,
 ) -> Result<JsValue, JsValue> {
-    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
-        .handle_error()?
-        .as_u128();
-
-    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
-    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
-    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
-    let recipient = EverAddress::from_str(&recipient).handle_error()?;
-    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
-
     let settings_pubkey = token_proxy::get_settings_address();
-    let multivault_pubkey = token_proxy::get_multivault_address();
-    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
-    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint.as_str()).handle_error()?;
+    let new_authority = new_authority
+        .map(|new_authority| Pubkey::from_str(new_authority.as_str()).handle_error())
+        .transpose()?;
 
-    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
 
-    let data = token_proxy::TokenProxyInstruction::CancelWithdrawSol {
-        deposit_seed,
-        recipient,
-        value,
-        expected_evers,
-        payload,
-    }
-    .try_to_vec()
-    .expect("pack");
+    let data = token_proxy::TokenProxyInstruction::ProposeVaultAuthority { new_authority }
+        .try_to_vec()
+        .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(funder_pubkey, true),
-            AccountMeta::new(author_pubkey, true),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
             AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(token_settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1277,113 +2299,69 @@ pub fn cancel_withdrawal_sol(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(getter_with_clone)]
-#[derive(Debug, Deserialize)]
-pub struct FillWithdrawals {
-    pub withdrawal_pubkey: String,
-    pub to_pubkey: String,
-}
-
-#[wasm_bindgen(js_name = "fillWithdrawalSol")]
-pub fn fill_withdrawal_sol(
-    funder_pubkey: String,
-    author_pubkey: String,
-    mint_pubkey: String,
-    deposit_seed: String,
-    recipient: String,
-    amount: u64,
-    withdrawal_pubkeys: Vec<JsValue>,
-    vault_pubkey: Option<String>,
-    value: u64,
-    expected_evers: u64,
-    payload: String,
+#[wasm_bindgen(
+    js_name = "migrateVaultAuthority",
+    unchecked_return_type = "Instruction"
+)]
+pub fn migrate_vault_authority_ix(
+    authority_pubkey: String,
+    mint: String,
 ) -> Result<JsValue, JsValue> {
-    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
-        .handle_error()?
-        .as_u128();
-
-    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
-    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
-    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
-    let recipient = EverAddress::from_str(&recipient).handle_error()?;
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let author_token_pubkey =
-        spl_associated_token_account::get_associated_token_address(&author_pubkey, &mint_pubkey);
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint.as_str()).handle_error()?;
 
-    let settings_pubkey = token_proxy::get_settings_address();
-    let multivault_pubkey = token_proxy::get_multivault_address();
-    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
 
-    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
-    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
-
-    let data = token_proxy::TokenProxyInstruction::FillWithdrawSol {
-        deposit_seed,
-        recipient,
-        amount,
-        value,
-        expected_evers,
-        payload,
-    }
-    .try_to_vec()
-    .expect("pack");
+    let data = token_proxy::TokenProxyInstruction::MigrateVaultAuthority
+        .try_to_vec()
+        .expect("pack");
 
-    let mut ix = Instruction {
+    let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(funder_pubkey, true),
-            AccountMeta::new(author_pubkey, true),
-            AccountMeta::new(author_token_pubkey, false),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
             AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(token_settings_pubkey, false),
             AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(sysvar::rent::id(), false),
-            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
 
-    for fill in withdrawal_pubkeys {
-        let fill: FillWithdrawals = serde_wasm_bindgen::from_value(fill).handle_error()?;
-        let withdrawal_pubkey = Pubkey::from_str(fill.withdrawal_pubkey.as_str()).handle_error()?;
-        let to_pubkey = Pubkey::from_str(fill.to_pubkey.as_str()).handle_error()?;
-        let recipient_token_pubkey =
-            spl_associated_token_account::get_associated_token_address(&to_pubkey, &mint_pubkey);
-        ix.accounts.push(AccountMeta::new(withdrawal_pubkey, false));
-        ix.accounts
-            .push(AccountMeta::new(recipient_token_pubkey, false));
-    }
-
-    if let Some(vault_pubkey) = vault_pubkey {
-        let vault_pubkey = Pubkey::from_str(vault_pubkey.as_str()).handle_error()?;
-        ix.accounts.push(AccountMeta::new(vault_pubkey, false));
-    }
-
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeBountyForWithdrawalSol")]
-pub fn change_bounty_for_withdrawal_sol_ix(
-    author_pubkey: String,
-    withdrawal_pubkey: String,
-    bounty: u64,
+#[wasm_bindgen(js_name = "migrateSettingsV2", unchecked_return_type = "Instruction")]
+pub fn migrate_settings_v2_ix(
+    funder_pubkey: String,
+    authority_pubkey: String,
 ) -> Result<JsValue, JsValue> {
-    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
-    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+    let denylist_pubkey = token_proxy::get_denylist_address();
 
-    let data = token_proxy::TokenProxyInstruction::ChangeBountyForWithdrawSol { bounty }
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::MigrateSettingsV2
         .try_to_vec()
         .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(author_pubkey, true),
-            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(denylist_pubkey, false),
         ],
         data,
     };
@@ -1391,30 +2369,25 @@ pub fn change_bounty_for_withdrawal_sol_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "withdrawalMultiVault")]
-pub fn withdrawal_multi_vault_ix(
-    authority_pubkey: String,
-    recipient_pubkey: String,
-    amount: u64,
+#[wasm_bindgen(js_name = "migrateAccount", unchecked_return_type = "Instruction")]
+pub fn migrate_account_ix(
+    funder_pubkey: String,
+    account_pubkey: String,
 ) -> Result<JsValue, JsValue> {
-    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
-
-    let settings_pubkey = token_proxy::get_settings_address();
-    let multi_vault_pubkey = token_proxy::get_multivault_address();
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let account_pubkey = Pubkey::from_str(account_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::WithdrawMultiVault { amount }
+    let data = token_proxy::TokenProxyInstruction::MigrateAccount
         .try_to_vec()
         .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(recipient_pubkey, false),
-            AccountMeta::new(multi_vault_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(account_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
         ],
         data,
     };
@@ -1422,37 +2395,35 @@ pub fn withdrawal_multi_vault_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "withdrawalEverFee")]
-pub fn withdrawal_ever_fee_ix(
+#[wasm_bindgen(js_name = "changeWever", unchecked_return_type = "Instruction")]
+pub fn change_wever_ix(
     authority_pubkey: String,
-    mint_pubkey: String,
-    recipient_token_pubkey: String,
-    token: String,
-    amount: u64,
+    new_wever_mint: Option<String>,
+    #[wasm_bindgen(unchecked_param_type = "TokenDefaults | undefined")] new_wever_defaults: JsValue,
 ) -> Result<JsValue, JsValue> {
-    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
-    let recipient_token_pubkey =
-        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
-
     let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let token = EverAddress::from_str(&token).handle_error()?;
-    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
-
-    let data = token_proxy::TokenProxyInstruction::WithdrawEverFee { amount }
-        .try_to_vec()
-        .expect("pack");
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let new_wever_mint = new_wever_mint
+        .map(|new_wever_mint| Pubkey::from_str(new_wever_mint.as_str()).handle_error())
+        .transpose()?;
+    let new_wever_defaults: Option<token_proxy::TokenDefaults> =
+        serde_wasm_bindgen::from_value(new_wever_defaults).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeWever {
+        new_wever_mint,
+        new_wever_defaults,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(mint_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1460,23 +2431,20 @@ pub fn withdrawal_ever_fee_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "withdrawalSolFee")]
-pub fn withdrawal_sol_fee_ix(
+#[wasm_bindgen(js_name = "setTreasury", unchecked_return_type = "Instruction")]
+pub fn set_treasury_ix(
     authority_pubkey: String,
-    recipient_token_pubkey: String,
-    mint_pubkey: String,
-    amount: u64,
+    treasury: Option<String>,
 ) -> Result<JsValue, JsValue> {
-    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let recipient_token_pubkey =
-        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
-    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
-
     let settings_pubkey = token_proxy::get_settings_address();
-    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
-    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let data = token_proxy::TokenProxyInstruction::WithdrawSolFee { amount }
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let treasury = treasury
+        .map(|treasury| Pubkey::from_str(treasury.as_str()).handle_error())
+        .transpose()?;
+
+    let data = token_proxy::TokenProxyInstruction::SetTreasury { treasury }
         .try_to_vec()
         .expect("pack");
 
@@ -1484,11 +2452,8 @@ pub fn withdrawal_sol_fee_ix(
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(vault_pubkey, false),
-            AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new(recipient_token_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
     };
@@ -1496,203 +2461,4421 @@ pub fn withdrawal_sol_fee_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "unpackSettings")]
-pub fn unpack_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
-    let settings = token_proxy::Settings::unpack(&data).handle_error()?;
+#[wasm_bindgen(js_name = "changeEnabledChains", unchecked_return_type = "Instruction")]
+pub fn change_enabled_chains_ix(
+    authority_pubkey: String,
+    enabled_chains: Vec<u64>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let s = WasmSettings {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeEnabledChains { enabled_chains }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeDepositValueFloor",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_deposit_value_floor_ix(
+    authority_pubkey: String,
+    #[wasm_bindgen(unchecked_param_type = "DepositValueFloor | undefined")]
+    new_deposit_value_floor: JsValue,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let new_deposit_value_floor: Option<token_proxy::DepositValueFloor> =
+        serde_wasm_bindgen::from_value(new_deposit_value_floor).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeDepositValueFloor {
+        new_deposit_value_floor,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeMinSupportedIxVersion",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_min_supported_ix_version_ix(
+    authority_pubkey: String,
+    min_supported_ix_version: u8,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeMinSupportedIxVersion {
+        min_supported_ix_version,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "pauseToken", unchecked_return_type = "Instruction")]
+pub fn pause_token_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    deposits: bool,
+    withdrawals: bool,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::PauseToken {
+        deposits,
+        withdrawals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpauseToken", unchecked_return_type = "Instruction")]
+pub fn unpause_token_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    deposits: bool,
+    withdrawals: bool,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::UnpauseToken {
+        deposits,
+        withdrawals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeGuardians", unchecked_return_type = "Instruction")]
+pub fn change_guardians_ix(
+    authority_pubkey: String,
+    guardians: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let guardians = guardians
+        .into_iter()
+        .map(|guardian| {
+            let guardian: String = serde_wasm_bindgen::from_value(guardian).handle_error()?;
+            Pubkey::from_str(guardian.as_str()).handle_error()
+        })
+        .collect::<Result<Vec<Pubkey>, JsValue>>()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeGuardians { guardians }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "pauseBridge", unchecked_return_type = "Instruction")]
+pub fn pause_bridge_ix(
+    authority_pubkey: String,
+    deposits: bool,
+    withdrawals: bool,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::PauseBridge {
+        deposits,
+        withdrawals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpauseBridge", unchecked_return_type = "Instruction")]
+pub fn unpause_bridge_ix(
+    authority_pubkey: String,
+    deposits: bool,
+    withdrawals: bool,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::UnpauseBridge {
+        deposits,
+        withdrawals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "createBridgeStats", unchecked_return_type = "Instruction")]
+pub fn create_bridge_stats_ix(funder_pubkey: String) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let data = token_proxy::TokenProxyInstruction::CreateBridgeStats
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "createApprovalQueue", unchecked_return_type = "Instruction")]
+pub fn create_approval_queue_ix(funder_pubkey: String) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+
+    let data = token_proxy::TokenProxyInstruction::CreateApprovalQueue
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(approval_queue_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "createFeeLedger", unchecked_return_type = "Instruction")]
+pub fn create_fee_ledger_ix(
+    funder_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+) -> Result<JsValue, JsValue> {
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::CreateFeeLedger
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "setRecipientHook", unchecked_return_type = "Instruction")]
+pub fn set_recipient_hook_ix(
+    funder_pubkey: String,
+    recipient_pubkey: String,
+    hook_program_id: String,
+    data: Vec<u8>,
+    accounts: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let hook_program_id = Pubkey::from_str(hook_program_id.as_str()).handle_error()?;
+
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+
+    let accounts = accounts
+        .into_iter()
+        .map(|account| {
+            let account: String = serde_wasm_bindgen::from_value(account).handle_error()?;
+            Pubkey::from_str(account.as_str()).handle_error()
+        })
+        .collect::<Result<Vec<Pubkey>, JsValue>>()?;
+
+    let data = token_proxy::TokenProxyInstruction::SetRecipientHook {
+        program_id: hook_program_id,
+        data,
+        accounts,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(recipient_pubkey, true),
+            AccountMeta::new(recipient_hook_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "removeRecipientHook", unchecked_return_type = "Instruction")]
+pub fn remove_recipient_hook_ix(
+    recipient_pubkey: String,
+    funder_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::RemoveRecipientHook
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(recipient_pubkey, true),
+            AccountMeta::new(recipient_hook_pubkey, false),
+            AccountMeta::new(funder_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeDepositLimit", unchecked_return_type = "Instruction")]
+pub fn change_deposit_limit_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    new_deposit_limit: u64,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeMinDeposit", unchecked_return_type = "Instruction")]
+pub fn change_min_deposit_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    new_min_deposit_amount: u64,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeMinDeposit {
+        new_min_deposit_amount,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeFillsRestricted",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_fills_restricted_ix(
+    authority_pubkey: String,
+    mint: String,
+    fills_restricted: bool,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let mint = Pubkey::from_str(mint.as_str()).handle_error()?;
+
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint);
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeFillsRestricted { fills_restricted }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeReferralFee", unchecked_return_type = "Instruction")]
+pub fn change_referral_fee_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    referral_fee_bps: u16,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeReferralFee { referral_fee_bps }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeDepositDailyLimit",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_deposit_daily_limit_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    new_deposit_daily_limit: Option<u64>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeDepositDailyLimit {
+        new_deposit_daily_limit,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeWithdrawalEpochLength",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_withdrawal_epoch_length_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    new_withdrawal_epoch_length: Option<i64>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeWithdrawalEpochLength {
+        new_withdrawal_epoch_length,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeWithdrawalLimits",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_withdrawal_limits_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeWithdrawalLimits {
+        new_withdrawal_limit,
+        new_withdrawal_daily_limit,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeWithdrawalLimitExemptions",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_withdrawal_limit_exemptions_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    new_withdrawal_limit_exemptions: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let new_withdrawal_limit_exemptions = new_withdrawal_limit_exemptions
+        .into_iter()
+        .map(|recipient| {
+            let recipient: String = serde_wasm_bindgen::from_value(recipient).handle_error()?;
+            Pubkey::from_str(recipient.as_str()).handle_error()
+        })
+        .collect::<Result<Vec<Pubkey>, JsValue>>()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeWithdrawalLimitExemptions {
+        new_withdrawal_limit_exemptions,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "createTokenMetadata", unchecked_return_type = "Instruction")]
+pub fn create_token_metadata_ix(
+    authority_pubkey: String,
+    funder_pubkey: String,
+    token: String,
+    uri: String,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+
+    let token = EverAddress::from_str(&token).handle_error()?;
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let mint_pubkey = token_proxy::get_mint_address(&token);
+    let metadata_pubkey = token_proxy::get_token_metadata_address(&mint_pubkey);
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::CreateTokenMetadata { uri }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(metadata_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(token_proxy::token_metadata::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "enableEmergency", unchecked_return_type = "Instruction")]
+pub fn enable_emergency_ix(
+    authority_pubkey: String,
+    expiry: Option<i64>,
+    reason: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::EnableEmergencyModeV2 { expiry, reason }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "enableEmergencyByOwner",
+    unchecked_return_type = "Instruction"
+)]
+pub fn enable_emergency_by_owner_ix(
+    authority_pubkey: String,
+    expiry: Option<i64>,
+    reason: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::EnableEmergencyModeV2 { expiry, reason }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "disableEmergency", unchecked_return_type = "Instruction")]
+pub fn disable_emergency_ix(
+    authority_pubkey: String,
+    reason: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::DisableEmergencyModeV2 { reason }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "enableTokenEmergency",
+    unchecked_return_type = "Instruction"
+)]
+pub fn enable_token_emergency_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    expiry: Option<i64>,
+    reason: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::EnableTokenEmergencyModeV2 { expiry, reason }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "enableTokenEmergencyByOwner",
+    unchecked_return_type = "Instruction"
+)]
+pub fn enable_token_emergency_by_owner_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    expiry: Option<i64>,
+    reason: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::EnableTokenEmergencyModeV2 { expiry, reason }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "disableTokenEmergency",
+    unchecked_return_type = "Instruction"
+)]
+pub fn disable_token_emergency_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    reason: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::DisableTokenEmergencyModeV2 { reason }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "withdrawalProxy", unchecked_return_type = "Instruction")]
+pub fn withdrawal_proxy_ix(
+    recipient_pubkey: String,
+    recipient_token_pubkey: String,
+    mint_pubkey: String,
+    amount: u64,
+) -> Result<JsValue, JsValue> {
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let recipient_token_pubkey =
+        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+
+    let proxy_pubkey = token_proxy::get_proxy_address(&mint_pubkey, &recipient_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::WithdrawProxy { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(recipient_pubkey, true),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(proxy_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "closeDeposit", unchecked_return_type = "Instruction")]
+pub fn close_deposit(
+    author_address: String,
+    deposit_address: String,
+    funder_address: String,
+) -> Result<JsValue, JsValue> {
+    let author_address = Pubkey::from_str(author_address.as_str()).handle_error()?;
+    let deposit_address = Pubkey::from_str(deposit_address.as_str()).handle_error()?;
+    let funder_address = Pubkey::from_str(funder_address.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::CloseDeposit
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(author_address, true),
+            AccountMeta::new(deposit_address, false),
+            AccountMeta::new(funder_address, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "closeWithdrawal", unchecked_return_type = "Instruction")]
+pub fn close_withdrawal(
+    withdrawal_address: String,
+    funder_address: String,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_address = Pubkey::from_str(withdrawal_address.as_str()).handle_error()?;
+    let funder_address = Pubkey::from_str(funder_address.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::CloseWithdrawal
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_address, false),
+            AccountMeta::new(funder_address, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "sweepClosedAccounts", unchecked_return_type = "Instruction")]
+pub fn sweep_closed_accounts(
+    account_addresses: Vec<JsValue>,
+    funder_addresses: Vec<JsValue>,
+    proposal_index_addresses: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    (account_addresses.len() == funder_addresses.len())
+        .then_some(())
+        .ok_or("Each account must have its funder")
+        .handle_error()?;
+
+    // Withdrawal must have its proposal index, deposit has `null`
+    (account_addresses.len() == proposal_index_addresses.len())
+        .then_some(())
+        .ok_or("Each account must have its proposal index")
+        .handle_error()?;
+
+    let parse_addresses = |addresses: Vec<JsValue>| {
+        addresses
+            .into_iter()
+            .map(|address| {
+                let address: String = serde_wasm_bindgen::from_value(address).handle_error()?;
+                Pubkey::from_str(address.as_str()).handle_error()
+            })
+            .collect::<Result<Vec<Pubkey>, JsValue>>()
+    };
+
+    let account_addresses = parse_addresses(account_addresses)?;
+    let funder_addresses = parse_addresses(funder_addresses)?;
+    let proposal_index_addresses = proposal_index_addresses
+        .into_iter()
+        .map(|address| {
+            let address: Option<String> = serde_wasm_bindgen::from_value(address).handle_error()?;
+            address
+                .map(|address| Pubkey::from_str(address.as_str()).handle_error())
+                .transpose()
+        })
+        .collect::<Result<Vec<Option<Pubkey>>, JsValue>>()?;
+
+    let data = token_proxy::TokenProxyInstruction::SweepClosedAccounts
+        .try_to_vec()
+        .expect("pack");
+
+    let accounts = account_addresses
+        .into_iter()
+        .zip(funder_addresses)
+        .zip(proposal_index_addresses)
+        .flat_map(
+            |((account_address, funder_address), proposal_index_address)| {
+                [account_address, funder_address]
+                    .into_iter()
+                    .chain(proposal_index_address)
+                    .map(|address| AccountMeta::new(address, false))
+            },
+        )
+        .collect();
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts,
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "expireWithdrawal", unchecked_return_type = "Instruction")]
+pub fn expire_withdrawal(
+    withdrawal_address: String,
+    funder_address: String,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_address = Pubkey::from_str(withdrawal_address.as_str()).handle_error()?;
+    let funder_address = Pubkey::from_str(funder_address.as_str()).handle_error()?;
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let data = token_proxy::TokenProxyInstruction::ExpireWithdrawal
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_address, false),
+            AccountMeta::new(funder_address, false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "cancelDeposit", unchecked_return_type = "Instruction")]
+pub fn cancel_deposit(
+    authority_address: String,
+    deposit_address: String,
+    author_token_address: String,
+    token: String,
+    is_sol: bool,
+) -> Result<JsValue, JsValue> {
+    let authority_address = Pubkey::from_str(authority_address.as_str()).handle_error()?;
+    let deposit_address = Pubkey::from_str(deposit_address.as_str()).handle_error()?;
+    let author_token_address = Pubkey::from_str(author_token_address.as_str()).handle_error()?;
+
+    let settings_address = token_proxy::get_settings_address();
+
+    let (mint_address, token_settings_address, vault_address) = if is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        (
+            mint,
+            token_proxy::get_token_settings_sol_address(&mint),
+            Some(token_proxy::get_vault_address(&mint)),
+        )
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        (
+            token_proxy::get_mint_address(&token),
+            token_proxy::get_token_settings_ever_address(&token),
+            None,
+        )
+    };
+
+    let data = token_proxy::TokenProxyInstruction::CancelDeposit
+        .try_to_vec()
+        .expect("pack");
+
+    let mut ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_address, true),
+            AccountMeta::new(deposit_address, false),
+            AccountMeta::new(author_token_address, false),
+            AccountMeta::new(mint_address, false),
+            AccountMeta::new_readonly(token_settings_address, false),
+            AccountMeta::new_readonly(settings_address, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    if let Some(vault_address) = vault_address {
+        ix.accounts.push(AccountMeta::new(vault_address, false));
+    }
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "addToDenylist", unchecked_return_type = "Instruction")]
+pub fn add_to_denylist_ix(
+    funder_pubkey: String,
+    authority_pubkey: String,
+    address: String,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let address = Pubkey::from_str(address.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let denylist_pubkey = token_proxy::get_denylist_address();
+
+    let data = token_proxy::TokenProxyInstruction::AddToDenylist { address }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(authority_pubkey, true),
+            AccountMeta::new(denylist_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "removeFromDenylist", unchecked_return_type = "Instruction")]
+pub fn remove_from_denylist_ix(
+    authority_pubkey: String,
+    address: String,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let address = Pubkey::from_str(address.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let denylist_pubkey = token_proxy::get_denylist_address();
+
+    let data = token_proxy::TokenProxyInstruction::RemoveFromDenylist { address }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority_pubkey, true),
+            AccountMeta::new(denylist_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "registerLiquidityProvider",
+    unchecked_return_type = "Instruction"
+)]
+pub fn register_liquidity_provider_ix(
+    funder_pubkey: String,
+    authority_pubkey: String,
+    provider: String,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let provider = Pubkey::from_str(provider.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let lp_registry_pubkey = token_proxy::get_lp_registry_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::RegisterLiquidityProvider { provider }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(authority_pubkey, true),
+            AccountMeta::new(lp_registry_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unregisterLiquidityProvider",
+    unchecked_return_type = "Instruction"
+)]
+pub fn unregister_liquidity_provider_ix(
+    authority_pubkey: String,
+    provider: String,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let provider = Pubkey::from_str(provider.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let lp_registry_pubkey = token_proxy::get_lp_registry_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::UnregisterLiquidityProvider { provider }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority_pubkey, true),
+            AccountMeta::new(lp_registry_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(
+    js_name = "cancelWithdrawalRequest",
+    unchecked_return_type = "Instruction"
+)]
+pub fn cancel_withdrawal_request(
+    author_pubkey: String,
+    withdrawal_address: String,
+    funder_address: String,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: String,
+    chain_id: Option<u64>,
+) -> Result<JsValue, JsValue> {
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let withdrawal_address = Pubkey::from_str(withdrawal_address.as_str()).handle_error()?;
+    let funder_address = Pubkey::from_str(funder_address.as_str()).handle_error()?;
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+
+    let proposal_index_pubkey = token_proxy::get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        chain_id,
+    );
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let data = token_proxy::TokenProxyInstruction::CancelWithdrawalRequest
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(withdrawal_address, false),
+            AccountMeta::new(proposal_index_pubkey, false),
+            AccountMeta::new(funder_address, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeVoteReward", unchecked_return_type = "Instruction")]
+pub fn change_vote_reward(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    vote_reward: u64,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeVoteReward { vote_reward }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "claimVoteRewards", unchecked_return_type = "Instruction")]
+pub fn claim_vote_rewards(
+    relay_pubkey: String,
+    recipient_token_address: String,
+    token: String,
+    token_is_sol: bool,
+    round_number: u32,
+    withdrawals: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let relay_pubkey = Pubkey::from_str(relay_pubkey.as_str()).handle_error()?;
+    let recipient_token_address =
+        Pubkey::from_str(recipient_token_address.as_str()).handle_error()?;
+
+    let (mint_or_vault_pubkey, token_settings_pubkey) = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        (
+            token_proxy::get_vault_address(&mint),
+            token_proxy::get_token_settings_sol_address(&mint),
+        )
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        (
+            token_proxy::get_mint_address(&token),
+            token_proxy::get_token_settings_ever_address(&token),
+        )
+    };
+
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+
+    let withdrawals = withdrawals
+        .into_iter()
+        .map(|withdrawal| {
+            let withdrawal: String = serde_wasm_bindgen::from_value(withdrawal).handle_error()?;
+            Pubkey::from_str(withdrawal.as_str()).handle_error()
+        })
+        .collect::<Result<Vec<Pubkey>, JsValue>>()?;
+
+    let data = token_proxy::TokenProxyInstruction::ClaimVoteRewards
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(relay_pubkey, true),
+        AccountMeta::new_readonly(relay_round_pubkey, false),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(fee_ledger_pubkey, false),
+        AccountMeta::new(mint_or_vault_pubkey, false),
+        AccountMeta::new(recipient_token_address, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    accounts.extend(
+        withdrawals
+            .into_iter()
+            .map(|withdrawal_pubkey| AccountMeta::new(withdrawal_pubkey, false)),
+    );
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts,
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "claimReferralFee", unchecked_return_type = "Instruction")]
+pub fn claim_referral_fee(
+    referrer_pubkey: String,
+    recipient_token_address: String,
+    token: String,
+    token_is_sol: bool,
+) -> Result<JsValue, JsValue> {
+    let referrer_pubkey = Pubkey::from_str(referrer_pubkey.as_str()).handle_error()?;
+    let recipient_token_address =
+        Pubkey::from_str(recipient_token_address.as_str()).handle_error()?;
+
+    let (mint_or_vault_pubkey, token_settings_pubkey) = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        (
+            token_proxy::get_vault_address(&mint),
+            token_proxy::get_token_settings_sol_address(&mint),
+        )
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        (
+            token_proxy::get_mint_address(&token),
+            token_proxy::get_token_settings_ever_address(&token),
+        )
+    };
+
+    let referral_pubkey =
+        token_proxy::get_referral_address(&token_settings_pubkey, &referrer_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::ClaimReferralFee
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new_readonly(referrer_pubkey, true),
+            AccountMeta::new(referral_pubkey, false),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new(mint_or_vault_pubkey, false),
+            AccountMeta::new(recipient_token_address, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "updateFee", unchecked_return_type = "Instruction")]
+pub fn update_fee(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    fee_type: String,
+    multiplier: u64,
+    divisor: u64,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let data = token_proxy::TokenProxyInstruction::UpdateFee {
+        fee_type: token_proxy::FeeType::from_str(&fee_type).handle_error()?,
+        multiplier,
+        divisor,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "updateFeeTiers", unchecked_return_type = "Instruction")]
+pub fn update_fee_tiers(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    fee_type: String,
+    #[wasm_bindgen(unchecked_param_type = "FeeTiers")] fee_tiers: JsValue,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let fee_tiers: token_proxy::FeeTiers =
+        serde_wasm_bindgen::from_value(fee_tiers).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::UpdateFeeTiers {
+        fee_type: token_proxy::FeeType::from_str(&fee_type).handle_error()?,
+        fee_tiers,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "updateTokenName", unchecked_return_type = "Instruction")]
+pub fn update_token_name(
+    authority_pubkey: String,
+    token: String,
+    symbol: String,
+    name: String,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let token_settings_pubkey = {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    };
+
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let data = token_proxy::TokenProxyInstruction::UpdateTokenName { symbol, name }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "approveWithdrawalEver",
+    unchecked_return_type = "Instruction"
+)]
+pub fn approve_withdrawal_ever(
+    authority_pubkey: String,
+    withdrawal_pubkey: String,
+    recipient_token_pubkey: String,
+    recipient_pubkey: String,
+    mint_pubkey: String,
+    token: String,
+    note: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let recipient_token_pubkey =
+        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let token = EverAddress::from_str(&token).handle_error()?;
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let data = token_proxy::TokenProxyInstruction::ApproveWithdrawEverV2 {
+        note: note.map(String::into_bytes),
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(recipient_hook_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "approveWithdrawalSol",
+    unchecked_return_type = "Instruction"
+)]
+pub fn approve_withdrawal_sol(
+    authority_pubkey: String,
+    withdrawal_pubkey: String,
+    recipient_token_pubkey: String,
+    recipient_pubkey: String,
+    mint_pubkey: String,
+    note: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let recipient_token_pubkey =
+        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let data = token_proxy::TokenProxyInstruction::ApproveWithdrawSolV2 {
+        note: note.map(String::into_bytes),
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(recipient_hook_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "rejectWithdrawalEver",
+    unchecked_return_type = "Instruction"
+)]
+pub fn reject_withdrawal_ever(
+    authority_pubkey: String,
+    withdrawal_pubkey: String,
+    token: String,
+    note: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+
+    let token = EverAddress::from_str(&token).handle_error()?;
+    let mint_pubkey = token_proxy::get_mint_address(&token);
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let data = token_proxy::TokenProxyInstruction::RejectWithdrawEver {
+        note: note.map(String::into_bytes),
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "rejectWithdrawalSol", unchecked_return_type = "Instruction")]
+pub fn reject_withdrawal_sol(
+    authority_pubkey: String,
+    withdrawal_pubkey: String,
+    mint_pubkey: String,
+    note: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let data = token_proxy::TokenProxyInstruction::RejectWithdrawSol {
+        note: note.map(String::into_bytes),
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(approval_queue_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "cancelWithdrawalSol", unchecked_return_type = "Instruction")]
+pub fn cancel_withdrawal_sol(
+    funder_pubkey: String,
+    author_pubkey: String,
+    withdrawal_pubkey: String,
+    mint_pubkey: String,
+    deposit_seed: String,
+    recipient: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+) -> Result<JsValue, JsValue> {
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multivault_pubkey = token_proxy::get_multivault_address();
+    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::CancelWithdrawSol {
+        deposit_seed,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Deserialize)]
+pub struct FillWithdrawals {
+    pub withdrawal_pubkey: String,
+    pub to_pubkey: String,
+    // Amount to cover. The unfilled rest if omitted
+    #[serde(default)]
+    pub fill_amount: Option<u64>,
+}
+
+#[wasm_bindgen(js_name = "fillWithdrawalSol", unchecked_return_type = "Instruction")]
+pub fn fill_withdrawal_sol(
+    funder_pubkey: String,
+    author_pubkey: String,
+    mint_pubkey: String,
+    deposit_seed: String,
+    recipient: String,
+    amount: u64,
+    withdrawal_pubkeys: Vec<JsValue>,
+    vault_pubkey: Option<String>,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+) -> Result<JsValue, JsValue> {
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient).handle_error()?;
+
+    let author_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&author_pubkey, &mint_pubkey);
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multivault_pubkey = token_proxy::get_multivault_address();
+    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let fills = withdrawal_pubkeys
+        .into_iter()
+        .map(|fill| serde_wasm_bindgen::from_value::<FillWithdrawals>(fill).handle_error())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Any portion makes it partial fill, the rest of withdrawals are covered completely
+    let data = if fills.iter().any(|fill| fill.fill_amount.is_some()) {
+        token_proxy::TokenProxyInstruction::PartialFillWithdrawSol {
+            deposit_seed,
+            recipient,
+            amount,
+            value,
+            expected_evers,
+            payload,
+            fill_amounts: fills
+                .iter()
+                .map(|fill| fill.fill_amount.unwrap_or(u64::MAX))
+                .collect(),
+        }
+    } else {
+        token_proxy::TokenProxyInstruction::FillWithdrawSol {
+            deposit_seed,
+            recipient,
+            amount,
+            value,
+            expected_evers,
+            payload,
+        }
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(bridge_stats_pubkey, false),
+        ],
+        data,
+    };
+
+    for fill in fills {
+        let withdrawal_pubkey = Pubkey::from_str(fill.withdrawal_pubkey.as_str()).handle_error()?;
+        let to_pubkey = Pubkey::from_str(fill.to_pubkey.as_str()).handle_error()?;
+        let recipient_token_pubkey =
+            spl_associated_token_account::get_associated_token_address(&to_pubkey, &mint_pubkey);
+        ix.accounts.push(AccountMeta::new(withdrawal_pubkey, false));
+        ix.accounts
+            .push(AccountMeta::new(recipient_token_pubkey, false));
+    }
+
+    if let Some(vault_pubkey) = vault_pubkey {
+        let vault_pubkey = Pubkey::from_str(vault_pubkey.as_str()).handle_error()?;
+        ix.accounts.push(AccountMeta::new(vault_pubkey, false));
+    }
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeBountyForWithdrawalSol",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_bounty_for_withdrawal_sol_ix(
+    author_pubkey: String,
+    withdrawal_pubkey: String,
+    bounty: u64,
+) -> Result<JsValue, JsValue> {
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeBountyForWithdrawSol { bounty }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "depositBountyForWithdrawalSol",
+    unchecked_return_type = "Instruction"
+)]
+pub fn deposit_bounty_for_withdrawal_sol_ix(
+    author_pubkey: String,
+    withdrawal_pubkey: String,
+    mint_pubkey: String,
+    amount: u64,
+) -> Result<JsValue, JsValue> {
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let author_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&author_pubkey, &mint_pubkey);
+    let bounty_pubkey = token_proxy::get_bounty_address(&withdrawal_pubkey, &author_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::DepositBountyForWithdrawSol { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new_readonly(withdrawal_pubkey, false),
+            AccountMeta::new(bounty_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "refundBountyForWithdrawalSol",
+    unchecked_return_type = "Instruction"
+)]
+pub fn refund_bounty_for_withdrawal_sol_ix(
+    author_pubkey: String,
+    withdrawal_pubkey: String,
+    mint_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let author_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&author_pubkey, &mint_pubkey);
+    let bounty_pubkey = token_proxy::get_bounty_address(&withdrawal_pubkey, &author_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::RefundBountyForWithdrawSol
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new_readonly(withdrawal_pubkey, false),
+            AccountMeta::new(bounty_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "withdrawalMultiVault",
+    unchecked_return_type = "Instruction"
+)]
+pub fn withdrawal_multi_vault_ix(
+    authority_pubkey: String,
+    recipient_pubkey: String,
+    amount: u64,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multi_vault_pubkey = token_proxy::get_multivault_address();
+
+    let data = token_proxy::TokenProxyInstruction::WithdrawMultiVault { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(recipient_pubkey, false),
+            AccountMeta::new(multi_vault_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "withdrawalEverFee", unchecked_return_type = "Instruction")]
+pub fn withdrawal_ever_fee_ix(
+    authority_pubkey: String,
+    mint_pubkey: String,
+    recipient_token_pubkey: String,
+    token: String,
+    amount: u64,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+    let recipient_token_pubkey =
+        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let token = EverAddress::from_str(&token).handle_error()?;
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::WithdrawEverFee { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "withdrawalSolFee", unchecked_return_type = "Instruction")]
+pub fn withdrawal_sol_fee_ix(
+    authority_pubkey: String,
+    recipient_token_pubkey: String,
+    mint_pubkey: String,
+    amount: u64,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let recipient_token_pubkey =
+        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let settings_pubkey = token_proxy::get_settings_address();
+    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::WithdrawSolFee { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(fee_ledger_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeRoundSubmitter",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_round_submitter_ix(
+    authority_pubkey: String,
+    round_submitter: String,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let round_submitter = Pubkey::from_str(round_submitter.as_str()).handle_error()?;
+
+    let data = round_loader::RoundLoaderInstruction::UpdateSettings {
+        current_round_number: None,
+        round_submitter: Some(round_submitter),
+        min_required_votes: None,
+        round_ttl: None,
+        guardian: None,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = round_loader_update_settings_ix(authority_pubkey, data);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeRoundTtl", unchecked_return_type = "Instruction")]
+pub fn change_round_ttl_ix(authority_pubkey: String, round_ttl: u32) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = round_loader::RoundLoaderInstruction::UpdateSettings {
+        current_round_number: None,
+        round_submitter: None,
+        min_required_votes: None,
+        round_ttl: Some(round_ttl),
+        guardian: None,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = round_loader_update_settings_ix(authority_pubkey, data);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "changeRoundHandoverPeriod",
+    unchecked_return_type = "Instruction"
+)]
+pub fn change_round_handover_period_ix(
+    authority_pubkey: String,
+    round_handover_period: u32,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = round_loader::RoundLoaderInstruction::UpdateRoundHandoverPeriod {
+        round_handover_period,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = round_loader_update_settings_ix(authority_pubkey, data);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeQuorum", unchecked_return_type = "Instruction")]
+pub fn change_quorum_ix(
+    authority_pubkey: String,
+    quorum_numerator: u32,
+    quorum_denominator: u32,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = round_loader::RoundLoaderInstruction::UpdateQuorum {
+        quorum_numerator,
+        quorum_denominator,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = round_loader_update_settings_ix(authority_pubkey, data);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+fn round_loader_update_settings_ix(authority_pubkey: Pubkey, data: Vec<u8>) -> Instruction {
+    let settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+    let program_data_pubkey = bridge_utils::helper::get_programdata_address(&round_loader::id());
+
+    Instruction {
+        program_id: round_loader::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    }
+}
+
+#[wasm_bindgen(js_name = "buildMessage", unchecked_return_type = "string")]
+pub fn build_message(
+    payer_pubkey: String,
+    #[wasm_bindgen(unchecked_param_type = "Instruction[]")] instructions: Vec<JsValue>,
+    recent_blockhash: Option<String>,
+    #[wasm_bindgen(unchecked_param_type = "WasmAddressLookupTable[]")] lookup_tables: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let message = compile_message(payer_pubkey, instructions, recent_blockhash, lookup_tables)?;
+
+    let message = general_purpose::STANDARD.encode(message.serialize());
+
+    return serde_wasm_bindgen::to_value(&message).handle_error();
+}
+
+#[wasm_bindgen(js_name = "buildTransaction", unchecked_return_type = "string")]
+pub fn build_transaction(
+    payer_pubkey: String,
+    #[wasm_bindgen(unchecked_param_type = "Instruction[]")] instructions: Vec<JsValue>,
+    recent_blockhash: Option<String>,
+    #[wasm_bindgen(unchecked_param_type = "WasmAddressLookupTable[]")] lookup_tables: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let message = compile_message(payer_pubkey, instructions, recent_blockhash, lookup_tables)?;
+
+    // Unsigned transaction with zeroed signatures to be filled by wallet
+    let num_signatures = message.header().num_required_signatures;
+
+    let mut transaction = encode_short_u16(num_signatures as u16);
+    transaction.resize(
+        transaction.len() + num_signatures as usize * SIGNATURE_BYTES,
+        0,
+    );
+    transaction.extend(message.serialize());
+
+    let transaction = general_purpose::STANDARD.encode(transaction);
+
+    return serde_wasm_bindgen::to_value(&transaction).handle_error();
+}
+
+fn compile_message(
+    payer_pubkey: String,
+    instructions: Vec<JsValue>,
+    recent_blockhash: Option<String>,
+    lookup_tables: Vec<JsValue>,
+) -> Result<VersionedMessage, JsValue> {
+    let lookup_tables = lookup_tables
+        .into_iter()
+        .map(|lookup_table| {
+            let lookup_table: WasmAddressLookupTable =
+                serde_wasm_bindgen::from_value(lookup_table).handle_error()?;
+
+            let key = Pubkey::from_str(lookup_table.key.as_str()).handle_error()?;
+            let addresses = lookup_table
+                .addresses
+                .iter()
+                .map(|address| Pubkey::from_str(address.as_str()).handle_error())
+                .collect::<Result<Vec<Pubkey>, JsValue>>()?;
+
+            Ok(AddressLookupTableAccount { key, addresses })
+        })
+        .collect::<Result<Vec<AddressLookupTableAccount>, JsValue>>()?;
+
+    compile_versioned_message(payer_pubkey, instructions, recent_blockhash, lookup_tables)
+}
+
+fn compile_versioned_message(
+    payer_pubkey: String,
+    instructions: Vec<JsValue>,
+    recent_blockhash: Option<String>,
+    lookup_tables: Vec<AddressLookupTableAccount>,
+) -> Result<VersionedMessage, JsValue> {
+    let payer_pubkey = Pubkey::from_str(payer_pubkey.as_str()).handle_error()?;
+
+    // Placeholder to be replaced by wallet before signing
+    let recent_blockhash = match recent_blockhash {
+        Some(recent_blockhash) => Hash::from_str(recent_blockhash.as_str()).handle_error()?,
+        None => Hash::default(),
+    };
+
+    let instructions = instructions
+        .into_iter()
+        .map(|ix| serde_wasm_bindgen::from_value(ix).handle_error())
+        .collect::<Result<Vec<Instruction>, JsValue>>()?;
+
+    let message = if lookup_tables.is_empty() {
+        VersionedMessage::Legacy(Message::new_with_blockhash(
+            &instructions,
+            Some(&payer_pubkey),
+            &recent_blockhash,
+        ))
+    } else {
+        VersionedMessage::V0(
+            v0::Message::try_compile(
+                &payer_pubkey,
+                &instructions,
+                &lookup_tables,
+                recent_blockhash,
+            )
+            .handle_error()?,
+        )
+    };
+
+    Ok(message)
+}
+
+fn encode_short_u16(mut value: u16) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(3);
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+
+        bytes.push(byte | 0x80);
+    }
+}
+
+#[wasm_bindgen(
+    js_name = "getLookupTableAddresses",
+    unchecked_return_type = "string[]"
+)]
+pub fn get_lookup_table_addresses(
+    mints: Vec<JsValue>,
+    tokens: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let (mints, tokens) = parse_lookup_table_tokens(mints, tokens)?;
+
+    let addresses = token_proxy::get_lookup_table_addresses(&mints, &tokens)
+        .iter()
+        .map(Pubkey::to_string)
+        .collect::<Vec<_>>();
+
+    return serde_wasm_bindgen::to_value(&addresses).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "buildMessageWithLookupTable",
+    unchecked_return_type = "string"
+)]
+pub fn build_message_with_lookup_table(
+    payer_pubkey: String,
+    #[wasm_bindgen(unchecked_param_type = "Instruction[]")] instructions: Vec<JsValue>,
+    recent_blockhash: Option<String>,
+    lookup_table_address: String,
+    mints: Vec<JsValue>,
+    tokens: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let lookup_table_address = Pubkey::from_str(lookup_table_address.as_str()).handle_error()?;
+
+    let (mints, tokens) = parse_lookup_table_tokens(mints, tokens)?;
+
+    let lookup_table = AddressLookupTableAccount {
+        key: lookup_table_address,
+        addresses: token_proxy::get_lookup_table_addresses(&mints, &tokens),
+    };
+
+    let message = compile_versioned_message(
+        payer_pubkey,
+        instructions,
+        recent_blockhash,
+        vec![lookup_table],
+    )?;
+
+    let message = general_purpose::STANDARD.encode(message.serialize());
+
+    return serde_wasm_bindgen::to_value(&message).handle_error();
+}
+
+fn parse_lookup_table_tokens(
+    mints: Vec<JsValue>,
+    tokens: Vec<JsValue>,
+) -> Result<(Vec<Pubkey>, Vec<EverAddress>), JsValue> {
+    let mints = mints
+        .into_iter()
+        .map(|mint| {
+            let mint: String = serde_wasm_bindgen::from_value(mint).handle_error()?;
+            Pubkey::from_str(mint.as_str()).handle_error()
+        })
+        .collect::<Result<Vec<Pubkey>, JsValue>>()?;
+
+    let tokens = tokens
+        .into_iter()
+        .map(|token| {
+            let token: String = serde_wasm_bindgen::from_value(token).handle_error()?;
+            EverAddress::from_str(token.as_str()).handle_error()
+        })
+        .collect::<Result<Vec<EverAddress>, JsValue>>()?;
+
+    Ok((mints, tokens))
+}
+
+#[wasm_bindgen(
+    js_name = "validateEverAddress",
+    unchecked_return_type = "WasmEverAddressValidation"
+)]
+pub fn validate_ever_address(address: String) -> Result<JsValue, JsValue> {
+    let v = match EverAddress::from_str(address.trim()) {
+        Ok(EverAddress::AddrStd(addr)) => WasmEverAddressValidation {
+            is_valid: true,
+            address: Some(EverAddress::AddrStd(addr).to_string()),
+            bytes: Some(addr.address.to_vec()),
+            workchain_id: Some(addr.workchain_id),
+            error: None,
+        },
+        Err(e) => WasmEverAddressValidation {
+            is_valid: false,
+            address: None,
+            bytes: None,
+            workchain_id: None,
+            error: Some(e.to_string()),
+        },
+    };
+
+    return serde_wasm_bindgen::to_value(&v).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackSettings", unchecked_return_type = "WasmSettings")]
+pub fn unpack_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let settings = token_proxy::Settings::unpack(&data).handle_error()?;
+
+    let s = WasmSettings {
         emergency: settings.emergency,
         guardian: settings.guardian,
-        withdrawal_manager: settings.withdrawal_manager,
-        manager: settings.manager,
+        withdrawal_manager: settings.withdrawal_manager,
+        manager: settings.manager,
+        payload_allowlist: settings.payload_allowlist,
+        deposit_caller_allowlist: settings.deposit_caller_allowlist,
+        emergency_expiry: settings.emergency_expiry,
+        emergency_info: settings.emergency_info,
+        token_defaults: settings.token_defaults.map(|defaults| WasmTokenDefaults {
+            deposit_limit: defaults.deposit_limit.to_string(),
+            withdrawal_limit: defaults.withdrawal_limit.to_string(),
+            withdrawal_daily_limit: defaults.withdrawal_daily_limit.to_string(),
+            deposit_daily_limit: defaults.deposit_daily_limit.map(|limit| limit.to_string()),
+            fee_deposit_info: defaults.fee_deposit_info,
+            fee_withdrawal_info: defaults.fee_withdrawal_info,
+        }),
+        deposits_paused: settings.deposits_paused,
+        withdrawals_paused: settings.withdrawals_paused,
+        guardians: settings.guardians,
+        treasury: settings.treasury,
+        wever_mint: settings.wever_mint,
+        wever_defaults: settings.wever_defaults.map(|defaults| WasmTokenDefaults {
+            deposit_limit: defaults.deposit_limit.to_string(),
+            withdrawal_limit: defaults.withdrawal_limit.to_string(),
+            withdrawal_daily_limit: defaults.withdrawal_daily_limit.to_string(),
+            deposit_daily_limit: defaults.deposit_daily_limit.map(|limit| limit.to_string()),
+            fee_deposit_info: defaults.fee_deposit_info,
+            fee_withdrawal_info: defaults.fee_withdrawal_info,
+        }),
+        enabled_chains: settings.enabled_chains,
+        deposit_value_floor: settings.deposit_value_floor,
+        min_supported_ix_version: settings.min_supported_ix_version,
+        pending_role: settings.pending_role,
+        timelock_delay: settings.timelock_delay,
+    };
+
+    return serde_wasm_bindgen::to_value(&s).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getProposalSolAddress", unchecked_return_type = "Pubkey")]
+pub fn get_proposal_sol_address(
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: String,
+    mint_address: String,
+    recipient_address: String,
+    amount: String,
+    payload: String,
+) -> Result<JsValue, JsValue> {
+    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+    let amount = u128::from_str(&amount).handle_error()?;
+
+    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let withdrawal_pubkey = token_proxy::get_withdrawal_sol_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint_address,
+        recipient_address,
+        amount,
+        payload,
+    );
+
+    return serde_wasm_bindgen::to_value(&withdrawal_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getProposalIndexAddress", unchecked_return_type = "Pubkey")]
+pub fn get_proposal_index_address(
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: String,
+    chain_id: Option<u64>,
+) -> Result<JsValue, JsValue> {
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+
+    let proposal_index_pubkey = token_proxy::get_proposal_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        chain_id,
+    );
+
+    return serde_wasm_bindgen::to_value(&proposal_index_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getFeeLedgerAddress", unchecked_return_type = "Pubkey")]
+pub fn get_fee_ledger_address(token_settings: String) -> Result<JsValue, JsValue> {
+    let token_settings = Pubkey::from_str(token_settings.as_str()).handle_error()?;
+
+    let fee_ledger_pubkey = token_proxy::get_fee_ledger_address(&token_settings);
+
+    return serde_wasm_bindgen::to_value(&fee_ledger_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getPendingChangeAddress", unchecked_return_type = "Pubkey")]
+pub fn get_pending_change_address(change_seed: String) -> Result<JsValue, JsValue> {
+    let change_seed = uuid::Uuid::from_str(&change_seed).handle_error()?.as_u128();
+
+    let pending_change_pubkey = token_proxy::get_pending_change_address(change_seed);
+
+    return serde_wasm_bindgen::to_value(&pending_change_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getRecipientHookAddress", unchecked_return_type = "Pubkey")]
+pub fn get_recipient_hook_address(recipient: String) -> Result<JsValue, JsValue> {
+    let recipient = Pubkey::from_str(recipient.as_str()).handle_error()?;
+
+    let recipient_hook_pubkey = token_proxy::get_recipient_hook_address(&recipient);
+
+    return serde_wasm_bindgen::to_value(&recipient_hook_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getLpRegistryAddress", unchecked_return_type = "Pubkey")]
+pub fn get_lp_registry_address() -> Result<JsValue, JsValue> {
+    let lp_registry_pubkey = token_proxy::get_lp_registry_address();
+
+    return serde_wasm_bindgen::to_value(&lp_registry_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getReferralAddress", unchecked_return_type = "Pubkey")]
+pub fn get_referral_address(token_settings: String, referrer: String) -> Result<JsValue, JsValue> {
+    let token_settings = Pubkey::from_str(token_settings.as_str()).handle_error()?;
+    let referrer = Pubkey::from_str(referrer.as_str()).handle_error()?;
+
+    let referral_pubkey = token_proxy::get_referral_address(&token_settings, &referrer);
+
+    return serde_wasm_bindgen::to_value(&referral_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getApprovalQueueAddress", unchecked_return_type = "Pubkey")]
+pub fn get_approval_queue_address() -> Result<JsValue, JsValue> {
+    let approval_queue_pubkey = token_proxy::get_approval_queue_address();
+
+    return serde_wasm_bindgen::to_value(&approval_queue_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getBridgeStatsAddress", unchecked_return_type = "Pubkey")]
+pub fn get_bridge_stats_address() -> Result<JsValue, JsValue> {
+    let bridge_stats_pubkey = token_proxy::get_bridge_stats_address();
+
+    return serde_wasm_bindgen::to_value(&bridge_stats_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getBountyAddress", unchecked_return_type = "Pubkey")]
+pub fn get_bounty_address(withdrawal: String, author: String) -> Result<JsValue, JsValue> {
+    let withdrawal = Pubkey::from_str(withdrawal.as_str()).handle_error()?;
+    let author = Pubkey::from_str(author.as_str()).handle_error()?;
+
+    let bounty_pubkey = token_proxy::get_bounty_address(&withdrawal, &author);
+
+    return serde_wasm_bindgen::to_value(&bounty_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getRelayRewardAddress", unchecked_return_type = "Pubkey")]
+pub fn get_relay_reward_address(relay: String, round_number: u32) -> Result<JsValue, JsValue> {
+    let relay = Pubkey::from_str(relay.as_str()).handle_error()?;
+
+    let relay_reward_pubkey = token_proxy::get_relay_reward_address(&relay, round_number);
+
+    return serde_wasm_bindgen::to_value(&relay_reward_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getGovernanceAddress", unchecked_return_type = "Pubkey")]
+pub fn get_governance_address(
+    governance_program_id: String,
+    realm: String,
+) -> Result<JsValue, JsValue> {
+    let governance_program_id = Pubkey::from_str(governance_program_id.as_str()).handle_error()?;
+    let realm = Pubkey::from_str(realm.as_str()).handle_error()?;
+
+    let governance_pubkey = token_proxy::get_governance_address(&governance_program_id, &realm);
+
+    return serde_wasm_bindgen::to_value(&governance_pubkey).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "getRoundLoaderSettingsAddress",
+    unchecked_return_type = "Pubkey"
+)]
+pub fn get_round_loader_settings_address() -> Result<JsValue, JsValue> {
+    let settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader::id());
+
+    return serde_wasm_bindgen::to_value(&settings_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getProxyAddress", unchecked_return_type = "Pubkey")]
+pub fn get_proxy_address_payload(
+    mint_address: String,
+    recipient_address: String,
+) -> Result<JsValue, JsValue> {
+    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
+    let proxy_address = token_proxy::get_proxy_address(&mint_address, &recipient_address);
+    return serde_wasm_bindgen::to_value(&proxy_address).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getProxyAccountAddress", unchecked_return_type = "Pubkey")]
+pub fn get_proxy_account_address(
+    mint_address: String,
+    recipient_address: String,
+    index: u8,
+) -> Result<JsValue, JsValue> {
+    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
+    let proxy_account_address =
+        token_proxy::get_proxy_account_address(&mint_address, &recipient_address, index);
+    return serde_wasm_bindgen::to_value(&proxy_account_address).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getProposalEverAddress", unchecked_return_type = "Pubkey")]
+pub fn get_proposal_ever_address(
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: String,
+    token_address: String,
+    recipient_address: String,
+    amount: String,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    payload: String,
+) -> Result<JsValue, JsValue> {
+    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+    let amount = u128::from_str(&amount).handle_error()?;
+
+    let token = EverAddress::from_str(&token_address).handle_error()?;
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let withdrawal_pubkey = token_proxy::get_withdrawal_ever_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        token,
+        name,
+        symbol,
+        decimals,
+        recipient_address,
+        amount,
+        payload,
+    );
+
+    return serde_wasm_bindgen::to_value(&withdrawal_pubkey).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackTokenSettings",
+    unchecked_return_type = "WasmTokenSettings"
+)]
+pub fn unpack_token_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let token_settings = token_proxy::TokenSettings::unpack(&data).handle_error()?;
+
+    let s = WasmTokenSettings {
+        is_initialized: token_settings.is_initialized,
+        account_kind: token_settings.account_kind,
+        kind: token_settings.kind.into(),
+        deposit_limit: token_settings.deposit_limit.to_string(),
+        withdrawal_limit: token_settings.withdrawal_limit.to_string(),
+        withdrawal_daily_limit: token_settings.withdrawal_daily_limit.to_string(),
+        withdrawal_daily_amount: token_settings.withdrawal_daily_amount.to_string(),
+        withdrawal_epoch: token_settings.withdrawal_epoch.to_string(),
+        emergency: token_settings.emergency,
+        name: token_settings.name,
+        symbol: token_settings.symbol,
+        fee_supply: token_settings.fee_supply,
+        fee_deposit_info: token_settings.fee_deposit_info,
+        fee_withdrawal_info: token_settings.fee_withdrawal_info,
+        deposit_daily_limit: token_settings
+            .deposit_daily_limit
+            .map(|limit| limit.to_string()),
+        deposit_daily_amount: token_settings.deposit_daily_amount.to_string(),
+        deposit_epoch: token_settings.deposit_epoch.to_string(),
+        withdrawal_epoch_length: token_settings
+            .withdrawal_epoch_length
+            .map(|length| length.to_string()),
+        emergency_expiry: token_settings.emergency_expiry,
+        emergency_info: token_settings.emergency_info,
+        delisted: token_settings.delisted,
+        authority_migration: token_settings.authority_migration,
+        deposits_paused: token_settings.deposits_paused,
+        withdrawals_paused: token_settings.withdrawals_paused,
+        vote_reward: token_settings.vote_reward,
+        withdrawal_limit_exemptions: token_settings.withdrawal_limit_exemptions,
+        fee_deposit_tiers: token_settings.fee_deposit_tiers,
+        fee_withdrawal_tiers: token_settings.fee_withdrawal_tiers,
+        min_deposit_amount: token_settings.min_deposit_amount,
+        fills_restricted: token_settings.fills_restricted,
+        referral_fee_bps: token_settings.referral_fee_bps,
+        pending_withdrawals: token_settings.pending_withdrawals,
+        closed: token_settings.closed,
+    };
+
+    return serde_wasm_bindgen::to_value(&s).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackWithdrawalMultiTokenEver",
+    unchecked_return_type = "WasmWithdrawalMultiTokenEver"
+)]
+pub fn unpack_withdrawal_multitoken_ever(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let withdrawal = token_proxy::WithdrawalMultiTokenEver::unpack(&data).handle_error()?;
+
+    let w = WasmWithdrawalMultiTokenEver {
+        is_initialized: withdrawal.is_initialized,
+        account_kind: withdrawal.account_kind,
+        author: withdrawal.author,
+        round_number: withdrawal.round_number,
+        required_votes: withdrawal.required_votes,
+        pda: withdrawal.pda,
+        event: withdrawal.event,
+        meta: withdrawal.meta,
+        signers: withdrawal.signers,
+        chain_id: withdrawal.chain_id,
+        vote_rewards: withdrawal.vote_rewards,
+        confirmed_weight: withdrawal.confirmed_weight,
+    };
+
+    return serde_wasm_bindgen::to_value(&w).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackWithdrawalMultiTokenSol",
+    unchecked_return_type = "WasmWithdrawalMultiTokenSol"
+)]
+pub fn unpack_withdrawal_multitoken_sol(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let withdrawal = token_proxy::WithdrawalMultiTokenSol::unpack(&data).handle_error()?;
+
+    let w = WasmWithdrawalMultiTokenSol {
+        is_initialized: withdrawal.is_initialized,
+        account_kind: withdrawal.account_kind,
+        author: withdrawal.author,
+        round_number: withdrawal.round_number,
+        required_votes: withdrawal.required_votes,
+        pda: withdrawal.pda,
+        event: withdrawal.event,
+        meta: withdrawal.meta,
+        signers: withdrawal.signers,
+        chain_id: withdrawal.chain_id,
+        vote_rewards: withdrawal.vote_rewards,
+        confirmed_weight: withdrawal.confirmed_weight,
+    };
+
+    return serde_wasm_bindgen::to_value(&w).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackDepositEver",
+    unchecked_return_type = "WasmDepositMultiTokenEver"
+)]
+pub fn unpack_deposit_ever(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let deposit = token_proxy::DepositMultiTokenEver::unpack(&data).handle_error()?;
+
+    let d = WasmDepositMultiTokenEver {
+        is_initialized: deposit.is_initialized,
+        account_kind: deposit.account_kind,
+        event: deposit.event,
+        meta: WasmDepositTokenMeta {
+            seed: deposit.meta.data.seed.to_string(),
+            funder: deposit.meta.data.funder,
+            slot: deposit.meta.data.slot,
+            refunded: deposit.meta.data.refunded,
+        },
+    };
+
+    return serde_wasm_bindgen::to_value(&d).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackDepositSol",
+    unchecked_return_type = "WasmDepositMultiTokenSol"
+)]
+pub fn unpack_deposit_sol(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let deposit = token_proxy::DepositMultiTokenSol::unpack(&data).handle_error()?;
+
+    let d = WasmDepositMultiTokenSol {
+        is_initialized: deposit.is_initialized,
+        account_kind: deposit.account_kind,
+        event: deposit.event,
+        meta: WasmDepositTokenMeta {
+            seed: deposit.meta.data.seed.to_string(),
+            funder: deposit.meta.data.funder,
+            slot: deposit.meta.data.slot,
+            refunded: deposit.meta.data.refunded,
+        },
+    };
+
+    return serde_wasm_bindgen::to_value(&d).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackMultiVault", unchecked_return_type = "WasmMultiVault")]
+pub fn unpack_multi_vault(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let multi_vault = token_proxy::MultiVault::unpack(&data).handle_error()?;
+
+    let mv = WasmMultiVault {
+        is_initialized: multi_vault.is_initialized,
+        account_kind: multi_vault.account_kind,
+    };
+
+    return serde_wasm_bindgen::to_value(&mv).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackTokenDecimals",
+    unchecked_return_type = "WasmTokenDecimals"
+)]
+pub fn unpack_token_decimals(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let token_decimals = token_proxy::TokenDecimals::unpack(&data).handle_error()?;
+
+    let td = WasmTokenDecimals {
+        is_initialized: token_decimals.is_initialized,
+        account_kind: token_decimals.account_kind,
+        token: token_decimals.token.to_string(),
+        solana_decimals: token_decimals.solana_decimals,
+    };
+
+    return serde_wasm_bindgen::to_value(&td).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackProposalIndex",
+    unchecked_return_type = "WasmProposalIndex"
+)]
+pub fn unpack_proposal_index(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let proposal_index = token_proxy::ProposalIndex::unpack(&data).handle_error()?;
+
+    let pi = WasmProposalIndex {
+        is_initialized: proposal_index.is_initialized,
+        account_kind: proposal_index.account_kind,
+        event_data: proposal_index.event_data.to_string(),
+    };
+
+    return serde_wasm_bindgen::to_value(&pi).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackFeeLedger", unchecked_return_type = "WasmFeeLedger")]
+pub fn unpack_fee_ledger(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let fee_ledger = token_proxy::FeeLedger::unpack(&data).handle_error()?;
+
+    let fl = WasmFeeLedger {
+        is_initialized: fee_ledger.is_initialized,
+        account_kind: fee_ledger.account_kind,
+        token_settings: fee_ledger.token_settings,
+        supply: fee_ledger.supply,
+    };
+
+    return serde_wasm_bindgen::to_value(&fl).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackPendingChange",
+    unchecked_return_type = "WasmPendingChange"
+)]
+pub fn unpack_pending_change(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let pending_change = token_proxy::PendingChange::unpack(&data).handle_error()?;
+
+    let pc = WasmPendingChange {
+        is_initialized: pending_change.is_initialized,
+        account_kind: pending_change.account_kind,
+        author: pending_change.author,
+        seed: uuid::Uuid::from_u128(pending_change.seed).to_string(),
+        change: pending_change.change,
+        eta: pending_change.eta,
+    };
+
+    return serde_wasm_bindgen::to_value(&pc).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackRecipientHook",
+    unchecked_return_type = "WasmRecipientHook"
+)]
+pub fn unpack_recipient_hook(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let recipient_hook = token_proxy::RecipientHook::unpack(&data).handle_error()?;
+
+    let rh = WasmRecipientHook {
+        is_initialized: recipient_hook.is_initialized,
+        account_kind: recipient_hook.account_kind,
+        recipient: recipient_hook.recipient,
+        program_id: recipient_hook.program_id,
+        data: recipient_hook.data,
+        accounts: recipient_hook.accounts,
+    };
+
+    return serde_wasm_bindgen::to_value(&rh).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackDenylist", unchecked_return_type = "WasmDenylist")]
+pub fn unpack_denylist(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let denylist = token_proxy::Denylist::unpack(&data).handle_error()?;
+
+    let dl = WasmDenylist {
+        is_initialized: denylist.is_initialized,
+        account_kind: denylist.account_kind,
+        addresses: denylist.addresses,
+    };
+
+    return serde_wasm_bindgen::to_value(&dl).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackLpRegistry", unchecked_return_type = "WasmLpRegistry")]
+pub fn unpack_lp_registry(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let lp_registry = token_proxy::LpRegistry::unpack(&data).handle_error()?;
+
+    let lr = WasmLpRegistry {
+        is_initialized: lp_registry.is_initialized,
+        account_kind: lp_registry.account_kind,
+        providers: lp_registry.providers,
+    };
+
+    return serde_wasm_bindgen::to_value(&lr).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackReferral", unchecked_return_type = "WasmReferral")]
+pub fn unpack_referral(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let referral = token_proxy::Referral::unpack(&data).handle_error()?;
+
+    let r = WasmReferral {
+        is_initialized: referral.is_initialized,
+        account_kind: referral.account_kind,
+        referrer: referral.referrer,
+        token_settings: referral.token_settings,
+        amount: referral.amount,
+    };
+
+    return serde_wasm_bindgen::to_value(&r).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackApprovalQueue",
+    unchecked_return_type = "WasmApprovalQueue"
+)]
+pub fn unpack_approval_queue(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let approval_queue = token_proxy::ApprovalQueue::unpack(&data).handle_error()?;
+
+    let aq = WasmApprovalQueue {
+        is_initialized: approval_queue.is_initialized,
+        account_kind: approval_queue.account_kind,
+        approvals: approval_queue
+            .approvals
+            .into_iter()
+            .map(|approval| WasmApproval {
+                withdrawal: approval.withdrawal,
+                recipient: approval.recipient,
+                amount: approval.amount,
+                status: approval.status,
+                note: approval
+                    .note
+                    .map(|note| String::from_utf8_lossy(&note).into_owned()),
+            })
+            .collect(),
+    };
+
+    return serde_wasm_bindgen::to_value(&aq).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackBridgeStats",
+    unchecked_return_type = "WasmBridgeStats"
+)]
+pub fn unpack_bridge_stats(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let bridge_stats = token_proxy::BridgeStats::unpack(&data).handle_error()?;
+
+    let bs = WasmBridgeStats {
+        is_initialized: bridge_stats.is_initialized,
+        account_kind: bridge_stats.account_kind,
+        deposits: bridge_stats.deposits,
+        withdrawals: bridge_stats.withdrawals,
+        deposit_volume: bridge_stats.deposit_volume.to_string(),
+        withdrawal_volume: bridge_stats.withdrawal_volume.to_string(),
+        pending_proposals: bridge_stats.pending_proposals,
+    };
+
+    return serde_wasm_bindgen::to_value(&bs).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackRelayReward",
+    unchecked_return_type = "WasmRelayReward"
+)]
+pub fn unpack_relay_reward(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let relay_reward = token_proxy::RelayReward::unpack(&data).handle_error()?;
+
+    let rr = WasmRelayReward {
+        is_initialized: relay_reward.is_initialized,
+        account_kind: relay_reward.account_kind,
+        relay: relay_reward.relay,
+        round_number: relay_reward.round_number,
+        votes: relay_reward.votes,
+        rewarded_votes: relay_reward.rewarded_votes,
+        reparation: relay_reward.reparation,
+    };
+
+    return serde_wasm_bindgen::to_value(&rr).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackProxyAccount",
+    unchecked_return_type = "WasmProxyAccount"
+)]
+pub fn unpack_proxy_account(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let proxy = spl_token::state::Account::unpack(&data).handle_error()?;
+
+    let pa = WasmProxyAccount {
+        mint: proxy.mint,
+        owner: proxy.owner,
+        amount: proxy.amount,
+        is_frozen: proxy.is_frozen(),
+    };
+
+    return serde_wasm_bindgen::to_value(&pa).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "getPendingProxyBalances",
+    unchecked_return_type = "WasmPendingProxyBalance[]"
+)]
+pub fn get_pending_proxy_balances(
+    owner: String,
+    #[wasm_bindgen(unchecked_param_type = "WasmAccountData[]")] withdrawals: Vec<JsValue>,
+    #[wasm_bindgen(unchecked_param_type = "WasmAccountData[]")] proxies: Vec<JsValue>,
+) -> Result<JsValue, JsValue> {
+    let owner = Pubkey::from_str(owner.as_str()).handle_error()?;
+
+    // Withdrawals of the owner whose funds were parked in the proxy awaiting payload execution
+    let mut pending_withdrawals: Vec<(Pubkey, Pubkey)> = Vec::new();
+
+    for withdrawal in withdrawals {
+        let withdrawal: WasmAccountData =
+            serde_wasm_bindgen::from_value(withdrawal).handle_error()?;
+        let withdrawal_pubkey = Pubkey::from_str(withdrawal.address.as_str()).handle_error()?;
+        let data = general_purpose::STANDARD
+            .decode(withdrawal.data)
+            .handle_error()?;
+
+        let is_ever = token_proxy::WithdrawalMultiTokenEver::unpack(&data)
+            .map(|withdrawal| is_event_len_valid(withdrawal.event.len, &withdrawal.event.data))
+            .unwrap_or_default();
+
+        let (mint, recipient, status) = match is_ever {
+            true => {
+                let withdrawal =
+                    token_proxy::WithdrawalMultiTokenEver::unpack(&data).handle_error()?;
+                (
+                    token_proxy::get_mint_address(&withdrawal.event.data.token),
+                    withdrawal.event.data.recipient,
+                    withdrawal.meta.data.status,
+                )
+            }
+            false => {
+                let withdrawal =
+                    token_proxy::WithdrawalMultiTokenSol::unpack(&data).handle_error()?;
+                (
+                    withdrawal.event.data.mint,
+                    withdrawal.event.data.recipient,
+                    withdrawal.meta.data.status,
+                )
+            }
+        };
+
+        if recipient == owner && status == token_proxy::WithdrawalTokenStatus::WaitingForExecute {
+            pending_withdrawals.push((mint, withdrawal_pubkey));
+        }
+    }
+
+    let mut balances = Vec::new();
+
+    for proxy in proxies {
+        let proxy: WasmAccountData = serde_wasm_bindgen::from_value(proxy).handle_error()?;
+        let proxy_pubkey = Pubkey::from_str(proxy.address.as_str()).handle_error()?;
+        let data = general_purpose::STANDARD
+            .decode(proxy.data)
+            .handle_error()?;
+
+        let proxy_account = spl_token::state::Account::unpack(&data).handle_error()?;
+
+        if proxy_pubkey != token_proxy::get_proxy_address(&proxy_account.mint, &owner) {
+            return Err("Proxy account doesn't belong to the owner").handle_error();
+        }
+
+        if proxy_account.amount == 0 {
+            continue;
+        }
+
+        let withdrawals = pending_withdrawals
+            .iter()
+            .filter(|(mint, _)| *mint == proxy_account.mint)
+            .map(|(_, withdrawal_pubkey)| *withdrawal_pubkey)
+            .collect();
+
+        balances.push(WasmPendingProxyBalance {
+            mint: proxy_account.mint,
+            proxy: proxy_pubkey,
+            amount: proxy_account.amount,
+            withdrawals,
+        });
+    }
+
+    return serde_wasm_bindgen::to_value(&balances).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackRoundLoaderSettings",
+    unchecked_return_type = "WasmRoundLoaderSettings"
+)]
+pub fn unpack_round_loader_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let settings = round_loader::Settings::unpack(&data).handle_error()?;
+
+    let s = WasmRoundLoaderSettings {
+        is_initialized: settings.is_initialized,
+        account_kind: settings.account_kind,
+        current_round_number: settings.current_round_number,
+        round_submitter: settings.round_submitter,
+        min_required_votes: settings.min_required_votes,
+        round_ttl: settings.round_ttl,
+        guardian: settings.guardian,
+        quorum_numerator: settings.quorum_numerator,
+        quorum_denominator: settings.quorum_denominator,
+        round_handover_period: settings.round_handover_period,
     };
 
     return serde_wasm_bindgen::to_value(&s).handle_error();
 }
 
-#[wasm_bindgen(js_name = "getProposalSolAddress")]
-pub fn get_proposal_sol_address(
-    round_number: u32,
-    event_timestamp: u32,
-    event_transaction_lt: u64,
-    event_configuration: String,
-    mint_address: String,
-    recipient_address: String,
+#[wasm_bindgen(js_name = "unpackRelayRound", unchecked_return_type = "WasmRelayRound")]
+pub fn unpack_relay_round(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let relay_round = round_loader::RelayRound::unpack(&data).handle_error()?;
+
+    let rr = WasmRelayRound {
+        is_initialized: relay_round.is_initialized,
+        account_kind: relay_round.account_kind,
+        round_number: relay_round.round_number,
+        round_end: relay_round.round_end,
+        relays: relay_round.relays,
+        weights: relay_round.weights,
+        quorum_numerator: relay_round.quorum_numerator,
+        quorum_denominator: relay_round.quorum_denominator,
+    };
+
+    return serde_wasm_bindgen::to_value(&rr).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackRelayRoundProposal",
+    unchecked_return_type = "WasmRelayRoundProposal"
+)]
+pub fn unpack_relay_round_proposal(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let relay_round_proposal = round_loader::RelayRoundProposal::unpack(&data).handle_error()?;
+
+    let rrp = WasmRelayRoundProposal {
+        is_initialized: relay_round_proposal.is_initialized,
+        account_kind: relay_round_proposal.account_kind,
+        author: relay_round_proposal.author,
+        round_number: relay_round_proposal.round_number,
+        required_votes: relay_round_proposal.required_votes,
+        pda: relay_round_proposal.pda,
+        event: relay_round_proposal.event,
+        meta: relay_round_proposal.meta,
+        signers: relay_round_proposal.signers,
+    };
+
+    return serde_wasm_bindgen::to_value(&rrp).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "unpackRelayEviction",
+    unchecked_return_type = "WasmRelayEviction"
+)]
+pub fn unpack_relay_eviction(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let relay_eviction = round_loader::RelayEviction::unpack(&data).handle_error()?;
+
+    let re = WasmRelayEviction {
+        is_initialized: relay_eviction.is_initialized,
+        account_kind: relay_eviction.account_kind,
+        round_number: relay_eviction.round_number,
+        relay: relay_eviction.relay,
+        required_votes: relay_eviction.required_votes,
+        executed: relay_eviction.executed,
+        signers: relay_eviction.signers,
+    };
+
+    return serde_wasm_bindgen::to_value(&re).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "getQuorumProgress",
+    unchecked_return_type = "WasmQuorumProgress"
+)]
+pub fn get_quorum_progress(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let proposal = Proposal::unpack_from_slice(&data).handle_error()?;
+
+    proposal
+        .account_kind
+        .as_proposal()
+        .ok_or("Account is not a proposal")
+        .handle_error()?;
+
+    let count_votes = |vote: Vote| proposal.signers.iter().filter(|v| **v == vote).count() as u32;
+
+    let qp = WasmQuorumProgress {
+        required_votes: proposal.required_votes,
+        confirms: bridge_utils::helper::get_recorded_weight(
+            &proposal.signers,
+            proposal.confirmed_weight,
+        ),
+        rejects: count_votes(Vote::Reject),
+        remaining_relays: count_votes(Vote::None),
+    };
+
+    return serde_wasm_bindgen::to_value(&qp).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "getDepositPreview",
+    unchecked_return_type = "WasmTransferPreview"
+)]
+pub fn get_deposit_preview(
+    settings_data: Vec<u8>,
+    token_settings_data: Vec<u8>,
+    amount: u64,
+    solana_decimals: u8,
+    vault_balance: Option<u64>,
+    timestamp: i64,
+) -> Result<JsValue, JsValue> {
+    let settings = token_proxy::Settings::unpack(&settings_data).handle_error()?;
+    let token_settings = token_proxy::TokenSettings::unpack(&token_settings_data).handle_error()?;
+
+    let tp = match preview_deposit(
+        &settings,
+        &token_settings,
+        amount,
+        solana_decimals,
+        vault_balance,
+        timestamp,
+    ) {
+        Ok((fee, receive_amount)) => WasmTransferPreview {
+            fee: Some(fee.to_string()),
+            receive_amount: Some(receive_amount.to_string()),
+            queued: None,
+            blocked: None,
+        },
+        Err(e) => WasmTransferPreview::blocked(e),
+    };
+
+    return serde_wasm_bindgen::to_value(&tp).handle_error();
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(
+    js_name = "getWithdrawalPreview",
+    unchecked_return_type = "WasmTransferPreview"
+)]
+pub fn get_withdrawal_preview(
+    settings_data: Vec<u8>,
+    token_settings_data: Vec<u8>,
     amount: String,
-    payload: String,
+    ever_decimals: u8,
+    solana_decimals: u8,
+    recipient: String,
+    chain_id: Option<u64>,
+    vault_balance: Option<u64>,
+    timestamp: i64,
+    author: Option<String>,
+    denylist_data: Option<Vec<u8>>,
 ) -> Result<JsValue, JsValue> {
-    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
-    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
-    let amount = u128::from_str(&amount).handle_error()?;
+    let settings = token_proxy::Settings::unpack(&settings_data).handle_error()?;
+    let token_settings = token_proxy::TokenSettings::unpack(&token_settings_data).handle_error()?;
+    let denylist = denylist_data
+        .map(|data| token_proxy::Denylist::unpack(&data))
+        .transpose()
+        .handle_error()?;
 
-    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
+    let amount = u128::from_str(amount.as_str()).handle_error()?;
+    let recipient = Pubkey::from_str(recipient.as_str()).handle_error()?;
+    let author = author
+        .map(|author| Pubkey::from_str(author.as_str()))
+        .transpose()
+        .handle_error()?
+        .unwrap_or_default();
+
+    let tp = match preview_withdrawal(
+        &settings,
+        &token_settings,
+        denylist.as_ref(),
+        &author,
+        &recipient,
+        amount,
+        ever_decimals,
+        solana_decimals,
+        chain_id,
+        vault_balance,
+        timestamp,
+    ) {
+        Ok((fee, receive_amount, queued)) => WasmTransferPreview {
+            fee: Some(fee.to_string()),
+            receive_amount: Some(receive_amount.to_string()),
+            queued: queued.map(|reason| reason.to_string()),
+            blocked: None,
+        },
+        Err(e) => WasmTransferPreview::blocked(e),
+    };
 
-    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+    return serde_wasm_bindgen::to_value(&tp).handle_error();
+}
 
-    let withdrawal_pubkey = token_proxy::get_withdrawal_sol_address(
-        round_number,
-        event_timestamp,
-        event_transaction_lt,
-        &event_configuration,
-        mint_address,
-        recipient_address,
+fn preview_deposit(
+    settings: &token_proxy::Settings,
+    token_settings: &token_proxy::TokenSettings,
+    amount: u64,
+    solana_decimals: u8,
+    vault_balance: Option<u64>,
+    timestamp: i64,
+) -> Result<(u64, u128), SolanaBridgeError> {
+    if settings.is_emergency(timestamp) || token_settings.is_emergency(timestamp) {
+        return Err(SolanaBridgeError::EmergencyEnabled);
+    }
+
+    if token_settings.deposits_paused {
+        return Err(SolanaBridgeError::TokenDepositsPaused);
+    }
+
+    if token_settings.delisted {
+        return Err(SolanaBridgeError::TokenDelisted);
+    }
+
+    if amount < token_settings.min_deposit_amount {
+        return Err(SolanaBridgeError::DepositBelowMinimum);
+    }
+
+    // Vault balance is only bounded for Solana tokens
+    if let (token_proxy::TokenKind::Solana { .. }, Some(vault_balance)) =
+        (&token_settings.kind, vault_balance)
+    {
+        if vault_balance
+            .checked_add(amount)
+            .ok_or(SolanaBridgeError::Overflow)?
+            > token_settings.deposit_limit
+        {
+            return Err(SolanaBridgeError::DepositLimit);
+        }
+    }
+
+    if let Some(deposit_daily_limit) = token_settings.deposit_daily_limit {
+        let current_epoch = timestamp / SECONDS_PER_DAY as i64;
+
+        let deposit_daily_amount = if token_settings.deposit_epoch == current_epoch {
+            token_settings.deposit_daily_amount
+        } else {
+            0
+        };
+
+        if deposit_daily_amount
+            .checked_add(amount)
+            .ok_or(SolanaBridgeError::Overflow)?
+            > deposit_daily_limit
+        {
+            return Err(SolanaBridgeError::DepositDailyLimit);
+        }
+    }
+
+    let fee = token_settings
+        .deposit_fee(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    // Amount without fee
+    let pure_amount = amount.checked_sub(fee).ok_or(SolanaBridgeError::Overflow)?;
+
+    // Amount in Ever decimals
+    let receive_amount = match token_settings.kind {
+        token_proxy::TokenKind::Ever { decimals, .. } => {
+            bridge_utils::amount::to_ever_amount(pure_amount, decimals, solana_decimals)?
+        }
+        token_proxy::TokenKind::Solana { .. } => pure_amount as u128,
+    };
+
+    Ok((fee, receive_amount))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn preview_withdrawal(
+    settings: &token_proxy::Settings,
+    token_settings: &token_proxy::TokenSettings,
+    denylist: Option<&token_proxy::Denylist>,
+    author: &Pubkey,
+    recipient: &Pubkey,
+    amount: u128,
+    ever_decimals: u8,
+    solana_decimals: u8,
+    chain_id: Option<u64>,
+    vault_balance: Option<u64>,
+    timestamp: i64,
+) -> Result<(u64, u64, Option<&'static str>), SolanaBridgeError> {
+    // Amount in Solana decimals
+    let amount = match token_settings.kind {
+        token_proxy::TokenKind::Ever { .. } => {
+            bridge_utils::amount::to_solana_amount(amount, ever_decimals, solana_decimals)?
+        }
+        token_proxy::TokenKind::Solana { .. } => {
+            u64::try_from(amount).map_err(|_| SolanaBridgeError::Overflow)?
+        }
+    };
+
+    let preview = token_settings.preview_withdrawal(
+        settings,
+        denylist,
+        author,
+        recipient,
         amount,
-        payload,
-    );
+        chain_id,
+        vault_balance,
+        timestamp,
+    )?;
+
+    let queued = if preview.limit_exceeded {
+        Some("Withdrawal limit exceeded")
+    } else if preview.daily_limit_exceeded {
+        Some("Withdrawal daily limit exceeded")
+    } else if preview.denylisted {
+        Some("Withdrawal author or recipient is denylisted")
+    } else if preview.insufficient_vault_balance {
+        Some("Insufficient vault balance")
+    } else {
+        None
+    };
 
-    return serde_wasm_bindgen::to_value(&withdrawal_pubkey).handle_error();
+    Ok((preview.fee, preview.transfer_amount, queued))
 }
 
-#[wasm_bindgen(js_name = "getProxyAddress")]
-pub fn get_proxy_address_payload(
-    mint_address: String,
-    recipient_address: String,
+#[wasm_bindgen(
+    js_name = "getBridgeErrors",
+    unchecked_return_type = "WasmBridgeError[]"
+)]
+pub fn get_bridge_errors() -> Result<JsValue, JsValue> {
+    let errors = (0..)
+        .map_while(|code| SolanaBridgeError::try_from(code).ok())
+        .map(WasmBridgeError::from)
+        .collect::<Vec<_>>();
+
+    return serde_wasm_bindgen::to_value(&errors).handle_error();
+}
+
+#[wasm_bindgen(
+    js_name = "mapTransactionError",
+    unchecked_return_type = "WasmBridgeError | undefined"
+)]
+pub fn map_transaction_error(
+    #[wasm_bindgen(unchecked_param_type = "string[]")] logs: JsValue,
 ) -> Result<JsValue, JsValue> {
-    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
-    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
-    let proxy_address = token_proxy::get_proxy_address(&mint_address, &recipient_address);
-    return serde_wasm_bindgen::to_value(&proxy_address).handle_error();
+    let logs: Vec<String> = serde_wasm_bindgen::from_value(logs).handle_error()?;
+
+    let error = SolanaBridgeError::from_logs(&logs).map(WasmBridgeError::from);
+
+    return serde_wasm_bindgen::to_value(&error).handle_error();
 }
 
-#[wasm_bindgen(js_name = "getProposalEverAddress")]
-pub fn get_proposal_ever_address(
-    round_number: u32,
-    event_timestamp: u32,
-    event_transaction_lt: u64,
-    event_configuration: String,
-    token_address: String,
-    recipient_address: String,
-    amount: String,
-    name: String,
-    symbol: String,
-    decimals: u8,
-    payload: String,
+#[wasm_bindgen(
+    js_name = "decodeAccount",
+    unchecked_return_type = "WasmDecodedAccount"
+)]
+pub fn decode_account(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    // Skip type tag and `is_initialized` flag
+    let account_data = strip_discriminator(&data);
+    let mut account_kind_data = account_data.get(1..).unwrap_or_default();
+    let account_kind =
+        <AccountKind as BorshDeserialize>::deserialize(&mut account_kind_data).handle_error()?;
+
+    // Settings and proposals of Round Loader share account kind with Token Proxy ones
+    let (account_type, account) = match account_kind {
+        AccountKind::Settings(..) if data.len() == round_loader::Settings::LEN => {
+            ("roundLoaderSettings", unpack_round_loader_settings(data)?)
+        }
+        AccountKind::Settings(..) => ("settings", unpack_settings(data)?),
+        AccountKind::TokenSettings(..) => ("tokenSettings", unpack_token_settings(data)?),
+        AccountKind::TokenDecimals(..) => ("tokenDecimals", unpack_token_decimals(data)?),
+        AccountKind::MultiVault(..) => ("multiVault", unpack_multi_vault(data)?),
+        AccountKind::ProposalIndex(..) => ("proposalIndex", unpack_proposal_index(data)?),
+        AccountKind::FeeLedger(..) => ("feeLedger", unpack_fee_ledger(data)?),
+        AccountKind::RecipientHook(..) => ("recipientHook", unpack_recipient_hook(data)?),
+        AccountKind::PendingChange(..) => ("pendingChange", unpack_pending_change(data)?),
+        AccountKind::Denylist(..) => ("denylist", unpack_denylist(data)?),
+        AccountKind::LpRegistry(..) => ("lpRegistry", unpack_lp_registry(data)?),
+        AccountKind::RelayReward(..) => ("relayReward", unpack_relay_reward(data)?),
+        AccountKind::Referral(..) => ("referral", unpack_referral(data)?),
+        AccountKind::ApprovalQueue(..) => ("approvalQueue", unpack_approval_queue(data)?),
+        AccountKind::BridgeStats(..) => ("bridgeStats", unpack_bridge_stats(data)?),
+        AccountKind::RelayRound(..) => ("relayRound", unpack_relay_round(data)?),
+        AccountKind::RelayEviction(..) => ("relayEviction", unpack_relay_eviction(data)?),
+        AccountKind::MerkleRelayRound(..) => {
+            return Err("Merkle relay rounds are not supported").handle_error()
+        }
+        AccountKind::Deposit(..) => {
+            let is_ever = token_proxy::DepositMultiTokenEver::unpack(&data)
+                .map(|deposit| is_event_len_valid(deposit.event.len, &deposit.event.data))
+                .unwrap_or_default();
+
+            match is_ever {
+                true => ("depositEver", unpack_deposit_ever(data)?),
+                false => ("depositSol", unpack_deposit_sol(data)?),
+            }
+        }
+        // Including proposals created before type tags
+        AccountKind::Proposal(..)
+            if data.len() + DISCRIMINATOR_LEN >= round_loader::RelayRoundProposal::LEN =>
+        {
+            ("relayRoundProposal", unpack_relay_round_proposal(data)?)
+        }
+        AccountKind::Proposal(..) => {
+            let is_ever = token_proxy::WithdrawalMultiTokenEver::unpack(&data)
+                .map(|withdrawal| is_event_len_valid(withdrawal.event.len, &withdrawal.event.data))
+                .unwrap_or_default();
+
+            match is_ever {
+                true => (
+                    "withdrawalMultiTokenEver",
+                    unpack_withdrawal_multitoken_ever(data)?,
+                ),
+                false => (
+                    "withdrawalMultiTokenSol",
+                    unpack_withdrawal_multitoken_sol(data)?,
+                ),
+            }
+        }
+    };
+
+    let decoded = js_sys::Object::new();
+    js_sys::Reflect::set(&decoded, &"type".into(), &account_type.into())?;
+    js_sys::Reflect::set(&decoded, &"account".into(), &account)?;
+
+    return Ok(decoded.into());
+}
+
+#[wasm_bindgen(
+    js_name = "decodeInstruction",
+    unchecked_return_type = "WasmDecodedInstruction"
+)]
+pub fn decode_instruction(
+    #[wasm_bindgen(unchecked_param_type = "Instruction")] instruction: JsValue,
 ) -> Result<JsValue, JsValue> {
-    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
-    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
-    let amount = u128::from_str(&amount).handle_error()?;
+    let instruction: Instruction = serde_wasm_bindgen::from_value(instruction).handle_error()?;
+
+    if instruction.program_id != token_proxy::id() {
+        return Err("Instruction is not addressed to Token Proxy").handle_error();
+    }
+
+    let ix = token_proxy::TokenProxyInstruction::decode(&instruction.data).handle_error()?;
+
+    let accounts = label_accounts(&ix.expected_accounts(), &instruction.accounts);
+
+    let decoded = js_sys::Object::new();
+    js_sys::Reflect::set(&decoded, &"name".into(), &ix.name().into())?;
+    js_sys::Reflect::set(
+        &decoded,
+        &"data".into(),
+        &serde_wasm_bindgen::to_value(&ix).handle_error()?,
+    )?;
+    js_sys::Reflect::set(
+        &decoded,
+        &"accounts".into(),
+        &serde_wasm_bindgen::to_value(&accounts).handle_error()?,
+    )?;
+
+    return Ok(decoded.into());
+}
+
+/// Optional roles are filled in order while there are accounts beyond the required ones,
+/// a list takes what is left
+fn label_accounts(
+    roles: &[token_proxy::AccountRole],
+    accounts: &[AccountMeta],
+) -> Vec<WasmLabeledAccount> {
+    let required = roles
+        .iter()
+        .filter(|role| !role.is_optional && !role.is_list)
+        .count();
+    let mut extra = accounts.len().saturating_sub(required);
+
+    let mut labeled = Vec::with_capacity(accounts.len());
+    let mut accounts = accounts.iter();
+
+    for (i, role) in roles.iter().enumerate() {
+        let count = if role.is_list {
+            let optional_after = roles[i + 1..]
+                .iter()
+                .filter(|role| role.is_optional)
+                .count();
+            let count = extra.saturating_sub(optional_after);
+            extra -= count;
+            count
+        } else if role.is_optional {
+            if extra == 0 {
+                continue;
+            }
+            extra -= 1;
+            1
+        } else {
+            1
+        };
+
+        for account in accounts.by_ref().take(count) {
+            labeled.push(WasmLabeledAccount::new(Some(role.name), account));
+        }
+    }
+
+    labeled.extend(accounts.map(|account| WasmLabeledAccount::new(None, account)));
+
+    labeled
+}
+
+/// Ever and Solana events of the same account kind are told apart by the stored event length
+fn is_event_len_valid<T: BorshSerialize>(len: u32, event: &T) -> bool {
+    event
+        .try_to_vec()
+        .map(|event| event.len() == len as usize)
+        .unwrap_or_default()
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &'static str = r#"
+export type Pubkey = number[];
+
+export interface AccountMeta {
+    pubkey: Pubkey;
+    is_signer: boolean;
+    is_writable: boolean;
+}
+
+export interface Instruction {
+    program_id: Pubkey;
+    accounts: AccountMeta[];
+    data: number[];
+}
+
+export type AccountKind =
+    | { Settings: [number, number] }
+    | { Deposit: number }
+    | { Proposal: [number, number | undefined] }
+    | { RelayRound: number }
+    | { MultiVault: number }
+    | { TokenSettings: [number, number] }
+    | { TokenDecimals: number }
+    | { ProposalIndex: number }
+    | { RelayEviction: number }
+    | { FeeLedger: number }
+    | { RecipientHook: number }
+    | { PendingChange: number }
+    | { Denylist: number }
+    | { MerkleRelayRound: number }
+    | { RelayReward: number }
+    | { BridgeStats: number }
+    | { LpRegistry: number }
+    | { Referral: number }
+    | { ApprovalQueue: number };
+
+export type Vote = "None" | "Confirm" | "Reject";
+export type VoteReward = "None" | "Unclaimed" | "Claimed";
+export type Role = "Guardian" | "Manager" | "WithdrawalManager";
+export type FeeType = "Deposit" | "Withdrawal";
+export type ProposalStatus = "New" | "Executed";
+export type WithdrawalTokenStatus =
+    | "New"
+    | "Processed"
+    | "Cancelled"
+    | "Pending"
+    | "WaitingForApprove"
+    | "WaitingForExecute"
+    | "Expired";
+
+export interface EverAddress {
+    AddrStd: { workchain_id: number; address: number[] };
+}
+
+export interface PDA {
+    event_timestamp: number;
+    event_transaction_lt: number;
+    event_configuration: Pubkey;
+}
+
+export interface FeeInfo {
+    multiplier: number;
+    divisor: number;
+}
+
+export interface FeeTier {
+    amount_threshold: number;
+    fee_info: FeeInfo;
+}
+
+export interface FeeTiers {
+    tiers: FeeTier[];
+    min_fee: number;
+}
+
+export interface TokenDefaults {
+    deposit_limit: number;
+    withdrawal_limit: number;
+    withdrawal_daily_limit: number;
+    deposit_daily_limit?: number;
+    fee_deposit_info: FeeInfo;
+    fee_withdrawal_info: FeeInfo;
+}
+
+export interface DepositValueFloor {
+    multiplier: number;
+    divisor: number;
+}
+
+export interface EmergencyInfo {
+    authority: Pubkey;
+    timestamp: number;
+    reason?: number;
+}
+
+export interface AuthorityMigration {
+    new_authority: Pubkey;
+    unlock_timestamp: number;
+}
+
+export interface PendingRole {
+    role: Role;
+    authority: Pubkey;
+}
+
+export type TimelockedChange =
+    | {
+          WithdrawalLimits: {
+              token_settings: Pubkey;
+              new_withdrawal_limit?: number;
+              new_withdrawal_daily_limit?: number;
+          };
+      }
+    | { Fee: { token_settings: Pubkey; fee_type: FeeType; multiplier: number; divisor: number } }
+    | { Role: { role: Role; new_authority?: Pubkey } }
+    | { Delay: { delay_seconds: number } }
+    | {
+          WithdrawalLimitExemptions: {
+              token_settings: Pubkey;
+              new_withdrawal_limit_exemptions: Pubkey[];
+          };
+      }
+    | { Wever: { new_wever_mint?: Pubkey; new_wever_defaults?: TokenDefaults } }
+    | { TokenDefaults: { new_token_defaults: TokenDefaults } };
+
+export interface WithLen<T> {
+    len: number;
+    data: T;
+}
+
+export interface DepositMultiTokenEverEvent {
+    token: EverAddress;
+    amount: bigint;
+    recipient: EverAddress;
+    value: number;
+    expected_evers: number[];
+    payload: number[];
+    fee: bigint;
+    gross_amount: bigint;
+    reference?: number[];
+}
+
+export interface DepositMultiTokenSolEvent {
+    base_token: Pubkey;
+    name: string;
+    symbol: string;
+    decimals: number;
+    amount: bigint;
+    recipient: EverAddress;
+    value: number;
+    expected_evers: number[];
+    payload: number[];
+    fee: bigint;
+    gross_amount: bigint;
+    reference?: number[];
+}
+
+export interface WithdrawalMultiTokenEverEvent {
+    token: EverAddress;
+    name: string;
+    symbol: string;
+    decimals: number;
+    amount: bigint;
+    recipient: Pubkey;
+    payload: number[];
+}
+
+export interface WithdrawalMultiTokenSolEvent {
+    mint: Pubkey;
+    amount: bigint;
+    recipient: Pubkey;
+    payload: number[];
+}
+
+export interface WithdrawalTokenMeta {
+    status: WithdrawalTokenStatus;
+    bounty: number;
+    epoch: number;
+    funder: Pubkey;
+    payload_cursor: number;
+    expires_at: number;
+    filled_amount: number;
+    rejected: boolean;
+}
+
+export interface RelayRoundProposalEvent {
+    round_num: number;
+    relays: Pubkey[];
+    round_end: number;
+}
+
+export interface RelayRoundProposalMeta {
+    status: ProposalStatus;
+}
+
+export interface WasmTokenDefaults {
+    deposit_limit: string;
+    withdrawal_limit: string;
+    withdrawal_daily_limit: string;
+    deposit_daily_limit?: string;
+    fee_deposit_info: FeeInfo;
+    fee_withdrawal_info: FeeInfo;
+}
+
+export interface WasmSettings {
+    emergency: boolean;
+    guardian: Pubkey;
+    withdrawal_manager: Pubkey;
+    manager: Pubkey;
+    payload_allowlist: Pubkey[];
+    deposit_caller_allowlist?: Pubkey[];
+    emergency_expiry?: number;
+    emergency_info?: EmergencyInfo;
+    token_defaults?: WasmTokenDefaults;
+    deposits_paused: boolean;
+    withdrawals_paused: boolean;
+    guardians: Pubkey[];
+    treasury?: Pubkey;
+    wever_mint?: Pubkey;
+    wever_defaults?: WasmTokenDefaults;
+    enabled_chains: number[];
+    deposit_value_floor?: DepositValueFloor;
+    min_supported_ix_version: number;
+    pending_role?: PendingRole;
+    timelock_delay: number;
+}
+
+export interface WasmTokenAddresses {
+    mint: Pubkey;
+    vault?: Pubkey;
+    settings: Pubkey;
+    token_settings: Pubkey;
+    multivault: Pubkey;
+    fee_ledger: Pubkey;
+    token_decimals?: Pubkey;
+}
+
+export type WasmTokenKind =
+    | { Ever: { mint: string; token: string; decimals: number } }
+    | { Solana: { mint: string; vault: string } };
+
+export interface WasmTokenSettings {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    kind: WasmTokenKind;
+    deposit_limit: string;
+    withdrawal_limit: string;
+    withdrawal_daily_limit: string;
+    withdrawal_daily_amount: string;
+    withdrawal_epoch: string;
+    emergency: boolean;
+    name: string;
+    symbol: string;
+    fee_supply: number;
+    fee_deposit_info: FeeInfo;
+    fee_withdrawal_info: FeeInfo;
+    deposit_daily_limit?: string;
+    deposit_daily_amount: string;
+    deposit_epoch: string;
+    withdrawal_epoch_length?: string;
+    emergency_expiry?: number;
+    emergency_info?: EmergencyInfo;
+    delisted: boolean;
+    authority_migration?: AuthorityMigration;
+    deposits_paused: boolean;
+    withdrawals_paused: boolean;
+    vote_reward: number;
+    withdrawal_limit_exemptions: Pubkey[];
+    fee_deposit_tiers: FeeTiers;
+    fee_withdrawal_tiers: FeeTiers;
+    min_deposit_amount: number;
+    fills_restricted: boolean;
+    referral_fee_bps: number;
+    pending_withdrawals: number;
+    closed: boolean;
+}
+
+export interface WasmWithdrawalMultiTokenEver {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    author: Pubkey;
+    round_number: number;
+    required_votes: number;
+    pda: PDA;
+    event: WithLen<WithdrawalMultiTokenEverEvent>;
+    meta: WithLen<WithdrawalTokenMeta>;
+    signers: Vote[];
+    chain_id?: number;
+    vote_rewards: VoteReward[];
+    confirmed_weight: number;
+}
+
+export interface WasmWithdrawalMultiTokenSol {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    author: Pubkey;
+    round_number: number;
+    required_votes: number;
+    pda: PDA;
+    event: WithLen<WithdrawalMultiTokenSolEvent>;
+    meta: WithLen<WithdrawalTokenMeta>;
+    signers: Vote[];
+    chain_id?: number;
+    vote_rewards: VoteReward[];
+    confirmed_weight: number;
+}
+
+export interface WasmDepositTokenMeta {
+    seed: string;
+    funder: Pubkey;
+    slot: number;
+    refunded: boolean;
+}
+
+export interface WasmDepositMultiTokenEver {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    event: WithLen<DepositMultiTokenEverEvent>;
+    meta: WasmDepositTokenMeta;
+}
+
+export interface WasmDepositMultiTokenSol {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    event: WithLen<DepositMultiTokenSolEvent>;
+    meta: WasmDepositTokenMeta;
+}
+
+export interface WasmMultiVault {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+}
+
+export interface WasmTokenDecimals {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    token: string;
+    solana_decimals: number;
+}
+
+export interface WasmProposalIndex {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    event_data: string;
+}
+
+export interface WasmFeeLedger {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    token_settings: Pubkey;
+    supply: number;
+}
 
-    let token = EverAddress::from_str(&token_address).handle_error()?;
+export interface WasmPendingChange {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    author: Pubkey;
+    seed: string;
+    change: TimelockedChange;
+    eta: number;
+}
 
-    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+export interface WasmRecipientHook {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    recipient: Pubkey;
+    program_id: Pubkey;
+    data: number[];
+    accounts: Pubkey[];
+}
 
-    let withdrawal_pubkey = token_proxy::get_withdrawal_ever_address(
-        round_number,
-        event_timestamp,
-        event_transaction_lt,
-        &event_configuration,
-        token,
-        name,
-        symbol,
-        decimals,
-        recipient_address,
-        amount,
-        payload,
-    );
+export interface WasmDenylist {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    addresses: Pubkey[];
+}
 
-    return serde_wasm_bindgen::to_value(&withdrawal_pubkey).handle_error();
+export interface WasmLpRegistry {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    providers: Pubkey[];
 }
 
-#[wasm_bindgen(js_name = "unpackTokenSettings")]
-pub fn unpack_token_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
-    let token_settings = token_proxy::TokenSettings::unpack(&data).handle_error()?;
+export interface WasmReferral {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    referrer: Pubkey;
+    token_settings: Pubkey;
+    amount: number;
+}
 
-    let s = WasmTokenSettings {
-        is_initialized: token_settings.is_initialized,
-        account_kind: token_settings.account_kind,
-        kind: token_settings.kind.into(),
-        deposit_limit: token_settings.deposit_limit.to_string(),
-        withdrawal_limit: token_settings.withdrawal_limit.to_string(),
-        withdrawal_daily_limit: token_settings.withdrawal_daily_limit.to_string(),
-        withdrawal_daily_amount: token_settings.withdrawal_daily_amount.to_string(),
-        withdrawal_epoch: token_settings.withdrawal_epoch.to_string(),
-        emergency: token_settings.emergency,
-        name: token_settings.name,
-        symbol: token_settings.symbol,
-        fee_supply: token_settings.fee_supply,
-        fee_deposit_info: token_settings.fee_deposit_info,
-        fee_withdrawal_info: token_settings.fee_withdrawal_info,
-    };
+export interface WasmApproval {
+    withdrawal: Pubkey;
+    recipient: Pubkey;
+    amount: number;
+    status: WithdrawalTokenStatus;
+    note?: string;
+}
 
-    return serde_wasm_bindgen::to_value(&s).handle_error();
+export interface WasmApprovalQueue {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    approvals: WasmApproval[];
 }
 
-#[wasm_bindgen(js_name = "unpackWithdrawalMultiTokenEver")]
-pub fn unpack_withdrawal_multitoken_ever(data: Vec<u8>) -> Result<JsValue, JsValue> {
-    let withdrawal = token_proxy::WithdrawalMultiTokenEver::unpack(&data).handle_error()?;
+export interface WasmBridgeStats {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    deposits: number;
+    withdrawals: number;
+    deposit_volume: string;
+    withdrawal_volume: string;
+    pending_proposals: number;
+}
 
-    let w = WasmWithdrawalMultiTokenEver {
-        is_initialized: withdrawal.is_initialized,
-        account_kind: withdrawal.account_kind,
-        author: withdrawal.author,
-        round_number: withdrawal.round_number,
-        required_votes: withdrawal.required_votes,
-        pda: withdrawal.pda,
-        event: withdrawal.event,
-        meta: withdrawal.meta,
-        signers: withdrawal.signers,
-    };
+export interface WasmRelayReward {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    relay: Pubkey;
+    round_number: number;
+    votes: number;
+    rewarded_votes: number;
+    reparation: number;
+}
 
-    return serde_wasm_bindgen::to_value(&w).handle_error();
+export interface WasmProxyAccount {
+    mint: Pubkey;
+    owner: Pubkey;
+    amount: number;
+    is_frozen: boolean;
 }
 
-#[wasm_bindgen(js_name = "unpackWithdrawalMultiTokenSol")]
-pub fn unpack_withdrawal_multitoken_sol(data: Vec<u8>) -> Result<JsValue, JsValue> {
-    let withdrawal = token_proxy::WithdrawalMultiTokenSol::unpack(&data).handle_error()?;
+export interface WasmAccountData {
+    address: string;
+    data: string;
+}
 
-    let w = WasmWithdrawalMultiTokenSol {
-        is_initialized: withdrawal.is_initialized,
-        account_kind: withdrawal.account_kind,
-        author: withdrawal.author,
-        round_number: withdrawal.round_number,
-        required_votes: withdrawal.required_votes,
-        pda: withdrawal.pda,
-        event: withdrawal.event,
-        meta: withdrawal.meta,
-        signers: withdrawal.signers,
-    };
+export interface WasmPendingProxyBalance {
+    mint: Pubkey;
+    proxy: Pubkey;
+    amount: number;
+    withdrawals: Pubkey[];
+}
 
-    return serde_wasm_bindgen::to_value(&w).handle_error();
+export interface WasmQuorumProgress {
+    requiredVotes: number;
+    confirms: number;
+    rejects: number;
+    remainingRelays: number;
 }
 
-#[wasm_bindgen(js_name = "unpackDepositEver")]
-pub fn unpack_deposit_ever(data: Vec<u8>) -> Result<JsValue, JsValue> {
-    let deposit = token_proxy::DepositMultiTokenEver::unpack(&data).handle_error()?;
+export interface WasmTransferPreview {
+    fee?: string;
+    receiveAmount?: string;
+    queued?: string;
+    blocked?: string;
+}
 
-    let d = WasmDepositMultiTokenEver {
-        is_initialized: deposit.is_initialized,
-        account_kind: deposit.account_kind,
-        event: deposit.event,
-        meta: WasmDepositTokenMeta {
-            seed: deposit.meta.data.seed.to_string(),
-        },
-    };
+export interface WasmLabeledAccount {
+    role?: string;
+    pubkey: string;
+    is_signer: boolean;
+    is_writable: boolean;
+}
 
-    return serde_wasm_bindgen::to_value(&d).handle_error();
+export interface WasmDecodedInstruction {
+    name: string;
+    data: string | { [name: string]: unknown };
+    accounts: WasmLabeledAccount[];
 }
 
-#[wasm_bindgen(js_name = "unpackDepositSol")]
-pub fn unpack_deposit_sol(data: Vec<u8>) -> Result<JsValue, JsValue> {
-    let deposit = token_proxy::DepositMultiTokenSol::unpack(&data).handle_error()?;
+export interface WasmAddressLookupTable {
+    key: string;
+    addresses: string[];
+}
 
-    let d = WasmDepositMultiTokenSol {
-        is_initialized: deposit.is_initialized,
-        account_kind: deposit.account_kind,
-        event: deposit.event,
-        meta: WasmDepositTokenMeta {
-            seed: deposit.meta.data.seed.to_string(),
-        },
-    };
+export interface WasmRoundLoaderSettings {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    current_round_number: number;
+    round_submitter: Pubkey;
+    min_required_votes: number;
+    round_ttl: number;
+    guardian: Pubkey;
+    quorum_numerator: number;
+    quorum_denominator: number;
+    round_handover_period: number;
+}
 
-    return serde_wasm_bindgen::to_value(&d).handle_error();
+export interface WasmRelayRound {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    round_number: number;
+    round_end: number;
+    relays: Pubkey[];
+    weights: number[];
+    quorum_numerator: number;
+    quorum_denominator: number;
+}
+
+export interface WasmRelayEviction {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    round_number: number;
+    relay: Pubkey;
+    required_votes: number;
+    executed: boolean;
+    signers: Vote[];
+}
+
+export interface WasmRelayRoundProposal {
+    is_initialized: boolean;
+    account_kind: AccountKind;
+    author: Pubkey;
+    round_number: number;
+    required_votes: number;
+    pda: PDA;
+    event: WithLen<RelayRoundProposalEvent>;
+    meta: WithLen<RelayRoundProposalMeta>;
+    signers: Vote[];
+}
+
+export interface WasmEverAddressValidation {
+    is_valid: boolean;
+    address?: string;
+    bytes?: number[];
+    workchain_id?: number;
+    error?: string;
+}
+
+export interface WasmBridgeError {
+    code: number;
+    name: string;
+    message: string;
 }
 
+export type WasmDecodedAccount =
+    | { type: "settings"; account: WasmSettings }
+    | { type: "roundLoaderSettings"; account: WasmRoundLoaderSettings }
+    | { type: "tokenSettings"; account: WasmTokenSettings }
+    | { type: "tokenDecimals"; account: WasmTokenDecimals }
+    | { type: "multiVault"; account: WasmMultiVault }
+    | { type: "proposalIndex"; account: WasmProposalIndex }
+    | { type: "feeLedger"; account: WasmFeeLedger }
+    | { type: "recipientHook"; account: WasmRecipientHook }
+    | { type: "pendingChange"; account: WasmPendingChange }
+    | { type: "denylist"; account: WasmDenylist }
+    | { type: "lpRegistry"; account: WasmLpRegistry }
+    | { type: "relayReward"; account: WasmRelayReward }
+    | { type: "referral"; account: WasmReferral }
+    | { type: "approvalQueue"; account: WasmApprovalQueue }
+    | { type: "bridgeStats"; account: WasmBridgeStats }
+    | { type: "relayRound"; account: WasmRelayRound }
+    | { type: "relayEviction"; account: WasmRelayEviction }
+    | { type: "depositEver"; account: WasmDepositMultiTokenEver }
+    | { type: "depositSol"; account: WasmDepositMultiTokenSol }
+    | { type: "relayRoundProposal"; account: WasmRelayRoundProposal }
+    | { type: "withdrawalMultiTokenEver"; account: WasmWithdrawalMultiTokenEver }
+    | { type: "withdrawalMultiTokenSol"; account: WasmWithdrawalMultiTokenSol };
+"#;
+
 #[derive(Serialize, Deserialize)]
 pub struct WasmSettings {
     pub emergency: bool,
     pub guardian: Pubkey,
     pub withdrawal_manager: Pubkey,
     pub manager: Pubkey,
+    pub payload_allowlist: Vec<Pubkey>,
+    pub deposit_caller_allowlist: Option<Vec<Pubkey>>,
+    pub emergency_expiry: Option<i64>,
+    pub emergency_info: Option<token_proxy::EmergencyInfo>,
+    pub token_defaults: Option<WasmTokenDefaults>,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+    pub guardians: Vec<Pubkey>,
+    pub treasury: Option<Pubkey>,
+    pub wever_mint: Option<Pubkey>,
+    pub wever_defaults: Option<WasmTokenDefaults>,
+    pub enabled_chains: Vec<u64>,
+    pub deposit_value_floor: Option<token_proxy::DepositValueFloor>,
+    pub min_supported_ix_version: u8,
+    pub pending_role: Option<token_proxy::PendingRole>,
+    pub timelock_delay: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmTokenAddresses {
+    pub mint: Pubkey,
+    pub vault: Option<Pubkey>,
+    pub settings: Pubkey,
+    pub token_settings: Pubkey,
+    pub multivault: Pubkey,
+    pub fee_ledger: Pubkey,
+    pub token_decimals: Option<Pubkey>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmTokenDefaults {
+    pub deposit_limit: String,
+    pub withdrawal_limit: String,
+    pub withdrawal_daily_limit: String,
+    pub deposit_daily_limit: Option<String>,
+    pub fee_deposit_info: token_proxy::FeeInfo,
+    pub fee_withdrawal_info: token_proxy::FeeInfo,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1711,6 +6894,25 @@ pub struct WasmTokenSettings {
     pub fee_supply: u64,
     pub fee_deposit_info: token_proxy::FeeInfo,
     pub fee_withdrawal_info: token_proxy::FeeInfo,
+    pub deposit_daily_limit: Option<String>,
+    pub deposit_daily_amount: String,
+    pub deposit_epoch: String,
+    pub withdrawal_epoch_length: Option<String>,
+    pub emergency_expiry: Option<i64>,
+    pub emergency_info: Option<token_proxy::EmergencyInfo>,
+    pub delisted: bool,
+    pub authority_migration: Option<token_proxy::AuthorityMigration>,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+    pub vote_reward: u64,
+    pub withdrawal_limit_exemptions: Vec<Pubkey>,
+    pub fee_deposit_tiers: token_proxy::FeeTiers,
+    pub fee_withdrawal_tiers: token_proxy::FeeTiers,
+    pub min_deposit_amount: u64,
+    pub fills_restricted: bool,
+    pub referral_fee_bps: u16,
+    pub pending_withdrawals: u32,
+    pub closed: bool,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1724,6 +6926,9 @@ pub struct WasmWithdrawalMultiTokenEver {
     pub event: token_proxy::WithdrawalMultiTokenEverEventWithLen,
     pub meta: token_proxy::WithdrawalTokenMetaWithLen,
     pub signers: Vec<Vote>,
+    pub chain_id: Option<u64>,
+    pub vote_rewards: Vec<VoteReward>,
+    pub confirmed_weight: u32,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1737,6 +6942,9 @@ pub struct WasmWithdrawalMultiTokenSol {
     pub event: token_proxy::WithdrawalMultiTokenSolEventWithLen,
     pub meta: token_proxy::WithdrawalTokenMetaWithLen,
     pub signers: Vec<Vote>,
+    pub chain_id: Option<u64>,
+    pub vote_rewards: Vec<VoteReward>,
+    pub confirmed_weight: u32,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1758,6 +6966,260 @@ pub struct WasmDepositMultiTokenSol {
 #[derive(Serialize, Deserialize)]
 pub struct WasmDepositTokenMeta {
     pub seed: String,
+    pub funder: Pubkey,
+    pub slot: u64,
+    pub refunded: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmMultiVault {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmTokenDecimals {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub token: String,
+    pub solana_decimals: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmProposalIndex {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub event_data: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmFeeLedger {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub token_settings: Pubkey,
+    pub supply: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmPendingChange {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub seed: String,
+    pub change: token_proxy::TimelockedChange,
+    pub eta: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmRecipientHook {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub recipient: Pubkey,
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+    pub accounts: Vec<Pubkey>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmDenylist {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub addresses: Vec<Pubkey>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmLpRegistry {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub providers: Vec<Pubkey>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmReferral {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub referrer: Pubkey,
+    pub token_settings: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmApproval {
+    pub withdrawal: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub status: token_proxy::WithdrawalTokenStatus,
+    pub note: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmApprovalQueue {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub approvals: Vec<WasmApproval>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmBridgeStats {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub deposits: u64,
+    pub withdrawals: u64,
+    pub deposit_volume: String,
+    pub withdrawal_volume: String,
+    pub pending_proposals: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmRelayReward {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub relay: Pubkey,
+    pub round_number: u32,
+    pub votes: u32,
+    pub rewarded_votes: u32,
+    pub reparation: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmProxyAccount {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub is_frozen: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmAccountData {
+    pub address: String,
+    // Base64 encoded account data
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmPendingProxyBalance {
+    pub mint: Pubkey,
+    pub proxy: Pubkey,
+    pub amount: u64,
+    // Withdrawals waiting for payload execution
+    pub withdrawals: Vec<Pubkey>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmQuorumProgress {
+    pub required_votes: u32,
+    pub confirms: u32,
+    pub rejects: u32,
+    pub remaining_relays: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WasmTransferPreview {
+    pub fee: Option<String>,
+    // Amount credited to the recipient
+    pub receive_amount: Option<String>,
+    // Why the transfer would wait for approval or vault liquidity
+    pub queued: Option<String>,
+    // Why the transfer would be rejected
+    pub blocked: Option<String>,
+}
+
+impl WasmTransferPreview {
+    fn blocked(e: SolanaBridgeError) -> Self {
+        WasmTransferPreview {
+            fee: None,
+            receive_amount: None,
+            queued: None,
+            blocked: Some(e.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmLabeledAccount {
+    // None for accounts not expected by the instruction
+    pub role: Option<String>,
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl WasmLabeledAccount {
+    fn new(role: Option<&str>, account: &AccountMeta) -> Self {
+        Self {
+            role: role.map(str::to_string),
+            pubkey: account.pubkey.to_string(),
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmAddressLookupTable {
+    pub key: String,
+    pub addresses: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmRoundLoaderSettings {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub current_round_number: u32,
+    pub round_submitter: Pubkey,
+    pub min_required_votes: u32,
+    pub round_ttl: u32,
+    pub guardian: Pubkey,
+    pub quorum_numerator: u32,
+    pub quorum_denominator: u32,
+    pub round_handover_period: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmRelayRound {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    pub round_end: u32,
+    pub relays: Vec<Pubkey>,
+    pub weights: Vec<u32>,
+    pub quorum_numerator: u32,
+    pub quorum_denominator: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmRelayEviction {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    pub relay: Pubkey,
+    pub required_votes: u32,
+    pub executed: bool,
+    pub signers: Vec<Vote>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmRelayRoundProposal {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: round_loader::RelayRoundProposalEventWithLen,
+    pub meta: round_loader::RelayRoundProposalMetaWithLen,
+    pub signers: Vec<Vote>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmEverAddressValidation {
+    pub is_valid: bool,
+    pub address: Option<String>,
+    pub bytes: Option<Vec<u8>>,
+    pub workchain_id: Option<i8>,
+    pub error: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1793,6 +7255,23 @@ impl From<token_proxy::TokenKind> for WasmTokenKind {
     }
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct WasmBridgeError {
+    pub code: u32,
+    pub name: String,
+    pub message: String,
+}
+
+impl From<SolanaBridgeError> for WasmBridgeError {
+    fn from(error: SolanaBridgeError) -> Self {
+        Self {
+            code: error as u32,
+            name: format!("{:?}", error),
+            message: error.to_string(),
+        }
+    }
+}
+
 impl<T, E> HandleError for Result<T, E>
 where
     E: ToString,